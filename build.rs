@@ -0,0 +1,36 @@
+//! Datos de compilación para la vista "Acerca de" y `--version`
+//!
+//! Exporta `WIN_OPT_GIT_HASH` (commit corto, o `unknown` si no se compila
+//! desde un repositorio git) y `WIN_OPT_BUILD_EPOCH` (segundos desde 1970,
+//! tomados de `SOURCE_DATE_EPOCH` si está definida para compilaciones
+//! reproducibles).
+
+use std::process::Command;
+use std::time::{SystemTime, UNIX_EPOCH};
+
+fn main() {
+    println!("cargo:rerun-if-changed=.git/HEAD");
+    println!("cargo:rerun-if-changed=.git/refs");
+    println!("cargo:rerun-if-env-changed=SOURCE_DATE_EPOCH");
+
+    let hash = Command::new("git")
+        .args(["rev-parse", "--short", "HEAD"])
+        .output()
+        .ok()
+        .filter(|output| output.status.success())
+        .map(|output| String::from_utf8_lossy(&output.stdout).trim().to_string())
+        .filter(|hash| !hash.is_empty())
+        .unwrap_or_else(|| "unknown".to_string());
+    println!("cargo:rustc-env=WIN_OPT_GIT_HASH={}", hash);
+
+    let epoch = std::env::var("SOURCE_DATE_EPOCH")
+        .ok()
+        .and_then(|epoch| epoch.parse::<u64>().ok())
+        .unwrap_or_else(|| {
+            SystemTime::now()
+                .duration_since(UNIX_EPOCH)
+                .map(|elapsed| elapsed.as_secs())
+                .unwrap_or_default()
+        });
+    println!("cargo:rustc-env=WIN_OPT_BUILD_EPOCH={}", epoch);
+}
@@ -0,0 +1,117 @@
+//! Vista "Acerca de" y diagnóstico para informes de errores
+//!
+//! Reúne la versión y los datos de compilación, la edición y compilación
+//! de Windows, los permisos con que se ejecuta y las rutas de la
+//! configuración y los logs. Se calcula al abrir la vista (lee el registro)
+//! y `Y` lo copia como texto para pegarlo en un informe de error.
+
+use crate::app::App;
+use crate::clipboard;
+use crate::config::Config;
+use crate::i18n::I18nKey;
+use crate::logger;
+use crate::simulate;
+use crate::version;
+use crate::winver;
+use crate::{log_error, log_info};
+
+/// Filas del diagnóstico: etiqueta y valor
+pub fn collect(app: &App) -> Vec<(I18nKey, String)> {
+    let unknown = || app.t(I18nKey::InfoUnknown).to_string();
+
+    let mut elevation = app
+        .t(if app.elevated {
+            I18nKey::StatusAdmin
+        } else {
+            I18nKey::StatusStandardUser
+        })
+        .to_string();
+    if let Some(label) = app.boot_mode.label_key() {
+        elevation.push_str(&format!(" · {}", app.t(label)));
+    }
+    if simulate::is_enabled() {
+        elevation.push_str(&format!(" · {}", app.t(I18nKey::StatusSimulation)));
+    }
+
+    vec![
+        (I18nKey::AboutVersion, version::display()),
+        (I18nKey::AboutCommit, version::GIT_HASH.to_string()),
+        (
+            I18nKey::AboutBuilt,
+            format!("{} UTC", version::build_date()),
+        ),
+        (
+            I18nKey::AboutWindows,
+            winver::edition().unwrap_or_else(unknown),
+        ),
+        (
+            I18nKey::AboutWindowsBuild,
+            app.os_build
+                .map(|build| build.to_string())
+                .unwrap_or_else(unknown),
+        ),
+        (I18nKey::AboutElevation, elevation),
+        (
+            I18nKey::AboutConfig,
+            Config::get_config_file()
+                .map(|path| path.display().to_string())
+                .unwrap_or_else(|_| unknown()),
+        ),
+        (
+            I18nKey::AboutLogs,
+            logger::get_log_directory()
+                .map(|path| path.display().to_string())
+                .unwrap_or_else(|_| unknown()),
+        ),
+        (
+            I18nKey::AboutRepository,
+            env!("CARGO_PKG_REPOSITORY").to_string(),
+        ),
+        (I18nKey::AboutLicense, env!("CARGO_PKG_LICENSE").to_string()),
+    ]
+}
+
+/// Diagnóstico como texto, una fila `etiqueta: valor` por línea
+pub fn report(app: &App) -> String {
+    app.diagnostics
+        .iter()
+        .map(|(label, value)| format!("{}: {}\n", app.t(*label), value))
+        .collect()
+}
+
+/// Copia el diagnóstico al portapapeles
+pub fn copy_diagnostics(app: &mut App) {
+    match clipboard::copy_text(&report(app)) {
+        Ok(()) => log_info!(app, "📋 {}", app.t(I18nKey::AboutCopied)),
+        Err(e) => log_error!(
+            app,
+            "❌ {}",
+            app.t_args(I18nKey::AboutCopyError, &[("error", &e.to_string())])
+        ),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_report_lists_every_row() {
+        let mut app = App {
+            elevated: true,
+            os_build: Some(22631),
+            ..App::default()
+        };
+        app.diagnostics = collect(&app);
+        let report = report(&app);
+
+        assert_eq!(report.lines().count(), app.diagnostics.len());
+        assert!(report.contains(&format!(
+            "{}: {}",
+            app.t(I18nKey::AboutVersion),
+            version::display()
+        )));
+        assert!(report.contains(&format!("{}: 22631", app.t(I18nKey::AboutWindowsBuild))));
+        assert!(report.contains(app.t(I18nKey::StatusAdmin)));
+    }
+}
@@ -0,0 +1,140 @@
+//! Estado de activación de Windows
+//!
+//! Consulta la licencia del sistema operativo en la clase WMI
+//! `SoftwareLicensingProduct` (la misma que usa `slmgr /xpr`), filtrando el
+//! producto de Windows que tiene una clave instalada. `LicenseStatus` indica
+//! si está activado, en periodo de gracia o sin licencia, y
+//! `GracePeriodRemaining` los minutos que quedan de gracia.
+//!
+//! PowerShell tarda en arrancar, así que la vista de información hace la
+//! consulta en un thread aparte con [`spawn_query`].
+
+use crate::i18n::I18nKey;
+use crate::platform;
+use std::sync::mpsc::{self, Receiver};
+use std::thread;
+
+/// Identificador de aplicación de Windows en `SoftwareLicensingProduct`
+const WINDOWS_APPLICATION_ID: &str = "55c92734-d682-4d71-983e-d6ec3f16059f";
+
+/// Estado de activación de Windows
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Activation {
+    /// Activado
+    Licensed,
+    /// En periodo de gracia, con los días que quedan
+    Grace { days: u32 },
+    /// Sin activar tras el periodo de gracia (modo notificación)
+    Notification,
+    /// Sin licencia
+    Unlicensed,
+}
+
+impl Activation {
+    /// Estado a partir de `LicenseStatus` y `GracePeriodRemaining` (minutos)
+    fn from_status(status: u32, grace_minutes: u32) -> Option<Self> {
+        match status {
+            0 => Some(Activation::Unlicensed),
+            1 => Some(Activation::Licensed),
+            // Gracia inicial, fuera de tolerancia, no original y extendida
+            2 | 3 | 4 | 6 => Some(Activation::Grace {
+                days: grace_minutes.div_ceil(24 * 60),
+            }),
+            5 => Some(Activation::Notification),
+            _ => None,
+        }
+    }
+
+    /// Clave de traducción de la descripción
+    pub fn label_key(self) -> I18nKey {
+        match self {
+            Activation::Licensed => I18nKey::ActivationLicensed,
+            Activation::Grace { .. } => I18nKey::ActivationGrace,
+            Activation::Notification => I18nKey::ActivationNotification,
+            Activation::Unlicensed => I18nKey::ActivationUnlicensed,
+        }
+    }
+}
+
+/// Consulta el estado de activación de Windows
+///
+/// Retorna `None` si WMI no responde o no hay un producto de Windows con
+/// clave (por ejemplo, fuera de Windows).
+pub fn query() -> Option<Activation> {
+    let script = format!(
+        "Get-CimInstance -ClassName SoftwareLicensingProduct \
+         -Filter \"ApplicationID='{}' AND PartialProductKey IS NOT NULL\" | \
+         ForEach-Object {{ 'STATUS|' + $_.LicenseStatus + '|' + $_.GracePeriodRemaining }}",
+        WINDOWS_APPLICATION_ID
+    );
+    match platform::command("powershell")
+        .args(["-NoProfile", "-Command", &script])
+        .output()
+    {
+        Ok(output) if output.status.success() => {
+            parse_output(&String::from_utf8_lossy(&output.stdout))
+        }
+        Ok(_) => None,
+        Err(e) => {
+            tracing::debug!("No se pudo consultar la activación de Windows: {}", e);
+            None
+        }
+    }
+}
+
+/// Lanza la consulta de activación en un thread y devuelve el receptor del
+/// resultado
+pub fn spawn_query() -> Receiver<Option<Activation>> {
+    let (sender, receiver) = mpsc::channel();
+
+    thread::spawn(move || {
+        let _ = sender.send(query());
+    });
+
+    receiver
+}
+
+/// Parsea la salida del script de activación
+///
+/// Si hay varios productos con clave, se toma el mejor estado (el activado).
+fn parse_output(output: &str) -> Option<Activation> {
+    output
+        .lines()
+        .filter_map(|line| {
+            let mut fields = line.trim().strip_prefix("STATUS|")?.split('|');
+            let status = fields.next()?.trim().parse().ok()?;
+            let grace = fields
+                .next()
+                .and_then(|minutes| minutes.trim().parse().ok())
+                .unwrap_or(0);
+            Activation::from_status(status, grace)
+        })
+        .min_by_key(|activation| match activation {
+            Activation::Licensed => 0,
+            Activation::Grace { .. } => 1,
+            Activation::Notification => 2,
+            Activation::Unlicensed => 3,
+        })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_activation_status() {
+        assert_eq!(parse_output("STATUS|1|0\n"), Some(Activation::Licensed));
+        // 43200 minutos son 30 días de gracia
+        assert_eq!(
+            parse_output("STATUS|2|43200\n"),
+            Some(Activation::Grace { days: 30 })
+        );
+        assert_eq!(
+            parse_output("STATUS|5|0\r\nSTATUS|1|0\r\n"),
+            Some(Activation::Licensed)
+        );
+        assert_eq!(parse_output("STATUS|0|0"), Some(Activation::Unlicensed));
+        assert_eq!(parse_output(""), None);
+        assert_eq!(parse_output("STATUS|9|0"), None);
+    }
+}
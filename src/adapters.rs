@@ -0,0 +1,226 @@
+//! Adaptadores de red
+//!
+//! Enumera los adaptadores con `Get-NetAdapter` (WMI `MSFT_NetAdapter`) junto
+//! con su métrica e IPv4, y permite activar o desactivar cada adaptador y
+//! renovar su concesión DHCP por separado.
+
+use crate::i18n::I18nKey;
+use crate::platform;
+use crate::types::OperationState;
+use crate::utils::is_admin;
+use crate::{log_error, log_info, log_warn};
+
+/// Script de PowerShell que lista los adaptadores en formato
+/// `nombre|estado|descripción|métrica|ipv4`
+const LIST_ADAPTERS_SCRIPT: &str = "Get-NetAdapter | Sort-Object Name | ForEach-Object { \
+     $m = (Get-NetIPInterface -InterfaceIndex $_.ifIndex -AddressFamily IPv4 -ErrorAction SilentlyContinue).InterfaceMetric; \
+     $ip = (Get-NetIPAddress -InterfaceIndex $_.ifIndex -AddressFamily IPv4 -ErrorAction SilentlyContinue | Select-Object -First 1).IPAddress; \
+     '{0}|{1}|{2}|{3}|{4}' -f $_.Name, $_.Status, $_.InterfaceDescription, $m, $ip }";
+
+/// Estado de un adaptador
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum AdapterStatus {
+    Up,
+    Disconnected,
+    Disabled,
+    Unknown,
+}
+
+impl AdapterStatus {
+    fn parse(value: &str) -> Self {
+        match value {
+            "Up" => AdapterStatus::Up,
+            "Disconnected" => AdapterStatus::Disconnected,
+            "Disabled" => AdapterStatus::Disabled,
+            _ => AdapterStatus::Unknown,
+        }
+    }
+
+    /// Clave de traducción del estado
+    pub fn label_key(self) -> I18nKey {
+        match self {
+            AdapterStatus::Up => I18nKey::AdapterUp,
+            AdapterStatus::Disconnected => I18nKey::AdapterDisconnected,
+            AdapterStatus::Disabled => I18nKey::AdapterDisabled,
+            AdapterStatus::Unknown => I18nKey::AdapterUnknown,
+        }
+    }
+}
+
+/// Adaptador de red
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct NetworkAdapter {
+    /// Nombre de la conexión (p. ej. "Wi-Fi")
+    pub name: String,
+    pub status: AdapterStatus,
+    /// Descripción del hardware
+    pub description: String,
+    /// Métrica de la interfaz IPv4 (menor = preferida)
+    pub metric: Option<u32>,
+    /// Primera dirección IPv4
+    pub ipv4: Option<String>,
+}
+
+/// Lista los adaptadores de red
+pub fn execute_adapters(app: &mut crate::app::App) {
+    app.operation_state = OperationState::Running;
+    app.adapters.clear();
+    log_info!(app, "🔌 Enumerando adaptadores de red...");
+
+    let result = platform::command("powershell")
+        .args(["-NoProfile", "-Command", LIST_ADAPTERS_SCRIPT])
+        .output();
+
+    match result {
+        Ok(output) if output.status.success() => {
+            app.adapters = parse_adapter_list(&String::from_utf8_lossy(&output.stdout));
+        }
+        Ok(_) => {
+            log_error!(app, "❌ No se pudo obtener la lista de adaptadores");
+            app.operation_state = OperationState::Failed;
+            return;
+        }
+        Err(e) => {
+            log_error!(app, "❌ Error: {}", e);
+            app.operation_state = OperationState::Failed;
+            return;
+        }
+    }
+
+    let total = app.adapters.len();
+    log_info!(app, "✅ Adaptadores encontrados: {}", total);
+    log_info!(
+        app,
+        "💡 E activa/desactiva el adaptador seleccionado, N renueva su IP"
+    );
+    app.operation_state = OperationState::Completed;
+}
+
+/// Activa o desactiva el adaptador indicado
+pub fn execute_toggle_adapter(app: &mut crate::app::App, index: usize) {
+    let Some(adapter) = app.adapters.get(index).cloned() else {
+        return;
+    };
+    if !require_admin(app) {
+        return;
+    }
+
+    let (cmdlet, action) = if adapter.status == AdapterStatus::Disabled {
+        ("Enable-NetAdapter", "Activando")
+    } else {
+        ("Disable-NetAdapter", "Desactivando")
+    };
+    log_info!(app, "🔧 {} {}...", action, adapter.name);
+
+    let script = format!(
+        "{} -Name '{}' -Confirm:$false",
+        cmdlet,
+        adapter.name.replace('\'', "''")
+    );
+    match platform::command("powershell")
+        .args(["-NoProfile", "-Command", &script])
+        .output()
+    {
+        Ok(output) if output.status.success() => {
+            log_info!(app, "✅ {} actualizado", adapter.name);
+            execute_adapters(app);
+            app.selected_item = index.min(app.adapters.len().saturating_sub(1));
+        }
+        Ok(output) => log_error!(app, "❌ {}", String::from_utf8_lossy(&output.stderr).trim()),
+        Err(e) => log_error!(app, "❌ Error: {}", e),
+    }
+}
+
+/// Renueva la concesión DHCP del adaptador indicado
+pub fn execute_renew_adapter(app: &mut crate::app::App, index: usize) {
+    let Some(adapter) = app.adapters.get(index).cloned() else {
+        return;
+    };
+    if adapter.status != AdapterStatus::Up {
+        log_warn!(app, "⚠️  {} no está conectado", adapter.name);
+        return;
+    }
+    if !require_admin(app) {
+        return;
+    }
+
+    log_info!(app, "🔄 Renovando la IP de {}...", adapter.name);
+    match platform::command("ipconfig")
+        .args(["/renew", &adapter.name])
+        .output()
+    {
+        Ok(output) if output.status.success() => {
+            log_info!(app, "✅ IP renovada");
+            execute_adapters(app);
+            app.selected_item = index.min(app.adapters.len().saturating_sub(1));
+        }
+        Ok(_) => log_error!(
+            app,
+            "❌ No se pudo renovar la IP (¿el adaptador usa IP estática?)"
+        ),
+        Err(e) => log_error!(app, "❌ Error: {}", e),
+    }
+}
+
+/// Comprueba los permisos de administrador, registrando el error si faltan
+fn require_admin(app: &mut crate::app::App) -> bool {
+    log_info!(app, "");
+    if is_admin() {
+        return true;
+    }
+    log_error!(
+        app,
+        "⛔ ERROR: Esta operación requiere permisos de Administrador"
+    );
+    false
+}
+
+/// Parsea la salida del script de adaptadores
+fn parse_adapter_list(output: &str) -> Vec<NetworkAdapter> {
+    output
+        .lines()
+        .filter_map(|line| {
+            let mut fields = line.split('|').map(str::trim);
+            let name = fields.next().filter(|name| !name.is_empty())?;
+            let status = AdapterStatus::parse(fields.next()?);
+            let description = fields.next().unwrap_or_default();
+            let metric = fields.next().and_then(|m| m.parse().ok());
+            let ipv4 = fields
+                .next()
+                .filter(|ip| !ip.is_empty())
+                .map(str::to_string);
+            Some(NetworkAdapter {
+                name: name.to_string(),
+                status,
+                description: description.to_string(),
+                metric,
+                ipv4,
+            })
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_adapter_list() {
+        let output = "Ethernet|Disconnected|Intel(R) Ethernet I219-V|5|\r\n\
+                      Wi-Fi|Up|Intel(R) Wi-Fi 6 AX201|35|192.168.1.20\r\n\
+                      VPN|Disabled|TAP-Windows Adapter V9||\r\n";
+        let adapters = parse_adapter_list(output);
+        assert_eq!(adapters.len(), 3);
+        assert_eq!(adapters[0].status, AdapterStatus::Disconnected);
+        assert_eq!(adapters[0].metric, Some(5));
+        assert_eq!(adapters[0].ipv4, None);
+        assert_eq!(adapters[1].ipv4.as_deref(), Some("192.168.1.20"));
+        assert_eq!(adapters[2].status, AdapterStatus::Disabled);
+        assert_eq!(adapters[2].metric, None);
+    }
+
+    #[test]
+    fn test_parse_adapter_list_ignores_noise() {
+        assert!(parse_adapter_list("\r\nWARNING\r\n").is_empty());
+    }
+}
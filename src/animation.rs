@@ -179,6 +179,7 @@ mod tests {
     use super::*;
 
     #[test]
+    #[allow(clippy::bool_assert_comparison)]
     fn test_progress_bar() {
         let bar = progress_bar(50, 10);
         assert_eq!(bar.len() >= 10, true);
@@ -224,6 +225,7 @@ mod tests {
     }
 
     #[test]
+    #[allow(clippy::manual_range_contains)]
     fn test_pulse_opacity() {
         let pulse = Pulse::new(1000);
         let opacity = pulse.opacity();
@@ -1,20 +1,94 @@
-use crate::animation::{Spinner, progress_bar};
+use crate::activation::{self, Activation};
+use crate::adapters::{self, AdapterStatus, NetworkAdapter};
+use crate::animation::{Spinner, progress_bar, sparkline};
+use crate::app_logs::{LogFilter, LogTail};
+use crate::banner;
+use crate::battery::{self, BatteryHealth};
+use crate::benchmark::{self, Comparison, Snapshot};
+use crate::boot::{self, BootRecord};
 use crate::config::Config;
-use crate::i18n::{I18n, I18nKey};
-use crate::theme::{ColorPalette, Theme};
-use crate::types::{CleanStats, OperationState, View, WorkerHandle, WorkerMessage};
+use crate::config_watch::ConfigWatcher;
+use crate::defender::{self, DefenderStatus};
+use crate::downloads::{self, AgeGroup, DownloadEntry, DownloadsAction, FileKind};
+use crate::drivers::{self, DriverInfo};
+use crate::drives::{self, DriveChoice};
+use crate::env_path::{self, PathEntry};
+use crate::firewall::{self, FirewallProfile, ProfileState};
+use crate::footer::{self, FooterContext};
+use crate::help;
+use crate::hosts::{self, HostsEditor};
+use crate::i18n::{I18n, I18nKey, Language};
+use crate::jobs::{JobLauncher, JobMessage, JobQueue, JobStatus};
+use crate::journal::{self, JournalEntry, Rollback};
+use crate::log_search::LogSearch;
+use crate::logger::{LogEntry, LogLevel};
+use crate::menu::{MENU_ITEMS, MenuAction, MenuCategory};
+use crate::mouse::{self, ListHitbox, MouseTargets};
+use crate::network_info::{self, NetworkInfo};
+use crate::onboarding::{Onboarding, Step};
+use crate::optimization::NetworkStepState;
+use crate::palette::CommandPalette;
+use crate::ports::{self, Connection};
+use crate::profiles::{self, Profile};
+use crate::programs::{self, InstalledProgram, ProgramSort};
+use crate::proxy::{self, ProxySettings};
+use crate::quarantine::{self, QuarantinedItem};
+use crate::reboot::{self, PendingReboot};
+use crate::repair::{self, Verdict, VerdictLevel};
+use crate::responsive;
+use crate::retry::{self, FailedItem};
+use crate::safe_mode::BootMode;
+use crate::services;
+use crate::settings::SettingsItem;
+use crate::simulate;
+use crate::startup::{self, Impact, StartupEntry};
+use crate::symbols::{SymbolMode, to_ascii};
+use crate::tasks::{self, ScheduledTask, TaskState};
+use crate::theme::{ColorMode, ColorPalette, Theme};
+use crate::timer::OperationTimer;
+use crate::top_processes::{ProcessMonitor, ProcessSort};
+use crate::types::{
+    CleanStats, LocationStats, OperationState, OperationStats, View, WorkerMessage,
+};
+use crate::update::{self, Release};
+use crate::user_profiles::{self, UserProfile};
 use crate::utils::format_uptime;
-use crate::{cleanup, optimization};
-use crossterm::event::{self, Event, KeyCode, KeyEventKind};
+use crate::wifi::{self, WifiProfile};
+use crate::windows_update::{self, StatusResult};
+use crate::winver;
+use crate::{
+    about, cleanup, clipboard, history, hooks, ip_backup, logger, memory, notify, operation,
+    optimization, platform, utils, version,
+};
+use crossterm::event::{
+    self, Event, KeyCode, KeyEvent, KeyEventKind, KeyModifiers, MouseButton, MouseEvent,
+    MouseEventKind,
+};
 use ratatui::{
     DefaultTerminal, Frame,
-    layout::{Alignment, Constraint, Direction, Layout, Rect},
-    style::{Modifier, Style, Stylize},
+    layout::{Alignment, Constraint, Direction, Layout, Margin, Rect},
+    style::{Color, Modifier, Style, Stylize},
     symbols,
     text::{Line, Span, Text},
-    widgets::{Block, Borders, List, ListItem, Paragraph, Wrap},
+    widgets::{
+        Block, Borders, Cell, Clear, List, ListItem, ListState, Padding, Paragraph, Row, Scrollbar,
+        ScrollbarOrientation, ScrollbarState, Table, Wrap,
+    },
 };
+use std::sync::mpsc::Receiver;
+use std::time::{Duration, Instant};
 use sysinfo::{Disks, System};
+use unicode_width::UnicodeWidthStr;
+
+/// Tarjeta de estadística: icono, valor, etiqueta y color
+type StatCard = (&'static str, String, I18nKey, Color);
+
+/// Líneas de `CBS.log` visibles con la sección desplegada
+const CBS_VISIBLE_LINES: usize = 12;
+
+/// Atajos de las vistas que deshabilitan servicios
+pub(crate) const SERVICES_FOOTER_KEYS: &[(&str, I18nKey)] =
+    &[("R", I18nKey::FooterRestoreServices)];
 
 /// Estructura principal de la aplicación
 pub struct App {
@@ -23,51 +97,323 @@ pub struct App {
     /// Índice del item seleccionado en el menú
     pub selected_menu_item: usize,
     /// Logs de operaciones
-    pub operation_logs: Vec<String>,
+    pub operation_logs: Vec<LogEntry>,
     /// Estado de la operación actual
     pub operation_state: OperationState,
-    /// Estadísticas de la última limpieza
-    pub clean_stats: CleanStats,
+    /// Estadísticas de la última operación
+    pub stats: Option<OperationStats>,
     /// Flag para salir de la aplicación
     pub should_quit: bool,
     /// Scroll vertical para logs
     pub scroll_offset: u16,
+    /// El panel de logs sigue las líneas nuevas (se pausa al desplazarse)
+    pub log_follow: bool,
+    /// Líneas visibles en el último dibujado del panel de logs (para paginar)
+    pub log_page_height: u16,
+    /// Zonas del último fotograma que responden al ratón
+    pub mouse_targets: MouseTargets,
+    /// Se muestra la ventana de ayuda de atajos
+    pub show_help: bool,
+    /// Operaciones que quedaron a medias en la ejecución anterior
+    pub interrupted: Vec<JournalEntry>,
+    /// Atajos del pie de la vista actual, para la ayuda
+    pub footer_keys: Vec<(String, I18nKey)>,
     /// Tema actual de la aplicación
     pub theme: Theme,
+    /// Colores que admite el terminal (ya resuelto, nunca `Auto`)
+    pub color_mode: ColorMode,
+    /// Símbolos que admite el terminal (ya resuelto, nunca `Auto`)
+    pub symbol_mode: SymbolMode,
+    /// Si el último fotograma usó el diseño compacto para terminales pequeños
+    pub compact: bool,
+    /// La aplicación se ejecuta con permisos de administrador
+    pub elevated: bool,
+    /// Entradas del menú que requieren administrador sin tener permisos
+    /// elevados, en el orden de `MENU_ITEMS`
+    pub menu_locked: Vec<bool>,
+    /// Modo en que arrancó Windows (normal o modo seguro)
+    pub boot_mode: BootMode,
+    /// Compilación de Windows, si se conoce
+    pub os_build: Option<u32>,
+    /// Zona de la vista en el último fotograma (sin la barra de estado)
+    pub content_area: Rect,
     /// Sistema de internacionalización
     pub i18n: I18n,
     /// Configuración de la aplicación
     pub config: Config,
     /// Spinner para animaciones
     pub spinner: Spinner,
-    /// Handle del worker thread actual (si hay alguno ejecutándose)
-    pub worker_handle: Option<WorkerHandle>,
+    /// Cola de operaciones en segundo plano
+    pub jobs: JobQueue,
+    /// Progreso del comando en curso de la tarea de la vista, si lo informa
+    pub operation_progress: Option<f32>,
+    /// Reloj de la operación de la vista actual (tiempo transcurrido y
+    /// estimación del restante)
+    pub operation_timer: Option<OperationTimer>,
+    /// Sección de `CBS.log` desplegada en la vista de reparación
+    pub cbs_expanded: bool,
+    /// Alguna operación terminada necesita reiniciar el equipo
+    pub needs_reboot: bool,
+    /// Momento en que vence el reinicio programado
+    pub reboot_deadline: Option<Instant>,
+    /// Tiempo que llevaba encendido el equipo al arrancar y cuándo se midió
+    pub launch_uptime: Option<(Duration, Instant)>,
+    /// Motivos del reinicio pendiente de Windows detectados al arrancar
+    pub pending_reboot: Vec<PendingReboot>,
+    /// La vista espera Enter para ejecutarse pese al reinicio pendiente
+    pub pending_reboot_confirm: bool,
+    /// El comando en curso de la tarea de la vista no produce salida
+    pub operation_stalled: bool,
+    /// Elementos de la operación de la vista que fallaron y se pueden reintentar
+    pub failed_items: Vec<FailedItem>,
+    /// Índice del elemento seleccionado en vistas con listas
+    pub selected_item: usize,
+    /// Perfil de optimización aplicado actualmente
+    pub active_profile: Option<Profile>,
+    /// Salud de las baterías (vacío en equipos de escritorio)
+    pub battery_health: Vec<BatteryHealth>,
+    /// Informe de batería en curso (vista de información)
+    pub battery_receiver: Option<Receiver<Vec<BatteryHealth>>>,
+    /// Estado de activación de Windows (vista de información)
+    pub windows_activation: Option<Activation>,
+    /// Consulta de activación en curso (vista de información)
+    pub activation_receiver: Option<Receiver<Option<Activation>>>,
+    /// Configuración de red de los adaptadores conectados (vista de información)
+    pub network_info: Vec<NetworkInfo>,
+    /// Consulta de red en curso (vista de información)
+    pub network_receiver: Option<Receiver<Vec<NetworkInfo>>>,
+    /// Procesos que más consumen, refrescados mientras la vista está abierta
+    pub process_monitor: ProcessMonitor,
+    /// Drivers instalados (vista de drivers)
+    pub drivers: Vec<DriverInfo>,
+    /// Tareas programadas, agrupadas por fabricante
+    pub scheduled_tasks: Vec<ScheduledTask>,
+    /// Consulta de tareas programadas en curso
+    pub tasks_receiver: Option<Receiver<tasks::ListResult>>,
+    /// Entradas de inicio de la vista de programas de inicio
+    pub startup_entries: Vec<StartupEntry>,
+    /// Programas instalados (vista de programas)
+    pub programs: Vec<InstalledProgram>,
+    /// Criterio de ordenación de la lista de programas
+    pub program_sort: ProgramSort,
+    /// Contenido analizado de la carpeta Descargas
+    pub downloads: Vec<DownloadEntry>,
+    /// Umbral de antigüedad (días) del asistente de Descargas
+    pub downloads_threshold_days: u64,
+    /// Acción en bloque de Descargas pendiente de confirmar
+    pub downloads_confirm: Option<DownloadsAction>,
+    /// Último estado consultado de Microsoft Defender
+    pub defender_status: Option<DefenderStatus>,
+    /// Estado de los perfiles del firewall
+    pub firewall_profiles: Vec<ProfileState>,
+    /// Adaptadores de red enumerados
+    pub adapters: Vec<NetworkAdapter>,
+    /// Pasos de la limpieza de red y su último resultado
+    pub network_steps: Vec<NetworkStepState>,
+    /// Unidades de la limpieza de temporales (vacía: solo las del sistema)
+    pub clean_drives: Vec<DriveChoice>,
+    /// Perfiles de otros usuarios que se pueden limpiar (solo como
+    /// administrador)
+    pub clean_profiles: Vec<UserProfile>,
+    /// Arranques recientes con su duración
+    pub boot_records: Vec<BootRecord>,
+    /// Comparativa antes/después de la última limpieza u optimización
+    pub benchmark: Option<Comparison>,
+    /// Conexiones TCP/UDP activas
+    pub connections: Vec<Connection>,
+    /// Filtro de texto de la vista de conexiones
+    pub ports_filter: String,
+    /// Se está escribiendo el filtro de conexiones
+    pub ports_filter_editing: bool,
+    /// Búsqueda en el panel de logs de la vista actual
+    pub log_search: LogSearch,
+    /// Paleta de comandos (Ctrl+P), si está abierta
+    pub palette: Option<CommandPalette>,
+    /// Lector del archivo de log de la aplicación
+    pub log_tail: LogTail,
+    /// Filtro del visor de log
+    pub log_filter: LogFilter,
+    /// Editando el texto del filtro del visor de log
+    pub log_filter_editing: bool,
+    /// PID pendiente de confirmar para terminarlo
+    pub ports_kill_confirm: Option<u32>,
+    /// Configuración de proxy leída
+    pub proxy_settings: Option<ProxySettings>,
+    /// Perfiles Wi-Fi guardados
+    pub wifi_profiles: Vec<WifiProfile>,
+    /// Eliminación de perfiles Wi-Fi pendiente de confirmar
+    pub wifi_delete_confirm: bool,
+    /// Entradas del PATH analizadas
+    pub path_entries: Vec<PathEntry>,
+    /// Limpieza del PATH pendiente de confirmar
+    pub path_clean_confirm: bool,
+    /// Editor del archivo hosts
+    pub hosts_editor: HostsEditor,
+    /// Elementos en cuarentena (vista de cuarentena)
+    pub quarantine_items: Vec<QuarantinedItem>,
+    /// Último estado consultado de Windows Update
+    pub update_status: Option<StatusResult>,
+    /// Receptor de la consulta de Windows Update en curso
+    pub update_status_receiver: Option<Receiver<StatusResult>>,
+    /// Versión nueva de win_opt, si la comprobación la encontró
+    pub new_release: Option<Release>,
+    /// Receptor de la comprobación de versión en curso
+    pub release_check_receiver: Option<Receiver<update::CheckResult>>,
+    /// Receptor de la descarga de la versión nueva en curso
+    pub release_download_receiver: Option<Receiver<update::DownloadResult>>,
+    /// Resultado de la descarga de la versión nueva
+    pub release_download: Option<update::DownloadResult>,
+    /// Filas del diagnóstico de la vista "Acerca de"
+    pub diagnostics: Vec<(I18nKey, String)>,
+    /// Avisos de validación de `config.toml`, mostrados en un banner
+    pub config_warnings: Vec<String>,
+    /// `config.toml` no se pudo interpretar: no se sobrescribe al guardar
+    pub config_invalid: bool,
+    /// Vigilante de `config.toml` para recargarlo en caliente
+    pub config_watcher: Option<ConfigWatcher>,
+    /// No existía `config.toml` al iniciar: se abre el asistente
+    pub first_run: bool,
+    /// Opciones del asistente del primer inicio
+    pub onboarding: Onboarding,
 }
 
 impl Default for App {
     fn default() -> Self {
-        // Cargar configuración
-        let config = Config::load();
+        // Cargar configuración; los avisos se muestran en un banner y, si el
+        // archivo no es válido, se usan los valores por defecto sin
+        // sobrescribirlo
+        let (config, config_warnings, config_invalid, first_run) = match Config::load_from_file() {
+            Ok((config, warnings)) => (config, warnings, false, false),
+            Err(e) if e.kind() == std::io::ErrorKind::NotFound => {
+                // Primer inicio: se parte del idioma del sistema si está soportado
+                let mut config = Config::default();
+                if let Some(language) = Language::detect() {
+                    tracing::info!("Idioma del sistema detectado: {}", language.code());
+                    config.set_language(language);
+                }
+                (config, Vec::new(), false, true)
+            }
+            Err(e) => (
+                Config::default(),
+                vec![format!("{} (se usan los valores por defecto)", e)],
+                true,
+                false,
+            ),
+        };
+        for warning in &config_warnings {
+            tracing::warn!("config.toml: {}", warning);
+        }
 
         // Inicializar i18n con el idioma de la configuración
         let i18n = I18n::new(config.language());
 
         // Obtener tema de la configuración
         let theme = config.theme();
+        let color_mode = config.appearance.color_mode.resolve();
+        tracing::info!("Modo de color del terminal: {:?}", color_mode);
+        let symbol_mode = config.appearance.symbols.resolve();
+        tracing::info!("Símbolos de la interfaz: {:?}", symbol_mode);
+
+        let elevated = utils::is_admin();
+        let menu_locked = MENU_ITEMS
+            .iter()
+            .map(|item| !elevated && item.requires_admin())
+            .collect();
+
+        let downloads_threshold_days = config.downloads.threshold_days;
+        let onboarding = Onboarding::from_config(&config);
 
         Self {
             current_view: View::MainMenu,
             selected_menu_item: 0,
             operation_logs: Vec::new(),
             operation_state: OperationState::Idle,
-            clean_stats: CleanStats::default(),
+            stats: None,
             should_quit: false,
             scroll_offset: 0,
+            log_follow: true,
+            log_page_height: 0,
+            mouse_targets: MouseTargets::default(),
+            show_help: false,
+            interrupted: Vec::new(),
+            footer_keys: Vec::new(),
             theme,
+            color_mode,
+            symbol_mode,
+            compact: false,
+            elevated,
+            menu_locked,
+            boot_mode: BootMode::detect(),
+            os_build: winver::current_build(),
+            content_area: Rect::default(),
             i18n,
             config,
             spinner: Spinner::new(),
-            worker_handle: None,
+            jobs: JobQueue::default(),
+            operation_progress: None,
+            operation_timer: None,
+            cbs_expanded: false,
+            needs_reboot: false,
+            reboot_deadline: None,
+            launch_uptime: None,
+            pending_reboot: Vec::new(),
+            pending_reboot_confirm: false,
+            operation_stalled: false,
+            failed_items: Vec::new(),
+            selected_item: 0,
+            active_profile: profiles::load_backup().map(|backup| backup.profile),
+            battery_health: Vec::new(),
+            battery_receiver: None,
+            windows_activation: None,
+            activation_receiver: None,
+            network_info: Vec::new(),
+            network_receiver: None,
+            process_monitor: ProcessMonitor::default(),
+            drivers: Vec::new(),
+            scheduled_tasks: Vec::new(),
+            tasks_receiver: None,
+            startup_entries: Vec::new(),
+            programs: Vec::new(),
+            program_sort: ProgramSort::default(),
+            downloads: Vec::new(),
+            downloads_threshold_days,
+            downloads_confirm: None,
+            defender_status: None,
+            firewall_profiles: Vec::new(),
+            adapters: Vec::new(),
+            network_steps: optimization::default_network_steps(),
+            clean_drives: Vec::new(),
+            clean_profiles: Vec::new(),
+            boot_records: Vec::new(),
+            benchmark: None,
+            connections: Vec::new(),
+            ports_filter: String::new(),
+            ports_filter_editing: false,
+            log_search: LogSearch::default(),
+            palette: None,
+            log_tail: LogTail::default(),
+            log_filter: LogFilter::default(),
+            log_filter_editing: false,
+            ports_kill_confirm: None,
+            proxy_settings: None,
+            wifi_profiles: Vec::new(),
+            wifi_delete_confirm: false,
+            path_entries: Vec::new(),
+            path_clean_confirm: false,
+            hosts_editor: HostsEditor::default(),
+            quarantine_items: Vec::new(),
+            update_status: None,
+            update_status_receiver: None,
+            new_release: None,
+            release_check_receiver: None,
+            release_download_receiver: None,
+            release_download: None,
+            diagnostics: Vec::new(),
+            config_warnings,
+            config_invalid,
+            config_watcher: None,
+            first_run,
+            onboarding,
         }
     }
 }
@@ -75,19 +421,19 @@ impl Default for App {
 impl App {
     /// Obtiene la paleta de colores según el tema actual
     pub fn get_colors(&self) -> ColorPalette {
-        ColorPalette::from_theme(self.theme)
+        ColorPalette::from_theme(self.theme, &self.config.theme.custom).with_mode(self.color_mode)
     }
 
-    /// Alterna entre tema claro y oscuro
+    /// Pasa al tema siguiente (claro, oscuro y, si está definido, el
+    /// personalizado)
     pub fn toggle_theme(&mut self) {
-        self.theme = match self.theme {
-            Theme::Light => Theme::Dark,
-            Theme::Dark => Theme::Light,
-        };
+        self.theme = self.theme.next(!self.config.theme.custom.is_empty());
         // Actualizar configuración
         self.config.set_theme(self.theme);
         // Guardar si está configurado para recordar
-        let _ = self.config.save_if_remember();
+        if !self.config_invalid {
+            let _ = self.config.save_if_remember();
+        }
     }
 
     /// Alterna entre idiomas disponibles
@@ -96,71 +442,384 @@ impl App {
         // Actualizar configuración
         self.config.set_language(self.i18n.current_language());
         // Guardar si está configurado para recordar
-        let _ = self.config.save_if_remember();
+        if !self.config_invalid {
+            let _ = self.config.save_if_remember();
+        }
     }
 
     /// Obtiene una traducción
-    pub fn t(&self, key: I18nKey) -> &str {
+    pub fn t(&self, key: I18nKey) -> &'static str {
         self.i18n.t(key)
     }
 
+    /// Obtiene una traducción rellenando sus marcadores `{nombre}`
+    pub fn t_args(&self, key: I18nKey, args: &[(&str, &str)]) -> String {
+        self.i18n.t_args(key, args)
+    }
+
     /// Ejecuta el loop principal de la aplicación
     pub fn run(mut self, mut terminal: DefaultTerminal) -> std::io::Result<()> {
+        self.config_watcher = ConfigWatcher::start()
+            .inspect_err(|e| tracing::warn!("No se pudo vigilar config.toml: {}", e))
+            .ok();
+        if self.boot_mode.is_safe() {
+            tracing::warn!("Windows se ejecuta en modo seguro: {:?}", self.boot_mode);
+        }
+        self.pending_reboot = reboot::detect_pending();
+        if !self.pending_reboot.is_empty() {
+            tracing::warn!("Reinicio pendiente de Windows: {:?}", self.pending_reboot);
+        }
+        self.launch_uptime = Some((Duration::from_secs(System::uptime()), Instant::now()));
+        self.interrupted = journal::interrupted();
+        if !self.interrupted.is_empty() {
+            tracing::warn!("Operaciones interrumpidas: {:?}", self.interrupted);
+        }
+        // Las simulaciones no consultan Internet
+        if self.config.update.check_on_startup && simulate::sandbox().is_none() {
+            self.release_check_receiver = Some(update::spawn_check());
+        }
+        if self.first_run {
+            self.open_view(View::Onboarding);
+        }
+
         while !self.should_quit {
             // Procesar mensajes del worker si hay uno activo
             self.process_worker_messages();
+            self.poll_update_status();
+            self.poll_info_queries();
+            self.poll_tasks_list();
+            self.poll_release();
+            self.poll_config_reload();
+            if self.current_view == View::AppLogs {
+                self.log_tail.poll();
+            }
+            if self.current_view == View::Info {
+                self.process_monitor.poll();
+            }
 
             terminal.draw(|frame| self.draw(frame))?;
             self.handle_events()?;
         }
 
-        // Guardar configuración al salir
-        if let Err(e) = self.config.save() {
+        // Guardar configuración al salir (sin pisar un archivo no válido)
+        if !self.config_invalid
+            && let Err(e) = self.config.save()
+        {
             tracing::warn!("No se pudo guardar la configuración al salir: {}", e);
         }
 
         Ok(())
     }
 
-    /// Procesa mensajes del worker thread
+    /// Vuelve a leer `config.toml` si ha cambiado
+    fn poll_config_reload(&mut self) {
+        if self
+            .config_watcher
+            .as_ref()
+            .is_some_and(ConfigWatcher::changed)
+        {
+            self.reload_config(Config::load_from_file());
+        }
+    }
+
+    /// Aplica una configuración recargada
     ///
-    /// Este método lee todos los mensajes disponibles del canal del worker
-    /// sin bloquear, actualizando el estado de la aplicación según corresponda.
-    fn process_worker_messages(&mut self) {
-        let mut should_clear_worker = false;
-
-        if let Some(ref handle) = self.worker_handle {
-            // Procesar todos los mensajes disponibles (non-blocking)
-            while let Ok(message) = handle.receiver.try_recv() {
-                match message {
-                    WorkerMessage::Log(log) => {
-                        self.operation_logs.push(log);
-                    }
-                    WorkerMessage::StateChange(state) => {
-                        self.operation_state = state;
-                    }
-                    WorkerMessage::StatsUpdate(stats) => {
-                        self.clean_stats = stats;
-                    }
-                    WorkerMessage::Error(error) => {
-                        self.operation_logs.push(format!("❌ ERROR: {}", error));
+    /// Si el archivo no es válido se mantiene la configuración en uso y el
+    /// error se muestra en el banner de avisos.
+    pub fn reload_config(&mut self, loaded: std::io::Result<(Config, Vec<String>)>) {
+        let (config, warnings) = match loaded {
+            Ok(loaded) => loaded,
+            Err(e) => {
+                tracing::warn!("No se pudo recargar config.toml: {}", e);
+                self.config_warnings =
+                    vec![format!("{} (se mantiene la configuración anterior)", e)];
+                self.config_invalid = true;
+                return;
+            }
+        };
+        for warning in &warnings {
+            tracing::warn!("config.toml: {}", warning);
+        }
+        self.config_warnings = warnings;
+        self.config_invalid = false;
+
+        // Los guardados de la propia aplicación también avisan del cambio
+        if toml::to_string(&config).ok() == toml::to_string(&self.config).ok() {
+            return;
+        }
+        if config.logging.level != self.config.logging.level
+            && let Err(e) = logger::set_level(&config.logging.level)
+        {
+            tracing::warn!("No se pudo aplicar el nivel de log: {}", e);
+        }
+        logger::set_file_logging(config.logging.file_logging);
+        self.theme = config.theme();
+        self.color_mode = config.appearance.color_mode.resolve();
+        self.symbol_mode = config.appearance.symbols.resolve();
+        self.i18n.set_language(config.language());
+        self.downloads_threshold_days = config.downloads.threshold_days;
+        self.config = config;
+        tracing::info!("Configuración recargada desde config.toml");
+    }
+
+    /// Encola una operación en segundo plano lanzada desde la vista actual
+    ///
+    /// Las tareas exclusivas esperan a que termine la exclusiva en curso.
+    pub fn start_job(&mut self, view: View, exclusive: bool, launcher: JobLauncher) {
+        let id = self.jobs.enqueue(view, exclusive, launcher);
+        if self
+            .jobs
+            .get(id)
+            .is_some_and(|job| job.status == JobStatus::Pending)
+        {
+            self.operation_logs.push(LogEntry::new(
+                LogLevel::Info,
+                "⏳ En cola: empezará cuando termine la otra operación de DISM",
+            ));
+        }
+    }
+
+    /// Ejecuta una operación con sus hooks `pre_` y `post_`
+    ///
+    /// Si la operación lanza una tarea en segundo plano, el hook `post_` se
+    /// ejecuta cuando termina la tarea.
+    pub fn run_operation(&mut self, view: View, execute: fn(&mut App)) {
+        let span =
+            operation::name_for_view(view).map_or_else(tracing::Span::none, logger::operation_span);
+        let _span = span.enter();
+        self.operation_timer = Some(OperationTimer::start());
+        self.stats = None;
+        if !hooks::run_pre(self, view) {
+            self.operation_logs.push(LogEntry::new(
+                LogLevel::Error,
+                "⛔ Operación cancelada: falló el hook previo",
+            ));
+            self.operation_state = OperationState::Failed;
+            return;
+        }
+        self.failed_items.clear();
+        execute(self);
+        if self.jobs.active_for(view).is_none() {
+            journal::finish(view);
+            retry::log_hint(self);
+            let success = self.operation_state == OperationState::Completed;
+            hooks::run_post(self, view, success, true);
+        }
+    }
+
+    /// Procesa los mensajes de las tareas en segundo plano
+    ///
+    /// Este método lee todos los mensajes disponibles sin bloquear. Los de
+    /// la tarea de la vista actual actualizan el estado de la aplicación.
+    pub(crate) fn process_worker_messages(&mut self) {
+        for JobMessage {
+            job_id,
+            view,
+            message,
+        } in self.jobs.poll()
+        {
+            if let WorkerMessage::Completed = message {
+                journal::finish(view);
+                let show = view == self.current_view;
+                if show {
+                    self.operation_stalled = false;
+                    retry::log_hint(self);
+                }
+                let Some((success, span)) = self
+                    .jobs
+                    .get(job_id)
+                    .map(|job| (job.status == JobStatus::Completed, job.span.clone()))
+                else {
+                    continue;
+                };
+                span.in_scope(|| hooks::run_post(self, view, success, show));
+                self.notify_job_finished(job_id);
+                continue;
+            }
+            // El aviso de reinicio se muestra aunque la vista ya no esté abierta
+            if let WorkerMessage::StatsUpdate(OperationStats::Repair(stats)) = &message
+                && stats.sfc_ok.is_some()
+                && repair::verdict(stats).is_some_and(|verdict| verdict.needs_reboot())
+            {
+                self.needs_reboot = true;
+            }
+            if view != self.current_view {
+                continue;
+            }
+            match message {
+                WorkerMessage::Log(log) => {
+                    self.operation_logs.push(LogEntry::new(LogLevel::Info, log));
+                }
+                WorkerMessage::StateChange(state) => {
+                    self.operation_state = state;
+                    self.operation_progress = None;
+                    if let Some(timer) = self.operation_timer.as_mut() {
+                        timer.reset_progress();
                     }
-                    WorkerMessage::Completed => {
-                        // Marcar para limpiar handle después del loop
-                        should_clear_worker = true;
+                }
+                WorkerMessage::StatsUpdate(stats) => {
+                    self.stats = Some(stats);
+                }
+                WorkerMessage::Progress(percent) => {
+                    self.operation_progress = Some(percent);
+                    if let Some(timer) = self.operation_timer.as_mut() {
+                        timer.record_progress(percent);
                     }
                 }
+                WorkerMessage::Stalled(stalled) => {
+                    self.operation_stalled = stalled;
+                }
+                WorkerMessage::ItemFailed(item) => self.failed_items.push(item),
+                WorkerMessage::Error(error) => {
+                    self.operation_logs.push(LogEntry::new(
+                        LogLevel::Error,
+                        format!("❌ ERROR: {}", error),
+                    ));
+                }
+                WorkerMessage::Completed => {}
+            }
+        }
+    }
+
+    /// Lanza una nueva consulta del estado de Windows Update
+    pub fn refresh_update_status(&mut self) {
+        self.update_status = None;
+        self.update_status_receiver = Some(windows_update::spawn_status_query());
+    }
+
+    /// Recoge el resultado de la consulta de Windows Update si ya terminó
+    fn poll_update_status(&mut self) {
+        if let Some(receiver) = &self.update_status_receiver
+            && let Ok(result) = receiver.try_recv()
+        {
+            if let Err(e) = &result {
+                tracing::warn!("No se pudo consultar Windows Update: {}", e);
+            }
+            self.update_status = Some(result);
+            self.update_status_receiver = None;
+        }
+    }
+
+    /// Recoge las consultas de la vista de información que ya terminaron
+    fn poll_info_queries(&mut self) {
+        if let Some(receiver) = &self.battery_receiver
+            && let Ok(batteries) = receiver.try_recv()
+        {
+            self.battery_health = batteries;
+            self.battery_receiver = None;
+        }
+        if let Some(receiver) = &self.activation_receiver
+            && let Ok(activation) = receiver.try_recv()
+        {
+            self.windows_activation = activation;
+            self.activation_receiver = None;
+        }
+        if let Some(receiver) = &self.network_receiver
+            && let Ok(adapters) = receiver.try_recv()
+        {
+            self.network_info = adapters;
+            self.network_receiver = None;
+        }
+    }
+
+    /// Recoge la lista de tareas programadas si ya terminó
+    ///
+    /// Si se ha salido de la vista, el resultado se descarta: los logs ya
+    /// son los de otra vista y la lista se vuelve a pedir al abrirla.
+    fn poll_tasks_list(&mut self) {
+        if self.current_view != View::Tasks {
+            self.tasks_receiver = None;
+            return;
+        }
+        if let Some(receiver) = &self.tasks_receiver
+            && let Ok(result) = receiver.try_recv()
+        {
+            self.tasks_receiver = None;
+            tasks::finish_tasks_list(self, result);
+        }
+    }
+
+    /// Recoge la comprobación o la descarga de la versión nueva si terminaron
+    fn poll_release(&mut self) {
+        if let Some(receiver) = &self.release_check_receiver
+            && let Ok(result) = receiver.try_recv()
+        {
+            match result {
+                Ok(Some(release)) => {
+                    tracing::info!("Nueva versión de win_opt disponible: {}", release.version);
+                    self.new_release = Some(release);
+                }
+                Ok(None) => tracing::debug!("win_opt {} está al día", version::VERSION),
+                Err(e) => tracing::warn!("No se pudo comprobar si hay versión nueva: {}", e),
+            }
+            self.release_check_receiver = None;
+        }
+        if let Some(receiver) = &self.release_download_receiver
+            && let Ok(result) = receiver.try_recv()
+        {
+            match &result {
+                Ok(path) => tracing::info!("Versión nueva descargada en {}", path.display()),
+                Err(e) => tracing::warn!("No se pudo descargar la versión nueva: {}", e),
             }
+            self.release_download = Some(result);
+            self.release_download_receiver = None;
+        }
+    }
+
+    /// Indica si la versión nueva se puede descargar con `U`
+    ///
+    /// Sin archivo de sumas la descarga no se podría comprobar, así que no se
+    /// ofrece.
+    fn release_downloadable(&self) -> bool {
+        self.new_release
+            .as_ref()
+            .is_some_and(|release| release.download_url.is_some() && release.checksum_url.is_some())
+            && self.release_download_receiver.is_none()
+            && !matches!(self.release_download, Some(Ok(_)))
+    }
+
+    /// Descarga el ejecutable de la versión nueva en segundo plano
+    fn download_release(&mut self) {
+        if !self.release_downloadable() {
+            return;
+        }
+        if let Some(release) = self.new_release.clone() {
+            self.release_download = None;
+            self.release_download_receiver = Some(update::spawn_download(release));
         }
+    }
+
+    /// Muestra una notificación del sistema al terminar una tarea
+    ///
+    /// Las tareas ejecutan operaciones largas (DISM, SFC), por lo que es
+    /// probable que el usuario haya cambiado a otra ventana mientras tanto.
+    fn notify_job_finished(&mut self, job_id: usize) {
+        let Some((view, status)) = self.jobs.get(job_id).map(|job| (job.view, job.status)) else {
+            return;
+        };
+        if !self.config.notifications.enabled {
+            return;
+        }
+
+        let result_key = if status == JobStatus::Completed {
+            I18nKey::OpCompleted
+        } else {
+            I18nKey::OpError
+        };
 
-        // Limpiar worker handle si recibimos el mensaje de Completed
-        if should_clear_worker {
-            self.worker_handle = None;
+        if let Err(e) = notify::show_toast(self.t(view.title_key()), self.t(result_key)) {
+            tracing::warn!("No se pudo mostrar la notificación: {}", e);
         }
     }
 
     /// Dibuja la interfaz según la vista actual
     fn draw(&mut self, frame: &mut Frame) {
+        self.mouse_targets = MouseTargets::default();
+        self.footer_keys.clear();
+        self.compact = responsive::is_compact(frame.area());
+        let [content_area, status_area] =
+            Layout::vertical([Constraint::Min(0), Constraint::Length(1)]).areas(frame.area());
+        self.content_area = content_area;
         match self.current_view {
             View::MainMenu => self.draw_main_menu(frame),
             View::Clean => self.draw_clean_view(frame),
@@ -175,88 +834,253 @@ impl App {
             View::RecycleBin => self.draw_recycle_bin_view(frame),
             View::StartupOptimizer => self.draw_startup_optimizer_view(frame),
             View::VisualEffects => self.draw_visual_effects_view(frame),
+            View::Profiles => self.draw_profiles_view(frame),
+            View::Drivers => self.draw_drivers_view(frame),
+            View::Tasks => self.draw_tasks_view(frame),
+            View::Memory => self.draw_memory_view(frame),
+            View::Programs => self.draw_programs_view(frame),
+            View::Downloads => self.draw_downloads_view(frame),
+            View::Quarantine => self.draw_quarantine_view(frame),
+            View::Defender => self.draw_defender_view(frame),
+            View::Firewall => self.draw_firewall_view(frame),
+            View::Hosts => self.draw_hosts_view(frame),
+            View::PathCleaner => self.draw_path_cleaner_view(frame),
+            View::Adapters => self.draw_adapters_view(frame),
+            View::Wifi => self.draw_wifi_view(frame),
+            View::Proxy => self.draw_proxy_view(frame),
+            View::Ports => self.draw_ports_view(frame),
+            View::BootTime => self.draw_boot_time_view(frame),
+            View::Jobs => self.draw_jobs_view(frame),
+            View::AppLogs => self.draw_app_logs_view(frame),
+            View::Onboarding => self.draw_onboarding_view(frame),
+            View::Settings => self.draw_settings_view(frame),
+            View::About => self.draw_about_view(frame),
+            View::FontCache => {
+                let title = self.t(I18nKey::FontCacheTitle).to_string();
+                self.draw_generic_operation_view(frame, "🔤", &title);
+            }
+            View::SearchIndex => {
+                let title = self.t(I18nKey::SearchIndexTitle).to_string();
+                self.draw_generic_operation_view(frame, "🔎", &title);
+            }
+        }
+
+        if !self.config_warnings.is_empty() {
+            self.render_config_banner(frame);
+        }
+        if self.needs_reboot || self.reboot_deadline.is_some() {
+            self.render_reboot_banner(frame);
+        }
+        self.render_status_bar(frame, status_area);
+        if !self.interrupted.is_empty() {
+            self.render_interrupted_overlay(frame);
+        }
+        if self.palette.is_some() {
+            self.render_palette_overlay(frame);
+        }
+        if self.show_help {
+            self.render_help_overlay(frame);
+        }
+        if self.symbol_mode == SymbolMode::Ascii {
+            to_ascii(frame.buffer_mut());
         }
     }
 
-    /// Maneja los eventos de teclado
+    /// Maneja los eventos de teclado y ratón
     fn handle_events(&mut self) -> std::io::Result<()> {
-        if event::poll(std::time::Duration::from_millis(100))?
-            && let Event::Key(key) = event::read()?
-            && key.kind == KeyEventKind::Press
-        {
-            match self.current_view {
-                View::MainMenu => self.handle_menu_input(key.code),
-                _ => self.handle_operation_input(key.code),
+        if event::poll(std::time::Duration::from_millis(100))? {
+            match event::read()? {
+                Event::Key(key) if key.kind == KeyEventKind::Press => self.handle_key(key),
+                Event::Mouse(mouse) => self.handle_mouse(mouse),
+                _ => {}
             }
         }
         Ok(())
     }
 
+    /// Maneja una tecla pulsada según la vista actual
+    fn handle_key(&mut self, key: KeyEvent) {
+        // Con la ayuda abierta, cualquier tecla la cierra
+        if self.show_help {
+            self.show_help = false;
+            return;
+        }
+        if !self.interrupted.is_empty() {
+            self.handle_interrupted_input(key.code);
+            return;
+        }
+        if self.palette.is_some() {
+            self.handle_palette_input(key.code);
+            return;
+        }
+        if key.modifiers.contains(KeyModifiers::CONTROL)
+            && key.code == KeyCode::Char('p')
+            && self.current_view != View::Onboarding
+        {
+            self.palette = Some(CommandPalette::default());
+            return;
+        }
+        if key.modifiers.contains(KeyModifiers::CONTROL)
+            && key.code == KeyCode::Char('r')
+            && self.reboot_key_is_free()
+            && (self.needs_reboot
+                || self.reboot_deadline.is_some()
+                || (self.current_view == View::MainMenu && self.uptime_reboot_days().is_some()))
+        {
+            self.toggle_reboot();
+            return;
+        }
+        if key.code == KeyCode::F(1) || (key.code == KeyCode::Char('?') && !self.is_typing()) {
+            self.show_help = true;
+            return;
+        }
+        if self.log_search.editing {
+            self.handle_log_search_input(key.code);
+            return;
+        }
+        if self.handle_worker_input(key.code) {
+            return;
+        }
+        match self.current_view {
+            View::MainMenu => self.handle_menu_input(key.code),
+            View::Profiles => self.handle_profiles_input(key.code),
+            View::Drivers => self.handle_drivers_input(key.code),
+            View::StartupOptimizer => self.handle_startup_input(key.code),
+            View::Tasks => self.handle_tasks_input(key.code),
+            View::WindowsUpdate => self.handle_windows_update_input(key.code),
+            View::Memory => self.handle_memory_input(key.code),
+            View::Optimize | View::Privacy => self.handle_services_input(key.code),
+            View::Repair => self.handle_repair_input(key.code),
+            View::Programs => self.handle_programs_input(key.code),
+            View::Downloads => self.handle_downloads_input(key.code),
+            View::Quarantine => self.handle_quarantine_input(key.code),
+            View::Defender => self.handle_defender_input(key.code),
+            View::Firewall => self.handle_firewall_input(key.code),
+            View::Hosts => self.handle_hosts_input(key),
+            View::PathCleaner => self.handle_path_cleaner_input(key.code),
+            View::Adapters => self.handle_adapters_input(key.code),
+            View::Wifi => self.handle_wifi_input(key.code),
+            View::Proxy => self.handle_proxy_input(key.code),
+            View::Ports => self.handle_ports_input(key.code),
+            View::AppLogs => self.handle_app_logs_input(key.code),
+            View::BootTime => self.handle_boot_time_input(key.code),
+            View::Jobs => self.handle_jobs_input(key.code),
+            View::Network => self.handle_network_input(key.code),
+            View::Clean => self.handle_clean_input(key.code),
+            View::Onboarding => self.handle_onboarding_input(key.code),
+            View::Settings => self.handle_settings_input(key.code),
+            View::Info => self.handle_info_input(key.code),
+            View::About => self.handle_about_input(key.code),
+            _ => self.handle_operation_input(key.code),
+        }
+    }
+
+    /// Maneja el ratón sobre las zonas del último fotograma
+    ///
+    /// La rueda desplaza el panel de logs si el puntero está encima y, en el
+    /// resto de la vista, equivale a las flechas. Un clic selecciona la fila
+    /// de la lista; en el menú, un clic sobre la opción ya seleccionada la abre.
+    fn handle_mouse(&mut self, mouse: MouseEvent) {
+        if self.show_help {
+            if let MouseEventKind::Down(_) = mouse.kind {
+                self.show_help = false;
+            }
+            return;
+        }
+        if self.palette.is_some() || !self.interrupted.is_empty() {
+            return;
+        }
+        let (column, row) = (mouse.column, mouse.row);
+        match mouse.kind {
+            MouseEventKind::ScrollUp | MouseEventKind::ScrollDown
+                if self.mouse_targets.over_log_panel(column, row) =>
+            {
+                self.log_follow = false;
+                self.scroll_offset = if mouse.kind == MouseEventKind::ScrollUp {
+                    self.scroll_offset.saturating_sub(mouse::WHEEL_LINES)
+                } else {
+                    self.scroll_offset.saturating_add(mouse::WHEEL_LINES)
+                };
+            }
+            MouseEventKind::ScrollUp => self.handle_key(KeyEvent::from(KeyCode::Up)),
+            MouseEventKind::ScrollDown => self.handle_key(KeyEvent::from(KeyCode::Down)),
+            MouseEventKind::Down(MouseButton::Left) => {
+                let Some(item) = self
+                    .mouse_targets
+                    .list
+                    .as_ref()
+                    .and_then(|list| list.item_at(column, row))
+                else {
+                    return;
+                };
+                if self.current_view == View::MainMenu {
+                    if item == self.selected_menu_item {
+                        self.handle_menu_input(KeyCode::Enter);
+                    } else {
+                        self.selected_menu_item = item;
+                    }
+                } else if item != self.selected_item {
+                    self.selected_item = item;
+                    if self.current_view == View::Jobs {
+                        self.scroll_offset = 0;
+                    }
+                }
+            }
+            _ => {}
+        }
+    }
+
+    /// Indica si las teclas se están escribiendo en un campo de texto
+    fn is_typing(&self) -> bool {
+        self.log_search.editing
+            || self.ports_filter_editing
+            || self.log_filter_editing
+            || self.current_view == View::Hosts
+    }
+
+    /// Maneja los atajos de la vista de una tarea activa
+    ///
+    /// X cancela la tarea terminando el comando en curso; si el comando
+    /// está bloqueado, T lo termina y continúa con el siguiente paso.
+    /// Devuelve `true` si se consumió la tecla.
+    fn handle_worker_input(&mut self, key_code: KeyCode) -> bool {
+        let Some((id, stalled)) = self
+            .jobs
+            .active_for(self.current_view)
+            .map(|job| (job.id, job.stalled))
+        else {
+            return false;
+        };
+        match key_code {
+            KeyCode::Char('t') | KeyCode::Char('T') if stalled => {
+                self.jobs.skip_step(id);
+                self.operation_logs.push(LogEntry::new(
+                    LogLevel::Warning,
+                    format!("⏭️  {}", self.t(I18nKey::WorkerSkipping)),
+                ));
+            }
+            KeyCode::Char('x') | KeyCode::Char('X') => {
+                self.jobs.cancel(id);
+                self.operation_logs.push(LogEntry::new(
+                    LogLevel::Warning,
+                    format!("⏹️  {}", self.t(I18nKey::WorkerCancelling)),
+                ));
+            }
+            _ => return false,
+        }
+        true
+    }
+
     /// Maneja input en el menú principal
     fn handle_menu_input(&mut self, key_code: KeyCode) {
         match key_code {
             KeyCode::Down | KeyCode::Char('j') => {
-                self.selected_menu_item = (self.selected_menu_item + 1).min(13);
+                self.selected_menu_item = (self.selected_menu_item + 1).min(MENU_ITEMS.len() - 1);
             }
             KeyCode::Up | KeyCode::Char('k') => {
                 self.selected_menu_item = self.selected_menu_item.saturating_sub(1);
             }
-            KeyCode::Enter => {
-                self.operation_logs.clear();
-                self.scroll_offset = 0;
-                self.current_view = match self.selected_menu_item {
-                    0 => {
-                        cleanup::execute_clean(self);
-                        View::Clean
-                    }
-                    1 => {
-                        cleanup::execute_recycle_bin(self);
-                        View::RecycleBin
-                    }
-                    2 => {
-                        cleanup::execute_browser_cache(self);
-                        View::BrowserCache
-                    }
-                    3 => {
-                        cleanup::execute_system_logs(self);
-                        View::SystemLogs
-                    }
-                    4 => {
-                        optimization::execute_windows_update_cleanup(self);
-                        View::WindowsUpdate
-                    }
-                    5 => {
-                        optimization::execute_optimize(self);
-                        View::Optimize
-                    }
-                    6 => {
-                        optimization::execute_startup_optimizer(self);
-                        View::StartupOptimizer
-                    }
-                    7 => {
-                        optimization::execute_visual_effects(self);
-                        View::VisualEffects
-                    }
-                    8 => {
-                        optimization::execute_network(self);
-                        View::Network
-                    }
-                    9 => {
-                        optimization::execute_repair(self);
-                        View::Repair
-                    }
-                    10 => {
-                        optimization::execute_privacy(self);
-                        View::Privacy
-                    }
-                    11 => View::Info,
-                    12 => {
-                        self.should_quit = true;
-                        View::MainMenu
-                    }
-                    _ => View::MainMenu,
-                };
-            }
+            KeyCode::Enter => self.activate_menu_item(self.selected_menu_item),
             KeyCode::Char('q') | KeyCode::Esc => {
                 self.should_quit = true;
             }
@@ -266,391 +1090,4578 @@ impl App {
             KeyCode::Char('l') | KeyCode::Char('L') => {
                 self.toggle_language();
             }
+            KeyCode::Char('u') | KeyCode::Char('U') => self.download_release(),
             _ => {}
         }
     }
 
-    /// Maneja input en las vistas de operaciones
-    fn handle_operation_input(&mut self, key_code: KeyCode) {
-        match key_code {
-            KeyCode::Char('q') | KeyCode::Esc => {
-                self.current_view = View::MainMenu;
-                self.operation_state = OperationState::Idle;
-            }
-            KeyCode::Down => {
-                self.scroll_offset = self.scroll_offset.saturating_add(1);
-            }
-            KeyCode::Up => {
-                self.scroll_offset = self.scroll_offset.saturating_sub(1);
-            }
-            KeyCode::Tab => {
-                self.toggle_theme();
+    /// Ejecuta la acción de una entrada del menú
+    ///
+    /// Con `lock_admin_items`, las entradas que requieren administrador sin
+    /// tener permisos elevados no hacen nada, igual que las que no funcionan
+    /// en modo seguro o en esta versión de Windows.
+    fn activate_menu_item(&mut self, idx: usize) {
+        if self.config.appearance.lock_admin_items && self.is_menu_item_locked(idx) {
+            return;
+        }
+        if self.menu_item_unavailable_reason(idx).is_some() {
+            return;
+        }
+        if let Some(item) = MENU_ITEMS.get(idx) {
+            match item.action {
+                MenuAction::Open(view) => self.open_view(view),
+                MenuAction::Exit => self.should_quit = true,
             }
-            KeyCode::Char('l') | KeyCode::Char('L') => {
-                self.toggle_language();
+        }
+    }
+
+    /// Maneja las teclas con la paleta de comandos abierta
+    ///
+    /// Enter ejecuta la opción seleccionada como si se eligiera en el menú.
+    fn handle_palette_input(&mut self, key_code: KeyCode) {
+        let Some(palette) = self.palette.as_mut() else {
+            return;
+        };
+        let matches = palette.matches(&self.i18n);
+        match key_code {
+            KeyCode::Esc => self.palette = None,
+            KeyCode::Down => palette.next(matches.len()),
+            KeyCode::Up => palette.prev(matches.len()),
+            KeyCode::Backspace => palette.pop(),
+            KeyCode::Char(c) => palette.push(c),
+            KeyCode::Enter => {
+                let Some(&idx) = matches.get(palette.selected(matches.len())) else {
+                    return;
+                };
+                self.palette = None;
+                self.selected_menu_item = idx;
+                self.activate_menu_item(idx);
             }
             _ => {}
         }
     }
 
-    /// Dibuja el menú principal
-    fn draw_main_menu(&mut self, frame: &mut Frame) {
-        let colors = self.get_colors();
-        let main_block = Block::default().style(Style::default().bg(colors.bg_main));
-        frame.render_widget(main_block, frame.area());
+    /// Indica si una entrada del menú requiere administrador sin tener
+    /// permisos elevados
+    fn is_menu_item_locked(&self, idx: usize) -> bool {
+        self.menu_locked.get(idx).copied().unwrap_or(false)
+    }
 
-        let chunks = Layout::default()
-            .direction(Direction::Vertical)
-            .margin(2)
-            .constraints([
-                Constraint::Length(13),
-                Constraint::Min(8),
-                Constraint::Length(4),
-            ])
-            .split(frame.area());
+    /// Motivo por el que una entrada del menú no funciona en este sistema
+    /// (modo seguro o versión de Windows no admitida), si no funciona
+    fn menu_item_unavailable_reason(&self, idx: usize) -> Option<I18nKey> {
+        let item = MENU_ITEMS.get(idx)?;
+        let MenuAction::Open(view) = item.action else {
+            return None;
+        };
+        if !self.boot_mode.allows(view) {
+            Some(I18nKey::MenuSafeModeUnavailable)
+        } else if !item.supports_build(self.os_build) {
+            Some(I18nKey::MenuUnsupportedBuild)
+        } else {
+            None
+        }
+    }
 
-        // Banner moderno con degradado simulado
-        self.render_modern_banner(frame, chunks[0]);
+    /// Marca tras el título de una entrada del menú que no se puede ejecutar
+    fn menu_item_badge(&self, idx: usize) -> &'static str {
+        if self.menu_item_unavailable_reason(idx).is_some() {
+            " ⛔"
+        } else if self.is_menu_item_locked(idx) {
+            " 🛡️"
+        } else {
+            ""
+        }
+    }
 
-        // Menú con diseño moderno
-        self.render_modern_menu(frame, chunks[1]);
+    /// Abre una vista, ejecutando la operación asociada si la tiene
+    ///
+    /// Si la vista ya tiene una tarea en segundo plano activa, se muestra su
+    /// progreso en lugar de lanzar otra.
+    pub fn open_view(&mut self, view: View) {
+        self.operation_logs.clear();
+        self.failed_items.clear();
+        self.log_search.clear();
+        self.scroll_offset = 0;
+        self.log_follow = true;
+        self.selected_item = 0;
+
+        if let Some(job) = self.jobs.active_for(view) {
+            self.operation_logs = job.logs.clone();
+            self.operation_state = match job.status {
+                JobStatus::Pending => OperationState::Starting,
+                _ => OperationState::Running,
+            };
+            self.operation_progress = job.progress;
+            self.operation_timer = job.started.map(OperationTimer::since);
+            self.operation_stalled = job.stalled;
+            self.failed_items = job.failed.clone();
+            self.current_view = view;
+            return;
+        }
 
-        // Footer elegante
-        self.render_modern_footer(frame, chunks[2]);
-    }
+        // Confirmado con Enter desde la propia vista, o retenerla para avisar
+        let confirmed =
+            std::mem::take(&mut self.pending_reboot_confirm) && self.current_view == view;
+        if !confirmed && self.hold_for_pending_reboot(view) {
+            return;
+        }
 
-    /// Renderiza un banner moderno y profesional
-    fn render_modern_banner(&self, frame: &mut Frame, area: Rect) {
-        let colors = self.get_colors();
+        match view {
+            View::Clean => {
+                self.clean_drives = drives::list();
+                self.clean_profiles = if self.elevated {
+                    user_profiles::others()
+                } else {
+                    Vec::new()
+                };
+                if self.clean_picker_rows() > 0 {
+                    self.operation_state = OperationState::Idle;
+                    self.stats = None;
+                    if self.clean_drives.len() > 1 {
+                        let hint = format!("💡 {}", self.t(I18nKey::CleanDrivesHint));
+                        logger::log(Some(self), LogLevel::Info, hint);
+                    }
+                    if !self.clean_profiles.is_empty() {
+                        let hint = format!("💡 {}", self.t(I18nKey::CleanProfilesHint));
+                        logger::log(Some(self), LogLevel::Info, hint);
+                    }
+                } else {
+                    self.start_clean();
+                }
+            }
+            View::RecycleBin => self.run_operation(view, cleanup::execute_recycle_bin),
+            View::BrowserCache => self.run_operation(view, cleanup::execute_browser_cache),
+            View::SystemLogs => self.run_operation(view, cleanup::execute_system_logs),
+            View::WindowsUpdate => {
+                self.run_operation(view, optimization::execute_windows_update_cleanup);
+                self.refresh_update_status();
+            }
+            View::Optimize => {
+                let before = Snapshot::capture();
+                self.run_operation(view, optimization::execute_optimize);
+                benchmark::record(self, before);
+                history::record_run("optimize");
+            }
+            View::StartupOptimizer => self.run_operation(view, startup::execute_startup_audit),
+            View::VisualEffects => self.run_operation(view, optimization::execute_visual_effects),
+            View::Network => optimization::prepare_network(self),
+            View::Repair => self.run_operation(view, optimization::execute_repair),
+            View::Privacy => self.run_operation(view, optimization::execute_privacy),
+            View::Drivers => drivers::execute_drivers(self),
+            View::Tasks => tasks::execute_tasks_list(self),
+            View::Memory => self.run_operation(view, memory::execute_memory_cleanup),
+            View::Programs => programs::execute_programs(self),
+            View::Downloads => downloads::execute_downloads_scan(self),
+            View::Quarantine => quarantine::execute_quarantine_list(self),
+            View::Defender => defender::execute_defender_status(self),
+            View::Firewall => firewall::execute_firewall_status(self),
+            View::Hosts => hosts::execute_hosts_load(self),
+            View::PathCleaner => env_path::execute_path_scan(self),
+            View::Adapters => adapters::execute_adapters(self),
+            View::Wifi => wifi::execute_wifi_profiles(self),
+            View::Proxy => proxy::execute_proxy_status(self),
+            View::BootTime => boot::execute_boot_times(self),
+            View::AppLogs => {
+                self.log_tail = LogTail::open();
+                self.log_filter_editing = false;
+            }
+            View::Jobs => {
+                // Mostrar primero la tarea más reciente
+                self.selected_item = self.jobs.jobs().len().saturating_sub(1);
+            }
+            View::Ports => {
+                self.ports_filter.clear();
+                self.ports_filter_editing = false;
+                ports::execute_ports(self);
+            }
+            View::FontCache => self.run_operation(view, optimization::execute_font_cache_rebuild),
+            View::SearchIndex => {
+                self.run_operation(view, optimization::execute_search_index_rebuild)
+            }
+            View::Profiles => {
+                // Preseleccionar el perfil por defecto de la configuración
+                if let Some(default) = self.config.profiles.default_profile {
+                    self.selected_item =
+                        Profile::ALL.iter().position(|&p| p == default).unwrap_or(0);
+                }
+            }
+            View::Info => {
+                // El informe de batería, la activación y la red tardan unos
+                // segundos: consultarlos solo al entrar y en segundo plano,
+                // para no bloquear la interfaz
+                self.battery_health.clear();
+                self.battery_receiver = Some(battery::spawn_query());
+                self.windows_activation = None;
+                self.activation_receiver = Some(activation::spawn_query());
+                self.network_info.clear();
+                self.network_receiver = Some(network_info::spawn_query());
+                self.process_monitor = ProcessMonitor::default();
+            }
+            View::Onboarding => self.onboarding = Onboarding::from_config(&self.config),
+            View::Settings => {
+                let current = SettingsItem::Language(self.i18n.current_language());
+                self.selected_item = self
+                    .settings_items()
+                    .iter()
+                    .position(|&item| item == current)
+                    .unwrap_or(0);
+            }
+            View::About => self.diagnostics = about::collect(self),
+            View::MainMenu => {}
+        }
 
-        // Banner con diseño moderno y limpio
-        let banner_lines = vec![
-            // Línea superior con gradiente simulado
+        self.current_view = view;
+    }
+
+    /// Avisa del reinicio pendiente de Windows antes de una operación que
+    /// funciona mal con él
+    ///
+    /// Retorna `true` si la vista queda a la espera de que se pulse Enter.
+    fn hold_for_pending_reboot(&mut self, view: View) -> bool {
+        if self.pending_reboot.is_empty() || !reboot::affected_by_pending(view) {
+            return false;
+        }
+
+        let warning = format!("⚠️  {}", self.t(I18nKey::PendingRebootWarning));
+        logger::log(Some(self), LogLevel::Warning, warning);
+        for reason in self.pending_reboot.clone() {
+            let reason = format!("   • {}", self.t(reason.label_key()));
+            logger::log(Some(self), LogLevel::Warning, reason);
+        }
+        let confirm = format!("💡 {}", self.t(I18nKey::PendingRebootConfirm));
+        logger::log(Some(self), LogLevel::Info, confirm);
+
+        self.pending_reboot_confirm = true;
+        self.operation_state = OperationState::Idle;
+        self.current_view = view;
+        true
+    }
+
+    /// Atiende el aviso de la primera operación interrumpida
+    ///
+    /// Enter la vuelve a ejecutar, R la deshace si se puede y Esc descarta
+    /// el aviso. En los tres casos se borra del diario.
+    fn handle_interrupted_input(&mut self, key_code: KeyCode) {
+        let Some(entry) = self.interrupted.first().cloned() else {
+            return;
+        };
+        let rollback = journal::rollback_for(&entry.operation, &self.config);
+        match key_code {
+            KeyCode::Enter => {
+                self.interrupted.remove(0);
+                journal::dismiss(&entry.operation);
+                if let Some(view) = entry.view() {
+                    self.open_view(view);
+                }
+            }
+            KeyCode::Char('r') | KeyCode::Char('R') if rollback.is_some() => {
+                self.interrupted.remove(0);
+                journal::dismiss(&entry.operation);
+                match rollback {
+                    Some(Rollback::RestoreServices) => {
+                        self.current_view = entry.view().unwrap_or(View::Optimize);
+                        self.operation_logs.clear();
+                        self.scroll_offset = 0;
+                        services::execute_restore_services(self);
+                    }
+                    Some(Rollback::Quarantine) => self.open_view(View::Quarantine),
+                    None => {}
+                }
+            }
+            KeyCode::Esc => {
+                self.interrupted.remove(0);
+                journal::dismiss(&entry.operation);
+            }
+            _ => {}
+        }
+    }
+
+    /// Maneja input en las vistas de operaciones
+    fn handle_operation_input(&mut self, key_code: KeyCode) {
+        let operation = operation::name_for_view(self.current_view);
+        let finished = matches!(
+            self.operation_state,
+            OperationState::Completed | OperationState::Failed
+        );
+        match key_code {
+            KeyCode::Enter if self.pending_reboot_confirm => self.open_view(self.current_view),
+            KeyCode::Enter
+                if self.operation_state == OperationState::Failed && operation.is_some() =>
+            {
+                self.open_view(self.current_view)
+            }
+            KeyCode::Char('e') | KeyCode::Char('E') if finished && operation.is_some() => {
+                self.export_logs()
+            }
+            // R ya es «actualizar» o «restaurar» en muchas vistas
+            KeyCode::Char('i') | KeyCode::Char('I')
+                if finished && !self.failed_items.is_empty() =>
+            {
+                retry::execute_retry(self)
+            }
+            KeyCode::Esc if self.log_search.is_active() => self.log_search.clear(),
+            KeyCode::Char('/') => self.log_search.editing = true,
+            KeyCode::Char('n') if self.log_search.is_active() => {
+                let total = self.log_search.matches(self.displayed_logs()).len();
+                self.log_search.next(total);
+            }
+            KeyCode::Char('N') if self.log_search.is_active() => {
+                let total = self.log_search.matches(self.displayed_logs()).len();
+                self.log_search.prev(total);
+            }
+            KeyCode::Char('q') | KeyCode::Esc => {
+                self.current_view = View::MainMenu;
+                self.operation_state = OperationState::Idle;
+                self.pending_reboot_confirm = false;
+            }
+            KeyCode::Down => {
+                self.log_follow = false;
+                self.scroll_offset = self.scroll_offset.saturating_add(1);
+            }
+            KeyCode::Up => {
+                self.log_follow = false;
+                self.scroll_offset = self.scroll_offset.saturating_sub(1);
+            }
+            KeyCode::PageDown => {
+                self.log_follow = false;
+                self.scroll_offset = self.scroll_offset.saturating_add(self.log_page());
+            }
+            KeyCode::PageUp => {
+                self.log_follow = false;
+                self.scroll_offset = self.scroll_offset.saturating_sub(self.log_page());
+            }
+            KeyCode::Home => {
+                self.log_follow = false;
+                self.scroll_offset = 0;
+            }
+            // El dibujado ajusta el desplazamiento a la última línea
+            KeyCode::End => self.scroll_offset = u16::MAX,
+            KeyCode::Tab => {
+                self.toggle_theme();
+            }
+            KeyCode::Char('f') | KeyCode::Char('F') => self.log_follow = !self.log_follow,
+            KeyCode::Char('y') | KeyCode::Char('Y') => self.copy_logs(),
+            KeyCode::Char('h') | KeyCode::Char('H') => {
+                self.config.appearance.show_log_timestamps =
+                    !self.config.appearance.show_log_timestamps;
+            }
+            KeyCode::Char('l') | KeyCode::Char('L') => {
+                self.toggle_language();
+            }
+            _ => {}
+        }
+    }
+
+    /// Maneja la escritura del patrón de búsqueda en los logs
+    fn handle_log_search_input(&mut self, key_code: KeyCode) {
+        match key_code {
+            KeyCode::Char(c) => self.log_search.push(c),
+            KeyCode::Backspace => self.log_search.pop(),
+            KeyCode::Esc => self.log_search.clear(),
+            KeyCode::Enter => self.log_search.editing = false,
+            _ => {}
+        }
+    }
+
+    /// Copia al portapapeles los logs del panel (o las coincidencias de la búsqueda)
+    fn copy_logs(&mut self) {
+        let logs = self.displayed_logs();
+        let text = if self.log_search.is_active() {
+            let matches = self.log_search.matches(logs);
+            clipboard::format_logs(matches.iter().map(|&idx| &logs[idx]))
+        } else {
+            clipboard::format_logs(logs)
+        };
+        if text.is_empty() {
+            return;
+        }
+
+        let lines = text.lines().count();
+        match clipboard::copy_text(&text) {
+            Ok(()) => {
+                let message = format!(
+                    "📋 {}",
+                    self.t_args(I18nKey::LogsCopied, &[("count", &lines.to_string())])
+                );
+                logger::log(Some(self), LogLevel::Info, message);
+            }
+            Err(e) => {
+                let message = format!(
+                    "❌ {}",
+                    self.t_args(I18nKey::LogsCopyError, &[("error", &e.to_string())])
+                );
+                logger::log(Some(self), LogLevel::Error, message);
+            }
+        }
+    }
+
+    /// Guarda los logs de la operación en un archivo de texto en la carpeta
+    /// Documentos (`win_opt_<operación>.log`)
+    fn export_logs(&mut self) {
+        let text = clipboard::format_logs(&self.operation_logs);
+        if text.is_empty() {
+            return;
+        }
+        let name = operation::name_for_view(self.current_view).unwrap_or("operation");
+        let path = platform::documents_dir()
+            .or_else(|| Config::get_config_dir().ok())
+            .unwrap_or_else(std::env::temp_dir)
+            .join(format!("win_opt_{}.log", name));
+
+        match std::fs::write(&path, text + "\n") {
+            Ok(()) => logger::log(
+                Some(self),
+                LogLevel::Info,
+                format!("📄 Logs guardados en {}", path.display()),
+            ),
+            Err(e) => logger::log(
+                Some(self),
+                LogLevel::Error,
+                format!("❌ No se pudieron guardar los logs: {}", e),
+            ),
+        }
+    }
+
+    /// Líneas que avanzan PageUp/PageDown: las visibles menos una de contexto
+    fn log_page(&self) -> u16 {
+        self.log_page_height.saturating_sub(1).max(1)
+    }
+
+    /// Logs que muestra el panel de la vista actual
+    fn displayed_logs(&self) -> &[LogEntry] {
+        if self.current_view == View::Jobs {
+            self.jobs
+                .jobs()
+                .get(self.selected_item)
+                .map(|job| job.logs.as_slice())
+                .unwrap_or_default()
+        } else {
+            &self.operation_logs
+        }
+    }
+
+    /// Maneja input en la vista de perfiles
+    fn handle_profiles_input(&mut self, key_code: KeyCode) {
+        match key_code {
+            KeyCode::Down | KeyCode::Char('j') => {
+                self.selected_item = (self.selected_item + 1).min(Profile::ALL.len() - 1);
+            }
+            KeyCode::Up | KeyCode::Char('k') => {
+                self.selected_item = self.selected_item.saturating_sub(1);
+            }
+            KeyCode::Enter => {
+                if let Some(&profile) = Profile::ALL.get(self.selected_item) {
+                    self.operation_logs.clear();
+                    self.scroll_offset = 0;
+                    profiles::execute_apply_profile(self, profile);
+                }
+            }
+            KeyCode::Char('r') | KeyCode::Char('R') => {
+                self.operation_logs.clear();
+                self.scroll_offset = 0;
+                profiles::execute_revert_profile(self);
+            }
+            _ => self.handle_operation_input(key_code),
+        }
+    }
+
+    /// Maneja input en la vista de Windows Update
+    fn handle_windows_update_input(&mut self, key_code: KeyCode) {
+        match key_code {
+            KeyCode::Char('s') | KeyCode::Char('S') if self.update_status_receiver.is_none() => {
+                windows_update::execute_scan(self);
+            }
+            _ => self.handle_operation_input(key_code),
+        }
+    }
+
+    /// Maneja input en la vista de reparación
+    fn handle_repair_input(&mut self, key_code: KeyCode) {
+        match key_code {
+            KeyCode::Char('c') | KeyCode::Char('C') if !self.cbs_excerpt().is_empty() => {
+                self.cbs_expanded = !self.cbs_expanded;
+            }
+            _ => self.handle_operation_input(key_code),
+        }
+    }
+
+    /// Líneas `[SR]` de `CBS.log` de la última reparación terminada
+    fn cbs_excerpt(&self) -> &[String] {
+        match &self.stats {
+            Some(OperationStats::Repair(stats)) => &stats.cbs_excerpt,
+            _ => &[],
+        }
+    }
+
+    /// Maneja input en la vista de memoria
+    fn handle_memory_input(&mut self, key_code: KeyCode) {
+        match key_code {
+            KeyCode::Char('d') | KeyCode::Char('D') => memory::execute_memory_diagnostic(self),
+            _ => self.handle_operation_input(key_code),
+        }
+    }
+
+    /// Maneja input en las vistas que deshabilitan servicios
+    fn handle_services_input(&mut self, key_code: KeyCode) {
+        match key_code {
+            KeyCode::Char('r') | KeyCode::Char('R')
+                if self.operation_state != OperationState::Running =>
+            {
+                self.operation_logs.clear();
+                self.scroll_offset = 0;
+                services::execute_restore_services(self);
+            }
+            _ => self.handle_operation_input(key_code),
+        }
+    }
+
+    /// Maneja input en la vista de programas instalados
+    fn handle_programs_input(&mut self, key_code: KeyCode) {
+        match key_code {
+            KeyCode::Down | KeyCode::Char('j') => {
+                self.selected_item =
+                    (self.selected_item + 1).min(self.programs.len().saturating_sub(1));
+            }
+            KeyCode::Up | KeyCode::Char('k') => {
+                self.selected_item = self.selected_item.saturating_sub(1);
+            }
+            KeyCode::Char('s') | KeyCode::Char('S') => programs::execute_cycle_sort(self),
+            KeyCode::Char('u') | KeyCode::Char('U') => {
+                programs::execute_uninstall(self, self.selected_item)
+            }
+            _ => self.handle_operation_input(key_code),
+        }
+    }
+
+    /// Maneja input en el asistente de Descargas
+    fn handle_downloads_input(&mut self, key_code: KeyCode) {
+        match key_code {
+            KeyCode::Char('d') | KeyCode::Char('D') => {
+                downloads::request_action(self, DownloadsAction::Delete)
+            }
+            KeyCode::Char('m') | KeyCode::Char('M') => {
+                downloads::request_action(self, DownloadsAction::Archive)
+            }
+            KeyCode::Char('t') | KeyCode::Char('T') => {
+                self.downloads_confirm = None;
+                self.downloads_threshold_days =
+                    downloads::next_threshold(self.downloads_threshold_days);
+            }
+            _ => {
+                self.downloads_confirm = None;
+                self.handle_operation_input(key_code);
+            }
+        }
+    }
+
+    /// Maneja input en la vista de cuarentena
+    fn handle_quarantine_input(&mut self, key_code: KeyCode) {
+        match key_code {
+            KeyCode::Down | KeyCode::Char('j') => {
+                self.selected_item =
+                    (self.selected_item + 1).min(self.quarantine_items.len().saturating_sub(1));
+            }
+            KeyCode::Up | KeyCode::Char('k') => {
+                self.selected_item = self.selected_item.saturating_sub(1);
+            }
+            KeyCode::Enter | KeyCode::Char('r') | KeyCode::Char('R') => {
+                quarantine::execute_restore(self, self.selected_item)
+            }
+            _ => self.handle_operation_input(key_code),
+        }
+    }
+
+    /// Maneja input en la vista de Defender
+    fn handle_defender_input(&mut self, key_code: KeyCode) {
+        let exclusions = self
+            .defender_status
+            .as_ref()
+            .map_or(0, |status| status.exclusions.len());
+        match key_code {
+            KeyCode::Down | KeyCode::Char('j') => {
+                self.selected_item = (self.selected_item + 1).min(exclusions.saturating_sub(1));
+            }
+            KeyCode::Up | KeyCode::Char('k') => {
+                self.selected_item = self.selected_item.saturating_sub(1);
+            }
+            KeyCode::Char('s') | KeyCode::Char('S') => defender::execute_quick_scan(self),
+            KeyCode::Char('r') | KeyCode::Char('R')
+                if self.jobs.active_for(View::Defender).is_none() =>
+            {
+                self.selected_item = 0;
+                defender::execute_defender_status(self);
+            }
+            _ => self.handle_operation_input(key_code),
+        }
+    }
+
+    /// Maneja input en la vista del firewall
+    fn handle_firewall_input(&mut self, key_code: KeyCode) {
+        match key_code {
+            KeyCode::Down | KeyCode::Char('j') => {
+                self.selected_item =
+                    (self.selected_item + 1).min(self.firewall_profiles.len().saturating_sub(1));
+            }
+            KeyCode::Up | KeyCode::Char('k') => {
+                self.selected_item = self.selected_item.saturating_sub(1);
+            }
+            KeyCode::Enter | KeyCode::Char('e') | KeyCode::Char('E') => {
+                if let Some(state) = self.firewall_profiles.get(self.selected_item).copied() {
+                    firewall::execute_enable_profiles(self, &[state.profile]);
+                }
+            }
+            KeyCode::Char('a') | KeyCode::Char('A') => {
+                firewall::execute_enable_profiles(self, &FirewallProfile::ALL)
+            }
+            KeyCode::Char('r') | KeyCode::Char('R') => firewall::execute_firewall_status(self),
+            _ => self.handle_operation_input(key_code),
+        }
+    }
+
+    /// Maneja input en la vista de adaptadores de red
+    fn handle_adapters_input(&mut self, key_code: KeyCode) {
+        match key_code {
+            KeyCode::Down | KeyCode::Char('j') => {
+                self.selected_item =
+                    (self.selected_item + 1).min(self.adapters.len().saturating_sub(1));
+            }
+            KeyCode::Up | KeyCode::Char('k') => {
+                self.selected_item = self.selected_item.saturating_sub(1);
+            }
+            KeyCode::Char('e') | KeyCode::Char('E') => {
+                adapters::execute_toggle_adapter(self, self.selected_item)
+            }
+            KeyCode::Char('n') | KeyCode::Char('N') => {
+                adapters::execute_renew_adapter(self, self.selected_item)
+            }
+            KeyCode::Char('r') | KeyCode::Char('R') => {
+                self.selected_item = 0;
+                adapters::execute_adapters(self);
+            }
+            _ => self.handle_operation_input(key_code),
+        }
+    }
+
+    /// Lanza la limpieza de temporales en las unidades marcadas
+    fn start_clean(&mut self) {
+        if !self.clean_drives.is_empty() && !self.clean_drives.iter().any(|d| d.selected) {
+            let warning = format!("⚠️  {}", self.t(I18nKey::CleanNoDrives));
+            logger::log(Some(self), LogLevel::Warning, warning);
+            return;
+        }
+        let before = Snapshot::capture();
+        self.run_operation(View::Clean, cleanup::execute_clean);
+        benchmark::record(self, before);
+        history::record_run("clean");
+    }
+
+    /// Unidades que se muestran para elegir (ninguna si solo hay una)
+    fn clean_picker_drives(&self) -> usize {
+        if self.clean_drives.len() > 1 {
+            self.clean_drives.len()
+        } else {
+            0
+        }
+    }
+
+    /// Filas de la lista de unidades y perfiles
+    fn clean_picker_rows(&self) -> usize {
+        self.clean_picker_drives() + self.clean_profiles.len()
+    }
+
+    /// Indica si la vista de limpieza está esperando la elección de unidades
+    /// y perfiles
+    fn clean_picker_active(&self) -> bool {
+        self.clean_picker_rows() > 0 && self.operation_state == OperationState::Idle
+    }
+
+    /// Maneja input en la vista de limpieza
+    fn handle_clean_input(&mut self, key_code: KeyCode) {
+        if self.pending_reboot_confirm || !self.clean_picker_active() {
+            self.handle_operation_input(key_code);
+            return;
+        }
+        match key_code {
+            KeyCode::Down | KeyCode::Char('j') => {
+                self.selected_item =
+                    (self.selected_item + 1).min(self.clean_picker_rows().saturating_sub(1));
+            }
+            KeyCode::Up | KeyCode::Char('k') => {
+                self.selected_item = self.selected_item.saturating_sub(1);
+            }
+            KeyCode::Char(' ') => {
+                let drives = self.clean_picker_drives();
+                if self.selected_item < drives {
+                    let drive = &mut self.clean_drives[self.selected_item];
+                    drive.selected = !drive.selected;
+                } else if let Some(profile) =
+                    self.clean_profiles.get_mut(self.selected_item - drives)
+                {
+                    profile.selected = !profile.selected;
+                }
+            }
+            KeyCode::Enter => self.start_clean(),
+            _ => self.handle_operation_input(key_code),
+        }
+    }
+
+    /// Maneja input en la vista de red
+    fn handle_network_input(&mut self, key_code: KeyCode) {
+        match key_code {
+            KeyCode::Down | KeyCode::Char('j') => {
+                self.selected_item =
+                    (self.selected_item + 1).min(self.network_steps.len().saturating_sub(1));
+            }
+            KeyCode::Up | KeyCode::Char('k') => {
+                self.selected_item = self.selected_item.saturating_sub(1);
+            }
+            KeyCode::Char(' ') => {
+                if let Some(state) = self.network_steps.get_mut(self.selected_item) {
+                    state.enabled = !state.enabled;
+                }
+            }
+            KeyCode::Enter => self.run_operation(View::Network, optimization::execute_network),
+            KeyCode::Char('a') | KeyCode::Char('A') => {
+                ip_backup::execute_restore_static_config(self)
+            }
+            _ => self.handle_operation_input(key_code),
+        }
+    }
+
+    /// Maneja input en la vista de tareas en segundo plano
+    fn handle_jobs_input(&mut self, key_code: KeyCode) {
+        let selected = self.jobs.jobs().get(self.selected_item).map(|job| job.id);
+        match key_code {
+            KeyCode::Down | KeyCode::Char('j') => {
+                self.selected_item =
+                    (self.selected_item + 1).min(self.jobs.jobs().len().saturating_sub(1));
+                self.scroll_offset = 0;
+            }
+            KeyCode::Up | KeyCode::Char('k') => {
+                self.selected_item = self.selected_item.saturating_sub(1);
+                self.scroll_offset = 0;
+            }
+            KeyCode::Char('x') | KeyCode::Char('X') => {
+                if let Some(id) = selected {
+                    self.jobs.cancel(id);
+                }
+            }
+            KeyCode::Char('c') | KeyCode::Char('C') => {
+                self.jobs.clear_finished();
+                self.selected_item = 0;
+                self.scroll_offset = 0;
+            }
+            _ => self.handle_operation_input(key_code),
+        }
+    }
+
+    /// Maneja input en la vista de ajustes
+    fn handle_settings_input(&mut self, key_code: KeyCode) {
+        let items = self.settings_items();
+        match key_code {
+            KeyCode::Down | KeyCode::Char('j') => {
+                self.selected_item = (self.selected_item + 1).min(items.len() - 1);
+            }
+            KeyCode::Up | KeyCode::Char('k') => {
+                self.selected_item = self.selected_item.saturating_sub(1);
+            }
+            KeyCode::Enter => {
+                if let Some(&item) = items.get(self.selected_item) {
+                    self.apply_setting(item);
+                }
+            }
+            _ => self.handle_operation_input(key_code),
+        }
+    }
+
+    /// Filas de la vista de ajustes
+    fn settings_items(&self) -> Vec<SettingsItem> {
+        SettingsItem::all(!self.config.theme.custom.is_empty())
+    }
+
+    /// Indica si un valor de la vista de ajustes es el que está en uso
+    fn is_current_setting(&self, item: SettingsItem) -> bool {
+        match item {
+            SettingsItem::Language(language) => language == self.i18n.current_language(),
+            SettingsItem::Theme(theme) => theme == self.theme,
+        }
+    }
+
+    /// Aplica un valor elegido en la vista de ajustes y lo guarda
+    pub fn apply_setting(&mut self, item: SettingsItem) {
+        match item {
+            SettingsItem::Language(language) => {
+                self.i18n.set_language(language);
+                self.config.set_language(language);
+            }
+            SettingsItem::Theme(theme) => {
+                self.theme = theme;
+                self.config.set_theme(theme);
+            }
+        }
+        if !self.config_invalid
+            && let Err(e) = self.config.save()
+        {
+            tracing::warn!("No se pudieron guardar los ajustes: {}", e);
+        }
+    }
+
+    /// Maneja input en el asistente del primer inicio
+    ///
+    /// El idioma y el tema se aplican al momento para verlos antes de
+    /// confirmarlos. Esc en el primer paso omite el resto del asistente.
+    fn handle_onboarding_input(&mut self, key_code: KeyCode) {
+        match key_code {
+            KeyCode::Left | KeyCode::Up | KeyCode::Char('h') | KeyCode::Char('k') => {
+                self.onboarding.cycle(false);
+            }
+            KeyCode::Right
+            | KeyCode::Down
+            | KeyCode::Char('l')
+            | KeyCode::Char('j')
+            | KeyCode::Char(' ')
+            | KeyCode::Tab => self.onboarding.cycle(true),
+            KeyCode::Enter => {
+                if !self.onboarding.advance() {
+                    self.finish_onboarding();
+                }
+                return;
+            }
+            KeyCode::Esc => {
+                if !self.onboarding.back() {
+                    self.finish_onboarding();
+                }
+                return;
+            }
+            _ => return,
+        }
+        self.i18n.set_language(self.onboarding.language);
+        self.theme = self.onboarding.theme;
+    }
+
+    /// Escribe la configuración inicial con las opciones del asistente
+    fn finish_onboarding(&mut self) {
+        self.onboarding.apply(&mut self.config);
+        self.i18n.set_language(self.config.language());
+        self.theme = self.config.theme();
+        logger::set_file_logging(self.config.logging.file_logging);
+        self.first_run = false;
+
+        match self.config.save() {
+            Ok(()) => tracing::info!("Configuración inicial creada con el asistente"),
+            Err(e) => tracing::warn!("No se pudo guardar la configuración inicial: {}", e),
+        }
+        self.current_view = View::MainMenu;
+    }
+
+    /// Maneja input en el visor del log de la aplicación
+    ///
+    /// `scroll_offset` cuenta las líneas desde el final: con 0 el visor
+    /// sigue las líneas nuevas a medida que se escriben.
+    fn handle_app_logs_input(&mut self, key_code: KeyCode) {
+        if self.log_filter_editing {
+            match key_code {
+                KeyCode::Char(c) => self.log_filter.keyword.push(c),
+                KeyCode::Backspace => {
+                    self.log_filter.keyword.pop();
+                }
+                KeyCode::Esc => {
+                    self.log_filter.keyword.clear();
+                    self.log_filter_editing = false;
+                }
+                KeyCode::Enter => self.log_filter_editing = false,
+                _ => {}
+            }
+            self.scroll_offset = 0;
+            return;
+        }
+
+        match key_code {
+            KeyCode::Up | KeyCode::Char('k') => {
+                self.scroll_offset = self.scroll_offset.saturating_add(1);
+            }
+            KeyCode::Down | KeyCode::Char('j') => {
+                self.scroll_offset = self.scroll_offset.saturating_sub(1);
+            }
+            KeyCode::Char('/') => self.log_filter_editing = true,
+            KeyCode::Char('v') | KeyCode::Char('V') => {
+                self.log_filter.cycle_level();
+                self.scroll_offset = 0;
+            }
+            KeyCode::Char('r') | KeyCode::Char('R') => {
+                self.log_tail = LogTail::open();
+                self.scroll_offset = 0;
+            }
+            KeyCode::Char('n') | KeyCode::Char('N') => {
+                let level = logger::next_level(&self.config.logging.level);
+                match logger::set_level(level) {
+                    // Se guarda en la configuración al salir
+                    Ok(()) => self.config.logging.level = level.to_string(),
+                    Err(e) => tracing::warn!("No se pudo cambiar el nivel de log: {}", e),
+                }
+            }
+            _ => self.handle_operation_input(key_code),
+        }
+    }
+
+    /// Maneja input en la vista de tiempo de arranque
+    fn handle_boot_time_input(&mut self, key_code: KeyCode) {
+        match key_code {
+            KeyCode::Char('r') | KeyCode::Char('R') => boot::execute_boot_times(self),
+            _ => self.handle_operation_input(key_code),
+        }
+    }
+
+    /// Maneja input en la vista de información del sistema
+    fn handle_info_input(&mut self, key_code: KeyCode) {
+        match key_code {
+            KeyCode::Char('s') | KeyCode::Char('S') => self.process_monitor.toggle_sort(),
+            _ => self.handle_operation_input(key_code),
+        }
+    }
+
+    /// Maneja input en la vista "Acerca de"
+    fn handle_about_input(&mut self, key_code: KeyCode) {
+        match key_code {
+            KeyCode::Char('y') | KeyCode::Char('Y') => about::copy_diagnostics(self),
+            _ => self.handle_operation_input(key_code),
+        }
+    }
+
+    /// Maneja input en la vista de puertos y conexiones
+    fn handle_ports_input(&mut self, key_code: KeyCode) {
+        if self.ports_filter_editing {
+            match key_code {
+                KeyCode::Char(c) => {
+                    self.ports_filter.push(c);
+                    self.selected_item = 0;
+                }
+                KeyCode::Backspace => {
+                    self.ports_filter.pop();
+                    self.selected_item = 0;
+                }
+                KeyCode::Esc => {
+                    self.ports_filter.clear();
+                    self.ports_filter_editing = false;
+                    self.selected_item = 0;
+                }
+                KeyCode::Enter => self.ports_filter_editing = false,
+                _ => {}
+            }
+            return;
+        }
+
+        match key_code {
+            KeyCode::Down | KeyCode::Char('j') => {
+                self.ports_kill_confirm = None;
+                let visible = ports::filtered(&self.connections, &self.ports_filter).len();
+                self.selected_item = (self.selected_item + 1).min(visible.saturating_sub(1));
+            }
+            KeyCode::Up | KeyCode::Char('k') => {
+                self.ports_kill_confirm = None;
+                self.selected_item = self.selected_item.saturating_sub(1);
+            }
+            KeyCode::Char('/') => {
+                self.ports_kill_confirm = None;
+                self.ports_filter_editing = true;
+            }
+            KeyCode::Char('x') | KeyCode::Char('X') => ports::request_kill(self),
+            KeyCode::Char('r') | KeyCode::Char('R') => {
+                self.selected_item = 0;
+                ports::execute_ports(self);
+            }
+            _ => {
+                self.ports_kill_confirm = None;
+                self.handle_operation_input(key_code);
+            }
+        }
+    }
+
+    /// Maneja input en la vista de proxy
+    fn handle_proxy_input(&mut self, key_code: KeyCode) {
+        match key_code {
+            KeyCode::Char('x') | KeyCode::Char('X') => proxy::execute_proxy_reset(self),
+            KeyCode::Char('r') | KeyCode::Char('R') => proxy::execute_proxy_status(self),
+            _ => self.handle_operation_input(key_code),
+        }
+    }
+
+    /// Maneja input en la vista de perfiles Wi-Fi
+    fn handle_wifi_input(&mut self, key_code: KeyCode) {
+        match key_code {
+            KeyCode::Down | KeyCode::Char('j') => {
+                self.wifi_delete_confirm = false;
+                self.selected_item =
+                    (self.selected_item + 1).min(self.wifi_profiles.len().saturating_sub(1));
+            }
+            KeyCode::Up | KeyCode::Char('k') => {
+                self.wifi_delete_confirm = false;
+                self.selected_item = self.selected_item.saturating_sub(1);
+            }
+            KeyCode::Char(' ') => wifi::toggle_mark(self, self.selected_item),
+            KeyCode::Char('d') | KeyCode::Char('D') => wifi::request_delete(self),
+            KeyCode::Char('x') | KeyCode::Char('X') => wifi::execute_export(self, false),
+            KeyCode::Char('c') | KeyCode::Char('C') => wifi::execute_export(self, true),
+            KeyCode::Char('r') | KeyCode::Char('R') => {
+                self.selected_item = 0;
+                wifi::execute_wifi_profiles(self);
+            }
+            _ => {
+                self.wifi_delete_confirm = false;
+                self.handle_operation_input(key_code);
+            }
+        }
+    }
+
+    /// Maneja input en la vista de limpieza del PATH
+    fn handle_path_cleaner_input(&mut self, key_code: KeyCode) {
+        match key_code {
+            KeyCode::Down | KeyCode::Char('j') => {
+                self.path_clean_confirm = false;
+                self.selected_item =
+                    (self.selected_item + 1).min(self.path_entries.len().saturating_sub(1));
+            }
+            KeyCode::Up | KeyCode::Char('k') => {
+                self.path_clean_confirm = false;
+                self.selected_item = self.selected_item.saturating_sub(1);
+            }
+            KeyCode::Char('c') | KeyCode::Char('C') => env_path::request_clean(self),
+            _ => {
+                self.path_clean_confirm = false;
+                self.handle_operation_input(key_code);
+            }
+        }
+    }
+
+    /// Maneja input en el editor del archivo hosts
+    ///
+    /// Las letras se escriben en el editor, así que las acciones usan Ctrl y
+    /// solo Esc vuelve al menú (dos veces si hay cambios sin guardar).
+    fn handle_hosts_input(&mut self, key: KeyEvent) {
+        if key.modifiers.contains(KeyModifiers::CONTROL) {
+            match key.code {
+                KeyCode::Char('s') => hosts::execute_hosts_save(self),
+                KeyCode::Char('r') => hosts::execute_hosts_reset(self),
+                _ => {}
+            }
+            return;
+        }
+
+        if key.code == KeyCode::Esc {
+            if hosts::confirm_close(self) {
+                self.handle_operation_input(KeyCode::Esc);
+            }
+            return;
+        }
+
+        let editor = &mut self.hosts_editor;
+        match key.code {
+            KeyCode::Char(c) => editor.insert_char(c),
+            KeyCode::Tab => editor.insert_char('\t'),
+            KeyCode::Enter => editor.newline(),
+            KeyCode::Backspace => editor.backspace(),
+            KeyCode::Delete => editor.delete(),
+            KeyCode::Left => editor.move_left(),
+            KeyCode::Right => editor.move_right(),
+            KeyCode::Up => editor.move_up(),
+            KeyCode::Down => editor.move_down(),
+            KeyCode::Home => editor.home(),
+            KeyCode::End => editor.end(),
+            _ => {}
+        }
+    }
+
+    /// Maneja input en la vista de drivers
+    fn handle_drivers_input(&mut self, key_code: KeyCode) {
+        match key_code {
+            KeyCode::Down | KeyCode::Char('j') => {
+                self.selected_item =
+                    (self.selected_item + 1).min(self.drivers.len().saturating_sub(1));
+            }
+            KeyCode::Up | KeyCode::Char('k') => {
+                self.selected_item = self.selected_item.saturating_sub(1);
+            }
+            KeyCode::Char('e') | KeyCode::Char('E') => drivers::execute_export_drivers(self),
+            KeyCode::Char('s') | KeyCode::Char('S') => {
+                self.selected_item = 0;
+                drivers::execute_scan_devices(self);
+            }
+            _ => self.handle_operation_input(key_code),
+        }
+    }
+
+    /// Maneja input en la vista de programas de inicio
+    fn handle_startup_input(&mut self, key_code: KeyCode) {
+        match key_code {
+            KeyCode::Down | KeyCode::Char('j') => {
+                self.selected_item =
+                    (self.selected_item + 1).min(self.startup_entries.len().saturating_sub(1));
+            }
+            KeyCode::Up | KeyCode::Char('k') => {
+                self.selected_item = self.selected_item.saturating_sub(1);
+            }
+            KeyCode::Char('e') | KeyCode::Char('E') => {
+                startup::execute_set_startup_enabled(self, true)
+            }
+            KeyCode::Char('d') | KeyCode::Char('D') => {
+                startup::execute_set_startup_enabled(self, false)
+            }
+            KeyCode::Char('r') | KeyCode::Char('R') => {
+                self.selected_item = 0;
+                startup::execute_startup_audit(self);
+            }
+            _ => self.handle_operation_input(key_code),
+        }
+    }
+
+    /// Maneja input en la vista de tareas programadas
+    fn handle_tasks_input(&mut self, key_code: KeyCode) {
+        match key_code {
+            KeyCode::Down | KeyCode::Char('j') => {
+                self.selected_item =
+                    (self.selected_item + 1).min(self.scheduled_tasks.len().saturating_sub(1));
+            }
+            KeyCode::Up | KeyCode::Char('k') => {
+                self.selected_item = self.selected_item.saturating_sub(1);
+            }
+            KeyCode::Char(' ') => tasks::toggle_mark(self, self.selected_item),
+            KeyCode::Char('e') | KeyCode::Char('E') => tasks::execute_set_tasks_enabled(self, true),
+            KeyCode::Char('d') | KeyCode::Char('D') => {
+                tasks::execute_set_tasks_enabled(self, false)
+            }
+            KeyCode::Char('r') | KeyCode::Char('R') => {
+                self.selected_item = 0;
+                tasks::execute_tasks_list(self);
+            }
+            _ => self.handle_operation_input(key_code),
+        }
+    }
+
+    /// Dibuja el menú principal
+    fn draw_main_menu(&mut self, frame: &mut Frame) {
+        let colors = self.get_colors();
+        let main_block = Block::default().style(Style::default().bg(colors.bg_main));
+        frame.render_widget(main_block, frame.area());
+        let uptime_days = self.uptime_reboot_days();
+        let uptime_hint_height = u16::from(uptime_days.is_some());
+
+        let chunks = Layout::default()
+            .direction(Direction::Vertical)
+            .margin(responsive::margin(self.compact))
+            .constraints(if self.compact {
+                // Sin banner: todo el espacio para el menú
+                [
+                    Constraint::Length(0),
+                    Constraint::Min(3),
+                    Constraint::Length(uptime_hint_height),
+                    Constraint::Length(3),
+                ]
+            } else {
+                [
+                    Constraint::Length(13),
+                    Constraint::Min(8),
+                    Constraint::Length(uptime_hint_height),
+                    Constraint::Length(4),
+                ]
+            })
+            .split(self.content_area);
+
+        // Banner moderno con degradado simulado
+        if !self.compact {
+            self.render_modern_banner(frame, chunks[0]);
+        }
+
+        // Menú con diseño moderno
+        self.render_modern_menu(frame, chunks[1]);
+
+        // Recomendación de reiniciar tras muchos días encendido
+        if let Some(days) = uptime_days {
+            let hint = Line::from(vec![
+                Span::raw("💡 ").fg(colors.brand_accent),
+                Span::raw(self.t_args(I18nKey::UptimeRebootHint, &[("count", &days.to_string())]))
+                    .fg(colors.info_color),
+                Span::raw("  ·  ").fg(colors.text_secondary),
+                Span::raw("Ctrl+R").fg(colors.brand_primary).bold(),
+                Span::raw(format!(
+                    " {}",
+                    self.t_args(
+                        I18nKey::RebootSchedule,
+                        &[(
+                            "time",
+                            &utils::format_clock(self.config.reboot.delay().as_secs())
+                        )]
+                    )
+                ))
+                .fg(colors.text_secondary),
+            ]);
+            frame.render_widget(Paragraph::new(hint).alignment(Alignment::Center), chunks[2]);
+        }
+
+        // Footer elegante
+        self.render_modern_footer(frame, chunks[3]);
+    }
+
+    /// Días encendido si el menú principal debe recomendar reiniciar
+    ///
+    /// No se recomienda si ya hay un reinicio necesario o programado: su
+    /// aviso ya ofrece Ctrl+R.
+    fn uptime_reboot_days(&self) -> Option<u64> {
+        if self.needs_reboot || self.reboot_deadline.is_some() {
+            return None;
+        }
+        let (uptime, measured) = self.launch_uptime?;
+        self.config
+            .reboot
+            .long_uptime_days(uptime + measured.elapsed())
+    }
+
+    /// Renderiza un banner moderno y profesional
+    ///
+    /// El logo se compone con letras grandes y se elige la variante que
+    /// quepa en el ancho disponible; la insignia del subtítulo se ajusta al
+    /// texto traducido para que quede centrada en cualquier idioma.
+    fn render_modern_banner(&self, frame: &mut Frame, area: Rect) {
+        let colors = self.get_colors();
+        let max_width = usize::from(area.width);
+
+        // Línea decorativa del ancho del banner (sin salirse del área)
+        let rule_width = max_width.saturating_sub(4).min(54);
+        let rule = |symbol: &str| {
+            Line::from(
+                Span::raw(symbol.repeat(rule_width))
+                    .fg(colors.brand_primary)
+                    .bold(),
+            )
+        };
+
+        let mut banner_lines = vec![rule("▀"), Line::from("")];
+
+        // Logo con degradado simulado de arriba abajo
+        let gradient = [
+            colors.brand_primary,
+            colors.brand_primary,
+            colors.brand_secondary,
+            colors.brand_accent,
+            colors.brand_accent,
+            colors.brand_accent,
+        ];
+        match banner::fit(&["WIN OPT", "WIN"], max_width) {
+            Some(logo) => {
+                banner_lines.extend(
+                    logo.into_iter()
+                        .zip(gradient)
+                        .map(|(row, color)| Line::from(Span::raw(row).fg(color).bold())),
+                );
+            }
+            None => {
+                banner_lines.push(Line::from(
+                    Span::raw("WIN_OPT").fg(colors.brand_primary).bold(),
+                ));
+            }
+        }
+        banner_lines.push(Line::from(""));
+
+        // Subtítulo con badge
+        let subtitle = self.t(I18nKey::AppSubtitle);
+        let version = version::display();
+        let inner_width = format!("  ⚡ {subtitle}  │  {version}  ").width();
+        banner_lines.push(Line::from(
+            Span::raw(format!("╔{}╗", "═".repeat(inner_width))).fg(colors.brand_secondary),
+        ));
+        banner_lines.push(Line::from(vec![
+            Span::raw("║  ").fg(colors.brand_secondary),
+            Span::raw("⚡ ").fg(colors.brand_accent).bold(),
+            Span::raw(subtitle).fg(colors.text_primary).bold(),
+            Span::raw("  ").fg(colors.brand_secondary),
+            Span::raw("│").fg(colors.text_secondary),
+            Span::raw("  ").fg(colors.brand_secondary),
+            Span::raw(version.clone()).fg(colors.info_color).bold(),
+            Span::raw("  ║").fg(colors.brand_secondary),
+        ]));
+        banner_lines.push(Line::from(
+            Span::raw(format!("╚{}╝", "═".repeat(inner_width))).fg(colors.brand_secondary),
+        ));
+        banner_lines.push(self.release_line());
+
+        // Footer decorativo
+        banner_lines.push(rule("▄"));
+
+        let banner_text = Text::from(banner_lines);
+        let banner_widget = Paragraph::new(banner_text).alignment(Alignment::Center);
+        frame.render_widget(banner_widget, area);
+    }
+
+    /// Renderiza el menú con diseño moderno y categorías
+    fn render_modern_menu(&mut self, frame: &mut Frame, area: Rect) {
+        let colors = self.get_colors();
+
+        let mut items: Vec<ListItem> = Vec::new();
+        // Opción del menú de cada fila (`None` en las cabeceras)
+        let mut rows = Vec::new();
+        let mut selected_visual_idx = 0;
+        let mut current_category = None;
+
+        for (idx, item) in MENU_ITEMS.iter().enumerate() {
+            // Cabecera al empezar una nueva categoría
+            if current_category != Some(item.category) {
+                current_category = Some(item.category);
+                let color = match item.category {
+                    MenuCategory::Cleanup => colors.success_color,
+                    MenuCategory::Optimization => colors.warning_color,
+                    MenuCategory::System => colors.info_color,
+                };
+                let content = Line::from(vec![
+                    Span::raw("  "),
+                    Span::raw("▌").fg(color).bold(),
+                    Span::raw(" "),
+                    Span::raw(self.t(item.category.label_key()))
+                        .fg(color)
+                        .bold(),
+                    Span::raw(" "),
+                    Span::raw("━".repeat(45)).fg(color),
+                ]);
+                items.push(
+                    ListItem::new(content).style(Style::default().add_modifier(Modifier::DIM)),
+                );
+                rows.push((None, 1));
+            }
+
+            let is_selected = idx == self.selected_menu_item;
+            if is_selected {
+                selected_visual_idx = items.len();
+            }
+
+            let title = self.t(item.title);
+            let unavailable = self.menu_item_unavailable_reason(idx);
+            let locked = unavailable.is_some() || self.is_menu_item_locked(idx);
+            // Escudo tras el título de las opciones que requieren administrador
+            // y señal de prohibido en las que no funcionan en este sistema
+            let badge = self.menu_item_badge(idx);
+            let desc = if self.compact {
+                // Lo que queda de la fila tras bordes, icono, título y
+                // separador; si apenas cabe nada se omite la descripción
+                let used = 2 + 3 + 2 + 2 + title.width() + badge.width() + 2 + 2;
+                let available = usize::from(area.width).saturating_sub(used);
+                if available < 6 {
+                    String::new()
+                } else {
+                    responsive::truncate(self.t(item.description), available)
+                }
+            } else {
+                self.t(item.description).to_string()
+            };
+            let separator = if desc.is_empty() { "" } else { "│ " };
+
+            let mut content = Text::from(if is_selected {
+                Line::from(vec![
+                    Span::raw(" ▶ ").fg(colors.brand_accent).bold(),
+                    Span::raw(item.icon).fg(colors.brand_accent).bold(),
+                    Span::raw("  "),
+                    Span::raw(title).fg(colors.text_primary).bold(),
+                    Span::raw(badge).fg(colors.warning_color),
+                    Span::raw("  "),
+                    Span::raw(format!("{separator}{desc}"))
+                        .fg(colors.text_primary)
+                        .italic(),
+                ])
+            } else {
+                Line::from(vec![
+                    Span::raw("   "),
+                    Span::raw(item.icon).fg(colors.brand_primary),
+                    Span::raw("  "),
+                    Span::raw(title).fg(colors.text_primary),
+                    Span::raw(badge).fg(colors.warning_color),
+                    Span::raw("  "),
+                    Span::raw(format!("{separator}{desc}"))
+                        .fg(colors.text_secondary)
+                        .italic(),
+                ])
+            });
+
+            // Aviso bajo la opción seleccionada si no se puede ejecutar
+            if is_selected && locked {
+                let reason = unavailable.unwrap_or(I18nKey::MenuAdminRequired);
+                content.push_line(Line::from(vec![
+                    Span::raw("       "),
+                    Span::raw(self.t(reason)).fg(colors.warning_color).italic(),
+                ]));
+            }
+
+            let style = if is_selected {
+                Style::default()
+                    .bg(colors.selection_bg)
+                    .add_modifier(Modifier::BOLD)
+            } else if locked {
+                Style::default().add_modifier(Modifier::DIM)
+            } else {
+                Style::default()
+            };
+
+            let height = content.height();
+            items.push(ListItem::new(content).style(style));
+            rows.push((Some(idx), height));
+        }
+
+        let menu_block = Block::default()
+            .borders(Borders::ALL)
+            .border_style(Style::default().fg(colors.brand_primary))
+            .border_set(symbols::border::ROUNDED)
+            .title(Line::from(vec![
+                Span::raw(" "),
+                Span::raw("◆ ").fg(colors.brand_accent).bold(),
+                Span::raw(format!("{} ", self.t(I18nKey::MainMenu)))
+                    .fg(colors.text_primary)
+                    .bold(),
+            ]))
+            .title_alignment(Alignment::Center);
+
+        // El estado de la lista solo se usa para mantener visible el item
+        // seleccionado cuando el menú no cabe en pantalla
+        let mut list_state = ListState::default().with_selected(Some(selected_visual_idx));
+        let menu_list = List::new(items).block(menu_block);
+        frame.render_stateful_widget(menu_list, area, &mut list_state);
+        self.mouse_targets.list = Some(ListHitbox::new(area, list_state.offset(), rows));
+    }
+
+    /// Dibuja una lista con borde que resalta `selected` y devuelve sus filas
+    /// para el ratón
+    fn render_selectable_list(
+        frame: &mut Frame,
+        area: Rect,
+        items: Vec<ListItem>,
+        block: Block,
+        selected: usize,
+    ) -> ListHitbox {
+        let heights: Vec<usize> = items.iter().map(ListItem::height).collect();
+        let mut state = ListState::default().with_selected(Some(selected));
+        frame.render_stateful_widget(List::new(items).block(block), area, &mut state);
+        ListHitbox::new(
+            area,
+            state.offset(),
+            heights
+                .into_iter()
+                .enumerate()
+                .map(|(idx, h)| (Some(idx), h)),
+        )
+    }
+
+    /// Línea del banner con el estado de la versión nueva (vacía si no hay)
+    fn release_line(&self) -> Line<'static> {
+        let colors = self.get_colors();
+        let Some(release) = &self.new_release else {
+            return Line::from("");
+        };
+        let version = [("version", release.version.as_str())];
+        let (text, color) = match &self.release_download {
+            _ if self.release_download_receiver.is_some() => (
+                format!("⬇️  {}", self.t_args(I18nKey::UpdateDownloading, &version)),
+                colors.info_color,
+            ),
+            Some(Ok(path)) => (
+                format!(
+                    "✅ {}",
+                    self.t_args(
+                        I18nKey::UpdateDownloaded,
+                        &[
+                            ("version", release.version.as_str()),
+                            ("path", &path.display().to_string()),
+                        ]
+                    )
+                ),
+                colors.success_color,
+            ),
+            Some(Err(_)) => (
+                format!(
+                    "❌ {}",
+                    self.t_args(I18nKey::UpdateDownloadFailed, &version)
+                ),
+                colors.error_color,
+            ),
+            None => (
+                format!("✨ {}", self.t_args(I18nKey::UpdateAvailable, &version)),
+                colors.success_color,
+            ),
+        };
+        Line::from(Span::raw(text).fg(color).bold())
+    }
+
+    /// Renderiza un footer moderno
+    fn render_modern_footer(&self, frame: &mut Frame, area: Rect) {
+        let colors = self.get_colors();
+        let mut footer_text = Line::from(vec![
+            Span::raw("  ").fg(colors.brand_accent),
+            Span::raw("↑↓").fg(colors.brand_primary).bold(),
+            Span::raw(format!(" {}  ", self.t(I18nKey::FooterNavigate))).fg(colors.text_secondary),
+            Span::raw("•").fg(colors.brand_accent),
+            Span::raw("  ").fg(colors.brand_accent),
+            Span::raw("Enter").fg(colors.brand_primary).bold(),
+            Span::raw(format!(" {}  ", self.t(I18nKey::FooterSelect))).fg(colors.text_secondary),
+            Span::raw("•").fg(colors.brand_accent),
+            Span::raw("  ").fg(colors.brand_accent),
+            Span::raw("Q/Esc").fg(colors.brand_primary).bold(),
+            Span::raw(format!(" {}  ", self.t(I18nKey::FooterExit))).fg(colors.text_secondary),
+            Span::raw("•").fg(colors.brand_accent),
+            Span::raw("  ").fg(colors.brand_accent),
+            Span::raw("Tab").fg(colors.brand_primary).bold(),
+            Span::raw(format!(" {}  ", self.t(I18nKey::FooterTheme))).fg(colors.text_secondary),
+            Span::raw("•").fg(colors.brand_accent),
+            Span::raw("  ").fg(colors.brand_accent),
+            Span::raw("L").fg(colors.brand_primary).bold(),
+            Span::raw(format!(" {}  ", self.t(I18nKey::FooterLanguage))).fg(colors.text_secondary),
+            Span::raw("•").fg(colors.brand_accent),
+            Span::raw("  ").fg(colors.brand_accent),
+            Span::raw("?").fg(colors.brand_primary).bold(),
+            Span::raw(format!(" {}  ", self.t(I18nKey::FooterHelp))).fg(colors.text_secondary),
+        ]);
+        if self.release_downloadable() {
+            footer_text.extend([
+                Span::raw("•").fg(colors.brand_accent),
+                Span::raw("  ").fg(colors.brand_accent),
+                Span::raw("U").fg(colors.brand_primary).bold(),
+                Span::raw(format!(" {}  ", self.t(I18nKey::FooterUpdate)))
+                    .fg(colors.text_secondary),
+            ]);
+        }
+
+        let footer_block = Block::default()
+            .borders(Borders::ALL)
+            .border_style(Style::default().fg(colors.brand_primary))
+            .border_set(symbols::border::ROUNDED);
+
+        let footer = Paragraph::new(footer_text)
+            .alignment(Alignment::Center)
+            .block(footer_block);
+        frame.render_widget(footer, area);
+    }
+
+    /// Dibuja la vista de limpieza con diseño mejorado
+    fn draw_clean_view(&mut self, frame: &mut Frame) {
+        let colors = self.get_colors();
+        let main_block = Block::default().style(Style::default().bg(colors.bg_main));
+        frame.render_widget(main_block, frame.area());
+        let cards = self.stat_cards();
+
+        let chunks = Layout::default()
+            .direction(Direction::Vertical)
+            .margin(responsive::margin(self.compact))
+            .constraints([
+                Constraint::Length(3),
+                Constraint::Length(self.clean_drives_height()),
+                Constraint::Min(if self.compact { 3 } else { 10 }),
+                Constraint::Length(self.stat_cards_height(&cards)),
+                Constraint::Length(self.clean_locations_height()),
+                Constraint::Length(self.benchmark_height()),
+            ])
+            .split(self.content_area);
+
+        // Título elegante
+        let title_block = Block::default()
+            .borders(Borders::ALL)
+            .border_style(Style::default().fg(colors.brand_primary))
+            .border_set(symbols::border::ROUNDED);
+
+        let title = Paragraph::new(Line::from(vec![
+            Span::raw("🧹 ").fg(colors.brand_accent).bold(),
+            Span::raw(self.t(I18nKey::CleanTitle))
+                .fg(colors.text_primary)
+                .bold(),
+        ]))
+        .alignment(Alignment::Center)
+        .block(title_block);
+        frame.render_widget(title, chunks[0]);
+
+        self.render_clean_drives(frame, chunks[1]);
+
+        // Logs con diseño moderno
+        self.render_styled_logs(frame, chunks[2], self.t(I18nKey::OperationsLog));
+
+        // Estadísticas elegantes
+        self.render_stat_cards(frame, chunks[3], cards);
+
+        self.render_clean_locations(frame, chunks[4]);
+        self.render_benchmark_table(frame, chunks[5]);
+    }
+
+    /// Altura de la lista de unidades y perfiles (0 si no se está eligiendo)
+    fn clean_drives_height(&self) -> u16 {
+        if self.clean_picker_active() {
+            self.clean_picker_rows() as u16 + 2
+        } else {
+            0
+        }
+    }
+
+    /// Renderiza la lista de unidades y perfiles que se pueden limpiar
+    fn render_clean_drives(&mut self, frame: &mut Frame, area: Rect) {
+        if !self.clean_picker_active() {
+            return;
+        }
+        let colors = self.get_colors();
+        let drives = self.clean_picker_drives();
+
+        let mut items: Vec<ListItem> = self.clean_drives[..drives]
+            .iter()
+            .enumerate()
+            .map(|(idx, drive)| {
+                let is_selected = idx == self.selected_item;
+                let free = self.t_args(
+                    I18nKey::CleanDriveFree,
+                    &[
+                        ("free", &self.i18n.format_size(drive.available)),
+                        ("total", &self.i18n.format_size(drive.total)),
+                    ],
+                );
+                let mut spans = vec![
+                    if is_selected {
+                        Span::raw(" ▶ ").fg(colors.brand_accent).bold()
+                    } else {
+                        Span::raw("   ")
+                    },
+                    if drive.selected {
+                        Span::raw("[x] ").fg(colors.success_color).bold()
+                    } else {
+                        Span::raw("[ ] ").fg(colors.text_secondary)
+                    },
+                    Span::raw(format!("💿 {}", drive.name))
+                        .fg(colors.text_primary)
+                        .bold(),
+                ];
+                if drive.system {
+                    spans.push(
+                        Span::raw(format!(" ({})", self.t(I18nKey::CleanDriveSystem)))
+                            .fg(colors.brand_primary),
+                    );
+                }
+                spans.push(Span::raw(format!("  {}", free)).fg(colors.text_secondary));
+
+                let style = if is_selected {
+                    Style::default().bg(colors.selection_bg)
+                } else {
+                    Style::default()
+                };
+                ListItem::new(Line::from(spans)).style(style)
+            })
+            .collect();
+        items.extend(
+            self.clean_profiles
+                .iter()
+                .enumerate()
+                .map(|(idx, profile)| {
+                    let is_selected = drives + idx == self.selected_item;
+                    let spans = vec![
+                        if is_selected {
+                            Span::raw(" ▶ ").fg(colors.brand_accent).bold()
+                        } else {
+                            Span::raw("   ")
+                        },
+                        if profile.selected {
+                            Span::raw("[x] ").fg(colors.success_color).bold()
+                        } else {
+                            Span::raw("[ ] ").fg(colors.text_secondary)
+                        },
+                        Span::raw(format!("👤 {}", profile.name))
+                            .fg(colors.text_primary)
+                            .bold(),
+                        Span::raw(format!("  {}", profile.path.display()))
+                            .fg(colors.text_secondary),
+                    ];
+                    let style = if is_selected {
+                        Style::default().bg(colors.selection_bg)
+                    } else {
+                        Style::default()
+                    };
+                    ListItem::new(Line::from(spans)).style(style)
+                }),
+        );
+
+        let title = match (drives > 0, self.clean_profiles.is_empty()) {
+            (true, true) => I18nKey::CleanDrivesTitle,
+            (true, false) => I18nKey::CleanTargetsTitle,
+            (false, _) => I18nKey::CleanProfilesTitle,
+        };
+        let block = Block::default()
+            .borders(Borders::ALL)
+            .border_style(Style::default().fg(colors.brand_secondary))
+            .border_set(symbols::border::ROUNDED)
+            .title(Line::from(vec![
+                Span::raw(" "),
+                Span::raw(self.t(title)).fg(colors.text_primary).bold(),
+                Span::raw(" "),
+            ]));
+
+        self.mouse_targets.list = Some(Self::render_selectable_list(
+            frame,
+            area,
+            items,
+            block,
+            self.selected_item,
+        ));
+    }
+
+    /// Estadísticas por ubicación de la última limpieza
+    fn clean_locations(&self) -> &[LocationStats] {
+        match &self.stats {
+            Some(OperationStats::Clean(stats)) => &stats.locations,
+            _ => &[],
+        }
+    }
+
+    /// Altura de la tabla por ubicación (0 si no hubo limpieza)
+    fn clean_locations_height(&self) -> u16 {
+        match self.clean_locations().len() {
+            0 => 0,
+            rows => rows as u16 + 3,
+        }
+    }
+
+    /// Renderiza las estadísticas de cada carpeta temporal limpiada
+    fn render_clean_locations(&self, frame: &mut Frame, area: Rect) {
+        let locations = self.clean_locations();
+        if locations.is_empty() {
+            return;
+        }
+        let colors = self.get_colors();
+
+        // Las etiquetas de las tarjetas terminan en dos puntos
+        let column = |key| self.t(key).trim_end_matches(':');
+        let header = Row::new(vec![
+            Cell::from(self.t(I18nKey::CleanLocationColumn)),
+            Cell::from(column(I18nKey::StatsDeleted)),
+            Cell::from(column(I18nKey::StatsSkipped)),
+            Cell::from(column(I18nKey::StatsInUse)),
+            Cell::from(column(I18nKey::StatsFreed)),
+        ])
+        .style(Style::default().fg(colors.text_secondary).bold());
+
+        let rows: Vec<Row> = locations
+            .iter()
+            .map(|stats| {
+                let failed_color = if stats.failed_count > 0 {
+                    colors.warning_color
+                } else {
+                    colors.text_secondary
+                };
+                Row::new(vec![
+                    Cell::from(stats.location.label(&self.i18n)).fg(colors.text_primary),
+                    Cell::from(stats.deleted_count.to_string()).fg(colors.success_color),
+                    Cell::from(stats.failed_count.to_string()).fg(failed_color),
+                    Cell::from(stats.in_use_count.to_string()).fg(colors.text_secondary),
+                    Cell::from(self.i18n.format_size(stats.size_freed)).fg(colors.info_color),
+                ])
+            })
+            .collect();
+
+        let block = Block::default()
+            .borders(Borders::ALL)
+            .border_style(Style::default().fg(colors.info_color))
+            .border_set(symbols::border::ROUNDED)
+            .title(Line::from(vec![
+                Span::raw(" 📁 "),
+                Span::raw(self.t(I18nKey::CleanLocationsTitle))
+                    .fg(colors.text_primary)
+                    .bold(),
+                Span::raw(" "),
+            ]));
+
+        let table = Table::new(
+            rows,
+            [
+                Constraint::Percentage(32),
+                Constraint::Percentage(17),
+                Constraint::Percentage(17),
+                Constraint::Percentage(17),
+                Constraint::Percentage(17),
+            ],
+        )
+        .header(header)
+        .block(block);
+        frame.render_widget(table, area);
+    }
+
+    /// Altura de la tabla comparativa (0 si no hay comparativa)
+    fn benchmark_height(&self) -> u16 {
+        self.benchmark
+            .as_ref()
+            .map(|comparison| comparison.rows().len() as u16 + 3)
+            .unwrap_or(0)
+    }
+
+    /// Renderiza la tabla comparativa antes/después
+    fn render_benchmark_table(&self, frame: &mut Frame, area: Rect) {
+        let Some(comparison) = &self.benchmark else {
+            return;
+        };
+        let colors = self.get_colors();
+
+        let header = Row::new(vec![
+            Cell::from(self.t(I18nKey::BenchmarkMetric)),
+            Cell::from(self.t(I18nKey::BenchmarkBefore)),
+            Cell::from(self.t(I18nKey::BenchmarkAfter)),
+            Cell::from(self.t(I18nKey::BenchmarkDelta)),
+        ])
+        .style(Style::default().fg(colors.text_secondary).bold());
+
+        let rows: Vec<Row> = comparison
+            .rows()
+            .into_iter()
+            .map(|row| {
+                let delta_color = match row.improved() {
+                    Some(true) => colors.success_color,
+                    Some(false) => colors.warning_color,
+                    None => colors.text_secondary,
+                };
+                let language = self.i18n.current_language();
+                Row::new(vec![
+                    Cell::from(self.t(row.metric.label_key())).fg(colors.text_primary),
+                    Cell::from(row.metric.format(row.before, language)).fg(colors.text_secondary),
+                    Cell::from(row.metric.format(row.after, language)).fg(colors.text_primary),
+                    Cell::from(row.metric.format_delta(row.delta(), language))
+                        .fg(delta_color)
+                        .bold(),
+                ])
+            })
+            .collect();
+
+        let block = Block::default()
+            .borders(Borders::ALL)
+            .border_style(Style::default().fg(colors.info_color))
+            .border_set(symbols::border::ROUNDED)
+            .title(Line::from(vec![
+                Span::raw(" 📊 "),
+                Span::raw(self.t(I18nKey::BenchmarkTitle))
+                    .fg(colors.text_primary)
+                    .bold(),
+                Span::raw(" "),
+            ]));
+
+        let table = Table::new(
+            rows,
+            [
+                Constraint::Percentage(40),
+                Constraint::Percentage(20),
+                Constraint::Percentage(20),
+                Constraint::Percentage(20),
+            ],
+        )
+        .header(header)
+        .block(block);
+        frame.render_widget(table, area);
+    }
+
+    /// Tarjetas de estadísticas de la vista actual
+    ///
+    /// Cada tipo de operación muestra sus propias estadísticas. La limpieza
+    /// siempre muestra sus tarjetas; el resto, solo cuando la última
+    /// operación de la vista ha informado de las suyas.
+    fn stat_cards(&self) -> Vec<StatCard> {
+        let colors = self.get_colors();
+        let count = |value: usize| value.to_string();
+        match (self.current_view, &self.stats) {
+            (View::Clean, stats) => {
+                let stats = match stats {
+                    Some(OperationStats::Clean(stats)) => stats.clone(),
+                    _ => CleanStats::default(),
+                };
+                vec![
+                    (
+                        "✅",
+                        count(stats.deleted_count),
+                        I18nKey::StatsDeleted,
+                        colors.success_color,
+                    ),
+                    (
+                        "⚠️",
+                        count(stats.failed_count),
+                        I18nKey::StatsSkipped,
+                        colors.warning_color,
+                    ),
+                    (
+                        "🔒",
+                        count(stats.in_use_count),
+                        I18nKey::StatsInUse,
+                        colors.brand_primary,
+                    ),
+                    (
+                        "💾",
+                        self.i18n.format_size(stats.size_freed),
+                        I18nKey::StatsFreed,
+                        colors.info_color,
+                    ),
+                ]
+            }
+            (View::Repair, Some(OperationStats::Repair(stats))) => {
+                let result = |ok: Option<bool>| match ok {
+                    Some(true) => (self.t(I18nKey::StatsResultOk), colors.success_color),
+                    Some(false) => (self.t(I18nKey::StatsResultFailed), colors.error_color),
+                    None => (self.t(I18nKey::StatsResultPending), colors.text_secondary),
+                };
+                let (dism, dism_color) = result(stats.dism_ok);
+                let (sfc, sfc_color) = result(stats.sfc_ok);
+                let (corruption, corruption_color) =
+                    match (stats.corruption_found, stats.corruption_fixed) {
+                        (false, _) if stats.sfc_ok.is_none() => {
+                            (I18nKey::StatsResultPending, colors.text_secondary)
+                        }
+                        (false, _) => (I18nKey::StatsCorruptionNone, colors.success_color),
+                        (true, true) => (I18nKey::StatsCorruptionFixed, colors.success_color),
+                        (true, false) => (I18nKey::StatsCorruptionUnfixed, colors.error_color),
+                    };
+                vec![
+                    ("🩺", dism.to_string(), I18nKey::StatsDism, dism_color),
+                    ("🛡️", sfc.to_string(), I18nKey::StatsSfc, sfc_color),
+                    (
+                        "🔍",
+                        self.t(corruption).to_string(),
+                        I18nKey::StatsCorruption,
+                        corruption_color,
+                    ),
+                ]
+            }
+            (View::Network, Some(OperationStats::Network(stats))) => vec![
+                (
+                    "✅",
+                    count(stats.succeeded),
+                    I18nKey::StatsStepsSucceeded,
+                    colors.success_color,
+                ),
+                (
+                    "❌",
+                    count(stats.failed),
+                    I18nKey::StatsStepsFailed,
+                    colors.error_color,
+                ),
+                (
+                    "➖",
+                    count(stats.skipped),
+                    I18nKey::StatsStepsSkipped,
+                    colors.text_secondary,
+                ),
+            ],
+            (View::Optimize | View::Privacy, Some(OperationStats::Services(stats))) => vec![
+                (
+                    "🛑",
+                    count(stats.disabled),
+                    I18nKey::StatsServicesDisabled,
+                    colors.success_color,
+                ),
+                (
+                    "⚠️",
+                    count(stats.failed),
+                    I18nKey::StatsServicesFailed,
+                    colors.warning_color,
+                ),
+            ],
+            _ => Vec::new(),
+        }
+    }
+
+    /// Altura de la fila de tarjetas de estadísticas (0 si no hay tarjetas)
+    fn stat_cards_height(&self, cards: &[StatCard]) -> u16 {
+        match (cards.len(), self.compact) {
+            (0, _) => 0,
+            (count, true) => count as u16 + 2,
+            (_, false) => 10,
+        }
+    }
+
+    /// Renderiza las tarjetas de estadísticas con diseño moderno
+    fn render_stat_cards(&self, frame: &mut Frame, area: Rect, cards: Vec<StatCard>) {
+        if cards.is_empty() {
+            return;
+        }
+        if self.compact {
+            self.render_compact_stat_cards(frame, area, cards);
+            return;
+        }
+        let colors = self.get_colors();
+
+        // Layout horizontal con una columna por tarjeta
+        let total = cards.len() as u32;
+        let columns = Layout::default()
+            .direction(Direction::Horizontal)
+            .constraints(cards.iter().map(|_| Constraint::Ratio(1, total)))
+            .split(area);
+
+        for ((icon, value, label, color), column) in cards.into_iter().zip(columns.iter()) {
+            let block = Block::default()
+                .borders(Borders::ALL)
+                .border_style(Style::default().fg(color).bold())
+                .border_set(symbols::border::ROUNDED)
+                .style(Style::default().bg(colors.bg_alt));
+
+            let content = vec![
+                Line::from(""),
+                Line::from(Span::raw(icon).fg(color).bold()),
+                Line::from(""),
+                Line::from(
+                    Span::raw(value)
+                        .fg(color)
+                        .bold()
+                        .add_modifier(Modifier::UNDERLINED),
+                ),
+                Line::from(""),
+                Line::from(Span::raw(self.t(label)).fg(colors.text_secondary).italic()),
+            ];
+
+            let widget = Paragraph::new(content)
+                .block(block)
+                .alignment(Alignment::Center);
+            frame.render_widget(widget, *column);
+        }
+    }
+
+    /// Renderiza las tarjetas de estadísticas apiladas, una por línea, para
+    /// terminales pequeños
+    fn render_compact_stat_cards(&self, frame: &mut Frame, area: Rect, cards: Vec<StatCard>) {
+        let colors = self.get_colors();
+        let lines: Vec<Line> = cards
+            .into_iter()
+            .map(|(icon, value, label, color)| {
+                Line::from(vec![
+                    Span::raw(format!("{icon} ")).fg(color).bold(),
+                    Span::raw(value).fg(color).bold(),
+                    Span::raw(format!(" {}", self.t(label)))
+                        .fg(colors.text_secondary)
+                        .italic(),
+                ])
+            })
+            .collect();
+
+        let block = Block::default()
+            .borders(Borders::ALL)
+            .border_style(Style::default().fg(colors.info_color))
+            .border_set(symbols::border::ROUNDED)
+            .style(Style::default().bg(colors.bg_alt));
+        frame.render_widget(Paragraph::new(lines).block(block), area);
+    }
+
+    /// Dibuja la vista de red con diseño mejorado
+    fn draw_network_view(&mut self, frame: &mut Frame) {
+        let colors = self.get_colors();
+        let main_block = Block::default().style(Style::default().bg(colors.bg_main));
+        frame.render_widget(main_block, frame.area());
+        let cards = self.stat_cards();
+
+        let chunks = Layout::default()
+            .direction(Direction::Vertical)
+            .margin(responsive::margin(self.compact))
+            .constraints([
+                Constraint::Length(3),                                   // Título
+                Constraint::Length(self.network_steps.len() as u16 + 2), // Pasos
+                Constraint::Min(7),                                      // Logs
+                Constraint::Length(self.stat_cards_height(&cards)),      // Estadísticas
+                Constraint::Length(3),                                   // Footer
+            ])
+            .split(self.content_area);
+
+        let title_block = Block::default()
+            .borders(Borders::ALL)
+            .border_style(Style::default().fg(colors.brand_primary))
+            .border_set(symbols::border::ROUNDED);
+
+        let title = Paragraph::new(Line::from(vec![
+            Span::raw("🌐 ").fg(colors.brand_accent).bold(),
+            Span::raw(self.t(I18nKey::NetworkTitle))
+                .fg(colors.text_primary)
+                .bold(),
+        ]))
+        .alignment(Alignment::Center)
+        .block(title_block);
+        frame.render_widget(title, chunks[0]);
+
+        let items: Vec<ListItem> = self
+            .network_steps
+            .iter()
+            .enumerate()
+            .map(|(idx, state)| {
+                let is_selected = idx == self.selected_item;
+                let result = match state.result {
+                    Some(true) => Span::raw(" ✅").fg(colors.success_color),
+                    Some(false) => Span::raw(" ❌").fg(colors.error_color),
+                    None => Span::raw(""),
+                };
+                let line = Line::from(vec![
+                    if is_selected {
+                        Span::raw(" ▶ ").fg(colors.brand_accent).bold()
+                    } else {
+                        Span::raw("   ")
+                    },
+                    if state.enabled {
+                        Span::raw("[x] ").fg(colors.success_color).bold()
+                    } else {
+                        Span::raw("[ ] ").fg(colors.text_secondary)
+                    },
+                    Span::raw(self.t(state.step.label_key())).fg(colors.text_primary),
+                    result,
+                ]);
+
+                let style = if is_selected {
+                    Style::default().bg(colors.selection_bg)
+                } else {
+                    Style::default()
+                };
+
+                ListItem::new(line).style(style)
+            })
+            .collect();
+
+        let steps_block = Block::default()
+            .borders(Borders::ALL)
+            .border_style(Style::default().fg(colors.brand_secondary))
+            .border_set(symbols::border::ROUNDED)
+            .title(Line::from(vec![
+                Span::raw(" "),
+                Span::raw(self.t(I18nKey::NetworkSteps))
+                    .fg(colors.text_primary)
+                    .bold(),
+                Span::raw(" "),
+            ]));
+
+        self.mouse_targets.list = Some(Self::render_selectable_list(
+            frame,
+            chunks[1],
+            items,
+            steps_block,
+            self.selected_item,
+        ));
+
+        self.render_styled_logs(frame, chunks[2], self.t(I18nKey::OperationsLog));
+        self.render_stat_cards(frame, chunks[3], cards);
+
+        self.render_footer_keys(
+            frame,
+            chunks[4],
+            &[
+                ("↑↓", I18nKey::FooterNavigate),
+                ("Space", I18nKey::FooterMark),
+                ("Enter", I18nKey::FooterRun),
+                ("A", I18nKey::FooterRestoreIp),
+                ("Q/Esc", I18nKey::FooterBack),
+            ],
+        );
+    }
+
+    /// Dibuja la vista de reparación con diseño mejorado
+    ///
+    /// Al terminar, bajo las tarjetas de resultados se muestra el
+    /// diagnóstico de DISM y SFC con los pasos recomendados.
+    fn draw_repair_view(&mut self, frame: &mut Frame) {
+        let colors = self.get_colors();
+        let main_block = Block::default().style(Style::default().bg(colors.bg_main));
+        frame.render_widget(main_block, frame.area());
+
+        let show_spinner = self.operation_state == OperationState::Running
+            || self.operation_state == OperationState::Starting;
+        let cards = self.stat_cards();
+        let verdict = match &self.stats {
+            Some(OperationStats::Repair(stats)) if !show_spinner => repair::verdict(stats),
+            _ => None,
+        };
+        let verdict_height = verdict
+            .as_ref()
+            .map_or(0, |verdict| verdict.steps.len() as u16 + 4);
+        let cbs_lines = if show_spinner {
+            0
+        } else {
+            self.cbs_excerpt().len()
+        };
+        let cbs_height = match (cbs_lines, self.cbs_expanded) {
+            (0, _) => 0,
+            (_, false) => 1,
+            (lines, true) => lines.min(CBS_VISIBLE_LINES) as u16 + 2,
+        };
+
+        let chunks = Layout::default()
+            .direction(Direction::Vertical)
+            .margin(responsive::margin(self.compact))
+            .constraints([
+                Constraint::Length(3),                                // Título
+                Constraint::Length(if show_spinner { 3 } else { 0 }), // Spinner
+                Constraint::Min(5),                                   // Logs
+                Constraint::Length(self.stat_cards_height(&cards)),   // Estadísticas
+                Constraint::Length(verdict_height),                   // Diagnóstico
+                Constraint::Length(cbs_height),                       // CBS.log
+                Constraint::Length(3),                                // Footer
+            ])
+            .split(self.content_area);
+
+        let title_block = Block::default()
+            .borders(Borders::ALL)
+            .border_style(Style::default().fg(colors.brand_primary))
+            .border_set(symbols::border::ROUNDED);
+
+        let title = Paragraph::new(Line::from(vec![
+            Span::raw("🔧 ").fg(colors.brand_accent).bold(),
+            Span::raw(self.t(I18nKey::RepairTitle))
+                .fg(colors.text_primary)
+                .bold(),
+        ]))
+        .alignment(Alignment::Center)
+        .block(title_block);
+        frame.render_widget(title, chunks[0]);
+
+        if show_spinner {
+            self.render_spinner(frame, chunks[1]);
+        }
+        self.render_styled_logs(frame, chunks[2], self.t(I18nKey::OperationsLog));
+        self.render_stat_cards(frame, chunks[3], cards);
+        if let Some(verdict) = verdict {
+            self.render_repair_verdict(frame, chunks[4], &verdict);
+        }
+        if cbs_lines > 0 {
+            self.render_cbs_excerpt(frame, chunks[5]);
+            self.render_operation_footer(frame, chunks[6], &[("C", I18nKey::FooterCbsLog)]);
+        } else {
+            self.render_operation_footer(frame, chunks[6], &[]);
+        }
+    }
+
+    /// Renderiza la sección plegable con las líneas `[SR]` de `CBS.log`
+    ///
+    /// Plegada solo muestra el título con el número de líneas; desplegada
+    /// muestra las últimas líneas que caben.
+    fn render_cbs_excerpt(&self, frame: &mut Frame, area: Rect) {
+        let colors = self.get_colors();
+        let excerpt = self.cbs_excerpt();
+        let count = excerpt.len().to_string();
+
+        let block = Block::default()
+            .borders(if self.cbs_expanded {
+                Borders::ALL
+            } else {
+                Borders::TOP
+            })
+            .border_style(Style::default().fg(colors.brand_secondary))
+            .border_set(symbols::border::ROUNDED)
+            .title(Line::from(vec![
+                Span::raw(if self.cbs_expanded { " ▼ " } else { " ▶ " }).fg(colors.brand_accent),
+                Span::raw(self.t(I18nKey::RepairCbsTitle))
+                    .fg(colors.text_primary)
+                    .bold(),
+                Span::raw(format!(
+                    " ({}) ",
+                    self.t_args(I18nKey::RepairCbsLines, &[("count", &count)])
+                ))
+                .fg(colors.text_secondary),
+            ]));
+
+        let visible = (area.height as usize).saturating_sub(2);
+        let lines: Vec<Line> = excerpt[excerpt.len().saturating_sub(visible)..]
+            .iter()
+            .map(|line| {
+                let color = if line.contains("Cannot repair") {
+                    colors.error_color
+                } else if line.contains("Repairing") || line.contains("Repaired") {
+                    colors.success_color
+                } else {
+                    colors.text_secondary
+                };
+                Line::from(Span::raw(line.as_str()).fg(color))
+            })
+            .collect();
+        frame.render_widget(Paragraph::new(lines).block(block), area);
+    }
+
+    /// Renderiza el diagnóstico de la reparación con los pasos recomendados
+    fn render_repair_verdict(&self, frame: &mut Frame, area: Rect, verdict: &Verdict) {
+        let colors = self.get_colors();
+        let (icon, color) = match verdict.level {
+            VerdictLevel::Ok => ("✅", colors.success_color),
+            VerdictLevel::Warning => ("⚠️", colors.warning_color),
+            VerdictLevel::Error => ("❌", colors.error_color),
+        };
+        let code = verdict.code.map(repair::format_code).unwrap_or_default();
+
+        let mut lines = vec![
+            Line::from(vec![
+                Span::raw(format!("{icon} ")).fg(color).bold(),
+                Span::raw(self.t_args(verdict.summary, &[("code", &code)]))
+                    .fg(color)
+                    .bold(),
+            ]),
+            Line::from(
+                Span::raw(self.t(I18nKey::RepairNextSteps))
+                    .fg(colors.text_secondary)
+                    .italic(),
+            ),
+        ];
+        lines.extend(verdict.steps.iter().enumerate().map(|(idx, step)| {
+            Line::from(vec![
+                Span::raw(format!("  {}. ", idx + 1)).fg(colors.brand_accent),
+                Span::raw(self.t(*step)).fg(colors.text_primary),
+            ])
+        }));
+
+        let block = Block::default()
+            .borders(Borders::ALL)
+            .border_style(Style::default().fg(color))
+            .border_set(symbols::border::ROUNDED)
+            .title(Line::from(vec![
+                Span::raw(" 🩺 "),
+                Span::raw(self.t(I18nKey::RepairVerdictTitle))
+                    .fg(colors.text_primary)
+                    .bold(),
+                Span::raw(" "),
+            ]));
+        frame.render_widget(
+            Paragraph::new(lines)
+                .block(block)
+                .wrap(Wrap { trim: false }),
+            area,
+        );
+    }
+
+    /// Dibuja la vista de optimización
+    fn draw_optimize_view(&mut self, frame: &mut Frame) {
+        if self.benchmark.is_none() {
+            self.draw_operation_view_with_keys(
+                frame,
+                "⚡",
+                "Optimización Avanzada",
+                SERVICES_FOOTER_KEYS,
+            );
+            return;
+        }
+
+        let colors = self.get_colors();
+        let main_block = Block::default().style(Style::default().bg(colors.bg_main));
+        frame.render_widget(main_block, frame.area());
+        let cards = self.stat_cards();
+
+        let chunks = Layout::default()
+            .direction(Direction::Vertical)
+            .margin(responsive::margin(self.compact))
+            .constraints([
+                Constraint::Length(3),                              // Título
+                Constraint::Min(7),                                 // Logs
+                Constraint::Length(self.stat_cards_height(&cards)), // Estadísticas
+                Constraint::Length(self.benchmark_height()),        // Comparativa
+                Constraint::Length(3),                              // Footer
+            ])
+            .split(self.content_area);
+
+        let title_block = Block::default()
+            .borders(Borders::ALL)
+            .border_style(Style::default().fg(colors.brand_primary))
+            .border_set(symbols::border::ROUNDED);
+
+        let title = Paragraph::new(Line::from(vec![
+            Span::raw("⚡ ").fg(colors.brand_accent).bold(),
+            Span::raw(self.t(I18nKey::OptimizeTitle))
+                .fg(colors.text_primary)
+                .bold(),
+        ]))
+        .alignment(Alignment::Center)
+        .block(title_block);
+        frame.render_widget(title, chunks[0]);
+
+        self.render_styled_logs(frame, chunks[1], self.t(I18nKey::OperationsLog));
+        self.render_stat_cards(frame, chunks[2], cards);
+        self.render_benchmark_table(frame, chunks[3]);
+        self.render_operation_footer(frame, chunks[4], SERVICES_FOOTER_KEYS);
+    }
+
+    /// Dibuja la vista de Windows Update cleanup
+    fn draw_windows_update_view(&mut self, frame: &mut Frame) {
+        let colors = self.get_colors();
+        let main_block = Block::default().style(Style::default().bg(colors.bg_main));
+        frame.render_widget(main_block, frame.area());
+
+        let show_spinner = self.operation_state == OperationState::Running
+            || self.operation_state == OperationState::Starting;
+
+        let chunks = Layout::default()
+            .direction(Direction::Vertical)
+            .margin(responsive::margin(self.compact))
+            .constraints([
+                Constraint::Length(3),                                // Título
+                Constraint::Length(8),                                // Estado
+                Constraint::Length(if show_spinner { 3 } else { 0 }), // Spinner
+                Constraint::Min(5),                                   // Logs
+                Constraint::Length(3),                                // Footer
+            ])
+            .split(self.content_area);
+
+        let title_block = Block::default()
+            .borders(Borders::ALL)
+            .border_style(Style::default().fg(colors.brand_primary))
+            .border_set(symbols::border::ROUNDED);
+
+        let title = Paragraph::new(Line::from(vec![
+            Span::raw("🔄 ").fg(colors.brand_accent).bold(),
+            Span::raw(self.t(I18nKey::WindowsUpdateTitle))
+                .fg(colors.text_primary)
+                .bold(),
+        ]))
+        .alignment(Alignment::Center)
+        .block(title_block);
+        frame.render_widget(title, chunks[0]);
+
+        self.render_update_status(frame, chunks[1]);
+
+        if show_spinner {
+            self.render_spinner(frame, chunks[2]);
+        }
+
+        self.render_styled_logs(frame, chunks[3], self.t(I18nKey::OperationsLog));
+
+        let view_keys: &[(&str, I18nKey)] = if self.update_status_receiver.is_none() {
+            &[("S", I18nKey::FooterScanUpdates)]
+        } else {
+            &[]
+        };
+        self.render_operation_footer(frame, chunks[4], view_keys);
+    }
+
+    /// Renderiza el panel de estado de Windows Update (pendientes y última instalación)
+    fn render_update_status(&self, frame: &mut Frame, area: Rect) {
+        let colors = self.get_colors();
+        // Filas disponibles para títulos: alto menos bordes y las dos líneas de resumen
+        let max_titles = area.height.saturating_sub(4) as usize;
+
+        let lines: Vec<Line> = match &self.update_status {
+            None => vec![Line::from(vec![
+                Span::raw("  "),
+                Span::raw(self.spinner.frame())
+                    .fg(colors.brand_accent)
+                    .bold(),
+                Span::raw(format!(" {}", self.t(I18nKey::WindowsUpdateChecking)))
+                    .fg(colors.text_secondary),
+            ])],
+            Some(Err(_)) => vec![Line::from(vec![
+                Span::raw("  "),
+                Span::raw(format!("❌ {}", self.t(I18nKey::WindowsUpdateStatusError)))
+                    .fg(colors.error_color),
+            ])],
+            Some(Ok(status)) => {
+                let mut lines = Vec::new();
+
+                if status.pending.is_empty() {
+                    lines.push(Line::from(vec![
+                        Span::raw("  "),
+                        Span::raw(format!("✅ {}", self.t(I18nKey::WindowsUpdateUpToDate)))
+                            .fg(colors.success_color)
+                            .bold(),
+                    ]));
+                } else {
+                    lines.push(Line::from(vec![
+                        Span::raw("  "),
+                        Span::raw(format!("{} ", self.t(I18nKey::WindowsUpdatePending)))
+                            .fg(colors.text_secondary),
+                        Span::raw(status.pending.len().to_string())
+                            .fg(colors.warning_color)
+                            .bold(),
+                    ]));
+                }
+
+                lines.push(Line::from(vec![
+                    Span::raw("  "),
+                    Span::raw(format!("{} ", self.t(I18nKey::WindowsUpdateLastInstall)))
+                        .fg(colors.text_secondary),
+                    Span::raw(
+                        status
+                            .last_install
+                            .as_deref()
+                            .map(|date| self.i18n.format_date(date))
+                            .unwrap_or_else(|| self.t(I18nKey::WindowsUpdateUnknown).to_string()),
+                    )
+                    .fg(colors.text_primary)
+                    .bold(),
+                ]));
+
+                let shown = if status.pending.len() > max_titles {
+                    max_titles.saturating_sub(1)
+                } else {
+                    status.pending.len()
+                };
+                for title in status.pending.iter().take(shown) {
+                    lines.push(Line::from(vec![
+                        Span::raw("    • ").fg(colors.brand_accent),
+                        Span::raw(title.as_str()).fg(colors.text_primary),
+                    ]));
+                }
+                if shown < status.pending.len() {
+                    lines.push(Line::from(
+                        Span::raw(format!(
+                            "    {}",
+                            self.t_args(
+                                I18nKey::WindowsUpdateMore,
+                                &[("count", &(status.pending.len() - shown).to_string())]
+                            )
+                        ))
+                        .fg(colors.text_secondary)
+                        .italic(),
+                    ));
+                }
+
+                lines
+            }
+        };
+
+        let status_block = Block::default()
+            .borders(Borders::ALL)
+            .border_style(Style::default().fg(colors.brand_secondary))
+            .border_set(symbols::border::ROUNDED)
+            .title(Line::from(vec![
+                Span::raw(" "),
+                Span::raw("📦 ").fg(colors.brand_accent),
+                Span::raw(format!("{} ", self.t(I18nKey::WindowsUpdateStatus)))
+                    .fg(colors.text_primary)
+                    .bold(),
+            ]));
+
+        frame.render_widget(Paragraph::new(lines).block(status_block), area);
+    }
+
+    /// Dibuja la vista de privacidad
+    fn draw_privacy_view(&mut self, frame: &mut Frame) {
+        self.draw_operation_view_with_keys(
+            frame,
+            "🔒",
+            "Privacidad y Telemetría",
+            SERVICES_FOOTER_KEYS,
+        );
+    }
+
+    /// Dibuja la vista de limpieza de caché de navegadores
+    fn draw_browser_cache_view(&mut self, frame: &mut Frame) {
+        self.draw_generic_operation_view(frame, "🌐", "Caché de Navegadores");
+    }
+
+    /// Dibuja la vista de limpieza de logs del sistema
+    fn draw_system_logs_view(&mut self, frame: &mut Frame) {
+        self.draw_generic_operation_view(frame, "📋", "Logs del Sistema");
+    }
+
+    /// Dibuja la vista de vaciado de papelera de reciclaje
+    fn draw_recycle_bin_view(&mut self, frame: &mut Frame) {
+        self.draw_generic_operation_view(frame, "🗑️", "Papelera de Reciclaje");
+    }
+
+    /// Dibuja la vista de programas de inicio, con una cabecera por origen
+    fn draw_startup_optimizer_view(&mut self, frame: &mut Frame) {
+        let colors = self.get_colors();
+        let main_block = Block::default().style(Style::default().bg(colors.bg_main));
+        frame.render_widget(main_block, frame.area());
+
+        let chunks = Layout::default()
+            .direction(Direction::Vertical)
+            .margin(responsive::margin(self.compact))
+            .constraints([
+                Constraint::Length(3), // Título
+                Constraint::Min(6),    // Entradas
+                Constraint::Length(7), // Logs
+                Constraint::Length(3), // Footer
+            ])
+            .split(self.content_area);
+
+        let title_block = Block::default()
+            .borders(Borders::ALL)
+            .border_style(Style::default().fg(colors.brand_primary))
+            .border_set(symbols::border::ROUNDED);
+
+        let enabled = self.startup_entries.iter().filter(|e| e.enabled).count();
+        let title = Paragraph::new(Line::from(vec![
+            Span::raw("🚀 ").fg(colors.brand_accent).bold(),
+            Span::raw(self.t(I18nKey::StartupTitle))
+                .fg(colors.text_primary)
+                .bold(),
+            Span::raw(format!(
+                " ({})",
+                self.t_args(
+                    I18nKey::StartupEnabledCount,
+                    &[
+                        ("enabled", &enabled.to_string()),
+                        ("count", &self.startup_entries.len().to_string())
+                    ],
+                )
+            ))
+            .fg(colors.text_secondary),
+        ]))
+        .alignment(Alignment::Center)
+        .block(title_block);
+        frame.render_widget(title, chunks[0]);
+
+        const NAME_WIDTH: usize = 30;
+        let mut items: Vec<ListItem> = Vec::new();
+        let mut rows: Vec<(Option<usize>, usize)> = Vec::new();
+        let mut selected_visual_idx = 0;
+        if !self.startup_entries.is_empty() {
+            items.push(ListItem::new(Line::from(
+                Span::raw(format!(
+                    "   {:<NAME_WIDTH$}  {:<15}{:<9}{}",
+                    self.t(I18nKey::StartupColumnEntry),
+                    self.t(I18nKey::StartupColumnState),
+                    self.t(I18nKey::StartupColumnImpact),
+                    self.t(I18nKey::StartupColumnCommand),
+                ))
+                .fg(colors.text_secondary)
+                .bold(),
+            )));
+            rows.push((None, 1));
+        }
+        for (idx, entry) in self.startup_entries.iter().enumerate() {
+            // Cabecera del origen al empezar su grupo
+            if idx == 0 || self.startup_entries[idx - 1].source != entry.source {
+                let count = self.startup_entries[idx..]
+                    .iter()
+                    .take_while(|other| other.source == entry.source)
+                    .count();
+                let mut header = vec![
+                    Span::raw(" ▸ ").fg(colors.brand_accent),
+                    Span::raw(self.t(entry.source.label_key()))
+                        .fg(colors.brand_primary)
+                        .bold(),
+                    Span::raw(format!(" ({})", count)).fg(colors.text_secondary),
+                ];
+                if let Some(key) = entry.source.run_key() {
+                    header.push(Span::raw(format!("  {}", key)).fg(colors.text_secondary));
+                }
+                items.push(ListItem::new(Line::from(header)));
+                rows.push((None, 1));
+            }
+
+            let is_selected = idx == self.selected_item;
+            if is_selected {
+                selected_visual_idx = items.len();
+            }
+            let (state_key, state_color) = if entry.enabled {
+                (I18nKey::StartupStateEnabled, colors.success_color)
+            } else {
+                (I18nKey::StartupStateDisabled, colors.warning_color)
+            };
+            let impact_color = match entry.impact {
+                Impact::High => colors.error_color,
+                Impact::Medium => colors.warning_color,
+                Impact::Low => colors.text_secondary,
+            };
+            let line = Line::from(vec![
+                if is_selected {
+                    Span::raw(" ▶ ").fg(colors.brand_accent).bold()
+                } else {
+                    Span::raw("   ")
+                },
+                Span::raw(format!(
+                    "{:<NAME_WIDTH$}  ",
+                    responsive::truncate(&entry.name, NAME_WIDTH)
+                ))
+                .fg(colors.text_primary),
+                Span::raw(format!("{:<15}", self.t(state_key))).fg(state_color),
+                Span::raw(format!("{:<9}", self.t(entry.impact.label_key()))).fg(impact_color),
+                if entry.missing {
+                    Span::raw(format!("⚠ {}", entry.command)).fg(colors.error_color)
+                } else {
+                    Span::raw(entry.command.as_str())
+                        .fg(colors.text_secondary)
+                        .italic()
+                },
+            ]);
+
+            let style = if is_selected {
+                Style::default().bg(colors.selection_bg)
+            } else {
+                Style::default()
+            };
+            items.push(ListItem::new(line).style(style));
+            rows.push((Some(idx), 1));
+        }
+
+        let entries_block = Block::default()
+            .borders(Borders::ALL)
+            .border_style(Style::default().fg(colors.brand_secondary))
+            .border_set(symbols::border::ROUNDED);
+
+        let mut list_state = ListState::default().with_selected(Some(selected_visual_idx));
+        frame.render_stateful_widget(
+            List::new(items).block(entries_block),
+            chunks[1],
+            &mut list_state,
+        );
+        self.mouse_targets.list = Some(ListHitbox::new(chunks[1], list_state.offset(), rows));
+
+        self.render_styled_logs(frame, chunks[2], self.t(I18nKey::OperationsLog));
+
+        self.render_footer_keys(
+            frame,
+            chunks[3],
+            &[
+                ("↑↓", I18nKey::FooterNavigate),
+                ("E", I18nKey::FooterEnable),
+                ("D", I18nKey::FooterDisable),
+                ("R", I18nKey::FooterRefresh),
+                ("Q/Esc", I18nKey::FooterBack),
+            ],
+        );
+    }
+
+    /// Dibuja la vista de efectos visuales
+    fn draw_visual_effects_view(&mut self, frame: &mut Frame) {
+        self.draw_generic_operation_view(frame, "🎨", "Efectos Visuales");
+    }
+
+    /// Dibuja la vista de optimización de memoria
+    fn draw_memory_view(&mut self, frame: &mut Frame) {
+        let title = self.t(I18nKey::MemoryTitle).to_string();
+        self.draw_operation_view_with_keys(
+            frame,
+            "🧠",
+            &title,
+            &[("D", I18nKey::FooterMemoryDiagnostic)],
+        );
+    }
+
+    /// Dibuja la vista de perfiles de optimización
+    fn draw_profiles_view(&mut self, frame: &mut Frame) {
+        let colors = self.get_colors();
+        let main_block = Block::default().style(Style::default().bg(colors.bg_main));
+        frame.render_widget(main_block, frame.area());
+
+        let chunks = Layout::default()
+            .direction(Direction::Vertical)
+            .margin(responsive::margin(self.compact))
+            .constraints([
+                Constraint::Length(3),                                 // Título
+                Constraint::Length(Profile::ALL.len() as u16 * 2 + 2), // Perfiles
+                Constraint::Min(7),                                    // Logs
+                Constraint::Length(3),                                 // Footer
+            ])
+            .split(self.content_area);
+
+        let title_block = Block::default()
+            .borders(Borders::ALL)
+            .border_style(Style::default().fg(colors.brand_primary))
+            .border_set(symbols::border::ROUNDED);
+
+        let title = Paragraph::new(Line::from(vec![
+            Span::raw("🎮 ").fg(colors.brand_accent).bold(),
+            Span::raw(self.t(I18nKey::ProfilesTitle))
+                .fg(colors.text_primary)
+                .bold(),
+        ]))
+        .alignment(Alignment::Center)
+        .block(title_block);
+        frame.render_widget(title, chunks[0]);
+
+        let items: Vec<ListItem> = Profile::ALL
+            .iter()
+            .enumerate()
+            .map(|(idx, &profile)| {
+                let is_selected = idx == self.selected_item;
+
+                let mut header = vec![
+                    if is_selected {
+                        Span::raw(" ▶ ").fg(colors.brand_accent).bold()
+                    } else {
+                        Span::raw("   ")
+                    },
+                    Span::raw(profile.icon()),
+                    Span::raw("  "),
+                    Span::raw(self.t(profile.name_key()))
+                        .fg(colors.text_primary)
+                        .bold(),
+                ];
+                if self.active_profile == Some(profile) {
+                    header.push(
+                        Span::raw(format!("  ● {}", self.t(I18nKey::ProfilesActive)))
+                            .fg(colors.success_color)
+                            .bold(),
+                    );
+                }
+                if self.config.profiles.default_profile == Some(profile) {
+                    header.push(
+                        Span::raw(format!("  ★ {}", self.t(I18nKey::ProfilesDefault)))
+                            .fg(colors.info_color),
+                    );
+                }
+
+                let description = Line::from(vec![
+                    Span::raw("       "),
+                    Span::raw(self.t(profile.description_key()))
+                        .fg(colors.text_secondary)
+                        .italic(),
+                ]);
+
+                let style = if is_selected {
+                    Style::default().bg(colors.selection_bg)
+                } else {
+                    Style::default()
+                };
+
+                ListItem::new(vec![Line::from(header), description]).style(style)
+            })
+            .collect();
+
+        let profiles_block = Block::default()
+            .borders(Borders::ALL)
+            .border_style(Style::default().fg(colors.brand_secondary))
+            .border_set(symbols::border::ROUNDED);
+        self.mouse_targets.list = Some(Self::render_selectable_list(
+            frame,
+            chunks[1],
+            items,
+            profiles_block,
+            self.selected_item,
+        ));
+
+        self.render_styled_logs(frame, chunks[2], self.t(I18nKey::OperationsLog));
+
+        self.render_footer_keys(
+            frame,
+            chunks[3],
+            &[
+                ("↑↓", I18nKey::FooterNavigate),
+                ("Enter", I18nKey::FooterApply),
+                ("R", I18nKey::FooterRevert),
+                ("Q/Esc", I18nKey::FooterBack),
+            ],
+        );
+    }
+
+    /// Dibuja la vista de drivers instalados
+    fn draw_drivers_view(&mut self, frame: &mut Frame) {
+        let colors = self.get_colors();
+        let main_block = Block::default().style(Style::default().bg(colors.bg_main));
+        frame.render_widget(main_block, frame.area());
+
+        let chunks = Layout::default()
+            .direction(Direction::Vertical)
+            .margin(responsive::margin(self.compact))
+            .constraints([
+                Constraint::Length(3), // Título
+                Constraint::Min(6),    // Drivers
+                Constraint::Length(7), // Logs
+                Constraint::Length(3), // Footer
+            ])
+            .split(self.content_area);
+
+        let title_block = Block::default()
+            .borders(Borders::ALL)
+            .border_style(Style::default().fg(colors.brand_primary))
+            .border_set(symbols::border::ROUNDED);
+
+        let title = Paragraph::new(Line::from(vec![
+            Span::raw("🧩 ").fg(colors.brand_accent).bold(),
+            Span::raw(self.t(I18nKey::DriversTitle))
+                .fg(colors.text_primary)
+                .bold(),
+            Span::raw(format!(" ({})", self.drivers.len())).fg(colors.text_secondary),
+        ]))
+        .alignment(Alignment::Center)
+        .block(title_block);
+        frame.render_widget(title, chunks[0]);
+
+        let year = drivers::current_year();
+        let items: Vec<ListItem> = self
+            .drivers
+            .iter()
+            .enumerate()
+            .map(|(idx, driver)| {
+                let is_selected = idx == self.selected_item;
+                let outdated = driver.is_outdated(year);
+                let date = if driver.date.is_empty() {
+                    self.t(I18nKey::DriversNoDate).to_string()
+                } else {
+                    self.i18n.format_date(&driver.date)
+                };
+
+                let mut spans = vec![
+                    if is_selected {
+                        Span::raw(" ▶ ").fg(colors.brand_accent).bold()
+                    } else {
+                        Span::raw("   ")
+                    },
+                    Span::raw(format!("{:<10}  ", date)).fg(if outdated {
+                        colors.warning_color
+                    } else {
+                        colors.text_secondary
+                    }),
+                    Span::raw(driver.device_name.as_str())
+                        .fg(colors.text_primary)
+                        .bold(),
+                    Span::raw(format!("  {} {}", driver.provider, driver.version))
+                        .fg(colors.text_secondary)
+                        .italic(),
+                ];
+                if outdated {
+                    spans.push(
+                        Span::raw(format!("  ⚠ {}", self.t(I18nKey::DriversOutdated)))
+                            .fg(colors.warning_color)
+                            .bold(),
+                    );
+                }
+
+                let style = if is_selected {
+                    Style::default().bg(colors.selection_bg)
+                } else {
+                    Style::default()
+                };
+
+                ListItem::new(Line::from(spans)).style(style)
+            })
+            .collect();
+
+        let drivers_block = Block::default()
+            .borders(Borders::ALL)
+            .border_style(Style::default().fg(colors.brand_secondary))
+            .border_set(symbols::border::ROUNDED);
+
+        self.mouse_targets.list = Some(Self::render_selectable_list(
+            frame,
+            chunks[1],
+            items,
+            drivers_block,
+            self.selected_item,
+        ));
+
+        self.render_styled_logs(frame, chunks[2], self.t(I18nKey::OperationsLog));
+
+        self.render_footer_keys(
+            frame,
+            chunks[3],
+            &[
+                ("↑↓", I18nKey::FooterNavigate),
+                ("E", I18nKey::FooterExport),
+                ("S", I18nKey::FooterScanDevices),
+                ("Q/Esc", I18nKey::FooterBack),
+            ],
+        );
+    }
+
+    /// Dibuja la vista de tareas programadas, con una cabecera por fabricante
+    fn draw_tasks_view(&mut self, frame: &mut Frame) {
+        let colors = self.get_colors();
+        let main_block = Block::default().style(Style::default().bg(colors.bg_main));
+        frame.render_widget(main_block, frame.area());
+
+        let chunks = Layout::default()
+            .direction(Direction::Vertical)
+            .margin(responsive::margin(self.compact))
+            .constraints([
+                Constraint::Length(3), // Título
+                Constraint::Min(6),    // Tareas
+                Constraint::Length(7), // Logs
+                Constraint::Length(3), // Footer
+            ])
+            .split(self.content_area);
+
+        let title_block = Block::default()
+            .borders(Borders::ALL)
+            .border_style(Style::default().fg(colors.brand_primary))
+            .border_set(symbols::border::ROUNDED);
+
+        let marked = self.scheduled_tasks.iter().filter(|t| t.marked).count();
+        let title = Paragraph::new(Line::from(vec![
+            Span::raw("📅 ").fg(colors.brand_accent).bold(),
+            Span::raw(self.t(I18nKey::TasksTitle))
+                .fg(colors.text_primary)
+                .bold(),
+            Span::raw(format!(
+                " ({}, {})",
+                self.scheduled_tasks.len(),
+                self.t_args(I18nKey::TasksMarked, &[("count", &marked.to_string())])
+            ))
+            .fg(colors.text_secondary),
+        ]))
+        .alignment(Alignment::Center)
+        .block(title_block);
+        frame.render_widget(title, chunks[0]);
+
+        const NAME_WIDTH: usize = 32;
+        let mut items: Vec<ListItem> = Vec::new();
+        let mut rows: Vec<(Option<usize>, usize)> = Vec::new();
+        let mut selected_visual_idx = 0;
+        if !self.scheduled_tasks.is_empty() {
+            items.push(ListItem::new(Line::from(
+                Span::raw(format!(
+                    "       {:<NAME_WIDTH$}  {:<15}{:<18}{:<18}{}",
+                    self.t(I18nKey::TasksColumnTask),
+                    self.t(I18nKey::TasksColumnState),
+                    self.t(I18nKey::TasksLastRun),
+                    self.t(I18nKey::TasksNextRun),
+                    self.t(I18nKey::TasksColumnAuthor),
+                ))
+                .fg(colors.text_secondary)
+                .bold(),
+            )));
+            rows.push((None, 1));
+        }
+        let never = self.t(I18nKey::TasksNever);
+        for (idx, task) in self.scheduled_tasks.iter().enumerate() {
+            // Cabecera del fabricante al empezar su grupo
+            if idx == 0 || self.scheduled_tasks[idx - 1].vendor != task.vendor {
+                let count = self.scheduled_tasks[idx..]
+                    .iter()
+                    .take_while(|other| other.vendor == task.vendor)
+                    .count();
+                let vendor = if task.vendor.is_empty() {
+                    self.t(I18nKey::TasksVendorUnknown)
+                } else {
+                    task.vendor.as_str()
+                };
+                items.push(ListItem::new(Line::from(vec![
+                    Span::raw(" ▸ ").fg(colors.brand_accent),
+                    Span::raw(vendor).fg(colors.brand_primary).bold(),
+                    Span::raw(format!(" ({})", count)).fg(colors.text_secondary),
+                ])));
+                rows.push((None, 1));
+            }
+
+            let is_selected = idx == self.selected_item;
+            if is_selected {
+                selected_visual_idx = items.len();
+            }
+            let state_color = match task.state {
+                TaskState::Ready => colors.success_color,
+                TaskState::Running | TaskState::Queued => colors.info_color,
+                TaskState::Disabled => colors.warning_color,
+                TaskState::Unknown => colors.text_secondary,
+            };
+            let line = Line::from(vec![
+                if is_selected {
+                    Span::raw(" ▶ ").fg(colors.brand_accent).bold()
+                } else {
+                    Span::raw("   ")
+                },
+                if task.marked {
+                    Span::raw("[x] ").fg(colors.warning_color).bold()
+                } else {
+                    Span::raw("[ ] ").fg(colors.text_secondary)
+                },
+                Span::raw(format!(
+                    "{:<NAME_WIDTH$}  ",
+                    responsive::truncate(task.name(), NAME_WIDTH)
+                ))
+                .fg(colors.text_primary),
+                Span::raw(format!("{:<15}", self.t(task.state.label_key()))).fg(state_color),
+                Span::raw(format!("{:<18}", task.last_run.as_deref().unwrap_or(never)))
+                    .fg(colors.text_secondary),
+                Span::raw(format!("{:<18}", task.next_run.as_deref().unwrap_or(never)))
+                    .fg(colors.info_color),
+                Span::raw(task.author.as_str())
+                    .fg(colors.text_secondary)
+                    .italic(),
+            ]);
+
+            let style = if is_selected {
+                Style::default().bg(colors.selection_bg)
+            } else {
+                Style::default()
+            };
+            items.push(ListItem::new(line).style(style));
+            rows.push((Some(idx), 1));
+        }
+
+        let tasks_block = Block::default()
+            .borders(Borders::ALL)
+            .border_style(Style::default().fg(colors.brand_secondary))
+            .border_set(symbols::border::ROUNDED);
+
+        let mut list_state = ListState::default().with_selected(Some(selected_visual_idx));
+        frame.render_stateful_widget(
+            List::new(items).block(tasks_block),
+            chunks[1],
+            &mut list_state,
+        );
+        self.mouse_targets.list = Some(ListHitbox::new(chunks[1], list_state.offset(), rows));
+
+        self.render_styled_logs(frame, chunks[2], self.t(I18nKey::OperationsLog));
+
+        self.render_footer_keys(
+            frame,
+            chunks[3],
+            &[
+                ("↑↓", I18nKey::FooterNavigate),
+                ("Space", I18nKey::FooterMark),
+                ("E", I18nKey::FooterEnable),
+                ("D", I18nKey::FooterDisable),
+                ("R", I18nKey::FooterRefresh),
+                ("Q/Esc", I18nKey::FooterBack),
+            ],
+        );
+    }
+
+    /// Dibuja la vista de programas instalados
+    fn draw_programs_view(&mut self, frame: &mut Frame) {
+        let colors = self.get_colors();
+        let main_block = Block::default().style(Style::default().bg(colors.bg_main));
+        frame.render_widget(main_block, frame.area());
+
+        let chunks = Layout::default()
+            .direction(Direction::Vertical)
+            .margin(responsive::margin(self.compact))
+            .constraints([
+                Constraint::Length(3), // Título
+                Constraint::Min(6),    // Programas
+                Constraint::Length(7), // Logs
+                Constraint::Length(3), // Footer
+            ])
+            .split(self.content_area);
+
+        let title_block = Block::default()
+            .borders(Borders::ALL)
+            .border_style(Style::default().fg(colors.brand_primary))
+            .border_set(symbols::border::ROUNDED);
+
+        let title = Paragraph::new(Line::from(vec![
+            Span::raw("📦 ").fg(colors.brand_accent).bold(),
+            Span::raw(self.t(I18nKey::ProgramsTitle))
+                .fg(colors.text_primary)
+                .bold(),
+            Span::raw(format!(
+                " ({})  ↕ {}",
+                self.programs.len(),
+                self.t(self.program_sort.label_key())
+            ))
+            .fg(colors.text_secondary),
+        ]))
+        .alignment(Alignment::Center)
+        .block(title_block);
+        frame.render_widget(title, chunks[0]);
+
+        let items: Vec<ListItem> = self
+            .programs
+            .iter()
+            .enumerate()
+            .map(|(idx, program)| {
+                let is_selected = idx == self.selected_item;
+                let name: String = program.name.chars().take(40).collect();
+                let size = program
+                    .size_kb
+                    .map(|kb| self.i18n.format_size(kb * 1024))
+                    .unwrap_or_default();
+
+                let line = Line::from(vec![
+                    if is_selected {
+                        Span::raw(" ▶ ").fg(colors.brand_accent).bold()
+                    } else {
+                        Span::raw("   ")
+                    },
+                    Span::raw(format!("{:<40}", name))
+                        .fg(colors.text_primary)
+                        .bold(),
+                    Span::raw(format!("{:>11}", size)).fg(colors.info_color),
+                    Span::raw(format!(
+                        "  {:<10}",
+                        program
+                            .install_date
+                            .as_deref()
+                            .map(|date| self.i18n.format_date(date))
+                            .unwrap_or_default()
+                    ))
+                    .fg(colors.text_secondary),
+                    Span::raw(format!("  {} {}", program.publisher, program.version))
+                        .fg(colors.text_secondary)
+                        .italic(),
+                ]);
+
+                let style = if is_selected {
+                    Style::default().bg(colors.selection_bg)
+                } else {
+                    Style::default()
+                };
+
+                ListItem::new(line).style(style)
+            })
+            .collect();
+
+        let programs_block = Block::default()
+            .borders(Borders::ALL)
+            .border_style(Style::default().fg(colors.brand_secondary))
+            .border_set(symbols::border::ROUNDED);
+
+        self.mouse_targets.list = Some(Self::render_selectable_list(
+            frame,
+            chunks[1],
+            items,
+            programs_block,
+            self.selected_item,
+        ));
+
+        self.render_styled_logs(frame, chunks[2], self.t(I18nKey::OperationsLog));
+
+        self.render_footer_keys(
+            frame,
+            chunks[3],
+            &[
+                ("↑↓", I18nKey::FooterNavigate),
+                ("S", I18nKey::FooterSort),
+                ("U", I18nKey::FooterUninstall),
+                ("Q/Esc", I18nKey::FooterBack),
+            ],
+        );
+    }
+
+    /// Dibuja el asistente de limpieza de Descargas
+    fn draw_downloads_view(&mut self, frame: &mut Frame) {
+        let colors = self.get_colors();
+        let main_block = Block::default().style(Style::default().bg(colors.bg_main));
+        frame.render_widget(main_block, frame.area());
+
+        let chunks = Layout::default()
+            .direction(Direction::Vertical)
+            .margin(responsive::margin(self.compact))
+            .constraints([
+                Constraint::Length(3), // Título
+                Constraint::Length(8), // Resumen
+                Constraint::Min(5),    // Logs
+                Constraint::Length(3), // Footer
+            ])
+            .split(self.content_area);
+
+        let title_block = Block::default()
+            .borders(Borders::ALL)
+            .border_style(Style::default().fg(colors.brand_primary))
+            .border_set(symbols::border::ROUNDED);
+
+        let title = Paragraph::new(Line::from(vec![
+            Span::raw("📥 ").fg(colors.brand_accent).bold(),
+            Span::raw(self.t(I18nKey::DownloadsTitle))
+                .fg(colors.text_primary)
+                .bold(),
+        ]))
+        .alignment(Alignment::Center)
+        .block(title_block);
+        frame.render_widget(title, chunks[0]);
+
+        let summary_chunks = Layout::default()
+            .direction(Direction::Horizontal)
+            .constraints([Constraint::Percentage(50), Constraint::Percentage(50)])
+            .split(chunks[1]);
+
+        // Fila de resumen: etiqueta, número de elementos y tamaño
+        let summary_line = |label: &str, (count, size): (usize, u64), color| {
             Line::from(vec![
-                Span::raw("  ▀▀▀▀▀▀▀▀▀▀▀▀▀▀▀▀▀▀▀▀▀▀▀▀▀▀▀▀▀▀▀▀▀▀▀▀▀▀▀▀▀▀▀▀▀▀▀▀▀▀▀▀▀▀  ")
-                    .fg(colors.brand_primary)
-                    .bold(),
-            ]),
+                Span::raw(format!("  {:<18}", label)).fg(colors.text_secondary),
+                Span::raw(format!("{:>5}", count)).fg(color).bold(),
+                Span::raw(format!("{:>12}", self.i18n.format_size(size))).fg(color),
+            ])
+        };
+
+        let threshold = self.downloads_threshold_days;
+        let mut age_lines: Vec<Line> = AgeGroup::ALL
+            .iter()
+            .map(|&group| {
+                summary_line(
+                    self.t(group.label_key()),
+                    downloads::summarize(&self.downloads, |e| {
+                        AgeGroup::from_days(e.age_days) == group
+                    }),
+                    colors.text_primary,
+                )
+            })
+            .collect();
+        age_lines.push(summary_line(
+            &self.t_args(
+                I18nKey::DownloadsOlderThan,
+                &[("count", &threshold.to_string())],
+            ),
+            downloads::summarize(&self.downloads, |e| e.age_days >= threshold),
+            colors.warning_color,
+        ));
+
+        let kind_lines: Vec<Line> = FileKind::ALL
+            .iter()
+            .map(|&kind| {
+                summary_line(
+                    self.t(kind.label_key()),
+                    downloads::summarize(&self.downloads, |e| e.kind == kind),
+                    colors.text_primary,
+                )
+            })
+            .collect();
+
+        for (lines, area, icon, title_key) in [
+            (age_lines, summary_chunks[0], "🕒 ", I18nKey::DownloadsByAge),
+            (
+                kind_lines,
+                summary_chunks[1],
+                "🗂️ ",
+                I18nKey::DownloadsByKind,
+            ),
+        ] {
+            let block = Block::default()
+                .borders(Borders::ALL)
+                .border_style(Style::default().fg(colors.brand_secondary))
+                .border_set(symbols::border::ROUNDED)
+                .title(Line::from(vec![
+                    Span::raw(" "),
+                    Span::raw(icon).fg(colors.brand_accent),
+                    Span::raw(format!("{} ", self.t(title_key)))
+                        .fg(colors.text_primary)
+                        .bold(),
+                ]));
+            frame.render_widget(Paragraph::new(lines).block(block), area);
+        }
+
+        self.render_styled_logs(frame, chunks[2], self.t(I18nKey::OperationsLog));
+
+        self.render_footer_keys(
+            frame,
+            chunks[3],
+            &[
+                ("T", I18nKey::FooterThreshold),
+                ("D", I18nKey::FooterDelete),
+                ("M", I18nKey::FooterArchive),
+                ("Q/Esc", I18nKey::FooterBack),
+            ],
+        );
+    }
+
+    /// Dibuja la vista de adaptadores de red
+    fn draw_adapters_view(&mut self, frame: &mut Frame) {
+        let colors = self.get_colors();
+        let main_block = Block::default().style(Style::default().bg(colors.bg_main));
+        frame.render_widget(main_block, frame.area());
+
+        let chunks = Layout::default()
+            .direction(Direction::Vertical)
+            .margin(responsive::margin(self.compact))
+            .constraints([
+                Constraint::Length(3), // Título
+                Constraint::Min(6),    // Adaptadores
+                Constraint::Length(7), // Logs
+                Constraint::Length(3), // Footer
+            ])
+            .split(self.content_area);
+
+        let title_block = Block::default()
+            .borders(Borders::ALL)
+            .border_style(Style::default().fg(colors.brand_primary))
+            .border_set(symbols::border::ROUNDED);
+
+        let title = Paragraph::new(Line::from(vec![
+            Span::raw("🔌 ").fg(colors.brand_accent).bold(),
+            Span::raw(self.t(I18nKey::AdaptersTitle))
+                .fg(colors.text_primary)
+                .bold(),
+            Span::raw(format!(" ({})", self.adapters.len())).fg(colors.text_secondary),
+        ]))
+        .alignment(Alignment::Center)
+        .block(title_block);
+        frame.render_widget(title, chunks[0]);
+
+        let items: Vec<ListItem> = self
+            .adapters
+            .iter()
+            .enumerate()
+            .map(|(idx, adapter)| {
+                let is_selected = idx == self.selected_item;
+                let status_color = match adapter.status {
+                    AdapterStatus::Up => colors.success_color,
+                    AdapterStatus::Disconnected => colors.warning_color,
+                    AdapterStatus::Disabled => colors.error_color,
+                    AdapterStatus::Unknown => colors.text_secondary,
+                };
+                let metric = adapter
+                    .metric
+                    .map(|m| format!("{} {}", self.t(I18nKey::AdapterMetric), m))
+                    .unwrap_or_default();
+
+                let line = Line::from(vec![
+                    if is_selected {
+                        Span::raw(" ▶ ").fg(colors.brand_accent).bold()
+                    } else {
+                        Span::raw("   ")
+                    },
+                    Span::raw(format!("{:<20}", adapter.name))
+                        .fg(colors.text_primary)
+                        .bold(),
+                    Span::raw(format!("{:<14}", self.t(adapter.status.label_key())))
+                        .fg(status_color),
+                    Span::raw(format!("{:<17}", adapter.ipv4.as_deref().unwrap_or("-")))
+                        .fg(colors.info_color),
+                    Span::raw(format!("{:<12}", metric)).fg(colors.text_secondary),
+                    Span::raw(adapter.description.as_str())
+                        .fg(colors.text_secondary)
+                        .italic(),
+                ]);
+
+                let style = if is_selected {
+                    Style::default().bg(colors.selection_bg)
+                } else {
+                    Style::default()
+                };
+
+                ListItem::new(line).style(style)
+            })
+            .collect();
+
+        let adapters_block = Block::default()
+            .borders(Borders::ALL)
+            .border_style(Style::default().fg(colors.brand_secondary))
+            .border_set(symbols::border::ROUNDED);
+
+        self.mouse_targets.list = Some(Self::render_selectable_list(
+            frame,
+            chunks[1],
+            items,
+            adapters_block,
+            self.selected_item,
+        ));
+
+        self.render_styled_logs(frame, chunks[2], self.t(I18nKey::OperationsLog));
+
+        self.render_footer_keys(
+            frame,
+            chunks[3],
+            &[
+                ("↑↓", I18nKey::FooterNavigate),
+                ("E", I18nKey::FooterToggle),
+                ("N", I18nKey::FooterRenewIp),
+                ("R", I18nKey::FooterRefresh),
+                ("Q/Esc", I18nKey::FooterBack),
+            ],
+        );
+    }
+
+    /// Dibuja la vista de tareas en segundo plano
+    fn draw_jobs_view(&mut self, frame: &mut Frame) {
+        let colors = self.get_colors();
+        let main_block = Block::default().style(Style::default().bg(colors.bg_main));
+        frame.render_widget(main_block, frame.area());
+
+        let jobs = self.jobs.jobs();
+        let list_height = (jobs.len().max(1) as u16 + 2).min(10);
+        let chunks = Layout::default()
+            .direction(Direction::Vertical)
+            .margin(responsive::margin(self.compact))
+            .constraints([
+                Constraint::Length(3),           // Título
+                Constraint::Length(list_height), // Tareas
+                Constraint::Min(7),              // Logs de la tarea
+                Constraint::Length(3),           // Footer
+            ])
+            .split(self.content_area);
+
+        let title_block = Block::default()
+            .borders(Borders::ALL)
+            .border_style(Style::default().fg(colors.brand_primary))
+            .border_set(symbols::border::ROUNDED);
+
+        let title = Paragraph::new(Line::from(vec![
+            Span::raw("🗂️ ").fg(colors.brand_accent).bold(),
+            Span::raw(self.t(I18nKey::JobsTitle))
+                .fg(colors.text_primary)
+                .bold(),
+            Span::raw(format!(" ({}/{})", self.jobs.active_count(), jobs.len()))
+                .fg(colors.text_secondary),
+        ]))
+        .alignment(Alignment::Center)
+        .block(title_block);
+        frame.render_widget(title, chunks[0]);
+
+        let jobs_block = Block::default()
+            .borders(Borders::ALL)
+            .border_style(Style::default().fg(colors.brand_secondary))
+            .border_set(symbols::border::ROUNDED);
+
+        if jobs.is_empty() {
+            let empty = Paragraph::new(self.t(I18nKey::JobsEmpty))
+                .style(Style::default().fg(colors.text_secondary))
+                .alignment(Alignment::Center)
+                .block(jobs_block);
+            frame.render_widget(empty, chunks[1]);
+        } else {
+            let items: Vec<ListItem> = jobs
+                .iter()
+                .enumerate()
+                .map(|(idx, job)| {
+                    let is_selected = idx == self.selected_item;
+                    let (icon, status_color) = match job.status {
+                        JobStatus::Pending => ("⏳", colors.text_secondary),
+                        JobStatus::Running => ("▶", colors.info_color),
+                        JobStatus::Completed => ("✓", colors.success_color),
+                        JobStatus::Failed => ("✗", colors.error_color),
+                    };
+                    let elapsed = job.elapsed().map(utils::format_clock).unwrap_or_default();
+                    let detail = if job.stalled {
+                        format!("⚠️  {}", self.t(I18nKey::OpStalled))
+                    } else {
+                        job.progress
+                            .map(|percent| format!("{:.1}%", percent))
+                            .unwrap_or_default()
+                    };
+
+                    let line = Line::from(vec![
+                        if is_selected {
+                            Span::raw(" ▶ ").fg(colors.brand_accent).bold()
+                        } else {
+                            Span::raw("   ")
+                        },
+                        Span::raw(format!("{} ", icon)).fg(status_color),
+                        Span::raw(format!("{:<36}", self.t(job.view.title_key())))
+                            .fg(colors.text_primary)
+                            .bold(),
+                        Span::raw(format!("{:<14}", self.t(job.status.label_key())))
+                            .fg(status_color),
+                        Span::raw(format!("{:<8}", elapsed)).fg(colors.text_secondary),
+                        Span::raw(detail).fg(colors.warning_color),
+                    ]);
+
+                    let style = if is_selected {
+                        Style::default().bg(colors.selection_bg)
+                    } else {
+                        Style::default()
+                    };
+
+                    ListItem::new(line).style(style)
+                })
+                .collect();
+
+            self.mouse_targets.list = Some(Self::render_selectable_list(
+                frame,
+                chunks[1],
+                items,
+                jobs_block,
+                self.selected_item,
+            ));
+        }
+
+        self.render_log_panel(frame, chunks[2], self.t(I18nKey::JobLogs));
+
+        self.render_footer_keys(
+            frame,
+            chunks[3],
+            &[
+                ("↑↓", I18nKey::FooterNavigate),
+                ("X", I18nKey::FooterCancel),
+                ("C", I18nKey::FooterClearFinished),
+                ("Q/Esc", I18nKey::FooterBack),
+            ],
+        );
+    }
+
+    /// Dibuja la vista de ajustes
+    fn draw_settings_view(&mut self, frame: &mut Frame) {
+        let colors = self.get_colors();
+        let main_block = Block::default().style(Style::default().bg(colors.bg_main));
+        frame.render_widget(main_block, frame.area());
+
+        let chunks = Layout::default()
+            .direction(Direction::Vertical)
+            .margin(responsive::margin(self.compact))
+            .constraints([
+                Constraint::Length(3), // Título
+                Constraint::Min(5),    // Ajustes
+                Constraint::Length(3), // Footer
+            ])
+            .split(self.content_area);
+
+        let title_block = Block::default()
+            .borders(Borders::ALL)
+            .border_style(Style::default().fg(colors.brand_primary))
+            .border_set(symbols::border::ROUNDED);
+
+        let title = Paragraph::new(Line::from(vec![
+            Span::raw("⚙️ ").fg(colors.brand_accent).bold(),
+            Span::raw(self.t(I18nKey::SettingsTitle))
+                .fg(colors.text_primary)
+                .bold(),
+        ]))
+        .alignment(Alignment::Center)
+        .block(title_block);
+        frame.render_widget(title, chunks[0]);
+
+        let mut previous_section = None;
+        let items: Vec<ListItem> = self
+            .settings_items()
+            .into_iter()
+            .enumerate()
+            .map(|(idx, item)| {
+                let is_selected = idx == self.selected_item;
+                // El nombre de la sección solo aparece en su primera fila
+                let section = item.section_key();
+                let section_label = if previous_section == Some(section) {
+                    ""
+                } else {
+                    self.t(section)
+                };
+                previous_section = Some(section);
+
+                let mut spans = vec![
+                    Span::raw(format!("  {:<16}", section_label))
+                        .fg(colors.info_color)
+                        .bold(),
+                    if is_selected {
+                        Span::raw(" ▶ ").fg(colors.brand_accent).bold()
+                    } else {
+                        Span::raw("   ")
+                    },
+                    Span::raw(item.label(&self.i18n)).fg(colors.text_primary),
+                ];
+                if self.is_current_setting(item) {
+                    spans.push(
+                        Span::raw(format!("  ● {}", self.t(I18nKey::SettingsCurrent)))
+                            .fg(colors.success_color)
+                            .bold(),
+                    );
+                }
+
+                let style = if is_selected {
+                    Style::default().bg(colors.selection_bg)
+                } else {
+                    Style::default()
+                };
+                ListItem::new(Line::from(spans)).style(style)
+            })
+            .collect();
+
+        let settings_block = Block::default()
+            .borders(Borders::ALL)
+            .border_style(Style::default().fg(colors.brand_secondary))
+            .border_set(symbols::border::ROUNDED);
+        self.mouse_targets.list = Some(Self::render_selectable_list(
+            frame,
+            chunks[1],
+            items,
+            settings_block,
+            self.selected_item,
+        ));
+
+        self.render_footer_keys(
+            frame,
+            chunks[2],
+            &[
+                ("↑↓", I18nKey::FooterNavigate),
+                ("Enter", I18nKey::FooterApply),
+                ("Q/Esc", I18nKey::FooterBack),
+            ],
+        );
+    }
+
+    /// Dibuja el asistente del primer inicio
+    fn draw_onboarding_view(&mut self, frame: &mut Frame) {
+        let colors = self.get_colors();
+        let main_block = Block::default().style(Style::default().bg(colors.bg_main));
+        frame.render_widget(main_block, frame.area());
+
+        let chunks = Layout::default()
+            .direction(Direction::Vertical)
+            .margin(responsive::margin(self.compact))
+            .constraints([
+                Constraint::Length(3), // Título
+                Constraint::Min(11),   // Paso actual
+                Constraint::Length(3), // Footer
+            ])
+            .split(self.content_area);
+
+        let title_block = Block::default()
+            .borders(Borders::ALL)
+            .border_style(Style::default().fg(colors.brand_primary))
+            .border_set(symbols::border::ROUNDED);
+
+        let title = Paragraph::new(Line::from(vec![
+            Span::raw("👋 ").fg(colors.brand_accent).bold(),
+            Span::raw(self.t(I18nKey::OnboardingTitle))
+                .fg(colors.text_primary)
+                .bold(),
+        ]))
+        .alignment(Alignment::Center)
+        .block(title_block);
+        frame.render_widget(title, chunks[0]);
+
+        let wizard = &self.onboarding;
+        let (labels, selected) = wizard.options(&self.i18n);
+        let mut options = Vec::new();
+        for (idx, label) in labels.into_iter().enumerate() {
+            if idx == selected {
+                options.push(
+                    Span::raw(format!("  ● {}  ", label))
+                        .fg(colors.brand_accent)
+                        .bold(),
+                );
+            } else {
+                options.push(Span::raw(format!("  ○ {}  ", label)).fg(colors.text_secondary));
+            }
+        }
+
+        let lines = vec![
             Line::from(""),
-            // Logo ASCII moderno
-            Line::from(vec![
-                Span::raw("           ██╗    ██╗██╗███╗   ██╗               ")
-                    .fg(colors.brand_primary)
-                    .bold(),
-            ]),
-            Line::from(vec![
-                Span::raw("           ██║    ██║██║████╗  ██║               ")
-                    .fg(colors.brand_primary)
-                    .bold(),
-            ]),
-            Line::from(vec![
-                Span::raw("           ██║ █╗ ██║██║██╔██╗ ██║               ")
-                    .fg(colors.brand_secondary)
-                    .bold(),
-            ]),
-            Line::from(vec![
-                Span::raw("           ██║███╗██║██║██║╚██╗██║               ")
-                    .fg(colors.brand_accent)
+            Line::from(Span::raw(self.t(I18nKey::OnboardingIntro)).fg(colors.text_secondary)),
+            Line::from(""),
+            Line::from(
+                Span::raw(format!(
+                    "{} {}/{}",
+                    self.t(I18nKey::OnboardingStep),
+                    wizard.step + 1,
+                    Step::ALL.len()
+                ))
+                .fg(colors.info_color),
+            ),
+            Line::from(
+                Span::raw(self.t(wizard.current().question_key()))
+                    .fg(colors.text_primary)
                     .bold(),
-            ]),
-            Line::from(vec![
-                Span::raw("           ╚███╔███╔╝██║██║ ╚████║               ")
-                    .fg(colors.brand_accent)
+            ),
+            Line::from(""),
+            Line::from(options),
+            Line::from(""),
+            Line::from(
+                Span::raw(self.t(I18nKey::OnboardingHint))
+                    .fg(colors.text_secondary)
+                    .italic(),
+            ),
+        ];
+
+        let step_block = Block::default()
+            .borders(Borders::ALL)
+            .border_style(Style::default().fg(colors.brand_secondary))
+            .border_set(symbols::border::ROUNDED);
+        let step = Paragraph::new(lines)
+            .alignment(Alignment::Center)
+            .wrap(Wrap { trim: true })
+            .block(step_block);
+        frame.render_widget(step, help::centered(chunks[1], 72, 11));
+
+        let next = if wizard.is_last() {
+            I18nKey::FooterFinish
+        } else {
+            I18nKey::FooterNext
+        };
+        let back = if wizard.step == 0 {
+            I18nKey::FooterSkip
+        } else {
+            I18nKey::FooterPrevious
+        };
+        self.render_footer_keys(
+            frame,
+            chunks[2],
+            &[
+                ("←→", I18nKey::FooterChoose),
+                ("Enter", next),
+                ("Esc", back),
+            ],
+        );
+    }
+
+    /// Dibuja la vista de tiempo de arranque
+    fn draw_boot_time_view(&mut self, frame: &mut Frame) {
+        let colors = self.get_colors();
+        let main_block = Block::default().style(Style::default().bg(colors.bg_main));
+        frame.render_widget(main_block, frame.area());
+
+        let chunks = Layout::default()
+            .direction(Direction::Vertical)
+            .margin(responsive::margin(self.compact))
+            .constraints([
+                Constraint::Length(3), // Título
+                Constraint::Length(6), // Tendencia
+                Constraint::Min(5),    // Arranques
+                Constraint::Length(7), // Logs
+                Constraint::Length(3), // Footer
+            ])
+            .split(self.content_area);
+
+        let title_block = Block::default()
+            .borders(Borders::ALL)
+            .border_style(Style::default().fg(colors.brand_primary))
+            .border_set(symbols::border::ROUNDED);
+
+        let title = Paragraph::new(Line::from(vec![
+            Span::raw("⏱️ ").fg(colors.brand_accent).bold(),
+            Span::raw(self.t(I18nKey::BootTitle))
+                .fg(colors.text_primary)
+                .bold(),
+        ]))
+        .alignment(Alignment::Center)
+        .block(title_block);
+        frame.render_widget(title, chunks[0]);
+
+        let seconds: Vec<f32> = self
+            .boot_records
+            .iter()
+            .map(|b| b.duration_ms as f32 / 1000.0)
+            .collect();
+        let average = |after_run: bool| {
+            boot::average_ms(&self.boot_records, after_run)
+                .map(|ms| format!("{:.1} s", ms as f64 / 1000.0))
+                .unwrap_or_else(|| "-".to_string())
+        };
+        let trend_lines = if seconds.is_empty() {
+            vec![Line::from(
+                Span::raw(format!("  {}", self.t(I18nKey::BootNoData)))
+                    .fg(colors.text_secondary)
+                    .italic(),
+            )]
+        } else {
+            vec![
+                Line::from(vec![
+                    Span::raw("  "),
+                    Span::raw(sparkline(&seconds)).fg(colors.info_color).bold(),
+                ]),
+                Line::from(""),
+                Line::from(vec![
+                    Span::raw(format!("  {} ", self.t(I18nKey::BootAverageAfterRun)))
+                        .fg(colors.text_secondary),
+                    Span::raw(average(true)).fg(colors.success_color).bold(),
+                    Span::raw(format!("   {} ", self.t(I18nKey::BootAverageOther)))
+                        .fg(colors.text_secondary),
+                    Span::raw(average(false)).fg(colors.text_primary).bold(),
+                ]),
+            ]
+        };
+        let trend_block = Block::default()
+            .borders(Borders::ALL)
+            .border_style(Style::default().fg(colors.brand_secondary))
+            .border_set(symbols::border::ROUNDED)
+            .title(Line::from(vec![
+                Span::raw(" "),
+                Span::raw(self.t(I18nKey::BootTrend))
+                    .fg(colors.text_primary)
                     .bold(),
-            ]),
-            Line::from(vec![
-                Span::raw("            ╚══╝╚══╝ ╚═╝╚═╝  ╚═══╝               ")
-                    .fg(colors.brand_accent)
+                Span::raw(" "),
+            ]));
+        frame.render_widget(Paragraph::new(trend_lines).block(trend_block), chunks[1]);
+
+        // Del más reciente al más antiguo
+        let items: Vec<ListItem> = self
+            .boot_records
+            .iter()
+            .rev()
+            .map(|boot| {
+                let mut spans = vec![
+                    Span::raw(format!(
+                        "  {} UTC  ",
+                        self.i18n.format_date(&boot.timestamp)
+                    ))
+                    .fg(colors.text_secondary),
+                    Span::raw(format!("{:>7.1} s", boot.duration_ms as f64 / 1000.0))
+                        .fg(colors.text_primary)
+                        .bold(),
+                ];
+                if boot.after_run {
+                    spans.push(
+                        Span::raw(format!("  ★ {}", self.t(I18nKey::BootAfterRun)))
+                            .fg(colors.success_color),
+                    );
+                }
+                ListItem::new(Line::from(spans))
+            })
+            .collect();
+
+        let boots_block = Block::default()
+            .borders(Borders::ALL)
+            .border_style(Style::default().fg(colors.brand_secondary))
+            .border_set(symbols::border::ROUNDED);
+        frame.render_widget(List::new(items).block(boots_block), chunks[2]);
+
+        self.render_styled_logs(frame, chunks[3], self.t(I18nKey::OperationsLog));
+
+        self.render_footer_keys(
+            frame,
+            chunks[4],
+            &[
+                ("R", I18nKey::FooterRefresh),
+                ("Q/Esc", I18nKey::FooterBack),
+            ],
+        );
+    }
+
+    /// Dibuja el visor del log de la aplicación
+    fn draw_app_logs_view(&mut self, frame: &mut Frame) {
+        let colors = self.get_colors();
+        let main_block = Block::default().style(Style::default().bg(colors.bg_main));
+        frame.render_widget(main_block, frame.area());
+
+        let chunks = Layout::default()
+            .direction(Direction::Vertical)
+            .margin(responsive::margin(self.compact))
+            .constraints([
+                Constraint::Length(3), // Título
+                Constraint::Length(3), // Filtro
+                Constraint::Min(6),    // Líneas del log
+                Constraint::Length(3), // Footer
+            ])
+            .split(self.content_area);
+
+        let title_block = Block::default()
+            .borders(Borders::ALL)
+            .border_style(Style::default().fg(colors.brand_primary))
+            .border_set(symbols::border::ROUNDED);
+
+        let file_name = self
+            .log_tail
+            .path()
+            .and_then(|path| path.file_name())
+            .map(|name| format!(" ({})", name.to_string_lossy()))
+            .unwrap_or_default();
+        let title = Paragraph::new(Line::from(vec![
+            Span::raw("📜 ").fg(colors.brand_accent).bold(),
+            Span::raw(self.t(I18nKey::AppLogsTitle))
+                .fg(colors.text_primary)
+                .bold(),
+            Span::raw(file_name).fg(colors.text_secondary),
+            Span::raw(format!(
+                " · {} {}",
+                self.t(I18nKey::AppLogsFileLevel),
+                self.config.logging.level
+            ))
+            .fg(colors.text_secondary),
+        ]))
+        .alignment(Alignment::Center)
+        .block(title_block);
+        frame.render_widget(title, chunks[0]);
+
+        let level = self
+            .log_filter
+            .min_level
+            .map_or(self.t(I18nKey::AppLogsAllLevels), |level| level.name());
+        let mut filter_spans = vec![
+            Span::raw(format!(" {} ", self.t(I18nKey::AppLogsLevel))).fg(colors.text_secondary),
+            Span::raw(format!("{:<8}", level))
+                .fg(colors.brand_primary)
+                .bold(),
+        ];
+        if self.log_filter.keyword.is_empty() && !self.log_filter_editing {
+            filter_spans.push(
+                Span::raw(self.t(I18nKey::AppLogsFilterHint))
+                    .fg(colors.text_secondary)
+                    .italic(),
+            );
+        } else {
+            filter_spans.push(
+                Span::raw(format!("{} ", self.t(I18nKey::PortsFilter))).fg(colors.text_secondary),
+            );
+            filter_spans.push(
+                Span::raw(self.log_filter.keyword.as_str())
+                    .fg(colors.text_primary)
                     .bold(),
-            ]),
-            Line::from(""),
-            // Subtítulo con badge
-            Line::from(vec![
-                Span::raw("               ╔══════════════════════════════════════╗")
-                    .fg(colors.brand_secondary),
-            ]),
+            );
+            if self.log_filter_editing {
+                filter_spans.push(Span::raw("▏").fg(colors.brand_accent));
+            }
+        }
+        let filter_block = Block::default()
+            .borders(Borders::ALL)
+            .border_style(Style::default().fg(if self.log_filter_editing {
+                colors.brand_accent
+            } else {
+                colors.brand_secondary
+            }))
+            .border_set(symbols::border::ROUNDED);
+        frame.render_widget(
+            Paragraph::new(Line::from(filter_spans)).block(filter_block),
+            chunks[1],
+        );
+
+        let logs_block = Block::default()
+            .borders(Borders::ALL)
+            .border_style(Style::default().fg(colors.brand_primary))
+            .border_set(symbols::border::ROUNDED);
+
+        if self.log_tail.path().is_none() {
+            let empty = Paragraph::new(self.t(I18nKey::AppLogsNoFile))
+                .style(Style::default().fg(colors.text_secondary))
+                .alignment(Alignment::Center)
+                .block(logs_block);
+            frame.render_widget(empty, chunks[2]);
+        } else {
+            // Mostrar las últimas líneas que caben, desplazadas `scroll_offset`
+            let visible: Vec<_> = self
+                .log_tail
+                .lines()
+                .iter()
+                .filter(|line| self.log_filter.matches(line))
+                .collect();
+            let height = chunks[2].height.saturating_sub(2) as usize;
+            let max_offset = visible.len().saturating_sub(height);
+            self.scroll_offset = self.scroll_offset.min(max_offset as u16);
+            let end = visible.len() - self.scroll_offset as usize;
+            let start = end.saturating_sub(height);
+
+            let lines: Vec<Line> = visible[start..end]
+                .iter()
+                .map(|line| {
+                    let color = match line.level {
+                        Some(LogLevel::Error) => colors.error_color,
+                        Some(LogLevel::Warning) => colors.warning_color,
+                        Some(LogLevel::Debug) => colors.text_secondary,
+                        _ => colors.text_primary,
+                    };
+                    Line::from(Span::raw(line.text.as_str()).fg(color))
+                })
+                .collect();
+            frame.render_widget(Paragraph::new(lines).block(logs_block), chunks[2]);
+        }
+
+        if self.log_filter_editing {
+            self.render_footer_keys(
+                frame,
+                chunks[3],
+                &[
+                    ("Enter", I18nKey::FooterDone),
+                    ("Esc", I18nKey::FooterClear),
+                ],
+            );
+        } else {
+            self.render_footer_keys(
+                frame,
+                chunks[3],
+                &[
+                    ("↑↓", I18nKey::FooterNavigate),
+                    ("/", I18nKey::FooterFilter),
+                    ("V", I18nKey::FooterLevel),
+                    ("N", I18nKey::FooterLogLevel),
+                    ("R", I18nKey::FooterRefresh),
+                    ("Q/Esc", I18nKey::FooterBack),
+                ],
+            );
+        }
+    }
+
+    /// Dibuja la vista de puertos y conexiones
+    fn draw_ports_view(&mut self, frame: &mut Frame) {
+        let colors = self.get_colors();
+        let main_block = Block::default().style(Style::default().bg(colors.bg_main));
+        frame.render_widget(main_block, frame.area());
+
+        let chunks = Layout::default()
+            .direction(Direction::Vertical)
+            .margin(responsive::margin(self.compact))
+            .constraints([
+                Constraint::Length(3), // Título
+                Constraint::Length(3), // Filtro
+                Constraint::Min(6),    // Conexiones
+                Constraint::Length(7), // Logs
+                Constraint::Length(3), // Footer
+            ])
+            .split(self.content_area);
+
+        let visible = ports::filtered(&self.connections, &self.ports_filter);
+
+        let title_block = Block::default()
+            .borders(Borders::ALL)
+            .border_style(Style::default().fg(colors.brand_primary))
+            .border_set(symbols::border::ROUNDED);
+
+        let title = Paragraph::new(Line::from(vec![
+            Span::raw("🔗 ").fg(colors.brand_accent).bold(),
+            Span::raw(self.t(I18nKey::PortsTitle))
+                .fg(colors.text_primary)
+                .bold(),
+            Span::raw(format!(" ({}/{})", visible.len(), self.connections.len()))
+                .fg(colors.text_secondary),
+        ]))
+        .alignment(Alignment::Center)
+        .block(title_block);
+        frame.render_widget(title, chunks[0]);
+
+        let filter_line = if self.ports_filter.is_empty() && !self.ports_filter_editing {
+            Line::from(
+                Span::raw(format!(" {}", self.t(I18nKey::PortsFilterHint)))
+                    .fg(colors.text_secondary)
+                    .italic(),
+            )
+        } else {
             Line::from(vec![
-                Span::raw("               ║  ").fg(colors.brand_secondary),
-                Span::raw("⚡ ").fg(colors.brand_accent).bold(),
-                Span::raw(self.t(I18nKey::AppSubtitle))
+                Span::raw(format!(" {} ", self.t(I18nKey::PortsFilter))).fg(colors.text_secondary),
+                Span::raw(self.ports_filter.as_str())
                     .fg(colors.text_primary)
                     .bold(),
-                Span::raw("  ").fg(colors.brand_secondary),
-                Span::raw("│").fg(colors.text_secondary),
-                Span::raw("  ").fg(colors.brand_secondary),
-                Span::raw(self.t(I18nKey::AppVersion))
-                    .fg(colors.info_color)
-                    .bold(),
-                Span::raw("  ║").fg(colors.brand_secondary),
-            ]),
+                if self.ports_filter_editing {
+                    Span::raw("▏").fg(colors.brand_accent)
+                } else {
+                    Span::raw("")
+                },
+            ])
+        };
+        let filter_block = Block::default()
+            .borders(Borders::ALL)
+            .border_style(Style::default().fg(if self.ports_filter_editing {
+                colors.brand_accent
+            } else {
+                colors.brand_secondary
+            }))
+            .border_set(symbols::border::ROUNDED);
+        frame.render_widget(Paragraph::new(filter_line).block(filter_block), chunks[1]);
+
+        let items: Vec<ListItem> = visible
+            .iter()
+            .enumerate()
+            .map(|(idx, connection)| {
+                let is_selected = idx == self.selected_item;
+                let line = Line::from(vec![
+                    if is_selected {
+                        Span::raw(" ▶ ").fg(colors.brand_accent).bold()
+                    } else {
+                        Span::raw("   ")
+                    },
+                    Span::raw(format!("{:<5}", connection.protocol)).fg(colors.info_color),
+                    Span::raw(format!("{:<24}", connection.local)).fg(colors.text_primary),
+                    Span::raw(format!("{:<24}", connection.remote)).fg(colors.text_secondary),
+                    Span::raw(format!("{:<14}", connection.state)).fg(colors.warning_color),
+                    Span::raw(format!("{:>7} ", connection.pid)).fg(colors.text_secondary),
+                    Span::raw(connection.process.as_str())
+                        .fg(colors.text_primary)
+                        .bold(),
+                ]);
+
+                let style = if is_selected {
+                    Style::default().bg(colors.selection_bg)
+                } else {
+                    Style::default()
+                };
+
+                ListItem::new(line).style(style)
+            })
+            .collect();
+
+        let connections_block = Block::default()
+            .borders(Borders::ALL)
+            .border_style(Style::default().fg(colors.brand_secondary))
+            .border_set(symbols::border::ROUNDED);
+
+        self.mouse_targets.list = Some(Self::render_selectable_list(
+            frame,
+            chunks[2],
+            items,
+            connections_block,
+            self.selected_item,
+        ));
+
+        self.render_styled_logs(frame, chunks[3], self.t(I18nKey::OperationsLog));
+
+        if self.ports_filter_editing {
+            self.render_footer_keys(
+                frame,
+                chunks[4],
+                &[
+                    ("Enter", I18nKey::FooterDone),
+                    ("Esc", I18nKey::FooterClear),
+                ],
+            );
+        } else {
+            self.render_footer_keys(
+                frame,
+                chunks[4],
+                &[
+                    ("↑↓", I18nKey::FooterNavigate),
+                    ("/", I18nKey::FooterFilter),
+                    ("X", I18nKey::FooterKill),
+                    ("R", I18nKey::FooterRefresh),
+                    ("Q/Esc", I18nKey::FooterBack),
+                ],
+            );
+        }
+    }
+
+    /// Dibuja la vista de configuración de proxy
+    fn draw_proxy_view(&mut self, frame: &mut Frame) {
+        let colors = self.get_colors();
+        let main_block = Block::default().style(Style::default().bg(colors.bg_main));
+        frame.render_widget(main_block, frame.area());
+
+        let chunks = Layout::default()
+            .direction(Direction::Vertical)
+            .margin(responsive::margin(self.compact))
+            .constraints([
+                Constraint::Length(3), // Título
+                Constraint::Length(9), // Estado
+                Constraint::Min(5),    // Logs
+                Constraint::Length(3), // Footer
+            ])
+            .split(self.content_area);
+
+        let title_block = Block::default()
+            .borders(Borders::ALL)
+            .border_style(Style::default().fg(colors.brand_primary))
+            .border_set(symbols::border::ROUNDED);
+
+        let title = Paragraph::new(Line::from(vec![
+            Span::raw("🧭 ").fg(colors.brand_accent).bold(),
+            Span::raw(self.t(I18nKey::ProxyTitle))
+                .fg(colors.text_primary)
+                .bold(),
+        ]))
+        .alignment(Alignment::Center)
+        .block(title_block);
+        frame.render_widget(title, chunks[0]);
+
+        let settings = self.proxy_settings.clone().unwrap_or_default();
+        let field = |label: I18nKey, value: Option<&str>| {
+            Line::from(vec![
+                Span::raw(format!("     {} ", self.t(label))).fg(colors.text_secondary),
+                Span::raw(value.unwrap_or("-").to_string()).fg(colors.text_primary),
+            ])
+        };
+        let (wininet, wininet_color) = if settings.wininet_enabled {
+            (self.t(I18nKey::ProxyOn), colors.warning_color)
+        } else {
+            (self.t(I18nKey::ProxyOff), colors.success_color)
+        };
+        let (winhttp, winhttp_color) = match &settings.winhttp_server {
+            Some(server) => (server.as_str(), colors.warning_color),
+            None => (self.t(I18nKey::ProxyDirect), colors.success_color),
+        };
+
+        let status_lines = vec![
             Line::from(vec![
-                Span::raw("               ╚══════════════════════════════════════╝")
-                    .fg(colors.brand_secondary),
+                Span::raw(format!("  {} ", self.t(I18nKey::ProxyWininet)))
+                    .fg(colors.text_secondary),
+                Span::raw(wininet).fg(wininet_color).bold(),
             ]),
+            field(I18nKey::ProxyServer, settings.wininet_server.as_deref()),
+            field(I18nKey::ProxyBypass, settings.wininet_bypass.as_deref()),
+            field(I18nKey::ProxyPac, settings.auto_config_url.as_deref()),
             Line::from(""),
-            // Footer decorativo
             Line::from(vec![
-                Span::raw("  ▄▄▄▄▄▄▄▄▄▄▄▄▄▄▄▄▄▄▄▄▄▄▄▄▄▄▄▄▄▄▄▄▄▄▄▄▄▄▄▄▄▄▄▄▄▄▄▄▄▄▄▄▄▄  ")
-                    .fg(colors.brand_primary)
-                    .bold(),
+                Span::raw(format!("  {} ", self.t(I18nKey::ProxyWinhttp)))
+                    .fg(colors.text_secondary),
+                Span::raw(winhttp).fg(winhttp_color).bold(),
             ]),
+            field(I18nKey::ProxyBypass, settings.winhttp_bypass.as_deref()),
         ];
 
-        let banner_text = Text::from(banner_lines);
-        let banner_widget = Paragraph::new(banner_text).alignment(Alignment::Center);
-        frame.render_widget(banner_widget, area);
+        let status_block = Block::default()
+            .borders(Borders::ALL)
+            .border_style(Style::default().fg(colors.brand_secondary))
+            .border_set(symbols::border::ROUNDED);
+        frame.render_widget(Paragraph::new(status_lines).block(status_block), chunks[1]);
+
+        self.render_styled_logs(frame, chunks[2], self.t(I18nKey::OperationsLog));
+
+        self.render_footer_keys(
+            frame,
+            chunks[3],
+            &[
+                ("X", I18nKey::FooterResetDefault),
+                ("R", I18nKey::FooterRefresh),
+                ("Q/Esc", I18nKey::FooterBack),
+            ],
+        );
     }
 
-    /// Renderiza el menú con diseño moderno y categorías
-    fn render_modern_menu(&mut self, frame: &mut Frame, area: Rect) {
+    /// Dibuja la vista de perfiles Wi-Fi
+    fn draw_wifi_view(&mut self, frame: &mut Frame) {
         let colors = self.get_colors();
+        let main_block = Block::default().style(Style::default().bg(colors.bg_main));
+        frame.render_widget(main_block, frame.area());
 
-        // Definir categorías y sus items
-        let cleanup_label = match self.i18n.current_language() {
-            crate::Language::Spanish => "LIMPIEZA",
-            crate::Language::English => "CLEANUP",
-        };
-        let optimize_label = match self.i18n.current_language() {
-            crate::Language::Spanish => "OPTIMIZACIÓN",
-            crate::Language::English => "OPTIMIZATION",
-        };
-        let system_label = match self.i18n.current_language() {
-            crate::Language::Spanish => "SISTEMA",
-            crate::Language::English => "SYSTEM",
-        };
+        let chunks = Layout::default()
+            .direction(Direction::Vertical)
+            .margin(responsive::margin(self.compact))
+            .constraints([
+                Constraint::Length(3), // Título
+                Constraint::Min(6),    // Perfiles
+                Constraint::Length(7), // Logs
+                Constraint::Length(3), // Footer
+            ])
+            .split(self.content_area);
 
-        // Items con categorías
-        let menu_data = vec![
-            // CLEANUP
-            ("", cleanup_label, "", Some(colors.success_color)),
-            (
-                "🧹",
-                self.t(I18nKey::MenuTempFiles),
-                self.t(I18nKey::MenuTempFilesDesc),
-                None,
-            ),
-            (
-                "🗑️",
-                self.t(I18nKey::MenuRecycleBin),
-                self.t(I18nKey::MenuRecycleBinDesc),
-                None,
-            ),
-            (
-                "🌐",
-                self.t(I18nKey::MenuBrowserCache),
-                self.t(I18nKey::MenuBrowserCacheDesc),
-                None,
-            ),
-            (
-                "📋",
-                self.t(I18nKey::MenuSystemLogs),
-                self.t(I18nKey::MenuSystemLogsDesc),
-                None,
-            ),
-            (
-                "🔄",
-                self.t(I18nKey::MenuWindowsUpdate),
-                self.t(I18nKey::MenuWindowsUpdateDesc),
-                None,
-            ),
-            // OPTIMIZATION
-            ("", optimize_label, "", Some(colors.warning_color)),
-            (
-                "⚡",
-                self.t(I18nKey::MenuOptimize),
-                self.t(I18nKey::MenuOptimizeDesc),
-                None,
-            ),
-            (
-                "🚀",
-                self.t(I18nKey::MenuStartup),
-                self.t(I18nKey::MenuStartupDesc),
-                None,
-            ),
-            (
-                "🎨",
-                self.t(I18nKey::MenuVisualEffects),
-                self.t(I18nKey::MenuVisualEffectsDesc),
-                None,
-            ),
-            // SYSTEM
-            ("", system_label, "", Some(colors.info_color)),
-            (
-                "🌐",
-                self.t(I18nKey::MenuNetwork),
-                self.t(I18nKey::MenuNetworkDesc),
-                None,
-            ),
-            (
-                "🔧",
-                self.t(I18nKey::MenuRepair),
-                self.t(I18nKey::MenuRepairDesc),
-                None,
-            ),
-            (
-                "🔒",
-                self.t(I18nKey::MenuPrivacy),
-                self.t(I18nKey::MenuPrivacyDesc),
-                None,
-            ),
-            (
-                "💻",
-                self.t(I18nKey::MenuInfo),
-                self.t(I18nKey::MenuInfoDesc),
-                None,
-            ),
-            (
-                "🚪",
-                self.t(I18nKey::MenuExit),
-                self.t(I18nKey::MenuExitDesc),
-                None,
-            ),
-        ];
+        let title_block = Block::default()
+            .borders(Borders::ALL)
+            .border_style(Style::default().fg(colors.brand_primary))
+            .border_set(symbols::border::ROUNDED);
 
-        // Mapeo de índice visual a índice real (sin contar headers)
-        let visual_to_actual: Vec<usize> = vec![0, 1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12];
-        let actual_to_visual: Vec<usize> = vec![1, 2, 3, 4, 5, 7, 8, 9, 11, 12, 13, 14, 15];
+        let marked = self.wifi_profiles.iter().filter(|p| p.marked).count();
+        let title = Paragraph::new(Line::from(vec![
+            Span::raw("📶 ").fg(colors.brand_accent).bold(),
+            Span::raw(self.t(I18nKey::WifiTitle))
+                .fg(colors.text_primary)
+                .bold(),
+            Span::raw(format!(
+                " ({}, {})",
+                self.wifi_profiles.len(),
+                self.t_args(I18nKey::WifiMarked, &[("count", &marked.to_string())])
+            ))
+            .fg(colors.text_secondary),
+        ]))
+        .alignment(Alignment::Center)
+        .block(title_block);
+        frame.render_widget(title, chunks[0]);
 
-        let items: Vec<ListItem> = menu_data
+        let items: Vec<ListItem> = self
+            .wifi_profiles
             .iter()
             .enumerate()
-            .map(|(visual_idx, (icon, title, desc, cat_color))| {
-                // Si es una categoría (header)
-                if let Some(color) = cat_color {
-                    let content = Line::from(vec![
-                        Span::raw("  "),
-                        Span::raw("▌").fg(*color).bold(),
-                        Span::raw(" "),
-                        Span::raw(*title).fg(*color).bold(),
-                        Span::raw(" "),
-                        Span::raw("━".repeat(45)).fg(*color),
-                    ]);
-                    return ListItem::new(content)
-                        .style(Style::default().add_modifier(Modifier::DIM));
-                }
+            .map(|(idx, profile)| {
+                let is_selected = idx == self.selected_item;
+                let line = Line::from(vec![
+                    if is_selected {
+                        Span::raw(" ▶ ").fg(colors.brand_accent).bold()
+                    } else {
+                        Span::raw("   ")
+                    },
+                    if profile.marked {
+                        Span::raw("[x] ").fg(colors.warning_color).bold()
+                    } else {
+                        Span::raw("[ ] ").fg(colors.text_secondary)
+                    },
+                    Span::raw(profile.name.as_str()).fg(colors.text_primary),
+                ]);
 
-                // Item normal
-                let actual_idx = visual_to_actual
-                    .iter()
-                    .position(|&v| actual_to_visual.get(v).is_some_and(|&av| av == visual_idx))
-                    .unwrap_or(0);
-
-                let is_selected = actual_idx == self.selected_menu_item;
-
-                let content = if is_selected {
-                    Line::from(vec![
-                        Span::raw(" ▶ ").fg(colors.brand_accent).bold(),
-                        Span::raw(*icon).fg(colors.brand_accent).bold(),
-                        Span::raw("  "),
-                        Span::raw(*title).fg(colors.text_primary).bold(),
-                        Span::raw("  "),
-                        Span::raw(format!("│ {}", desc))
-                            .fg(colors.text_primary)
-                            .italic(),
-                    ])
+                let style = if is_selected {
+                    Style::default().bg(colors.selection_bg)
                 } else {
-                    Line::from(vec![
-                        Span::raw("   "),
-                        Span::raw(*icon).fg(colors.brand_primary),
-                        Span::raw("  "),
-                        Span::raw(*title).fg(colors.text_primary),
-                        Span::raw("  "),
-                        Span::raw(format!("│ {}", desc))
-                            .fg(colors.text_secondary)
-                            .italic(),
-                    ])
+                    Style::default()
                 };
 
+                ListItem::new(line).style(style)
+            })
+            .collect();
+
+        let profiles_block = Block::default()
+            .borders(Borders::ALL)
+            .border_style(Style::default().fg(colors.brand_secondary))
+            .border_set(symbols::border::ROUNDED);
+
+        self.mouse_targets.list = Some(Self::render_selectable_list(
+            frame,
+            chunks[1],
+            items,
+            profiles_block,
+            self.selected_item,
+        ));
+
+        self.render_styled_logs(frame, chunks[2], self.t(I18nKey::OperationsLog));
+
+        self.render_footer_keys(
+            frame,
+            chunks[3],
+            &[
+                ("↑↓", I18nKey::FooterNavigate),
+                ("Space", I18nKey::FooterMark),
+                ("D", I18nKey::FooterDelete),
+                ("X", I18nKey::FooterExport),
+                ("C", I18nKey::FooterExportKeys),
+                ("Q/Esc", I18nKey::FooterBack),
+            ],
+        );
+    }
+
+    /// Dibuja la vista de limpieza del PATH
+    fn draw_path_cleaner_view(&mut self, frame: &mut Frame) {
+        let colors = self.get_colors();
+        let main_block = Block::default().style(Style::default().bg(colors.bg_main));
+        frame.render_widget(main_block, frame.area());
+
+        let chunks = Layout::default()
+            .direction(Direction::Vertical)
+            .margin(responsive::margin(self.compact))
+            .constraints([
+                Constraint::Length(3), // Título
+                Constraint::Min(6),    // Entradas
+                Constraint::Length(7), // Logs
+                Constraint::Length(3), // Footer
+            ])
+            .split(self.content_area);
+
+        let title_block = Block::default()
+            .borders(Borders::ALL)
+            .border_style(Style::default().fg(colors.brand_primary))
+            .border_set(symbols::border::ROUNDED);
+
+        let issues = self
+            .path_entries
+            .iter()
+            .filter(|e| e.issue.is_some())
+            .count();
+        let title = Paragraph::new(Line::from(vec![
+            Span::raw("🛤️ ").fg(colors.brand_accent).bold(),
+            Span::raw(self.t(I18nKey::PathCleanerTitle))
+                .fg(colors.text_primary)
+                .bold(),
+            Span::raw(format!(" ({}", self.path_entries.len())).fg(colors.text_secondary),
+            Span::raw(format!(", ⚠ {}", issues)).fg(if issues > 0 {
+                colors.warning_color
+            } else {
+                colors.text_secondary
+            }),
+            Span::raw(")").fg(colors.text_secondary),
+        ]))
+        .alignment(Alignment::Center)
+        .block(title_block);
+        frame.render_widget(title, chunks[0]);
+
+        let items: Vec<ListItem> = self
+            .path_entries
+            .iter()
+            .enumerate()
+            .map(|(idx, entry)| {
+                let is_selected = idx == self.selected_item;
+                let mut spans = vec![
+                    if is_selected {
+                        Span::raw(" ▶ ").fg(colors.brand_accent).bold()
+                    } else {
+                        Span::raw("   ")
+                    },
+                    Span::raw(format!("{:<9}", self.t(entry.scope.label_key())))
+                        .fg(colors.text_secondary),
+                    Span::raw(entry.value.as_str()).fg(if entry.issue.is_some() {
+                        colors.text_secondary
+                    } else {
+                        colors.text_primary
+                    }),
+                ];
+                if let Some(issue) = entry.issue {
+                    spans.push(
+                        Span::raw(format!("  ⚠ {}", self.t(issue.label_key())))
+                            .fg(colors.warning_color)
+                            .bold(),
+                    );
+                }
+
                 let style = if is_selected {
-                    Style::default()
-                        .bg(colors.selection_bg)
-                        .add_modifier(Modifier::BOLD)
+                    Style::default().bg(colors.selection_bg)
                 } else {
                     Style::default()
                 };
 
-                ListItem::new(content).style(style)
+                ListItem::new(Line::from(spans)).style(style)
             })
             .collect();
 
-        let menu_block = Block::default()
+        let entries_block = Block::default()
             .borders(Borders::ALL)
-            .border_style(Style::default().fg(colors.brand_primary))
-            .border_set(symbols::border::ROUNDED)
-            .title(Line::from(vec![
-                Span::raw(" "),
-                Span::raw("◆ ").fg(colors.brand_accent).bold(),
-                Span::raw(format!("{} ", self.t(I18nKey::MainMenu)))
-                    .fg(colors.text_primary)
-                    .bold(),
-            ]))
-            .title_alignment(Alignment::Center);
+            .border_style(Style::default().fg(colors.brand_secondary))
+            .border_set(symbols::border::ROUNDED);
 
-        let menu_list = List::new(items).block(menu_block);
-        frame.render_widget(menu_list, area);
+        self.mouse_targets.list = Some(Self::render_selectable_list(
+            frame,
+            chunks[1],
+            items,
+            entries_block,
+            self.selected_item,
+        ));
+
+        self.render_styled_logs(frame, chunks[2], self.t(I18nKey::OperationsLog));
+
+        self.render_footer_keys(
+            frame,
+            chunks[3],
+            &[
+                ("↑↓", I18nKey::FooterNavigate),
+                ("C", I18nKey::FooterClean),
+                ("Q/Esc", I18nKey::FooterBack),
+            ],
+        );
     }
 
-    /// Renderiza un footer moderno
-    fn render_modern_footer(&self, frame: &mut Frame, area: Rect) {
+    /// Dibuja el editor del archivo hosts
+    fn draw_hosts_view(&mut self, frame: &mut Frame) {
         let colors = self.get_colors();
-        let footer_text = Line::from(vec![
-            Span::raw("  ").fg(colors.brand_accent),
-            Span::raw("↑↓").fg(colors.brand_primary).bold(),
-            Span::raw(format!(" {}  ", self.t(I18nKey::FooterNavigate))).fg(colors.text_secondary),
-            Span::raw("•").fg(colors.brand_accent),
-            Span::raw("  ").fg(colors.brand_accent),
-            Span::raw("Enter").fg(colors.brand_primary).bold(),
-            Span::raw(format!(" {}  ", self.t(I18nKey::FooterSelect))).fg(colors.text_secondary),
-            Span::raw("•").fg(colors.brand_accent),
-            Span::raw("  ").fg(colors.brand_accent),
-            Span::raw("Q/Esc").fg(colors.brand_primary).bold(),
-            Span::raw(format!(" {}  ", self.t(I18nKey::FooterExit))).fg(colors.text_secondary),
-            Span::raw("•").fg(colors.brand_accent),
-            Span::raw("  ").fg(colors.brand_accent),
-            Span::raw("Tab").fg(colors.brand_primary).bold(),
-            Span::raw(format!(" {}  ", self.t(I18nKey::FooterTheme))).fg(colors.text_secondary),
-            Span::raw("•").fg(colors.brand_accent),
-            Span::raw("  ").fg(colors.brand_accent),
-            Span::raw("L").fg(colors.brand_primary).bold(),
-            Span::raw(format!(" {}  ", self.t(I18nKey::FooterLanguage))).fg(colors.text_secondary),
-        ]);
+        let main_block = Block::default().style(Style::default().bg(colors.bg_main));
+        frame.render_widget(main_block, frame.area());
+
+        let chunks = Layout::default()
+            .direction(Direction::Vertical)
+            .margin(responsive::margin(self.compact))
+            .constraints([
+                Constraint::Length(3), // Título
+                Constraint::Min(8),    // Editor
+                Constraint::Length(5), // Logs
+                Constraint::Length(3), // Footer
+            ])
+            .split(self.content_area);
+
+        let title_block = Block::default()
+            .borders(Borders::ALL)
+            .border_style(Style::default().fg(colors.brand_primary))
+            .border_set(symbols::border::ROUNDED);
+
+        let mut title_spans = vec![
+            Span::raw("📝 ").fg(colors.brand_accent).bold(),
+            Span::raw(self.t(I18nKey::HostsTitle))
+                .fg(colors.text_primary)
+                .bold(),
+            Span::raw(format!("  {}", hosts::hosts_path().display())).fg(colors.text_secondary),
+        ];
+        if self.hosts_editor.modified {
+            title_spans.push(
+                Span::raw(format!("  ● {}", self.t(I18nKey::HostsModified)))
+                    .fg(colors.warning_color)
+                    .bold(),
+            );
+        }
+        let title = Paragraph::new(Line::from(title_spans))
+            .alignment(Alignment::Center)
+            .block(title_block);
+        frame.render_widget(title, chunks[0]);
 
-        let footer_block = Block::default()
+        let editor_block = Block::default()
             .borders(Borders::ALL)
-            .border_style(Style::default().fg(colors.brand_primary))
+            .border_style(Style::default().fg(colors.brand_secondary))
             .border_set(symbols::border::ROUNDED);
+        let inner = editor_block.inner(chunks[1]);
 
-        let footer = Paragraph::new(footer_text)
-            .alignment(Alignment::Center)
-            .block(footer_block);
-        frame.render_widget(footer, area);
+        // Margen izquierdo para los números de línea
+        let gutter = self.hosts_editor.lines.len().to_string().len() + 1;
+        self.hosts_editor.scroll_into_view(inner.height as usize);
+        let editor = &self.hosts_editor;
+
+        let lines: Vec<Line> = editor
+            .lines
+            .iter()
+            .enumerate()
+            .skip(editor.top)
+            .take(inner.height as usize)
+            .map(|(idx, line)| {
+                // Los tabuladores se muestran como un espacio para que cada
+                // carácter ocupe una columna
+                let line = line.replace('\t', " ");
+                let parts = hosts::split_line(&line);
+                let ip_color = if parts.has_valid_ip() {
+                    colors.info_color
+                } else {
+                    colors.error_color
+                };
+                Line::from(vec![
+                    Span::raw(format!("{:>width$} ", idx + 1, width = gutter - 1))
+                        .fg(colors.text_secondary),
+                    Span::raw(parts.ip.to_string()).fg(ip_color).bold(),
+                    Span::raw(parts.hosts.to_string()).fg(colors.text_primary),
+                    Span::raw(parts.comment.to_string())
+                        .fg(colors.text_secondary)
+                        .italic(),
+                ])
+            })
+            .collect();
+
+        frame.render_widget(Paragraph::new(lines).block(editor_block), chunks[1]);
+        frame.set_cursor_position((
+            inner.x + (gutter + editor.col) as u16,
+            inner.y + (editor.row - editor.top) as u16,
+        ));
+
+        self.render_styled_logs(frame, chunks[2], self.t(I18nKey::OperationsLog));
+
+        self.render_footer_keys(
+            frame,
+            chunks[3],
+            &[
+                ("Ctrl+S", I18nKey::FooterSave),
+                ("Ctrl+R", I18nKey::FooterResetDefault),
+                ("Esc", I18nKey::FooterBack),
+            ],
+        );
     }
 
-    /// Dibuja la vista de limpieza con diseño mejorado
-    fn draw_clean_view(&mut self, frame: &mut Frame) {
+    /// Dibuja la vista del Firewall de Windows
+    fn draw_firewall_view(&mut self, frame: &mut Frame) {
         let colors = self.get_colors();
         let main_block = Block::default().style(Style::default().bg(colors.bg_main));
         frame.render_widget(main_block, frame.area());
 
         let chunks = Layout::default()
             .direction(Direction::Vertical)
-            .margin(2)
+            .margin(responsive::margin(self.compact))
             .constraints([
-                Constraint::Length(3),
-                Constraint::Min(10),
-                Constraint::Length(10),
+                Constraint::Length(3), // Título
+                Constraint::Length(7), // Perfiles
+                Constraint::Min(5),    // Logs
+                Constraint::Length(3), // Footer
             ])
-            .split(frame.area());
+            .split(self.content_area);
 
-        // Título elegante
         let title_block = Block::default()
             .borders(Borders::ALL)
             .border_style(Style::default().fg(colors.brand_primary))
             .border_set(symbols::border::ROUNDED);
 
         let title = Paragraph::new(Line::from(vec![
-            Span::raw("🧹 ").fg(colors.brand_accent).bold(),
-            Span::raw(self.t(I18nKey::CleanTitle))
+            Span::raw("🧱 ").fg(colors.brand_accent).bold(),
+            Span::raw(self.t(I18nKey::FirewallTitle))
                 .fg(colors.text_primary)
                 .bold(),
         ]))
@@ -658,186 +5669,331 @@ impl App {
         .block(title_block);
         frame.render_widget(title, chunks[0]);
 
-        // Logs con diseño moderno
-        self.render_styled_logs(frame, chunks[1], self.t(I18nKey::OperationsLog));
+        let mut lines: Vec<Line> = self
+            .firewall_profiles
+            .iter()
+            .enumerate()
+            .map(|(idx, state)| {
+                let is_selected = idx == self.selected_item;
+                let (label, color) = match state.enabled {
+                    Some(true) => (self.t(I18nKey::FirewallOn), colors.success_color),
+                    Some(false) => (self.t(I18nKey::FirewallOff), colors.error_color),
+                    None => (self.t(I18nKey::FirewallUnknown), colors.text_secondary),
+                };
 
-        // Estadísticas elegantes
-        self.render_clean_stats(frame, chunks[2]);
+                let line = Line::from(vec![
+                    if is_selected {
+                        Span::raw(" ▶ ").fg(colors.brand_accent).bold()
+                    } else {
+                        Span::raw("   ")
+                    },
+                    Span::raw(format!("{:<12}", self.t(state.profile.label_key())))
+                        .fg(colors.text_primary)
+                        .bold(),
+                    Span::raw(label).fg(color).bold(),
+                ]);
+
+                if is_selected {
+                    line.style(Style::default().bg(colors.selection_bg))
+                } else {
+                    line
+                }
+            })
+            .collect();
+
+        if firewall::all_disabled(&self.firewall_profiles) {
+            lines.push(Line::from(""));
+            lines.push(Line::from(
+                Span::raw(format!("   ⚠ {}", self.t(I18nKey::FirewallAllOff)))
+                    .fg(colors.warning_color)
+                    .bold(),
+            ));
+        }
+
+        let profiles_block = Block::default()
+            .borders(Borders::ALL)
+            .border_style(Style::default().fg(colors.brand_secondary))
+            .border_set(symbols::border::ROUNDED);
+        frame.render_widget(Paragraph::new(lines).block(profiles_block), chunks[1]);
+
+        self.render_styled_logs(frame, chunks[2], self.t(I18nKey::OperationsLog));
+
+        self.render_footer_keys(
+            frame,
+            chunks[3],
+            &[
+                ("↑↓", I18nKey::FooterNavigate),
+                ("E", I18nKey::FooterEnable),
+                ("A", I18nKey::FooterEnableAll),
+                ("R", I18nKey::FooterRefresh),
+                ("Q/Esc", I18nKey::FooterBack),
+            ],
+        );
     }
 
-    /// Renderiza estadísticas de limpieza con diseño moderno
-    fn render_clean_stats(&self, frame: &mut Frame, area: Rect) {
+    /// Dibuja la vista de Microsoft Defender
+    fn draw_defender_view(&mut self, frame: &mut Frame) {
         let colors = self.get_colors();
+        let main_block = Block::default().style(Style::default().bg(colors.bg_main));
+        frame.render_widget(main_block, frame.area());
 
-        // Layout horizontal para las 3 estadísticas principales
-        let main_layout = Layout::default()
-            .direction(Direction::Horizontal)
+        let chunks = Layout::default()
+            .direction(Direction::Vertical)
+            .margin(responsive::margin(self.compact))
             .constraints([
-                Constraint::Percentage(33),
-                Constraint::Percentage(33),
-                Constraint::Percentage(34),
+                Constraint::Length(3), // Título
+                Constraint::Length(5), // Estado
+                Constraint::Min(5),    // Exclusiones
+                Constraint::Length(7), // Logs
+                Constraint::Length(3), // Footer
             ])
-            .split(area);
+            .split(self.content_area);
 
-        // Card 1: Elementos eliminados
-        let deleted_block = Block::default()
+        let title_block = Block::default()
             .borders(Borders::ALL)
-            .border_style(Style::default().fg(colors.success_color).bold())
-            .border_set(symbols::border::ROUNDED)
-            .style(Style::default().bg(colors.bg_alt));
+            .border_style(Style::default().fg(colors.brand_primary))
+            .border_set(symbols::border::ROUNDED);
 
-        let deleted_content = vec![
-            Line::from(""),
+        let title = Paragraph::new(Line::from(vec![
+            Span::raw("🔒 ").fg(colors.brand_accent).bold(),
+            Span::raw(self.t(I18nKey::DefenderTitle))
+                .fg(colors.text_primary)
+                .bold(),
+        ]))
+        .alignment(Alignment::Center)
+        .block(title_block);
+        frame.render_widget(title, chunks[0]);
+
+        let status = self.defender_status.clone().unwrap_or_default();
+        let unknown = self.t(I18nKey::DefenderUnknown);
+
+        let signature_age = status
+            .signature_age_days
+            .map(|age| self.t_args(I18nKey::DefenderDays, &[("count", &age.to_string())]))
+            .unwrap_or_else(|| unknown.to_string());
+        let (realtime, realtime_color) = match status.realtime_protection {
+            Some(true) => (self.t(I18nKey::DefenderOn), colors.success_color),
+            Some(false) => (self.t(I18nKey::DefenderOff), colors.error_color),
+            None => (unknown, colors.text_secondary),
+        };
+
+        let status_lines = vec![
             Line::from(vec![
-                Span::raw("     "),
-                Span::raw("✅").fg(colors.success_color).bold(),
+                Span::raw(format!("  {} ", self.t(I18nKey::DefenderLastScan)))
+                    .fg(colors.text_secondary),
+                Span::raw(status.last_quick_scan.as_deref().unwrap_or(unknown))
+                    .fg(colors.text_primary)
+                    .bold(),
             ]),
-            Line::from(""),
             Line::from(vec![
-                Span::raw("   "),
-                Span::raw(self.clean_stats.deleted_count.to_string())
-                    .fg(colors.success_color)
-                    .bold()
-                    .add_modifier(Modifier::UNDERLINED),
+                Span::raw(format!("  {} ", self.t(I18nKey::DefenderSignatureAge)))
+                    .fg(colors.text_secondary),
+                Span::raw(signature_age)
+                    .fg(if status.signatures_outdated() {
+                        colors.warning_color
+                    } else {
+                        colors.text_primary
+                    })
+                    .bold(),
             ]),
-            Line::from(""),
             Line::from(vec![
-                Span::raw(" "),
-                Span::raw(self.t(I18nKey::StatsDeleted))
-                    .fg(colors.text_secondary)
-                    .italic(),
+                Span::raw(format!("  {} ", self.t(I18nKey::DefenderRealtime)))
+                    .fg(colors.text_secondary),
+                Span::raw(realtime).fg(realtime_color).bold(),
             ]),
         ];
 
-        let deleted_widget = Paragraph::new(deleted_content)
-            .block(deleted_block)
-            .alignment(Alignment::Center);
-        frame.render_widget(deleted_widget, main_layout[0]);
-
-        // Card 2: Elementos omitidos
-        let failed_block = Block::default()
+        let status_block = Block::default()
             .borders(Borders::ALL)
-            .border_style(Style::default().fg(colors.warning_color).bold())
-            .border_set(symbols::border::ROUNDED)
-            .style(Style::default().bg(colors.bg_alt));
+            .border_style(Style::default().fg(colors.brand_secondary))
+            .border_set(symbols::border::ROUNDED);
+        frame.render_widget(Paragraph::new(status_lines).block(status_block), chunks[1]);
 
-        let failed_content = vec![
-            Line::from(""),
-            Line::from(vec![
-                Span::raw("     "),
-                Span::raw("⚠️").fg(colors.warning_color).bold(),
-            ]),
-            Line::from(""),
-            Line::from(vec![
-                Span::raw("   "),
-                Span::raw(self.clean_stats.failed_count.to_string())
-                    .fg(colors.warning_color)
-                    .bold()
-                    .add_modifier(Modifier::UNDERLINED),
-            ]),
-            Line::from(""),
-            Line::from(vec![
-                Span::raw(" "),
-                Span::raw(self.t(I18nKey::StatsSkipped))
+        let items: Vec<ListItem> = if status.exclusions.is_empty() {
+            let message = if status.exclusions_hidden {
+                self.t(I18nKey::DefenderExclusionsHidden)
+            } else {
+                self.t(I18nKey::DefenderNoExclusions)
+            };
+            vec![ListItem::new(Line::from(
+                Span::raw(format!("   {}", message))
                     .fg(colors.text_secondary)
                     .italic(),
-            ]),
-        ];
+            ))]
+        } else {
+            status
+                .exclusions
+                .iter()
+                .enumerate()
+                .map(|(idx, exclusion)| {
+                    let is_selected = idx == self.selected_item;
+                    let line = Line::from(vec![
+                        if is_selected {
+                            Span::raw(" ▶ ").fg(colors.brand_accent).bold()
+                        } else {
+                            Span::raw("   ")
+                        },
+                        Span::raw(format!("{:<10}  ", self.t(exclusion.kind.label_key())))
+                            .fg(colors.info_color),
+                        Span::raw(exclusion.value.as_str()).fg(colors.text_primary),
+                    ]);
+
+                    let style = if is_selected {
+                        Style::default().bg(colors.selection_bg)
+                    } else {
+                        Style::default()
+                    };
 
-        let failed_widget = Paragraph::new(failed_content)
-            .block(failed_block)
-            .alignment(Alignment::Center);
-        frame.render_widget(failed_widget, main_layout[1]);
+                    ListItem::new(line).style(style)
+                })
+                .collect()
+        };
 
-        // Card 3: Espacio liberado
-        let size_mb = self.clean_stats.size_freed as f64 / 1024.0 / 1024.0;
-        let freed_block = Block::default()
+        let exclusions_block = Block::default()
             .borders(Borders::ALL)
-            .border_style(Style::default().fg(colors.info_color).bold())
+            .border_style(Style::default().fg(colors.brand_secondary))
             .border_set(symbols::border::ROUNDED)
-            .style(Style::default().bg(colors.bg_alt));
-
-        let freed_content = vec![
-            Line::from(""),
-            Line::from(vec![
-                Span::raw("     "),
-                Span::raw("💾").fg(colors.info_color).bold(),
-            ]),
-            Line::from(""),
-            Line::from(vec![
-                Span::raw(" "),
-                Span::raw(format!("{:.2} MB", size_mb))
-                    .fg(colors.info_color)
-                    .bold()
-                    .add_modifier(Modifier::UNDERLINED),
-            ]),
-            Line::from(""),
-            Line::from(vec![
+            .title(Line::from(vec![
                 Span::raw(" "),
-                Span::raw(self.t(I18nKey::StatsFreed))
-                    .fg(colors.text_secondary)
-                    .italic(),
-            ]),
-        ];
-
-        let freed_widget = Paragraph::new(freed_content)
-            .block(freed_block)
-            .alignment(Alignment::Center);
-        frame.render_widget(freed_widget, main_layout[2]);
-    }
+                Span::raw(format!("{} ", self.t(I18nKey::DefenderExclusions)))
+                    .fg(colors.text_primary)
+                    .bold(),
+                Span::raw(format!("({}) ", status.exclusions.len())).fg(colors.text_secondary),
+            ]));
 
-    /// Dibuja la vista de red con diseño mejorado
-    fn draw_network_view(&mut self, frame: &mut Frame) {
-        self.draw_generic_operation_view(frame, "🌐", "Limpieza de Red");
+        self.mouse_targets.list = Some(Self::render_selectable_list(
+            frame,
+            chunks[2],
+            items,
+            exclusions_block,
+            self.selected_item,
+        ));
+
+        self.render_styled_logs(frame, chunks[3], self.t(I18nKey::OperationsLog));
+
+        self.render_footer_keys(
+            frame,
+            chunks[4],
+            &[
+                ("↑↓", I18nKey::FooterNavigate),
+                ("S", I18nKey::FooterQuickScan),
+                ("R", I18nKey::FooterRefresh),
+                ("Q/Esc", I18nKey::FooterBack),
+            ],
+        );
     }
 
-    /// Dibuja la vista de reparación con diseño mejorado
-    fn draw_repair_view(&mut self, frame: &mut Frame) {
-        self.draw_generic_operation_view(frame, "🔧", "Reparación del Sistema");
-    }
+    /// Dibuja la vista de cuarentena
+    fn draw_quarantine_view(&mut self, frame: &mut Frame) {
+        let colors = self.get_colors();
+        let main_block = Block::default().style(Style::default().bg(colors.bg_main));
+        frame.render_widget(main_block, frame.area());
 
-    /// Dibuja la vista de optimización
-    fn draw_optimize_view(&mut self, frame: &mut Frame) {
-        self.draw_generic_operation_view(frame, "⚡", "Optimización Avanzada");
-    }
+        let chunks = Layout::default()
+            .direction(Direction::Vertical)
+            .margin(responsive::margin(self.compact))
+            .constraints([
+                Constraint::Length(3), // Título
+                Constraint::Min(6),    // Elementos
+                Constraint::Length(7), // Logs
+                Constraint::Length(3), // Footer
+            ])
+            .split(self.content_area);
 
-    /// Dibuja la vista de Windows Update cleanup
-    fn draw_windows_update_view(&mut self, frame: &mut Frame) {
-        self.draw_generic_operation_view(frame, "🔄", "Limpieza de Windows Update");
-    }
+        let title_block = Block::default()
+            .borders(Borders::ALL)
+            .border_style(Style::default().fg(colors.brand_primary))
+            .border_set(symbols::border::ROUNDED);
 
-    /// Dibuja la vista de privacidad
-    fn draw_privacy_view(&mut self, frame: &mut Frame) {
-        self.draw_generic_operation_view(frame, "🔒", "Privacidad y Telemetría");
-    }
+        let title = Paragraph::new(Line::from(vec![
+            Span::raw("🛡️ ").fg(colors.brand_accent).bold(),
+            Span::raw(self.t(I18nKey::QuarantineTitle))
+                .fg(colors.text_primary)
+                .bold(),
+            Span::raw(format!(" ({})", self.quarantine_items.len())).fg(colors.text_secondary),
+        ]))
+        .alignment(Alignment::Center)
+        .block(title_block);
+        frame.render_widget(title, chunks[0]);
 
-    /// Dibuja la vista de limpieza de caché de navegadores
-    fn draw_browser_cache_view(&mut self, frame: &mut Frame) {
-        self.draw_generic_operation_view(frame, "🌐", "Caché de Navegadores");
-    }
+        let items: Vec<ListItem> = if self.quarantine_items.is_empty() {
+            vec![ListItem::new(Line::from(
+                Span::raw(format!("   {}", self.t(I18nKey::QuarantineEmpty)))
+                    .fg(colors.text_secondary)
+                    .italic(),
+            ))]
+        } else {
+            self.quarantine_items
+                .iter()
+                .enumerate()
+                .map(|(idx, item)| {
+                    let is_selected = idx == self.selected_item;
+                    let line = Line::from(vec![
+                        if is_selected {
+                            Span::raw(" ▶ ").fg(colors.brand_accent).bold()
+                        } else {
+                            Span::raw("   ")
+                        },
+                        Span::raw(format!("{}  ", self.i18n.format_date(&item.day)))
+                            .fg(colors.text_secondary),
+                        Span::raw(format!("{:>10}  ", self.i18n.format_size(item.size)))
+                            .fg(colors.info_color),
+                        Span::raw(item.original.display().to_string()).fg(colors.text_primary),
+                    ]);
 
-    /// Dibuja la vista de limpieza de logs del sistema
-    fn draw_system_logs_view(&mut self, frame: &mut Frame) {
-        self.draw_generic_operation_view(frame, "📋", "Logs del Sistema");
-    }
+                    let style = if is_selected {
+                        Style::default().bg(colors.selection_bg)
+                    } else {
+                        Style::default()
+                    };
 
-    /// Dibuja la vista de vaciado de papelera de reciclaje
-    fn draw_recycle_bin_view(&mut self, frame: &mut Frame) {
-        self.draw_generic_operation_view(frame, "🗑️", "Papelera de Reciclaje");
-    }
+                    ListItem::new(line).style(style)
+                })
+                .collect()
+        };
 
-    /// Dibuja la vista de optimización de inicio
-    fn draw_startup_optimizer_view(&mut self, frame: &mut Frame) {
-        self.draw_generic_operation_view(frame, "🚀", "Programas de Inicio");
-    }
+        let items_block = Block::default()
+            .borders(Borders::ALL)
+            .border_style(Style::default().fg(colors.brand_secondary))
+            .border_set(symbols::border::ROUNDED);
 
-    /// Dibuja la vista de efectos visuales
-    fn draw_visual_effects_view(&mut self, frame: &mut Frame) {
-        self.draw_generic_operation_view(frame, "🎨", "Efectos Visuales");
+        self.mouse_targets.list = Some(Self::render_selectable_list(
+            frame,
+            chunks[1],
+            items,
+            items_block,
+            self.selected_item,
+        ));
+
+        self.render_styled_logs(frame, chunks[2], self.t(I18nKey::OperationsLog));
+
+        self.render_footer_keys(
+            frame,
+            chunks[3],
+            &[
+                ("↑↓", I18nKey::FooterNavigate),
+                ("Enter/R", I18nKey::FooterRestore),
+                ("Q/Esc", I18nKey::FooterBack),
+            ],
+        );
     }
 
     /// Dibuja una vista genérica de operación
     fn draw_generic_operation_view(&mut self, frame: &mut Frame, icon: &str, title: &str) {
+        self.draw_operation_view_with_keys(frame, icon, title, &[]);
+    }
+
+    /// Dibuja una vista de operación con atajos propios en el footer
+    ///
+    /// Los atajos de la vista se muestran junto a los del estado de la
+    /// operación (ver `render_operation_footer`).
+    fn draw_operation_view_with_keys(
+        &mut self,
+        frame: &mut Frame,
+        icon: &str,
+        title: &str,
+        view_keys: &[(&'static str, I18nKey)],
+    ) {
         let colors = self.get_colors();
         let main_block = Block::default().style(Style::default().bg(colors.bg_main));
         frame.render_widget(main_block, frame.area());
@@ -845,141 +6001,688 @@ impl App {
         // Ajustar layout según si hay spinner o no
         let show_spinner = self.operation_state == OperationState::Running
             || self.operation_state == OperationState::Starting;
+        let cards = self.stat_cards();
+        let cards_height = self.stat_cards_height(&cards);
 
         let chunks = if show_spinner {
             Layout::default()
                 .direction(Direction::Vertical)
-                .margin(2)
+                .margin(responsive::margin(self.compact))
                 .constraints([
-                    Constraint::Length(3), // Título
-                    Constraint::Length(3), // Spinner
-                    Constraint::Min(7),    // Logs
-                    Constraint::Length(3), // Footer
+                    Constraint::Length(3),            // Título
+                    Constraint::Length(3),            // Spinner
+                    Constraint::Min(7),               // Logs
+                    Constraint::Length(cards_height), // Estadísticas
+                    Constraint::Length(3),            // Footer
                 ])
-                .split(frame.area())
+                .split(self.content_area)
         } else {
             Layout::default()
                 .direction(Direction::Vertical)
-                .margin(2)
+                .margin(responsive::margin(self.compact))
                 .constraints([
-                    Constraint::Length(3), // Título
-                    Constraint::Min(10),   // Logs
-                    Constraint::Length(3), // Footer
+                    Constraint::Length(3),            // Título
+                    Constraint::Min(10),              // Logs
+                    Constraint::Length(cards_height), // Estadísticas
+                    Constraint::Length(3),            // Footer
                 ])
-                .split(frame.area())
+                .split(self.content_area)
+        };
+
+        // Título
+        let title_block = Block::default()
+            .borders(Borders::ALL)
+            .border_style(Style::default().fg(colors.brand_primary))
+            .border_set(symbols::border::ROUNDED);
+
+        let title_widget = Paragraph::new(Line::from(vec![
+            Span::raw(format!("{} ", icon))
+                .fg(colors.brand_accent)
+                .bold(),
+            Span::raw(title).fg(colors.text_primary).bold(),
+        ]))
+        .alignment(Alignment::Center)
+        .block(title_block);
+        frame.render_widget(title_widget, chunks[0]);
+
+        if show_spinner {
+            // Spinner
+            self.render_spinner(frame, chunks[1]);
+
+            // Logs
+            self.render_styled_logs(frame, chunks[2], "Registro de Operaciones");
+        } else {
+            // Logs
+            self.render_styled_logs(frame, chunks[1], "Registro de Operaciones");
+        }
+
+        // Estadísticas
+        self.render_stat_cards(frame, chunks[chunks.len() - 2], cards);
+
+        // Footer
+        self.render_operation_footer(frame, chunks[chunks.len() - 1], view_keys);
+    }
+
+    /// Renderiza logs con estilo mejorado
+    fn render_styled_logs(&mut self, frame: &mut Frame, area: Rect, title: &str) {
+        self.render_log_panel(frame, area, title);
+    }
+
+    /// Renderiza un panel con las líneas de log indicadas
+    ///
+    /// Con una búsqueda activa solo se muestran las líneas que coinciden, con
+    /// la coincidencia seleccionada resaltada en la parte superior.
+    fn render_log_panel(&mut self, frame: &mut Frame, area: Rect, title: &str) {
+        let colors = self.get_colors();
+        let logs = self.displayed_logs();
+        let show_time = self.config.appearance.show_log_timestamps;
+
+        let mut title_spans = vec![
+            Span::raw(" "),
+            Span::raw("📋 ").fg(colors.brand_accent),
+            Span::raw(title).fg(colors.text_primary).bold(),
+            Span::raw(" "),
+        ];
+
+        let searching = self.log_search.is_active() || self.log_search.editing;
+        let (log_lines, scroll): (Vec<Line>, u16) = if searching {
+            let matches = self.log_search.matches(logs);
+            let current = self.log_search.current(matches.len());
+            let position = if matches.is_empty() {
+                self.t(I18nKey::LogSearchNoMatches).to_string()
+            } else {
+                format!("{}/{}", current + 1, matches.len())
+            };
+            title_spans.push(Span::raw("🔍 ").fg(colors.brand_accent));
+            title_spans.push(Span::raw(self.log_search.query()).fg(colors.brand_primary));
+            if self.log_search.editing {
+                title_spans.push(Span::raw("▏").fg(colors.brand_accent));
+            }
+            title_spans.push(Span::raw(format!(" ({}) ", position)).fg(colors.text_secondary));
+
+            let lines = matches
+                .iter()
+                .enumerate()
+                .map(|(pos, &idx)| {
+                    let line = Self::log_entry_line(&logs[idx], &colors, show_time);
+                    if pos == current {
+                        line.style(Style::default().bg(colors.selection_bg))
+                    } else {
+                        line
+                    }
+                })
+                .collect();
+            (lines, current as u16)
+        } else {
+            let lines = logs
+                .iter()
+                .map(|entry| Self::log_entry_line(entry, &colors, show_time))
+                .collect();
+            (lines, self.scroll_offset)
+        };
+
+        if !searching && !self.log_follow {
+            title_spans.push(
+                Span::raw(format!("⏸ {} ", self.t(I18nKey::LogFollowPaused)))
+                    .fg(colors.warning_color),
+            );
+        }
+
+        let logs_block = Block::default()
+            .borders(Borders::ALL)
+            .border_style(Style::default().fg(colors.brand_primary))
+            .border_set(symbols::border::ROUNDED)
+            .title(Line::from(title_spans));
+
+        let logs = Paragraph::new(log_lines)
+            .block(logs_block)
+            .wrap(Wrap { trim: true });
+
+        // Altura de los logs ajustados al ancho interior (incluye los bordes)
+        let content_height = logs.line_count(area.width.saturating_sub(2));
+        let max_scroll = content_height.saturating_sub(area.height as usize);
+        let max_scroll = u16::try_from(max_scroll).unwrap_or(u16::MAX);
+        let scroll = if searching {
+            scroll
+        } else if self.log_follow {
+            max_scroll
+        } else {
+            scroll.min(max_scroll)
+        };
+        frame.render_widget(logs.scroll((scroll, 0)), area);
+        self.mouse_targets.log_panel = Some(area);
+
+        if max_scroll > 0 {
+            let mut state = ScrollbarState::new(max_scroll as usize).position(scroll as usize);
+            let scrollbar = Scrollbar::new(ScrollbarOrientation::VerticalRight)
+                .begin_symbol(None)
+                .end_symbol(None)
+                .thumb_style(Style::default().fg(colors.brand_primary))
+                .track_style(Style::default().fg(colors.text_secondary));
+            frame.render_stateful_widget(
+                scrollbar,
+                area.inner(Margin {
+                    vertical: 1,
+                    horizontal: 0,
+                }),
+                &mut state,
+            );
+        }
+
+        self.log_page_height = area.height.saturating_sub(2);
+        if !searching {
+            self.scroll_offset = scroll;
+        }
+    }
+
+    /// Línea de un log coloreada según el nivel con el que se registró
+    fn log_entry_line<'a>(entry: &'a LogEntry, colors: &ColorPalette, show_time: bool) -> Line<'a> {
+        let color = match entry.level {
+            LogLevel::Error => colors.error_color,
+            LogLevel::Warning => colors.warning_color,
+            LogLevel::Debug => colors.text_secondary,
+            LogLevel::Info => colors.text_primary,
+        };
+        let mut spans = Vec::with_capacity(2);
+        if show_time {
+            spans.push(Span::raw(format!("{} ", entry.time())).fg(colors.text_secondary));
+        }
+        spans.push(Span::raw(entry.message.as_str()).fg(color));
+        Line::from(spans)
+    }
+
+    /// Renderiza el footer de las vistas de operación con los atajos válidos
+    /// en su estado actual
+    fn render_operation_footer(
+        &mut self,
+        frame: &mut Frame,
+        area: Rect,
+        view_keys: &[(&'static str, I18nKey)],
+    ) {
+        let context = FooterContext {
+            state: self.operation_state,
+            cancellable: self.jobs.active_for(self.current_view).is_some(),
+            stalled: self.operation_stalled,
+            awaiting_confirm: self.pending_reboot_confirm,
+            retryable: operation::name_for_view(self.current_view).is_some(),
+            failed_items: self.failed_items.len(),
+            has_logs: !self.operation_logs.is_empty(),
+            searching: self.log_search.is_active(),
         };
+        let keys = footer::operation_keys(&context, view_keys);
+        self.render_footer_keys(frame, area, &keys);
+    }
 
-        // Título
-        let title_block = Block::default()
+    /// Renderiza un footer a partir de una lista de atajos (tecla, descripción)
+    fn render_footer_keys(&mut self, frame: &mut Frame, area: Rect, keys: &[(&str, I18nKey)]) {
+        let colors = self.get_colors();
+        self.footer_keys = keys
+            .iter()
+            .map(|(key, label)| (key.to_string(), *label))
+            .collect();
+
+        let mut spans = Vec::new();
+        for (idx, (key, label)) in keys.iter().enumerate() {
+            if idx > 0 {
+                spans.push(Span::raw("•").fg(colors.brand_accent));
+            }
+            spans.push(Span::raw("  ").fg(colors.brand_accent));
+            spans.push(Span::raw(*key).fg(colors.brand_primary).bold());
+            spans.push(Span::raw(format!(" {}  ", self.t(*label))).fg(colors.text_secondary));
+        }
+
+        let footer_block = Block::default()
             .borders(Borders::ALL)
             .border_style(Style::default().fg(colors.brand_primary))
             .border_set(symbols::border::ROUNDED);
 
-        let title_widget = Paragraph::new(Line::from(vec![
-            Span::raw(format!("{} ", icon))
-                .fg(colors.brand_accent)
-                .bold(),
-            Span::raw(title).fg(colors.text_primary).bold(),
-        ]))
-        .alignment(Alignment::Center)
-        .block(title_block);
-        frame.render_widget(title_widget, chunks[0]);
+        let footer = Paragraph::new(Line::from(spans))
+            .alignment(Alignment::Center)
+            .block(footer_block);
+        frame.render_widget(footer, area);
+    }
 
-        if show_spinner {
-            // Spinner
-            self.render_spinner(frame, chunks[1]);
+    /// Dibuja en la primera fila el aviso de validación de `config.toml`
+    fn render_config_banner(&self, frame: &mut Frame) {
+        let colors = self.get_colors();
+        let mut text = format!(" ⚠️  config.toml: {}", self.config_warnings[0]);
+        if self.config_warnings.len() > 1 {
+            text.push_str(&format!(
+                " ({})",
+                self.t_args(
+                    I18nKey::ConfigWarningMore,
+                    &[("count", &(self.config_warnings.len() - 1).to_string())]
+                )
+            ));
+        }
 
-            // Logs
-            self.render_styled_logs(frame, chunks[2], "Registro de Operaciones");
+        let area = Rect {
+            height: 1,
+            ..frame.area()
+        };
+        let banner = Paragraph::new(text).style(
+            Style::default()
+                .fg(colors.bg_main)
+                .bg(colors.warning_color)
+                .add_modifier(Modifier::BOLD),
+        );
+        frame.render_widget(Clear, area);
+        frame.render_widget(banner, area);
+    }
 
-            // Footer
-            self.render_operation_footer(frame, chunks[3]);
-        } else {
-            // Logs
-            self.render_styled_logs(frame, chunks[1], "Registro de Operaciones");
+    /// Dibuja el aviso de reinicio en la parte superior (bajo el de la
+    /// configuración, si lo hay)
+    fn render_reboot_banner(&self, frame: &mut Frame) {
+        let colors = self.get_colors();
+        let text = match self.reboot_deadline {
+            // Sin el atajo solo se avisa
+            Some(deadline) if !self.reboot_key_is_free() => {
+                let remaining = deadline.saturating_duration_since(Instant::now());
+                format!(
+                    " 🔄 {}",
+                    self.t_args(
+                        I18nKey::RebootScheduledIn,
+                        &[("time", &utils::format_clock(remaining.as_secs()))]
+                    )
+                )
+            }
+            None if !self.reboot_key_is_free() => {
+                format!(" 🔄 {}", self.t(I18nKey::RebootNeeded))
+            }
+            Some(deadline) => {
+                let remaining = deadline.saturating_duration_since(Instant::now());
+                format!(
+                    " 🔄 {} · Ctrl+R: {}",
+                    self.t_args(
+                        I18nKey::RebootScheduledIn,
+                        &[("time", &utils::format_clock(remaining.as_secs()))]
+                    ),
+                    self.t(I18nKey::RebootCancel)
+                )
+            }
+            None => format!(
+                " 🔄 {} · Ctrl+R: {}",
+                self.t(I18nKey::RebootNeeded),
+                self.t_args(
+                    I18nKey::RebootSchedule,
+                    &[(
+                        "time",
+                        &utils::format_clock(self.config.reboot.delay().as_secs())
+                    )]
+                )
+            ),
+        };
+
+        let area = Rect {
+            y: frame.area().y + u16::from(!self.config_warnings.is_empty()),
+            height: 1,
+            ..frame.area()
+        };
+        let banner = Paragraph::new(text).style(
+            Style::default()
+                .fg(colors.bg_main)
+                .bg(colors.info_color)
+                .add_modifier(Modifier::BOLD),
+        );
+        frame.render_widget(Clear, area);
+        frame.render_widget(banner, area);
+    }
 
-            // Footer
-            self.render_operation_footer(frame, chunks[2]);
+    /// Indica si Ctrl+R queda libre para el reinicio en la vista actual
+    ///
+    /// El editor de hosts usa Ctrl+R para restablecer el archivo.
+    fn reboot_key_is_free(&self) -> bool {
+        self.current_view != View::Hosts
+    }
+
+    /// Programa el reinicio o, si ya está programado, lo cancela
+    fn toggle_reboot(&mut self) {
+        if self.reboot_deadline.is_some() {
+            let (level, message) = match reboot::cancel() {
+                Ok(()) => {
+                    self.reboot_deadline = None;
+                    (
+                        LogLevel::Info,
+                        format!("✅ {}", self.t(I18nKey::RebootCancelled)),
+                    )
+                }
+                Err(error) => (
+                    LogLevel::Error,
+                    format!(
+                        "❌ {}",
+                        self.t_args(I18nKey::RebootCancelError, &[("error", &error)])
+                    ),
+                ),
+            };
+            logger::log(Some(self), level, message);
+            return;
         }
+
+        let delay = self.config.reboot.delay();
+        let (level, message) = match reboot::schedule(delay) {
+            Ok(()) => {
+                self.reboot_deadline = Some(Instant::now() + delay);
+                let time = utils::format_clock(delay.as_secs());
+                (
+                    LogLevel::Info,
+                    format!(
+                        "🔄 {}",
+                        self.t_args(I18nKey::RebootScheduled, &[("time", &time)])
+                    ),
+                )
+            }
+            Err(error) => (
+                LogLevel::Error,
+                format!(
+                    "❌ {}",
+                    self.t_args(I18nKey::RebootScheduleError, &[("error", &error)])
+                ),
+            ),
+        };
+        logger::log(Some(self), level, message);
     }
 
-    /// Renderiza logs con estilo mejorado
-    fn render_styled_logs(&self, frame: &mut Frame, area: Rect, title: &str) {
+    /// Dibuja la ventana de ayuda con los atajos, centrada sobre la vista
+    fn render_help_overlay(&self, frame: &mut Frame) {
         let colors = self.get_colors();
-        let log_lines: Vec<Line> = self
-            .operation_logs
+        let view_keys: Vec<(&str, I18nKey)> = self
+            .footer_keys
             .iter()
-            .map(|log| {
-                // Colorear logs según contenido (optimizado para reducir allocaciones)
-                let span = if log.contains("✅") {
-                    Span::raw(log.as_str()).fg(colors.success_color)
-                } else if log.contains("⚠️") || log.contains("ℹ️") {
-                    Span::raw(log.as_str()).fg(colors.warning_color)
-                } else if log.contains("❌") || log.contains("⛔") {
-                    Span::raw(log.as_str()).fg(colors.error_color)
-                } else if log.contains("🧹")
-                    || log.contains("🌐")
-                    || log.contains("🔧")
-                    || log.contains("⚡")
-                    || log.contains("🔄")
-                    || log.contains("🔒")
-                {
-                    Span::raw(log.as_str()).fg(colors.brand_primary).bold()
-                } else {
-                    Span::raw(log.as_str()).fg(colors.text_primary)
-                };
-                Line::from(span)
-            })
+            .map(|(key, label)| (key.as_str(), *label))
             .collect();
 
-        let logs_block = Block::default()
+        let mut lines = Vec::new();
+        for (section, keys) in [
+            (I18nKey::HelpGeneral, help::GENERAL),
+            (I18nKey::HelpLogs, help::LOGS),
+            (I18nKey::HelpView, view_keys.as_slice()),
+        ] {
+            if keys.is_empty() {
+                continue;
+            }
+            if !lines.is_empty() {
+                lines.push(Line::default());
+            }
+            lines.push(Line::from(
+                Span::raw(format!(" {}", self.t(section)))
+                    .fg(colors.brand_accent)
+                    .bold(),
+            ));
+            for (key, label) in keys {
+                lines.push(Line::from(vec![
+                    Span::raw(format!("   {:<12}", key))
+                        .fg(colors.brand_primary)
+                        .bold(),
+                    Span::raw(self.t(*label)).fg(colors.text_primary),
+                ]));
+            }
+        }
+
+        let area = help::centered(frame.area(), 64, lines.len() as u16 + 2);
+        let block = Block::default()
             .borders(Borders::ALL)
-            .border_style(Style::default().fg(colors.brand_primary))
+            .border_style(Style::default().fg(colors.brand_accent))
             .border_set(symbols::border::ROUNDED)
+            .style(Style::default().bg(colors.bg_main))
             .title(Line::from(vec![
-                Span::raw(" "),
-                Span::raw("📋 ").fg(colors.brand_accent),
-                Span::raw(title).fg(colors.text_primary).bold(),
-                Span::raw(" "),
-            ]));
-
-        let logs = Paragraph::new(log_lines)
-            .block(logs_block)
-            .wrap(Wrap { trim: true })
-            .scroll((self.scroll_offset, 0));
-        frame.render_widget(logs, area);
+                Span::raw(" ❓ ").fg(colors.brand_accent),
+                Span::raw(format!("{} ", self.t(I18nKey::HelpTitle)))
+                    .fg(colors.text_primary)
+                    .bold(),
+            ]))
+            .title_bottom(
+                Line::from(
+                    Span::raw(format!(" {} ", self.t(I18nKey::HelpClose)))
+                        .fg(colors.text_secondary),
+                )
+                .centered(),
+            );
+
+        frame.render_widget(Clear, area);
+        frame.render_widget(Paragraph::new(lines).block(block), area);
     }
 
-    /// Renderiza footer para vistas de operación
-    fn render_operation_footer(&self, frame: &mut Frame, area: Rect) {
+    /// Dibuja el aviso de la primera operación que quedó interrumpida
+    fn render_interrupted_overlay(&self, frame: &mut Frame) {
+        /// Objetivos que se listan antes de resumir el resto
+        const SHOWN_TARGETS: usize = 5;
+
+        let Some(entry) = self.interrupted.first() else {
+            return;
+        };
         let colors = self.get_colors();
-        let footer_text = Line::from(vec![
-            Span::raw("  ").fg(colors.brand_accent),
-            Span::raw("Q/Esc").fg(colors.brand_primary).bold(),
-            Span::raw(format!(" {}  ", self.t(I18nKey::FooterBack))).fg(colors.text_secondary),
-            Span::raw("•").fg(colors.brand_accent),
-            Span::raw("  ").fg(colors.brand_accent),
-            Span::raw("↑↓").fg(colors.brand_primary).bold(),
-            Span::raw(format!(" {}  ", self.t(I18nKey::FooterScroll))).fg(colors.text_secondary),
-            Span::raw("•").fg(colors.brand_accent),
-            Span::raw("  ").fg(colors.brand_accent),
-            Span::raw("Tab").fg(colors.brand_primary).bold(),
-            Span::raw(format!(" {}  ", self.t(I18nKey::FooterTheme))).fg(colors.text_secondary),
-            Span::raw("•").fg(colors.brand_accent),
-            Span::raw("  ").fg(colors.brand_accent),
-            Span::raw("L").fg(colors.brand_primary).bold(),
-            Span::raw(format!(" {}  ", self.t(I18nKey::FooterLanguage))).fg(colors.text_secondary),
-        ]);
+        let operation = entry.view().map_or_else(
+            || entry.operation.clone(),
+            |view| self.t(view.title_key()).to_string(),
+        );
+
+        let mut lines = vec![Line::from(
+            Span::raw(self.t_args(
+                I18nKey::InterruptedIntro,
+                &[("operation", &operation), ("started", &entry.started)],
+            ))
+            .fg(colors.text_primary),
+        )];
+        if !entry.targets.is_empty() {
+            lines.push(Line::default());
+            lines.push(Line::from(
+                Span::raw(self.t(I18nKey::InterruptedTargets)).fg(colors.text_secondary),
+            ));
+            for target in entry.targets.iter().take(SHOWN_TARGETS) {
+                lines.push(Line::from(
+                    Span::raw(format!("  • {}", target)).fg(colors.text_primary),
+                ));
+            }
+            if entry.targets.len() > SHOWN_TARGETS {
+                let more = (entry.targets.len() - SHOWN_TARGETS).to_string();
+                lines.push(Line::from(
+                    Span::raw(format!(
+                        "  {}",
+                        self.t_args(I18nKey::InterruptedMore, &[("count", &more)])
+                    ))
+                    .fg(colors.text_secondary),
+                ));
+            }
+        }
 
-        let footer_block = Block::default()
+        let mut keys = vec![("Enter", I18nKey::InterruptedResume)];
+        if let Some(rollback) = journal::rollback_for(&entry.operation, &self.config) {
+            let hint = match rollback {
+                Rollback::RestoreServices => I18nKey::InterruptedRollbackServices,
+                Rollback::Quarantine => I18nKey::InterruptedRollbackQuarantine,
+            };
+            lines.push(Line::default());
+            lines.push(Line::from(
+                Span::raw(format!("💡 {}", self.t(hint))).fg(colors.info_color),
+            ));
+            keys.push(("R", I18nKey::InterruptedRollback));
+        }
+        keys.push(("Esc", I18nKey::InterruptedDismiss));
+
+        let mut key_spans = vec![Span::raw(" ")];
+        for (key, label) in keys {
+            key_spans.push(Span::raw(key).fg(colors.brand_primary).bold());
+            key_spans.push(Span::raw(format!(" {}  ", self.t(label))).fg(colors.text_secondary));
+        }
+
+        let width = 68;
+        let paragraph = Paragraph::new(lines).wrap(Wrap { trim: false });
+        let height = paragraph.line_count(width - 4) as u16 + 4;
+        let area = help::centered(frame.area(), width, height);
+        let block = Block::default()
             .borders(Borders::ALL)
-            .border_style(Style::default().fg(colors.brand_primary))
-            .border_set(symbols::border::ROUNDED);
+            .border_style(Style::default().fg(colors.warning_color))
+            .border_set(symbols::border::ROUNDED)
+            .style(Style::default().bg(colors.bg_main))
+            .padding(Padding::uniform(1))
+            .title(Line::from(vec![
+                Span::raw(" ⚠️  ").fg(colors.warning_color),
+                Span::raw(format!("{} ", self.t(I18nKey::InterruptedTitle)))
+                    .fg(colors.text_primary)
+                    .bold(),
+            ]))
+            .title_bottom(Line::from(key_spans).centered());
 
-        let footer = Paragraph::new(footer_text)
-            .alignment(Alignment::Center)
-            .block(footer_block);
-        frame.render_widget(footer, area);
+        frame.render_widget(Clear, area);
+        frame.render_widget(paragraph.block(block), area);
+    }
+
+    /// Renderiza la barra de estado de la última línea
+    ///
+    /// A la izquierda, la ruta hasta la vista actual; a la derecha, los
+    /// permisos, el tema, el idioma, las tareas activas y la duración de la
+    /// operación de la vista.
+    fn render_status_bar(&self, frame: &mut Frame, area: Rect) {
+        let colors = self.get_colors();
+        let separator = || Span::raw("  │  ").fg(colors.text_secondary);
+
+        let mut breadcrumb = vec![Span::raw(" win_opt").fg(colors.brand_primary).bold()];
+        if self.current_view != View::MainMenu {
+            breadcrumb.push(Span::raw(" › ").fg(colors.text_secondary));
+            breadcrumb
+                .push(Span::raw(self.t(self.current_view.title_key())).fg(colors.text_primary));
+        }
+
+        let mut status = if self.elevated {
+            vec![Span::raw(format!("🛡️ {}", self.t(I18nKey::StatusAdmin))).fg(colors.success_color)]
+        } else {
+            vec![
+                Span::raw(format!("👤 {}", self.t(I18nKey::StatusStandardUser)))
+                    .fg(colors.warning_color),
+            ]
+        };
+        if let Some(label) = self.boot_mode.label_key() {
+            status.push(separator());
+            status.push(Span::raw(format!("🚧 {}", self.t(label))).fg(colors.warning_color));
+        }
+        if simulate::is_enabled() {
+            status.push(separator());
+            status.push(
+                Span::raw(format!("🎬 {}", self.t(I18nKey::StatusSimulation)))
+                    .fg(colors.info_color),
+            );
+        }
+        status.push(separator());
+        status.push(
+            Span::raw(format!(
+                "🎨 {} · {}",
+                self.t(self.theme.label_key()),
+                self.i18n.current_language().code()
+            ))
+            .fg(colors.text_secondary),
+        );
+        let active_jobs = self.jobs.active_count();
+        if active_jobs > 0 {
+            status.push(separator());
+            status.push(
+                Span::raw(format!(
+                    "⚙️ {}",
+                    self.t_args(I18nKey::StatusJobs, &[("count", &active_jobs.to_string())])
+                ))
+                .fg(colors.info_color),
+            );
+        }
+        if let Some(secs) = self
+            .jobs
+            .active_for(self.current_view)
+            .and_then(|job| job.elapsed())
+        {
+            status.push(separator());
+            status
+                .push(Span::raw(format!("⏱ {}", utils::format_clock(secs))).fg(colors.info_color));
+        }
+        status.push(Span::raw(" "));
+
+        let background = Style::default().bg(colors.bg_alt);
+        let status = Line::from(status);
+        let [left, right] = Layout::horizontal([
+            Constraint::Min(0),
+            Constraint::Length(status.width() as u16),
+        ])
+        .areas(area);
+        frame.render_widget(
+            Paragraph::new(Line::from(breadcrumb)).style(background),
+            left,
+        );
+        frame.render_widget(Paragraph::new(status).style(background), right);
+    }
+
+    /// Renderiza la paleta de comandos sobre la vista actual
+    fn render_palette_overlay(&self, frame: &mut Frame) {
+        let Some(palette) = &self.palette else {
+            return;
+        };
+        let colors = self.get_colors();
+        let matches = palette.matches(&self.i18n);
+        let selected = palette.selected(matches.len());
+
+        let query = if palette.query().is_empty() {
+            Span::raw(self.t(I18nKey::PalettePlaceholder))
+                .fg(colors.text_secondary)
+                .italic()
+        } else {
+            Span::raw(palette.query()).fg(colors.text_primary).bold()
+        };
+        let mut lines = vec![
+            Line::from(vec![
+                Span::raw(" › ").fg(colors.brand_accent).bold(),
+                query,
+                Span::raw("▏").fg(colors.brand_accent),
+            ]),
+            Line::default(),
+        ];
+
+        // Se muestran como mucho 10 resultados, desplazando la ventana para
+        // que el seleccionado quede siempre a la vista
+        const VISIBLE: usize = 10;
+        let first = selected.saturating_sub(VISIBLE - 1);
+        for (position, &idx) in matches.iter().enumerate().skip(first).take(VISIBLE) {
+            let item = &MENU_ITEMS[idx];
+            let badge = self.menu_item_badge(idx);
+            let line = Line::from(vec![
+                Span::raw(format!(" {}  ", item.icon)),
+                Span::raw(self.t(item.title)).fg(colors.text_primary).bold(),
+                Span::raw(badge).fg(colors.warning_color),
+                Span::raw(format!("  {}", self.t(item.description)))
+                    .fg(colors.text_secondary)
+                    .italic(),
+            ]);
+            lines.push(if position == selected {
+                line.style(Style::default().bg(colors.selection_bg))
+            } else {
+                line
+            });
+        }
+        if matches.is_empty() {
+            lines.push(Line::from(
+                Span::raw(format!(" {}", self.t(I18nKey::PaletteNoResults)))
+                    .fg(colors.text_secondary)
+                    .italic(),
+            ));
+        }
+
+        let area = help::centered(frame.area(), 72, VISIBLE as u16 + 4);
+        let block = Block::default()
+            .borders(Borders::ALL)
+            .border_style(Style::default().fg(colors.brand_accent))
+            .border_set(symbols::border::ROUNDED)
+            .style(Style::default().bg(colors.bg_main))
+            .title(Line::from(vec![
+                Span::raw(" 🔎 ").fg(colors.brand_accent),
+                Span::raw(format!("{} ", self.t(I18nKey::PaletteTitle)))
+                    .fg(colors.text_primary)
+                    .bold(),
+            ]))
+            .title_bottom(
+                Line::from(
+                    Span::raw(format!(" {} ", self.t(I18nKey::PaletteHint)))
+                        .fg(colors.text_secondary),
+                )
+                .centered(),
+            );
+
+        frame.render_widget(Clear, area);
+        frame.render_widget(Paragraph::new(lines).block(block), area);
     }
 
     /// Renderiza el spinner animado durante operaciones en curso
@@ -990,12 +6693,48 @@ impl App {
         let colors = self.get_colors();
 
         // El spinner calcula automáticamente su frame basado en el tiempo transcurrido
-        let spinner_text = Line::from(vec![
+        let mut spans = vec![
             Span::raw(self.spinner.frame())
                 .fg(colors.brand_accent)
                 .bold(),
-            Span::raw(" Operación en progreso...").fg(colors.text_primary),
-        ]);
+            Span::raw(format!(" {}", self.t(I18nKey::OpInProgress))).fg(colors.text_primary),
+        ];
+        // Tiempo transcurrido y, si el comando informa de su avance, el
+        // tiempo restante estimado
+        if let Some(timer) = &self.operation_timer {
+            spans.push(
+                Span::raw(format!("  ⏱ {}", utils::format_clock(timer.elapsed_secs())))
+                    .fg(colors.info_color),
+            );
+            if let Some(eta) = timer.eta_secs().filter(|_| !self.operation_stalled) {
+                let eta = utils::format_clock(eta);
+                spans.push(
+                    Span::raw(format!(
+                        " · {}",
+                        self.t_args(I18nKey::OpEta, &[("time", &eta)])
+                    ))
+                    .fg(colors.text_secondary),
+                );
+            }
+        }
+        if self.operation_stalled {
+            spans.push(Span::raw("  "));
+            spans.push(
+                Span::raw(format!("⚠️  {}", self.t(I18nKey::OpStalled))).fg(colors.warning_color),
+            );
+        } else if let Some(percent) = self.operation_progress {
+            // Barra de progreso real si el comando informa de su avance (DISM, SFC)
+            spans.push(Span::raw("  "));
+            spans.push(Span::raw(progress_bar(percent as u16, 30)).fg(colors.brand_accent));
+            spans.push(Span::raw(format!(" {:.1}%", percent)).fg(colors.text_primary));
+        }
+        if !self.operation_stalled && self.jobs.active_for(self.current_view).is_some() {
+            spans.push(
+                Span::raw(format!("  ({})", self.t(I18nKey::OpCancelHint)))
+                    .fg(colors.text_secondary),
+            );
+        }
+        let spinner_text = Line::from(spans);
 
         let spinner_block = Block::default()
             .borders(Borders::ALL)
@@ -1015,17 +6754,24 @@ impl App {
         let main_block = Block::default().style(Style::default().bg(colors.bg_main));
         frame.render_widget(main_block, frame.area());
 
+        let battery_height = if self.battery_health.is_empty() {
+            0
+        } else {
+            self.battery_health.len() as u16 * 2 + 2
+        };
+
         let chunks = Layout::default()
             .direction(Direction::Vertical)
-            .margin(2)
+            .margin(responsive::margin(self.compact))
             .constraints([
                 Constraint::Length(3),
-                Constraint::Length(9),
+                Constraint::Length(12),
                 Constraint::Length(8),
+                Constraint::Length(battery_height),
                 Constraint::Min(5),
                 Constraint::Length(3),
             ])
-            .split(frame.area());
+            .split(self.content_area);
 
         // Título
         let title_block = Block::default()
@@ -1046,31 +6792,109 @@ impl App {
         let mut sys = System::new_all();
         sys.refresh_all();
 
-        // Información del SO
-        self.render_os_info(frame, chunks[1], &sys);
+        // Información del SO y red
+        let top_chunks = Layout::default()
+            .direction(Direction::Horizontal)
+            .constraints([Constraint::Percentage(50), Constraint::Percentage(50)])
+            .split(chunks[1]);
+        self.render_os_info(frame, top_chunks[0], &sys);
+        self.render_network_info(frame, top_chunks[1]);
 
-        // CPU y Memoria
-        self.render_cpu_mem_info(frame, chunks[2], &sys);
+        // CPU y Memoria, y procesos que más consumen
+        let middle_chunks = Layout::default()
+            .direction(Direction::Horizontal)
+            .constraints([Constraint::Percentage(50), Constraint::Percentage(50)])
+            .split(chunks[2]);
+        self.render_cpu_mem_info(frame, middle_chunks[0], &sys);
+        self.render_top_processes(frame, middle_chunks[1]);
+
+        // Batería (solo portátiles)
+        if !self.battery_health.is_empty() {
+            self.render_battery_info(frame, chunks[3]);
+        }
 
         // Discos y gauge de memoria
-        self.render_storage_info(frame, chunks[3], &sys);
+        self.render_storage_info(frame, chunks[4], &sys);
 
-        // Footer
-        let footer_text = Line::from(vec![
-            Span::raw("  ").fg(colors.brand_accent),
-            Span::raw("Q/Esc").fg(colors.brand_primary).bold(),
-            Span::raw(format!(" {}  ", self.t(I18nKey::FooterBack))).fg(colors.text_secondary),
-        ]);
+        self.render_footer_keys(
+            frame,
+            chunks[5],
+            &[("S", I18nKey::FooterSort), ("Q/Esc", I18nKey::FooterBack)],
+        );
+    }
 
-        let footer_block = Block::default()
+    /// Dibuja la vista "Acerca de" con la versión y el diagnóstico
+    fn draw_about_view(&mut self, frame: &mut Frame) {
+        let colors = self.get_colors();
+        let main_block = Block::default().style(Style::default().bg(colors.bg_main));
+        frame.render_widget(main_block, frame.area());
+
+        let chunks = Layout::default()
+            .direction(Direction::Vertical)
+            .margin(responsive::margin(self.compact))
+            .constraints([
+                Constraint::Length(3),                                 // Título
+                Constraint::Length(self.diagnostics.len() as u16 + 4), // Diagnóstico
+                Constraint::Min(5),                                    // Logs
+                Constraint::Length(3),                                 // Footer
+            ])
+            .split(self.content_area);
+
+        let title_block = Block::default()
             .borders(Borders::ALL)
             .border_style(Style::default().fg(colors.brand_primary))
             .border_set(symbols::border::ROUNDED);
 
-        let footer = Paragraph::new(footer_text)
-            .alignment(Alignment::Center)
-            .block(footer_block);
-        frame.render_widget(footer, chunks[4]);
+        let title = Paragraph::new(Line::from(vec![
+            Span::raw("🏷️ ").fg(colors.brand_accent).bold(),
+            Span::raw(self.t(I18nKey::AboutTitle))
+                .fg(colors.text_primary)
+                .bold(),
+        ]))
+        .alignment(Alignment::Center)
+        .block(title_block);
+        frame.render_widget(title, chunks[0]);
+
+        let label_width = self
+            .diagnostics
+            .iter()
+            .map(|(label, _)| self.t(*label).width())
+            .max()
+            .unwrap_or(0);
+        let lines: Vec<Line> = self
+            .diagnostics
+            .iter()
+            .map(|(label, value)| {
+                Line::from(vec![
+                    Span::raw(format!(
+                        "  {}{} ",
+                        self.t(*label),
+                        " ".repeat(label_width - self.t(*label).width())
+                    ))
+                    .fg(colors.brand_primary)
+                    .bold(),
+                    Span::raw(value.clone()).fg(colors.text_primary),
+                ])
+            })
+            .collect();
+
+        let block = Block::default()
+            .borders(Borders::ALL)
+            .border_style(Style::default().fg(colors.info_color))
+            .border_set(symbols::border::ROUNDED)
+            .padding(Padding::vertical(1));
+        frame.render_widget(Paragraph::new(lines).block(block), chunks[1]);
+
+        self.render_styled_logs(frame, chunks[2], self.t(I18nKey::OperationsLog));
+
+        self.render_footer_keys(
+            frame,
+            chunks[3],
+            &[
+                ("Y", I18nKey::FooterCopyDiagnostics),
+                ("Q/Esc", I18nKey::FooterBack),
+            ],
+        );
     }
 
     /// Renderiza información del OS
@@ -1088,9 +6912,23 @@ impl App {
                     .bold(),
             ]));
 
-        let unknown = match self.i18n.current_language() {
-            crate::Language::Spanish => "Desconocido",
-            crate::Language::English => "Unknown",
+        let unknown = self.t(I18nKey::InfoUnknown);
+        let activation = match self.windows_activation {
+            None if self.activation_receiver.is_some() => Span::raw(self.t(I18nKey::InfoLoading))
+                .fg(colors.text_secondary)
+                .italic(),
+            Some(status @ Activation::Grace { days }) => {
+                Span::raw(self.t_args(status.label_key(), &[("days", &days.to_string())]))
+                    .fg(colors.warning_color)
+            }
+            Some(status) => {
+                Span::raw(self.t(status.label_key())).fg(if status == Activation::Licensed {
+                    colors.success_color
+                } else {
+                    colors.error_color
+                })
+            }
+            None => Span::raw(unknown).fg(colors.text_secondary),
         };
 
         let os_info = vec![
@@ -1110,6 +6948,29 @@ impl App {
                 Span::raw(System::os_version().unwrap_or_else(|| unknown.to_string()))
                     .fg(colors.text_primary),
             ]),
+            Line::from(vec![
+                Span::raw("  "),
+                Span::raw(format!("{} ", self.t(I18nKey::InfoEdition)))
+                    .fg(colors.brand_primary)
+                    .bold(),
+                Span::raw(winver::edition().unwrap_or_else(|| unknown.to_string()))
+                    .fg(colors.text_primary),
+            ]),
+            Line::from(vec![
+                Span::raw("  "),
+                Span::raw(format!("{} ", self.t(I18nKey::InfoInstalled)))
+                    .fg(colors.brand_primary)
+                    .bold(),
+                Span::raw(winver::install_date().unwrap_or_else(|| unknown.to_string()))
+                    .fg(colors.text_primary),
+            ]),
+            Line::from(vec![
+                Span::raw("  "),
+                Span::raw(format!("{} ", self.t(I18nKey::InfoActivation)))
+                    .fg(colors.brand_primary)
+                    .bold(),
+                activation,
+            ]),
             Line::from(vec![
                 Span::raw("  "),
                 Span::raw(format!("{} ", self.t(I18nKey::InfoKernel)))
@@ -1138,28 +6999,102 @@ impl App {
                 Span::raw(format!("{} ", self.t(I18nKey::InfoUptime)))
                     .fg(colors.brand_primary)
                     .bold(),
-                Span::raw(format_uptime(System::uptime())).fg(colors.success_color),
-            ]),
-        ];
+                Span::raw(format_uptime(System::uptime())).fg(colors.success_color),
+            ]),
+        ];
+
+        let os_widget = Paragraph::new(os_info).block(os_block);
+        frame.render_widget(os_widget, area);
+    }
+
+    /// Renderiza la configuración de red de los adaptadores conectados
+    fn render_network_info(&self, frame: &mut Frame, area: Rect) {
+        let colors = self.get_colors();
+        let mut network_lines: Vec<Line> = vec![];
+
+        for adapter in &self.network_info {
+            if !network_lines.is_empty() {
+                network_lines.push(Line::from(""));
+            }
+
+            let mut header = vec![
+                Span::raw("  "),
+                Span::raw(format!("🔌 {}", adapter.name))
+                    .fg(colors.brand_primary)
+                    .bold(),
+            ];
+            if let Some(speed) = &adapter.link_speed {
+                header.push(Span::raw(format!("  {}", speed)).fg(colors.success_color));
+            }
+            if let Some(mac) = &adapter.mac {
+                header.push(
+                    Span::raw(format!("  {} {}", self.t(I18nKey::InfoMac), mac))
+                        .fg(colors.text_secondary),
+                );
+            }
+            network_lines.push(Line::from(header));
+
+            for (label, values) in [
+                (I18nKey::InfoAddresses, &adapter.addresses),
+                (I18nKey::InfoGateway, &adapter.gateways),
+                (I18nKey::InfoDns, &adapter.dns_servers),
+            ] {
+                let value = if values.is_empty() {
+                    "-".to_string()
+                } else {
+                    values.join(", ")
+                };
+                network_lines.push(Line::from(vec![
+                    Span::raw("     "),
+                    Span::raw(format!("{} ", self.t(label)))
+                        .fg(colors.brand_primary)
+                        .bold(),
+                    Span::raw(value).fg(colors.text_primary),
+                ]));
+            }
+        }
+
+        if network_lines.is_empty() {
+            let message = if self.network_receiver.is_some() {
+                I18nKey::InfoLoading
+            } else {
+                I18nKey::InfoNoNetwork
+            };
+            network_lines.push(Line::from(vec![
+                Span::raw("  "),
+                Span::raw(self.t(message))
+                    .fg(colors.text_secondary)
+                    .italic(),
+            ]));
+        }
+
+        let network_block = Block::default()
+            .borders(Borders::ALL)
+            .border_style(Style::default().fg(colors.info_color))
+            .border_set(symbols::border::ROUNDED)
+            .title(Line::from(vec![
+                Span::raw(" "),
+                Span::raw("🌐 ").fg(colors.brand_accent),
+                Span::raw(format!("{} ", self.t(I18nKey::InfoNetwork)))
+                    .fg(colors.text_primary)
+                    .bold(),
+            ]));
 
-        let os_widget = Paragraph::new(os_info).block(os_block);
-        frame.render_widget(os_widget, area);
+        let network_widget = Paragraph::new(network_lines).block(network_block);
+        frame.render_widget(network_widget, area);
     }
 
     /// Renderiza información de CPU y memoria
     fn render_cpu_mem_info(&self, frame: &mut Frame, area: Rect, sys: &System) {
         let colors = self.get_colors();
 
-        let unknown = match self.i18n.current_language() {
-            crate::Language::Spanish => "Desconocido",
-            crate::Language::English => "Unknown",
-        };
+        let unknown = self.t(I18nKey::InfoUnknown);
 
         let cpu_count = sys.cpus().len();
         let cpu_brand = sys.cpus().first().map(|cpu| cpu.brand()).unwrap_or(unknown);
 
-        let total_memory = sys.total_memory() as f64 / 1024.0 / 1024.0 / 1024.0;
-        let used_memory = sys.used_memory() as f64 / 1024.0 / 1024.0 / 1024.0;
+        let total_memory = self.i18n.format_size(sys.total_memory());
+        let used_memory = self.i18n.format_size(sys.used_memory());
 
         let cpu_mem_block = Block::default()
             .borders(Borders::ALL)
@@ -1194,14 +7129,14 @@ impl App {
                 Span::raw(format!("{} ", self.t(I18nKey::InfoMemTotal)))
                     .fg(colors.brand_primary)
                     .bold(),
-                Span::raw(format!("{:.2} GB", total_memory)).fg(colors.text_primary),
+                Span::raw(total_memory).fg(colors.text_primary),
             ]),
             Line::from(vec![
                 Span::raw("  "),
                 Span::raw(format!("{} ", self.t(I18nKey::InfoMemUsed)))
                     .fg(colors.brand_primary)
                     .bold(),
-                Span::raw(format!("{:.2} GB", used_memory)).fg(colors.warning_color),
+                Span::raw(used_memory).fg(colors.warning_color),
             ]),
         ];
 
@@ -1209,6 +7144,147 @@ impl App {
         frame.render_widget(cpu_mem_widget, area);
     }
 
+    /// Renderiza la tabla de procesos que más consumen
+    fn render_top_processes(&self, frame: &mut Frame, area: Rect) {
+        let colors = self.get_colors();
+        let sort = self.process_monitor.sort();
+
+        let column_style = |column: ProcessSort| {
+            let style = Style::default().fg(colors.text_secondary).bold();
+            if column == sort {
+                style.fg(colors.brand_primary)
+            } else {
+                style
+            }
+        };
+        let header = Row::new(vec![
+            Cell::from(self.t(I18nKey::TopProcessesColumn))
+                .style(Style::default().fg(colors.text_secondary).bold()),
+            Cell::from(self.t(I18nKey::TopProcessesCpu)).style(column_style(ProcessSort::Cpu)),
+            Cell::from(self.t(I18nKey::TopProcessesMemory))
+                .style(column_style(ProcessSort::Memory)),
+        ]);
+
+        let rows: Vec<Row> = self
+            .process_monitor
+            .top()
+            .iter()
+            .map(|process| {
+                let name = if process.count > 1 {
+                    format!("{} ({})", process.name, process.count)
+                } else {
+                    process.name.clone()
+                };
+                let cpu_color = if process.cpu > 50.0 {
+                    colors.error_color
+                } else if process.cpu > 20.0 {
+                    colors.warning_color
+                } else {
+                    colors.text_primary
+                };
+                Row::new(vec![
+                    Cell::from(name).fg(colors.text_primary),
+                    Cell::from(format!("{:.1}%", process.cpu)).fg(cpu_color),
+                    Cell::from(self.i18n.format_size(process.memory)).fg(colors.info_color),
+                ])
+            })
+            .collect();
+
+        let block = Block::default()
+            .borders(Borders::ALL)
+            .border_style(Style::default().fg(colors.brand_secondary))
+            .border_set(symbols::border::ROUNDED)
+            .title(Line::from(vec![
+                Span::raw(" "),
+                Span::raw("📊 ").fg(colors.brand_accent),
+                Span::raw(format!(
+                    "{} ({}) ",
+                    self.t(I18nKey::TopProcessesTitle),
+                    self.t(sort.label_key())
+                ))
+                .fg(colors.text_primary)
+                .bold(),
+            ]));
+
+        let table = Table::new(
+            rows,
+            [
+                Constraint::Min(10),
+                Constraint::Length(7),
+                Constraint::Length(10),
+            ],
+        )
+        .header(header)
+        .block(block);
+        frame.render_widget(table, area);
+    }
+
+    /// Renderiza la salud de las baterías con su capacidad y ciclos de carga
+    fn render_battery_info(&self, frame: &mut Frame, area: Rect) {
+        let colors = self.get_colors();
+        let mut battery_lines: Vec<Line> = vec![];
+
+        for battery in &self.battery_health {
+            let health = battery.health_percent();
+            let color = if health < 50 {
+                colors.error_color
+            } else if health < 80 {
+                colors.warning_color
+            } else {
+                colors.success_color
+            };
+
+            battery_lines.push(Line::from(vec![
+                Span::raw("  "),
+                Span::raw(format!("🔋 {}", battery.name))
+                    .fg(colors.brand_primary)
+                    .bold(),
+                Span::raw("  "),
+                Span::raw(progress_bar(health, 20)).fg(color),
+                Span::raw(format!(
+                    " {}% {}",
+                    health,
+                    self.t(I18nKey::InfoBatteryHealth)
+                ))
+                .fg(color)
+                .bold(),
+            ]));
+
+            let mut details = format!(
+                "{:.1} Wh / {:.1} Wh {}",
+                battery.full_charge_capacity_mwh as f64 / 1000.0,
+                battery.design_capacity_mwh as f64 / 1000.0,
+                self.t(I18nKey::InfoBatteryDesign)
+            );
+            if let Some(cycles) = battery.cycle_count {
+                details.push_str(&format!(
+                    "  •  {} {}",
+                    self.t(I18nKey::InfoBatteryCycles),
+                    cycles
+                ));
+            }
+            battery_lines.push(Line::from(vec![
+                Span::raw("     "),
+                Span::raw(details).fg(colors.text_secondary).italic(),
+            ]));
+        }
+
+        let battery_block = Block::default()
+            .borders(Borders::ALL)
+            .border_style(Style::default().fg(colors.success_color))
+            .border_set(symbols::border::ROUNDED)
+            .title(Line::from(vec![
+                Span::raw(" "),
+                Span::raw("🔋 ").fg(colors.brand_accent),
+                Span::raw(format!("{} ", self.t(I18nKey::InfoBattery)))
+                    .fg(colors.text_primary)
+                    .bold(),
+            ]));
+
+        let battery_widget = Paragraph::new(battery_lines).block(battery_block);
+        frame.render_widget(battery_widget, area);
+    }
+
     /// Renderiza información de almacenamiento con gráficos visuales
     fn render_storage_info(&self, frame: &mut Frame, area: Rect, sys: &System) {
         let colors = self.get_colors();
@@ -1260,8 +7336,12 @@ impl App {
             ]),
             Line::from(vec![
                 Span::raw("  "),
-                Span::raw(format!("{:.1} GB / {:.1} GB", used_memory, total_memory))
-                    .fg(colors.text_secondary),
+                Span::raw(format!(
+                    "{} / {}",
+                    self.i18n.format_size(sys.used_memory()),
+                    self.i18n.format_size(sys.total_memory())
+                ))
+                .fg(colors.text_secondary),
             ]),
         ];
 
@@ -1312,9 +7392,14 @@ impl App {
             // Info de espacio
             disk_lines.push(Line::from(vec![
                 Span::raw("     "),
-                Span::raw(format!("{:.1} GB / {:.1} GB", used_space, total_space))
-                    .fg(colors.text_secondary)
-                    .italic(),
+                Span::raw(format!(
+                    "{} / {}",
+                    self.i18n
+                        .format_size(disk.total_space().saturating_sub(disk.available_space())),
+                    self.i18n.format_size(disk.total_space())
+                ))
+                .fg(colors.text_secondary)
+                .italic(),
             ]));
         }
 
@@ -1335,9 +7420,13 @@ impl App {
     }
 }
 
+#[cfg(test)]
+mod snapshot_tests;
+
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::menu::MenuItem;
 
     #[test]
     fn test_app_default() {
@@ -1347,4 +7436,736 @@ mod tests {
         assert!(!app.should_quit);
         assert_eq!(app.operation_logs.len(), 0);
     }
+
+    #[test]
+    fn test_log_follow_pauses_on_manual_scroll() {
+        use ratatui::{Terminal, backend::TestBackend};
+
+        let mut app = App::default();
+        for i in 0..50 {
+            app.operation_logs
+                .push(LogEntry::new(LogLevel::Info, format!("línea {}", i)));
+        }
+        let mut terminal = Terminal::new(TestBackend::new(40, 10)).unwrap();
+        let mut draw = |app: &mut App| {
+            terminal
+                .draw(|frame| app.render_log_panel(frame, frame.area(), "Logs"))
+                .unwrap();
+        };
+
+        // Siguiendo: se muestran las últimas 8 líneas (10 menos los bordes)
+        draw(&mut app);
+        assert_eq!(app.scroll_offset, 42);
+
+        app.handle_operation_input(KeyCode::Up);
+        app.operation_logs
+            .push(LogEntry::new(LogLevel::Info, "línea nueva"));
+        draw(&mut app);
+        assert!(!app.log_follow);
+        assert_eq!(app.scroll_offset, 41);
+
+        app.handle_operation_input(KeyCode::Char('f'));
+        draw(&mut app);
+        assert_eq!(app.scroll_offset, 43);
+    }
+
+    #[test]
+    fn test_main_menu_uses_compact_layout_on_small_terminals() {
+        use ratatui::{Terminal, backend::TestBackend};
+
+        let mut app = App {
+            current_view: View::MainMenu,
+            ..App::default()
+        };
+        let mut screen = |width: u16, height: u16| {
+            let mut terminal = Terminal::new(TestBackend::new(width, height)).unwrap();
+            terminal.draw(|frame| app.draw(frame)).unwrap();
+            let buffer = terminal.backend().buffer().clone();
+            buffer
+                .content()
+                .iter()
+                .map(|cell| cell.symbol())
+                .collect::<String>()
+        };
+
+        // El banner solo aparece con el diseño completo
+        assert!(screen(100, 40).contains("███╗"));
+        assert!(!screen(60, 20).contains("███╗"));
+        assert!(app.compact);
+    }
+
+    #[test]
+    fn test_stat_cards_match_the_operation() {
+        use crate::types::{RepairStats, ServiceStats};
+        use ratatui::{Terminal, backend::TestBackend};
+
+        let mut app = App {
+            current_view: View::Repair,
+            ..App::default()
+        };
+        assert!(app.stat_cards().is_empty());
+
+        app.stats = Some(OperationStats::Repair(RepairStats {
+            dism_ok: Some(true),
+            sfc_ok: Some(true),
+            corruption_found: true,
+            corruption_fixed: true,
+            ..RepairStats::default()
+        }));
+        let cards = app.stat_cards();
+        assert_eq!(cards.len(), 3);
+        assert_eq!(cards[2].1, app.t(I18nKey::StatsCorruptionFixed));
+
+        let mut terminal = Terminal::new(TestBackend::new(100, 40)).unwrap();
+        terminal.draw(|frame| app.draw(frame)).unwrap();
+        let screen: String = terminal
+            .backend()
+            .buffer()
+            .content()
+            .iter()
+            .map(|cell| cell.symbol())
+            .collect();
+        assert!(screen.contains(app.t(I18nKey::StatsCorruption)));
+
+        // Las estadísticas de otra operación no se muestran en esta vista
+        app.stats = Some(OperationStats::Services(ServiceStats::default()));
+        assert!(app.stat_cards().is_empty());
+        app.current_view = View::Privacy;
+        assert_eq!(app.stat_cards().len(), 2);
+    }
+
+    #[test]
+    fn test_repair_view_shows_verdict_when_finished() {
+        use crate::repair::{DismResult, SfcResult};
+        use crate::types::RepairStats;
+        use ratatui::{Terminal, backend::TestBackend};
+
+        let mut app = App {
+            current_view: View::Repair,
+            operation_state: OperationState::Running,
+            stats: Some(OperationStats::Repair(RepairStats {
+                dism_ok: Some(false),
+                sfc_ok: Some(true),
+                dism: DismResult::SourceMissing,
+                sfc: SfcResult::Unrepaired,
+                ..RepairStats::default()
+            })),
+            ..App::default()
+        };
+        let screen = |app: &mut App| {
+            let mut terminal = Terminal::new(TestBackend::new(160, 50)).unwrap();
+            terminal.draw(|frame| app.draw(frame)).unwrap();
+            terminal
+                .backend()
+                .buffer()
+                .content()
+                .iter()
+                .map(|cell| cell.symbol())
+                .collect::<String>()
+        };
+        let summary = app
+            .t_args(
+                I18nKey::RepairVerdictSourceMissing,
+                &[("code", "0x800F081F")],
+            )
+            .to_string();
+
+        // Mientras la reparación sigue en marcha no hay diagnóstico
+        assert!(!screen(&mut app).contains(&summary));
+        app.operation_state = OperationState::Failed;
+        let text = screen(&mut app);
+        assert!(text.contains(&summary));
+        assert!(text.contains(app.t(I18nKey::RepairNextSteps)));
+    }
+
+    #[test]
+    fn test_repair_view_toggles_cbs_excerpt() {
+        use crate::types::RepairStats;
+        use ratatui::{Terminal, backend::TestBackend};
+
+        let line = "2024-05-01 10:14:00 [SR] Cannot repair member file";
+        let mut app = App {
+            current_view: View::Repair,
+            operation_state: OperationState::Completed,
+            stats: Some(OperationStats::Repair(RepairStats {
+                dism_ok: Some(true),
+                sfc_ok: Some(true),
+                cbs_excerpt: vec![line.to_string()],
+                ..RepairStats::default()
+            })),
+            ..App::default()
+        };
+        let screen = |app: &mut App| {
+            let mut terminal = Terminal::new(TestBackend::new(160, 50)).unwrap();
+            terminal.draw(|frame| app.draw(frame)).unwrap();
+            terminal
+                .backend()
+                .buffer()
+                .content()
+                .iter()
+                .map(|cell| cell.symbol())
+                .collect::<String>()
+        };
+
+        // Plegada solo se ve el título
+        let text = screen(&mut app);
+        assert!(text.contains(app.t(I18nKey::RepairCbsTitle)));
+        assert!(!text.contains(line));
+
+        app.handle_key(KeyEvent::from(KeyCode::Char('c')));
+        assert!(app.cbs_expanded);
+        assert!(screen(&mut app).contains(line));
+    }
+
+    #[test]
+    fn test_pending_reboot_holds_repair_until_confirmed() {
+        let mut app = App {
+            current_view: View::MainMenu,
+            pending_reboot: vec![PendingReboot::FileRenames],
+            ..App::default()
+        };
+
+        // Las operaciones no afectadas se ejecutan sin aviso
+        app.hold_for_pending_reboot(View::Clean);
+        assert!(!app.pending_reboot_confirm);
+
+        app.open_view(View::Repair);
+        assert_eq!(app.current_view, View::Repair);
+        assert!(app.pending_reboot_confirm);
+        assert!(app.jobs.active_for(View::Repair).is_none());
+        let reason = app.t(I18nKey::PendingRebootFileRenames).to_string();
+        assert!(
+            app.operation_logs
+                .iter()
+                .any(|log| log.message.contains(&reason))
+        );
+
+        app.handle_key(KeyEvent::from(KeyCode::Esc));
+        assert_eq!(app.current_view, View::MainMenu);
+        assert!(!app.pending_reboot_confirm);
+    }
+
+    #[test]
+    fn test_interrupted_operations_are_answered_one_by_one() {
+        let entry = |operation: &str| JournalEntry {
+            operation: operation.to_string(),
+            started: "2026-10-16 09:00".to_string(),
+            targets: Vec::new(),
+        };
+        let mut app = App {
+            current_view: View::MainMenu,
+            interrupted: vec![entry("clean"), entry("repair")],
+            ..App::default()
+        };
+
+        // El aviso se queda con las teclas hasta que se responde
+        app.handle_key(KeyEvent::from(KeyCode::Char('q')));
+        assert!(!app.should_quit);
+        assert_eq!(app.current_view, View::MainMenu);
+
+        // Sin copia de servicios ni cuarentena no hay nada que deshacer
+        app.handle_key(KeyEvent::from(KeyCode::Char('r')));
+        assert_eq!(app.interrupted.len(), 2);
+
+        app.handle_key(KeyEvent::from(KeyCode::Esc));
+        assert_eq!(app.interrupted, [entry("repair")]);
+        assert_eq!(app.current_view, View::MainMenu);
+
+        app.handle_key(KeyEvent::from(KeyCode::Enter));
+        assert!(app.interrupted.is_empty());
+        assert_eq!(app.current_view, View::Repair);
+    }
+
+    #[test]
+    fn test_new_release_without_executable_is_not_downloaded() {
+        let mut app = App {
+            current_view: View::MainMenu,
+            new_release: Some(Release {
+                version: "9.0.0".to_string(),
+                download_url: None,
+                checksum_url: None,
+            }),
+            ..App::default()
+        };
+        assert!(!app.release_downloadable());
+        app.handle_key(KeyEvent::from(KeyCode::Char('u')));
+        assert!(app.release_download_receiver.is_none());
+
+        // Ni tampoco sin sumas con las que comprobarlo
+        app.new_release.as_mut().unwrap().download_url = Some("https://example.com".to_string());
+        assert!(!app.release_downloadable());
+
+        // Una vez descargada no se vuelve a ofrecer
+        app.new_release.as_mut().unwrap().checksum_url =
+            Some("https://example.com/checksums.txt".to_string());
+        assert!(app.release_downloadable());
+        app.release_download = Some(Ok("win_opt-9.0.0.exe".into()));
+        assert!(!app.release_downloadable());
+    }
+
+    #[test]
+    fn test_uptime_reboot_hint_yields_to_the_reboot_banner() {
+        let day = Duration::from_secs(24 * 60 * 60);
+        let mut app = App {
+            current_view: View::MainMenu,
+            launch_uptime: Some((day * 3, Instant::now())),
+            ..App::default()
+        };
+        assert_eq!(app.uptime_reboot_days(), None);
+
+        app.launch_uptime = Some((day * 20, Instant::now()));
+        assert_eq!(app.uptime_reboot_days(), Some(20));
+
+        app.needs_reboot = true;
+        assert_eq!(app.uptime_reboot_days(), None);
+    }
+
+    #[test]
+    fn test_ctrl_r_resets_hosts_while_a_reboot_is_pending() {
+        let mut app = App {
+            current_view: View::Hosts,
+            needs_reboot: true,
+            ..App::default()
+        };
+        app.handle_key(KeyEvent::new(KeyCode::Char('r'), KeyModifiers::CONTROL));
+        assert!(app.hosts_editor.modified);
+        assert!(app.reboot_deadline.is_none());
+        assert!(
+            app.operation_logs
+                .iter()
+                .any(|log| log.message.contains("Ctrl+S"))
+        );
+    }
+
+    #[test]
+    fn test_path_clean_asks_for_confirmation() {
+        use crate::env_path::{PathIssue, PathScope};
+
+        let mut app = App {
+            current_view: View::PathCleaner,
+            path_entries: vec![PathEntry {
+                scope: PathScope::User,
+                value: "C:\\Old".to_string(),
+                issue: Some(PathIssue::Missing),
+            }],
+            ..App::default()
+        };
+        app.handle_key(KeyEvent::from(KeyCode::Char('c')));
+        assert!(app.path_clean_confirm);
+
+        // Cualquier otra tecla cancela la confirmación
+        app.handle_key(KeyEvent::from(KeyCode::Down));
+        assert!(!app.path_clean_confirm);
+    }
+
+    #[test]
+    fn test_info_queries_are_collected_when_they_finish() {
+        let (battery_sender, battery_receiver) = std::sync::mpsc::channel();
+        let (activation_sender, activation_receiver) = std::sync::mpsc::channel();
+        let mut app = App {
+            current_view: View::Info,
+            battery_receiver: Some(battery_receiver),
+            activation_receiver: Some(activation_receiver),
+            ..App::default()
+        };
+        app.poll_info_queries();
+        assert!(app.battery_receiver.is_some());
+        assert!(app.activation_receiver.is_some());
+
+        activation_sender
+            .send(Some(Activation::Grace { days: 12 }))
+            .unwrap();
+        app.poll_info_queries();
+        assert_eq!(app.windows_activation, Some(Activation::Grace { days: 12 }));
+        assert!(app.activation_receiver.is_none());
+
+        let battery = BatteryHealth {
+            name: "DELL 7FHHV".to_string(),
+            design_capacity_mwh: 60_000,
+            full_charge_capacity_mwh: 48_000,
+            cycle_count: Some(312),
+        };
+        battery_sender.send(vec![battery.clone()]).unwrap();
+        app.poll_info_queries();
+        assert_eq!(app.battery_health, [battery]);
+        assert!(app.battery_receiver.is_none());
+    }
+
+    #[test]
+    fn test_tasks_list_is_loaded_in_the_background() {
+        let (sender, receiver) = std::sync::mpsc::channel();
+        let mut app = App {
+            current_view: View::Tasks,
+            operation_state: OperationState::Running,
+            tasks_receiver: Some(receiver),
+            ..App::default()
+        };
+        app.poll_tasks_list();
+        assert!(app.tasks_receiver.is_some());
+        assert_eq!(app.operation_state, OperationState::Running);
+
+        let task = ScheduledTask {
+            path: "\\Backup nocturno".to_string(),
+            state: TaskState::Ready,
+            last_run: None,
+            next_run: None,
+            author: String::new(),
+            vendor: String::new(),
+            at_startup: false,
+            command: String::new(),
+            marked: false,
+        };
+        sender.send(Ok(vec![task.clone()])).unwrap();
+        app.poll_tasks_list();
+        assert!(app.tasks_receiver.is_none());
+        assert_eq!(app.scheduled_tasks, std::slice::from_ref(&task));
+        assert_eq!(app.operation_state, OperationState::Completed);
+
+        // Un resultado que llega tras salir de la vista no se muestra
+        let (sender, receiver) = std::sync::mpsc::channel();
+        app.tasks_receiver = Some(receiver);
+        app.current_view = View::Drivers;
+        app.operation_logs.clear();
+        sender.send(Ok(vec![task])).unwrap();
+        app.poll_tasks_list();
+        assert!(app.tasks_receiver.is_none());
+        assert!(app.operation_logs.is_empty());
+    }
+
+    #[test]
+    fn test_info_view_shows_loading_until_queries_finish() {
+        use ratatui::{Terminal, backend::TestBackend};
+
+        let (activation_sender, activation_receiver) = std::sync::mpsc::channel();
+        let (network_sender, network_receiver) = std::sync::mpsc::channel();
+        let mut app = App {
+            current_view: View::Info,
+            activation_receiver: Some(activation_receiver),
+            network_receiver: Some(network_receiver),
+            ..App::default()
+        };
+        let screen = |app: &mut App| {
+            let mut terminal = Terminal::new(TestBackend::new(160, 50)).unwrap();
+            terminal.draw(|frame| app.draw(frame)).unwrap();
+            terminal
+                .backend()
+                .buffer()
+                .content()
+                .iter()
+                .map(|cell| cell.symbol())
+                .collect::<String>()
+        };
+        let loading = app.t(I18nKey::InfoLoading).to_string();
+        assert!(screen(&mut app).contains(&loading));
+
+        activation_sender.send(Some(Activation::Licensed)).unwrap();
+        network_sender
+            .send(vec![NetworkInfo {
+                name: "Wi-Fi".to_string(),
+                addresses: vec!["192.168.1.20".to_string()],
+                ..NetworkInfo::default()
+            }])
+            .unwrap();
+        app.poll_info_queries();
+        assert!(app.network_receiver.is_none());
+        let text = screen(&mut app);
+        assert!(!text.contains(&loading));
+        assert!(text.contains(app.t(I18nKey::ActivationLicensed)));
+        assert!(text.contains("192.168.1.20"));
+    }
+
+    #[test]
+    fn test_info_view_sorts_top_processes_with_s() {
+        let mut app = App {
+            current_view: View::Info,
+            ..App::default()
+        };
+        assert_eq!(app.process_monitor.sort(), ProcessSort::Memory);
+        app.handle_key(KeyEvent::from(KeyCode::Char('s')));
+        assert_eq!(app.process_monitor.sort(), ProcessSort::Cpu);
+        app.handle_key(KeyEvent::from(KeyCode::Char('S')));
+        assert_eq!(app.process_monitor.sort(), ProcessSort::Memory);
+        assert_eq!(app.current_view, View::Info);
+    }
+
+    #[test]
+    fn test_reboot_banner_shows_on_every_view() {
+        use ratatui::{Terminal, backend::TestBackend};
+
+        let mut app = App {
+            current_view: View::MainMenu,
+            ..App::default()
+        };
+        let screen = |app: &mut App| {
+            let mut terminal = Terminal::new(TestBackend::new(120, 40)).unwrap();
+            terminal.draw(|frame| app.draw(frame)).unwrap();
+            terminal
+                .backend()
+                .buffer()
+                .content()
+                .iter()
+                .map(|cell| cell.symbol())
+                .collect::<String>()
+        };
+        let needed = app.t(I18nKey::RebootNeeded).to_string();
+        assert!(!screen(&mut app).contains(&needed));
+
+        app.needs_reboot = true;
+        assert!(screen(&mut app).contains(&needed));
+        app.current_view = View::Settings;
+        assert!(screen(&mut app).contains(&needed));
+
+        // Con el reinicio programado se muestra la cuenta atrás
+        app.reboot_deadline = Some(Instant::now() + std::time::Duration::from_secs(90));
+        let text = screen(&mut app);
+        assert!(text.contains(app.t(I18nKey::RebootCancel)));
+        assert!(!text.contains(&needed));
+    }
+
+    #[test]
+    fn test_status_bar_shows_breadcrumb() {
+        use ratatui::{Terminal, backend::TestBackend};
+
+        let mut app = App {
+            current_view: View::Settings,
+            ..App::default()
+        };
+        let mut terminal = Terminal::new(TestBackend::new(100, 30)).unwrap();
+        terminal.draw(|frame| app.draw(frame)).unwrap();
+
+        let buffer = terminal.backend().buffer();
+        let last_row: String = (0..100).map(|x| buffer[(x, 29)].symbol()).collect();
+        let breadcrumb = format!("win_opt › {}", app.t(View::Settings.title_key()));
+        assert!(last_row.contains(&breadcrumb));
+        assert!(last_row.contains(app.i18n.current_language().code()));
+        assert_eq!(app.content_area.height, 29);
+    }
+
+    #[test]
+    fn test_palette_opens_the_matching_view() {
+        let mut app = App {
+            current_view: View::MainMenu,
+            ..App::default()
+        };
+        app.i18n.set_language(Language::English);
+
+        app.handle_key(KeyEvent::new(KeyCode::Char('p'), KeyModifiers::CONTROL));
+        assert!(app.palette.is_some());
+        for c in "settngs".chars() {
+            app.handle_key(KeyEvent::from(KeyCode::Char(c)));
+        }
+        app.handle_key(KeyEvent::from(KeyCode::Enter));
+
+        assert!(app.palette.is_none());
+        assert_eq!(app.current_view, View::Settings);
+    }
+
+    #[test]
+    fn test_locked_menu_items_can_be_blocked() {
+        let repair = MENU_ITEMS
+            .iter()
+            .position(|item| item.action == MenuAction::Open(View::Repair))
+            .unwrap();
+        let mut app = App {
+            current_view: View::MainMenu,
+            selected_menu_item: repair,
+            menu_locked: MENU_ITEMS.iter().map(MenuItem::requires_admin).collect(),
+            ..App::default()
+        };
+
+        app.config.appearance.lock_admin_items = true;
+        app.handle_menu_input(KeyCode::Enter);
+        assert_eq!(app.current_view, View::MainMenu);
+    }
+
+    #[test]
+    fn test_safe_mode_blocks_unavailable_items() {
+        use ratatui::{Terminal, backend::TestBackend};
+
+        let defender = MENU_ITEMS
+            .iter()
+            .position(|item| item.action == MenuAction::Open(View::Defender))
+            .unwrap();
+        let mut app = App {
+            current_view: View::MainMenu,
+            selected_menu_item: defender,
+            menu_locked: vec![false; MENU_ITEMS.len()],
+            boot_mode: BootMode::Safe,
+            ..App::default()
+        };
+
+        app.handle_menu_input(KeyCode::Enter);
+        assert_eq!(app.current_view, View::MainMenu);
+
+        let mut terminal = Terminal::new(TestBackend::new(120, 60)).unwrap();
+        terminal.draw(|frame| app.draw(frame)).unwrap();
+        let text: String = terminal
+            .backend()
+            .buffer()
+            .content()
+            .iter()
+            .map(|cell| cell.symbol())
+            .collect();
+        assert!(text.contains(app.t(I18nKey::MenuSafeModeUnavailable)));
+        assert!(text.contains(app.t(I18nKey::StatusSafeMode)));
+    }
+
+    #[test]
+    fn test_log_paging_is_clamped_to_content() {
+        use ratatui::{Terminal, backend::TestBackend};
+
+        let mut app = App::default();
+        for i in 0..30 {
+            app.operation_logs
+                .push(LogEntry::new(LogLevel::Info, format!("línea {}", i)));
+        }
+        let mut terminal = Terminal::new(TestBackend::new(40, 10)).unwrap();
+        let mut press = |app: &mut App, key: KeyCode| {
+            app.handle_operation_input(key);
+            terminal
+                .draw(|frame| app.render_log_panel(frame, frame.area(), "Logs"))
+                .unwrap();
+            app.scroll_offset
+        };
+
+        // 30 líneas en 8 visibles: el desplazamiento máximo es 22
+        assert_eq!(press(&mut app, KeyCode::Home), 0);
+        assert_eq!(press(&mut app, KeyCode::PageDown), 7);
+        assert_eq!(press(&mut app, KeyCode::PageDown), 14);
+        assert_eq!(press(&mut app, KeyCode::PageDown), 21);
+        assert_eq!(press(&mut app, KeyCode::PageDown), 22);
+        assert_eq!(press(&mut app, KeyCode::PageUp), 15);
+        assert_eq!(press(&mut app, KeyCode::End), 22);
+        assert!(!app.log_follow);
+    }
+
+    #[test]
+    fn test_reload_config_applies_or_keeps_previous() {
+        let mut app = App::default();
+        let mut config = Config::default();
+        config.appearance.theme = Theme::Light;
+        config.language.language = crate::i18n::Language::English;
+        config.logging.level = app.config.logging.level.clone();
+
+        app.reload_config(Ok((config, vec!["clave desconocida `x`".to_string()])));
+        assert_eq!(app.theme, Theme::Light);
+        assert_eq!(app.t(I18nKey::FooterHelp), "Help");
+        assert_eq!(app.config_warnings.len(), 1);
+
+        // Un archivo no válido no cambia la configuración en uso
+        app.reload_config(Err(std::io::Error::new(
+            std::io::ErrorKind::InvalidData,
+            "TOML parse error",
+        )));
+        assert_eq!(app.theme, Theme::Light);
+        assert!(app.config_invalid);
+        assert!(app.config_warnings[0].contains("TOML parse error"));
+    }
+
+    #[test]
+    fn test_help_overlay_opens_and_closes() {
+        let mut app = App::default();
+        app.handle_key(KeyEvent::from(KeyCode::Char('?')));
+        assert!(app.show_help);
+        // Cualquier tecla cierra la ayuda sin llegar a la vista
+        app.handle_key(KeyEvent::from(KeyCode::Char('q')));
+        assert!(!app.show_help);
+        assert!(!app.should_quit);
+
+        // Mientras se escribe, `?` es texto y la ayuda se abre con F1
+        app.log_search.editing = true;
+        app.handle_key(KeyEvent::from(KeyCode::Char('?')));
+        assert!(!app.show_help);
+        assert_eq!(app.log_search.query(), "?");
+        app.handle_key(KeyEvent::from(KeyCode::F(1)));
+        assert!(app.show_help);
+    }
+
+    #[test]
+    fn test_mouse_selects_rows_and_scrolls_logs() {
+        let mouse = |kind, column, row| MouseEvent {
+            kind,
+            column,
+            row,
+            modifiers: KeyModifiers::NONE,
+        };
+        let mut app = App::default();
+        app.mouse_targets.list = Some(ListHitbox::new(
+            Rect::new(0, 0, 40, 10),
+            0,
+            [(None, 1), (Some(0), 1), (Some(1), 1)],
+        ));
+        app.mouse_targets.log_panel = Some(Rect::new(0, 10, 40, 10));
+
+        // Clic en la segunda opción (la cabecera ocupa la fila 1)
+        app.handle_mouse(mouse(MouseEventKind::Down(MouseButton::Left), 5, 3));
+        assert_eq!(app.selected_menu_item, 1);
+        app.handle_mouse(mouse(MouseEventKind::Down(MouseButton::Left), 5, 1));
+        assert_eq!(app.selected_menu_item, 1);
+
+        // La rueda sobre los logs los desplaza y deja de seguir el final
+        app.handle_mouse(mouse(MouseEventKind::ScrollDown, 5, 12));
+        assert_eq!(app.scroll_offset, mouse::WHEEL_LINES);
+        assert!(!app.log_follow);
+
+        // Fuera de los logs equivale a las flechas
+        app.handle_mouse(mouse(MouseEventKind::ScrollUp, 5, 3));
+        assert_eq!(app.selected_menu_item, 0);
+    }
+
+    #[test]
+    fn test_clean_drive_picker() {
+        let drive = |name: &str, system: bool| DriveChoice {
+            mount: std::path::PathBuf::from(name),
+            name: name.to_string(),
+            total: 1024,
+            available: 512,
+            system,
+            selected: system,
+        };
+        let mut app = App {
+            current_view: View::Clean,
+            operation_state: OperationState::Idle,
+            clean_drives: vec![drive("C:", true), drive("D:", false)],
+            ..App::default()
+        };
+
+        // Espacio marca la unidad seleccionada
+        app.handle_key(KeyEvent::from(KeyCode::Down));
+        app.handle_key(KeyEvent::from(KeyCode::Char(' ')));
+        assert!(app.clean_drives[1].selected);
+        app.handle_key(KeyEvent::from(KeyCode::Up));
+        app.handle_key(KeyEvent::from(KeyCode::Char(' ')));
+        app.handle_key(KeyEvent::from(KeyCode::Down));
+        app.handle_key(KeyEvent::from(KeyCode::Char(' ')));
+        assert!(app.clean_drives.iter().all(|drive| !drive.selected));
+
+        // Sin unidades marcadas, Enter no limpia nada
+        app.handle_key(KeyEvent::from(KeyCode::Enter));
+        assert_eq!(app.operation_state, OperationState::Idle);
+        assert_eq!(app.operation_logs.last().unwrap().level, LogLevel::Warning);
+    }
+
+    #[test]
+    fn test_clean_profile_picker() {
+        let profile = |name: &str| UserProfile {
+            name: name.to_string(),
+            path: std::path::PathBuf::from(name),
+            selected: false,
+        };
+        let mut app = App {
+            current_view: View::Clean,
+            operation_state: OperationState::Idle,
+            clean_profiles: vec![profile("Ana"), profile("luis")],
+            ..App::default()
+        };
+
+        // Con una sola unidad, la lista solo muestra los perfiles
+        assert_eq!(app.clean_picker_rows(), 2);
+        app.handle_key(KeyEvent::from(KeyCode::Down));
+        app.handle_key(KeyEvent::from(KeyCode::Down));
+        app.handle_key(KeyEvent::from(KeyCode::Char(' ')));
+        assert!(!app.clean_profiles[0].selected);
+        assert!(app.clean_profiles[1].selected);
+    }
 }
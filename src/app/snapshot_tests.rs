@@ -0,0 +1,485 @@
+//! Capturas de la interfaz con `TestBackend`
+//!
+//! Cada vista se dibuja en un terminal de prueba y se compara con la captura
+//! guardada en `src/app/snapshots/`. Un cambio en la interfaz hace fallar la
+//! prueba con la diferencia; si el cambio es intencionado, se aceptan las
+//! nuevas capturas con `cargo insta review` (o `INSTA_UPDATE=always cargo
+//! test`).
+//!
+//! Las capturas no deben depender del equipo: la aplicación parte de la
+//! configuración por defecto, sin privilegios de administrador, en arranque
+//! normal y con la versión de Windows desconocida. La vista de información
+//! muestra datos en vivo del sistema, así que se captura solo su estructura.
+
+use super::*;
+use crate::cleanup::TempLocation;
+use crate::drives::DriveChoice;
+use crate::repair::{DismResult, SfcResult};
+use crate::startup::{Impact, StartupEntry, StartupSource};
+use crate::tasks::{ScheduledTask, TaskState};
+use crate::types::{LocationStats, RepairStats};
+use insta::{assert_debug_snapshot, assert_snapshot};
+use ratatui::{Terminal, backend::TestBackend, buffer::Buffer};
+use std::path::PathBuf;
+
+/// Tamaño del terminal de las capturas
+const WIDTH: u16 = 120;
+const HEIGHT: u16 = 40;
+
+/// Todas las vistas de la aplicación
+const ALL_VIEWS: [View; 36] = [
+    View::MainMenu,
+    View::Clean,
+    View::Network,
+    View::Repair,
+    View::Info,
+    View::Optimize,
+    View::WindowsUpdate,
+    View::Privacy,
+    View::BrowserCache,
+    View::SystemLogs,
+    View::RecycleBin,
+    View::StartupOptimizer,
+    View::VisualEffects,
+    View::Profiles,
+    View::Drivers,
+    View::Tasks,
+    View::Memory,
+    View::Programs,
+    View::Downloads,
+    View::Quarantine,
+    View::Defender,
+    View::Firewall,
+    View::Hosts,
+    View::PathCleaner,
+    View::FontCache,
+    View::SearchIndex,
+    View::Adapters,
+    View::Wifi,
+    View::Proxy,
+    View::Ports,
+    View::BootTime,
+    View::Jobs,
+    View::AppLogs,
+    View::Onboarding,
+    View::Settings,
+    View::About,
+];
+
+/// Aplicación con un estado que no depende del equipo
+fn snapshot_app(view: View, theme: Theme, language: Language) -> App {
+    let config = Config::default();
+    let mut app = App {
+        current_view: view,
+        theme,
+        color_mode: ColorMode::TrueColor,
+        symbol_mode: SymbolMode::Unicode,
+        elevated: false,
+        menu_locked: MENU_ITEMS
+            .iter()
+            .map(|item| item.requires_admin())
+            .collect(),
+        boot_mode: BootMode::Normal,
+        os_build: None,
+        i18n: I18n::new(language),
+        active_profile: None,
+        downloads_threshold_days: config.downloads.threshold_days,
+        onboarding: Onboarding::from_config(&config),
+        config,
+        config_warnings: Vec::new(),
+        config_invalid: false,
+        first_run: false,
+        ..App::default()
+    };
+    if view == View::About {
+        // El commit, la fecha de compilación y las rutas cambian en cada equipo
+        app.diagnostics = [
+            (I18nKey::AboutVersion, "v1.2.1"),
+            (I18nKey::AboutCommit, "1a2b3c4"),
+            (I18nKey::AboutBuilt, "2026-10-16 09:00 UTC"),
+            (I18nKey::AboutWindows, "Windows 11 Pro 23H2"),
+            (I18nKey::AboutWindowsBuild, "22631"),
+            (I18nKey::AboutElevation, "Usuario estándar"),
+            (
+                I18nKey::AboutConfig,
+                "C:\\Users\\Demo\\AppData\\Roaming\\win_opt\\config.toml",
+            ),
+            (
+                I18nKey::AboutLogs,
+                "C:\\Users\\Demo\\AppData\\Roaming\\win_opt\\logs",
+            ),
+            (
+                I18nKey::AboutRepository,
+                "https://github.com/PabloViniegra/win_opt",
+            ),
+            (I18nKey::AboutLicense, "MIT"),
+        ]
+        .map(|(label, value)| (label, value.to_string()))
+        .to_vec();
+    }
+    app
+}
+
+/// Dibuja la aplicación en un terminal de prueba
+fn render(app: &mut App) -> Terminal<TestBackend> {
+    let mut terminal = Terminal::new(TestBackend::new(WIDTH, HEIGHT)).unwrap();
+    terminal
+        .draw(|frame| {
+            app.draw(frame);
+            if app.current_view == View::Info {
+                mask_live_data(frame.buffer_mut());
+            }
+        })
+        .unwrap();
+    terminal
+}
+
+/// Borra las filas de la vista de información con datos del sistema
+///
+/// El contenido de las cajas del sistema operativo, la CPU y los discos
+/// cambia en cada equipo: de esas filas solo se conservan los bordes, y los
+/// títulos (que incluyen la memoria en uso) se sustituyen por el borde.
+fn mask_live_data(buffer: &mut Buffer) {
+    let margin = responsive::margin(false);
+    let first = margin + 3;
+    // Pie (3 filas), margen inferior y barra de estado
+    let last = HEIGHT - 1 - margin - 3;
+    for y in first..last {
+        let title_row = buffer[(margin, y)].symbol() == "╭";
+        for x in 0..WIDTH {
+            let cell = &mut buffer[(x, y)];
+            if BORDERS.contains(cell.symbol()) {
+                continue;
+            }
+            let bg = cell.bg;
+            cell.reset();
+            cell.set_bg(bg);
+            if title_row && x > margin && x < WIDTH - margin {
+                cell.set_symbol("─");
+            }
+        }
+    }
+}
+
+/// Caracteres de los bordes redondeados de las cajas
+const BORDERS: &str = "─│╭╮╰╯";
+
+/// Aplicación con estadísticas de una operación terminada
+fn finished_app(view: View, theme: Theme, language: Language) -> App {
+    let mut app = snapshot_app(view, theme, language);
+    match view {
+        View::Clean => {
+            app.operation_state = OperationState::Completed;
+            app.stats = Some(OperationStats::Clean(CleanStats {
+                deleted_count: 1284,
+                failed_count: 12,
+                in_use_count: 7,
+                size_freed: 734 * 1024 * 1024,
+                locations: vec![
+                    LocationStats {
+                        location: TempLocation::User,
+                        path: PathBuf::from("C:\\Users\\Demo\\AppData\\Local\\Temp"),
+                        deleted_count: 1046,
+                        failed_count: 9,
+                        in_use_count: 5,
+                        size_freed: 612 * 1024 * 1024,
+                    },
+                    LocationStats {
+                        location: TempLocation::Windows,
+                        path: PathBuf::from("C:\\Windows\\Temp"),
+                        deleted_count: 201,
+                        failed_count: 3,
+                        in_use_count: 2,
+                        size_freed: 98 * 1024 * 1024,
+                    },
+                    LocationStats {
+                        location: TempLocation::InetCache,
+                        path: PathBuf::from(
+                            "C:\\Users\\Demo\\AppData\\Local\\Microsoft\\Windows\\INetCache",
+                        ),
+                        deleted_count: 37,
+                        failed_count: 0,
+                        in_use_count: 0,
+                        size_freed: 24 * 1024 * 1024,
+                    },
+                ],
+            }));
+        }
+        View::Repair => {
+            app.operation_state = OperationState::Failed;
+            app.stats = Some(OperationStats::Repair(RepairStats {
+                dism_ok: Some(false),
+                sfc_ok: Some(true),
+                dism: DismResult::SourceMissing,
+                sfc: SfcResult::Unrepaired,
+                corruption_found: true,
+                ..RepairStats::default()
+            }));
+        }
+        _ => {}
+    }
+    app
+}
+
+/// Texto de la pantalla con las rutas del sistema normalizadas
+///
+/// La ruta del archivo hosts se muestra con el separador de la plataforma.
+fn screen_text(terminal: &Terminal<TestBackend>) -> String {
+    let hosts = hosts::hosts_path().display().to_string();
+    terminal
+        .backend()
+        .to_string()
+        .replace(&hosts, &hosts.replace('\\', "/"))
+}
+
+/// Nombre de la captura de una vista
+fn snapshot_name(view: View) -> String {
+    format!("{:?}", view).to_lowercase()
+}
+
+#[test]
+fn snapshot_every_view() {
+    for view in ALL_VIEWS {
+        let mut app = snapshot_app(view, Theme::Dark, Language::Spanish);
+        let terminal = render(&mut app);
+        assert_snapshot!(snapshot_name(view), screen_text(&terminal));
+    }
+}
+
+#[test]
+fn snapshot_finished_operations() {
+    for view in [View::Clean, View::Repair] {
+        let mut app = finished_app(view, Theme::Dark, Language::Spanish);
+        let terminal = render(&mut app);
+        assert_snapshot!(
+            format!("{}_finished", snapshot_name(view)),
+            screen_text(&terminal)
+        );
+    }
+}
+
+#[test]
+fn snapshot_clean_drive_picker() {
+    const GB: u64 = 1024 * 1024 * 1024;
+    let drive = |name: &str, total: u64, available: u64, system: bool| DriveChoice {
+        mount: PathBuf::from(format!("{}\\", name)),
+        name: name.to_string(),
+        total: total * GB,
+        available: available * GB,
+        system,
+        selected: system,
+    };
+    let mut app = snapshot_app(View::Clean, Theme::Dark, Language::Spanish);
+    app.clean_drives = vec![
+        drive("C:", 512, 87, true),
+        drive("D:", 2048, 1310, false),
+        drive("E:", 256, 12, false),
+    ];
+    app.selected_item = 1;
+    let terminal = render(&mut app);
+    assert_snapshot!("clean_drives", screen_text(&terminal));
+}
+
+#[test]
+fn snapshot_clean_profile_picker() {
+    let mut app = snapshot_app(View::Clean, Theme::Dark, Language::Spanish);
+    app.clean_drives = vec![
+        DriveChoice {
+            mount: PathBuf::from("C:\\"),
+            name: "C:".to_string(),
+            total: 512 * 1024 * 1024 * 1024,
+            available: 87 * 1024 * 1024 * 1024,
+            system: true,
+            selected: true,
+        },
+        DriveChoice {
+            mount: PathBuf::from("D:\\"),
+            name: "D:".to_string(),
+            total: 2048 * 1024 * 1024 * 1024,
+            available: 1310 * 1024 * 1024 * 1024,
+            system: false,
+            selected: false,
+        },
+    ];
+    app.clean_profiles = ["Ana", "luis"]
+        .into_iter()
+        .map(|name| UserProfile {
+            name: name.to_string(),
+            path: PathBuf::from(format!("C:\\Users\\{}", name)),
+            selected: name == "Ana",
+        })
+        .collect();
+    app.selected_item = 2;
+    let terminal = render(&mut app);
+    assert_snapshot!("clean_profiles", screen_text(&terminal));
+}
+
+#[test]
+fn snapshot_scheduled_tasks() {
+    let task =
+        |path: &str, state, last: Option<&str>, next: Option<&str>, author: &str, vendor: &str| {
+            ScheduledTask {
+                path: path.to_string(),
+                state,
+                last_run: last.map(str::to_string),
+                next_run: next.map(str::to_string),
+                author: author.to_string(),
+                vendor: vendor.to_string(),
+                at_startup: false,
+                command: String::new(),
+                marked: false,
+            }
+        };
+    let mut app = snapshot_app(View::Tasks, Theme::Dark, Language::Spanish);
+    app.scheduled_tasks = vec![
+        task(
+            "\\GoogleSystem\\GoogleUpdater\\GoogleUpdaterTaskSystem",
+            TaskState::Ready,
+            Some("2026-03-02 08:30"),
+            Some("2026-03-03 03:00"),
+            "Google LLC",
+            "Google LLC",
+        ),
+        task(
+            "\\Microsoft\\Windows\\Autochk\\Proxy",
+            TaskState::Ready,
+            Some("2026-03-02 08:30"),
+            None,
+            "",
+            "Microsoft",
+        ),
+        task(
+            "\\Microsoft\\Windows\\Customer Experience Improvement Program\\Consolidator",
+            TaskState::Disabled,
+            None,
+            None,
+            "Microsoft Corporation",
+            "Microsoft",
+        ),
+        task(
+            "\\Backup nocturno",
+            TaskState::Disabled,
+            None,
+            None,
+            "DESKTOP-DEMO\\ana",
+            "",
+        ),
+    ];
+    app.scheduled_tasks[2].marked = true;
+    app.selected_item = 1;
+    let terminal = render(&mut app);
+    assert_snapshot!("tasks_list", screen_text(&terminal));
+}
+
+#[test]
+fn snapshot_startup_entries() {
+    let entry = |source, name: &str, command: &str, enabled, impact, missing| StartupEntry {
+        source,
+        id: name.to_string(),
+        name: name.to_string(),
+        command: command.to_string(),
+        enabled,
+        impact,
+        missing,
+    };
+    let mut app = snapshot_app(View::StartupOptimizer, Theme::Dark, Language::Spanish);
+    app.startup_entries = vec![
+        entry(
+            StartupSource::UserRun,
+            "OneDrive",
+            "\"C:\\Users\\Demo\\AppData\\Local\\Microsoft\\OneDrive\\OneDrive.exe\" /background",
+            true,
+            Impact::Medium,
+            false,
+        ),
+        entry(
+            StartupSource::UserRun,
+            "Spotify",
+            "C:\\Users\\Demo\\AppData\\Roaming\\Spotify\\Spotify.exe /minimized",
+            false,
+            Impact::Medium,
+            false,
+        ),
+        entry(
+            StartupSource::MachineRun32,
+            "AdobeAAMUpdater-1.0",
+            "\"C:\\Program Files (x86)\\Common Files\\Adobe\\OOBE\\UpdaterStartupUtility.exe\"",
+            true,
+            Impact::Low,
+            true,
+        ),
+        entry(
+            StartupSource::Service,
+            "TeamViewer",
+            "\"C:\\Program Files\\TeamViewer\\TeamViewer_Service.exe\"",
+            true,
+            Impact::High,
+            false,
+        ),
+    ];
+    app.selected_item = 1;
+    let terminal = render(&mut app);
+    assert_snapshot!("startup_list", screen_text(&terminal));
+}
+
+#[test]
+fn snapshot_interrupted_operation() {
+    let mut app = snapshot_app(View::MainMenu, Theme::Dark, Language::Spanish);
+    app.interrupted = vec![JournalEntry {
+        operation: "clean".to_string(),
+        started: "2026-10-16 09:00".to_string(),
+        targets: (1..=7)
+            .map(|n| format!("C:\\Users\\Demo{}\\AppData\\Local\\Temp", n))
+            .collect(),
+    }];
+    let terminal = render(&mut app);
+    assert_snapshot!("interrupted_operation", screen_text(&terminal));
+}
+
+#[test]
+fn snapshot_new_release() {
+    let mut app = snapshot_app(View::MainMenu, Theme::Dark, Language::English);
+    app.new_release = Some(Release {
+        version: "9.0.0".to_string(),
+        download_url: Some(
+            "https://github.com/PabloViniegra/win_opt/releases/download/v9.0.0/win_opt.exe"
+                .to_string(),
+        ),
+        checksum_url: Some(
+            "https://github.com/PabloViniegra/win_opt/releases/download/v9.0.0/checksums.txt"
+                .to_string(),
+        ),
+    });
+    let terminal = render(&mut app);
+    assert_snapshot!("new_release", screen_text(&terminal));
+}
+
+#[test]
+fn snapshot_uptime_reboot_hint() {
+    let mut app = snapshot_app(View::MainMenu, Theme::Dark, Language::English);
+    app.launch_uptime = Some((Duration::from_secs(21 * 24 * 60 * 60), Instant::now()));
+    let terminal = render(&mut app);
+    assert_snapshot!("uptime_reboot_hint", screen_text(&terminal));
+}
+
+#[test]
+fn snapshot_themes_and_languages() {
+    // Con estilos: un cambio de colores también hace fallar la captura
+    for theme in [Theme::Dark, Theme::Light] {
+        for language in [Language::Spanish, Language::English] {
+            for view in [View::MainMenu, View::Clean, View::Info, View::Repair] {
+                let mut app = finished_app(view, theme, language);
+                let terminal = render(&mut app);
+                assert_debug_snapshot!(
+                    format!(
+                        "{}_{}_{}",
+                        snapshot_name(view),
+                        format!("{:?}", theme).to_lowercase(),
+                        language.code()
+                    ),
+                    terminal.backend().buffer()
+                );
+            }
+        }
+    }
+}
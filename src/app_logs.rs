@@ -0,0 +1,250 @@
+//! Visor del archivo de log de la aplicación
+//!
+//! Lee el final del archivo de log actual (`win_opt.log.<fecha>`) y va
+//! añadiendo las líneas nuevas a medida que se escriben, de modo que se
+//! puede seguir lo que hace una operación mientras se ejecuta. Entiende
+//! tanto el formato de texto como el JSON.
+
+use crate::logger::{self, LogLevel};
+use std::fs::File;
+use std::io::{Read, Seek, SeekFrom};
+use std::path::PathBuf;
+
+/// Bytes que se leen del final del archivo al abrirlo
+const INITIAL_TAIL_BYTES: u64 = 256 * 1024;
+
+/// Líneas que se conservan en memoria
+const MAX_LINES: usize = 5000;
+
+/// Línea del archivo de log
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct FileLogLine {
+    /// Nivel de la línea (las líneas de continuación heredan el anterior)
+    pub level: Option<LogLevel>,
+    pub text: String,
+}
+
+/// Lector incremental del archivo de log actual
+#[derive(Debug, Default)]
+pub struct LogTail {
+    path: Option<PathBuf>,
+    /// Bytes ya leídos del archivo
+    offset: u64,
+    /// Final de línea incompleto de la última lectura
+    pending: String,
+    lines: Vec<FileLogLine>,
+}
+
+impl LogTail {
+    /// Abre el archivo de log más reciente y lee sus últimas líneas
+    pub fn open() -> Self {
+        let mut tail = LogTail {
+            path: logger::current_log_file(),
+            ..LogTail::default()
+        };
+        if let Some(len) = tail.file_len() {
+            let start = len.saturating_sub(INITIAL_TAIL_BYTES);
+            tail.offset = start;
+            tail.read_new();
+            // La primera línea puede haber quedado cortada por la mitad
+            if start > 0 && !tail.lines.is_empty() {
+                tail.lines.remove(0);
+            }
+        }
+        tail
+    }
+
+    /// Archivo que se está leyendo
+    pub fn path(&self) -> Option<&PathBuf> {
+        self.path.as_ref()
+    }
+
+    /// Líneas leídas hasta ahora
+    pub fn lines(&self) -> &[FileLogLine] {
+        &self.lines
+    }
+
+    /// Lee las líneas escritas desde la última consulta
+    ///
+    /// Si el log rota (nuevo día) o se trunca, se vuelve a abrir desde el
+    /// principio del archivo nuevo. Devuelve si hay líneas nuevas.
+    pub fn poll(&mut self) -> bool {
+        let current = logger::current_log_file();
+        if current != self.path {
+            self.path = current;
+            self.offset = 0;
+            self.pending.clear();
+        }
+        match self.file_len() {
+            Some(len) if len < self.offset => {
+                self.offset = 0;
+                self.pending.clear();
+            }
+            Some(len) if len == self.offset => return false,
+            Some(_) => {}
+            None => return false,
+        }
+        self.read_new()
+    }
+
+    /// Tamaño actual del archivo
+    fn file_len(&self) -> Option<u64> {
+        let path = self.path.as_ref()?;
+        std::fs::metadata(path).ok().map(|metadata| metadata.len())
+    }
+
+    /// Lee desde `offset` hasta el final y añade las líneas completas
+    fn read_new(&mut self) -> bool {
+        let Some(path) = &self.path else {
+            return false;
+        };
+        let Ok(mut file) = File::open(path) else {
+            return false;
+        };
+        let mut bytes = Vec::new();
+        if file.seek(SeekFrom::Start(self.offset)).is_err() || file.read_to_end(&mut bytes).is_err()
+        {
+            return false;
+        }
+        self.offset += bytes.len() as u64;
+        self.pending.push_str(&String::from_utf8_lossy(&bytes));
+
+        let Some(end) = self.pending.rfind('\n') else {
+            return false;
+        };
+        let complete: String = self.pending.drain(..=end).collect();
+        let before = self.lines.len();
+        for text in complete.lines().filter(|text| !text.trim().is_empty()) {
+            let level = parse_level(text).or_else(|| self.lines.last().and_then(|l| l.level));
+            self.lines.push(FileLogLine {
+                level,
+                text: text.to_string(),
+            });
+        }
+        if self.lines.len() > MAX_LINES {
+            self.lines.drain(..self.lines.len() - MAX_LINES);
+        }
+        self.lines.len() != before
+    }
+}
+
+/// Filtro de las líneas mostradas
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct LogFilter {
+    /// Nivel mínimo (`None` muestra todas)
+    pub min_level: Option<LogLevel>,
+    /// Texto que debe contener la línea (sin distinguir mayúsculas)
+    pub keyword: String,
+}
+
+impl LogFilter {
+    /// Pasa al siguiente nivel mínimo: todos → info → warn → error → todos
+    pub fn cycle_level(&mut self) {
+        self.min_level = match self.min_level {
+            None => Some(LogLevel::Info),
+            Some(LogLevel::Debug) | Some(LogLevel::Info) => Some(LogLevel::Warning),
+            Some(LogLevel::Warning) => Some(LogLevel::Error),
+            Some(LogLevel::Error) => None,
+        };
+    }
+
+    /// Indica si una línea pasa el filtro
+    pub fn matches(&self, line: &FileLogLine) -> bool {
+        let level_ok = match (self.min_level, line.level) {
+            (None, _) => true,
+            (Some(min), Some(level)) => level >= min,
+            (Some(_), None) => false,
+        };
+        level_ok
+            && (self.keyword.is_empty()
+                || line
+                    .text
+                    .to_lowercase()
+                    .contains(&self.keyword.to_lowercase()))
+    }
+}
+
+/// Extrae el nivel de una línea en formato de texto o JSON
+fn parse_level(line: &str) -> Option<LogLevel> {
+    let token = if let Some(start) = line.find("\"level\":\"") {
+        let rest = &line[start + 9..];
+        &rest[..rest.find('"')?]
+    } else {
+        // Texto: `<fecha>  INFO ThreadId(01) ...`
+        line.split_whitespace().nth(1)?
+    };
+    match token {
+        "TRACE" | "DEBUG" => Some(LogLevel::Debug),
+        "INFO" => Some(LogLevel::Info),
+        "WARN" => Some(LogLevel::Warning),
+        "ERROR" => Some(LogLevel::Error),
+        _ => None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_level_text_and_json() {
+        assert_eq!(
+            parse_level("2026-10-16T10:00:00.000Z  WARN ThreadId(01) win_opt::app: aviso"),
+            Some(LogLevel::Warning)
+        );
+        assert_eq!(
+            parse_level(r#"{"timestamp":"2026-10-16","level":"ERROR","fields":{}}"#),
+            Some(LogLevel::Error)
+        );
+        assert_eq!(parse_level("   continuación"), None);
+    }
+
+    #[test]
+    fn test_filter_by_level_and_keyword() {
+        let warn = FileLogLine {
+            level: Some(LogLevel::Warning),
+            text: "No se pudo eliminar C:\\Temp".to_string(),
+        };
+        let info = FileLogLine {
+            level: Some(LogLevel::Info),
+            text: "Limpieza completada".to_string(),
+        };
+
+        let mut filter = LogFilter::default();
+        assert!(filter.matches(&info));
+        filter.cycle_level();
+        filter.cycle_level();
+        assert_eq!(filter.min_level, Some(LogLevel::Warning));
+        assert!(filter.matches(&warn));
+        assert!(!filter.matches(&info));
+
+        filter.min_level = None;
+        filter.keyword = "temp".to_string();
+        assert!(filter.matches(&warn));
+        assert!(!filter.matches(&info));
+    }
+
+    #[test]
+    fn test_read_new_keeps_partial_lines_pending() {
+        let path = std::env::temp_dir().join("win_opt_test_log_tail.log");
+        std::fs::write(&path, "t  INFO uno\nt  ERROR dos\n   detalle\nt  INFO tr").unwrap();
+
+        let mut tail = LogTail {
+            path: Some(path.clone()),
+            ..LogTail::default()
+        };
+        assert!(tail.read_new());
+        assert_eq!(tail.lines().len(), 3);
+        assert_eq!(tail.lines()[2].level, Some(LogLevel::Error));
+
+        std::fs::write(
+            &path,
+            "t  INFO uno\nt  ERROR dos\n   detalle\nt  INFO tres\n",
+        )
+        .unwrap();
+        assert!(tail.read_new());
+        assert_eq!(tail.lines().last().unwrap().text, "t  INFO tres");
+
+        std::fs::remove_file(&path).unwrap();
+    }
+}
@@ -0,0 +1,112 @@
+//! Logo del banner con letras grandes al estilo figlet
+//!
+//! En lugar de líneas de arte ASCII escritas a mano, el logo se compone a
+//! partir de un texto con una pequeña fuente de bloques (estilo "ANSI
+//! Shadow"). Así puede elegirse la variante que quepa en el ancho del
+//! terminal, y todas las filas tienen el mismo ancho para centrarse bien.
+
+use unicode_width::UnicodeWidthStr;
+
+/// Filas de cada letra
+pub const GLYPH_HEIGHT: usize = 6;
+
+/// Filas de una letra de la fuente, o `None` si la fuente no la incluye
+fn glyph(c: char) -> Option<[&'static str; GLYPH_HEIGHT]> {
+    let rows = match c {
+        'W' => [
+            "██╗    ██╗",
+            "██║    ██║",
+            "██║ █╗ ██║",
+            "██║███╗██║",
+            "╚███╔███╔╝",
+            " ╚══╝╚══╝ ",
+        ],
+        'I' => ["██╗", "██║", "██║", "██║", "██║", "╚═╝"],
+        'N' => [
+            "███╗   ██╗",
+            "████╗  ██║",
+            "██╔██╗ ██║",
+            "██║╚██╗██║",
+            "██║ ╚████║",
+            "╚═╝  ╚═══╝",
+        ],
+        'O' => [
+            " ██████╗ ",
+            "██╔═══██╗",
+            "██║   ██║",
+            "██║   ██║",
+            "╚██████╔╝",
+            " ╚═════╝ ",
+        ],
+        'P' => [
+            "██████╗ ",
+            "██╔══██╗",
+            "██████╔╝",
+            "██╔═══╝ ",
+            "██║     ",
+            "╚═╝     ",
+        ],
+        'T' => [
+            "████████╗",
+            "╚══██╔══╝",
+            "   ██║   ",
+            "   ██║   ",
+            "   ██║   ",
+            "   ╚═╝   ",
+        ],
+        ' ' => ["   "; GLYPH_HEIGHT],
+        _ => return None,
+    };
+    Some(rows)
+}
+
+/// Compone un texto con letras grandes
+///
+/// Retorna `None` si alguna letra no está en la fuente.
+pub fn render(text: &str) -> Option<Vec<String>> {
+    let glyphs = text
+        .chars()
+        .map(|c| glyph(c.to_ascii_uppercase()))
+        .collect::<Option<Vec<_>>>()?;
+
+    Some(
+        (0..GLYPH_HEIGHT)
+            .map(|row| glyphs.iter().map(|rows| rows[row]).collect())
+            .collect(),
+    )
+}
+
+/// Compone el primer texto que quepa en `max_width` columnas
+///
+/// Los textos se prueban en orden, del más largo al más corto.
+pub fn fit(candidates: &[&str], max_width: usize) -> Option<Vec<String>> {
+    candidates
+        .iter()
+        .filter_map(|text| render(text))
+        .find(|lines| lines.first().is_some_and(|row| row.width() <= max_width))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_rows_have_the_same_width() {
+        for c in "WINOPT ".chars() {
+            let rows = glyph(c).unwrap();
+            assert!(rows.iter().all(|row| row.width() == rows[0].width()));
+        }
+        let lines = render("win opt").unwrap();
+        assert_eq!(lines.len(), GLYPH_HEIGHT);
+        assert!(lines.iter().all(|line| line.width() == 52));
+        assert!(render("win?").is_none());
+    }
+
+    #[test]
+    fn test_fit_picks_the_first_candidate_that_fits() {
+        let width = |lines: Option<Vec<String>>| lines.map(|lines| lines[0].width());
+        assert_eq!(width(fit(&["WIN OPT", "WIN"], 100)), Some(52));
+        assert_eq!(width(fit(&["WIN OPT", "WIN"], 40)), Some(23));
+        assert_eq!(width(fit(&["WIN OPT", "WIN"], 10)), None);
+    }
+}
@@ -0,0 +1,164 @@
+//! Informe de salud de la batería
+//!
+//! Ejecuta `powercfg /batteryreport` en formato XML y extrae la capacidad de
+//! diseño, la capacidad de carga completa y el número de ciclos de cada
+//! batería instalada.
+//!
+//! El informe tarda unos segundos, así que la vista de información lo pide
+//! en un thread aparte con [`spawn_query`].
+
+use crate::platform;
+use std::fs;
+use std::sync::mpsc::{self, Receiver};
+use std::thread;
+
+/// Estado de salud de una batería
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct BatteryHealth {
+    /// Identificador de la batería (modelo)
+    pub name: String,
+    /// Capacidad de diseño en mWh
+    pub design_capacity_mwh: u64,
+    /// Capacidad de carga completa actual en mWh
+    pub full_charge_capacity_mwh: u64,
+    /// Número de ciclos de carga (si el firmware lo informa)
+    pub cycle_count: Option<u32>,
+}
+
+impl BatteryHealth {
+    /// Porcentaje de capacidad restante respecto a la de diseño
+    pub fn health_percent(&self) -> u16 {
+        if self.design_capacity_mwh == 0 {
+            return 0;
+        }
+        let percent = self.full_charge_capacity_mwh * 100 / self.design_capacity_mwh;
+        percent.min(100) as u16
+    }
+}
+
+/// Genera el informe de batería y devuelve la salud de cada batería
+///
+/// Retorna una lista vacía en equipos sin batería o si `powercfg` falla.
+pub fn query_battery_health() -> Vec<BatteryHealth> {
+    let report_path = std::env::temp_dir().join("win_opt_battery_report.xml");
+
+    let result = platform::command("powercfg")
+        .arg("/batteryreport")
+        .arg("/xml")
+        .arg("/output")
+        .arg(&report_path)
+        .output();
+
+    let batteries = match result {
+        Ok(output) if output.status.success() => fs::read_to_string(&report_path)
+            .map(|xml| parse_battery_report(&xml))
+            .unwrap_or_default(),
+        Ok(_) => Vec::new(),
+        Err(e) => {
+            tracing::debug!("No se pudo generar el informe de batería: {}", e);
+            Vec::new()
+        }
+    };
+
+    let _ = fs::remove_file(&report_path);
+    batteries
+}
+
+/// Lanza el informe de batería en un thread y devuelve el receptor del
+/// resultado
+pub fn spawn_query() -> Receiver<Vec<BatteryHealth>> {
+    let (sender, receiver) = mpsc::channel();
+
+    thread::spawn(move || {
+        let _ = sender.send(query_battery_health());
+    });
+
+    receiver
+}
+
+/// Extrae las baterías del XML generado por `powercfg /batteryreport /xml`
+fn parse_battery_report(xml: &str) -> Vec<BatteryHealth> {
+    xml.split("<Battery>")
+        .skip(1)
+        .filter_map(|block| {
+            let block = block.split("</Battery>").next()?;
+            let design_capacity_mwh = tag_value(block, "DesignCapacity")?.parse().ok()?;
+            let full_charge_capacity_mwh = tag_value(block, "FullChargeCapacity")?.parse().ok()?;
+            let name = tag_value(block, "Id")
+                .or_else(|| tag_value(block, "Manufacturer"))
+                .unwrap_or("Battery")
+                .to_string();
+
+            Some(BatteryHealth {
+                name,
+                design_capacity_mwh,
+                full_charge_capacity_mwh,
+                cycle_count: tag_value(block, "CycleCount").and_then(|c| c.parse().ok()),
+            })
+        })
+        .collect()
+}
+
+/// Obtiene el contenido de `<tag>...</tag>` dentro de un bloque XML
+fn tag_value<'a>(block: &'a str, tag: &str) -> Option<&'a str> {
+    let open = format!("<{}>", tag);
+    let close = format!("</{}>", tag);
+    let start = block.find(&open)? + open.len();
+    let end = block[start..].find(&close)? + start;
+    let value = block[start..end].trim();
+    (!value.is_empty()).then_some(value)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const SAMPLE_REPORT: &str = r#"<?xml version="1.0" encoding="utf-8"?>
+<BatteryReport xmlns="http://schemas.microsoft.com/battery/2012">
+  <Batteries>
+    <Battery>
+      <Id>DELL 5XJ2873</Id>
+      <Manufacturer>SMP</Manufacturer>
+      <DesignCapacity>56000</DesignCapacity>
+      <FullChargeCapacity>47040</FullChargeCapacity>
+      <CycleCount>212</CycleCount>
+    </Battery>
+  </Batteries>
+</BatteryReport>"#;
+
+    #[test]
+    fn test_parse_battery_report() {
+        let batteries = parse_battery_report(SAMPLE_REPORT);
+        assert_eq!(batteries.len(), 1);
+        assert_eq!(batteries[0].name, "DELL 5XJ2873");
+        assert_eq!(batteries[0].design_capacity_mwh, 56000);
+        assert_eq!(batteries[0].full_charge_capacity_mwh, 47040);
+        assert_eq!(batteries[0].cycle_count, Some(212));
+        assert_eq!(batteries[0].health_percent(), 84);
+    }
+
+    #[test]
+    fn test_parse_report_without_batteries() {
+        let xml = "<BatteryReport><Batteries></Batteries></BatteryReport>";
+        assert!(parse_battery_report(xml).is_empty());
+    }
+
+    #[test]
+    fn test_parse_report_missing_cycle_count() {
+        let xml = "<Batteries><Battery><Id>X</Id><DesignCapacity>1000</DesignCapacity><FullChargeCapacity>1000</FullChargeCapacity><CycleCount></CycleCount></Battery></Batteries>";
+        let batteries = parse_battery_report(xml);
+        assert_eq!(batteries[0].cycle_count, None);
+        assert_eq!(batteries[0].health_percent(), 100);
+    }
+
+    #[test]
+    fn test_health_percent_zero_design_capacity() {
+        let battery = BatteryHealth {
+            name: "X".to_string(),
+            design_capacity_mwh: 0,
+            full_charge_capacity_mwh: 100,
+            cycle_count: None,
+        };
+        assert_eq!(battery.health_percent(), 0);
+    }
+}
@@ -0,0 +1,266 @@
+//! Comparativa antes/después de una optimización
+//!
+//! Toma una instantánea de métricas relevantes para el arranque (archivos
+//! temporales, programas de inicio, servicios en ejecución, RAM y disco
+//! libres) al empezar una operación y otra al terminar, y muestra la
+//! diferencia en el resumen.
+
+use crate::i18n::{I18nKey, Language};
+use crate::log_info;
+use crate::platform;
+use sysinfo::{Disks, System};
+
+/// Script de PowerShell que imprime los contadores en líneas `CLAVE|valor`
+const COUNTERS_SCRIPT: &str = "'STARTUP|' + @(Get-CimInstance Win32_StartupCommand).Count; \
+     'SERVICES|' + @(Get-Service | Where-Object Status -eq 'Running').Count";
+
+/// Métrica medida
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Metric {
+    TempFiles,
+    StartupItems,
+    RunningServices,
+    FreeRam,
+    FreeDisk,
+}
+
+impl Metric {
+    /// Clave de traducción de la métrica
+    pub fn label_key(self) -> I18nKey {
+        match self {
+            Metric::TempFiles => I18nKey::BenchmarkTempFiles,
+            Metric::StartupItems => I18nKey::BenchmarkStartupItems,
+            Metric::RunningServices => I18nKey::BenchmarkServices,
+            Metric::FreeRam => I18nKey::BenchmarkFreeRam,
+            Metric::FreeDisk => I18nKey::BenchmarkFreeDisk,
+        }
+    }
+
+    /// Indica si un valor menor es una mejora
+    fn lower_is_better(self) -> bool {
+        !matches!(self, Metric::FreeRam | Metric::FreeDisk)
+    }
+
+    /// Formatea un valor de la métrica en un idioma
+    pub fn format(self, value: u64, language: Language) -> String {
+        match self {
+            Metric::FreeRam | Metric::FreeDisk => language.format_size(value),
+            _ => value.to_string(),
+        }
+    }
+
+    /// Formatea una diferencia con signo
+    pub fn format_delta(self, delta: i64, language: Language) -> String {
+        let sign = if delta > 0 {
+            "+"
+        } else if delta < 0 {
+            "-"
+        } else {
+            ""
+        };
+        format!("{}{}", sign, self.format(delta.unsigned_abs(), language))
+    }
+}
+
+/// Instantánea de las métricas en un momento dado
+///
+/// Los contadores que dependen de PowerShell quedan a `None` si no se pueden
+/// obtener.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Snapshot {
+    pub temp_files: u64,
+    pub startup_items: Option<u64>,
+    pub running_services: Option<u64>,
+    pub free_ram: u64,
+    pub free_disk: u64,
+}
+
+impl Snapshot {
+    /// Mide las métricas actuales
+    pub fn capture() -> Self {
+        let temp_files = std::fs::read_dir(crate::platform::temp_dir())
+            .map(|entries| entries.count() as u64)
+            .unwrap_or_default();
+
+        let mut sys = System::new();
+        sys.refresh_memory();
+        let free_disk = Disks::new_with_refreshed_list()
+            .list()
+            .iter()
+            .map(|disk| disk.available_space())
+            .sum();
+
+        let (startup_items, running_services) = platform::command("powershell")
+            .args(["-NoProfile", "-Command", COUNTERS_SCRIPT])
+            .output()
+            .ok()
+            .filter(|output| output.status.success())
+            .map(|output| parse_counters(&String::from_utf8_lossy(&output.stdout)))
+            .unwrap_or_default();
+
+        Snapshot {
+            temp_files,
+            startup_items,
+            running_services,
+            free_ram: sys.available_memory(),
+            free_disk,
+        }
+    }
+
+    /// Valor de una métrica
+    fn value(&self, metric: Metric) -> Option<u64> {
+        match metric {
+            Metric::TempFiles => Some(self.temp_files),
+            Metric::StartupItems => self.startup_items,
+            Metric::RunningServices => self.running_services,
+            Metric::FreeRam => Some(self.free_ram),
+            Metric::FreeDisk => Some(self.free_disk),
+        }
+    }
+}
+
+/// Fila de la tabla comparativa
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct DiffRow {
+    pub metric: Metric,
+    pub before: u64,
+    pub after: u64,
+}
+
+impl DiffRow {
+    /// Diferencia `después - antes`
+    pub fn delta(&self) -> i64 {
+        self.after as i64 - self.before as i64
+    }
+
+    /// `Some(true)` si mejoró, `Some(false)` si empeoró, `None` si no cambió
+    pub fn improved(&self) -> Option<bool> {
+        match self.delta() {
+            0 => None,
+            delta => Some((delta < 0) == self.metric.lower_is_better()),
+        }
+    }
+}
+
+/// Comparativa entre dos instantáneas
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Comparison {
+    pub before: Snapshot,
+    pub after: Snapshot,
+}
+
+impl Comparison {
+    /// Filas de la tabla, omitiendo las métricas que no se pudieron medir
+    pub fn rows(&self) -> Vec<DiffRow> {
+        [
+            Metric::TempFiles,
+            Metric::StartupItems,
+            Metric::RunningServices,
+            Metric::FreeRam,
+            Metric::FreeDisk,
+        ]
+        .into_iter()
+        .filter_map(|metric| {
+            Some(DiffRow {
+                metric,
+                before: self.before.value(metric)?,
+                after: self.after.value(metric)?,
+            })
+        })
+        .collect()
+    }
+}
+
+/// Mide el estado final, guarda la comparativa y la resume en el log
+pub fn record(app: &mut crate::app::App, before: Snapshot) {
+    let comparison = Comparison {
+        before,
+        after: Snapshot::capture(),
+    };
+
+    log_info!(app, "");
+    log_info!(app, "📊 Antes → después:");
+    let language = app.i18n.current_language();
+    for row in comparison.rows() {
+        let label = app.t(row.metric.label_key()).to_string();
+        let line = format!(
+            "   {}: {} → {} ({})",
+            label,
+            row.metric.format(row.before, language),
+            row.metric.format(row.after, language),
+            row.metric.format_delta(row.delta(), language)
+        );
+        log_info!(app, "{}", line);
+    }
+
+    app.benchmark = Some(comparison);
+}
+
+/// Parsea la salida del script de contadores
+fn parse_counters(output: &str) -> (Option<u64>, Option<u64>) {
+    let mut startup = None;
+    let mut services = None;
+    for line in output.lines() {
+        match line.trim().split_once('|') {
+            Some(("STARTUP", value)) => startup = value.parse().ok(),
+            Some(("SERVICES", value)) => services = value.parse().ok(),
+            _ => {}
+        }
+    }
+    (startup, services)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn snapshot(temp_files: u64, services: Option<u64>, free_disk: u64) -> Snapshot {
+        Snapshot {
+            temp_files,
+            startup_items: None,
+            running_services: services,
+            free_ram: 4 * 1024 * 1024 * 1024,
+            free_disk,
+        }
+    }
+
+    #[test]
+    fn test_rows_and_improvement() {
+        let comparison = Comparison {
+            before: snapshot(120, Some(90), 10 * 1024 * 1024 * 1024),
+            after: snapshot(15, Some(88), 12 * 1024 * 1024 * 1024),
+        };
+        let rows = comparison.rows();
+        // Los programas de inicio no se midieron
+        assert_eq!(rows.len(), 4);
+        assert_eq!(rows[0].metric, Metric::TempFiles);
+        assert_eq!(rows[0].delta(), -105);
+        assert_eq!(rows[0].improved(), Some(true));
+        assert_eq!(rows[1].improved(), Some(true));
+        assert_eq!(rows[2].improved(), None);
+        assert_eq!(rows[3].improved(), Some(true));
+        assert_eq!(
+            Metric::FreeDisk.format_delta(rows[3].delta(), Language::English),
+            "+2.00 GB"
+        );
+    }
+
+    #[test]
+    fn test_format_delta() {
+        assert_eq!(Metric::TempFiles.format_delta(-3, Language::English), "-3");
+        assert_eq!(Metric::TempFiles.format_delta(0, Language::English), "0");
+        assert_eq!(
+            Metric::FreeRam.format_delta(-1024 * 1024 * 1024, Language::Spanish),
+            "-1,00 GB"
+        );
+    }
+
+    #[test]
+    fn test_parse_counters() {
+        assert_eq!(
+            parse_counters("STARTUP|7\r\nSERVICES|112\r\n"),
+            (Some(7), Some(112))
+        );
+        assert_eq!(parse_counters("error"), (None, None));
+    }
+}
@@ -0,0 +1,169 @@
+//! Tiempo de arranque
+//!
+//! Lee los eventos 100 del registro `Diagnostics-Performance`, que Windows
+//! escribe en cada arranque con su duración (`BootTime`, en milisegundos), y
+//! marca los arranques que siguieron a una ejecución de win_opt según el
+//! historial de ejecuciones.
+
+use crate::history::{self, RunRecord};
+use crate::platform;
+use crate::types::OperationState;
+use crate::{log_error, log_info};
+
+/// Número máximo de arranques consultados
+const MAX_BOOTS: usize = 30;
+
+/// Script de PowerShell que imprime `fecha UTC|BootTime` por arranque
+fn boot_events_script() -> String {
+    format!(
+        "Get-WinEvent -FilterHashtable @{{LogName='Microsoft-Windows-Diagnostics-Performance/Operational'; Id=100}} -MaxEvents {} | \
+         ForEach-Object {{ $x = [xml]$_.ToXml(); \
+         $t = ($x.Event.EventData.Data | Where-Object {{ $_.Name -eq 'BootTime' }}).'#text'; \
+         $_.TimeCreated.ToUniversalTime().ToString('yyyy-MM-dd HH:mm') + '|' + $t }}",
+        MAX_BOOTS
+    )
+}
+
+/// Arranque registrado
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct BootRecord {
+    /// Fecha y hora UTC del arranque (`yyyy-MM-dd HH:mm`)
+    pub timestamp: String,
+    /// Duración del arranque en milisegundos
+    pub duration_ms: u64,
+    /// Hubo una ejecución de win_opt desde el arranque anterior
+    pub after_run: bool,
+}
+
+/// Duración media de los arranques del grupo indicado
+pub fn average_ms(boots: &[BootRecord], after_run: bool) -> Option<u64> {
+    let durations: Vec<u64> = boots
+        .iter()
+        .filter(|b| b.after_run == after_run)
+        .map(|b| b.duration_ms)
+        .collect();
+    if durations.is_empty() {
+        return None;
+    }
+    Some(durations.iter().sum::<u64>() / durations.len() as u64)
+}
+
+/// Lee los tiempos de arranque recientes
+pub fn execute_boot_times(app: &mut crate::app::App) {
+    app.operation_state = OperationState::Running;
+    app.boot_records.clear();
+    log_info!(
+        app,
+        "⏱️  Leyendo los tiempos de arranque del registro de eventos..."
+    );
+
+    let result = platform::command("powershell")
+        .args(["-NoProfile", "-Command", &boot_events_script()])
+        .output();
+
+    let mut boots = match result {
+        Ok(output) if output.status.success() => {
+            parse_boot_events(&String::from_utf8_lossy(&output.stdout))
+        }
+        Ok(_) => {
+            log_error!(
+                app,
+                "❌ No se pudo leer el registro Diagnostics-Performance (requiere Administrador)"
+            );
+            app.operation_state = OperationState::Failed;
+            return;
+        }
+        Err(e) => {
+            log_error!(app, "❌ Error: {}", e);
+            app.operation_state = OperationState::Failed;
+            return;
+        }
+    };
+    correlate(&mut boots, &history::load_runs());
+
+    let total = boots.len();
+    log_info!(app, "✅ Arranques encontrados: {}", total);
+    if let Some(latest) = boots.last() {
+        let seconds = latest.duration_ms as f64 / 1000.0;
+        log_info!(app, "🕒 Último arranque: {:.1} s", seconds);
+    }
+    if let (Some(after), Some(other)) = (average_ms(&boots, true), average_ms(&boots, false)) {
+        log_info!(
+            app,
+            "📊 Media tras ejecutar win_opt: {:.1} s (resto: {:.1} s)",
+            after as f64 / 1000.0,
+            other as f64 / 1000.0
+        );
+    }
+
+    app.boot_records = boots;
+    app.operation_state = OperationState::Completed;
+}
+
+/// Parsea la salida del script y ordena los arranques del más antiguo al más reciente
+fn parse_boot_events(output: &str) -> Vec<BootRecord> {
+    let mut boots: Vec<BootRecord> = output
+        .lines()
+        .filter_map(|line| {
+            let (timestamp, duration) = line.trim().split_once('|')?;
+            Some(BootRecord {
+                timestamp: timestamp.to_string(),
+                duration_ms: duration.parse().ok()?,
+                after_run: false,
+            })
+        })
+        .collect();
+    boots.sort_by(|a, b| a.timestamp.cmp(&b.timestamp));
+    boots
+}
+
+/// Marca los arranques precedidos por una ejecución desde el arranque anterior
+///
+/// Las fechas tienen el mismo formato UTC, así que se comparan como texto.
+/// El arranque más antiguo solo cuenta las ejecuciones anteriores a él.
+fn correlate(boots: &mut [BootRecord], runs: &[RunRecord]) {
+    let mut previous: Option<String> = None;
+    for boot in boots.iter_mut() {
+        boot.after_run = runs.iter().any(|run| {
+            run.timestamp <= boot.timestamp
+                && previous.as_ref().is_none_or(|prev| run.timestamp > *prev)
+        });
+        previous = Some(boot.timestamp.clone());
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn run(timestamp: &str) -> RunRecord {
+        RunRecord {
+            timestamp: timestamp.to_string(),
+            operation: "clean".to_string(),
+        }
+    }
+
+    #[test]
+    fn test_parse_boot_events_sorted() {
+        let output = "2026-10-15 07:58|41230\r\n2026-10-12 08:03|52100\r\nERROR\r\n";
+        let boots = parse_boot_events(output);
+        assert_eq!(boots.len(), 2);
+        assert_eq!(boots[0].timestamp, "2026-10-12 08:03");
+        assert_eq!(boots[1].duration_ms, 41230);
+    }
+
+    #[test]
+    fn test_correlate_with_runs() {
+        let mut boots = parse_boot_events(
+            "2026-10-10 08:00|50000\n2026-10-12 08:00|40000\n2026-10-14 08:00|45000\n",
+        );
+        correlate(
+            &mut boots,
+            &[run("2026-10-11 19:30"), run("2026-10-15 10:00")],
+        );
+        let flags: Vec<bool> = boots.iter().map(|b| b.after_run).collect();
+        assert_eq!(flags, vec![false, true, false]);
+        assert_eq!(average_ms(&boots, true), Some(40000));
+        assert_eq!(average_ms(&boots, false), Some(47500));
+    }
+}
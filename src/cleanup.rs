@@ -1,155 +1,628 @@
-use crate::types::{CleanStats, OperationState};
+use crate::config::Config;
+use crate::drives::{self, DRIVE_TEMP_DIRS, DriveChoice};
+use crate::error::{Result, WinOptError};
+use crate::i18n::{I18n, I18nKey};
+use crate::journal;
+use crate::platform;
+use crate::quarantine;
+use crate::retry::FailedItem;
+use crate::safety;
+use crate::types::{CleanStats, LocationStats, OperationState, OperationStats, View};
+use crate::user_profiles::UserProfile;
+use crate::utils::path_size;
 use crate::{log_debug, log_error, log_info, log_warn};
+use serde::{Deserialize, Serialize};
 use std::fs;
-use std::process::Command;
+use std::io;
+use std::path::{Path, PathBuf};
+use sysinfo::{ProcessRefreshKind, System, UpdateKind};
+
+/// Configuración de las limpiezas
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct CleanupConfig {
+    /// Enviar los elementos a la Papelera de reciclaje en lugar de eliminarlos
+    pub recycle_bin: bool,
+    /// Limpiar los archivos Prefetch antiguos en la optimización avanzada
+    #[serde(default)]
+    pub prefetch: bool,
+}
+
+/// Caché del sistema que una reconstrucción elimina con su servicio detenido
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SystemCache {
+    /// Caché del servicio FontCache
+    Fonts,
+    /// Base de datos del índice de Windows Search
+    SearchIndex,
+}
+
+impl SystemCache {
+    /// Archivos de la caché que existen en este equipo
+    pub fn files(self) -> Vec<PathBuf> {
+        self.files_in(&platform::windows_dir(), &platform::program_data_dir())
+    }
+
+    /// Archivos de la caché bajo las carpetas de Windows y de datos de
+    /// programas
+    ///
+    /// Solo se buscan nombres conocidos: nada de comodines sobre carpetas
+    /// del sistema.
+    fn files_in(self, windows: &Path, program_data: &Path) -> Vec<PathBuf> {
+        let mut files = match self {
+            SystemCache::Fonts => {
+                let dir = windows
+                    .join("ServiceProfiles")
+                    .join("LocalService")
+                    .join("AppData")
+                    .join("Local")
+                    .join("FontCache");
+                let mut files: Vec<PathBuf> = fs::read_dir(dir)
+                    .into_iter()
+                    .flatten()
+                    .flatten()
+                    .filter(|entry| {
+                        entry
+                            .file_name()
+                            .to_string_lossy()
+                            .to_lowercase()
+                            .contains("fontcache")
+                    })
+                    .map(|entry| entry.path())
+                    .collect();
+                files.push(windows.join("System32").join("FNTCACHE.DAT"));
+                files
+            }
+            // Windows 10 guarda el índice en `Windows.edb` y Windows 11 en
+            // `Windows.db`
+            SystemCache::SearchIndex => {
+                let dir = program_data
+                    .join("Microsoft")
+                    .join("Search")
+                    .join("Data")
+                    .join("Applications")
+                    .join("Windows");
+                vec![dir.join("Windows.edb"), dir.join("Windows.db")]
+            }
+        };
+        files.retain(|path| path.is_file());
+        files.sort();
+        files
+    }
+}
+
+/// Elimina un archivo o carpeta según el modo de borrado configurado
+///
+/// La cuarentena tiene prioridad sobre la Papelera de reciclaje; si ninguna
+/// está activada el elemento se elimina definitivamente. Los enlaces
+/// simbólicos y las uniones (junctions) nunca se siguen: se elimina solo el
+/// enlace, aunque apunte fuera de la carpeta que se limpia.
+///
+/// Retorna `WinOptError::InvalidPath` si la ruta está protegida (ver
+/// [`safety`]).
+pub fn remove_path(config: &Config, path: &Path) -> Result<()> {
+    safety::check_removable(path)?;
+    let metadata = fs::symlink_metadata(path)?;
+    let result = if metadata.file_type().is_symlink() {
+        remove_link(path)
+    } else if config.quarantine.enabled {
+        quarantine::quarantine_path(path)
+    } else if config.cleanup.recycle_bin {
+        move_to_recycle_bin(path)
+    } else if metadata.is_dir() {
+        fs::remove_dir_all(path)
+    } else {
+        fs::remove_file(path)
+    };
+    result.map_err(WinOptError::from)
+}
+
+/// Elimina un enlace simbólico o una unión sin tocar su destino
+///
+/// Un enlace no tiene contenido propio, así que no pasa por la cuarentena ni
+/// por la Papelera. Los enlaces a carpetas y las uniones se eliminan como
+/// carpetas vacías.
+#[cfg(windows)]
+fn remove_link(path: &Path) -> io::Result<()> {
+    use std::os::windows::fs::FileTypeExt;
+
+    if fs::symlink_metadata(path)?.file_type().is_symlink_dir() {
+        fs::remove_dir(path)
+    } else {
+        fs::remove_file(path)
+    }
+}
+
+#[cfg(not(windows))]
+fn remove_link(path: &Path) -> io::Result<()> {
+    fs::remove_file(path)
+}
+
+/// Registra en los logs el modo de borrado que usarán las limpiezas
+pub fn log_deletion_mode(app: &mut crate::app::App) {
+    if app.config.quarantine.enabled {
+        let retention = app.config.quarantine.retention_days.to_string();
+        log_info!(
+            app,
+            "ℹ️  {}",
+            app.t_args(I18nKey::CleanQuarantineMode, &[("count", &retention)])
+        );
+    } else if app.config.cleanup.recycle_bin {
+        log_info!(app, "ℹ️  {}", app.t(I18nKey::CleanRecycleBinMode));
+    }
+}
+
+/// Envía un archivo o carpeta a la Papelera de reciclaje con `SHFileOperationW`
+#[cfg(windows)]
+fn move_to_recycle_bin(path: &Path) -> io::Result<()> {
+    use std::os::windows::ffi::OsStrExt;
+    use windows::Win32::UI::Shell::{
+        FO_DELETE, FOF_ALLOWUNDO, FOF_NOCONFIRMATION, FOF_NOERRORUI, FOF_SILENT, SHFILEOPSTRUCTW,
+        SHFileOperationW,
+    };
+    use windows::core::PCWSTR;
+
+    // pFrom es una lista de rutas absolutas terminada en doble nulo
+    let from: Vec<u16> = std::path::absolute(path)?
+        .as_os_str()
+        .encode_wide()
+        .chain([0, 0])
+        .collect();
+
+    let mut operation = SHFILEOPSTRUCTW {
+        wFunc: FO_DELETE,
+        pFrom: PCWSTR(from.as_ptr()),
+        fFlags: (FOF_ALLOWUNDO | FOF_NOCONFIRMATION | FOF_NOERRORUI | FOF_SILENT).0 as u16,
+        ..Default::default()
+    };
+
+    // SAFETY: `from` sigue vivo durante la llamada y termina en doble nulo
+    let result = unsafe { SHFileOperationW(&mut operation) };
+    if result != 0 {
+        Err(io::Error::other(format!(
+            "SHFileOperation falló (código 0x{:X})",
+            result
+        )))
+    } else if operation.fAnyOperationsAborted.as_bool() {
+        Err(io::Error::new(
+            io::ErrorKind::Interrupted,
+            "operación cancelada",
+        ))
+    } else {
+        Ok(())
+    }
+}
+
+#[cfg(not(windows))]
+fn move_to_recycle_bin(_path: &Path) -> io::Result<()> {
+    Err(io::Error::new(
+        io::ErrorKind::Unsupported,
+        "la Papelera de reciclaje solo está disponible en Windows",
+    ))
+}
+
+/// Carpeta temporal que recorre la limpieza
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub enum TempLocation {
+    /// `%TEMP%` del usuario actual
+    #[default]
+    User,
+    /// `C:\Windows\Temp`
+    Windows,
+    /// `AppData\Local\Temp` de otro perfil del equipo
+    Profile(String),
+    /// Caché de Internet de otro perfil del equipo
+    ProfileCache(String),
+    /// Caché de Internet de Edge e Internet Explorer (`INetCache`)
+    InetCache,
+    /// `Temp` o `tmp` en la raíz de otra unidad
+    Drive(String),
+}
+
+impl TempLocation {
+    /// Nombre de la ubicación en el idioma actual
+    pub fn label(&self, i18n: &I18n) -> String {
+        match self {
+            TempLocation::User => i18n.t(I18nKey::CleanLocationUser).to_string(),
+            TempLocation::Windows => i18n.t(I18nKey::CleanLocationWindows).to_string(),
+            TempLocation::Profile(user) => {
+                i18n.t_args(I18nKey::CleanLocationProfile, &[("user", user)])
+            }
+            TempLocation::ProfileCache(user) => {
+                i18n.t_args(I18nKey::CleanLocationProfileCache, &[("user", user)])
+            }
+            TempLocation::InetCache => i18n.t(I18nKey::CleanLocationInetCache).to_string(),
+            TempLocation::Drive(drive) => {
+                i18n.t_args(I18nKey::CleanLocationDrive, &[("drive", drive)])
+            }
+        }
+    }
+}
+
+/// Carpetas temporales conocidas, en el orden en que se limpian
+///
+/// De los demás perfiles solo se incluyen los marcados en `profiles`, que
+/// únicamente se ofrecen con privilegios de administrador porque sin ellos no
+/// se pueden leer. Si se indican unidades, solo se limpian las carpetas de las
+/// marcadas, más las carpetas temporales de la raíz de las que no son del
+/// sistema.
+pub fn temp_locations(
+    drives: &[DriveChoice],
+    profiles: &[UserProfile],
+) -> Vec<(TempLocation, PathBuf)> {
+    let profile = platform::user_profile();
+    let mut candidates = vec![
+        (TempLocation::User, platform::temp_dir()),
+        (TempLocation::Windows, platform::windows_dir().join("Temp")),
+    ];
+    for other in profiles.iter().filter(|other| other.selected) {
+        candidates.push((TempLocation::Profile(other.name.clone()), other.temp_dir()));
+        candidates.push((
+            TempLocation::ProfileCache(other.name.clone()),
+            other.inet_cache_dir(),
+        ));
+    }
+    candidates.push((
+        TempLocation::InetCache,
+        profile
+            .join("AppData")
+            .join("Local")
+            .join("Microsoft")
+            .join("Windows")
+            .join("INetCache"),
+    ));
+    if !drives.is_empty() {
+        candidates.retain(|(_, path)| drives::owner(path, drives).is_none_or(|d| d.selected));
+        for drive in drives.iter().filter(|d| d.selected && !d.system) {
+            candidates.extend(DRIVE_TEMP_DIRS.iter().map(|dir| {
+                (
+                    TempLocation::Drive(drive.name.clone()),
+                    drive.mount.join(dir),
+                )
+            }));
+        }
+    }
+    existing_unique(candidates)
+}
+
+/// Descarta las carpetas que no existen y las que repiten una anterior
+///
+/// `%TEMP%` puede apuntar a `C:\Windows\Temp` (por ejemplo, en la cuenta
+/// SYSTEM); cada carpeta se recorre una sola vez.
+fn existing_unique(candidates: Vec<(TempLocation, PathBuf)>) -> Vec<(TempLocation, PathBuf)> {
+    let mut seen = Vec::new();
+    candidates
+        .into_iter()
+        .filter(|(_, path)| match fs::canonicalize(path) {
+            Ok(canonical) if canonical.is_dir() && !seen.contains(&canonical) => {
+                seen.push(canonical);
+                true
+            }
+            _ => false,
+        })
+        .collect()
+}
+
+/// Códigos de error de Windows de un archivo abierto por otro proceso
+/// (`ERROR_SHARING_VIOLATION` y `ERROR_LOCK_VIOLATION`)
+const IN_USE_ERRORS: [i32; 2] = [32, 33];
+
+/// Indica si el error al borrar se debe a que el archivo está en uso
+fn is_in_use_error(error: &io::Error) -> bool {
+    cfg!(windows)
+        && error
+            .raw_os_error()
+            .is_some_and(|code| IN_USE_ERRORS.contains(&code))
+}
+
+/// Rutas que usan los procesos en ejecución
+///
+/// Un instalador que se descomprime en `%TEMP%` se ejecuta desde allí, y una
+/// terminal abierta en una carpeta temporal la usa como directorio de
+/// trabajo. Esas carpetas se dejan intactas en lugar de borrarlas a medias.
+struct ActivePaths(Vec<PathBuf>);
+
+impl ActivePaths {
+    /// Ejecutable y directorio de trabajo de este proceso y de los demás
+    fn detect() -> ActivePaths {
+        let mut sys = System::new();
+        sys.refresh_processes_specifics(
+            ProcessRefreshKind::new()
+                .with_exe(UpdateKind::OnlyIfNotSet)
+                .with_cwd(UpdateKind::OnlyIfNotSet),
+        );
+        let own = [std::env::current_exe().ok(), std::env::current_dir().ok()];
+        let others = sys
+            .processes()
+            .values()
+            .flat_map(|process| [process.exe(), process.cwd()])
+            .flatten()
+            .map(Path::to_path_buf);
+        ActivePaths(
+            own.into_iter()
+                .flatten()
+                .chain(others)
+                .filter_map(|path| fs::canonicalize(path).ok())
+                .collect(),
+        )
+    }
+
+    /// Indica si algún proceso usa la ruta o algo dentro de ella
+    fn holds(&self, path: &Path) -> bool {
+        fs::canonicalize(path)
+            .is_ok_and(|path| self.0.iter().any(|active| active.starts_with(&path)))
+    }
+}
 
 /// Ejecuta la operación de limpieza de archivos temporales
+///
+/// Recorre todas las carpetas temporales conocidas y guarda las estadísticas
+/// de cada una además del total. Si una carpeta no se puede leer, se avisa y
+/// se continúa con las demás. Los elementos que usa algún proceso se cuentan
+/// como en uso, no como fallos.
 pub fn execute_clean(app: &mut crate::app::App) {
     app.operation_state = OperationState::Running;
-    log_info!(app, "🧹 Iniciando limpieza de archivos temporales...");
+    log_info!(app, "🧹 {}", app.t(I18nKey::CleanStarting));
+    log_deletion_mode(app);
+    if !app.elevated {
+        log_info!(app, "ℹ️  {}", app.t(I18nKey::CleanProfilesSkipped));
+    }
 
-    let temp_dir = std::env::temp_dir();
-    log_info!(app, "📁 Directorio: {}", temp_dir.to_string_lossy());
+    let active = ActivePaths::detect();
+    let mut stats = CleanStats::default();
+    let locations = temp_locations(&app.clean_drives, &app.clean_profiles);
+    journal::begin(
+        View::Clean,
+        shown_paths(locations.iter().map(|(_, path)| path)),
+    );
+    for (location, path) in locations {
+        let location_stats = clean_location(app, &active, location, path);
+        stats.deleted_count += location_stats.deleted_count;
+        stats.failed_count += location_stats.failed_count;
+        stats.in_use_count += location_stats.in_use_count;
+        stats.size_freed += location_stats.size_freed;
+        stats.locations.push(location_stats);
+    }
 
-    let mut deleted_count = 0;
-    let mut size_freed: u64 = 0;
-    let mut failed_count = 0;
+    log_info!(
+        app,
+        "✅ {}",
+        app.t_args(
+            I18nKey::CleanCompleted,
+            &[
+                ("deleted", &stats.deleted_count.to_string()),
+                ("failed", &stats.failed_count.to_string()),
+                ("in_use", &stats.in_use_count.to_string()),
+                ("size", &app.i18n.format_size(stats.size_freed)),
+            ],
+        )
+    );
+    app.stats = Some(OperationStats::Clean(stats));
+    app.operation_state = OperationState::Completed;
+}
 
-    match fs::read_dir(&temp_dir) {
-        Ok(entries) => {
-            let entries_vec: Vec<_> = entries.flatten().collect();
-            let total = entries_vec.len();
+/// Limpia el contenido de una carpeta temporal
+fn clean_location(
+    app: &mut crate::app::App,
+    active: &ActivePaths,
+    location: TempLocation,
+    dir: PathBuf,
+) -> LocationStats {
+    let label = location.label(&app.i18n);
+    log_info!(
+        app,
+        "📁 {} — {}",
+        label,
+        app.t_args(I18nKey::CleanDirectory, &[("path", &dir.to_string_lossy())])
+    );
 
-            log_info!(app, "📊 Elementos encontrados: {}", total);
+    let mut stats = LocationStats {
+        location,
+        path: dir,
+        ..LocationStats::default()
+    };
 
-            for (idx, entry) in entries_vec.iter().enumerate() {
-                let path = entry.path();
+    let entries_vec: Vec<_> = match fs::read_dir(&stats.path) {
+        Ok(entries) => entries.flatten().collect(),
+        Err(e) => {
+            log_warn!(
+                app,
+                "⚠️  {}",
+                app.t_args(
+                    I18nKey::CleanLocationUnreadable,
+                    &[("location", &label), ("error", &e.to_string())]
+                )
+            );
+            return stats;
+        }
+    };
+    let total = entries_vec.len();
 
-                if path.is_file() {
-                    if let Ok(metadata) = fs::metadata(&path) {
-                        size_freed += metadata.len();
-                    }
-                    if fs::remove_file(&path).is_ok() {
-                        deleted_count += 1;
-                        log_debug!(app, "Archivo eliminado: {}", path.display());
-                    } else {
-                        failed_count += 1;
-                        log_warn!(app, "No se pudo eliminar archivo: {}", path.display());
-                    }
-                } else if path.is_dir() {
-                    if let Ok(entries) = fs::read_dir(&path) {
-                        for entry in entries.flatten() {
-                            if let Ok(meta) = entry.metadata() {
-                                size_freed += meta.len();
-                            }
-                        }
-                    }
-                    if fs::remove_dir_all(&path).is_ok() {
-                        deleted_count += 1;
-                        log_debug!(app, "Directorio eliminado: {}", path.display());
-                    } else {
-                        failed_count += 1;
-                        log_warn!(app, "No se pudo eliminar directorio: {}", path.display());
-                    }
-                }
+    log_info!(
+        app,
+        "📊 {}",
+        app.t_args(I18nKey::CleanItemsFound, &[("count", &total.to_string())])
+    );
 
-                if idx % 10 == 0 {
-                    log_debug!(app, "Procesando... {}/{}", idx + 1, total);
-                }
-            }
+    for (idx, entry) in entries_vec.iter().enumerate() {
+        if idx % 10 == 0 {
+            log_debug!(
+                app,
+                "{}",
+                app.t_args(
+                    I18nKey::CleanProcessing,
+                    &[
+                        ("current", &(idx + 1).to_string()),
+                        ("total", &total.to_string()),
+                    ],
+                )
+            );
+        }
 
-            app.clean_stats = CleanStats {
-                deleted_count,
-                failed_count,
-                size_freed,
-            };
+        let path = entry.path();
+        let shown = path.display().to_string();
+        let Ok(metadata) = fs::symlink_metadata(&path) else {
+            continue;
+        };
+        // Un enlace se elimina sin seguirlo, así que su destino no cuenta
+        let link = metadata.file_type().is_symlink();
+        let (deleted_key, failed_key) = if link {
+            (I18nKey::CleanLinkDeleted, I18nKey::CleanFileFailed)
+        } else if metadata.is_dir() {
+            (I18nKey::CleanDirDeleted, I18nKey::CleanDirFailed)
+        } else {
+            (I18nKey::CleanFileDeleted, I18nKey::CleanFileFailed)
+        };
+        // Se mide antes de borrar: después ya no queda nada que medir
+        let size = path_size(&path);
 
-            log_info!(
+        if !link && active.holds(&path) {
+            stats.in_use_count += 1;
+            log_debug!(
                 app,
-                "✅ Limpieza completada - Eliminados: {}, Omitidos: {}, Espacio: {} bytes",
-                deleted_count,
-                failed_count,
-                size_freed
+                "{}",
+                app.t_args(I18nKey::CleanInUse, &[("path", &shown)])
             );
+            continue;
         }
-        Err(e) => {
-            log_error!(app, "❌ Error al leer el directorio temporal: {}", e);
+
+        match remove_path(&app.config, &path) {
+            Ok(()) => {
+                stats.deleted_count += 1;
+                stats.size_freed += size;
+                log_debug!(app, "{}", app.t_args(deleted_key, &[("path", &shown)]));
+            }
+            Err(WinOptError::Io(e)) if is_in_use_error(&e) => {
+                stats.in_use_count += 1;
+                log_debug!(
+                    app,
+                    "{}",
+                    app.t_args(I18nKey::CleanInUse, &[("path", &shown)])
+                );
+            }
+            Err(WinOptError::InvalidPath(reason)) => {
+                stats.failed_count += 1;
+                log_error!(app, "🛡️  {}", reason);
+            }
+            Err(_) => {
+                stats.failed_count += 1;
+                log_warn!(app, "{}", app.t_args(failed_key, &[("path", &shown)]));
+                app.failed_items.push(FailedItem::Path(path));
+            }
         }
     }
 
-    app.operation_state = OperationState::Completed;
+    log_info!(
+        app,
+        "   {}",
+        app.t_args(
+            I18nKey::CleanLocationDone,
+            &[
+                ("location", &label),
+                ("deleted", &stats.deleted_count.to_string()),
+                ("failed", &stats.failed_count.to_string()),
+                ("in_use", &stats.in_use_count.to_string()),
+                ("size", &app.i18n.format_size(stats.size_freed)),
+            ],
+        )
+    );
+    stats
 }
 
 /// Ejecuta limpieza de caché de navegadores
 pub fn execute_browser_cache(app: &mut crate::app::App) {
     app.operation_state = OperationState::Running;
-    log_info!(app, "🌐 Iniciando limpieza de caché de navegadores...");
+    log_info!(app, "🌐 {}", app.t(I18nKey::BrowserCacheStarting));
+    log_deletion_mode(app);
 
-    let user_profile =
-        std::env::var("USERPROFILE").unwrap_or_else(|_| "C:\\Users\\Default".to_string());
+    let local_app_data = platform::user_profile().join("AppData").join("Local");
 
     // Rutas de caché de navegadores
     let cache_paths = [
         (
             "Google Chrome",
-            format!(
-                "{}\\AppData\\Local\\Google\\Chrome\\User Data\\Default\\Cache",
-                user_profile
-            ),
+            local_app_data
+                .join("Google")
+                .join("Chrome")
+                .join("User Data")
+                .join("Default")
+                .join("Cache"),
         ),
         (
             "Microsoft Edge",
-            format!(
-                "{}\\AppData\\Local\\Microsoft\\Edge\\User Data\\Default\\Cache",
-                user_profile
-            ),
+            local_app_data
+                .join("Microsoft")
+                .join("Edge")
+                .join("User Data")
+                .join("Default")
+                .join("Cache"),
         ),
         (
             "Mozilla Firefox",
-            format!(
-                "{}\\AppData\\Local\\Mozilla\\Firefox\\Profiles",
-                user_profile
-            ),
+            local_app_data
+                .join("Mozilla")
+                .join("Firefox")
+                .join("Profiles"),
         ),
     ];
 
+    journal::begin(
+        View::BrowserCache,
+        shown_paths(cache_paths.iter().map(|(_, path)| path)),
+    );
+
     let mut total_cleaned = 0;
     let mut total_failed = 0;
 
     for (browser_name, cache_path) in cache_paths {
         log_info!(app, "");
-        log_info!(app, "🗑️  Limpiando caché de {}...", browser_name);
+        log_info!(
+            app,
+            "🗑️  {}",
+            app.t_args(I18nKey::BrowserCacheCleaning, &[("browser", browser_name)])
+        );
 
         if let Ok(entries) = fs::read_dir(&cache_path) {
             for entry in entries.flatten() {
                 let path = entry.path();
-                let result = if path.is_dir() {
-                    fs::remove_dir_all(&path)
-                } else {
-                    fs::remove_file(&path)
-                };
+                let result = remove_path(&app.config, &path);
 
+                let shown = path.display().to_string();
                 if result.is_ok() {
                     total_cleaned += 1;
-                    log_debug!(app, "Eliminado: {}", path.display());
+                    log_debug!(
+                        app,
+                        "{}",
+                        app.t_args(I18nKey::OpItemDeleted, &[("path", &shown)])
+                    );
                 } else {
                     total_failed += 1;
-                    log_debug!(app, "Omitido: {}", path.display());
+                    log_debug!(
+                        app,
+                        "{}",
+                        app.t_args(I18nKey::OpItemSkipped, &[("path", &shown)])
+                    );
+                    if !matches!(result, Err(WinOptError::InvalidPath(_))) {
+                        app.failed_items.push(FailedItem::Path(path));
+                    }
                 }
             }
-            log_info!(app, "✅ {} - Caché limpiada", browser_name);
+            log_info!(
+                app,
+                "✅ {}",
+                app.t_args(I18nKey::BrowserCacheSuccess, &[("browser", browser_name)])
+            );
         } else {
-            log_warn!(app, "⚠️  {} - No encontrado o inaccesible", browser_name);
+            log_warn!(
+                app,
+                "⚠️  {}",
+                app.t_args(I18nKey::BrowserCacheNotFound, &[("browser", browser_name)])
+            );
         }
     }
 
     log_info!(app, "");
-    log_info!(app, "✅ Archivos eliminados: {}", total_cleaned);
-    log_info!(app, "⚠️  Archivos omitidos: {}", total_failed);
-    log_info!(
-        app,
-        "ℹ️  Cierra los navegadores antes de ejecutar esta operación para mejores resultados"
-    );
+    log_files_summary(app, total_cleaned, total_failed);
+    log_info!(app, "ℹ️  {}", app.t(I18nKey::BrowserCacheCloseWarning));
 
     app.operation_state = OperationState::Completed;
 }
@@ -157,22 +630,26 @@ pub fn execute_browser_cache(app: &mut crate::app::App) {
 /// Ejecuta limpieza de logs del sistema
 pub fn execute_system_logs(app: &mut crate::app::App) {
     app.operation_state = OperationState::Running;
-    log_info!(app, "📋 Iniciando limpieza de logs del sistema...");
+    log_info!(app, "📋 {}", app.t(I18nKey::SystemLogsStarting));
+    log_deletion_mode(app);
 
-    let log_paths = [
-        "C:\\Windows\\Logs",
-        "C:\\Windows\\Temp",
-        "C:\\Windows\\Prefetch",
-    ];
+    let windows_dir = platform::windows_dir();
+    let log_paths = [windows_dir.join("Logs"), windows_dir.join("Temp")];
+    journal::begin(View::SystemLogs, shown_paths(&log_paths));
 
     let mut total_deleted = 0;
     let mut total_failed = 0;
 
     for log_path in log_paths {
+        let shown_dir = log_path.display().to_string();
         log_info!(app, "");
-        log_info!(app, "🗑️  Limpiando: {}...", log_path);
+        log_info!(
+            app,
+            "🗑️  {}",
+            app.t_args(I18nKey::SystemLogsCleaning, &[("path", &shown_dir)])
+        );
 
-        if let Ok(entries) = fs::read_dir(log_path) {
+        if let Ok(entries) = fs::read_dir(&log_path) {
             for entry in entries.flatten() {
                 let path = entry.path();
 
@@ -180,41 +657,79 @@ pub fn execute_system_logs(app: &mut crate::app::App) {
                 if let Some(ext) = path.extension()
                     && (ext == "log" || ext == "txt" || ext == "etl" || ext == "tmp")
                 {
-                    let result = if path.is_dir() {
-                        fs::remove_dir_all(&path)
-                    } else {
-                        fs::remove_file(&path)
-                    };
+                    let result = remove_path(&app.config, &path);
 
+                    let shown = path.display().to_string();
                     if result.is_ok() {
                         total_deleted += 1;
-                        log_debug!(app, "Eliminado: {}", path.display());
+                        log_debug!(
+                            app,
+                            "{}",
+                            app.t_args(I18nKey::OpItemDeleted, &[("path", &shown)])
+                        );
                     } else {
                         total_failed += 1;
-                        log_debug!(app, "Omitido: {}", path.display());
+                        log_debug!(
+                            app,
+                            "{}",
+                            app.t_args(I18nKey::OpItemSkipped, &[("path", &shown)])
+                        );
+                        if !matches!(result, Err(WinOptError::InvalidPath(_))) {
+                            app.failed_items.push(FailedItem::Path(path));
+                        }
                     }
                 }
             }
-            log_info!(app, "✅ {} procesado", log_path);
+            log_info!(
+                app,
+                "✅ {}",
+                app.t_args(I18nKey::SystemLogsProcessed, &[("path", &shown_dir)])
+            );
         } else {
-            log_warn!(app, "⚠️  {} - Requiere permisos de administrador", log_path);
+            log_warn!(
+                app,
+                "⚠️  {}",
+                app.t_args(I18nKey::SystemLogsRequiresAdmin, &[("path", &shown_dir)])
+            );
         }
     }
 
     log_info!(app, "");
-    log_info!(app, "✅ Archivos eliminados: {}", total_deleted);
-    log_info!(app, "⚠️  Archivos omitidos: {}", total_failed);
+    log_files_summary(app, total_deleted, total_failed);
 
     app.operation_state = OperationState::Completed;
 }
 
+/// Rutas tal y como se anotan en el diario de operaciones
+fn shown_paths<'a>(paths: impl IntoIterator<Item = &'a PathBuf>) -> Vec<String> {
+    paths
+        .into_iter()
+        .map(|path| path.display().to_string())
+        .collect()
+}
+
+/// Registra el resumen de archivos eliminados y omitidos de una limpieza
+fn log_files_summary(app: &mut crate::app::App, deleted: usize, failed: usize) {
+    log_info!(
+        app,
+        "✅ {}",
+        app.t_args(I18nKey::OpFilesDeleted, &[("count", &deleted.to_string())])
+    );
+    log_info!(
+        app,
+        "⚠️  {}",
+        app.t_args(I18nKey::OpFilesSkipped, &[("count", &failed.to_string())])
+    );
+}
+
 /// Ejecuta vaciado de papelera de reciclaje
 pub fn execute_recycle_bin(app: &mut crate::app::App) {
     app.operation_state = OperationState::Running;
-    log_info!(app, "🗑️  Iniciando vaciado de papelera de reciclaje...");
+    log_info!(app, "🗑️  {}", app.t(I18nKey::RecycleBinStarting));
+    journal::begin(View::RecycleBin, Vec::new());
 
     // Vaciar papelera usando PowerShell
-    let result = Command::new("powershell")
+    let result = platform::command("powershell")
         .args([
             "-Command",
             "Clear-RecycleBin -Force -ErrorAction SilentlyContinue",
@@ -224,26 +739,390 @@ pub fn execute_recycle_bin(app: &mut crate::app::App) {
     match result {
         Ok(output) => {
             if output.status.success() {
-                log_info!(app, "✅ Papelera de reciclaje vaciada exitosamente");
+                log_info!(app, "✅ {}", app.t(I18nKey::RecycleBinSuccess));
             } else {
-                log_warn!(
-                    app,
-                    "⚠️  Advertencia: Algunas carpetas no pudieron vaciarse"
-                );
+                log_warn!(app, "⚠️  {}", app.t(I18nKey::RecycleBinWarning));
                 log_debug!(
                     app,
-                    "Salida del comando: {}",
-                    String::from_utf8_lossy(&output.stderr)
+                    "{}",
+                    app.t_args(
+                        I18nKey::OpCommandOutput,
+                        &[("output", &String::from_utf8_lossy(&output.stderr))],
+                    )
                 );
             }
         }
         Err(e) => {
-            log_error!(app, "❌ Error al vaciar papelera: {}", e);
+            log_error!(
+                app,
+                "❌ {}",
+                app.t_args(I18nKey::RecycleBinError, &[("error", &e.to_string())])
+            );
         }
     }
 
     log_info!(app, "");
-    log_info!(app, "ℹ️  Espacio en disco liberado");
+    log_info!(app, "ℹ️  {}", app.t(I18nKey::RecycleBinFreed));
 
     app.operation_state = OperationState::Completed;
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_recycle_bin_with_fake_runner() {
+        use crate::platform::FakeRunner;
+
+        let (runner, _guard) = FakeRunner::default()
+            .respond("powershell -Command Clear-RecycleBin", 0, "")
+            .install();
+        let mut app = crate::app::App::default();
+        execute_recycle_bin(&mut app);
+
+        assert!(runner.ran("powershell -Command Clear-RecycleBin -Force"));
+        let success = app.t(I18nKey::RecycleBinSuccess).to_string();
+        assert!(
+            app.operation_logs
+                .iter()
+                .any(|log| log.message.contains(&success))
+        );
+        assert_eq!(app.operation_state, OperationState::Completed);
+    }
+
+    #[test]
+    fn test_remove_path_deletes_permanently_by_default() {
+        let root = std::env::temp_dir().join("win_opt_test_cleanup_remove");
+        let _ = fs::remove_dir_all(&root);
+        fs::create_dir_all(root.join("dir")).unwrap();
+        fs::write(root.join("dir").join("a.tmp"), b"x").unwrap();
+        fs::write(root.join("b.tmp"), b"y").unwrap();
+
+        let config = Config::default();
+        remove_path(&config, &root.join("dir")).unwrap();
+        remove_path(&config, &root.join("b.tmp")).unwrap();
+        assert!(!root.join("dir").exists());
+        assert!(!root.join("b.tmp").exists());
+
+        fs::remove_dir_all(&root).unwrap();
+    }
+
+    #[test]
+    fn test_system_cache_files_are_resolved_by_name() {
+        let root = std::env::temp_dir().join("win_opt_test_cleanup_system_cache");
+        let _ = fs::remove_dir_all(&root);
+        let (windows, program_data) = (root.join("Windows"), root.join("ProgramData"));
+        let font_dir = windows
+            .join("ServiceProfiles")
+            .join("LocalService")
+            .join("AppData")
+            .join("Local")
+            .join("FontCache");
+        let index_dir = program_data
+            .join("Microsoft")
+            .join("Search")
+            .join("Data")
+            .join("Applications")
+            .join("Windows");
+        for dir in [&font_dir, &windows.join("System32"), &index_dir] {
+            fs::create_dir_all(dir).unwrap();
+        }
+        for file in [
+            font_dir.join("~FontCache-System.dat"),
+            font_dir.join("other.dat"),
+            windows.join("System32").join("FNTCACHE.DAT"),
+            index_dir.join("Windows.db"),
+        ] {
+            fs::write(file, b"cache").unwrap();
+        }
+
+        assert_eq!(
+            SystemCache::Fonts.files_in(&windows, &program_data),
+            [
+                font_dir.join("~FontCache-System.dat"),
+                windows.join("System32").join("FNTCACHE.DAT"),
+            ]
+        );
+        // Solo el archivo del índice que existe
+        assert_eq!(
+            SystemCache::SearchIndex.files_in(&windows, &program_data),
+            [index_dir.join("Windows.db")]
+        );
+
+        fs::remove_dir_all(&root).unwrap();
+    }
+
+    #[test]
+    fn test_remove_path_refuses_protected_paths() {
+        let cwd = std::env::current_dir().unwrap();
+        let drive_root = cwd.ancestors().last().unwrap();
+
+        let result = remove_path(&Config::default(), drive_root);
+        assert!(matches!(result, Err(WinOptError::InvalidPath(_))));
+    }
+
+    #[test]
+    fn test_deletion_mode_is_logged_in_app_language() {
+        let mut app = crate::app::App::default();
+        app.config.cleanup.recycle_bin = true;
+        app.i18n.set_language(crate::i18n::Language::English);
+
+        log_deletion_mode(&mut app);
+        let entry = app.operation_logs.last().unwrap();
+        assert_eq!(entry.message, "ℹ️  Items will be sent to the Recycle Bin");
+    }
+
+    #[test]
+    fn test_temp_locations_of_selected_profiles() {
+        let root = std::env::temp_dir().join("win_opt_test_cleanup_profiles");
+        let _ = fs::remove_dir_all(&root);
+        let profile = |name: &str, selected: bool| UserProfile {
+            name: name.to_string(),
+            path: root.join(name),
+            selected,
+        };
+        let profiles = [profile("Ana", true), profile("luis", false)];
+        for other in &profiles {
+            fs::create_dir_all(other.temp_dir()).unwrap();
+            fs::create_dir_all(other.inet_cache_dir()).unwrap();
+        }
+
+        let locations: Vec<_> = temp_locations(&[], &profiles)
+            .into_iter()
+            .filter(|(_, path)| path.starts_with(&root))
+            .collect();
+        assert_eq!(
+            locations,
+            [
+                (
+                    TempLocation::Profile("Ana".to_string()),
+                    profiles[0].temp_dir()
+                ),
+                (
+                    TempLocation::ProfileCache("Ana".to_string()),
+                    profiles[0].inet_cache_dir()
+                ),
+            ]
+        );
+
+        fs::remove_dir_all(&root).unwrap();
+    }
+
+    #[test]
+    fn test_temp_locations_of_selected_drives() {
+        let root = std::env::temp_dir().join("win_opt_test_cleanup_drives");
+        let _ = fs::remove_dir_all(&root);
+        for dir in ["D/Temp", "D/tmp", "E/Temp"] {
+            fs::create_dir_all(root.join(dir)).unwrap();
+        }
+        let drive = |name: &str, selected: bool| DriveChoice {
+            mount: root.join(name),
+            name: format!("{}:", name),
+            total: 0,
+            available: 0,
+            system: false,
+            selected,
+        };
+        let drives = [drive("D", true), drive("E", false)];
+
+        let locations = temp_locations(&drives, &[]);
+        let on_drives: Vec<_> = locations
+            .iter()
+            .filter(|(_, path)| path.starts_with(&root))
+            .collect();
+        assert_eq!(
+            on_drives,
+            [
+                &(TempLocation::Drive("D:".to_string()), root.join("D/Temp")),
+                &(TempLocation::Drive("D:".to_string()), root.join("D/tmp")),
+            ]
+        );
+        // Las carpetas que no están en ninguna unidad de la lista se mantienen
+        assert!(locations.contains(&(TempLocation::User, platform::temp_dir())));
+
+        fs::remove_dir_all(&root).unwrap();
+    }
+
+    #[test]
+    fn test_existing_unique_drops_missing_and_repeated_dirs() {
+        let root = std::env::temp_dir().join("win_opt_test_cleanup_unique");
+        let _ = fs::remove_dir_all(&root);
+        fs::create_dir_all(root.join("Temp")).unwrap();
+
+        let locations = existing_unique(vec![
+            (TempLocation::User, root.join("Temp")),
+            (
+                TempLocation::Windows,
+                root.join("Temp").join("..").join("Temp"),
+            ),
+            (TempLocation::InetCache, root.join("INetCache")),
+        ]);
+        assert_eq!(locations, [(TempLocation::User, root.join("Temp"))]);
+
+        fs::remove_dir_all(&root).unwrap();
+    }
+
+    #[test]
+    fn test_clean_summary_shows_a_readable_size() {
+        use crate::i18n::{I18n, Language};
+
+        for language in Language::ALL {
+            let i18n = I18n::new(language);
+            let size = i18n.format_size(5 * 1024 * 1024 * 1024);
+            let summary = i18n.t_args(
+                I18nKey::CleanCompleted,
+                &[
+                    ("deleted", "1"),
+                    ("failed", "0"),
+                    ("in_use", "0"),
+                    ("size", &size),
+                ],
+            );
+            assert!(summary.ends_with(&size), "{:?}: {}", language, summary);
+        }
+    }
+
+    #[test]
+    fn test_clean_reports_each_location() {
+        let root = std::env::temp_dir().join("win_opt_test_cleanup_location");
+        let _ = fs::remove_dir_all(&root);
+        fs::create_dir_all(root.join("sub").join("deep")).unwrap();
+        fs::write(root.join("a.tmp"), [0; 100]).unwrap();
+        fs::write(root.join("sub").join("b.tmp"), [0; 50]).unwrap();
+        fs::write(root.join("sub").join("deep").join("c.tmp"), [0; 25]).unwrap();
+
+        let mut app = crate::app::App::default();
+        let active = ActivePaths(Vec::new());
+        let stats = clean_location(&mut app, &active, TempLocation::Windows, root.clone());
+        assert_eq!(stats.location, TempLocation::Windows);
+        assert_eq!(stats.deleted_count, 2);
+        assert_eq!(stats.failed_count, 0);
+        // El tamaño de las carpetas incluye todos sus niveles
+        assert_eq!(stats.size_freed, 175);
+        assert_eq!(fs::read_dir(&root).unwrap().count(), 0);
+
+        // Una carpeta que no se puede leer no detiene la limpieza
+        let missing = clean_location(&mut app, &active, TempLocation::User, root.join("missing"));
+        assert_eq!(missing.deleted_count, 0);
+        assert_eq!(
+            app.operation_logs.last().unwrap().level,
+            crate::LogLevel::Warning
+        );
+
+        fs::remove_dir_all(&root).unwrap();
+    }
+
+    #[test]
+    fn test_clean_skips_paths_used_by_processes() {
+        let root = std::env::temp_dir().join("win_opt_test_cleanup_in_use");
+        let _ = fs::remove_dir_all(&root);
+        fs::create_dir_all(root.join("7zS1A2B")).unwrap();
+        fs::write(root.join("7zS1A2B").join("setup.exe"), b"MZ").unwrap();
+        fs::write(root.join("old.tmp"), b"x").unwrap();
+
+        // Un instalador que se ejecuta desde su carpeta temporal
+        let setup = fs::canonicalize(root.join("7zS1A2B").join("setup.exe")).unwrap();
+        let active = ActivePaths(vec![setup]);
+        assert!(active.holds(&root.join("7zS1A2B")));
+        assert!(!active.holds(&root.join("old.tmp")));
+
+        let mut app = crate::app::App::default();
+        let stats = clean_location(&mut app, &active, TempLocation::User, root.clone());
+        assert_eq!(stats.deleted_count, 1);
+        assert_eq!(stats.in_use_count, 1);
+        assert_eq!(stats.failed_count, 0);
+        assert!(root.join("7zS1A2B").join("setup.exe").exists());
+
+        fs::remove_dir_all(&root).unwrap();
+    }
+
+    #[test]
+    fn test_active_paths_include_current_process() {
+        let active = ActivePaths::detect();
+        assert!(active.holds(&std::env::current_dir().unwrap()));
+        assert!(active.holds(&std::env::current_exe().unwrap()));
+    }
+
+    /// Carpeta con un archivo que no debe borrarse al limpiar otra carpeta
+    fn outside_dir(root: &Path) -> PathBuf {
+        let outside = root.join("Users");
+        fs::create_dir_all(&outside).unwrap();
+        fs::write(outside.join("keep.txt"), [0; 1000]).unwrap();
+        outside
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn test_remove_path_removes_only_the_link() {
+        let root = std::env::temp_dir().join("win_opt_test_cleanup_link");
+        let _ = fs::remove_dir_all(&root);
+        let outside = outside_dir(&root);
+        fs::create_dir_all(root.join("Temp")).unwrap();
+        let link = root.join("Temp").join("link");
+        std::os::unix::fs::symlink(&outside, &link).unwrap();
+
+        // Ni siquiera con la Papelera activada se toca el destino
+        let mut config = Config::default();
+        config.cleanup.recycle_bin = true;
+        remove_path(&config, &link).unwrap();
+        assert!(fs::symlink_metadata(&link).is_err());
+        assert!(outside.join("keep.txt").exists());
+
+        fs::remove_dir_all(&root).unwrap();
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn test_clean_does_not_follow_links() {
+        let root = std::env::temp_dir().join("win_opt_test_cleanup_links");
+        let _ = fs::remove_dir_all(&root);
+        let outside = outside_dir(&root);
+        let temp = root.join("Temp");
+        fs::create_dir_all(&temp).unwrap();
+        std::os::unix::fs::symlink(&outside, temp.join("profiles")).unwrap();
+        std::os::unix::fs::symlink(root.join("missing"), temp.join("broken")).unwrap();
+
+        // El directorio de trabajo de un proceso dentro del destino no cuenta
+        let active = ActivePaths(vec![fs::canonicalize(&outside).unwrap()]);
+        let mut app = crate::app::App::default();
+        let stats = clean_location(&mut app, &active, TempLocation::User, temp.clone());
+        assert_eq!(stats.deleted_count, 2);
+        assert_eq!(stats.in_use_count, 0);
+        assert!(stats.size_freed < 1000);
+        assert_eq!(fs::read_dir(&temp).unwrap().count(), 0);
+        assert!(outside.join("keep.txt").exists());
+
+        fs::remove_dir_all(&root).unwrap();
+    }
+
+    #[cfg(windows)]
+    #[test]
+    fn test_remove_path_removes_only_the_junction() {
+        let root = std::env::temp_dir().join("win_opt_test_cleanup_junction");
+        let _ = fs::remove_dir_all(&root);
+        let outside = outside_dir(&root);
+        fs::create_dir_all(root.join("Temp")).unwrap();
+        let junction = root.join("Temp").join("Users");
+        // Las uniones no necesitan privilegios, a diferencia de los enlaces
+        let status = std::process::Command::new("cmd")
+            .arg("/C")
+            .arg("mklink")
+            .arg("/J")
+            .arg(&junction)
+            .arg(&outside)
+            .status()
+            .unwrap();
+        assert!(status.success());
+        assert!(junction.join("keep.txt").exists());
+
+        let mut app = crate::app::App::default();
+        let active = ActivePaths(Vec::new());
+        let stats = clean_location(&mut app, &active, TempLocation::User, root.join("Temp"));
+        assert_eq!(stats.deleted_count, 1);
+        assert!(fs::symlink_metadata(&junction).is_err());
+        assert!(outside.join("keep.txt").exists());
+
+        fs::remove_dir_all(&root).unwrap();
+    }
+}
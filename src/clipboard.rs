@@ -0,0 +1,72 @@
+//! Copia de los logs al portapapeles
+//!
+//! Con `Y` se copia el log mostrado en el panel (o solo las coincidencias de
+//! la búsqueda, si hay una activa) para pegarlo en un ticket de soporte. En
+//! plataformas distintas de Windows el portapapeles no está disponible.
+
+use crate::error::Result;
+use crate::logger::LogEntry;
+
+/// Texto de los logs, una entrada por línea con su hora y nivel
+pub fn format_logs<'a>(logs: impl IntoIterator<Item = &'a LogEntry>) -> String {
+    logs.into_iter()
+        .map(|entry| {
+            format!(
+                "{} {:<5} {}",
+                entry.time(),
+                entry.level.name(),
+                entry.message
+            )
+        })
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+/// Copia un texto al portapapeles del sistema
+///
+/// # Errores
+///
+/// Retorna `WinOptError::Clipboard` si no se puede abrir el portapapeles
+/// (por ejemplo, si otra aplicación lo tiene bloqueado).
+#[cfg(windows)]
+pub fn copy_text(text: &str) -> Result<()> {
+    use crate::error::WinOptError;
+
+    arboard::Clipboard::new()
+        .and_then(|mut clipboard| clipboard.set_text(text))
+        .map_err(|e| WinOptError::Clipboard(e.to_string()))
+}
+
+#[cfg(not(windows))]
+pub fn copy_text(_text: &str) -> Result<()> {
+    Err(crate::error::WinOptError::Clipboard(
+        "no disponible en esta plataforma".to_string(),
+    ))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::logger::LogLevel;
+
+    #[test]
+    fn test_format_logs_includes_time_and_level() {
+        let logs = [
+            LogEntry {
+                level: LogLevel::Info,
+                timestamp: 0,
+                message: "Limpieza iniciada".to_string(),
+            },
+            LogEntry {
+                level: LogLevel::Error,
+                timestamp: 61,
+                message: "❌ Acceso denegado".to_string(),
+            },
+        ];
+        assert_eq!(
+            format_logs(&logs),
+            "00:00:00 INFO  Limpieza iniciada\n00:01:01 ERROR ❌ Acceso denegado"
+        );
+        assert_eq!(format_logs(&[]), "");
+    }
+}
@@ -1,12 +1,116 @@
 //! Módulo de configuración para win_opt
 //!
 //! Maneja la configuración de la aplicación mediante archivos TOML.
-
+//!
+//! Los datos de la aplicación (configuración, logs, historial...) se guardan
+//! en `%APPDATA%\win_opt`. En modo portable (`--portable` o un archivo
+//! `win_opt.portable` junto al ejecutable) se guardan en una carpeta
+//! `win_opt` junto al ejecutable, para usar la herramienta desde un USB.
+
+use crate::cleanup::CleanupConfig;
+use crate::daemon::DaemonConfig;
+use crate::downloads::DownloadsConfig;
+use crate::executor::ExecutorConfig;
+use crate::hooks::HooksConfig;
 use crate::i18n::Language;
-use crate::theme::Theme;
+use crate::logger::{self, LogFormat};
+use crate::profiles::Profile;
+use crate::quarantine::QuarantineConfig;
+use crate::reboot::RebootConfig;
+use crate::simulate;
+use crate::symbols::SymbolMode;
+use crate::theme::{ColorMode, Theme, ThemeConfig};
+use crate::update::UpdateConfig;
 use serde::{Deserialize, Serialize};
 use std::fs;
-use std::path::PathBuf;
+use std::path::{Path, PathBuf};
+use std::sync::OnceLock;
+use std::sync::atomic::{AtomicBool, Ordering};
+
+/// Archivo que, junto al ejecutable, activa el modo portable
+pub const PORTABLE_MARKER: &str = "win_opt.portable";
+
+/// Modo portable activado con `--portable`
+static PORTABLE: AtomicBool = AtomicBool::new(false);
+
+/// Activa el modo portable para el resto de la sesión
+///
+/// Debe llamarse antes de cargar la configuración o inicializar los logs.
+pub fn enable_portable() {
+    PORTABLE.store(true, Ordering::Relaxed);
+}
+
+/// Carpeta del ejecutable si se ejecuta en modo portable
+pub fn portable_dir() -> Option<PathBuf> {
+    let exe = std::env::current_exe().ok()?;
+    portable_dir_for(exe.parent()?, PORTABLE.load(Ordering::Relaxed))
+}
+
+/// Carpeta base en modo portable: forzado o con el marcador presente
+fn portable_dir_for(exe_dir: &Path, forced: bool) -> Option<PathBuf> {
+    (forced || exe_dir.join(PORTABLE_MARKER).is_file()).then(|| exe_dir.to_path_buf())
+}
+
+/// Archivo de configuración indicado con `--config`
+static CONFIG_FILE: OnceLock<PathBuf> = OnceLock::new();
+
+/// Valores de la línea de comandos para esta sesión
+static OVERRIDES: OnceLock<Overrides> = OnceLock::new();
+
+/// Usa otro archivo de configuración durante la sesión (`--config`)
+///
+/// Debe llamarse antes de cargar la configuración.
+pub fn set_config_file(path: PathBuf) {
+    let path = std::path::absolute(&path).unwrap_or(path);
+    let _ = CONFIG_FILE.set(path);
+}
+
+/// Sustituye valores de la configuración durante la sesión
+///
+/// Se aplican cada vez que se carga el archivo pero no se guardan en él.
+/// Debe llamarse antes de cargar la configuración.
+pub fn set_overrides(overrides: Overrides) {
+    let _ = OVERRIDES.set(overrides);
+}
+
+/// Valores de la línea de comandos que sustituyen a los del archivo
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Overrides {
+    /// `--theme`
+    pub theme: Option<Theme>,
+    /// `--lang`
+    pub language: Option<Language>,
+    /// `--log-level`
+    pub log_level: Option<String>,
+}
+
+impl Overrides {
+    /// Aplica los valores indicados a una configuración
+    fn apply(&self, config: &mut Config) {
+        if let Some(theme) = self.theme {
+            config.appearance.theme = theme;
+        }
+        if let Some(language) = self.language {
+            config.language.language = language;
+        }
+        if let Some(level) = &self.log_level {
+            config.logging.level = level.clone();
+        }
+    }
+
+    /// Devuelve a sus valores del archivo los campos sustituidos
+    fn restore(&self, config: &mut Config, file: &Config) {
+        if self.theme.is_some() {
+            config.appearance.theme = file.appearance.theme;
+        }
+        if self.language.is_some() {
+            config.language.language = file.language.language;
+        }
+        if self.log_level.is_some() {
+            config.logging.level = file.logging.level.clone();
+        }
+    }
+}
 
 /// Configuración de la aplicación
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -19,16 +123,81 @@ pub struct Config {
 
     /// Configuración de logging
     pub logging: LoggingConfig,
+
+    /// Configuración de perfiles de optimización
+    #[serde(default)]
+    pub profiles: ProfilesConfig,
+
+    /// Configuración del modo daemon
+    #[serde(default)]
+    pub daemon: DaemonConfig,
+
+    /// Configuración de notificaciones
+    #[serde(default)]
+    pub notifications: NotificationsConfig,
+
+    /// Confirmación de las acciones destructivas
+    #[serde(default)]
+    pub confirmations: ConfirmationsConfig,
+
+    /// Configuración del asistente de Descargas
+    #[serde(default)]
+    pub downloads: DownloadsConfig,
+
+    /// Configuración de las limpiezas
+    #[serde(default)]
+    pub cleanup: CleanupConfig,
+
+    /// Configuración del modo cuarentena
+    #[serde(default)]
+    pub quarantine: QuarantineConfig,
+
+    /// Tiempos límite de los comandos externos
+    #[serde(default)]
+    pub executor: ExecutorConfig,
+
+    /// Reinicio programado tras las operaciones que lo necesitan
+    #[serde(default)]
+    pub reboot: RebootConfig,
+
+    /// Comandos a ejecutar antes y después de las operaciones
+    #[serde(default)]
+    pub hooks: HooksConfig,
+
+    /// Colores del tema personalizado
+    #[serde(default)]
+    pub theme: ThemeConfig,
+
+    /// Comprobación de versiones nuevas
+    #[serde(default)]
+    pub update: UpdateConfig,
 }
 
 /// Configuración de apariencia
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct AppearanceConfig {
-    /// Tema de la aplicación (Light o Dark)
+    /// Tema de la aplicación (Light, Dark, Solarized, Nord, HighContrast o Custom)
     pub theme: Theme,
 
     /// Recordar último tema usado
     pub remember_theme: bool,
+
+    /// Mostrar la hora de cada línea en los logs de las operaciones
+    #[serde(default)]
+    pub show_log_timestamps: bool,
+
+    /// Colores del terminal (Auto, TrueColor, Ansi256 o Ansi16)
+    #[serde(default)]
+    pub color_mode: ColorMode,
+
+    /// Símbolos de la interfaz (Auto, Unicode o Ascii)
+    #[serde(default)]
+    pub symbols: SymbolMode,
+
+    /// Impedir abrir las opciones que requieren administrador cuando la
+    /// aplicación no se ejecuta con permisos elevados
+    #[serde(default)]
+    pub lock_admin_items: bool,
 }
 
 /// Configuración de idioma
@@ -52,6 +221,44 @@ pub struct LoggingConfig {
 
     /// Mantener logs por N días
     pub retention_days: u32,
+
+    /// Formato del archivo de log (Text o Json)
+    #[serde(default)]
+    pub format: LogFormat,
+}
+
+/// Configuración de perfiles de optimización
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct ProfilesConfig {
+    /// Perfil preseleccionado al abrir la vista de perfiles
+    pub default_profile: Option<Profile>,
+}
+
+/// Configuración de notificaciones del sistema
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct NotificationsConfig {
+    /// Notificar al terminar operaciones largas (DISM, SFC)
+    pub enabled: bool,
+}
+
+impl Default for NotificationsConfig {
+    fn default() -> Self {
+        Self { enabled: true }
+    }
+}
+
+/// Configuración de las confirmaciones
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ConfirmationsConfig {
+    /// Pedir una segunda pulsación antes de borrar archivos, redes Wi-Fi o
+    /// terminar procesos
+    pub enabled: bool,
+}
+
+impl Default for ConfirmationsConfig {
+    fn default() -> Self {
+        Self { enabled: true }
+    }
 }
 
 impl Default for Config {
@@ -60,6 +267,10 @@ impl Default for Config {
             appearance: AppearanceConfig {
                 theme: Theme::Dark,
                 remember_theme: true,
+                show_log_timestamps: false,
+                color_mode: ColorMode::Auto,
+                symbols: SymbolMode::Auto,
+                lock_admin_items: false,
             },
             language: LanguageConfig {
                 language: Language::Spanish,
@@ -69,21 +280,55 @@ impl Default for Config {
                 level: "info".to_string(),
                 file_logging: true,
                 retention_days: 7,
+                format: LogFormat::Text,
             },
+            profiles: ProfilesConfig::default(),
+            daemon: DaemonConfig::default(),
+            notifications: NotificationsConfig::default(),
+            confirmations: ConfirmationsConfig::default(),
+            downloads: DownloadsConfig::default(),
+            cleanup: CleanupConfig::default(),
+            quarantine: QuarantineConfig::default(),
+            executor: ExecutorConfig::default(),
+            reboot: RebootConfig::default(),
+            hooks: HooksConfig::default(),
+            theme: ThemeConfig::default(),
+            update: UpdateConfig::default(),
         }
     }
 }
 
+/// Carpeta base de la configuración: `%APPDATA%`
+#[cfg(windows)]
+fn default_config_base() -> PathBuf {
+    let app_data = std::env::var("APPDATA")
+        .or_else(|_| std::env::var("USERPROFILE").map(|p| format!("{p}\\AppData\\Roaming")))
+        .unwrap_or_else(|_| "C:\\ProgramData".to_string());
+    PathBuf::from(app_data)
+}
+
+/// Carpeta base de la configuración fuera de Windows
+#[cfg(not(windows))]
+fn default_config_base() -> PathBuf {
+    directories::BaseDirs::new()
+        .map(|dirs| dirs.config_dir().to_path_buf())
+        .unwrap_or_else(std::env::temp_dir)
+}
+
 impl Config {
     /// Obtiene el directorio de configuración de la aplicación
     ///
-    /// En Windows: %APPDATA%\win_opt
-    fn get_config_dir() -> std::io::Result<PathBuf> {
-        let app_data = std::env::var("APPDATA")
-            .or_else(|_| std::env::var("USERPROFILE").map(|p| format!("{p}\\AppData\\Roaming")))
-            .unwrap_or_else(|_| "C:\\ProgramData".to_string());
-
-        let config_dir = PathBuf::from(app_data).join("win_opt");
+    /// En Windows: %APPDATA%\win_opt, o `<carpeta del exe>\win_opt` en modo
+    /// portable. En otras plataformas (desarrollo), la carpeta de
+    /// configuración del usuario, como `~/.config/win_opt`. En modo
+    /// simulación, la carpeta `AppData` del sandbox
+    pub(crate) fn get_config_dir() -> std::io::Result<PathBuf> {
+        let base = simulate::sandbox()
+            .map(|root| root.join("AppData"))
+            .or_else(portable_dir)
+            .unwrap_or_else(default_config_base);
+
+        let config_dir = base.join("win_opt");
 
         // Crear directorio si no existe
         if !config_dir.exists() {
@@ -94,7 +339,12 @@ impl Config {
     }
 
     /// Obtiene la ruta del archivo de configuración
-    fn get_config_file() -> std::io::Result<PathBuf> {
+    pub(crate) fn get_config_file() -> std::io::Result<PathBuf> {
+        if let Some(path) = CONFIG_FILE.get()
+            && !simulate::is_enabled()
+        {
+            return Ok(path.clone());
+        }
         let config_dir = Self::get_config_dir()?;
         Ok(config_dir.join("config.toml"))
     }
@@ -104,8 +354,11 @@ impl Config {
     /// Si el archivo no existe, retorna la configuración por defecto.
     pub fn load() -> Self {
         match Self::load_from_file() {
-            Ok(config) => {
+            Ok((config, warnings)) => {
                 tracing::info!("Configuración cargada desde archivo");
+                for warning in &warnings {
+                    tracing::warn!("config.toml: {}", warning);
+                }
                 config
             }
             Err(e) => {
@@ -118,8 +371,64 @@ impl Config {
         }
     }
 
+    /// Interpreta el contenido de `config.toml` y valida sus valores
+    ///
+    /// Devuelve la configuración con los avisos encontrados: claves
+    /// desconocidas y valores fuera de los admitidos. Un error de sintaxis o
+    /// de tipo hace fallar la carga.
+    pub fn parse(contents: &str) -> Result<(Self, Vec<String>), String> {
+        let mut warnings = Vec::new();
+        let config: Config =
+            serde_ignored::deserialize(toml::Deserializer::new(contents), |path| {
+                warnings.push(format!("clave desconocida `{}`", path));
+            })
+            .map_err(|e| e.to_string())?;
+        warnings.extend(config.validate());
+        Ok((config, warnings))
+    }
+
+    /// Comprueba los valores que el formato TOML no puede restringir
+    fn validate(&self) -> Vec<String> {
+        let mut warnings = Vec::new();
+        if !logger::is_valid_level(&self.logging.level) {
+            warnings.push(format!(
+                "nivel de log no válido en `logging.level`: \"{}\"",
+                self.logging.level
+            ));
+        }
+        for (key, value) in self.theme.custom.invalid_colors() {
+            warnings.push(format!(
+                "color no válido en `theme.custom.{}`: \"{}\" (se esperaba #RRGGBB)",
+                key, value
+            ));
+        }
+        for key in self.hooks.unknown_keys() {
+            warnings.push(format!(
+                "hook desconocido `hooks.{}` (se esperaba pre_<operación> o post_<operación>)",
+                key
+            ));
+        }
+        warnings
+    }
+
     /// Intenta cargar la configuración desde el archivo
-    fn load_from_file() -> std::io::Result<Self> {
+    ///
+    /// Se aplican los valores indicados en la línea de comandos.
+    ///
+    /// # Errores
+    ///
+    /// Retorna `NotFound` si no existe el archivo e `InvalidData` si no es
+    /// una configuración válida.
+    pub fn load_from_file() -> std::io::Result<(Self, Vec<String>)> {
+        let (mut config, warnings) = Self::read_file()?;
+        if let Some(overrides) = OVERRIDES.get() {
+            overrides.apply(&mut config);
+        }
+        Ok((config, warnings))
+    }
+
+    /// Lee el archivo de configuración tal cual está guardado
+    fn read_file() -> std::io::Result<(Self, Vec<String>)> {
         let config_file = Self::get_config_file()?;
 
         if !config_file.exists() {
@@ -130,21 +439,30 @@ impl Config {
         }
 
         let contents = fs::read_to_string(&config_file)?;
-        let config: Config = toml::from_str(&contents).map_err(|e| {
+        Self::parse(&contents).map_err(|e| {
             std::io::Error::new(
                 std::io::ErrorKind::InvalidData,
                 format!("TOML parse error: {}", e),
             )
-        })?;
-
-        Ok(config)
+        })
     }
 
     /// Guarda la configuración en el archivo
+    ///
+    /// Los valores sustituidos desde la línea de comandos conservan los que
+    /// tenía el archivo.
     pub fn save(&self) -> std::io::Result<()> {
         let config_file = Self::get_config_file()?;
 
-        let toml_string = toml::to_string_pretty(self).map_err(|e| {
+        let mut to_save = self.clone();
+        if let Some(overrides) = OVERRIDES.get() {
+            let file = Self::read_file()
+                .map(|(config, _)| config)
+                .unwrap_or_default();
+            overrides.restore(&mut to_save, &file);
+        }
+
+        let toml_string = toml::to_string_pretty(&to_save).map_err(|e| {
             std::io::Error::new(
                 std::io::ErrorKind::InvalidData,
                 format!("TOML serialize error: {}", e),
@@ -198,6 +516,7 @@ impl Config {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::daemon::DaemonCleanup;
 
     #[test]
     fn test_default_config() {
@@ -206,6 +525,115 @@ mod tests {
         assert_eq!(config.language.language, Language::Spanish);
         assert!(config.appearance.remember_theme);
         assert!(config.logging.file_logging);
+        assert!(config.notifications.enabled);
+    }
+
+    #[test]
+    fn test_parse_reports_unknown_keys_and_invalid_values() {
+        let toml_str = r#"
+            [appearance]
+            theme = "Light"
+            remember_theme = false
+            colour = "red"
+
+            [language]
+            language = "English"
+            remember_language = true
+
+            [logging]
+            level = "verbose"
+            file_logging = true
+            retention_days = 7
+
+            [hooks]
+            pre_clean = "echo antes"
+            pre_cleanup = "echo antes"
+        "#;
+
+        let (config, warnings) = Config::parse(toml_str).unwrap();
+        assert_eq!(config.appearance.theme, Theme::Light);
+        assert_eq!(warnings.len(), 3);
+        assert!(warnings[0].contains("appearance.colour"));
+        assert!(warnings[1].contains("verbose"));
+        assert!(warnings[2].contains("hooks.pre_cleanup"));
+
+        let (_, warnings) = Config::parse(&toml::to_string(&Config::default()).unwrap()).unwrap();
+        assert!(warnings.is_empty());
+    }
+
+    #[test]
+    fn test_parse_custom_theme() {
+        let toml_str = r##"
+            [appearance]
+            theme = "Custom"
+            remember_theme = true
+
+            [language]
+            language = "Spanish"
+            remember_language = true
+
+            [logging]
+            level = "info"
+            file_logging = true
+            retention_days = 7
+
+            [theme.custom]
+            bg_main = "#002b36"
+            text_primary = "claro"
+        "##;
+
+        let (config, warnings) = Config::parse(toml_str).unwrap();
+        assert_eq!(config.theme(), Theme::Custom);
+        assert_eq!(config.theme.custom.bg_main.as_deref(), Some("#002b36"));
+        assert_eq!(warnings.len(), 1);
+        assert!(warnings[0].contains("theme.custom.text_primary"));
+    }
+
+    #[test]
+    fn test_parse_rejects_invalid_types() {
+        let toml_str = toml::to_string(&Config::default())
+            .unwrap()
+            .replace("retention_days = 7", "retention_days = \"siete\"");
+        let error = Config::parse(&toml_str).unwrap_err();
+        assert!(error.contains("retention_days"));
+    }
+
+    #[test]
+    fn test_portable_dir_uses_flag_or_marker() {
+        let exe_dir = std::env::temp_dir().join("win_opt_test_portable");
+        fs::create_dir_all(&exe_dir).unwrap();
+        let _ = fs::remove_file(exe_dir.join(PORTABLE_MARKER));
+
+        assert_eq!(portable_dir_for(&exe_dir, false), None);
+        assert_eq!(portable_dir_for(&exe_dir, true), Some(exe_dir.clone()));
+
+        fs::write(exe_dir.join(PORTABLE_MARKER), "").unwrap();
+        assert_eq!(portable_dir_for(&exe_dir, false), Some(exe_dir.clone()));
+
+        fs::remove_dir_all(&exe_dir).unwrap();
+    }
+
+    #[test]
+    fn test_overrides_apply_and_restore() {
+        let overrides = Overrides {
+            theme: Some(Theme::Light),
+            log_level: Some("debug".to_string()),
+            ..Overrides::default()
+        };
+        let file = Config::default();
+
+        let mut config = file.clone();
+        config.language.language = Language::English;
+        overrides.apply(&mut config);
+        assert_eq!(config.appearance.theme, Theme::Light);
+        assert_eq!(config.logging.level, "debug");
+        assert_eq!(config.language.language, Language::English);
+
+        // Solo vuelven al valor del archivo los campos sustituidos
+        overrides.restore(&mut config, &file);
+        assert_eq!(config.appearance.theme, Theme::Dark);
+        assert_eq!(config.logging.level, "info");
+        assert_eq!(config.language.language, Language::English);
     }
 
     #[test]
@@ -239,6 +667,152 @@ mod tests {
         assert_eq!(config.language.language, Language::English);
         assert_eq!(config.logging.level, "debug");
         assert_eq!(config.logging.retention_days, 30);
+        assert_eq!(config.logging.format, LogFormat::Text);
+        assert_eq!(config.profiles.default_profile, None);
+    }
+
+    #[test]
+    fn test_config_default_profile() {
+        let toml_str = r#"
+            [appearance]
+            theme = "Dark"
+            remember_theme = true
+
+            [language]
+            language = "Spanish"
+            remember_language = true
+
+            [logging]
+            level = "info"
+            file_logging = true
+            retention_days = 7
+
+            [profiles]
+            default_profile = "Battery"
+        "#;
+
+        let config: Config = toml::from_str(toml_str).unwrap();
+        assert_eq!(config.profiles.default_profile, Some(Profile::Battery));
+    }
+
+    #[test]
+    fn test_config_daemon_section() {
+        let toml_str = r#"
+            [appearance]
+            theme = "Dark"
+            remember_theme = true
+
+            [language]
+            language = "Spanish"
+            remember_language = true
+
+            [logging]
+            level = "info"
+            file_logging = true
+            retention_days = 7
+
+            [daemon]
+            interval_minutes = 60
+            cleanups = ["TempFiles", "BrowserCache"]
+            notify = false
+            notify_threshold_mb = 500
+        "#;
+
+        let config: Config = toml::from_str(toml_str).unwrap();
+        assert_eq!(config.daemon.interval_minutes, 60);
+        assert_eq!(
+            config.daemon.cleanups,
+            vec![DaemonCleanup::TempFiles, DaemonCleanup::BrowserCache]
+        );
+        assert!(!config.daemon.notify);
+        assert_eq!(config.daemon.notify_threshold_mb, 500);
+    }
+
+    #[test]
+    fn test_config_downloads_section() {
+        let toml_str = r#"
+            [appearance]
+            theme = "Dark"
+            remember_theme = true
+
+            [language]
+            language = "Spanish"
+            remember_language = true
+
+            [logging]
+            level = "info"
+            file_logging = true
+            retention_days = 7
+
+            [downloads]
+            threshold_days = 180
+            archive_folder = "D:\\Archivo"
+        "#;
+
+        let config: Config = toml::from_str(toml_str).unwrap();
+        assert_eq!(config.downloads.threshold_days, 180);
+        assert_eq!(
+            config.downloads.archive_folder,
+            Some(PathBuf::from("D:\\Archivo"))
+        );
+        assert_eq!(Config::default().downloads.archive_folder, None);
+    }
+
+    #[test]
+    fn test_config_cleanup_section() {
+        assert!(!Config::default().cleanup.recycle_bin);
+        assert!(!Config::default().cleanup.prefetch);
+
+        let toml_str = r#"
+            [appearance]
+            theme = "Dark"
+            remember_theme = true
+
+            [language]
+            language = "Spanish"
+            remember_language = true
+
+            [logging]
+            level = "info"
+            file_logging = true
+            retention_days = 7
+
+            [cleanup]
+            recycle_bin = true
+        "#;
+
+        let config: Config = toml::from_str(toml_str).unwrap();
+        assert!(config.cleanup.recycle_bin);
+        // Los archivos de configuración anteriores no tienen `prefetch`
+        assert!(!config.cleanup.prefetch);
+    }
+
+    #[test]
+    fn test_config_quarantine_section() {
+        assert!(!Config::default().quarantine.enabled);
+
+        let toml_str = r#"
+            [appearance]
+            theme = "Dark"
+            remember_theme = true
+
+            [language]
+            language = "Spanish"
+            remember_language = true
+
+            [logging]
+            level = "info"
+            file_logging = true
+            retention_days = 7
+
+            [quarantine]
+            enabled = true
+            retention_days = 14
+        "#;
+
+        let config: Config = toml::from_str(toml_str).unwrap();
+        assert!(config.quarantine.enabled);
+        assert_eq!(config.quarantine.retention_days, 14);
     }
 
     #[test]
@@ -0,0 +1,57 @@
+//! Recarga de la configuración en caliente
+//!
+//! Vigila la carpeta de `config.toml` y avisa cuando el archivo cambia, de
+//! modo que la aplicación pueda volver a leerlo sin reiniciarse. Se vigila
+//! la carpeta y no el archivo porque muchos editores guardan escribiendo un
+//! archivo nuevo y renombrándolo.
+
+use crate::config::Config;
+use notify::{Event, RecommendedWatcher, RecursiveMode, Watcher};
+use std::path::PathBuf;
+use std::sync::mpsc::{self, Receiver};
+
+/// Vigilante del archivo de configuración
+pub struct ConfigWatcher {
+    /// Se mantiene vivo mientras se quieran recibir cambios
+    _watcher: RecommendedWatcher,
+    changes: Receiver<()>,
+}
+
+impl ConfigWatcher {
+    /// Empieza a vigilar `config.toml`
+    ///
+    /// # Errores
+    ///
+    /// Retorna error si no se puede obtener la carpeta de configuración o el
+    /// sistema no permite vigilarla.
+    pub fn start() -> notify::Result<Self> {
+        let path = Config::get_config_file()?;
+        let dir = path
+            .parent()
+            .map(PathBuf::from)
+            .unwrap_or_else(|| PathBuf::from("."));
+
+        let (sender, changes) = mpsc::channel();
+        let mut watcher = notify::recommended_watcher(move |event: notify::Result<Event>| {
+            if let Ok(event) = event
+                && (event.kind.is_modify() || event.kind.is_create())
+                && event.paths.iter().any(|changed| changed == &path)
+            {
+                let _ = sender.send(());
+            }
+        })?;
+        watcher.watch(&dir, RecursiveMode::NonRecursive)?;
+
+        Ok(Self {
+            _watcher: watcher,
+            changes,
+        })
+    }
+
+    /// Indica si el archivo cambió desde la última consulta
+    ///
+    /// Un guardado suele generar varios eventos; se agrupan en uno solo.
+    pub fn changed(&self) -> bool {
+        self.changes.try_iter().count() > 0
+    }
+}
@@ -0,0 +1,180 @@
+//! Modo daemon (`--daemon`)
+//!
+//! Ejecuta las limpiezas configuradas en `[daemon]` de forma periódica sin
+//! interfaz TUI. Toda la actividad se registra en el archivo de log y,
+//! opcionalmente, se muestra una notificación cuando el espacio recuperado
+//! supera un umbral.
+
+use crate::app::App;
+use crate::cleanup;
+use crate::notify;
+use crate::types::View;
+use crate::utils::format_bytes;
+use serde::{Deserialize, Serialize};
+use std::thread;
+use std::time::Duration;
+use sysinfo::Disks;
+
+/// Limpiezas que puede ejecutar el daemon
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum DaemonCleanup {
+    /// Archivos temporales del usuario
+    TempFiles,
+    /// Papelera de reciclaje
+    RecycleBin,
+    /// Caché de navegadores
+    BrowserCache,
+    /// Logs del sistema (requiere administrador)
+    SystemLogs,
+}
+
+impl DaemonCleanup {
+    /// Ejecuta la limpieza (con sus hooks) sobre la aplicación indicada
+    fn execute(self, app: &mut App) {
+        match self {
+            DaemonCleanup::TempFiles => app.run_operation(View::Clean, cleanup::execute_clean),
+            DaemonCleanup::RecycleBin => {
+                app.run_operation(View::RecycleBin, cleanup::execute_recycle_bin)
+            }
+            DaemonCleanup::BrowserCache => {
+                app.run_operation(View::BrowserCache, cleanup::execute_browser_cache)
+            }
+            DaemonCleanup::SystemLogs => {
+                app.run_operation(View::SystemLogs, cleanup::execute_system_logs)
+            }
+        }
+    }
+}
+
+/// Configuración del modo daemon
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct DaemonConfig {
+    /// Minutos entre ejecuciones
+    pub interval_minutes: u64,
+
+    /// Limpiezas a ejecutar en cada ciclo
+    pub cleanups: Vec<DaemonCleanup>,
+
+    /// Mostrar notificación cuando se recupere espacio
+    pub notify: bool,
+
+    /// Espacio mínimo recuperado (en MB) para mostrar la notificación
+    pub notify_threshold_mb: u64,
+}
+
+impl Default for DaemonConfig {
+    fn default() -> Self {
+        Self {
+            interval_minutes: 360,
+            cleanups: vec![DaemonCleanup::TempFiles, DaemonCleanup::RecycleBin],
+            notify: true,
+            notify_threshold_mb: 100,
+        }
+    }
+}
+
+impl DaemonConfig {
+    /// Indica si el espacio recuperado justifica una notificación
+    fn should_notify(&self, reclaimed_bytes: u64) -> bool {
+        self.notify && reclaimed_bytes > self.notify_threshold_mb * 1024 * 1024
+    }
+}
+
+/// Suma del espacio libre de todos los discos
+fn total_free_space() -> u64 {
+    Disks::new_with_refreshed_list()
+        .list()
+        .iter()
+        .map(|disk| disk.available_space())
+        .sum()
+}
+
+/// Ejecuta un ciclo de limpieza y devuelve los bytes recuperados
+///
+/// El espacio recuperado se calcula como la diferencia de espacio libre en
+/// disco, de modo que incluye también las limpiezas que no llevan cuenta de
+/// los bytes eliminados (papelera, navegadores).
+fn run_cycle(app: &mut App, config: &DaemonConfig) -> u64 {
+    let free_before = total_free_space();
+
+    for cleanup in &config.cleanups {
+        tracing::info!("Daemon: ejecutando limpieza {:?}", cleanup);
+        app.operation_logs.clear();
+        cleanup.execute(app);
+    }
+    app.operation_logs.clear();
+
+    total_free_space().saturating_sub(free_before)
+}
+
+/// Ejecuta el bucle del daemon hasta que el proceso sea terminado
+pub fn run() -> std::io::Result<()> {
+    let mut app = App::default();
+    let config = app.config.daemon.clone();
+    let interval = Duration::from_secs(config.interval_minutes.max(1) * 60);
+
+    println!(
+        "win_opt en modo daemon: {} limpiezas cada {} minutos (Ctrl+C para salir)",
+        config.cleanups.len(),
+        config.interval_minutes.max(1)
+    );
+    tracing::info!(
+        "Modo daemon iniciado: {:?} cada {} minutos",
+        config.cleanups,
+        config.interval_minutes
+    );
+
+    loop {
+        let reclaimed = run_cycle(&mut app, &config);
+        tracing::info!(
+            "Daemon: ciclo completado, {} recuperados",
+            format_bytes(reclaimed)
+        );
+
+        if config.should_notify(reclaimed)
+            && let Err(e) = notify::show_toast(
+                "win_opt",
+                &format!(
+                    "Limpieza programada: {} recuperados",
+                    format_bytes(reclaimed)
+                ),
+            )
+        {
+            tracing::warn!("No se pudo mostrar la notificación: {}", e);
+        }
+
+        thread::sleep(interval);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_default_daemon_config() {
+        let config = DaemonConfig::default();
+        assert!(config.interval_minutes > 0);
+        assert!(config.cleanups.contains(&DaemonCleanup::TempFiles));
+    }
+
+    #[test]
+    fn test_should_notify_threshold() {
+        let config = DaemonConfig {
+            notify_threshold_mb: 100,
+            ..DaemonConfig::default()
+        };
+        assert!(!config.should_notify(0));
+        assert!(!config.should_notify(100 * 1024 * 1024));
+        assert!(config.should_notify(100 * 1024 * 1024 + 1));
+    }
+
+    #[test]
+    fn test_should_notify_disabled() {
+        let config = DaemonConfig {
+            notify: false,
+            ..DaemonConfig::default()
+        };
+        assert!(!config.should_notify(u64::MAX));
+    }
+}
@@ -0,0 +1,258 @@
+//! Estado de Microsoft Defender
+//!
+//! Muestra la fecha del último análisis rápido, la antigüedad de las firmas y
+//! la protección en tiempo real (`Get-MpComputerStatus`), lista las
+//! exclusiones configuradas (`Get-MpPreference`) para poder revisarlas y
+//! permite lanzar un análisis rápido (`Start-MpScan -ScanType QuickScan`).
+
+use crate::executor::spawn_command_worker;
+use crate::i18n::I18nKey;
+use crate::platform;
+use crate::types::{OperationState, View};
+use crate::utils::is_admin;
+use crate::{log_error, log_info, log_warn};
+
+/// Antigüedad (en días) a partir de la cual las firmas se consideran desactualizadas
+pub const SIGNATURE_MAX_AGE_DAYS: u32 = 7;
+
+/// Script de PowerShell que imprime el estado en líneas `CLAVE|valor`
+///
+/// La fecha se formatea explícitamente para no depender de la configuración
+/// regional del sistema.
+const STATUS_SCRIPT: &str = "$s = Get-MpComputerStatus; \
+     if ($s.QuickScanEndTime) { 'QUICK|' + $s.QuickScanEndTime.ToString('yyyy-MM-dd HH:mm') }; \
+     'SIGAGE|' + $s.AntivirusSignatureAge; \
+     'RTP|' + $s.RealTimeProtectionEnabled; \
+     $p = Get-MpPreference; \
+     foreach ($e in $p.ExclusionPath) { 'PATH|' + $e }; \
+     foreach ($e in $p.ExclusionExtension) { 'EXT|' + $e }; \
+     foreach ($e in $p.ExclusionProcess) { 'PROC|' + $e }";
+
+/// Tipo de exclusión de Defender
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ExclusionKind {
+    Path,
+    Extension,
+    Process,
+}
+
+impl ExclusionKind {
+    /// Clave de traducción del tipo
+    pub fn label_key(self) -> I18nKey {
+        match self {
+            ExclusionKind::Path => I18nKey::DefenderExclusionPath,
+            ExclusionKind::Extension => I18nKey::DefenderExclusionExtension,
+            ExclusionKind::Process => I18nKey::DefenderExclusionProcess,
+        }
+    }
+}
+
+/// Exclusión configurada en Defender
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Exclusion {
+    pub kind: ExclusionKind,
+    pub value: String,
+}
+
+/// Estado de Microsoft Defender
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct DefenderStatus {
+    /// Fin del último análisis rápido (`yyyy-MM-dd HH:mm`)
+    pub last_quick_scan: Option<String>,
+    /// Antigüedad de las firmas del antivirus en días
+    pub signature_age_days: Option<u32>,
+    /// Protección en tiempo real activada
+    pub realtime_protection: Option<bool>,
+    /// Exclusiones configuradas
+    pub exclusions: Vec<Exclusion>,
+    /// Windows oculta las exclusiones a los usuarios sin privilegios
+    pub exclusions_hidden: bool,
+}
+
+impl DefenderStatus {
+    /// Indica si las firmas son más antiguas que `SIGNATURE_MAX_AGE_DAYS`
+    pub fn signatures_outdated(&self) -> bool {
+        self.signature_age_days
+            .is_some_and(|age| age > SIGNATURE_MAX_AGE_DAYS)
+    }
+}
+
+/// Consulta el estado de Defender y sus exclusiones
+pub fn execute_defender_status(app: &mut crate::app::App) {
+    app.operation_state = OperationState::Running;
+    app.defender_status = None;
+    log_info!(app, "🔒 Consultando el estado de Microsoft Defender...");
+
+    let result = platform::command("powershell")
+        .args(["-NoProfile", "-Command", STATUS_SCRIPT])
+        .output();
+
+    let status = match result {
+        Ok(output) if output.status.success() => {
+            parse_status_output(&String::from_utf8_lossy(&output.stdout))
+        }
+        Ok(_) => {
+            log_error!(
+                app,
+                "❌ No se pudo consultar Defender (¿está desactivado o lo gestiona otro antivirus?)"
+            );
+            app.operation_state = OperationState::Failed;
+            return;
+        }
+        Err(e) => {
+            log_error!(app, "❌ Error: {}", e);
+            app.operation_state = OperationState::Failed;
+            return;
+        }
+    };
+
+    if status.signatures_outdated() {
+        let age = status.signature_age_days.unwrap_or_default();
+        log_warn!(
+            app,
+            "⚠️  Las firmas tienen {} días: actualízalas desde Windows Update",
+            age
+        );
+    }
+    if status.realtime_protection == Some(false) {
+        log_warn!(app, "⚠️  La protección en tiempo real está desactivada");
+    }
+    if status.exclusions_hidden {
+        log_info!(
+            app,
+            "ℹ️  Ejecuta la aplicación como Administrador para ver las exclusiones"
+        );
+    } else {
+        let count = status.exclusions.len();
+        log_info!(app, "✅ Exclusiones configuradas: {}", count);
+    }
+    log_info!(app, "💡 Pulsa S para lanzar un análisis rápido");
+
+    app.defender_status = Some(status);
+    app.operation_state = OperationState::Completed;
+}
+
+/// Lanza un análisis rápido de Defender en segundo plano
+pub fn execute_quick_scan(app: &mut crate::app::App) {
+    if app.jobs.active_for(View::Defender).is_some() {
+        log_warn!(app, "⚠️  Ya hay un análisis en curso");
+        return;
+    }
+
+    log_info!(app, "");
+    if !is_admin() {
+        log_error!(
+            app,
+            "⛔ ERROR: Esta operación requiere permisos de Administrador"
+        );
+        return;
+    }
+
+    log_info!(
+        app,
+        "🔍 El análisis rápido puede tardar varios minutos; pulsa R al terminar para actualizar"
+    );
+    let limits = app.config.executor;
+    let i18n = app.i18n;
+    let description = app.t(I18nKey::DefenderQuickScanTitle).to_string();
+    app.start_job(
+        View::Defender,
+        false,
+        Box::new(move || {
+            spawn_command_worker(
+                "powershell".to_string(),
+                vec![
+                    "-NoProfile".to_string(),
+                    "-Command".to_string(),
+                    "Start-MpScan -ScanType QuickScan".to_string(),
+                ],
+                description,
+                limits,
+                i18n,
+            )
+        }),
+    );
+}
+
+/// Parsea la salida del script de estado
+fn parse_status_output(output: &str) -> DefenderStatus {
+    let mut status = DefenderStatus::default();
+
+    for line in output.lines() {
+        let Some((key, value)) = line.split_once('|') else {
+            continue;
+        };
+        let value = value.trim();
+
+        let kind = match key {
+            "QUICK" => {
+                status.last_quick_scan = Some(value.to_string());
+                continue;
+            }
+            "SIGAGE" => {
+                status.signature_age_days = value.parse().ok();
+                continue;
+            }
+            "RTP" => {
+                status.realtime_protection = match value {
+                    "True" => Some(true),
+                    "False" => Some(false),
+                    _ => None,
+                };
+                continue;
+            }
+            "PATH" => ExclusionKind::Path,
+            "EXT" => ExclusionKind::Extension,
+            "PROC" => ExclusionKind::Process,
+            _ => continue,
+        };
+
+        // Sin privilegios, Windows devuelve un texto "N/A: Must be an administrator..."
+        if value.starts_with("N/A") {
+            status.exclusions_hidden = true;
+        } else if !value.is_empty() {
+            status.exclusions.push(Exclusion {
+                kind,
+                value: value.to_string(),
+            });
+        }
+    }
+
+    status
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_status_output() {
+        let output = "QUICK|2026-10-14 09:30\r\n\
+                      SIGAGE|2\r\n\
+                      RTP|True\r\n\
+                      PATH|C:\\Dev\r\n\
+                      EXT|.iso\r\n\
+                      PROC|cargo.exe\r\n";
+        let status = parse_status_output(output);
+        assert_eq!(status.last_quick_scan.as_deref(), Some("2026-10-14 09:30"));
+        assert_eq!(status.signature_age_days, Some(2));
+        assert_eq!(status.realtime_protection, Some(true));
+        assert_eq!(status.exclusions.len(), 3);
+        assert_eq!(status.exclusions[1].kind, ExclusionKind::Extension);
+        assert_eq!(status.exclusions[2].value, "cargo.exe");
+        assert!(!status.signatures_outdated());
+    }
+
+    #[test]
+    fn test_parse_status_output_without_privileges() {
+        let output = "SIGAGE|12\n\
+                      RTP|False\n\
+                      PATH|N/A: Must be an administrator to view exclusions\n";
+        let status = parse_status_output(output);
+        assert_eq!(status.last_quick_scan, None);
+        assert!(status.signatures_outdated());
+        assert_eq!(status.realtime_protection, Some(false));
+        assert!(status.exclusions.is_empty());
+        assert!(status.exclusions_hidden);
+    }
+}
@@ -0,0 +1,484 @@
+//! Asistente de limpieza de la carpeta Descargas
+//!
+//! Agrupa el contenido de Descargas por antigüedad y por tipo, muestra el
+//! espacio que ocupa cada grupo y permite eliminar o archivar en bloque los
+//! elementos más antiguos que un umbral.
+
+use crate::cleanup;
+use crate::i18n::I18nKey;
+use crate::platform;
+use crate::size_cache::SizeCache;
+use crate::types::OperationState;
+use crate::{log_debug, log_error, log_info, log_warn};
+use serde::{Deserialize, Serialize};
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::time::SystemTime;
+
+/// Umbrales de antigüedad (en días) entre los que se puede alternar
+pub const THRESHOLD_STEPS: &[u64] = &[30, 90, 180, 365];
+
+/// Configuración del asistente de Descargas
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct DownloadsConfig {
+    /// Antigüedad (en días) a partir de la cual se proponen elementos
+    pub threshold_days: u64,
+
+    /// Carpeta de archivo (por defecto `Descargas\Archivo`)
+    pub archive_folder: Option<PathBuf>,
+}
+
+impl Default for DownloadsConfig {
+    fn default() -> Self {
+        Self {
+            threshold_days: 90,
+            archive_folder: None,
+        }
+    }
+}
+
+/// Grupo de antigüedad de un elemento
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum AgeGroup {
+    /// Menos de 30 días
+    Recent,
+    /// Entre 30 y 90 días
+    Months,
+    /// Entre 90 días y un año
+    Quarters,
+    /// Más de un año
+    Years,
+}
+
+impl AgeGroup {
+    /// Todos los grupos en orden de antigüedad
+    pub const ALL: [AgeGroup; 4] = [
+        AgeGroup::Recent,
+        AgeGroup::Months,
+        AgeGroup::Quarters,
+        AgeGroup::Years,
+    ];
+
+    /// Grupo correspondiente a una antigüedad en días
+    pub fn from_days(days: u64) -> Self {
+        match days {
+            0..30 => AgeGroup::Recent,
+            30..90 => AgeGroup::Months,
+            90..365 => AgeGroup::Quarters,
+            _ => AgeGroup::Years,
+        }
+    }
+
+    /// Clave de traducción del grupo
+    pub fn label_key(self) -> I18nKey {
+        match self {
+            AgeGroup::Recent => I18nKey::DownloadsAgeRecent,
+            AgeGroup::Months => I18nKey::DownloadsAgeMonths,
+            AgeGroup::Quarters => I18nKey::DownloadsAgeQuarters,
+            AgeGroup::Years => I18nKey::DownloadsAgeYears,
+        }
+    }
+}
+
+/// Tipo de archivo según su extensión
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum FileKind {
+    Installers,
+    Archives,
+    Documents,
+    Media,
+    Folders,
+    Other,
+}
+
+impl FileKind {
+    /// Todos los tipos en el orden en que se muestran
+    pub const ALL: [FileKind; 6] = [
+        FileKind::Installers,
+        FileKind::Archives,
+        FileKind::Documents,
+        FileKind::Media,
+        FileKind::Folders,
+        FileKind::Other,
+    ];
+
+    /// Clasifica una ruta por su extensión
+    pub fn from_path(path: &Path) -> Self {
+        if path.is_dir() {
+            return FileKind::Folders;
+        }
+        let extension = path
+            .extension()
+            .and_then(|e| e.to_str())
+            .unwrap_or("")
+            .to_ascii_lowercase();
+
+        match extension.as_str() {
+            "exe" | "msi" | "msix" | "appx" | "iso" => FileKind::Installers,
+            "zip" | "rar" | "7z" | "tar" | "gz" | "xz" => FileKind::Archives,
+            "pdf" | "doc" | "docx" | "xls" | "xlsx" | "ppt" | "pptx" | "txt" | "csv" | "odt" => {
+                FileKind::Documents
+            }
+            "jpg" | "jpeg" | "png" | "gif" | "webp" | "mp3" | "wav" | "flac" | "mp4" | "mkv"
+            | "avi" | "mov" => FileKind::Media,
+            _ => FileKind::Other,
+        }
+    }
+
+    /// Clave de traducción del tipo
+    pub fn label_key(self) -> I18nKey {
+        match self {
+            FileKind::Installers => I18nKey::DownloadsKindInstallers,
+            FileKind::Archives => I18nKey::DownloadsKindArchives,
+            FileKind::Documents => I18nKey::DownloadsKindDocuments,
+            FileKind::Media => I18nKey::DownloadsKindMedia,
+            FileKind::Folders => I18nKey::DownloadsKindFolders,
+            FileKind::Other => I18nKey::DownloadsKindOther,
+        }
+    }
+}
+
+/// Elemento de primer nivel de la carpeta Descargas
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DownloadEntry {
+    pub path: PathBuf,
+    /// Tamaño en bytes (recursivo para carpetas)
+    pub size: u64,
+    /// Días desde la última modificación
+    pub age_days: u64,
+    pub kind: FileKind,
+}
+
+/// Acción en bloque pendiente de confirmación
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DownloadsAction {
+    Delete,
+    Archive,
+}
+
+/// Resumen (elementos, bytes) de las entradas que cumplen un filtro
+pub fn summarize<F>(entries: &[DownloadEntry], filter: F) -> (usize, u64)
+where
+    F: Fn(&DownloadEntry) -> bool,
+{
+    entries
+        .iter()
+        .filter(|e| filter(e))
+        .fold((0, 0), |(count, size), e| (count + 1, size + e.size))
+}
+
+/// Siguiente umbral de la lista (cíclico)
+pub fn next_threshold(current: u64) -> u64 {
+    let position = THRESHOLD_STEPS.iter().position(|&t| t == current);
+    match position {
+        Some(i) => THRESHOLD_STEPS[(i + 1) % THRESHOLD_STEPS.len()],
+        None => THRESHOLD_STEPS[0],
+    }
+}
+
+/// Analiza la carpeta Descargas
+pub fn execute_downloads_scan(app: &mut crate::app::App) {
+    app.operation_state = OperationState::Running;
+    app.downloads.clear();
+    app.downloads_confirm = None;
+    log_info!(app, "📥 Analizando la carpeta Descargas...");
+
+    let Some(dir) = platform::downloads_dir() else {
+        log_error!(app, "❌ No se encontró la carpeta Descargas");
+        app.operation_state = OperationState::Failed;
+        return;
+    };
+
+    let archive = archive_dir(app, &dir);
+    let now = SystemTime::now();
+    let mut sizes = SizeCache::load();
+    let entries = match fs::read_dir(&dir) {
+        Ok(entries) => entries,
+        Err(e) => {
+            log_error!(app, "❌ Error leyendo {}: {}", dir.display(), e);
+            app.operation_state = OperationState::Failed;
+            return;
+        }
+    };
+
+    for entry in entries.flatten() {
+        let path = entry.path();
+        // La carpeta de archivo no se propone para limpieza
+        if path == archive {
+            continue;
+        }
+        let Ok(metadata) = entry.metadata() else {
+            continue;
+        };
+
+        let age_days = metadata
+            .modified()
+            .ok()
+            .and_then(|modified| now.duration_since(modified).ok())
+            .map(|age| age.as_secs() / 86_400)
+            .unwrap_or(0);
+        let size = sizes.path_size(&path);
+
+        app.downloads.push(DownloadEntry {
+            kind: FileKind::from_path(&path),
+            path,
+            size,
+            age_days,
+        });
+    }
+
+    sizes.save();
+    let rescanned = sizes.misses();
+    log_debug!(app, "Carpetas analizadas de nuevo: {}", rescanned);
+
+    let threshold = app.downloads_threshold_days;
+    let (total, total_size) = summarize(&app.downloads, |_| true);
+    let (old, old_size) = summarize(&app.downloads, |e| e.age_days >= threshold);
+
+    log_info!(
+        app,
+        "✅ {} elementos en {} ({})",
+        total,
+        dir.display(),
+        app.i18n.format_size(total_size)
+    );
+    log_info!(
+        app,
+        "ℹ️  {} elementos con más de {} días ({})",
+        old,
+        threshold,
+        app.i18n.format_size(old_size)
+    );
+
+    app.operation_state = OperationState::Completed;
+}
+
+/// Solicita una acción en bloque; la segunda pulsación la confirma
+///
+/// Con las confirmaciones desactivadas se ejecuta a la primera.
+pub fn request_action(app: &mut crate::app::App, action: DownloadsAction) {
+    if app.downloads_confirm == Some(action) {
+        app.downloads_confirm = None;
+        execute_action(app, action);
+        return;
+    }
+
+    let threshold = app.downloads_threshold_days;
+    let (count, size) = summarize(&app.downloads, |e| e.age_days >= threshold);
+    if count == 0 {
+        log_info!(app, "ℹ️  No hay elementos con más de {} días", threshold);
+        return;
+    }
+
+    if !app.config.confirmations.enabled {
+        execute_action(app, action);
+        return;
+    }
+
+    app.downloads_confirm = Some(action);
+    let verb = match action {
+        DownloadsAction::Delete => "eliminar",
+        DownloadsAction::Archive => "archivar",
+    };
+    log_warn!(
+        app,
+        "⚠️  Se van a {} {} elementos ({}). Pulsa de nuevo para confirmar",
+        verb,
+        count,
+        app.i18n.format_size(size)
+    );
+}
+
+/// Ejecuta una acción en bloque ya confirmada
+fn execute_action(app: &mut crate::app::App, action: DownloadsAction) {
+    match action {
+        DownloadsAction::Delete => execute_delete_old(app),
+        DownloadsAction::Archive => execute_archive_old(app),
+    }
+}
+
+/// Elimina los elementos más antiguos que el umbral
+fn execute_delete_old(app: &mut crate::app::App) {
+    let threshold = app.downloads_threshold_days;
+    log_info!(app, "");
+    log_info!(
+        app,
+        "🧹 Eliminando elementos con más de {} días...",
+        threshold
+    );
+    cleanup::log_deletion_mode(app);
+
+    let (mut deleted, mut failed, mut freed) = (0, 0, 0u64);
+    for entry in take_old_entries(app) {
+        match cleanup::remove_path(&app.config, &entry.path) {
+            Ok(()) => {
+                deleted += 1;
+                freed += entry.size;
+                log_debug!(app, "Eliminado: {}", entry.path.display());
+            }
+            Err(e) => {
+                failed += 1;
+                log_warn!(app, "⚠️  {}: {}", entry.path.display(), e);
+                app.downloads.push(entry);
+            }
+        }
+    }
+
+    log_info!(
+        app,
+        "✅ {} eliminados, {} omitidos, {} liberados",
+        deleted,
+        failed,
+        app.i18n.format_size(freed)
+    );
+}
+
+/// Mueve los elementos más antiguos que el umbral a la carpeta de archivo
+fn execute_archive_old(app: &mut crate::app::App) {
+    let Some(dir) = platform::downloads_dir() else {
+        return;
+    };
+    let archive = archive_dir(app, &dir);
+    let threshold = app.downloads_threshold_days;
+
+    log_info!(app, "");
+    log_info!(
+        app,
+        "📦 Archivando elementos con más de {} días en {}...",
+        threshold,
+        archive.display()
+    );
+
+    if let Err(e) = fs::create_dir_all(&archive) {
+        log_error!(app, "❌ No se pudo crear {}: {}", archive.display(), e);
+        return;
+    }
+
+    let (mut moved, mut failed) = (0, 0);
+    for entry in take_old_entries(app) {
+        let Some(name) = entry.path.file_name() else {
+            continue;
+        };
+        let target = unique_target(&archive, Path::new(name));
+        match fs::rename(&entry.path, &target) {
+            Ok(()) => {
+                moved += 1;
+                log_debug!(app, "Archivado: {}", target.display());
+            }
+            Err(e) => {
+                failed += 1;
+                log_warn!(app, "⚠️  {}: {}", entry.path.display(), e);
+                app.downloads.push(entry);
+            }
+        }
+    }
+
+    log_info!(app, "✅ {} archivados, {} omitidos", moved, failed);
+}
+
+/// Extrae de la lista las entradas más antiguas que el umbral
+fn take_old_entries(app: &mut crate::app::App) -> Vec<DownloadEntry> {
+    let threshold = app.downloads_threshold_days;
+    let (old, keep) = std::mem::take(&mut app.downloads)
+        .into_iter()
+        .partition(|e| e.age_days >= threshold);
+    app.downloads = keep;
+    old
+}
+
+/// Carpeta de archivo configurada o `Descargas\Archivo`
+fn archive_dir(app: &crate::app::App, downloads: &Path) -> PathBuf {
+    app.config
+        .downloads
+        .archive_folder
+        .clone()
+        .unwrap_or_else(|| downloads.join("Archivo"))
+}
+
+/// Ruta de destino que no sobrescribe un archivo existente (`nombre (1).ext`)
+fn unique_target(dir: &Path, name: &Path) -> PathBuf {
+    let candidate = dir.join(name);
+    if !candidate.exists() {
+        return candidate;
+    }
+
+    let stem = name.file_stem().and_then(|s| s.to_str()).unwrap_or("item");
+    let extension = name.extension().and_then(|e| e.to_str());
+    (1..)
+        .map(|i| match extension {
+            Some(ext) => dir.join(format!("{} ({}).{}", stem, i, ext)),
+            None => dir.join(format!("{} ({})", stem, i)),
+        })
+        .find(|path| !path.exists())
+        .unwrap_or(candidate)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn entry(name: &str, size: u64, age_days: u64) -> DownloadEntry {
+        let path = PathBuf::from(name);
+        DownloadEntry {
+            kind: FileKind::from_path(&path),
+            path,
+            size,
+            age_days,
+        }
+    }
+
+    #[test]
+    fn test_age_group_from_days() {
+        assert_eq!(AgeGroup::from_days(0), AgeGroup::Recent);
+        assert_eq!(AgeGroup::from_days(30), AgeGroup::Months);
+        assert_eq!(AgeGroup::from_days(364), AgeGroup::Quarters);
+        assert_eq!(AgeGroup::from_days(800), AgeGroup::Years);
+    }
+
+    #[test]
+    fn test_file_kind_from_extension() {
+        assert_eq!(
+            FileKind::from_path(Path::new("setup.EXE")),
+            FileKind::Installers
+        );
+        assert_eq!(
+            FileKind::from_path(Path::new("fotos.zip")),
+            FileKind::Archives
+        );
+        assert_eq!(
+            FileKind::from_path(Path::new("factura.pdf")),
+            FileKind::Documents
+        );
+        assert_eq!(FileKind::from_path(Path::new("sin_ext")), FileKind::Other);
+    }
+
+    #[test]
+    fn test_summarize_by_threshold() {
+        let entries = vec![
+            entry("a.exe", 100, 10),
+            entry("b.zip", 200, 100),
+            entry("c.pdf", 300, 400),
+        ];
+        assert_eq!(summarize(&entries, |_| true), (3, 600));
+        assert_eq!(summarize(&entries, |e| e.age_days >= 90), (2, 500));
+    }
+
+    #[test]
+    fn test_next_threshold_cycles() {
+        assert_eq!(next_threshold(30), 90);
+        assert_eq!(next_threshold(365), 30);
+        assert_eq!(next_threshold(45), 30);
+    }
+
+    #[test]
+    fn test_unique_target_avoids_collisions() {
+        let dir = std::env::temp_dir().join("win_opt_test_unique_target");
+        fs::create_dir_all(&dir).unwrap();
+        fs::write(dir.join("setup.exe"), b"x").unwrap();
+
+        let target = unique_target(&dir, Path::new("setup.exe"));
+        assert_eq!(target, dir.join("setup (1).exe"));
+
+        fs::remove_dir_all(&dir).unwrap();
+    }
+}
@@ -0,0 +1,271 @@
+//! Revisión de drivers instalados
+//!
+//! Enumera los drivers firmados mediante WMI (`Win32_PnPSignedDriver`),
+//! marca los que tienen una fecha muy antigua y permite exportar la lista a
+//! CSV o pedir a Windows que vuelva a buscar dispositivos con
+//! `pnputil /scan-devices`.
+
+use crate::platform;
+use crate::types::OperationState;
+use crate::utils::is_admin;
+use crate::{log_debug, log_error, log_info, log_warn};
+use std::fs;
+use std::path::PathBuf;
+use std::time::{SystemTime, UNIX_EPOCH};
+
+/// Antigüedad (en años) a partir de la cual un driver se considera antiguo
+pub const OUTDATED_YEARS: u16 = 5;
+
+/// Script de PowerShell que lista los drivers en formato `nombre|proveedor|versión|fecha`
+///
+/// La fecha se formatea explícitamente como `yyyy-MM-dd` para no depender de
+/// la configuración regional del sistema.
+const LIST_DRIVERS_SCRIPT: &str = "Get-CimInstance Win32_PnPSignedDriver | \
+     Where-Object { $_.DeviceName } | \
+     ForEach-Object { '{0}|{1}|{2}|{3}' -f $_.DeviceName, $_.DriverProviderName, $_.DriverVersion, \
+     $(if ($_.DriverDate) { $_.DriverDate.ToString('yyyy-MM-dd') } else { '' }) }";
+
+/// Driver instalado en el sistema
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DriverInfo {
+    /// Nombre del dispositivo
+    pub device_name: String,
+    /// Proveedor del driver
+    pub provider: String,
+    /// Versión del driver
+    pub version: String,
+    /// Año del driver (si se conoce)
+    pub year: Option<u16>,
+    /// Fecha del driver en formato `yyyy-MM-dd`
+    pub date: String,
+}
+
+impl DriverInfo {
+    /// Indica si el driver es más antiguo que `OUTDATED_YEARS`
+    ///
+    /// Los drivers de Microsoft incluidos con Windows llevan deliberadamente la
+    /// fecha 2006-06-21, así que no se marcan como antiguos.
+    pub fn is_outdated(&self, current_year: u16) -> bool {
+        if self.provider.eq_ignore_ascii_case("Microsoft") {
+            return false;
+        }
+        self.year
+            .is_some_and(|year| current_year.saturating_sub(year) >= OUTDATED_YEARS)
+    }
+}
+
+/// Año actual calculado a partir del reloj del sistema
+pub fn current_year() -> u16 {
+    let secs = SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map(|d| d.as_secs())
+        .unwrap_or(0);
+    // Año gregoriano medio: 365.2425 días
+    (1970 + secs / 31_556_952) as u16
+}
+
+/// Ejecuta el listado de drivers instalados
+pub fn execute_drivers(app: &mut crate::app::App) {
+    app.operation_state = OperationState::Running;
+    app.drivers.clear();
+    log_info!(app, "🧩 Analizando drivers instalados...");
+
+    let result = platform::command("powershell")
+        .args(["-NoProfile", "-Command", LIST_DRIVERS_SCRIPT])
+        .output();
+
+    match result {
+        Ok(output) if output.status.success() => {
+            let stdout = String::from_utf8_lossy(&output.stdout);
+            app.drivers = parse_driver_list(&stdout);
+        }
+        Ok(_) => {
+            log_error!(app, "❌ No se pudo obtener la lista de drivers");
+            app.operation_state = OperationState::Failed;
+            return;
+        }
+        Err(e) => {
+            log_error!(app, "❌ Error: {}", e);
+            app.operation_state = OperationState::Failed;
+            return;
+        }
+    }
+
+    let year = current_year();
+    let total = app.drivers.len();
+    let outdated = app.drivers.iter().filter(|d| d.is_outdated(year)).count();
+
+    log_info!(app, "✅ Drivers encontrados: {}", total);
+    if outdated > 0 {
+        log_warn!(
+            app,
+            "⚠️  {} drivers tienen más de {} años",
+            outdated,
+            OUTDATED_YEARS
+        );
+        log_info!(
+            app,
+            "ℹ️  Busca versiones nuevas en la web del fabricante o en Windows Update"
+        );
+    }
+
+    app.operation_state = OperationState::Completed;
+}
+
+/// Exporta la lista de drivers a un archivo CSV en la carpeta Documentos
+pub fn execute_export_drivers(app: &mut crate::app::App) {
+    if app.drivers.is_empty() {
+        log_warn!(app, "⚠️  No hay drivers que exportar");
+        return;
+    }
+
+    let path = export_path();
+    let year = current_year();
+    match fs::write(&path, drivers_to_csv(&app.drivers, year)) {
+        Ok(()) => log_info!(app, "✅ Lista exportada a {}", path.display()),
+        Err(e) => log_error!(app, "❌ Error exportando la lista: {}", e),
+    }
+}
+
+/// Pide a Windows que vuelva a detectar dispositivos (`pnputil /scan-devices`)
+pub fn execute_scan_devices(app: &mut crate::app::App) {
+    if !is_admin() {
+        log_error!(
+            app,
+            "⛔ ERROR: Esta operación requiere permisos de Administrador"
+        );
+        return;
+    }
+
+    log_info!(app, "");
+    log_info!(app, "Ejecutando: pnputil /scan-devices");
+    match platform::command("pnputil").arg("/scan-devices").output() {
+        Ok(output) if output.status.success() => {
+            log_info!(app, "✅ Búsqueda de cambios de hardware completada");
+            execute_drivers(app);
+        }
+        Ok(output) => {
+            log_debug!(app, "{}", String::from_utf8_lossy(&output.stdout).trim());
+            log_warn!(app, "⚠️  pnputil no pudo buscar dispositivos");
+        }
+        Err(e) => log_error!(app, "❌ Error: {}", e),
+    }
+}
+
+/// Ruta del CSV exportado (Documentos, o el directorio de configuración)
+fn export_path() -> PathBuf {
+    platform::documents_dir()
+        .or_else(|| crate::config::Config::get_config_dir().ok())
+        .unwrap_or_else(std::env::temp_dir)
+        .join("win_opt_drivers.csv")
+}
+
+/// Parsea la salida del script de listado, ordenando por fecha ascendente
+fn parse_driver_list(output: &str) -> Vec<DriverInfo> {
+    let mut drivers: Vec<DriverInfo> = output
+        .lines()
+        .filter_map(|line| {
+            let mut fields = line.trim().splitn(4, '|');
+            let device_name = fields.next()?.trim();
+            if device_name.is_empty() {
+                return None;
+            }
+            let provider = fields.next().unwrap_or("").trim();
+            let version = fields.next().unwrap_or("").trim();
+            let date = fields.next().unwrap_or("").trim();
+
+            Some(DriverInfo {
+                device_name: device_name.to_string(),
+                provider: provider.to_string(),
+                version: version.to_string(),
+                year: date.get(..4).and_then(|y| y.parse().ok()),
+                date: date.to_string(),
+            })
+        })
+        .collect();
+
+    // Los más antiguos primero; los que no tienen fecha al final
+    drivers.sort_by(|a, b| match (a.date.is_empty(), b.date.is_empty()) {
+        (false, true) => std::cmp::Ordering::Less,
+        (true, false) => std::cmp::Ordering::Greater,
+        _ => a.date.cmp(&b.date),
+    });
+    drivers
+}
+
+/// Genera el CSV de la lista de drivers
+fn drivers_to_csv(drivers: &[DriverInfo], current_year: u16) -> String {
+    let mut csv = String::from("Device,Provider,Version,Date,Outdated\n");
+    for driver in drivers {
+        csv.push_str(&format!(
+            "{},{},{},{},{}\n",
+            csv_field(&driver.device_name),
+            csv_field(&driver.provider),
+            csv_field(&driver.version),
+            driver.date,
+            driver.is_outdated(current_year)
+        ));
+    }
+    csv
+}
+
+/// Entrecomilla un campo CSV si contiene separadores o comillas
+fn csv_field(value: &str) -> String {
+    if value.contains([',', '"', '\n']) {
+        format!("\"{}\"", value.replace('"', "\"\""))
+    } else {
+        value.to_string()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const SAMPLE_OUTPUT: &str = "\
+NVIDIA GeForce RTX 3060|NVIDIA|31.0.15.5222|2024-03-12
+Realtek PCIe GbE Family Controller|Realtek|10.38.1118.2019|2019-11-18
+Volume Manager|Microsoft|10.0.22621.1|2006-06-21
+Unknown Device|||
+";
+
+    #[test]
+    fn test_parse_driver_list_sorted_by_date() {
+        let drivers = parse_driver_list(SAMPLE_OUTPUT);
+        assert_eq!(drivers.len(), 4);
+        assert_eq!(drivers[0].device_name, "Volume Manager");
+        assert_eq!(drivers[1].year, Some(2019));
+        assert_eq!(drivers[3].device_name, "Unknown Device");
+        assert_eq!(drivers[3].year, None);
+    }
+
+    #[test]
+    fn test_outdated_ignores_microsoft_inbox_drivers() {
+        let drivers = parse_driver_list(SAMPLE_OUTPUT);
+        let outdated: Vec<&str> = drivers
+            .iter()
+            .filter(|d| d.is_outdated(2026))
+            .map(|d| d.device_name.as_str())
+            .collect();
+        assert_eq!(outdated, vec!["Realtek PCIe GbE Family Controller"]);
+    }
+
+    #[test]
+    fn test_drivers_to_csv_escapes_fields() {
+        let drivers = vec![DriverInfo {
+            device_name: "Audio, \"HD\"".to_string(),
+            provider: "Realtek".to_string(),
+            version: "6.0".to_string(),
+            year: Some(2015),
+            date: "2015-01-01".to_string(),
+        }];
+        let csv = drivers_to_csv(&drivers, 2026);
+        assert!(csv.starts_with("Device,Provider,Version,Date,Outdated\n"));
+        assert!(csv.contains("\"Audio, \"\"HD\"\"\",Realtek,6.0,2015-01-01,true"));
+    }
+
+    #[test]
+    fn test_current_year_is_reasonable() {
+        assert!(current_year() >= 2024);
+    }
+}
@@ -0,0 +1,159 @@
+//! Unidades en las que se limpian archivos temporales
+//!
+//! Con varias unidades, la vista de limpieza muestra antes de empezar la
+//! lista de `sysinfo::Disks` para elegir en cuáles se limpia. En la unidad
+//! del sistema se recorren las carpetas temporales de Windows y de los
+//! perfiles; en las demás, las carpetas temporales de la raíz (`D:\Temp`,
+//! `D:\tmp`) que suelen usar los discos de trabajo.
+
+use crate::platform;
+use crate::safety;
+use crate::simulate;
+use std::path::{Path, PathBuf};
+use sysinfo::Disks;
+
+/// Carpetas temporales de la raíz de una unidad de datos
+pub const DRIVE_TEMP_DIRS: [&str; 2] = ["Temp", "tmp"];
+
+/// Unidad que se puede incluir en la limpieza
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DriveChoice {
+    /// Punto de montaje (`C:\`)
+    pub mount: PathBuf,
+    /// Nombre con el que se muestra (`C:`)
+    pub name: String,
+    /// Capacidad en bytes
+    pub total: u64,
+    /// Espacio libre en bytes
+    pub available: u64,
+    /// Contiene la carpeta de Windows
+    pub system: bool,
+    /// Marcada para limpiar
+    pub selected: bool,
+}
+
+/// Unidades del equipo, con la del sistema marcada
+///
+/// En modo simulación se retornan dos unidades dentro del sandbox.
+pub fn list() -> Vec<DriveChoice> {
+    if let Some(root) = simulate::sandbox() {
+        return simulated(root);
+    }
+    let mut mounts: Vec<(PathBuf, u64, u64)> = Vec::new();
+    for disk in Disks::new_with_refreshed_list().list() {
+        let mount = disk.mount_point().to_path_buf();
+        if disk.total_space() > 0 && !mounts.iter().any(|(known, _, _)| *known == mount) {
+            mounts.push((mount, disk.total_space(), disk.available_space()));
+        }
+    }
+    mounts.sort();
+    from_mounts(mounts, &platform::windows_dir())
+}
+
+/// Construye la lista a partir de los puntos de montaje
+fn from_mounts(mounts: Vec<(PathBuf, u64, u64)>, windows: &Path) -> Vec<DriveChoice> {
+    let mut drives: Vec<DriveChoice> = mounts
+        .into_iter()
+        .map(|(mount, total, available)| DriveChoice {
+            name: drive_name(&mount),
+            mount,
+            total,
+            available,
+            system: false,
+            selected: false,
+        })
+        .collect();
+    if let Some(system) = owner_index(windows, drives.iter().map(|drive| &drive.mount)) {
+        drives[system].system = true;
+        drives[system].selected = true;
+    }
+    drives
+}
+
+/// Nombre de una unidad sin la barra final (`C:\` se muestra como `C:`)
+fn drive_name(mount: &Path) -> String {
+    let shown = mount.to_string_lossy();
+    match shown.trim_end_matches(['\\', '/']) {
+        "" => shown.to_string(),
+        trimmed => trimmed.to_string(),
+    }
+}
+
+/// Unidades de ejemplo del sandbox: el propio sandbox y `D`
+fn simulated(root: &Path) -> Vec<DriveChoice> {
+    let drive =
+        |mount: PathBuf, name: &str, total: u64, available: u64, system: bool| DriveChoice {
+            mount,
+            name: name.to_string(),
+            total,
+            available,
+            system,
+            selected: system,
+        };
+    const GB: u64 = 1024 * 1024 * 1024;
+    vec![
+        drive(root.to_path_buf(), "C:", 512 * GB, 87 * GB, true),
+        drive(root.join("D"), "D:", 2048 * GB, 1310 * GB, false),
+    ]
+}
+
+/// Índice de la unidad que contiene la ruta
+///
+/// Si un punto de montaje está dentro de otro, gana el más largo.
+fn owner_index<'a>(path: &Path, mounts: impl Iterator<Item = &'a PathBuf>) -> Option<usize> {
+    mounts
+        .enumerate()
+        .filter(|(_, mount)| safety::is_within(path, mount))
+        .max_by_key(|(_, mount)| mount.components().count())
+        .map(|(idx, _)| idx)
+}
+
+/// Unidad de la lista que contiene la ruta
+pub fn owner<'a>(path: &Path, drives: &'a [DriveChoice]) -> Option<&'a DriveChoice> {
+    owner_index(path, drives.iter().map(|drive| &drive.mount)).map(|idx| &drives[idx])
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn root() -> PathBuf {
+        PathBuf::from(if cfg!(windows) { "C:\\" } else { "/" })
+    }
+
+    #[test]
+    fn test_system_drive_is_selected() {
+        let root = root();
+        let data = root.join("mnt").join("data");
+        let drives = from_mounts(
+            vec![(root.clone(), 500, 100), (data.clone(), 2000, 1500)],
+            &root.join("Windows"),
+        );
+        assert!(drives[0].system && drives[0].selected);
+        assert!(!drives[1].system && !drives[1].selected);
+        assert_eq!(drives[1].name, data.to_string_lossy());
+    }
+
+    #[test]
+    fn test_owner_prefers_the_longest_mount() {
+        let root = root();
+        let data = root.join("mnt").join("data");
+        let drives = from_mounts(
+            vec![(root.clone(), 500, 100), (data.clone(), 2000, 1500)],
+            &root.join("Windows"),
+        );
+        let temp = data.join("Temp").join("render.tmp");
+        assert_eq!(owner(&temp, &drives).map(|d| &d.mount), Some(&data));
+        let windows_temp = root.join("Windows").join("Temp");
+        assert_eq!(owner(&windows_temp, &drives).map(|d| &d.mount), Some(&root));
+    }
+
+    #[test]
+    fn test_simulated_drives_stay_in_the_sandbox() {
+        let root = std::env::temp_dir().join("win_opt_test_drives");
+        let drives = simulated(&root);
+        assert_eq!(drives.len(), 2);
+        assert!(drives.iter().all(|drive| drive.mount.starts_with(&root)));
+        assert!(drives[0].selected && !drives[1].selected);
+    }
+}
@@ -0,0 +1,441 @@
+//! Limpieza de la variable de entorno PATH
+//!
+//! Analiza el PATH del usuario y del sistema, detecta entradas vacías,
+//! carpetas que ya no existen y duplicados, y permite eliminarlas. Antes de
+//! escribir se exportan las claves del registro a un `.reg` en la carpeta de
+//! configuración para poder restaurarlas con `reg import`.
+
+use crate::config::Config;
+use crate::i18n::I18nKey;
+use crate::registry;
+use crate::types::OperationState;
+use crate::utils::{is_admin, today_iso};
+use crate::{log_error, log_info, log_warn};
+use std::collections::HashSet;
+use std::path::Path;
+
+/// Clave del registro con las variables de entorno del usuario
+const USER_ENV_KEY: &str = "HKCU\\Environment";
+
+/// Clave del registro con las variables de entorno del sistema
+const SYSTEM_ENV_KEY: &str =
+    "HKLM\\SYSTEM\\CurrentControlSet\\Control\\Session Manager\\Environment";
+
+/// Ámbito de una entrada del PATH
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PathScope {
+    System,
+    User,
+}
+
+impl PathScope {
+    /// Clave del registro donde se guarda el PATH del ámbito
+    fn registry_key(self) -> &'static str {
+        match self {
+            PathScope::System => SYSTEM_ENV_KEY,
+            PathScope::User => USER_ENV_KEY,
+        }
+    }
+
+    /// Sufijo del archivo de copia de seguridad
+    fn backup_name(self) -> &'static str {
+        match self {
+            PathScope::System => "system",
+            PathScope::User => "user",
+        }
+    }
+
+    /// Clave de traducción del ámbito
+    pub fn label_key(self) -> I18nKey {
+        match self {
+            PathScope::System => I18nKey::PathSystem,
+            PathScope::User => I18nKey::PathUser,
+        }
+    }
+}
+
+/// Problema detectado en una entrada del PATH
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PathIssue {
+    /// Entrada vacía (`;;`)
+    Empty,
+    /// La carpeta no existe
+    Missing,
+    /// Ya aparece antes en el PATH efectivo
+    Duplicate,
+}
+
+impl PathIssue {
+    /// Clave de traducción del problema
+    pub fn label_key(self) -> I18nKey {
+        match self {
+            PathIssue::Empty => I18nKey::PathEmpty,
+            PathIssue::Missing => I18nKey::PathMissing,
+            PathIssue::Duplicate => I18nKey::PathDuplicate,
+        }
+    }
+}
+
+/// Entrada del PATH con su diagnóstico
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PathEntry {
+    pub scope: PathScope,
+    /// Valor tal como está en el registro (sin expandir)
+    pub value: String,
+    pub issue: Option<PathIssue>,
+}
+
+/// PATH de un ámbito leído del registro
+#[derive(Debug, Clone, PartialEq, Eq)]
+struct PathValue {
+    scope: PathScope,
+    /// Tipo del valor (`REG_EXPAND_SZ` o `REG_SZ`)
+    kind: String,
+    data: String,
+}
+
+/// Expande las variables `%NOMBRE%` con el entorno del proceso
+///
+/// Las variables desconocidas se dejan tal cual, igual que hace Windows.
+pub fn expand_env(value: &str) -> String {
+    let mut result = String::new();
+    let mut rest = value;
+
+    while let Some(start) = rest.find('%') {
+        let Some(len) = rest[start + 1..].find('%') else {
+            break;
+        };
+        let name = &rest[start + 1..start + 1 + len];
+        result.push_str(&rest[..start]);
+        match std::env::var(name) {
+            Ok(expanded) if !name.is_empty() => result.push_str(&expanded),
+            _ => result.push_str(&rest[start..start + len + 2]),
+        }
+        rest = &rest[start + len + 2..];
+    }
+
+    result.push_str(rest);
+    result
+}
+
+/// Forma normalizada de una ruta para detectar duplicados
+fn normalize(value: &str) -> String {
+    expand_env(value.trim())
+        .trim_end_matches(['\\', '/'])
+        .to_lowercase()
+}
+
+/// Diagnostica las entradas del PATH del sistema y del usuario
+///
+/// Windows construye el PATH efectivo con el del sistema seguido del del
+/// usuario, así que una entrada del usuario que ya esté en el del sistema se
+/// marca como duplicada. `exists` comprueba si una ruta expandida existe.
+fn analyze(system: &str, user: &str, exists: impl Fn(&str) -> bool) -> Vec<PathEntry> {
+    let mut seen = HashSet::new();
+    let mut entries = Vec::new();
+
+    for (scope, data) in [(PathScope::System, system), (PathScope::User, user)] {
+        for value in data.split(';') {
+            let issue = if value.trim().is_empty() {
+                Some(PathIssue::Empty)
+            } else if !seen.insert(normalize(value)) {
+                Some(PathIssue::Duplicate)
+            } else if !exists(&expand_env(value.trim()))
+                // Una entrada que no se pudo decodificar bien no se puede
+                // comprobar: se conserva
+                && !value.contains(char::REPLACEMENT_CHARACTER)
+            {
+                Some(PathIssue::Missing)
+            } else {
+                None
+            };
+            entries.push(PathEntry {
+                scope,
+                value: value.to_string(),
+                issue,
+            });
+        }
+    }
+
+    // El `;` final es habitual y no cuenta como entrada vacía
+    for scope in [PathScope::System, PathScope::User] {
+        if let Some(pos) = entries.iter().rposition(|e| e.scope == scope)
+            && entries[pos].value.is_empty()
+        {
+            entries.remove(pos);
+        }
+    }
+
+    entries
+}
+
+/// Reconstruye el PATH de un ámbito sin las entradas problemáticas
+fn cleaned_value(entries: &[PathEntry], scope: PathScope) -> String {
+    entries
+        .iter()
+        .filter(|e| e.scope == scope && e.issue.is_none())
+        .map(|e| e.value.as_str())
+        .collect::<Vec<_>>()
+        .join(";")
+}
+
+/// Lee el PATH de un ámbito
+fn read_path(scope: PathScope) -> Option<PathValue> {
+    registry::read_string(scope.registry_key(), "Path").map(|value| PathValue {
+        scope,
+        kind: value.kind,
+        data: value.data,
+    })
+}
+
+/// Analiza el PATH del usuario y del sistema
+pub fn execute_path_scan(app: &mut crate::app::App) {
+    app.operation_state = OperationState::Running;
+    log_info!(app, "🛤️  Analizando la variable PATH...");
+
+    let system = read_path(PathScope::System).map(|v| v.data);
+    let user = read_path(PathScope::User).map(|v| v.data);
+    if system.is_none() && user.is_none() {
+        log_error!(app, "❌ No se pudo leer el PATH del registro");
+        app.path_entries.clear();
+        app.operation_state = OperationState::Failed;
+        return;
+    }
+
+    app.path_entries = analyze(
+        system.as_deref().unwrap_or_default(),
+        user.as_deref().unwrap_or_default(),
+        |path| Path::new(path).is_dir(),
+    );
+
+    let total = app.path_entries.len();
+    let issues = app
+        .path_entries
+        .iter()
+        .filter(|e| e.issue.is_some())
+        .count();
+    log_info!(app, "✅ Entradas analizadas: {}", total);
+    if issues > 0 {
+        log_warn!(
+            app,
+            "⚠️  {} entradas vacías, inexistentes o duplicadas",
+            issues
+        );
+        log_info!(
+            app,
+            "💡 Pulsa C para eliminarlas (se guarda una copia del registro)"
+        );
+    } else {
+        log_info!(app, "✅ El PATH está limpio");
+    }
+
+    app.operation_state = OperationState::Completed;
+}
+
+/// Solicita limpiar el PATH; la segunda pulsación lo confirma
+pub fn request_clean(app: &mut crate::app::App) {
+    let count = app
+        .path_entries
+        .iter()
+        .filter(|e| e.issue.is_some())
+        .count();
+    if count == 0 {
+        return;
+    }
+
+    if app.config.confirmations.enabled && !app.path_clean_confirm {
+        app.path_clean_confirm = true;
+        log_warn!(
+            app,
+            "⚠️  Se van a eliminar {} entradas del PATH. Pulsa C de nuevo para confirmar",
+            count
+        );
+        return;
+    }
+
+    app.path_clean_confirm = false;
+    app.selected_item = 0;
+    execute_path_clean(app);
+}
+
+/// Elimina las entradas problemáticas del PATH con copia de seguridad previa
+///
+/// El PATH se vuelve a leer y analizar justo antes de escribirlo: si un
+/// instalador añadió una carpeta después del análisis de la vista, esa
+/// entrada no se pierde.
+pub fn execute_path_clean(app: &mut crate::app::App) {
+    log_info!(app, "");
+    let system = read_path(PathScope::System);
+    let user = read_path(PathScope::User);
+    let entries = analyze(
+        system.as_ref().map(|v| v.data.as_str()).unwrap_or_default(),
+        user.as_ref().map(|v| v.data.as_str()).unwrap_or_default(),
+        |path| Path::new(path).is_dir(),
+    );
+
+    let mut scopes = vec![PathScope::User];
+    if is_admin() {
+        scopes.insert(0, PathScope::System);
+    } else if entries
+        .iter()
+        .any(|e| e.scope == PathScope::System && e.issue.is_some())
+    {
+        log_warn!(
+            app,
+            "⚠️  Sin permisos de Administrador solo se limpiará el PATH del usuario"
+        );
+    }
+
+    let mut changed = false;
+    for scope in scopes {
+        let has_issues = entries
+            .iter()
+            .any(|e| e.scope == scope && e.issue.is_some());
+        let current = match scope {
+            PathScope::System => system.as_ref(),
+            PathScope::User => user.as_ref(),
+        };
+        let Some(current) = current.filter(|_| has_issues) else {
+            continue;
+        };
+
+        let backup = match Config::get_config_dir() {
+            Ok(dir) => dir.join(format!("path_{}_{}.reg", scope.backup_name(), today_iso())),
+            Err(e) => {
+                log_error!(app, "❌ No se pudo crear la copia de seguridad: {}", e);
+                continue;
+            }
+        };
+        if let Err(e) = registry::export_key(current.scope.registry_key(), &backup) {
+            log_error!(app, "❌ No se pudo crear la copia de seguridad: {}", e);
+            continue;
+        }
+        log_info!(app, "💾 Copia de seguridad: {}", backup.display());
+
+        let cleaned = cleaned_value(&entries, scope);
+        match registry::write_string(scope.registry_key(), "Path", &current.kind, &cleaned) {
+            Ok(()) => {
+                changed = true;
+                let removed = entries
+                    .iter()
+                    .filter(|e| e.scope == scope && e.issue.is_some())
+                    .count();
+                log_info!(
+                    app,
+                    "✅ {} entradas eliminadas del PATH ({})",
+                    removed,
+                    scope.backup_name()
+                );
+            }
+            Err(e) => log_error!(app, "❌ Error: {}", e),
+        }
+    }
+
+    if changed {
+        broadcast_environment_change();
+        log_info!(
+            app,
+            "ℹ️  Las terminales abiertas deben reiniciarse para ver el nuevo PATH"
+        );
+        execute_path_scan(app);
+    }
+}
+
+/// Avisa a las aplicaciones abiertas (Explorer incluido) de que el entorno cambió
+#[cfg(windows)]
+fn broadcast_environment_change() {
+    use windows::Win32::Foundation::{LPARAM, WPARAM};
+    use windows::Win32::UI::WindowsAndMessaging::{
+        HWND_BROADCAST, SMTO_ABORTIFHUNG, SendMessageTimeoutW, WM_SETTINGCHANGE,
+    };
+
+    let area: Vec<u16> = "Environment".encode_utf16().chain([0]).collect();
+    // SAFETY: `area` es una cadena UTF-16 terminada en nulo que vive durante
+    // toda la llamada.
+    unsafe {
+        SendMessageTimeoutW(
+            HWND_BROADCAST,
+            WM_SETTINGCHANGE,
+            WPARAM(0),
+            LPARAM(area.as_ptr() as isize),
+            SMTO_ABORTIFHUNG,
+            5000,
+            None,
+        );
+    }
+}
+
+#[cfg(not(windows))]
+fn broadcast_environment_change() {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_expand_env() {
+        let path = std::env::var("PATH").unwrap();
+        assert_eq!(expand_env("%PATH%\\bin"), format!("{}\\bin", path));
+        assert_eq!(
+            expand_env("%WIN_OPT_UNDEFINED%\\bin"),
+            "%WIN_OPT_UNDEFINED%\\bin"
+        );
+        assert_eq!(expand_env("100%"), "100%");
+    }
+
+    #[test]
+    fn test_analyze_detects_issues() {
+        let system = "C:\\Windows;C:\\Windows\\System32;C:\\Old;";
+        let user = "c:\\windows\\;;C:\\Users\\me\\bin";
+        let entries = analyze(system, user, |path| !path.contains("Old"));
+
+        let issues: Vec<_> = entries.iter().map(|e| (e.scope, e.issue)).collect();
+        assert_eq!(
+            issues,
+            vec![
+                (PathScope::System, None),
+                (PathScope::System, None),
+                (PathScope::System, Some(PathIssue::Missing)),
+                (PathScope::User, Some(PathIssue::Duplicate)),
+                (PathScope::User, Some(PathIssue::Empty)),
+                (PathScope::User, None),
+            ]
+        );
+
+        assert_eq!(
+            cleaned_value(&entries, PathScope::System),
+            "C:\\Windows;C:\\Windows\\System32"
+        );
+        assert_eq!(
+            cleaned_value(&entries, PathScope::User),
+            "C:\\Users\\me\\bin"
+        );
+    }
+
+    #[test]
+    fn test_undecodable_entries_are_never_missing() {
+        let user = "C:\\Users\\Jos\u{FFFD}\\bin;C:\\Old";
+        let entries = analyze("", user, |_| false);
+        assert_eq!(entries[0].issue, None);
+        assert_eq!(entries[1].issue, Some(PathIssue::Missing));
+        assert_eq!(
+            cleaned_value(&entries, PathScope::User),
+            "C:\\Users\\Jos\u{FFFD}\\bin"
+        );
+    }
+
+    #[test]
+    fn test_entries_added_after_the_scan_are_kept() {
+        let exists = |path: &str| !path.contains("Old");
+        let scanned = analyze("", "C:\\Old;C:\\Users\\me\\bin", exists);
+
+        // Un instalador añade su carpeta entre el análisis y la limpieza
+        let current = "C:\\Old;C:\\Users\\me\\bin;C:\\Tools\\new";
+        let entries = analyze("", current, exists);
+        assert_eq!(
+            cleaned_value(&entries, PathScope::User),
+            "C:\\Users\\me\\bin;C:\\Tools\\new"
+        );
+        assert!(!cleaned_value(&scanned, PathScope::User).contains("Tools"));
+    }
+}
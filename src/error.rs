@@ -18,9 +18,59 @@ pub enum WinOptError {
     #[error("Servicio no permitido: {0}")]
     InvalidService(String),
 
+    #[error("Error de notificación: {0}")]
+    Notification(String),
+
+    #[error("Error del portapapeles: {0}")]
+    Clipboard(String),
+
+    #[error(transparent)]
+    Control(#[from] ControlError),
+
     #[error("Error inesperado: {0}")]
     Unknown(String),
 }
 
+/// Errores al modificar servicios y tareas programadas con las APIs de
+/// Windows
+#[derive(Error, Debug, Clone, PartialEq, Eq)]
+pub enum ControlError {
+    #[error("no existe")]
+    NotFound,
+
+    #[error("acceso denegado")]
+    AccessDenied,
+
+    #[error("solo está disponible en Windows")]
+    Unsupported,
+
+    #[error("{message} (0x{code:08X})")]
+    Os { code: u32, message: String },
+}
+
+impl ControlError {
+    /// Clasifica un HRESULT de Windows
+    pub fn from_hresult(code: u32, message: impl Into<String>) -> ControlError {
+        match code {
+            // ERROR_FILE_NOT_FOUND, ERROR_PATH_NOT_FOUND (tareas y carpetas)
+            // y ERROR_SERVICE_DOES_NOT_EXIST
+            0x8007_0002 | 0x8007_0003 | 0x8007_0424 => ControlError::NotFound,
+            // ERROR_ACCESS_DENIED
+            0x8007_0005 => ControlError::AccessDenied,
+            _ => ControlError::Os {
+                code,
+                message: message.into(),
+            },
+        }
+    }
+}
+
+#[cfg(windows)]
+impl From<windows::core::Error> for ControlError {
+    fn from(error: windows::core::Error) -> ControlError {
+        ControlError::from_hresult(error.code().0 as u32, error.message())
+    }
+}
+
 /// Alias para Result con WinOptError
 pub type Result<T> = std::result::Result<T, WinOptError>;
@@ -1,14 +1,143 @@
-/// Sistema de workers para ejecutar comandos en threads separados
+/// Sistema de workers para ejecutar comandos en segundo plano
 ///
 /// Este módulo proporciona funcionalidad para ejecutar comandos de Windows
-/// en threads separados, manteniendo la UI responsiva y evitando que la
-/// salida de los comandos corrompa la interfaz TUI.
-use crate::types::{OperationState, WorkerHandle, WorkerMessage};
-use std::process::Command;
-use std::sync::Arc;
+/// como tareas asíncronas de tokio, manteniendo la UI responsiva y evitando
+/// que la salida de los comandos corrompa la interfaz TUI. Varias
+/// operaciones pueden ejecutarse a la vez sobre el mismo runtime, cada
+/// comando tiene su propio tiempo límite y su salida se envía línea a línea
+/// según se produce.
+use crate::cleanup::{self, SystemCache};
+use crate::config::Config;
+use crate::i18n::{I18n, I18nKey};
+use crate::platform::{self, CommandRunner};
+use crate::repair;
+use crate::retry::FailedItem;
+use crate::types::{OperationState, OperationStats, RepairStats, WorkerHandle, WorkerMessage};
+use serde::{Deserialize, Serialize};
+use std::any::Any;
+use std::process::{ExitStatus, Stdio};
 use std::sync::atomic::{AtomicBool, Ordering};
 use std::sync::mpsc::{self, Sender};
-use std::thread;
+use std::sync::{Arc, Mutex, OnceLock};
+use std::time::{Duration, Instant};
+use tokio::io::{AsyncRead, AsyncReadExt};
+use tokio::process::Child;
+use tokio::runtime::Runtime;
+use tokio::task::JoinHandle;
+
+/// Intervalo con el que se comprueba el estado del comando en curso
+const POLL_INTERVAL: Duration = Duration::from_secs(1);
+
+/// Configuración de los tiempos límite de los comandos externos
+#[derive(Debug, Clone, Copy, Serialize, Deserialize)]
+#[serde(default)]
+pub struct ExecutorConfig {
+    /// Minutos sin salida tras los que un comando se considera bloqueado
+    pub stall_minutes: u64,
+    /// Tiempo límite de DISM en minutos (puede descargar componentes)
+    pub dism_minutes: u64,
+    /// Tiempo límite de SFC en minutos
+    pub sfc_minutes: u64,
+    /// Tiempo límite del resto de comandos en minutos
+    pub command_minutes: u64,
+}
+
+impl Default for ExecutorConfig {
+    fn default() -> Self {
+        Self {
+            stall_minutes: 10,
+            dism_minutes: 90,
+            sfc_minutes: 60,
+            command_minutes: 30,
+        }
+    }
+}
+
+/// Convierte minutos de la configuración en una duración (mínimo un minuto)
+fn minutes(value: u64) -> Duration {
+    Duration::from_secs(value.max(1) * 60)
+}
+
+/// Señales que vigilan el comando en curso de un worker
+#[derive(Debug, Clone)]
+struct Supervisor {
+    /// Tiempo sin salida tras el que el comando se considera bloqueado
+    stall_after: Duration,
+    /// Flag de cancelación de la operación completa
+    cancel_flag: Arc<AtomicBool>,
+    /// Flag para terminar el paso bloqueado y continuar con el siguiente
+    skip_flag: Arc<AtomicBool>,
+    /// Idioma de los logs del worker
+    i18n: I18n,
+}
+
+impl Supervisor {
+    fn new(
+        limits: &ExecutorConfig,
+        i18n: I18n,
+        cancel_flag: &Arc<AtomicBool>,
+        skip_flag: &Arc<AtomicBool>,
+    ) -> Self {
+        Self {
+            i18n,
+            stall_after: minutes(limits.stall_minutes),
+            cancel_flag: cancel_flag.clone(),
+            skip_flag: skip_flag.clone(),
+        }
+    }
+}
+
+/// Runtime compartido por todos los workers
+///
+/// Se crea la primera vez que se lanza un worker.
+pub fn runtime() -> &'static Runtime {
+    static RUNTIME: OnceLock<Runtime> = OnceLock::new();
+    RUNTIME.get_or_init(|| {
+        tokio::runtime::Builder::new_multi_thread()
+            .enable_all()
+            .thread_name("win_opt-worker")
+            .build()
+            .expect("No se pudo crear el runtime de los workers")
+    })
+}
+
+/// Lanza la tarea de un worker en el runtime compartido
+///
+/// Si la tarea entra en pánico, el pánico llega a la vista como un error y
+/// la operación termina como fallida, en lugar de quedarse en marcha sin
+/// avisar. El `JoinHandle` devuelto termina cuando lo hace la tarea.
+fn spawn_task<F, Fut>(sender: Sender<WorkerMessage>, i18n: I18n, task: F) -> JoinHandle<()>
+where
+    F: FnOnce(Sender<WorkerMessage>) -> Fut,
+    Fut: Future<Output = ()> + Send + 'static,
+{
+    let inner = runtime().spawn(task(sender.clone()));
+    runtime().spawn(async move {
+        if let Err(e) = inner.await
+            && e.is_panic()
+        {
+            let payload = e.into_panic();
+            let message = panic_message(payload.as_ref());
+            send_error(
+                &sender,
+                i18n.t_args(I18nKey::WorkerInternalError, &[("error", message)]),
+            );
+            send_state(&sender, OperationState::Failed);
+            let _ = sender.send(WorkerMessage::Completed);
+        }
+    })
+}
+
+/// Texto de un pánico (el de `panic!` si lo tiene)
+pub fn panic_message(payload: &(dyn Any + Send)) -> &str {
+    if let Some(message) = payload.downcast_ref::<&str>() {
+        message
+    } else if let Some(message) = payload.downcast_ref::<String>() {
+        message
+    } else {
+        "pánico sin mensaje"
+    }
+}
 
 /// Envía un mensaje de log al thread principal
 ///
@@ -26,6 +155,18 @@ fn send_state(sender: &Sender<WorkerMessage>, state: OperationState) -> bool {
     sender.send(WorkerMessage::StateChange(state)).is_ok()
 }
 
+/// Envía las estadísticas de la reparación al thread principal
+fn send_repair_stats(sender: &Sender<WorkerMessage>, stats: &RepairStats) {
+    let _ = sender.send(WorkerMessage::StatsUpdate(OperationStats::Repair(
+        stats.clone(),
+    )));
+}
+
+/// Anota un elemento fallido que se puede volver a intentar
+fn send_failed(sender: &Sender<WorkerMessage>, item: FailedItem) {
+    let _ = sender.send(WorkerMessage::ItemFailed(item));
+}
+
 /// Envía un mensaje de error al thread principal
 ///
 /// # Returns
@@ -34,64 +175,501 @@ fn send_error(sender: &Sender<WorkerMessage>, error: String) -> bool {
     sender.send(WorkerMessage::Error(error)).is_ok()
 }
 
-/// Ejecuta un comando y captura su salida sin mostrarla en pantalla
+/// Decodificador incremental de la salida de un proceso
+///
+/// Separa líneas tanto en `\n` como en `\r`: DISM y SFC redibujan su barra de
+/// progreso con `\r` sin saltar de línea, y sin esto el progreso no llegaría
+/// hasta el final. Además, `sfc` escribe en UTF-16LE cuando su salida está
+/// redirigida, lo que se detecta en el primer bloque leído. El resto se
+/// convierte a UTF-8 con reemplazo de caracteres inválidos, porque las
+/// herramientas de Windows escriben en la página de códigos OEM.
+#[derive(Debug, Default)]
+struct OutputDecoder {
+    /// `Some(true)` si la salida es UTF-16LE (se decide con el primer bloque)
+    utf16: Option<bool>,
+    /// Bytes de la línea incompleta
+    pending: Vec<u8>,
+}
+
+impl OutputDecoder {
+    /// Añade un bloque de bytes y devuelve las líneas completas
+    fn feed(&mut self, bytes: &[u8]) -> Vec<String> {
+        let utf16 = *self.utf16.get_or_insert_with(|| looks_like_utf16(bytes));
+        self.pending.extend_from_slice(bytes);
+
+        let unit = if utf16 { 2 } else { 1 };
+        let mut lines = Vec::new();
+        let mut start = 0;
+        let mut index = 0;
+        while index + unit <= self.pending.len() {
+            let code = if utf16 {
+                u16::from_le_bytes([self.pending[index], self.pending[index + 1]])
+            } else {
+                u16::from(self.pending[index])
+            };
+            if code == u16::from(b'\n') || code == u16::from(b'\r') {
+                lines.push(decode(&self.pending[start..index], utf16));
+                start = index + unit;
+            }
+            index += unit;
+        }
+        self.pending.drain(..start);
+        lines
+    }
+
+    /// Devuelve la última línea si la salida no terminaba en salto de línea
+    fn finish(self) -> Option<String> {
+        (!self.pending.is_empty()).then(|| decode(&self.pending, self.utf16.unwrap_or(false)))
+    }
+}
+
+/// Indica si un bloque parece UTF-16LE (BOM o byte alto nulo en texto ASCII)
+fn looks_like_utf16(bytes: &[u8]) -> bool {
+    bytes.starts_with(&[0xFF, 0xFE]) || (bytes.len() >= 2 && bytes[0] != 0 && bytes[1] == 0)
+}
+
+/// Convierte los bytes de una línea a texto
+fn decode(bytes: &[u8], utf16: bool) -> String {
+    if utf16 {
+        let units: Vec<u16> = bytes
+            .chunks_exact(2)
+            .map(|pair| u16::from_le_bytes([pair[0], pair[1]]))
+            .collect();
+        String::from_utf16_lossy(&units).replace('\u{FEFF}', "")
+    } else {
+        String::from_utf8_lossy(bytes).into_owned()
+    }
+}
+
+/// Porcentaje de una barra de progreso de DISM: `[===== 45.0% =====]`
+fn parse_dism_progress(line: &str) -> Option<f32> {
+    let line = line.trim();
+    let inner = line.strip_prefix('[')?.strip_suffix(']')?;
+    percent_token(inner)
+}
+
+/// Porcentaje de una línea de progreso de SFC: `Verification 45% complete.`
+///
+/// El texto está traducido (`Comprobación 45% completada.`), así que solo se
+/// busca el número seguido de `%`.
+fn parse_sfc_progress(line: &str) -> Option<f32> {
+    percent_token(line)
+}
+
+/// Busca un token `número%` en el texto (admite `,` como separador decimal)
+fn percent_token(text: &str) -> Option<f32> {
+    text.split(|c: char| c.is_whitespace() || c == '=')
+        .filter_map(|token| token.strip_suffix('%'))
+        .find_map(|number| number.replace(',', ".").parse::<f32>().ok())
+        .filter(|percent| (0.0..=100.0).contains(percent))
+}
+
+/// Porcentaje de una línea de progreso de DISM o SFC
+fn parse_progress(line: &str) -> Option<f32> {
+    parse_dism_progress(line).or_else(|| parse_sfc_progress(line))
+}
+
+/// Reenvía las líneas de una salida del proceso según llegan
+///
+/// Las líneas de progreso (DISM, SFC) se envían como `Progress` en lugar de
+/// como logs. Las líneas vacías se omiten, y también las repetidas seguidas.
+/// El resto se guarda además en `transcript`, si se indica, para analizar
+/// la salida cuando termine el comando.
+///
+/// Cada bloque leído actualiza `activity`, que se usa para detectar
+/// comandos bloqueados.
+///
+/// # Returns
+/// `false` si el canal se cerró
+async fn forward_lines(
+    sender: Sender<WorkerMessage>,
+    mut output: impl AsyncRead + Unpin,
+    prefix: &'static str,
+    activity: Arc<Mutex<Instant>>,
+    transcript: Option<Arc<Mutex<Vec<String>>>>,
+) -> bool {
+    let mut decoder = OutputDecoder::default();
+    let mut buffer = [0u8; 4096];
+    let mut last = String::new();
+    let mut send = |line: String| {
+        let line = line.trim_end();
+        if line.trim().is_empty() || line == last {
+            return true;
+        }
+        last = line.to_string();
+        if prefix.is_empty()
+            && let Some(percent) = parse_progress(line)
+        {
+            return sender.send(WorkerMessage::Progress(percent)).is_ok();
+        }
+        if let Some(mut lines) = transcript.as_ref().and_then(|lines| lines.lock().ok()) {
+            lines.push(line.to_string());
+        }
+        send_log(&sender, format!("{}{}", prefix, line))
+    };
+
+    loop {
+        let read = match output.read(&mut buffer).await {
+            Ok(0) | Err(_) => break,
+            Ok(read) => read,
+        };
+        if let Ok(mut last_output) = activity.lock() {
+            *last_output = Instant::now();
+        }
+        for line in decoder.feed(&buffer[..read]) {
+            if !send(line) {
+                return false;
+            }
+        }
+    }
+    decoder.finish().is_none_or(send)
+}
+
+/// Ejecuta un comando y envía su salida según se produce sin mostrarla en pantalla
+///
+/// Si el comando supera `limit` se termina y se considera fallido. Si pasa
+/// más de `stall_after` sin producir salida se avisa de que está bloqueado
+/// (`WorkerMessage::Stalled`) y, mientras lo esté, el usuario puede
+/// terminarlo para continuar con el siguiente paso. Activar el flag de
+/// cancelación termina el comando en curso sin esperar a que acabe.
 ///
 /// # Argumentos
 /// * `sender` - Canal para enviar logs al thread principal
 /// * `command` - Comando a ejecutar (ej: "DISM", "sfc")
 /// * `args` - Argumentos del comando
+/// * `limit` - Tiempo máximo de ejecución
+/// * `supervisor` - Detección de bloqueos y señales del usuario
 ///
 /// # Returns
 /// `true` si el comando se ejecutó exitosamente, `false` en caso contrario o si el canal está cerrado
-fn execute_command(sender: &Sender<WorkerMessage>, command: &str, args: &[&str]) -> bool {
+async fn execute_command(
+    sender: &Sender<WorkerMessage>,
+    command: &str,
+    args: &[&str],
+    limit: Duration,
+    supervisor: &Supervisor,
+) -> bool {
+    run_command(sender, command, args, limit, supervisor)
+        .await
+        .success
+}
+
+/// Resultado de un comando junto con su salida
+#[derive(Debug, Default)]
+struct CommandOutput {
+    /// El comando se ejecutó exitosamente
+    success: bool,
+    /// Código de salida, si el comando terminó por sí mismo
+    code: Option<i32>,
+    /// Líneas de la salida estándar, sin las de progreso
+    lines: Vec<String>,
+}
+
+/// Ejecuta un comando como [`execute_command`] y guarda además su salida
+/// estándar para analizarla
+async fn run_command(
+    sender: &Sender<WorkerMessage>,
+    command: &str,
+    args: &[&str],
+    limit: Duration,
+    supervisor: &Supervisor,
+) -> CommandOutput {
+    let i18n = &supervisor.i18n;
+    let command_line = format!("{} {}", command, args.join(" "));
     if !send_log(
         sender,
-        format!("Ejecutando: {} {}", command, args.join(" ")),
+        i18n.t_args(I18nKey::WorkerRunning, &[("command", &command_line)]),
     ) {
         // Canal cerrado, terminar operación
-        return false;
+        return CommandOutput::default();
+    }
+
+    if let Some(runner) = platform::replaced_runner(command) {
+        return run_replaced(sender, i18n, runner.as_ref(), command, args).await;
     }
 
-    match Command::new(command).args(args).output() {
-        Ok(output) => {
-            // Convertir salida a UTF-8 (con reemplazo de caracteres inválidos)
-            let stdout = String::from_utf8_lossy(&output.stdout);
-            let stderr = String::from_utf8_lossy(&output.stderr);
+    let mut child = match platform::async_command(command)
+        .args(args)
+        .stdin(Stdio::null())
+        .stdout(Stdio::piped())
+        .stderr(Stdio::piped())
+        .spawn()
+    {
+        Ok(child) => child,
+        Err(e) => {
+            send_error(
+                sender,
+                i18n.t_args(I18nKey::WorkerCommandError, &[("error", &e.to_string())]),
+            );
+            return CommandOutput::default();
+        }
+    };
 
-            // Enviar líneas de stdout como logs
-            for line in stdout.lines() {
-                if !line.trim().is_empty() && !send_log(sender, line.to_string()) {
-                    // Canal cerrado, terminar operación
-                    return false;
-                }
-            }
+    // Leer stdout y stderr a la vez para que ninguno llene su tubería
+    let started = Instant::now();
+    let activity = Arc::new(Mutex::new(started));
+    let transcript = Arc::new(Mutex::new(Vec::new()));
+    let stdout = child.stdout.take().map(|out| {
+        tokio::spawn(forward_lines(
+            sender.clone(),
+            out,
+            "",
+            activity.clone(),
+            Some(transcript.clone()),
+        ))
+    });
+    let stderr = child.stderr.take().map(|err| {
+        tokio::spawn(forward_lines(
+            sender.clone(),
+            err,
+            "ERROR: ",
+            activity.clone(),
+            None,
+        ))
+    });
 
-            // Enviar líneas de stderr como logs
-            for line in stderr.lines() {
-                if !line.trim().is_empty() && !send_log(sender, format!("ERROR: {}", line)) {
-                    // Canal cerrado, terminar operación
-                    return false;
-                }
-            }
+    supervisor.skip_flag.store(false, Ordering::Relaxed);
+    let mut stalled = false;
+    let status = loop {
+        if let Ok(status) = tokio::time::timeout(POLL_INTERVAL, child.wait()).await {
+            break status;
+        }
 
-            if output.status.success() {
-                send_log(sender, "✓ Comando completado exitosamente".to_string());
-                true
-            } else {
-                send_log(
-                    sender,
-                    format!("✗ Comando falló con código: {:?}", output.status.code()),
-                );
-                false
-            }
+        if started.elapsed() >= limit {
+            kill_tree(&mut child).await;
+            set_stalled(sender, &mut stalled, false);
+            send_error(
+                sender,
+                i18n.t_args(
+                    I18nKey::WorkerTimeout,
+                    &[("count", &(limit.as_secs() / 60).to_string())],
+                ),
+            );
+            return CommandOutput::default();
+        }
+
+        let idle = activity
+            .lock()
+            .map(|last_output| last_output.elapsed())
+            .unwrap_or_default();
+        if idle >= supervisor.stall_after && !stalled {
+            set_stalled(sender, &mut stalled, true);
+            send_log(
+                sender,
+                format!(
+                    "⚠️  {}",
+                    i18n.t_args(
+                        I18nKey::WorkerStalled,
+                        &[("count", &(idle.as_secs() / 60).to_string())],
+                    )
+                ),
+            );
+        } else if idle < supervisor.stall_after && stalled {
+            set_stalled(sender, &mut stalled, false);
+            send_log(sender, i18n.t(I18nKey::WorkerResponsive).to_string());
+        }
+
+        if stalled && supervisor.skip_flag.swap(false, Ordering::Relaxed) {
+            kill_tree(&mut child).await;
+            set_stalled(sender, &mut stalled, false);
+            send_error(sender, i18n.t(I18nKey::WorkerStepKilled).to_string());
+            return CommandOutput::default();
+        }
+        if supervisor.cancel_flag.load(Ordering::Relaxed) {
+            kill_tree(&mut child).await;
+            set_stalled(sender, &mut stalled, false);
+            send_log(sender, i18n.t(I18nKey::WorkerCommandCancelled).to_string());
+            return CommandOutput::default();
+        }
+    };
+    set_stalled(sender, &mut stalled, false);
+
+    // Esperar a que se reenvíe toda la salida antes del resultado
+    for reader in [stdout, stderr].into_iter().flatten() {
+        if !reader.await.unwrap_or(true) {
+            // Canal cerrado, terminar operación
+            return CommandOutput::default();
         }
+    }
+
+    let code = status.as_ref().ok().and_then(ExitStatus::code);
+    CommandOutput {
+        success: report_status(sender, i18n, status),
+        code,
+        lines: transcript
+            .lock()
+            .map(|mut lines| std::mem::take(&mut *lines))
+            .unwrap_or_default(),
+    }
+}
+
+/// Ejecuta un comando con el ejecutor sustituto (pruebas) en lugar de
+/// lanzar el proceso
+///
+/// La salida se reenvía igual que la de un proceso real, de una sola vez.
+async fn run_replaced(
+    sender: &Sender<WorkerMessage>,
+    i18n: &I18n,
+    runner: &dyn CommandRunner,
+    command: &str,
+    args: &[&str],
+) -> CommandOutput {
+    let args: Vec<String> = args.iter().map(|arg| arg.to_string()).collect();
+    let output = match runner.run(command, &args) {
+        Ok(output) => output,
         Err(e) => {
-            send_error(sender, format!("Error al ejecutar comando: {}", e));
+            send_error(
+                sender,
+                i18n.t_args(I18nKey::WorkerCommandError, &[("error", &e.to_string())]),
+            );
+            return CommandOutput::default();
+        }
+    };
+
+    let activity = Arc::new(Mutex::new(Instant::now()));
+    let transcript = Arc::new(Mutex::new(Vec::new()));
+    let forwarded = forward_lines(
+        sender.clone(),
+        output.stdout.as_slice(),
+        "",
+        activity.clone(),
+        Some(transcript.clone()),
+    )
+    .await
+        && forward_lines(
+            sender.clone(),
+            output.stderr.as_slice(),
+            "ERROR: ",
+            activity,
+            None,
+        )
+        .await;
+    if !forwarded {
+        // Canal cerrado, terminar operación
+        return CommandOutput::default();
+    }
+
+    CommandOutput {
+        success: report_status(sender, i18n, Ok(output.status)),
+        code: output.status.code(),
+        lines: transcript
+            .lock()
+            .map(|mut lines| std::mem::take(&mut *lines))
+            .unwrap_or_default(),
+    }
+}
+
+/// Termina un proceso hijo junto con los procesos que haya lanzado
+///
+/// Los pasos se ejecutan a través de `cmd /C`, así que terminar solo el hijo
+/// dejaría DISM o SFC funcionando. En Windows se usa `taskkill /T`.
+async fn kill_tree(child: &mut Child) {
+    #[cfg(windows)]
+    if let Some(pid) = child.id() {
+        let _ = platform::async_command("taskkill")
+            .args(["/T", "/F", "/PID", &pid.to_string()])
+            .stdout(Stdio::null())
+            .stderr(Stdio::null())
+            .status()
+            .await;
+    }
+    let _ = child.kill().await;
+}
+
+/// Informa de un cambio en el estado de bloqueo del comando en curso
+fn set_stalled(sender: &Sender<WorkerMessage>, stalled: &mut bool, value: bool) {
+    if *stalled != value {
+        *stalled = value;
+        let _ = sender.send(WorkerMessage::Stalled(value));
+    }
+}
+
+/// Envía el resultado final de un comando
+fn report_status(
+    sender: &Sender<WorkerMessage>,
+    i18n: &I18n,
+    status: std::io::Result<ExitStatus>,
+) -> bool {
+    match status {
+        Ok(status) if status.success() => {
+            send_log(
+                sender,
+                format!("✓ {}", i18n.t(I18nKey::WorkerCommandSucceeded)),
+            );
+            true
+        }
+        Ok(status) => {
+            // Sin código si el proceso terminó por una señal
+            let code = status
+                .code()
+                .map_or("?".to_string(), |code| code.to_string());
+            send_log(
+                sender,
+                format!(
+                    "✗ {}",
+                    i18n.t_args(I18nKey::WorkerCommandFailed, &[("code", &code)])
+                ),
+            );
+            false
+        }
+        Err(e) => {
+            send_error(
+                sender,
+                i18n.t_args(I18nKey::WorkerCommandError, &[("error", &e.to_string())]),
+            );
             false
         }
     }
 }
 
+/// Extrae de `CBS.log` las líneas `[SR]` del análisis de SFC que acaba de
+/// terminar
+///
+/// El registro puede ocupar decenas de megas, así que se lee fuera de los
+/// hilos del runtime. Si no se puede leer se avisa y se sigue sin extracto.
+async fn read_cbs_excerpt(sender: &Sender<WorkerMessage>, i18n: &I18n) -> Vec<String> {
+    let content = tokio::task::spawn_blocking(|| std::fs::read(repair::cbs_log())).await;
+    match content {
+        Ok(Ok(content)) => {
+            let excerpt = repair::sr_excerpt(
+                &String::from_utf8_lossy(&content),
+                repair::CBS_EXCERPT_LINES,
+            );
+            send_log(
+                sender,
+                i18n.t_args(
+                    I18nKey::WorkerCbsExtracted,
+                    &[("count", &excerpt.len().to_string())],
+                ),
+            );
+            excerpt
+        }
+        Ok(Err(e)) => {
+            send_log(
+                sender,
+                format!(
+                    "⚠️  {}",
+                    i18n.t_args(I18nKey::WorkerCbsError, &[("error", &e.to_string())])
+                ),
+            );
+            Vec::new()
+        }
+        Err(_) => Vec::new(),
+    }
+}
+
+/// Línea `Paso N/M: descripción...` con la que se anuncia cada paso
+fn step_line(i18n: &I18n, current: usize, total: usize, step: I18nKey) -> String {
+    i18n.t_args(
+        I18nKey::WorkerStep,
+        &[
+            ("current", &current.to_string()),
+            ("total", &total.to_string()),
+            ("step", i18n.t(step)),
+        ],
+    )
+}
+
 /// Spawn worker para operaciones de reparación del sistema (DISM + SFC)
 ///
 /// Ejecuta DISM y SFC en secuencia, capturando toda la salida sin mostrarla
@@ -103,90 +681,108 @@ fn execute_command(sender: &Sender<WorkerMessage>, command: &str, args: &[&str])
 /// # Returns
 /// Un `WorkerHandle` que contiene:
 /// - Un receptor de canal para mensajes de progreso
-/// - Un handle del thread para join
+/// - Un handle de la tarea para esperarla
 /// - Un flag de cancelación atómico
 ///
 /// # Example
 /// ```no_run
-/// use win_opt::executor::spawn_repair_worker;
+/// use win_opt::executor::{ExecutorConfig, spawn_repair_worker};
+/// use win_opt::i18n::{I18n, Language};
 ///
-/// let handle = spawn_repair_worker();
+/// let handle = spawn_repair_worker(ExecutorConfig::default(), I18n::new(Language::English));
 /// // Procesar mensajes del worker...
 /// while let Ok(msg) = handle.receiver.recv() {
 ///     // Manejar mensaje...
 /// }
 /// ```
-pub fn spawn_repair_worker() -> WorkerHandle {
+pub fn spawn_repair_worker(limits: ExecutorConfig, i18n: I18n) -> WorkerHandle {
     let (sender, receiver) = mpsc::channel();
     let cancel_flag = Arc::new(AtomicBool::new(false));
     let cancel_flag_clone = cancel_flag.clone();
+    let skip_flag = Arc::new(AtomicBool::new(false));
+    let supervisor = Supervisor::new(&limits, i18n, &cancel_flag, &skip_flag);
 
-    let thread_handle = thread::spawn(move || {
+    let task_handle = spawn_task(sender, i18n, move |sender| async move {
         if !send_state(&sender, OperationState::Running) {
             return; // Canal cerrado
         }
 
         if !send_log(
             &sender,
-            "=== Iniciando Reparación del Sistema ===".to_string(),
+            format!("=== {} ===", i18n.t(I18nKey::RepairWorkerTitle)),
         ) {
             return; // Canal cerrado
         }
 
         // Verificar cancelación antes de DISM
         if cancel_flag_clone.load(Ordering::Relaxed) {
-            send_log(&sender, "Operación cancelada por el usuario".to_string());
+            send_log(&sender, i18n.t(I18nKey::WorkerCancelled).to_string());
             send_state(&sender, OperationState::Failed);
             let _ = sender.send(WorkerMessage::Completed);
             return;
         }
 
         // Ejecutar DISM
-        send_log(&sender, "Paso 1/2: Ejecutando DISM...".to_string());
-        send_log(
-            &sender,
-            "Esto puede tomar entre 5-30 minutos dependiendo del sistema.".to_string(),
-        );
+        send_log(&sender, step_line(&i18n, 1, 2, I18nKey::RepairWorkerDism));
+        send_log(&sender, i18n.t(I18nKey::RepairWorkerDismHint).to_string());
 
-        let dism_success = execute_command(
+        let mut stats = RepairStats::default();
+        let dism = run_command(
             &sender,
             "cmd",
             &["/C", "DISM /Online /Cleanup-Image /RestoreHealth"],
-        );
+            minutes(limits.dism_minutes),
+            &supervisor,
+        )
+        .await;
+        let dism_success = dism.success;
+        stats.dism_ok = Some(dism_success);
+        stats.dism = repair::parse_dism(&dism.lines, dism.code);
+        send_repair_stats(&sender, &stats);
 
         if !dism_success {
-            send_error(
-                &sender,
-                "DISM falló. Continuando con SFC de todas formas...".to_string(),
-            );
+            send_error(&sender, i18n.t(I18nKey::RepairWorkerDismFailed).to_string());
         }
 
         // Verificar cancelación antes de SFC
         if cancel_flag_clone.load(Ordering::Relaxed) {
-            send_log(&sender, "Operación cancelada por el usuario".to_string());
+            send_log(&sender, i18n.t(I18nKey::WorkerCancelled).to_string());
             send_state(&sender, OperationState::Failed);
             let _ = sender.send(WorkerMessage::Completed);
             return;
         }
 
         // Ejecutar SFC
-        send_log(&sender, "Paso 2/2: Ejecutando SFC...".to_string());
-        send_log(
-            &sender,
-            "Verificando integridad de archivos del sistema...".to_string(),
-        );
+        send_log(&sender, step_line(&i18n, 2, 2, I18nKey::RepairWorkerSfc));
+        send_log(&sender, i18n.t(I18nKey::RepairWorkerSfcHint).to_string());
 
-        let sfc_success = execute_command(&sender, "cmd", &["/C", "sfc /scannow"]);
+        let sfc = run_command(
+            &sender,
+            "cmd",
+            &["/C", "sfc /scannow"],
+            minutes(limits.sfc_minutes),
+            &supervisor,
+        )
+        .await;
+        let sfc_success = sfc.success;
+        stats.sfc_ok = Some(sfc_success);
+        stats.sfc = repair::parse_sfc(&sfc.lines);
+        if let Some((found, fixed)) = stats.sfc.corruption() {
+            stats.corruption_found = found;
+            stats.corruption_fixed = fixed;
+        }
+        stats.cbs_excerpt = read_cbs_excerpt(&sender, &i18n).await;
+        send_repair_stats(&sender, &stats);
 
         // Determinar resultado final
         if dism_success && sfc_success {
             send_log(
                 &sender,
-                "=== Reparación completada exitosamente ===".to_string(),
+                format!("=== {} ===", i18n.t(I18nKey::RepairWorkerCompleted)),
             );
             send_state(&sender, OperationState::Completed);
         } else {
-            send_error(&sender, "Reparación completada con errores".to_string());
+            send_error(&sender, i18n.t(I18nKey::RepairWorkerFailed).to_string());
             send_state(&sender, OperationState::Failed);
         }
 
@@ -195,14 +791,187 @@ pub fn spawn_repair_worker() -> WorkerHandle {
 
     WorkerHandle {
         receiver,
-        thread_handle: Some(thread_handle),
+        task_handle: Some(task_handle),
         cancel_flag,
+        skip_flag,
+    }
+}
+
+/// Acción de un paso de un worker
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum StepAction {
+    /// Ejecutar un comando con sus argumentos
+    Command {
+        command: &'static str,
+        args: &'static [&'static str],
+    },
+    /// Eliminar los archivos de una caché del sistema
+    RemoveCache(SystemCache),
+}
+
+/// Paso de un worker de varios comandos
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct WorkerStep {
+    /// Descripción del paso para los logs
+    pub description: I18nKey,
+    /// Acción del paso
+    pub action: StepAction,
+    /// Tiempo máximo de ejecución del comando del paso
+    pub timeout: Duration,
+}
+
+/// Ejecuta un paso; retorna `true` si terminó bien
+async fn execute_step(
+    sender: &Sender<WorkerMessage>,
+    step: &WorkerStep,
+    config: &Arc<Config>,
+    supervisor: &Supervisor,
+) -> bool {
+    match step.action {
+        StepAction::Command { command, args } => {
+            execute_command(sender, command, args, step.timeout, supervisor).await
+        }
+        StepAction::RemoveCache(cache) => {
+            remove_cache(sender, &supervisor.i18n, cache, config).await
+        }
+    }
+}
+
+/// Elimina los archivos de una caché del sistema
+///
+/// Cada archivo pasa por [`cleanup::remove_path`], así que se comprueba
+/// contra las rutas protegidas y respeta la cuarentena y la Papelera.
+/// Retorna `false` si alguno no se pudo eliminar.
+async fn remove_cache(
+    sender: &Sender<WorkerMessage>,
+    i18n: &I18n,
+    cache: SystemCache,
+    config: &Arc<Config>,
+) -> bool {
+    let config = config.clone();
+    let results = tokio::task::spawn_blocking(move || {
+        cache
+            .files()
+            .into_iter()
+            .map(|path| {
+                let result = cleanup::remove_path(&config, &path);
+                (path, result)
+            })
+            .collect::<Vec<_>>()
+    })
+    .await;
+    let Ok(results) = results else {
+        return false;
+    };
+
+    let mut success = true;
+    for (path, result) in results {
+        let path = path.display().to_string();
+        let message = match result {
+            Ok(()) => i18n.t_args(I18nKey::WorkerRemoved, &[("path", &path)]),
+            Err(e) => {
+                success = false;
+                i18n.t_args(
+                    I18nKey::WorkerRemoveError,
+                    &[("path", &path), ("error", &e.to_string())],
+                )
+            }
+        };
+        send_log(sender, message);
+    }
+    success
+}
+
+/// Spawn worker que ejecuta una secuencia de pasos
+///
+/// Cada paso se anuncia como `Paso N/M` para mostrar el progreso. Un paso
+/// fallido no detiene la secuencia (por ejemplo, hay que volver a iniciar un
+/// servicio aunque no se hayan podido borrar sus archivos), pero la operación
+/// termina como fallida. La cancelación termina el paso en curso y detiene
+/// la secuencia; un paso bloqueado se puede terminar para continuar con el
+/// siguiente. Los pasos fallidos se anotan para poder reintentarlos.
+///
+/// # Returns
+/// Un `WorkerHandle` que contiene el receptor del canal, handle de la tarea y flag de cancelación
+pub fn spawn_steps_worker(
+    title: I18nKey,
+    steps: &'static [WorkerStep],
+    config: Config,
+    i18n: I18n,
+) -> WorkerHandle {
+    let (sender, receiver) = mpsc::channel();
+    let cancel_flag = Arc::new(AtomicBool::new(false));
+    let cancel_flag_clone = cancel_flag.clone();
+    let skip_flag = Arc::new(AtomicBool::new(false));
+    let supervisor = Supervisor::new(&config.executor, i18n, &cancel_flag, &skip_flag);
+    let config = Arc::new(config);
+
+    let task_handle = spawn_task(sender, i18n, move |sender| async move {
+        if !send_state(&sender, OperationState::Running) {
+            return; // Canal cerrado
+        }
+
+        let title = i18n.t(title);
+        if !send_log(&sender, format!("=== {} ===", title)) {
+            return; // Canal cerrado
+        }
+
+        let mut failed = 0;
+        for (index, step) in steps.iter().enumerate() {
+            if cancel_flag_clone.load(Ordering::Relaxed) {
+                send_log(&sender, i18n.t(I18nKey::WorkerCancelled).to_string());
+                send_state(&sender, OperationState::Failed);
+                let _ = sender.send(WorkerMessage::Completed);
+                return;
+            }
+
+            if !send_log(
+                &sender,
+                step_line(&i18n, index + 1, steps.len(), step.description),
+            ) {
+                return; // Canal cerrado
+            }
+
+            if !execute_step(&sender, step, &config, &supervisor).await {
+                failed += 1;
+                send_failed(&sender, FailedItem::Step(*step));
+            }
+        }
+
+        if failed == 0 {
+            send_log(
+                &sender,
+                format!(
+                    "=== {} ===",
+                    i18n.t_args(I18nKey::WorkerSucceeded, &[("title", title)])
+                ),
+            );
+            send_state(&sender, OperationState::Completed);
+        } else {
+            send_error(
+                &sender,
+                i18n.t_args(
+                    I18nKey::WorkerStepsFailed,
+                    &[("title", title), ("count", &failed.to_string())],
+                ),
+            );
+            send_state(&sender, OperationState::Failed);
+        }
+
+        let _ = sender.send(WorkerMessage::Completed);
+    });
+
+    WorkerHandle {
+        receiver,
+        task_handle: Some(task_handle),
+        cancel_flag,
+        skip_flag,
     }
 }
 
 /// Spawn worker para limpieza de Windows Update
 ///
-/// Ejecuta DISM para limpiar archivos obsoletos de Windows Update en un thread separado.
+/// Ejecuta DISM para limpiar archivos obsoletos de Windows Update en segundo plano.
 ///
 /// La operación puede ser cancelada en cualquier momento estableciendo el flag
 /// de cancelación del `WorkerHandle` retornado.
@@ -210,7 +979,7 @@ pub fn spawn_repair_worker() -> WorkerHandle {
 /// # Returns
 /// Un `WorkerHandle` que contiene:
 /// - Un receptor de canal para mensajes de progreso
-/// - Un handle del thread para join
+/// - Un handle de la tarea para esperarla
 /// - Un flag de cancelación atómico
 ///
 /// # Platform
@@ -218,42 +987,49 @@ pub fn spawn_repair_worker() -> WorkerHandle {
 ///
 /// # Example
 /// ```no_run
-/// use win_opt::executor::spawn_windows_update_worker;
+/// use win_opt::executor::{ExecutorConfig, spawn_windows_update_worker};
+/// use win_opt::i18n::{I18n, Language};
 ///
-/// let handle = spawn_windows_update_worker();
+/// let handle =
+///     spawn_windows_update_worker(ExecutorConfig::default(), I18n::new(Language::English));
 /// while let Ok(msg) = handle.receiver.recv() {
 ///     // Procesar mensaje...
 /// }
 /// ```
-pub fn spawn_windows_update_worker() -> WorkerHandle {
+pub fn spawn_windows_update_worker(limits: ExecutorConfig, i18n: I18n) -> WorkerHandle {
     let (sender, receiver) = mpsc::channel();
     let cancel_flag = Arc::new(AtomicBool::new(false));
     let cancel_flag_clone = cancel_flag.clone();
+    let skip_flag = Arc::new(AtomicBool::new(false));
+    let supervisor = Supervisor::new(&limits, i18n, &cancel_flag, &skip_flag);
 
-    let thread_handle = thread::spawn(move || {
+    let task_handle = spawn_task(sender, i18n, move |sender| async move {
         if !send_state(&sender, OperationState::Running) {
             return; // Canal cerrado
         }
 
         if !send_log(
             &sender,
-            "=== Iniciando Limpieza de Windows Update ===".to_string(),
+            format!("=== {} ===", i18n.t(I18nKey::WindowsUpdateWorkerTitle)),
         ) {
             return; // Canal cerrado
         }
 
         // Verificar cancelación antes de ejecutar
         if cancel_flag_clone.load(Ordering::Relaxed) {
-            send_log(&sender, "Operación cancelada por el usuario".to_string());
+            send_log(&sender, i18n.t(I18nKey::WorkerCancelled).to_string());
             send_state(&sender, OperationState::Failed);
             let _ = sender.send(WorkerMessage::Completed);
             return;
         }
 
-        send_log(&sender, "Ejecutando DISM para limpiar caché...".to_string());
         send_log(
             &sender,
-            "Esta operación puede tardar varios minutos...".to_string(),
+            i18n.t(I18nKey::WindowsUpdateWorkerDism).to_string(),
+        );
+        send_log(
+            &sender,
+            i18n.t(I18nKey::WindowsUpdateWorkerHint).to_string(),
         );
 
         let success = execute_command(
@@ -263,16 +1039,22 @@ pub fn spawn_windows_update_worker() -> WorkerHandle {
                 "/C",
                 "DISM /Online /Cleanup-Image /StartComponentCleanup /ResetBase",
             ],
-        );
+            minutes(limits.dism_minutes),
+            &supervisor,
+        )
+        .await;
 
         if success {
             send_log(
                 &sender,
-                "=== Limpieza completada exitosamente ===".to_string(),
+                format!("=== {} ===", i18n.t(I18nKey::WindowsUpdateWorkerCompleted)),
             );
             send_state(&sender, OperationState::Completed);
         } else {
-            send_error(&sender, "Limpieza falló".to_string());
+            send_error(
+                &sender,
+                i18n.t(I18nKey::WindowsUpdateWorkerFailed).to_string(),
+            );
             send_state(&sender, OperationState::Failed);
         }
 
@@ -281,8 +1063,9 @@ pub fn spawn_windows_update_worker() -> WorkerHandle {
 
     WorkerHandle {
         receiver,
-        thread_handle: Some(thread_handle),
+        task_handle: Some(task_handle),
         cancel_flag,
+        skip_flag,
     }
 }
 
@@ -294,31 +1077,39 @@ pub fn spawn_windows_update_worker() -> WorkerHandle {
 /// # Arguments
 /// * `command` - Comando a ejecutar
 /// * `args` - Argumentos del comando
-/// * `description` - Descripción de la operación para logs
+/// * `description` - Descripción de la operación para logs, ya traducida
+/// * `i18n` - Idioma del resto de los logs
 ///
 /// # Returns
-/// Un `WorkerHandle` que contiene el receptor del canal, handle del thread y flag de cancelación
+/// Un `WorkerHandle` que contiene el receptor del canal, handle de la tarea y flag de cancelación
 ///
 /// # Example
 /// ```no_run
-/// use win_opt::executor::spawn_command_worker;
+/// use win_opt::executor::{ExecutorConfig, spawn_command_worker};
+/// use win_opt::i18n::{I18n, Language};
 ///
 /// let handle = spawn_command_worker(
 ///     "cmd".to_string(),
 ///     vec!["/C".to_string(), "dir".to_string()],
-///     "Listar directorio".to_string(),
+///     "List directory".to_string(),
+///     ExecutorConfig::default(),
+///     I18n::new(Language::English),
 /// );
 /// ```
 pub fn spawn_command_worker(
     command: String,
     args: Vec<String>,
     description: String,
+    limits: ExecutorConfig,
+    i18n: I18n,
 ) -> WorkerHandle {
     let (sender, receiver) = mpsc::channel();
     let cancel_flag = Arc::new(AtomicBool::new(false));
     let cancel_flag_clone = cancel_flag.clone();
+    let skip_flag = Arc::new(AtomicBool::new(false));
+    let supervisor = Supervisor::new(&limits, i18n, &cancel_flag, &skip_flag);
 
-    let thread_handle = thread::spawn(move || {
+    let task_handle = spawn_task(sender, i18n, move |sender| async move {
         if !send_state(&sender, OperationState::Running) {
             return; // Canal cerrado
         }
@@ -329,20 +1120,36 @@ pub fn spawn_command_worker(
 
         // Verificar cancelación antes de ejecutar
         if cancel_flag_clone.load(Ordering::Relaxed) {
-            send_log(&sender, "Operación cancelada por el usuario".to_string());
+            send_log(&sender, i18n.t(I18nKey::WorkerCancelled).to_string());
             send_state(&sender, OperationState::Failed);
             let _ = sender.send(WorkerMessage::Completed);
             return;
         }
 
         let args_str: Vec<&str> = args.iter().map(|s| s.as_str()).collect();
-        let success = execute_command(&sender, &command, &args_str);
+        let success = execute_command(
+            &sender,
+            &command,
+            &args_str,
+            minutes(limits.command_minutes),
+            &supervisor,
+        )
+        .await;
 
         if success {
-            send_log(&sender, format!("=== {} completado ===", description));
+            send_log(
+                &sender,
+                format!(
+                    "=== {} ===",
+                    i18n.t_args(I18nKey::WorkerSucceeded, &[("title", &description)])
+                ),
+            );
             send_state(&sender, OperationState::Completed);
         } else {
-            send_error(&sender, format!("{} falló", description));
+            send_error(
+                &sender,
+                i18n.t_args(I18nKey::WorkerFailed, &[("title", &description)]),
+            );
             send_state(&sender, OperationState::Failed);
         }
 
@@ -351,14 +1158,162 @@ pub fn spawn_command_worker(
 
     WorkerHandle {
         receiver,
-        thread_handle: Some(thread_handle),
+        task_handle: Some(task_handle),
         cancel_flag,
+        skip_flag,
+    }
+}
+
+/// Spawn worker que reintenta los elementos fallidos de una operación
+///
+/// Vuelve a eliminar los archivos que no se pudieron borrar (con el mismo
+/// modo de borrado de la configuración) y a ejecutar los pasos que
+/// fallaron. Los que vuelven a fallar se anotan de nuevo, así que se puede
+/// reintentar otra vez.
+pub fn spawn_retry_worker(items: Vec<FailedItem>, config: Config, i18n: I18n) -> WorkerHandle {
+    let (sender, receiver) = mpsc::channel();
+    let cancel_flag = Arc::new(AtomicBool::new(false));
+    let cancel_flag_clone = cancel_flag.clone();
+    let skip_flag = Arc::new(AtomicBool::new(false));
+    let supervisor = Supervisor::new(&config.executor, i18n, &cancel_flag, &skip_flag);
+    let config = Arc::new(config);
+
+    let task_handle = spawn_task(sender, i18n, move |sender| async move {
+        if !send_state(&sender, OperationState::Running) {
+            return; // Canal cerrado
+        }
+
+        let mut failed = 0;
+        for (index, item) in items.iter().enumerate() {
+            if cancel_flag_clone.load(Ordering::Relaxed) {
+                send_log(&sender, i18n.t(I18nKey::WorkerCancelled).to_string());
+                send_state(&sender, OperationState::Failed);
+                let _ = sender.send(WorkerMessage::Completed);
+                return;
+            }
+
+            if !send_log(
+                &sender,
+                i18n.t_args(
+                    I18nKey::RetryWorkerItem,
+                    &[
+                        ("current", &(index + 1).to_string()),
+                        ("total", &items.len().to_string()),
+                        ("item", &item.describe(&i18n)),
+                    ],
+                ),
+            ) {
+                return; // Canal cerrado
+            }
+
+            let success = match item {
+                FailedItem::Step(step) => execute_step(&sender, step, &config, &supervisor).await,
+                FailedItem::Path(path) => {
+                    let config = config.clone();
+                    let target = path.clone();
+                    let result =
+                        tokio::task::spawn_blocking(move || cleanup::remove_path(&config, &target))
+                            .await;
+                    match result {
+                        Ok(Ok(())) => true,
+                        Ok(Err(e)) => {
+                            send_log(
+                                &sender,
+                                i18n.t_args(
+                                    I18nKey::WorkerRemoveError,
+                                    &[
+                                        ("path", &path.display().to_string()),
+                                        ("error", &e.to_string()),
+                                    ],
+                                ),
+                            );
+                            false
+                        }
+                        Err(_) => false,
+                    }
+                }
+            };
+            if !success {
+                failed += 1;
+                send_failed(&sender, item.clone());
+            }
+        }
+
+        if failed == 0 {
+            send_log(
+                &sender,
+                format!("=== {} ===", i18n.t(I18nKey::RetryWorkerCompleted)),
+            );
+            send_state(&sender, OperationState::Completed);
+        } else {
+            send_error(
+                &sender,
+                i18n.t_args(
+                    I18nKey::RetryWorkerFailed,
+                    &[
+                        ("count", &failed.to_string()),
+                        ("total", &items.len().to_string()),
+                    ],
+                ),
+            );
+            send_state(&sender, OperationState::Failed);
+        }
+
+        let _ = sender.send(WorkerMessage::Completed);
+    });
+
+    WorkerHandle {
+        receiver,
+        task_handle: Some(task_handle),
+        cancel_flag,
+        skip_flag,
     }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::i18n::Language;
+
+    #[test]
+    fn test_repair_worker_with_fake_runner() {
+        use crate::platform::FakeRunner;
+        use crate::repair::{DismResult, SfcResult};
+
+        let (runner, _guard) = FakeRunner::default()
+            .respond(
+                "cmd /C DISM",
+                0,
+                "[==========================100.0%==========================]\r\n\
+                 The restore operation completed successfully.\r\n",
+            )
+            .respond(
+                "cmd /C sfc",
+                0,
+                "Windows Resource Protection did not find any integrity violations.\r\n",
+            )
+            .install();
+
+        let handle = spawn_repair_worker(ExecutorConfig::default(), I18n::new(Language::Spanish));
+        let messages: Vec<WorkerMessage> = handle.receiver.iter().collect();
+
+        assert!(runner.ran("cmd /C DISM /Online /Cleanup-Image /RestoreHealth"));
+        assert!(runner.ran("cmd /C sfc /scannow"));
+        let stats = messages
+            .iter()
+            .rev()
+            .find_map(|message| match message {
+                WorkerMessage::StatsUpdate(OperationStats::Repair(stats)) => Some(stats),
+                _ => None,
+            })
+            .unwrap();
+        assert_eq!(stats.dism, DismResult::Completed);
+        assert_eq!(stats.sfc, SfcResult::Healthy);
+        assert!(messages.iter().any(|message| matches!(
+            message,
+            WorkerMessage::StateChange(OperationState::Completed)
+        )));
+    }
 
     #[test]
     fn test_send_functions_dont_panic() {
@@ -382,26 +1337,218 @@ mod tests {
         let cancel_flag = Arc::new(AtomicBool::new(false));
         let handle = WorkerHandle {
             receiver,
-            thread_handle: None,
+            task_handle: None,
             cancel_flag,
+            skip_flag: Arc::new(AtomicBool::new(false)),
         };
 
         // Verificar que el handle se puede crear sin problemas
-        assert!(handle.thread_handle.is_none());
+        assert!(handle.task_handle.is_none());
 
         // Enviar un mensaje y verificar que se puede recibir
         sender.send(WorkerMessage::Log("test".to_string())).unwrap();
         assert!(handle.receiver.try_recv().is_ok());
     }
 
+    #[test]
+    fn test_steps_worker_reports_progress_and_failure() {
+        const STEPS: &[WorkerStep] = &[
+            WorkerStep {
+                description: I18nKey::FontCacheStopService,
+                action: StepAction::Command {
+                    command: "win_opt_comando_inexistente",
+                    args: &[],
+                },
+                timeout: Duration::from_secs(60),
+            },
+            WorkerStep {
+                description: I18nKey::FontCacheStartService,
+                action: StepAction::Command {
+                    command: "win_opt_comando_inexistente",
+                    args: &[],
+                },
+                timeout: Duration::from_secs(60),
+            },
+        ];
+
+        // Los logs del worker salen en el idioma de la interfaz
+        let handle = spawn_steps_worker(
+            I18nKey::FontCacheTitle,
+            STEPS,
+            Config::default(),
+            I18n::new(Language::English),
+        );
+        let messages: Vec<WorkerMessage> = handle.receiver.iter().collect();
+
+        let logs: Vec<&str> = messages
+            .iter()
+            .filter_map(|m| match m {
+                WorkerMessage::Log(log) => Some(log.as_str()),
+                _ => None,
+            })
+            .collect();
+        assert!(logs.contains(&"Step 1/2: Stopping the FontCache service..."));
+        assert!(logs.contains(&"Step 2/2: Starting the FontCache service..."));
+        // Los dos pasos fallidos se anotan para reintentarlos
+        let failed = messages
+            .iter()
+            .filter(|m| matches!(m, WorkerMessage::ItemFailed(FailedItem::Step(_))))
+            .count();
+        assert_eq!(failed, 2);
+        assert!(matches!(
+            messages[messages.len() - 2],
+            WorkerMessage::StateChange(OperationState::Failed)
+        ));
+        assert!(matches!(messages.last(), Some(WorkerMessage::Completed)));
+    }
+
+    #[test]
+    fn test_worker_panic_becomes_an_error() {
+        let (sender, receiver) = mpsc::channel();
+        let handle = spawn_task(sender, I18n::new(Language::Spanish), |sender| async move {
+            let _ = sender.send(WorkerMessage::StateChange(OperationState::Running));
+            panic!("fallo simulado");
+        });
+        runtime().block_on(handle).unwrap();
+
+        let messages: Vec<WorkerMessage> = receiver.iter().collect();
+        assert!(messages.iter().any(|m| matches!(
+            m,
+            WorkerMessage::Error(error) if error.contains("fallo simulado")
+        )));
+        assert!(matches!(
+            messages[messages.len() - 2],
+            WorkerMessage::StateChange(OperationState::Failed)
+        ));
+        assert!(matches!(messages.last(), Some(WorkerMessage::Completed)));
+    }
+
+    #[test]
+    fn test_forward_lines_decodes_lossy() {
+        let (sender, receiver) = mpsc::channel();
+        // Salida en página OEM (0xA2 = 'ó' en CP850) con líneas vacías
+        let output: &[u8] = b"Versi\xA2n 10.0\r\n\r\nListo\n";
+
+        let activity = Arc::new(Mutex::new(Instant::now()));
+        assert!(runtime().block_on(forward_lines(sender, output, "ERROR: ", activity, None)));
+
+        let logs: Vec<String> = receiver
+            .iter()
+            .filter_map(|m| match m {
+                WorkerMessage::Log(log) => Some(log),
+                _ => None,
+            })
+            .collect();
+        assert_eq!(logs, vec!["ERROR: Versi\u{FFFD}n 10.0", "ERROR: Listo"]);
+    }
+
+    #[test]
+    fn test_forward_lines_keeps_transcript_without_progress() {
+        let (sender, _receiver) = mpsc::channel();
+        let output: &[u8] = b"Comprobando
+[====  40.0% ]
+Listo
+";
+
+        let activity = Arc::new(Mutex::new(Instant::now()));
+        let transcript = Arc::new(Mutex::new(Vec::new()));
+        assert!(runtime().block_on(forward_lines(
+            sender,
+            output,
+            "",
+            activity,
+            Some(transcript.clone())
+        )));
+        assert_eq!(*transcript.lock().unwrap(), vec!["Comprobando", "Listo"]);
+    }
+
+    #[test]
+    fn test_decoder_splits_carriage_returns() {
+        let mut decoder = OutputDecoder::default();
+        assert_eq!(
+            decoder.feed(b"[==   10.0%   ]\r[===="),
+            vec!["[==   10.0%   ]"]
+        );
+        assert_eq!(decoder.feed(b" 20.0% ]\r\n"), vec!["[==== 20.0% ]", ""]);
+        assert_eq!(decoder.feed(b"fin"), Vec::<String>::new());
+        assert_eq!(decoder.finish(), Some("fin".to_string()));
+    }
+
+    #[test]
+    fn test_executor_config_defaults() {
+        let limits: ExecutorConfig =
+            toml::from_str("stall_minutes = 0\nsfc_minutes = 120").unwrap();
+        assert_eq!(limits.sfc_minutes, 120);
+        assert_eq!(limits.dism_minutes, 90);
+        // Un valor de 0 no desactiva la vigilancia: se usa un minuto
+        assert_eq!(minutes(limits.stall_minutes), Duration::from_secs(60));
+    }
+
+    #[test]
+    fn test_parse_progress() {
+        assert_eq!(
+            parse_dism_progress("[=====                      10.0%                          ]"),
+            Some(10.0)
+        );
+        assert_eq!(
+            parse_dism_progress("[==========================100.0%==========================]"),
+            Some(100.0)
+        );
+        assert_eq!(parse_dism_progress("Deployment Image Servicing"), None);
+        assert_eq!(parse_sfc_progress("Verification 45% complete."), Some(45.0));
+        assert_eq!(parse_sfc_progress("Comprobación 7% completada."), Some(7.0));
+        assert_eq!(parse_progress("[==  45,5% ]"), Some(45.5));
+        assert_eq!(parse_progress("Versión: 10.0.22631.1"), None);
+    }
+
+    #[test]
+    fn test_decoder_utf16_output() {
+        let bytes: Vec<u8> = "\u{FEFF}Verificación 45%\r\nOK"
+            .encode_utf16()
+            .flat_map(u16::to_le_bytes)
+            .collect();
+        let mut decoder = OutputDecoder::default();
+        // Un bloque que corta un carácter UTF-16 por la mitad
+        let mut lines = decoder.feed(&bytes[..7]);
+        lines.extend(decoder.feed(&bytes[7..]));
+        assert_eq!(lines, vec!["Verificación 45%", ""]);
+        assert_eq!(decoder.finish(), Some("OK".to_string()));
+    }
+
+    #[test]
+    fn test_cancel_kills_running_command() {
+        let (command, args) = if cfg!(windows) {
+            ("ping", vec!["-n", "30", "127.0.0.1"])
+        } else {
+            ("sleep", vec!["30"])
+        };
+        let handle = spawn_command_worker(
+            command.to_string(),
+            args.into_iter().map(str::to_string).collect(),
+            "Espera".to_string(),
+            ExecutorConfig::default(),
+            I18n::new(Language::Spanish),
+        );
+
+        std::thread::sleep(Duration::from_millis(500));
+        let started = Instant::now();
+        handle.cancel_flag.store(true, Ordering::Relaxed);
+        let messages: Vec<WorkerMessage> = handle.receiver.iter().collect();
+
+        // El comando se termina sin esperar a los 30 segundos
+        assert!(started.elapsed() < Duration::from_secs(10));
+        assert!(matches!(messages.last(), Some(WorkerMessage::Completed)));
+    }
+
     #[test]
     fn test_worker_handle_cancellation() {
         let (_, receiver) = mpsc::channel();
         let cancel_flag = Arc::new(AtomicBool::new(false));
         let handle = WorkerHandle {
             receiver,
-            thread_handle: None,
+            task_handle: None,
             cancel_flag: cancel_flag.clone(),
+            skip_flag: Arc::new(AtomicBool::new(false)),
         };
 
         // Verificar que el flag de cancelación se puede establecer
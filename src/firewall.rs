@@ -0,0 +1,227 @@
+//! Estado del Firewall de Windows
+//!
+//! Lee el estado de cada perfil con `netsh advfirewall show allprofiles state`
+//! y permite volver a activar el firewall por perfil. Tener los tres perfiles
+//! desactivados se señala como configuración insegura.
+
+use crate::i18n::I18nKey;
+use crate::platform;
+use crate::types::OperationState;
+use crate::utils::is_admin;
+use crate::{log_debug, log_error, log_info, log_warn};
+
+/// Perfil del firewall
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum FirewallProfile {
+    Domain,
+    Private,
+    Public,
+}
+
+impl FirewallProfile {
+    /// Perfiles en el orden en que los muestra `netsh`
+    pub const ALL: [FirewallProfile; 3] = [
+        FirewallProfile::Domain,
+        FirewallProfile::Private,
+        FirewallProfile::Public,
+    ];
+
+    /// Nombre del perfil para `netsh advfirewall set`
+    fn netsh_name(self) -> &'static str {
+        match self {
+            FirewallProfile::Domain => "domainprofile",
+            FirewallProfile::Private => "privateprofile",
+            FirewallProfile::Public => "publicprofile",
+        }
+    }
+
+    /// Clave de traducción del perfil
+    pub fn label_key(self) -> I18nKey {
+        match self {
+            FirewallProfile::Domain => I18nKey::FirewallDomain,
+            FirewallProfile::Private => I18nKey::FirewallPrivate,
+            FirewallProfile::Public => I18nKey::FirewallPublic,
+        }
+    }
+}
+
+/// Estado de un perfil del firewall
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ProfileState {
+    pub profile: FirewallProfile,
+    /// `None` si no se pudo interpretar el estado
+    pub enabled: Option<bool>,
+}
+
+/// Indica si todos los perfiles conocidos están desactivados
+pub fn all_disabled(states: &[ProfileState]) -> bool {
+    !states.is_empty() && states.iter().all(|s| s.enabled == Some(false))
+}
+
+/// Lee el estado de los perfiles del firewall
+pub fn execute_firewall_status(app: &mut crate::app::App) {
+    app.operation_state = OperationState::Running;
+    app.firewall_profiles.clear();
+    log_info!(app, "🧱 Consultando el estado del Firewall de Windows...");
+
+    let result = platform::command("netsh")
+        .args(["advfirewall", "show", "allprofiles", "state"])
+        .output();
+
+    match result {
+        Ok(output) if output.status.success() => {
+            let stdout = String::from_utf8_lossy(&output.stdout);
+            log_debug!(app, "{}", stdout.trim());
+            app.firewall_profiles = parse_profile_states(&stdout);
+        }
+        Ok(_) => {
+            log_error!(app, "❌ No se pudo consultar el estado del firewall");
+            app.operation_state = OperationState::Failed;
+            return;
+        }
+        Err(e) => {
+            log_error!(app, "❌ Error: {}", e);
+            app.operation_state = OperationState::Failed;
+            return;
+        }
+    }
+
+    let disabled = app
+        .firewall_profiles
+        .iter()
+        .filter(|s| s.enabled == Some(false))
+        .count();
+    if all_disabled(&app.firewall_profiles) {
+        log_warn!(
+            app,
+            "⚠️  El firewall está desactivado en todos los perfiles: el equipo está expuesto"
+        );
+    } else if disabled > 0 {
+        log_warn!(
+            app,
+            "⚠️  Perfiles con el firewall desactivado: {}",
+            disabled
+        );
+    } else {
+        log_info!(app, "✅ El firewall está activo en todos los perfiles");
+    }
+    if disabled > 0 {
+        log_info!(
+            app,
+            "💡 Pulsa E para activar el perfil seleccionado o A para activarlos todos"
+        );
+    }
+
+    app.operation_state = OperationState::Completed;
+}
+
+/// Activa el firewall en los perfiles indicados y vuelve a leer el estado
+pub fn execute_enable_profiles(app: &mut crate::app::App, profiles: &[FirewallProfile]) {
+    log_info!(app, "");
+    if !is_admin() {
+        log_error!(
+            app,
+            "⛔ ERROR: Esta operación requiere permisos de Administrador"
+        );
+        return;
+    }
+
+    for profile in profiles {
+        let name = profile.netsh_name();
+        log_info!(app, "🔧 Activando firewall ({})...", name);
+        match platform::command("netsh")
+            .args(["advfirewall", "set", name, "state", "on"])
+            .output()
+        {
+            Ok(output) if output.status.success() => log_info!(app, "✅ {} activado", name),
+            Ok(output) => log_warn!(
+                app,
+                "⚠️  {}: {}",
+                name,
+                String::from_utf8_lossy(&output.stdout).trim()
+            ),
+            Err(e) => log_error!(app, "❌ Error: {}", e),
+        }
+    }
+
+    execute_firewall_status(app);
+}
+
+/// Parsea la salida de `netsh advfirewall show allprofiles state`
+///
+/// `netsh` traduce los títulos de los perfiles según el idioma del sistema,
+/// así que se asignan por orden de aparición (dominio, privado, público)
+/// tomando el valor de la primera línea tras cada separador `----`.
+fn parse_profile_states(output: &str) -> Vec<ProfileState> {
+    let mut states = Vec::new();
+    let mut lines = output.lines();
+
+    while let Some(line) = lines.next() {
+        if !line.trim_start().starts_with("---") {
+            continue;
+        }
+        let Some(profile) = FirewallProfile::ALL.get(states.len()).copied() else {
+            break;
+        };
+        let enabled = lines
+            .next()
+            .and_then(|state| state.split_whitespace().last())
+            .and_then(parse_state_value);
+        states.push(ProfileState { profile, enabled });
+    }
+
+    states
+}
+
+/// Interpreta el valor de estado (`ON`/`OFF` o su traducción)
+fn parse_state_value(value: &str) -> Option<bool> {
+    let value = value.to_uppercase();
+    if value == "ON" || value.starts_with("ACTIV") {
+        Some(true)
+    } else if value == "OFF" || value.starts_with("DESACTIV") || value.starts_with("INACTIV") {
+        Some(false)
+    } else {
+        None
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const SAMPLE: &str = "\r\nDomain Profile Settings: \r\n\
+        ----------------------------------------------------------------------\r\n\
+        State                                 ON\r\n\r\n\
+        Private Profile Settings: \r\n\
+        ----------------------------------------------------------------------\r\n\
+        State                                 OFF\r\n\r\n\
+        Public Profile Settings: \r\n\
+        ----------------------------------------------------------------------\r\n\
+        State                                 ON\r\n\
+        Ok.\r\n";
+
+    #[test]
+    fn test_parse_profile_states() {
+        let states = parse_profile_states(SAMPLE);
+        assert_eq!(states.len(), 3);
+        assert_eq!(states[0].profile, FirewallProfile::Domain);
+        assert_eq!(states[0].enabled, Some(true));
+        assert_eq!(states[1].enabled, Some(false));
+        assert_eq!(states[2].profile, FirewallProfile::Public);
+        assert!(!all_disabled(&states));
+    }
+
+    #[test]
+    fn test_all_disabled() {
+        let states = parse_profile_states(&SAMPLE.replace(" ON", " OFF"));
+        assert!(all_disabled(&states));
+        assert!(!all_disabled(&[]));
+    }
+
+    #[test]
+    fn test_parse_state_value_localized() {
+        assert_eq!(parse_state_value("ACTIVADO"), Some(true));
+        assert_eq!(parse_state_value("desactivado"), Some(false));
+        assert_eq!(parse_state_value("???"), None);
+    }
+}
@@ -0,0 +1,183 @@
+//! Pie de las vistas de operación
+//!
+//! El pie muestra solo los atajos que funcionan en el estado actual de la
+//! vista: mientras la operación se ejecuta, cancelarla (y terminar el paso
+//! bloqueado); al terminar, exportar el registro, reintentar los elementos
+//! fallidos y, si falló, repetirla entera.
+//! Los atajos propios de la vista solo aparecen cuando no hay nada en
+//! marcha. Los atajos generales y los del panel de logs están en la ayuda.
+
+use crate::i18n::I18nKey;
+use crate::types::OperationState;
+
+/// Estado de la vista del que dependen los atajos del pie
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct FooterContext {
+    pub state: OperationState,
+    /// La operación corre en segundo plano y se puede cancelar
+    pub cancellable: bool,
+    /// El paso actual no responde y se puede terminar
+    pub stalled: bool,
+    /// La vista espera a que se confirme el aviso de reinicio pendiente
+    pub awaiting_confirm: bool,
+    /// La vista ejecuta una operación que se puede repetir
+    pub retryable: bool,
+    /// Elementos fallidos que se pueden reintentar
+    pub failed_items: usize,
+    /// El panel tiene logs que exportar
+    pub has_logs: bool,
+    /// Hay una búsqueda activa en los logs
+    pub searching: bool,
+}
+
+/// Atajos del pie de una vista de operación
+///
+/// `view_keys` son los atajos propios de la vista, sin los de
+/// desplazamiento ni el de volver, que se añaden siempre.
+pub fn operation_keys(
+    context: &FooterContext,
+    view_keys: &[(&'static str, I18nKey)],
+) -> Vec<(&'static str, I18nKey)> {
+    let mut keys = Vec::new();
+    let running = matches!(
+        context.state,
+        OperationState::Running | OperationState::Starting
+    );
+    if context.awaiting_confirm {
+        keys.push(("Enter", I18nKey::FooterContinue));
+    } else if running {
+        if context.stalled {
+            keys.push(("T", I18nKey::FooterSkipStep));
+        }
+        if context.cancellable {
+            keys.push(("X", I18nKey::FooterCancel));
+        }
+        keys.push(("F", I18nKey::FooterFollow));
+    } else {
+        keys.extend_from_slice(view_keys);
+        let finished = matches!(
+            context.state,
+            OperationState::Completed | OperationState::Failed
+        );
+        if finished && context.failed_items > 0 {
+            keys.push(("I", I18nKey::FooterRetryFailed));
+        }
+        if context.state == OperationState::Failed && context.retryable {
+            keys.push(("Enter", I18nKey::FooterRetry));
+        }
+        if finished && context.retryable && context.has_logs {
+            keys.push(("E", I18nKey::FooterExport));
+        }
+    }
+
+    if context.searching {
+        keys.push(("n/N", I18nKey::HelpNextMatch));
+        keys.push(("Esc", I18nKey::FooterClear));
+        keys.push(("Q", I18nKey::FooterBack));
+    } else {
+        keys.push(("↑↓", I18nKey::FooterScroll));
+        keys.push(("Q/Esc", I18nKey::FooterBack));
+    }
+    keys
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn context(state: OperationState) -> FooterContext {
+        FooterContext {
+            state,
+            cancellable: false,
+            stalled: false,
+            awaiting_confirm: false,
+            retryable: true,
+            failed_items: 0,
+            has_logs: true,
+            searching: false,
+        }
+    }
+
+    fn shown(keys: &[(&str, I18nKey)]) -> Vec<String> {
+        keys.iter().map(|(key, _)| key.to_string()).collect()
+    }
+
+    const VIEW_KEYS: &[(&str, I18nKey)] = crate::app::SERVICES_FOOTER_KEYS;
+
+    #[test]
+    fn test_running_operations_can_only_be_cancelled() {
+        let mut running = context(OperationState::Running);
+        running.cancellable = true;
+        assert_eq!(
+            shown(&operation_keys(&running, VIEW_KEYS)),
+            ["X", "F", "↑↓", "Q/Esc"]
+        );
+
+        running.stalled = true;
+        assert_eq!(
+            shown(&operation_keys(&running, VIEW_KEYS)),
+            ["T", "X", "F", "↑↓", "Q/Esc"]
+        );
+
+        // Las operaciones que no corren en segundo plano no se cancelan
+        let blocking = context(OperationState::Starting);
+        assert_eq!(
+            shown(&operation_keys(&blocking, VIEW_KEYS)),
+            ["F", "↑↓", "Q/Esc"]
+        );
+    }
+
+    #[test]
+    fn test_finished_operations_offer_export_and_retry() {
+        assert_eq!(
+            shown(&operation_keys(&context(OperationState::Idle), VIEW_KEYS)),
+            ["R", "↑↓", "Q/Esc"]
+        );
+        assert_eq!(
+            shown(&operation_keys(
+                &context(OperationState::Completed),
+                VIEW_KEYS
+            )),
+            ["R", "E", "↑↓", "Q/Esc"]
+        );
+        assert_eq!(
+            shown(&operation_keys(&context(OperationState::Failed), VIEW_KEYS)),
+            ["R", "Enter", "E", "↑↓", "Q/Esc"]
+        );
+
+        // Con elementos fallidos se pueden reintentar solo esos, sin chocar
+        // con los atajos de la vista
+        let mut partial = context(OperationState::Completed);
+        partial.failed_items = 3;
+        assert_eq!(
+            shown(&operation_keys(&partial, VIEW_KEYS)),
+            ["R", "I", "E", "↑↓", "Q/Esc"]
+        );
+        partial.state = OperationState::Failed;
+        let keys = shown(&operation_keys(&partial, VIEW_KEYS));
+        let unique: std::collections::HashSet<_> = keys.iter().collect();
+        assert_eq!(unique.len(), keys.len());
+
+        // Sin logs no hay nada que exportar
+        let mut empty = context(OperationState::Completed);
+        empty.has_logs = false;
+        assert_eq!(shown(&operation_keys(&empty, &[])), ["↑↓", "Q/Esc"]);
+    }
+
+    #[test]
+    fn test_confirmation_and_search_keys() {
+        let mut waiting = context(OperationState::Idle);
+        waiting.awaiting_confirm = true;
+        assert_eq!(
+            shown(&operation_keys(&waiting, VIEW_KEYS)),
+            ["Enter", "↑↓", "Q/Esc"]
+        );
+
+        let mut searching = context(OperationState::Completed);
+        searching.searching = true;
+        assert_eq!(
+            shown(&operation_keys(&searching, &[])),
+            ["E", "n/N", "Esc", "Q"]
+        );
+    }
+}
@@ -0,0 +1,65 @@
+//! Ayuda de atajos de teclado
+//!
+//! Con `?` (o F1 mientras se escribe texto) se muestra una ventana con los
+//! atajos generales, los del panel de logs y los propios de la vista actual,
+//! que son los mismos que muestra su pie.
+
+use crate::i18n::I18nKey;
+use ratatui::layout::Rect;
+
+/// Atajos disponibles en todas las vistas
+pub const GENERAL: &[(&str, I18nKey)] = &[
+    ("↑↓ / j k", I18nKey::FooterNavigate),
+    ("Enter", I18nKey::FooterSelect),
+    ("Q/Esc", I18nKey::FooterBack),
+    ("Tab", I18nKey::FooterTheme),
+    ("L", I18nKey::FooterLanguage),
+    ("? / F1", I18nKey::FooterHelp),
+    ("Ctrl+P", I18nKey::FooterPalette),
+    ("Ctrl+R", I18nKey::FooterReboot),
+    ("Mouse", I18nKey::HelpMouse),
+];
+
+/// Atajos del panel de logs de las operaciones
+pub const LOGS: &[(&str, I18nKey)] = &[
+    ("↑↓", I18nKey::FooterScroll),
+    ("PgUp/PgDn", I18nKey::HelpPage),
+    ("Home/End", I18nKey::HelpTopBottom),
+    ("F", I18nKey::FooterFollow),
+    ("/", I18nKey::FooterSearch),
+    ("n/N", I18nKey::HelpNextMatch),
+    ("H", I18nKey::FooterTimestamps),
+    ("Y", I18nKey::FooterCopy),
+    ("X", I18nKey::FooterCancel),
+    ("T", I18nKey::FooterSkipStep),
+    ("E", I18nKey::FooterExport),
+    ("I", I18nKey::FooterRetryFailed),
+];
+
+/// Zona centrada de `width` x `height` dentro de `area` (recortada si no cabe)
+pub fn centered(area: Rect, width: u16, height: u16) -> Rect {
+    let width = width.min(area.width);
+    let height = height.min(area.height);
+    Rect {
+        x: area.x + (area.width - width) / 2,
+        y: area.y + (area.height - height) / 2,
+        width,
+        height,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_centered_fits_inside_area() {
+        let area = Rect::new(0, 0, 100, 40);
+        assert_eq!(centered(area, 60, 20), Rect::new(20, 10, 60, 20));
+        // Una terminal pequeña recorta la ventana
+        assert_eq!(
+            centered(Rect::new(2, 1, 40, 10), 60, 20),
+            Rect::new(2, 1, 40, 10)
+        );
+    }
+}
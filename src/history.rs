@@ -0,0 +1,74 @@
+//! Historial de ejecuciones
+//!
+//! Guarda en `history.tsv` (carpeta de configuración) una línea
+//! `yyyy-MM-dd HH:mm<TAB>operación` por cada limpieza u optimización, con la
+//! hora en UTC, para poder relacionarlas con otras métricas como el tiempo de
+//! arranque.
+
+use crate::config::Config;
+use crate::utils::now_utc_iso;
+use std::fs::{self, OpenOptions};
+use std::io::Write;
+use std::path::PathBuf;
+
+/// Ejecución registrada
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RunRecord {
+    /// Fecha y hora UTC (`yyyy-MM-dd HH:mm`)
+    pub timestamp: String,
+    /// Identificador de la operación (`clean`, `optimize`...)
+    pub operation: String,
+}
+
+/// Ruta del archivo de historial
+fn history_file() -> std::io::Result<PathBuf> {
+    Ok(Config::get_config_dir()?.join("history.tsv"))
+}
+
+/// Añade una ejecución al historial
+///
+/// Los errores solo se registran: el historial nunca debe impedir una operación.
+pub fn record_run(operation: &str) {
+    let result = history_file().and_then(|path| {
+        let mut file = OpenOptions::new().create(true).append(true).open(path)?;
+        writeln!(file, "{}\t{}", now_utc_iso(), operation)
+    });
+    if let Err(e) = result {
+        tracing::warn!("No se pudo guardar el historial: {}", e);
+    }
+}
+
+/// Lee el historial de ejecuciones (vacío si no existe)
+pub fn load_runs() -> Vec<RunRecord> {
+    history_file()
+        .and_then(fs::read_to_string)
+        .map(|contents| parse_history(&contents))
+        .unwrap_or_default()
+}
+
+/// Parsea el contenido de `history.tsv`, ignorando líneas mal formadas
+fn parse_history(contents: &str) -> Vec<RunRecord> {
+    contents
+        .lines()
+        .filter_map(|line| {
+            let (timestamp, operation) = line.split_once('\t')?;
+            Some(RunRecord {
+                timestamp: timestamp.to_string(),
+                operation: operation.trim().to_string(),
+            })
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_history() {
+        let runs = parse_history("2026-10-01 08:00\tclean\r\nbasura\n2026-10-02 09:30\toptimize\n");
+        assert_eq!(runs.len(), 2);
+        assert_eq!(runs[0].timestamp, "2026-10-01 08:00");
+        assert_eq!(runs[1].operation, "optimize");
+    }
+}
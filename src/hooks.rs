@@ -0,0 +1,209 @@
+//! Hooks de las operaciones
+//!
+//! La sección `[hooks]` de la configuración asocia comandos a las
+//! operaciones, usando los identificadores de [`crate::operation`]:
+//!
+//! ```toml
+//! [hooks]
+//! pre_clean = "robocopy C:\\Datos D:\\Copia /MIR"
+//! post_repair = "echo %WIN_OPT_RESULT% >> C:\\logs\\repair.txt"
+//! ```
+//!
+//! `pre_<operación>` se ejecuta antes de la operación y, si falla, la
+//! operación no se ejecuta. `post_<operación>` se ejecuta al terminar (para
+//! las tareas en segundo plano, cuando termina la tarea). La salida de los
+//! comandos se añade a los logs.
+
+use crate::app::App;
+use crate::logger::{self, LogLevel};
+use crate::operation;
+use crate::simulate;
+use crate::types::View;
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+use std::io;
+use std::process::{Command, Output, Stdio};
+
+/// Momento en que se ejecuta un hook
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum HookStage {
+    /// Antes de la operación
+    Pre,
+    /// Después de la operación
+    Post,
+}
+
+impl HookStage {
+    /// Prefijo de las claves de configuración
+    fn prefix(self) -> &'static str {
+        match self {
+            HookStage::Pre => "pre",
+            HookStage::Post => "post",
+        }
+    }
+}
+
+/// Configuración de los hooks (`pre_<operación>` / `post_<operación>`)
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(transparent)]
+pub struct HooksConfig {
+    commands: BTreeMap<String, String>,
+}
+
+impl HooksConfig {
+    /// Comando configurado para una operación, si lo hay
+    pub fn command(&self, stage: HookStage, operation: &str) -> Option<&str> {
+        self.commands
+            .get(&format!("{}_{}", stage.prefix(), operation))
+            .map(|command| command.trim())
+            .filter(|command| !command.is_empty())
+    }
+
+    /// Claves que no corresponden a `pre_`/`post_` de una operación conocida
+    pub fn unknown_keys(&self) -> impl Iterator<Item = &str> {
+        self.commands.keys().map(String::as_str).filter(|key| {
+            let operation = key
+                .strip_prefix("pre_")
+                .or_else(|| key.strip_prefix("post_"));
+            operation.is_none_or(|name| operation::find(name).is_none())
+        })
+    }
+}
+
+/// Ejecuta el hook `pre_` de la operación de una vista
+///
+/// Devuelve `false` si el hook falló y la operación no debe ejecutarse.
+pub fn run_pre(app: &mut App, view: View) -> bool {
+    run(app, HookStage::Pre, view, None, true)
+}
+
+/// Ejecuta el hook `post_` de la operación de una vista
+///
+/// Con `show` a `false` (la tarea terminó mientras se mostraba otra vista)
+/// la salida solo se escribe en el archivo de log.
+pub fn run_post(app: &mut App, view: View, success: bool, show: bool) {
+    run(app, HookStage::Post, view, Some(success), show);
+}
+
+/// Ejecuta un hook y registra su salida; devuelve si terminó correctamente
+fn run(app: &mut App, stage: HookStage, view: View, success: Option<bool>, show: bool) -> bool {
+    let Some(name) = operation::name_for_view(view) else {
+        return true;
+    };
+    let Some(command) = app.config.hooks.command(stage, name).map(str::to_string) else {
+        return true;
+    };
+
+    let key = format!("{}_{}", stage.prefix(), name);
+    let mut log = |level: LogLevel, message: String| {
+        logger::log(if show { Some(&mut *app) } else { None }, level, message)
+    };
+
+    if simulate::is_enabled() {
+        log(
+            LogLevel::Info,
+            format!("🪝 Hook {} omitido en la simulación: {}", key, command),
+        );
+        return true;
+    }
+
+    log(LogLevel::Info, format!("🪝 Hook {}: {}", key, command));
+    match run_command(&command, name, success) {
+        Ok(output) => {
+            let text = format!(
+                "{}{}",
+                String::from_utf8_lossy(&output.stdout),
+                String::from_utf8_lossy(&output.stderr)
+            );
+            for line in text.lines().filter(|line| !line.trim().is_empty()) {
+                log(LogLevel::Info, format!("   {}", line.trim_end()));
+            }
+            if output.status.success() {
+                true
+            } else {
+                log(
+                    LogLevel::Warning,
+                    format!("⚠️  El hook {} terminó con {}", key, output.status),
+                );
+                false
+            }
+        }
+        Err(e) => {
+            log(
+                LogLevel::Error,
+                format!("❌ No se pudo ejecutar el hook {}: {}", key, e),
+            );
+            false
+        }
+    }
+}
+
+/// Ejecuta un comando con el intérprete del sistema
+///
+/// El comando recibe `WIN_OPT_OPERATION` con el identificador de la
+/// operación y, en los hooks `post_`, `WIN_OPT_RESULT` (`success`/`failed`).
+fn run_command(command: &str, operation: &str, success: Option<bool>) -> io::Result<Output> {
+    let mut process = shell_command(command);
+    process
+        .env("WIN_OPT_OPERATION", operation)
+        .stdin(Stdio::null());
+    if let Some(success) = success {
+        process.env("WIN_OPT_RESULT", if success { "success" } else { "failed" });
+    }
+    process.output()
+}
+
+#[cfg(windows)]
+fn shell_command(command: &str) -> Command {
+    use std::os::windows::process::CommandExt;
+
+    // El comando se pasa tal cual para respetar las comillas del usuario
+    let mut process = Command::new("cmd");
+    process.arg("/C").raw_arg(command);
+    process
+}
+
+#[cfg(not(windows))]
+fn shell_command(command: &str) -> Command {
+    let mut process = Command::new("sh");
+    process.arg("-c").arg(command);
+    process
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_hooks_config_parses_flat_keys() {
+        let config: HooksConfig =
+            toml::from_str("pre_clean = \"echo antes\"\npost_repair = \"  \"\n").unwrap();
+        assert_eq!(config.command(HookStage::Pre, "clean"), Some("echo antes"));
+        assert_eq!(config.command(HookStage::Post, "clean"), None);
+        // Un comando vacío equivale a no tener hook
+        assert_eq!(config.command(HookStage::Post, "repair"), None);
+        assert_eq!(config.unknown_keys().count(), 0);
+
+        let config: HooksConfig =
+            toml::from_str("pre_clen = \"echo\"\nafter_clean = \"echo\"\n").unwrap();
+        assert_eq!(
+            config.unknown_keys().collect::<Vec<_>>(),
+            vec!["after_clean", "pre_clen"]
+        );
+    }
+
+    #[test]
+    fn test_run_command_passes_operation_and_result() {
+        let command = if cfg!(windows) {
+            "echo %WIN_OPT_OPERATION% %WIN_OPT_RESULT%"
+        } else {
+            "echo $WIN_OPT_OPERATION $WIN_OPT_RESULT"
+        };
+        let output = run_command(command, "clean", Some(true)).unwrap();
+        assert!(output.status.success());
+        assert_eq!(
+            String::from_utf8_lossy(&output.stdout).trim(),
+            "clean success"
+        );
+    }
+}
@@ -0,0 +1,402 @@
+//! Editor del archivo hosts
+//!
+//! Carga `%SystemRoot%\System32\drivers\etc\hosts` en un editor de texto
+//! sencillo con resaltado de comentarios e IPs. Antes de guardar se crea una
+//! copia de seguridad diaria junto al archivo y, tras guardar, se vacía la
+//! caché DNS para que los cambios se apliquen de inmediato.
+
+use crate::platform;
+use crate::utils::today_iso;
+use crate::{log_error, log_info, log_warn};
+use std::fs;
+use std::io;
+use std::net::IpAddr;
+use std::path::PathBuf;
+
+/// Contenido por defecto del archivo hosts de Windows
+pub const DEFAULT_HOSTS: &str = "# Copyright (c) 1993-2009 Microsoft Corp.
+#
+# This is a sample HOSTS file used by Microsoft TCP/IP for Windows.
+#
+# This file contains the mappings of IP addresses to host names. Each
+# entry should be kept on an individual line. The IP address should
+# be placed in the first column followed by the corresponding host name.
+# The IP address and the host name should be separated by at least one
+# space.
+#
+# Additionally, comments (such as these) may be inserted on individual
+# lines or following the machine name denoted by a '#' symbol.
+#
+# For example:
+#
+#      102.54.94.97     rhino.acme.com          # source server
+#       38.25.63.10     x.acme.com              # x client host
+
+# localhost name resolution is handled within DNS itself.
+#\t127.0.0.1       localhost
+#\t::1             localhost
+";
+
+/// Ruta del archivo hosts
+pub fn hosts_path() -> PathBuf {
+    platform::windows_dir()
+        .join("System32")
+        .join("drivers")
+        .join("etc")
+        .join("hosts")
+}
+
+/// Partes de una línea del archivo hosts para el resaltado
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct LineParts<'a> {
+    /// Dirección IP (con la indentación previa)
+    pub ip: &'a str,
+    /// Nombres de host que siguen a la IP
+    pub hosts: &'a str,
+    /// Comentario desde el primer `#`
+    pub comment: &'a str,
+}
+
+impl LineParts<'_> {
+    /// Indica si la IP de la entrada es válida
+    pub fn has_valid_ip(&self) -> bool {
+        self.ip.trim().parse::<IpAddr>().is_ok()
+    }
+}
+
+/// Divide una línea en IP, nombres de host y comentario
+pub fn split_line(line: &str) -> LineParts<'_> {
+    let (content, comment) = line.split_at(line.find('#').unwrap_or(line.len()));
+    let indent = content.len() - content.trim_start().len();
+    let ip_end = content[indent..]
+        .find(char::is_whitespace)
+        .map_or(content.len(), |i| indent + i);
+    let (ip, hosts) = content.split_at(ip_end);
+    LineParts { ip, hosts, comment }
+}
+
+/// Editor de texto por líneas para el archivo hosts
+#[derive(Debug, Clone)]
+pub struct HostsEditor {
+    pub lines: Vec<String>,
+    /// Línea del cursor
+    pub row: usize,
+    /// Columna del cursor (en caracteres)
+    pub col: usize,
+    /// Primera línea visible
+    pub top: usize,
+    /// Hay cambios sin guardar
+    pub modified: bool,
+    /// Se pulsó Esc con cambios sin guardar; otro Esc los descarta
+    pub discard_pending: bool,
+}
+
+impl Default for HostsEditor {
+    fn default() -> Self {
+        Self::from_text("")
+    }
+}
+
+impl HostsEditor {
+    /// Crea un editor con el texto indicado
+    pub fn from_text(text: &str) -> Self {
+        let mut lines: Vec<String> = text.lines().map(str::to_string).collect();
+        if lines.is_empty() {
+            lines.push(String::new());
+        }
+        Self {
+            lines,
+            row: 0,
+            col: 0,
+            top: 0,
+            modified: false,
+            discard_pending: false,
+        }
+    }
+
+    /// Texto completo con finales de línea de Windows
+    pub fn text(&self) -> String {
+        self.lines
+            .iter()
+            .map(|line| format!("{}\r\n", line))
+            .collect()
+    }
+
+    fn line_len(&self) -> usize {
+        self.lines[self.row].chars().count()
+    }
+
+    /// Índice en bytes de la columna del cursor
+    fn byte_index(&self) -> usize {
+        let line = &self.lines[self.row];
+        line.char_indices()
+            .nth(self.col)
+            .map_or(line.len(), |(i, _)| i)
+    }
+
+    fn touch(&mut self) {
+        self.modified = true;
+        self.discard_pending = false;
+    }
+
+    pub fn insert_char(&mut self, c: char) {
+        let index = self.byte_index();
+        self.lines[self.row].insert(index, c);
+        self.col += 1;
+        self.touch();
+    }
+
+    /// Parte la línea en la posición del cursor
+    pub fn newline(&mut self) {
+        let index = self.byte_index();
+        let rest = self.lines[self.row].split_off(index);
+        self.row += 1;
+        self.lines.insert(self.row, rest);
+        self.col = 0;
+        self.touch();
+    }
+
+    /// Borra el carácter anterior al cursor (o une con la línea anterior)
+    pub fn backspace(&mut self) {
+        if self.col > 0 {
+            self.col -= 1;
+            let index = self.byte_index();
+            self.lines[self.row].remove(index);
+        } else if self.row > 0 {
+            let line = self.lines.remove(self.row);
+            self.row -= 1;
+            self.col = self.line_len();
+            self.lines[self.row].push_str(&line);
+        } else {
+            return;
+        }
+        self.touch();
+    }
+
+    /// Borra el carácter bajo el cursor (o une con la línea siguiente)
+    pub fn delete(&mut self) {
+        if self.col < self.line_len() {
+            let index = self.byte_index();
+            self.lines[self.row].remove(index);
+        } else if self.row + 1 < self.lines.len() {
+            let next = self.lines.remove(self.row + 1);
+            self.lines[self.row].push_str(&next);
+        } else {
+            return;
+        }
+        self.touch();
+    }
+
+    pub fn move_left(&mut self) {
+        if self.col > 0 {
+            self.col -= 1;
+        } else if self.row > 0 {
+            self.row -= 1;
+            self.col = self.line_len();
+        }
+    }
+
+    pub fn move_right(&mut self) {
+        if self.col < self.line_len() {
+            self.col += 1;
+        } else if self.row + 1 < self.lines.len() {
+            self.row += 1;
+            self.col = 0;
+        }
+    }
+
+    pub fn move_up(&mut self) {
+        self.row = self.row.saturating_sub(1);
+        self.col = self.col.min(self.line_len());
+    }
+
+    pub fn move_down(&mut self) {
+        self.row = (self.row + 1).min(self.lines.len() - 1);
+        self.col = self.col.min(self.line_len());
+    }
+
+    pub fn home(&mut self) {
+        self.col = 0;
+    }
+
+    pub fn end(&mut self) {
+        self.col = self.line_len();
+    }
+
+    /// Ajusta la primera línea visible para que el cursor quede en pantalla
+    pub fn scroll_into_view(&mut self, height: usize) {
+        if self.row < self.top {
+            self.top = self.row;
+        } else if height > 0 && self.row >= self.top + height {
+            self.top = self.row + 1 - height;
+        }
+    }
+}
+
+/// Carga el archivo hosts en el editor
+pub fn execute_hosts_load(app: &mut crate::app::App) {
+    let path = hosts_path();
+    log_info!(app, "📝 Abriendo {}", path.display());
+
+    match fs::read(&path) {
+        Ok(bytes) => {
+            app.hosts_editor = HostsEditor::from_text(&String::from_utf8_lossy(&bytes));
+            let count = app.hosts_editor.lines.len();
+            log_info!(app, "✅ {} líneas cargadas", count);
+        }
+        Err(e) => {
+            app.hosts_editor = HostsEditor::default();
+            log_error!(app, "❌ No se pudo leer el archivo hosts: {}", e);
+        }
+    }
+    log_info!(
+        app,
+        "ℹ️  Ctrl+S guarda (con copia de seguridad), Ctrl+R restablece el contenido por defecto"
+    );
+}
+
+/// Guarda el editor en el archivo hosts, creando antes una copia de seguridad
+pub fn execute_hosts_save(app: &mut crate::app::App) {
+    let path = hosts_path();
+    log_info!(app, "");
+
+    match save_with_backup(&path, &app.hosts_editor.text()) {
+        Ok(backup) => {
+            app.hosts_editor.modified = false;
+            if let Some(backup) = backup {
+                log_info!(app, "💾 Copia de seguridad: {}", backup.display());
+            }
+            log_info!(app, "✅ Archivo hosts guardado");
+
+            match platform::command("ipconfig").arg("/flushdns").output() {
+                Ok(output) if output.status.success() => {
+                    log_info!(app, "✅ Caché DNS vaciada")
+                }
+                _ => log_warn!(app, "⚠️  No se pudo vaciar la caché DNS"),
+            }
+        }
+        Err(e) if e.kind() == io::ErrorKind::PermissionDenied => log_error!(
+            app,
+            "⛔ ERROR: Guardar el archivo hosts requiere permisos de Administrador"
+        ),
+        Err(e) => log_error!(app, "❌ Error guardando el archivo hosts: {}", e),
+    }
+}
+
+/// Sustituye el contenido del editor por el archivo hosts por defecto
+///
+/// El cambio no se escribe hasta guardar, así que puede descartarse con Esc.
+pub fn execute_hosts_reset(app: &mut crate::app::App) {
+    app.hosts_editor = HostsEditor::from_text(DEFAULT_HOSTS);
+    app.hosts_editor.modified = true;
+    log_info!(app, "");
+    log_warn!(
+        app,
+        "⚠️  Contenido restablecido al valor por defecto: pulsa Ctrl+S para guardarlo"
+    );
+}
+
+/// Indica si se puede cerrar el editor
+///
+/// Con cambios sin guardar, la primera pulsación solo avisa y la segunda
+/// los descarta.
+pub fn confirm_close(app: &mut crate::app::App) -> bool {
+    if app.hosts_editor.modified && !app.hosts_editor.discard_pending {
+        app.hosts_editor.discard_pending = true;
+        log_warn!(
+            app,
+            "⚠️  Hay cambios sin guardar: pulsa Esc de nuevo para descartarlos"
+        );
+        return false;
+    }
+    true
+}
+
+/// Escribe el archivo hosts guardando antes una copia del día
+///
+/// Solo se crea una copia por día para conservar el estado previo a la
+/// primera edición. Retorna la ruta de la copia si se creó.
+fn save_with_backup(path: &std::path::Path, content: &str) -> io::Result<Option<PathBuf>> {
+    let mut backup = None;
+    if path.exists() {
+        let backup_path = path.with_file_name(format!("hosts.{}.bak", today_iso()));
+        if !backup_path.exists() {
+            fs::copy(path, &backup_path)?;
+            backup = Some(backup_path);
+        }
+    }
+    fs::write(path, content)?;
+    Ok(backup)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_split_line() {
+        let parts = split_line("  127.0.0.1\tlocalhost  # loopback");
+        assert_eq!(parts.ip, "  127.0.0.1");
+        assert_eq!(parts.hosts, "\tlocalhost  ");
+        assert_eq!(parts.comment, "# loopback");
+        assert!(parts.has_valid_ip());
+
+        let comment = split_line("# solo comentario");
+        assert_eq!(comment.ip, "");
+        assert_eq!(comment.comment, "# solo comentario");
+
+        assert!(!split_line("999.1.1.1 bad.host").has_valid_ip());
+    }
+
+    #[test]
+    fn test_editor_editing() {
+        let mut editor = HostsEditor::from_text("127.0.0.1 a\r\n");
+        editor.end();
+        editor.insert_char('b');
+        editor.newline();
+        for c in "::1 c".chars() {
+            editor.insert_char(c);
+        }
+        assert_eq!(editor.lines, vec!["127.0.0.1 ab", "::1 c"]);
+        assert!(editor.modified);
+
+        editor.home();
+        editor.backspace();
+        assert_eq!(editor.lines, vec!["127.0.0.1 ab::1 c"]);
+        assert_eq!(editor.col, 12);
+
+        editor.delete();
+        assert_eq!(editor.lines[0], "127.0.0.1 ab:1 c");
+        assert_eq!(editor.text(), "127.0.0.1 ab:1 c\r\n");
+    }
+
+    #[test]
+    fn test_editor_scroll_into_view() {
+        let mut editor = HostsEditor::from_text(&"x\n".repeat(50));
+        for _ in 0..20 {
+            editor.move_down();
+        }
+        editor.scroll_into_view(10);
+        assert_eq!(editor.top, 11);
+        editor.row = 5;
+        editor.scroll_into_view(10);
+        assert_eq!(editor.top, 5);
+    }
+
+    #[test]
+    fn test_save_with_backup_once_per_day() {
+        let dir = std::env::temp_dir().join("win_opt_test_hosts");
+        let _ = fs::remove_dir_all(&dir);
+        fs::create_dir_all(&dir).unwrap();
+        let path = dir.join("hosts");
+        fs::write(&path, "original").unwrap();
+
+        let backup = save_with_backup(&path, "v1").unwrap().unwrap();
+        assert_eq!(fs::read_to_string(&backup).unwrap(), "original");
+        assert_eq!(save_with_backup(&path, "v2").unwrap(), None);
+        assert_eq!(fs::read_to_string(&backup).unwrap(), "original");
+        assert_eq!(fs::read_to_string(&path).unwrap(), "v2");
+
+        fs::remove_dir_all(&dir).unwrap();
+    }
+}
@@ -1,9 +1,25 @@
 //! Módulo de internacionalización (i18n) para win_opt
 //!
-//! Proporciona soporte para múltiples idiomas (Español e Inglés).
+//! Proporciona soporte para múltiples idiomas (español, inglés, francés,
+//! alemán y portugués de Brasil).
+//!
+//! Las traducciones están en archivos TOML (`locales/<código>.toml`) que se
+//! incluyen en el ejecutable. Un archivo con el mismo nombre en la carpeta
+//! `locales` de la configuración sustituye las claves que defina, de modo que
+//! se pueden corregir o completar traducciones sin recompilar; las claves que
+//! no defina siguen usando el texto incluido.
+//!
+//! Los textos pueden llevar marcadores `{nombre}` que se rellenan con
+//! [`I18n::t_args`]. Un texto que depende de una cantidad se escribe como
+//! tabla con sus formas de plural (`{ one = "...", other = "..." }`) y se
+//! elige la forma según el valor de `count`.
 
+use crate::config::Config;
+use crate::utils::{civil_from_days, format_bytes, parse_iso_date};
 use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
+use std::path::{Path, PathBuf};
+use std::str::FromStr;
 use std::sync::OnceLock;
 
 /// Idiomas soportados por la aplicación
@@ -14,14 +30,90 @@ pub enum Language {
     Spanish,
     /// Inglés
     English,
+    /// Francés
+    French,
+    /// Alemán
+    German,
+    /// Portugués de Brasil
+    Portuguese,
 }
 
 impl Language {
+    /// Idiomas disponibles, en el orden en que se ofrecen
+    pub const ALL: [Language; 5] = [
+        Language::Spanish,
+        Language::English,
+        Language::French,
+        Language::German,
+        Language::Portuguese,
+    ];
+
     /// Obtiene el código del idioma (para logging)
     pub fn code(&self) -> &'static str {
         match self {
             Language::Spanish => "es",
             Language::English => "en",
+            Language::French => "fr",
+            Language::German => "de",
+            Language::Portuguese => "pt-BR",
+        }
+    }
+
+    /// Traducciones incluidas en el ejecutable
+    fn embedded_locale(self) -> &'static str {
+        match self {
+            Language::Spanish => include_str!("../locales/es.toml"),
+            Language::English => include_str!("../locales/en.toml"),
+            Language::French => include_str!("../locales/fr.toml"),
+            Language::German => include_str!("../locales/de.toml"),
+            Language::Portuguese => include_str!("../locales/pt-BR.toml"),
+        }
+    }
+
+    /// Forma de plural que corresponde a una cantidad (reglas de CLDR)
+    pub fn plural(self, count: u64) -> Plural {
+        let one = match self {
+            // En francés y portugués de Brasil el 0 también va en singular
+            Language::French | Language::Portuguese => count <= 1,
+            Language::Spanish | Language::English | Language::German => count == 1,
+        };
+        if one { Plural::One } else { Plural::Other }
+    }
+
+    /// Separador decimal del idioma
+    pub fn decimal_separator(self) -> char {
+        match self {
+            Language::English => '.',
+            Language::Spanish | Language::French | Language::German | Language::Portuguese => ',',
+        }
+    }
+
+    /// Formatea un tamaño en bytes con [`format_bytes`] y el separador
+    /// decimal del idioma
+    pub fn format_size(self, bytes: u64) -> String {
+        let text = format_bytes(bytes);
+        match self.decimal_separator() {
+            '.' => text,
+            separator => text.replace('.', &separator.to_string()),
+        }
+    }
+
+    /// Formatea una fecha `yyyy-MM-dd` en el orden habitual del idioma
+    ///
+    /// Lo que siga a la fecha (por ejemplo la hora) se conserva. Un texto
+    /// que no empiece por una fecha válida se devuelve sin cambios.
+    pub fn format_date(self, date: &str) -> String {
+        let Some(days) = date.get(..10).and_then(parse_iso_date) else {
+            return date.to_string();
+        };
+        let (year, month, day) = civil_from_days(days);
+        let rest = &date[10..];
+        match self {
+            Language::English => format!("{:02}/{:02}/{:04}{}", month, day, year, rest),
+            Language::German => format!("{:02}.{:02}.{:04}{}", day, month, year, rest),
+            Language::Spanish | Language::French | Language::Portuguese => {
+                format!("{:02}/{:02}/{:04}{}", day, month, year, rest)
+            }
         }
     }
 
@@ -30,19 +122,123 @@ impl Language {
         match self {
             Language::Spanish => "Español",
             Language::English => "English",
+            Language::French => "Français",
+            Language::German => "Deutsch",
+            Language::Portuguese => "Português (Brasil)",
         }
     }
+
+    /// Idioma soportado más cercano a una etiqueta de idioma del sistema
+    ///
+    /// Solo se mira el idioma principal, así que `en-GB`, `pt_PT.UTF-8` o
+    /// `de_AT@euro` dan el idioma correspondiente aunque la región no sea la
+    /// de la traducción.
+    pub fn from_locale(locale: &str) -> Option<Language> {
+        locale
+            .split(['-', '_', '.', '@'])
+            .next()
+            .and_then(|primary| primary.parse().ok())
+    }
+
+    /// Detecta el idioma de la interfaz del sistema
+    ///
+    /// En Windows se usa el idioma de la interfaz del usuario y, si no es uno
+    /// de los soportados, las variables `LC_ALL`, `LC_MESSAGES` y `LANG`.
+    /// Retorna `None` si ninguno coincide con un idioma de la aplicación.
+    pub fn detect() -> Option<Language> {
+        system_ui_language().or_else(|| {
+            ["LC_ALL", "LC_MESSAGES", "LANG"]
+                .iter()
+                .filter_map(|name| std::env::var(name).ok())
+                .find(|value| !value.is_empty())
+                .and_then(|value| Language::from_locale(&value))
+        })
+    }
+}
+
+/// Idioma de la interfaz de Windows del usuario actual
+#[cfg(windows)]
+fn system_ui_language() -> Option<Language> {
+    use windows::Win32::Globalization::GetUserDefaultUILanguage;
+
+    // SAFETY: la función no recibe argumentos ni tiene precondiciones
+    let lang_id = unsafe { GetUserDefaultUILanguage() };
+    // El idioma principal está en los 10 bits bajos del LANGID
+    match lang_id & 0x3ff {
+        0x07 => Some(Language::German),
+        0x09 => Some(Language::English),
+        0x0a => Some(Language::Spanish),
+        0x0c => Some(Language::French),
+        0x16 => Some(Language::Portuguese),
+        _ => None,
+    }
+}
+
+#[cfg(not(windows))]
+fn system_ui_language() -> Option<Language> {
+    None
+}
+
+impl FromStr for Language {
+    type Err = String;
+
+    /// Interpreta el código (`es`, `en`, `fr`, `de`, `pt-BR`) o el nombre
+    /// del idioma
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        match value.trim().to_lowercase().as_str() {
+            "es" | "spanish" | "español" => Ok(Language::Spanish),
+            "en" | "english" => Ok(Language::English),
+            "fr" | "french" | "français" => Ok(Language::French),
+            "de" | "german" | "deutsch" => Ok(Language::German),
+            "pt" | "pt-br" | "portuguese" | "português" => Ok(Language::Portuguese),
+            _ => Err(format!(
+                "idioma no válido: {} (es, en, fr, de o pt-BR)",
+                value
+            )),
+        }
+    }
+}
+
+/// Declara las claves de traducción junto con la lista de todas ellas y su
+/// nombre en los archivos de `locales`
+macro_rules! i18n_keys {
+    ($($key:ident,)*) => {
+        /// Claves de traducción
+        #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+        pub enum I18nKey {
+            $($key,)*
+        }
+
+        impl I18nKey {
+            /// Todas las claves, en el orden en que se declaran
+            pub const ALL: &[I18nKey] = &[$(I18nKey::$key,)*];
+
+            /// Nombre de la clave en los archivos de traducción
+            pub fn name(self) -> &'static str {
+                match self {
+                    $(I18nKey::$key => stringify!($key),)*
+                }
+            }
+        }
+    };
 }
 
-/// Claves de traducción
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
-pub enum I18nKey {
+i18n_keys! {
     // === App Info ===
     AppTitle,
     AppSubtitle,
-    AppVersion,
+    UpdateAvailable,
+    UpdateDownloading,
+    UpdateDownloaded,
+    UpdateDownloadFailed,
     MainMenu,
     OperationsLog,
+    StatusAdmin,
+    StatusStandardUser,
+    StatusSafeMode,
+    StatusSafeModeNetworking,
+    StatusSimulation,
+    StatusJobs,
 
     // === Menu Items ===
     MenuTempFiles,
@@ -55,22 +251,72 @@ pub enum I18nKey {
     MenuSystemLogsDesc,
     MenuWindowsUpdate,
     MenuWindowsUpdateDesc,
+    MenuDownloads,
+    MenuDownloadsDesc,
+    MenuQuarantine,
+    MenuQuarantineDesc,
     MenuOptimize,
     MenuOptimizeDesc,
     MenuStartup,
     MenuStartupDesc,
+    MenuBootTime,
+    MenuBootTimeDesc,
     MenuVisualEffects,
     MenuVisualEffectsDesc,
+    MenuProfiles,
+    MenuProfilesDesc,
+    MenuMemory,
+    MenuMemoryDesc,
     MenuNetwork,
     MenuNetworkDesc,
+    MenuAdapters,
+    MenuAdaptersDesc,
+    MenuWifi,
+    MenuWifiDesc,
+    MenuProxy,
+    MenuProxyDesc,
+    MenuPorts,
+    MenuPortsDesc,
+    MenuJobs,
+    MenuJobsDesc,
+    MenuAppLogs,
+    MenuAppLogsDesc,
     MenuRepair,
     MenuRepairDesc,
+    MenuFontCache,
+    MenuFontCacheDesc,
+    MenuSearchIndex,
+    MenuSearchIndexDesc,
     MenuPrivacy,
     MenuPrivacyDesc,
+    MenuDefender,
+    MenuDefenderDesc,
+    MenuFirewall,
+    MenuFirewallDesc,
+    MenuHosts,
+    MenuHostsDesc,
+    MenuPathCleaner,
+    MenuPathCleanerDesc,
+    MenuPrograms,
+    MenuProgramsDesc,
     MenuInfo,
     MenuInfoDesc,
+    MenuSettings,
+    MenuSettingsDesc,
+    MenuAbout,
+    MenuAboutDesc,
+    MenuDrivers,
+    MenuDriversDesc,
+    MenuTasks,
+    MenuTasksDesc,
     MenuExit,
     MenuExitDesc,
+    MenuCategoryCleanup,
+    MenuCategoryOptimization,
+    MenuCategorySystem,
+    MenuAdminRequired,
+    MenuSafeModeUnavailable,
+    MenuUnsupportedBuild,
 
     // === Footer ===
     FooterNavigate,
@@ -80,14 +326,137 @@ pub enum I18nKey {
     FooterScroll,
     FooterTheme,
     FooterLanguage,
+    FooterApply,
+    FooterRevert,
+    FooterExport,
+    FooterScanDevices,
+    FooterScanUpdates,
+    FooterMemoryDiagnostic,
+    FooterCbsLog,
+    FooterSort,
+    FooterUninstall,
+    FooterThreshold,
+    FooterDelete,
+    FooterArchive,
+    FooterRestore,
+    FooterRestoreServices,
+    FooterQuickScan,
+    FooterRefresh,
+    FooterEnable,
+    FooterDisable,
+    FooterEnableAll,
+    FooterSave,
+    FooterResetDefault,
+    FooterClean,
+    FooterToggle,
+    FooterRenewIp,
+    FooterMark,
+    FooterExportKeys,
+    FooterRun,
+    FooterRestoreIp,
+    FooterFilter,
+    FooterKill,
+    FooterDone,
+    FooterClear,
+    FooterCancel,
+    FooterClearFinished,
+    FooterLevel,
+    FooterLogLevel,
+    FooterTimestamps,
+    FooterSearch,
+    FooterCopy,
+    FooterFollow,
+    FooterContinue,
+    FooterRetry,
+    FooterRetryFailed,
+    FooterSkipStep,
+    FooterHelp,
+    FooterUpdate,
+    FooterCopyDiagnostics,
+    FooterPalette,
+    FooterReboot,
 
     // === Operations ===
     OpStarting,
     OpCompleted,
     OpError,
+    OpStalled,
+    OpInProgress,
+    OpEta,
+    OpCancelHint,
     OpRequiresAdmin,
     OpPleaseRunAsAdmin,
     OpRebootRecommended,
+    RebootNeeded,
+    RebootSchedule,
+    RebootScheduledIn,
+    RebootCancel,
+    RebootScheduled,
+    RebootCancelled,
+    RebootScheduleError,
+    RebootCancelError,
+    UptimeRebootHint,
+    PendingRebootWarning,
+    PendingRebootComponentServicing,
+    PendingRebootWindowsUpdate,
+    PendingRebootFileRenames,
+    PendingRebootConfirm,
+    OpRunning,
+    OpCommandFailed,
+    OpCommandOutput,
+    OpErrorDetail,
+    OpServiceDisabled,
+    OpServiceDisableFailed,
+    OpServiceError,
+    ServicesRestoreStarting,
+    ServicesRestored,
+    ServicesRestoreFailed,
+    ServicesRestoreUnknown,
+    ServicesRestoreCompleted,
+    ServicesRestoreNothing,
+    OpFilesDeleted,
+    OpFilesSkipped,
+    RetryHint,
+    RetryStarting,
+    RetryWorkerItem,
+    RetryWorkerCompleted,
+    RetryWorkerFailed,
+    WorkerInternalError,
+    WorkerRunning,
+    WorkerCommandError,
+    WorkerTimeout,
+    WorkerStalled,
+    WorkerResponsive,
+    WorkerStepKilled,
+    WorkerCommandCancelled,
+    WorkerCommandSucceeded,
+    WorkerCommandFailed,
+    WorkerCbsExtracted,
+    WorkerCbsError,
+    WorkerCancelled,
+    WorkerStep,
+    WorkerSucceeded,
+    WorkerFailed,
+    WorkerStepsFailed,
+    WorkerRemoved,
+    WorkerRemoveError,
+    WorkerSkipping,
+    WorkerCancelling,
+    RepairWorkerTitle,
+    RepairWorkerDism,
+    RepairWorkerDismHint,
+    RepairWorkerDismFailed,
+    RepairWorkerSfc,
+    RepairWorkerSfcHint,
+    RepairWorkerCompleted,
+    RepairWorkerFailed,
+    WindowsUpdateWorkerTitle,
+    WindowsUpdateWorkerDism,
+    WindowsUpdateWorkerHint,
+    WindowsUpdateWorkerCompleted,
+    WindowsUpdateWorkerFailed,
+    OpItemDeleted,
+    OpItemSkipped,
 
     // === Clean Operation ===
     CleanTitle,
@@ -96,26 +465,88 @@ pub enum I18nKey {
     CleanItemsFound,
     CleanProcessing,
     CleanCompleted,
-    CleanErrorReading,
+    CleanFileDeleted,
+    CleanFileFailed,
+    CleanDirDeleted,
+    CleanDirFailed,
+    CleanLinkDeleted,
+    CleanInUse,
+    CleanQuarantineMode,
+    CleanRecycleBinMode,
+    CleanProfilesSkipped,
+    CleanLocationUser,
+    CleanLocationWindows,
+    CleanLocationProfile,
+    CleanLocationProfileCache,
+    CleanLocationInetCache,
+    CleanLocationDrive,
+    CleanLocationDone,
+    CleanLocationUnreadable,
+    CleanLocationsTitle,
+    CleanLocationColumn,
+    CleanDrivesTitle,
+    CleanDrivesHint,
+    CleanProfilesTitle,
+    CleanTargetsTitle,
+    CleanProfilesHint,
+    CleanDriveSystem,
+    CleanDriveFree,
+    CleanNoDrives,
 
     // === Statistics ===
     StatsTitle,
     StatsDeleted,
     StatsSkipped,
+    StatsInUse,
     StatsFreed,
+    StatsDism,
+    StatsSfc,
+    StatsCorruption,
+    StatsResultOk,
+    StatsResultFailed,
+    StatsResultPending,
+    StatsCorruptionNone,
+    StatsCorruptionFixed,
+    StatsCorruptionUnfixed,
+    StatsStepsSucceeded,
+    StatsStepsFailed,
+    StatsStepsSkipped,
+    StatsServicesDisabled,
+    StatsServicesFailed,
 
     // === Network Operation ===
     NetworkTitle,
     NetworkStarting,
-    NetworkDnsFlush,
+    NetworkSelectHint,
+    NetworkStaticIpPending,
+    NetworkNoSteps,
+    NetworkTcpIpSkipped,
+    NetworkCompleted,
+    NetworkStepsFailed,
     NetworkDnsSuccess,
-    NetworkDnsError,
-    NetworkWinsock,
     NetworkWinsockSuccess,
-    NetworkWinsockError,
+    NetworkArpSuccess,
+    NetworkNetbiosSuccess,
+    NetworkTcpIpSuccess,
+    NetworkStepDns,
+    NetworkStepWinsock,
+    NetworkStepArp,
+    NetworkStepNetbios,
+    NetworkStepTcpIp,
+    NetworkSteps,
 
     // === Repair Operation ===
     RepairTitle,
+    FontCacheTitle,
+    SearchIndexTitle,
+    FontCacheStarting,
+    SearchIndexStarting,
+    FontCacheStopService,
+    FontCacheRemoveFiles,
+    FontCacheStartService,
+    SearchIndexStopService,
+    SearchIndexRemoveFiles,
+    SearchIndexStartService,
     RepairStarting,
     RepairDism,
     RepairDismSuccess,
@@ -124,6 +555,31 @@ pub enum I18nKey {
     RepairSfcSuccess,
     RepairSfcWarning,
     RepairWait,
+    RepairVerdictTitle,
+    RepairCbsTitle,
+    RepairCbsLines,
+    RepairNextSteps,
+    RepairVerdictHealthy,
+    RepairVerdictRepaired,
+    RepairVerdictUnrepaired,
+    RepairVerdictSourceMissing,
+    RepairVerdictDownloadFailed,
+    RepairVerdictNeedsAdmin,
+    RepairVerdictRebootPending,
+    RepairVerdictSfcFailed,
+    RepairVerdictDismFailed,
+    RepairVerdictUnknown,
+    RepairStepNone,
+    RepairStepReboot,
+    RepairStepRerun,
+    RepairStepRunAsAdmin,
+    RepairStepCheckCbs,
+    RepairStepCheckDismLog,
+    RepairStepUseSource,
+    RepairStepCheckConnection,
+    RepairStepSafeMode,
+    RepairStepInPlaceUpgrade,
+    RepairStepCheckOutput,
 
     // === System Info ===
     InfoTitle,
@@ -133,12 +589,37 @@ pub enum I18nKey {
     InfoHost,
     InfoArch,
     InfoUptime,
+    InfoEdition,
+    InfoInstalled,
+    InfoActivation,
+    ActivationLicensed,
+    ActivationGrace,
+    ActivationNotification,
+    ActivationUnlicensed,
     InfoCpu,
     InfoCores,
     InfoMemTotal,
     InfoMemUsed,
     InfoMemUsage,
     InfoDisks,
+    InfoBattery,
+    InfoBatteryHealth,
+    InfoBatteryDesign,
+    InfoBatteryCycles,
+    InfoNetwork,
+    InfoAddresses,
+    InfoGateway,
+    InfoDns,
+    InfoMac,
+    InfoNoNetwork,
+    TopProcessesTitle,
+    TopProcessesSortMemory,
+    TopProcessesSortCpu,
+    TopProcessesColumn,
+    TopProcessesMemory,
+    TopProcessesCpu,
+    InfoUnknown,
+    InfoLoading,
 
     // === Browser Cache ===
     BrowserCacheTitle,
@@ -160,6 +641,7 @@ pub enum I18nKey {
     RecycleBinStarting,
     RecycleBinSuccess,
     RecycleBinWarning,
+    RecycleBinError,
     RecycleBinFreed,
 
     // === Windows Update ===
@@ -169,13 +651,33 @@ pub enum I18nKey {
     WindowsUpdateDiskCleanup,
     WindowsUpdateComponents,
     WindowsUpdateCompleted,
+    WindowsUpdateStatus,
+    WindowsUpdateChecking,
+    WindowsUpdateStatusError,
+    WindowsUpdatePending,
+    WindowsUpdateUpToDate,
+    WindowsUpdateLastInstall,
+    WindowsUpdateUnknown,
+    WindowsUpdateMore,
 
     // === Optimization ===
     OptimizeTitle,
     OptimizeStarting,
     OptimizePrefetch,
+    OptimizePrefetchDeleted,
+    OptimizePrefetchDone,
+    OptimizePrefetchError,
+    OptimizePrefetchNotFound,
+    OptimizePrefetchSkipped,
     OptimizePower,
+    OptimizePowerSuccess,
+    OptimizePowerFailed,
+    OptimizePowerError,
     OptimizeServices,
+    OptimizeServiceTelemetry,
+    OptimizeServiceSysMain,
+    OptimizeSysMainKeptHdd,
+    OptimizeSysMainKeptUnknown,
     OptimizeCompleted,
 
     // === Privacy ===
@@ -183,24 +685,297 @@ pub enum I18nKey {
     PrivacyStarting,
     PrivacyTelemetry,
     PrivacyTasks,
+    PrivacyTaskDisabled,
+    PrivacyTaskFailed,
     PrivacyCompleted,
 
     // === Startup Optimizer ===
     StartupTitle,
     StartupAnalyzing,
-    StartupGettingList,
     StartupFound,
-    StartupDisableHint,
-    StartupRecommendation,
+    StartupEntry,
+    StartupEnabledCount,
+    StartupSourceFailed,
+    StartupMissing,
+    StartupHint,
+    StartupEnabled,
+    StartupDisabled,
+    StartupServiceManual,
+    StartupChangeFailed,
+    StartupRunOnce,
+    StartupNeedsAdmin,
+    StartupSourceUserRun,
+    StartupSourceUserRunOnce,
+    StartupSourceMachineRun,
+    StartupSourceMachineRun32,
+    StartupSourceMachineRunOnce,
+    StartupSourceUserFolder,
+    StartupSourceCommonFolder,
+    StartupSourceTask,
+    StartupSourceService,
+    StartupImpactHigh,
+    StartupImpactMedium,
+    StartupImpactLow,
+    StartupStateEnabled,
+    StartupStateDisabled,
+    StartupColumnEntry,
+    StartupColumnState,
+    StartupColumnImpact,
+    StartupColumnCommand,
 
     // === Visual Effects ===
     VisualEffectsTitle,
+    MemoryTitle,
     VisualEffectsOptimizing,
     VisualEffectsApplying,
+    VisualEffectsMinAnimate,
+    VisualEffectsTransparency,
+    VisualEffectsMouseShadow,
+    VisualEffectsBestPerformance,
+    VisualEffectsSetting,
     VisualEffectsCompleted,
     VisualEffectsLogoutRequired,
     VisualEffectsHint,
 
+    // === Profiles ===
+    ProfilesTitle,
+    ProfilesActive,
+    ProfilesDefault,
+    ProfileGaming,
+    ProfileGamingDesc,
+    ProfileBattery,
+    ProfileBatteryDesc,
+
+    // === Drivers ===
+    DriversTitle,
+    DriversOutdated,
+    DriversNoDate,
+
+    // === Programs ===
+    ProgramsTitle,
+    ProgramsSortName,
+    ProgramsSortSize,
+    ProgramsSortDate,
+
+    // === Downloads ===
+    DownloadsTitle,
+    DownloadsByAge,
+    DownloadsByKind,
+    DownloadsAgeRecent,
+    DownloadsAgeMonths,
+    DownloadsAgeQuarters,
+    DownloadsAgeYears,
+    DownloadsOlderThan,
+    DownloadsKindInstallers,
+    DownloadsKindArchives,
+    DownloadsKindDocuments,
+    DownloadsKindMedia,
+    DownloadsKindFolders,
+    DownloadsKindOther,
+
+    // === Quarantine ===
+    QuarantineTitle,
+    QuarantineEmpty,
+
+    // === Defender ===
+    DefenderTitle,
+    DefenderLastScan,
+    DefenderSignatureAge,
+    DefenderDays,
+    DefenderRealtime,
+    DefenderOn,
+    DefenderOff,
+    DefenderUnknown,
+    DefenderExclusions,
+    DefenderNoExclusions,
+    DefenderExclusionsHidden,
+    DefenderExclusionPath,
+    DefenderExclusionExtension,
+    DefenderExclusionProcess,
+    DefenderQuickScanTitle,
+
+    // === Firewall ===
+    FirewallTitle,
+    FirewallDomain,
+    FirewallPrivate,
+    FirewallPublic,
+    FirewallOn,
+    FirewallOff,
+    FirewallUnknown,
+    FirewallAllOff,
+
+    // === Hosts ===
+    HostsTitle,
+    HostsModified,
+
+    // === PathCleaner ===
+    PathCleanerTitle,
+    PathSystem,
+    PathUser,
+    PathEmpty,
+    PathMissing,
+    PathDuplicate,
+
+    // === Adapters ===
+    AdaptersTitle,
+    AdapterUp,
+    AdapterDisconnected,
+    AdapterDisabled,
+    AdapterUnknown,
+    AdapterMetric,
+
+    // === Tasks ===
+    TasksTitle,
+    TasksLoading,
+    TasksFound,
+    TasksHint,
+    TasksListFailed,
+    TasksEnabled,
+    TasksDisabled,
+    TasksChangeFailed,
+    TasksAccessDenied,
+    TasksMarked,
+    TasksVendorUnknown,
+    TasksLastRun,
+    TasksNextRun,
+    TasksNever,
+    TasksColumnTask,
+    TasksColumnState,
+    TasksColumnAuthor,
+    TaskStateUnknown,
+    TaskStateDisabled,
+    TaskStateQueued,
+    TaskStateReady,
+    TaskStateRunning,
+
+    // === Wifi ===
+    WifiTitle,
+    WifiMarked,
+
+    // === Proxy ===
+    ProxyTitle,
+    ProxyWininet,
+    ProxyWinhttp,
+    ProxyServer,
+    ProxyBypass,
+    ProxyPac,
+    ProxyOn,
+    ProxyOff,
+    ProxyDirect,
+
+    // === Ports ===
+    PortsTitle,
+    PortsFilter,
+    PortsFilterHint,
+
+    // === Benchmark ===
+    BenchmarkTitle,
+    BenchmarkMetric,
+    BenchmarkBefore,
+    BenchmarkAfter,
+    BenchmarkDelta,
+    BenchmarkTempFiles,
+    BenchmarkStartupItems,
+    BenchmarkServices,
+    BenchmarkFreeRam,
+    BenchmarkFreeDisk,
+
+    // === BootTime ===
+    BootTitle,
+    BootTrend,
+    BootAfterRun,
+    BootAverageAfterRun,
+    BootAverageOther,
+    BootNoData,
+
+    // === Jobs ===
+    JobsTitle,
+    JobsEmpty,
+    JobPending,
+    JobRunning,
+    JobCompleted,
+    JobFailed,
+    JobLogs,
+
+    // === App Logs ===
+    AppLogsTitle,
+    AppLogsNoFile,
+    AppLogsLevel,
+    AppLogsAllLevels,
+    AppLogsFileLevel,
+    AppLogsFilterHint,
+    LogSearchNoMatches,
+    LogFollowPaused,
+    ConfigWarningMore,
+    InterruptedTitle,
+    InterruptedIntro,
+    InterruptedTargets,
+    InterruptedMore,
+    InterruptedRollbackServices,
+    InterruptedRollbackQuarantine,
+    InterruptedResume,
+    InterruptedRollback,
+    InterruptedDismiss,
+
+    // === Help ===
+    HelpTitle,
+    HelpGeneral,
+    HelpLogs,
+    HelpView,
+    HelpClose,
+    HelpMouse,
+    HelpPage,
+    HelpTopBottom,
+    HelpNextMatch,
+    PaletteTitle,
+    PalettePlaceholder,
+    PaletteNoResults,
+    PaletteHint,
+
+    // === Onboarding ===
+    OnboardingTitle,
+    OnboardingIntro,
+    OnboardingStep,
+    OnboardingLanguage,
+    OnboardingTheme,
+    OnboardingFileLogging,
+    OnboardingConfirmations,
+    OnboardingYes,
+    OnboardingNo,
+    OnboardingHint,
+    FooterChoose,
+    FooterNext,
+    FooterFinish,
+    FooterPrevious,
+    FooterSkip,
+
+    // === Settings ===
+    SettingsTitle,
+    AboutTitle,
+    AboutVersion,
+    AboutCommit,
+    AboutBuilt,
+    AboutWindows,
+    AboutWindowsBuild,
+    AboutElevation,
+    AboutConfig,
+    AboutLogs,
+    AboutRepository,
+    AboutLicense,
+    AboutCopied,
+    AboutCopyError,
+    LogsCopied,
+    LogsCopyError,
+    SettingsLanguage,
+    SettingsTheme,
+    SettingsCurrent,
+    ThemeLight,
+    ThemeDark,
+    ThemeSolarized,
+    ThemeNord,
+    ThemeHighContrast,
+    ThemeCustom,
+
     // === Generic Messages ===
     Success,
     Warning,
@@ -209,9 +984,121 @@ pub enum I18nKey {
 }
 
 /// HashMap global de traducciones (inicializado una sola vez)
-static TRANSLATIONS: OnceLock<HashMap<(Language, I18nKey), &'static str>> = OnceLock::new();
+static TRANSLATIONS: OnceLock<HashMap<(Language, I18nKey), Text>> = OnceLock::new();
+
+/// Forma de plural de un texto
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Plural {
+    One,
+    Other,
+}
+
+/// Texto de una clave en un archivo de `locales`
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize)]
+#[serde(untagged)]
+pub enum Text {
+    /// El mismo texto para cualquier cantidad
+    Plain(String),
+    /// Formas de singular y plural
+    Plural { one: String, other: String },
+}
+
+impl Text {
+    /// Forma del texto para una forma de plural
+    pub fn form(&self, plural: Plural) -> &str {
+        match (self, plural) {
+            (Text::Plain(text), _) => text,
+            (Text::Plural { one, .. }, Plural::One) => one,
+            (Text::Plural { other, .. }, Plural::Other) => other,
+        }
+    }
+}
+
+/// Sustituye los marcadores `{nombre}` de un texto
+///
+/// Los marcadores sin valor se dejan tal cual.
+pub fn interpolate(template: &str, args: &[(&str, &str)]) -> String {
+    let mut text = template.to_string();
+    for (name, value) in args {
+        text = text.replace(&format!("{{{}}}", name), value);
+    }
+    text
+}
+
+/// Traducciones de un archivo de `locales`
+#[derive(Debug, Default)]
+pub struct Locale {
+    /// Texto de cada clave definida en el archivo
+    pub texts: HashMap<I18nKey, Text>,
+    /// Claves del archivo que no existen en la aplicación
+    pub unknown_keys: Vec<String>,
+}
+
+impl Locale {
+    /// Interpreta el contenido de un archivo de traducciones
+    ///
+    /// # Errores
+    ///
+    /// Retorna error si el archivo no es TOML válido o algún valor no es un
+    /// texto.
+    pub fn parse(contents: &str) -> Result<Self, toml::de::Error> {
+        let names: HashMap<&str, I18nKey> =
+            I18nKey::ALL.iter().map(|&key| (key.name(), key)).collect();
+        let entries: HashMap<String, Text> = toml::from_str(contents)?;
+
+        let mut locale = Locale::default();
+        for (name, text) in entries {
+            match names.get(name.as_str()) {
+                Some(&key) => {
+                    locale.texts.insert(key, text);
+                }
+                None => locale.unknown_keys.push(name),
+            }
+        }
+        locale.unknown_keys.sort();
+        Ok(locale)
+    }
+}
+
+/// Carpeta con las traducciones del usuario (`locales` en la configuración)
+pub fn user_locales_dir() -> Option<PathBuf> {
+    Config::get_config_dir().ok().map(|dir| dir.join("locales"))
+}
+
+/// Lee el archivo de traducciones del usuario para un idioma, si existe
+///
+/// Un archivo que no se puede leer se ignora y se avisa en el log.
+fn load_user_locale(dir: &Path, language: Language) -> Option<Locale> {
+    let path = dir.join(format!("{}.toml", language.code()));
+    let contents = std::fs::read_to_string(&path).ok()?;
+    match Locale::parse(&contents) {
+        Ok(locale) => {
+            for name in &locale.unknown_keys {
+                tracing::warn!(
+                    "{}: clave de traducción desconocida: {}",
+                    path.display(),
+                    name
+                );
+            }
+            tracing::info!(
+                "Traducciones cargadas de {} ({} claves)",
+                path.display(),
+                locale.texts.len()
+            );
+            Some(locale)
+        }
+        Err(e) => {
+            tracing::warn!("No se pudo leer {}: {}", path.display(), e);
+            None
+        }
+    }
+}
 
 /// Sistema de traducciones (solo almacena el idioma actual)
+///
+/// Es `Copy`: los workers reciben su propia copia para escribir sus logs en
+/// el idioma de la interfaz.
+#[derive(Debug, Clone, Copy)]
 pub struct I18n {
     current_language: Language,
 }
@@ -236,465 +1123,81 @@ impl I18n {
         self.current_language
     }
 
-    /// Alterna entre idiomas disponibles
+    /// Formatea un tamaño en bytes con las unidades y el separador decimal
+    /// del idioma actual
+    pub fn format_size(&self, bytes: u64) -> String {
+        self.current_language.format_size(bytes)
+    }
+
+    /// Formatea una fecha `yyyy-MM-dd` en el orden del idioma actual
+    pub fn format_date(&self, date: &str) -> String {
+        self.current_language.format_date(date)
+    }
+
+    /// Pasa al siguiente idioma disponible (vuelve al primero tras el último)
     pub fn toggle_language(&mut self) {
-        self.current_language = match self.current_language {
-            Language::Spanish => Language::English,
-            Language::English => Language::Spanish,
-        };
+        let current = Language::ALL
+            .iter()
+            .position(|&language| language == self.current_language)
+            .unwrap_or(0);
+        self.current_language = Language::ALL[(current + 1) % Language::ALL.len()];
     }
 
     /// Obtiene una traducción para la clave especificada
-    pub fn t(&self, key: I18nKey) -> &str {
+    ///
+    /// Si el texto tiene formas de plural se devuelve la de plural.
+    pub fn t(&self, key: I18nKey) -> &'static str {
+        self.text(key)
+            .map_or("[MISSING TRANSLATION]", |text| text.form(Plural::Other))
+    }
+
+    /// Obtiene una traducción rellenando sus marcadores `{nombre}`
+    ///
+    /// Si el texto tiene formas de plural, se elige según el valor de
+    /// `count`.
+    ///
+    /// ```
+    /// use win_opt::i18n::{I18n, I18nKey, Language};
+    ///
+    /// let i18n = I18n::new(Language::English);
+    /// assert_eq!(i18n.t_args(I18nKey::DefenderDays, &[("count", "1")]), "1 day");
+    /// assert_eq!(i18n.t_args(I18nKey::DefenderDays, &[("count", "5")]), "5 days");
+    /// ```
+    pub fn t_args(&self, key: I18nKey, args: &[(&str, &str)]) -> String {
+        let Some(text) = self.text(key) else {
+            return "[MISSING TRANSLATION]".to_string();
+        };
+        let plural = args
+            .iter()
+            .find(|(name, _)| *name == "count")
+            .and_then(|(_, value)| value.parse().ok())
+            .map_or(Plural::Other, |count| self.current_language.plural(count));
+        interpolate(text.form(plural), args)
+    }
+
+    /// Texto de una clave en el idioma actual
+    fn text(&self, key: I18nKey) -> Option<&'static Text> {
         TRANSLATIONS
             .get()
             .and_then(|t| t.get(&(self.current_language, key)))
-            .copied()
-            .unwrap_or("[MISSING TRANSLATION]")
-    }
-
-    /// Inicializa el HashMap de traducciones (se llama una sola vez)
-    fn init_translations() -> HashMap<(Language, I18nKey), &'static str> {
-        use I18nKey::*;
-        use Language::*;
-
-        // ============ ESPAÑOL ============
-        let es_translations = [
-            // App Info
-            (AppTitle, "WIN OPT"),
-            (AppSubtitle, "Windows 11 Optimizer"),
-            (AppVersion, "v1.2.1"),
-            (MainMenu, "Menú Principal"),
-            (OperationsLog, "Registro de Operaciones"),
-            // Menu Items
-            (MenuTempFiles, "Archivos Temporales"),
-            (MenuTempFilesDesc, "Limpia archivos temp del sistema"),
-            (MenuRecycleBin, "Papelera de Reciclaje"),
-            (MenuRecycleBinDesc, "Vacía la papelera completamente"),
-            (MenuBrowserCache, "Caché de Navegadores"),
-            (MenuBrowserCacheDesc, "Limpia Chrome, Firefox, Edge"),
-            (MenuSystemLogs, "Logs del Sistema"),
-            (MenuSystemLogsDesc, "Elimina archivos de registro"),
-            (MenuWindowsUpdate, "Windows Update"),
-            (MenuWindowsUpdateDesc, "Limpia archivos de actualización"),
-            (MenuOptimize, "Optimización Avanzada"),
-            (MenuOptimizeDesc, "Servicios, energía y prefetch"),
-            (MenuStartup, "Programas de Inicio"),
-            (MenuStartupDesc, "Optimiza arranque de Windows"),
-            (MenuVisualEffects, "Efectos Visuales"),
-            (MenuVisualEffectsDesc, "Deshabilita animaciones"),
-            (MenuNetwork, "Red"),
-            (MenuNetworkDesc, "DNS flush & Winsock reset"),
-            (MenuRepair, "Reparación"),
-            (MenuRepairDesc, "DISM & SFC scan"),
-            (MenuPrivacy, "Privacidad"),
-            (MenuPrivacyDesc, "Desactiva telemetría"),
-            (MenuInfo, "Info del Sistema"),
-            (MenuInfoDesc, "Detalles del hardware"),
-            (MenuExit, "Salir"),
-            (MenuExitDesc, "Cerrar aplicación"),
-            // Footer
-            (FooterNavigate, "Navegar"),
-            (FooterSelect, "Seleccionar"),
-            (FooterBack, "Volver al menú"),
-            (FooterExit, "Salir"),
-            (FooterScroll, "Scroll"),
-            (FooterTheme, "Tema"),
-            (FooterLanguage, "Idioma"),
-            // Operations
-            (OpStarting, "Iniciando operación..."),
-            (OpCompleted, "Operación completada"),
-            (OpError, "Error en la operación"),
-            (
-                OpRequiresAdmin,
-                "ERROR: Esta operación requiere permisos de Administrador",
-            ),
-            (
-                OpPleaseRunAsAdmin,
-                "Por favor, ejecuta la aplicación como Administrador",
-            ),
-            (
-                OpRebootRecommended,
-                "Se recomienda reiniciar el sistema para aplicar los cambios",
-            ),
-            // Clean Operation
-            (CleanTitle, "Limpieza de Archivos Temporales"),
-            (
-                CleanStarting,
-                "Iniciando limpieza de archivos temporales...",
-            ),
-            (CleanDirectory, "Directorio:"),
-            (CleanItemsFound, "Elementos encontrados:"),
-            (CleanProcessing, "Procesando..."),
-            (CleanCompleted, "Limpieza completada"),
-            (CleanErrorReading, "Error al leer el directorio temporal"),
-            // Statistics
-            (StatsTitle, "Estadísticas"),
-            (StatsDeleted, "Elementos eliminados:"),
-            (StatsSkipped, "Elementos omitidos:"),
-            (StatsFreed, "Espacio liberado:"),
-            // Network
-            (NetworkTitle, "Limpieza de Red"),
-            (NetworkStarting, "Iniciando operaciones de red..."),
-            (NetworkDnsFlush, "Ejecutando: ipconfig /flushdns"),
-            (NetworkDnsSuccess, "Caché DNS limpiada exitosamente"),
-            (NetworkDnsError, "Error al limpiar la caché DNS"),
-            (NetworkWinsock, "Ejecutando: netsh winsock reset"),
-            (NetworkWinsockSuccess, "Winsock reiniciado exitosamente"),
-            (
-                NetworkWinsockError,
-                "Falló el reinicio de Winsock (se requieren permisos de administrador)",
-            ),
-            // Repair
-            (RepairTitle, "Reparación del Sistema"),
-            (RepairStarting, "Iniciando reparación del sistema..."),
-            (
-                RepairDism,
-                "Ejecutando DISM (Deployment Image Servicing and Management)...",
-            ),
-            (RepairDismSuccess, "DISM finalizado correctamente"),
-            (RepairDismError, "DISM finalizó con errores"),
-            (RepairSfc, "Ejecutando SFC (System File Checker)..."),
-            (RepairSfcSuccess, "Escaneo de archivos finalizado"),
-            (RepairSfcWarning, "Escaneo finalizado con advertencias"),
-            (RepairWait, "Esto puede tardar varios minutos..."),
-            // System Info
-            (InfoTitle, "Información del Sistema"),
-            (InfoOs, "OS:"),
-            (InfoVersion, "Versión:"),
-            (InfoKernel, "Kernel:"),
-            (InfoHost, "Host:"),
-            (InfoArch, "Arquitectura:"),
-            (InfoUptime, "Tiempo activo:"),
-            (InfoCpu, "CPU:"),
-            (InfoCores, "Núcleos:"),
-            (InfoMemTotal, "Memoria Total:"),
-            (InfoMemUsed, "Memoria Usada:"),
-            (InfoMemUsage, "Uso de Memoria"),
-            (InfoDisks, "Discos"),
-            // Browser Cache
-            (BrowserCacheTitle, "Caché de Navegadores"),
-            (
-                BrowserCacheStarting,
-                "Iniciando limpieza de caché de navegadores...",
-            ),
-            (BrowserCacheCleaning, "Limpiando caché de"),
-            (BrowserCacheSuccess, "Caché limpiada"),
-            (BrowserCacheNotFound, "No encontrado o inaccesible"),
-            (
-                BrowserCacheCloseWarning,
-                "Cierra los navegadores antes de ejecutar esta operación para mejores resultados",
-            ),
-            // System Logs
-            (SystemLogsTitle, "Logs del Sistema"),
-            (
-                SystemLogsStarting,
-                "Iniciando limpieza de logs del sistema...",
-            ),
-            (SystemLogsCleaning, "Limpiando:"),
-            (SystemLogsProcessed, "procesado"),
-            (
-                SystemLogsRequiresAdmin,
-                "Requiere permisos de administrador",
-            ),
-            // Recycle Bin
-            (RecycleBinTitle, "Papelera de Reciclaje"),
-            (
-                RecycleBinStarting,
-                "Iniciando vaciado de papelera de reciclaje...",
-            ),
-            (
-                RecycleBinSuccess,
-                "Papelera de reciclaje vaciada exitosamente",
-            ),
-            (
-                RecycleBinWarning,
-                "Advertencia: Algunas carpetas no pudieron vaciarse",
-            ),
-            (RecycleBinFreed, "Espacio en disco liberado"),
-            // Windows Update
-            (WindowsUpdateTitle, "Limpieza de Windows Update"),
-            (
-                WindowsUpdateStarting,
-                "Iniciando limpieza de Windows Update...",
-            ),
-            (
-                WindowsUpdateCleaning,
-                "Eliminando archivos de actualización antiguos...",
-            ),
-            (WindowsUpdateDiskCleanup, "Limpieza de disco iniciada"),
-            (
-                WindowsUpdateComponents,
-                "Ejecutando limpieza de componentes...",
-            ),
-            (
-                WindowsUpdateCompleted,
-                "Limpieza de Windows Update completada",
-            ),
-            // Optimization
-            (OptimizeTitle, "Optimización Avanzada"),
-            (
-                OptimizeStarting,
-                "Iniciando optimización avanzada del sistema...",
-            ),
-            (OptimizePrefetch, "Limpiando archivos Prefetch..."),
-            (
-                OptimizePower,
-                "Configurando plan de energía de alto rendimiento...",
-            ),
-            (OptimizeServices, "Optimizando servicios del sistema..."),
-            (OptimizeCompleted, "Optimización avanzada completada"),
-            // Privacy
-            (PrivacyTitle, "Privacidad y Telemetría"),
-            (PrivacyStarting, "Iniciando configuración de privacidad..."),
-            (PrivacyTelemetry, "Deshabilitando telemetría de Windows..."),
-            (
-                PrivacyTasks,
-                "Deshabilitando tareas programadas de telemetría...",
-            ),
-            (PrivacyCompleted, "Configuración de privacidad completada"),
-            // Startup
-            (StartupTitle, "Programas de Inicio"),
-            (StartupAnalyzing, "Analizando programas de inicio..."),
-            (
-                StartupGettingList,
-                "Obteniendo lista de programas de inicio...",
-            ),
-            (StartupFound, "Programas de inicio encontrados:"),
-            (
-                StartupDisableHint,
-                "Para deshabilitar programas: Ejecuta 'msconfig' o 'Administrador de tareas'",
-            ),
-            (
-                StartupRecommendation,
-                "Recomendación: Deshabilita programas innecesarios para acelerar el inicio",
-            ),
-            // Visual Effects
-            (VisualEffectsTitle, "Efectos Visuales"),
-            (VisualEffectsOptimizing, "Optimizando efectos visuales..."),
-            (
-                VisualEffectsApplying,
-                "Aplicando configuraciones de rendimiento...",
-            ),
-            (VisualEffectsCompleted, "Efectos visuales optimizados"),
-            (
-                VisualEffectsLogoutRequired,
-                "Los cambios se aplicarán después de cerrar sesión o reiniciar",
-            ),
-            (
-                VisualEffectsHint,
-                "Esto puede mejorar significativamente el rendimiento en equipos antiguos",
-            ),
-            // Generic
-            (Success, "Éxito"),
-            (Warning, "Advertencia"),
-            (Error, "Error"),
-            (Info, "Información"),
-        ];
-
-        // ============ ENGLISH ============
-        let en_translations = [
-            // App Info
-            (AppTitle, "WIN OPT"),
-            (AppSubtitle, "Windows 11 Optimizer"),
-            (AppVersion, "v1.2.1"),
-            (MainMenu, "Main Menu"),
-            (OperationsLog, "Operation Log"),
-            // Menu Items
-            (MenuTempFiles, "Temporary Files"),
-            (MenuTempFilesDesc, "Clean system temp files"),
-            (MenuRecycleBin, "Recycle Bin"),
-            (MenuRecycleBinDesc, "Empty recycle bin completely"),
-            (MenuBrowserCache, "Browser Cache"),
-            (MenuBrowserCacheDesc, "Clean Chrome, Firefox, Edge"),
-            (MenuSystemLogs, "System Logs"),
-            (MenuSystemLogsDesc, "Remove log files"),
-            (MenuWindowsUpdate, "Windows Update"),
-            (MenuWindowsUpdateDesc, "Clean update files"),
-            (MenuOptimize, "Advanced Optimization"),
-            (MenuOptimizeDesc, "Services, power and prefetch"),
-            (MenuStartup, "Startup Programs"),
-            (MenuStartupDesc, "Optimize Windows startup"),
-            (MenuVisualEffects, "Visual Effects"),
-            (MenuVisualEffectsDesc, "Disable animations"),
-            (MenuNetwork, "Network"),
-            (MenuNetworkDesc, "DNS flush & Winsock reset"),
-            (MenuRepair, "Repair"),
-            (MenuRepairDesc, "DISM & SFC scan"),
-            (MenuPrivacy, "Privacy"),
-            (MenuPrivacyDesc, "Disable telemetry"),
-            (MenuInfo, "System Info"),
-            (MenuInfoDesc, "Hardware details"),
-            (MenuExit, "Exit"),
-            (MenuExitDesc, "Close application"),
-            // Footer
-            (FooterNavigate, "Navigate"),
-            (FooterSelect, "Select"),
-            (FooterBack, "Back to menu"),
-            (FooterExit, "Exit"),
-            (FooterScroll, "Scroll"),
-            (FooterTheme, "Theme"),
-            (FooterLanguage, "Language"),
-            // Operations
-            (OpStarting, "Starting operation..."),
-            (OpCompleted, "Operation completed"),
-            (OpError, "Operation error"),
-            (
-                OpRequiresAdmin,
-                "ERROR: This operation requires Administrator permissions",
-            ),
-            (
-                OpPleaseRunAsAdmin,
-                "Please run the application as Administrator",
-            ),
-            (
-                OpRebootRecommended,
-                "System restart recommended to apply changes",
-            ),
-            // Clean Operation
-            (CleanTitle, "Temporary Files Cleanup"),
-            (CleanStarting, "Starting temporary files cleanup..."),
-            (CleanDirectory, "Directory:"),
-            (CleanItemsFound, "Items found:"),
-            (CleanProcessing, "Processing..."),
-            (CleanCompleted, "Cleanup completed"),
-            (CleanErrorReading, "Error reading temporary directory"),
-            // Statistics
-            (StatsTitle, "Statistics"),
-            (StatsDeleted, "Items deleted:"),
-            (StatsSkipped, "Items skipped:"),
-            (StatsFreed, "Space freed:"),
-            // Network
-            (NetworkTitle, "Network Cleanup"),
-            (NetworkStarting, "Starting network operations..."),
-            (NetworkDnsFlush, "Running: ipconfig /flushdns"),
-            (NetworkDnsSuccess, "DNS cache cleared successfully"),
-            (NetworkDnsError, "Error clearing DNS cache"),
-            (NetworkWinsock, "Running: netsh winsock reset"),
-            (NetworkWinsockSuccess, "Winsock reset successfully"),
-            (
-                NetworkWinsockError,
-                "Winsock reset failed (administrator permissions required)",
-            ),
-            // Repair
-            (RepairTitle, "System Repair"),
-            (RepairStarting, "Starting system repair..."),
-            (
-                RepairDism,
-                "Running DISM (Deployment Image Servicing and Management)...",
-            ),
-            (RepairDismSuccess, "DISM completed successfully"),
-            (RepairDismError, "DISM completed with errors"),
-            (RepairSfc, "Running SFC (System File Checker)..."),
-            (RepairSfcSuccess, "File scan completed"),
-            (RepairSfcWarning, "Scan completed with warnings"),
-            (RepairWait, "This may take several minutes..."),
-            // System Info
-            (InfoTitle, "System Information"),
-            (InfoOs, "OS:"),
-            (InfoVersion, "Version:"),
-            (InfoKernel, "Kernel:"),
-            (InfoHost, "Host:"),
-            (InfoArch, "Architecture:"),
-            (InfoUptime, "Uptime:"),
-            (InfoCpu, "CPU:"),
-            (InfoCores, "Cores:"),
-            (InfoMemTotal, "Total Memory:"),
-            (InfoMemUsed, "Used Memory:"),
-            (InfoMemUsage, "Memory Usage"),
-            (InfoDisks, "Disks"),
-            // Browser Cache
-            (BrowserCacheTitle, "Browser Cache"),
-            (BrowserCacheStarting, "Starting browser cache cleanup..."),
-            (BrowserCacheCleaning, "Cleaning cache from"),
-            (BrowserCacheSuccess, "Cache cleaned"),
-            (BrowserCacheNotFound, "Not found or inaccessible"),
-            (
-                BrowserCacheCloseWarning,
-                "Close browsers before running this operation for best results",
-            ),
-            // System Logs
-            (SystemLogsTitle, "System Logs"),
-            (SystemLogsStarting, "Starting system logs cleanup..."),
-            (SystemLogsCleaning, "Cleaning:"),
-            (SystemLogsProcessed, "processed"),
-            (
-                SystemLogsRequiresAdmin,
-                "Requires administrator permissions",
-            ),
-            // Recycle Bin
-            (RecycleBinTitle, "Recycle Bin"),
-            (RecycleBinStarting, "Starting recycle bin cleanup..."),
-            (RecycleBinSuccess, "Recycle bin emptied successfully"),
-            (
-                RecycleBinWarning,
-                "Warning: Some folders could not be emptied",
-            ),
-            (RecycleBinFreed, "Disk space freed"),
-            // Windows Update
-            (WindowsUpdateTitle, "Windows Update Cleanup"),
-            (WindowsUpdateStarting, "Starting Windows Update cleanup..."),
-            (WindowsUpdateCleaning, "Removing old update files..."),
-            (WindowsUpdateDiskCleanup, "Disk cleanup started"),
-            (WindowsUpdateComponents, "Running component cleanup..."),
-            (WindowsUpdateCompleted, "Windows Update cleanup completed"),
-            // Optimization
-            (OptimizeTitle, "Advanced Optimization"),
-            (OptimizeStarting, "Starting advanced system optimization..."),
-            (OptimizePrefetch, "Cleaning Prefetch files..."),
-            (OptimizePower, "Configuring high performance power plan..."),
-            (OptimizeServices, "Optimizing system services..."),
-            (OptimizeCompleted, "Advanced optimization completed"),
-            // Privacy
-            (PrivacyTitle, "Privacy and Telemetry"),
-            (PrivacyStarting, "Starting privacy configuration..."),
-            (PrivacyTelemetry, "Disabling Windows telemetry..."),
-            (PrivacyTasks, "Disabling telemetry scheduled tasks..."),
-            (PrivacyCompleted, "Privacy configuration completed"),
-            // Startup
-            (StartupTitle, "Startup Programs"),
-            (StartupAnalyzing, "Analyzing startup programs..."),
-            (StartupGettingList, "Getting startup programs list..."),
-            (StartupFound, "Startup programs found:"),
-            (
-                StartupDisableHint,
-                "To disable programs: Run 'msconfig' or 'Task Manager'",
-            ),
-            (
-                StartupRecommendation,
-                "Recommendation: Disable unnecessary programs to speed up startup",
-            ),
-            // Visual Effects
-            (VisualEffectsTitle, "Visual Effects"),
-            (VisualEffectsOptimizing, "Optimizing visual effects..."),
-            (VisualEffectsApplying, "Applying performance settings..."),
-            (VisualEffectsCompleted, "Visual effects optimized"),
-            (
-                VisualEffectsLogoutRequired,
-                "Changes will apply after logging out or restarting",
-            ),
-            (
-                VisualEffectsHint,
-                "This can significantly improve performance on older systems",
-            ),
-            // Generic
-            (Success, "Success"),
-            (Warning, "Warning"),
-            (Error, "Error"),
-            (Info, "Information"),
-        ];
-
-        // Crear HashMap e insertar traducciones
+    }
+
+    /// Carga las traducciones incluidas y las del usuario (se llama una sola vez)
+    fn init_translations() -> HashMap<(Language, I18nKey), Text> {
+        let user_dir = user_locales_dir();
         let mut translations = HashMap::new();
 
-        // Insertar traducciones en español
-        for (key, text) in es_translations {
-            translations.insert((Spanish, key), text);
-        }
+        for language in Language::ALL {
+            // Los archivos incluidos se validan en los tests
+            let embedded = Locale::parse(language.embedded_locale()).unwrap_or_default();
+            let user = user_dir
+                .as_deref()
+                .and_then(|dir| load_user_locale(dir, language))
+                .unwrap_or_default();
 
-        // Insertar traducciones en inglés
-        for (key, text) in en_translations {
-            translations.insert((English, key), text);
+            for (key, text) in embedded.texts.into_iter().chain(user.texts) {
+                translations.insert((language, key), text);
+            }
         }
 
         translations
@@ -717,6 +1220,54 @@ mod tests {
         assert_eq!(Language::English.code(), "en");
     }
 
+    #[test]
+    fn test_language_from_str() {
+        assert_eq!("EN".parse::<Language>(), Ok(Language::English));
+        assert_eq!("Español".parse::<Language>(), Ok(Language::Spanish));
+        assert_eq!("pt-BR".parse::<Language>(), Ok(Language::Portuguese));
+        assert_eq!("Français".parse::<Language>(), Ok(Language::French));
+        assert!("it".parse::<Language>().is_err());
+    }
+
+    #[test]
+    fn test_format_size_uses_language_separator() {
+        assert_eq!(
+            Language::English.format_size(5 * 1024 * 1024 / 2),
+            "2.50 MB"
+        );
+        assert_eq!(
+            Language::Spanish.format_size(5 * 1024 * 1024 / 2),
+            "2,50 MB"
+        );
+        assert_eq!(
+            Language::German.format_size(5 * 1024 * 1024 * 1024),
+            "5,00 GB"
+        );
+        assert_eq!(Language::French.format_size(2048), "2,00 KB");
+    }
+
+    #[test]
+    fn test_format_date_by_language() {
+        assert_eq!(Language::English.format_date("2026-10-02"), "10/02/2026");
+        assert_eq!(Language::Spanish.format_date("2026-10-02"), "02/10/2026");
+        assert_eq!(
+            Language::German.format_date("2026-10-02 11:15"),
+            "02.10.2026 11:15"
+        );
+        assert_eq!(Language::French.format_date("desconocida"), "desconocida");
+        assert_eq!(Language::French.format_date("2026-13-02"), "2026-13-02");
+    }
+
+    #[test]
+    fn test_language_from_locale() {
+        assert_eq!(Language::from_locale("en-US"), Some(Language::English));
+        assert_eq!(Language::from_locale("fr_CA.UTF-8"), Some(Language::French));
+        assert_eq!(Language::from_locale("pt_PT"), Some(Language::Portuguese));
+        assert_eq!(Language::from_locale("de_AT@euro"), Some(Language::German));
+        assert_eq!(Language::from_locale("C"), None);
+        assert_eq!(Language::from_locale("it_IT.UTF-8"), None);
+    }
+
     #[test]
     fn test_language_native_name() {
         assert_eq!(Language::Spanish.native_name(), "Español");
@@ -745,7 +1296,57 @@ mod tests {
         i18n.toggle_language();
         assert_eq!(i18n.current_language(), Language::English);
 
-        i18n.toggle_language();
+        // Recorre todos los idiomas y vuelve al primero
+        for _ in 1..Language::ALL.len() {
+            i18n.toggle_language();
+        }
         assert_eq!(i18n.current_language(), Language::Spanish);
     }
+
+    #[test]
+    fn test_embedded_locales_are_complete() {
+        for language in Language::ALL {
+            let locale = Locale::parse(language.embedded_locale()).unwrap();
+            assert!(locale.unknown_keys.is_empty(), "{:?}", locale.unknown_keys);
+            for key in I18nKey::ALL {
+                assert!(locale.texts.contains_key(key), "{:?}: {:?}", language, key);
+            }
+        }
+    }
+
+    #[test]
+    fn test_t_args_interpolates_and_picks_plural() {
+        let mut i18n = I18n::new(Language::Spanish);
+        let more =
+            |i18n: &I18n, count: &str| i18n.t_args(I18nKey::ConfigWarningMore, &[("count", count)]);
+        assert_eq!(more(&i18n, "1"), "+1 aviso más en el log");
+        assert_eq!(more(&i18n, "0"), "+0 avisos más en el log");
+
+        // En francés el 0 va en singular
+        i18n.set_language(Language::French);
+        assert_eq!(more(&i18n, "0"), "+0 autre avertissement dans le journal");
+        assert_eq!(more(&i18n, "2"), "+2 autres avertissements dans le journal");
+    }
+
+    #[test]
+    fn test_interpolate_keeps_unknown_placeholders() {
+        assert_eq!(
+            interpolate(
+                "{count} de {total} ({name})",
+                &[("count", "3"), ("total", "7")]
+            ),
+            "3 de 7 ({name})"
+        );
+    }
+
+    #[test]
+    fn test_locale_parse_reports_unknown_keys() {
+        let locale = Locale::parse("MenuExit = \"Salir ya\"\nMenuNope = \"?\"\n").unwrap();
+        assert_eq!(
+            locale.texts.get(&I18nKey::MenuExit),
+            Some(&Text::Plain("Salir ya".to_string()))
+        );
+        assert_eq!(locale.unknown_keys, vec!["MenuNope".to_string()]);
+        assert!(Locale::parse("MenuExit = 3").is_err());
+    }
 }
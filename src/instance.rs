@@ -0,0 +1,135 @@
+//! Instancia única de win_opt
+//!
+//! Dos instancias a la vez (dos ventanas, o la interfaz y el daemon) podrían
+//! ejecutar operaciones que se pisan: dos limpiezas sobre las mismas
+//! carpetas, dos DISM o cambios de servicios cruzados. Al arrancar se toma
+//! un mutex con nombre (en otros sistemas, el bloqueo de un archivo) que el
+//! sistema suelta al salir, también si el proceso termina de forma
+//! inesperada.
+
+/// Nombre del mutex de la instancia en ejecución
+const INSTANCE_NAME: &str = "win_opt_instance";
+
+/// Marca de la instancia en ejecución; se suelta al descartarla
+pub struct InstanceGuard {
+    _lock: imp::Lock,
+}
+
+/// Toma la marca de instancia única
+///
+/// Devuelve `None` si otra instancia de win_opt ya se está ejecutando. Si
+/// no se puede comprobar (por un error distinto), se deja continuar.
+pub fn acquire() -> Option<InstanceGuard> {
+    acquire_named(INSTANCE_NAME)
+}
+
+fn acquire_named(name: &str) -> Option<InstanceGuard> {
+    match imp::Lock::acquire(name) {
+        Ok(Some(lock)) => Some(InstanceGuard { _lock: lock }),
+        Ok(None) => None,
+        Err(e) => {
+            tracing::warn!("No se pudo comprobar si hay otra instancia: {}", e);
+            Some(InstanceGuard {
+                _lock: imp::Lock::none(),
+            })
+        }
+    }
+}
+
+#[cfg(windows)]
+mod imp {
+    use std::io;
+    use windows::Win32::Foundation::{
+        CloseHandle, ERROR_ACCESS_DENIED, ERROR_ALREADY_EXISTS, GetLastError, HANDLE,
+    };
+    use windows::Win32::System::Threading::CreateMutexW;
+    use windows::core::HSTRING;
+
+    /// Mutex con nombre, visible desde todas las sesiones
+    pub struct Lock(Option<HANDLE>);
+
+    impl Lock {
+        pub fn none() -> Self {
+            Self(None)
+        }
+
+        /// Crea el mutex; `None` si ya existía
+        pub fn acquire(name: &str) -> io::Result<Option<Self>> {
+            let name = HSTRING::from(format!("Global\\{}", name));
+            // SAFETY: el nombre es una cadena terminada en nulo que vive
+            // durante la llamada; GetLastError se lee justo después
+            let (result, last_error) = unsafe {
+                let result = CreateMutexW(None, false, &name);
+                (result, GetLastError())
+            };
+            match result {
+                Ok(handle) if last_error == ERROR_ALREADY_EXISTS => {
+                    // SAFETY: el handle lo acaba de devolver CreateMutexW
+                    unsafe {
+                        let _ = CloseHandle(handle);
+                    }
+                    Ok(None)
+                }
+                Ok(handle) => Ok(Some(Self(Some(handle)))),
+                // Lo creó una instancia con más permisos (como administrador)
+                Err(e) if e.code() == ERROR_ACCESS_DENIED.to_hresult() => Ok(None),
+                Err(e) => Err(io::Error::other(e)),
+            }
+        }
+    }
+
+    impl Drop for Lock {
+        fn drop(&mut self) {
+            if let Some(handle) = self.0.take() {
+                // SAFETY: el handle lo abrió CreateMutexW y solo se cierra aquí
+                unsafe {
+                    let _ = CloseHandle(handle);
+                }
+            }
+        }
+    }
+}
+
+#[cfg(not(windows))]
+mod imp {
+    use crate::platform;
+    use std::fs::{File, TryLockError};
+    use std::io;
+
+    /// Archivo bloqueado en la carpeta temporal
+    pub struct Lock {
+        /// Se mantiene abierto: cerrarlo suelta el bloqueo
+        _file: Option<File>,
+    }
+
+    impl Lock {
+        pub fn none() -> Self {
+            Self { _file: None }
+        }
+
+        /// Bloquea el archivo; `None` si otro proceso lo tiene bloqueado
+        pub fn acquire(name: &str) -> io::Result<Option<Self>> {
+            let file = File::create(platform::temp_dir().join(format!("{}.lock", name)))?;
+            match file.try_lock() {
+                Ok(()) => Ok(Some(Self { _file: Some(file) })),
+                Err(TryLockError::WouldBlock) => Ok(None),
+                Err(TryLockError::Error(e)) => Err(e),
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_second_instance_is_detected_until_the_first_exits() {
+        let name = "win_opt_test_instance";
+        let first = acquire_named(name).expect("primera instancia");
+        assert!(acquire_named(name).is_none());
+
+        drop(first);
+        assert!(acquire_named(name).is_some());
+    }
+}
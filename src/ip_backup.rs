@@ -0,0 +1,289 @@
+//! Copia de seguridad de la configuración IP estática
+//!
+//! `netsh int ip reset` devuelve todos los adaptadores a DHCP, así que antes de
+//! restablecer la pila TCP/IP se guardan las direcciones, puertas de enlace y
+//! DNS de los adaptadores con IP estática en `static_ip_backup.toml`. Tras el
+//! reinicio, la vista de red ofrece volver a aplicarlas.
+
+use crate::config::Config;
+use crate::platform;
+use crate::utils::{is_admin, today_iso};
+use crate::{log_error, log_info, log_warn};
+use serde::{Deserialize, Serialize};
+use std::fs;
+use std::path::PathBuf;
+
+/// Script de PowerShell que lista los adaptadores IPv4 sin DHCP en formato
+/// `alias|ip|prefijo|puerta de enlace|dns1,dns2`
+const QUERY_STATIC_SCRIPT: &str = "Get-NetIPInterface -AddressFamily IPv4 -Dhcp Disabled | ForEach-Object { \
+     $a = Get-NetIPAddress -InterfaceIndex $_.ifIndex -AddressFamily IPv4 -ErrorAction SilentlyContinue | Select-Object -First 1; \
+     $g = (Get-NetRoute -InterfaceIndex $_.ifIndex -DestinationPrefix '0.0.0.0/0' -ErrorAction SilentlyContinue | Select-Object -First 1).NextHop; \
+     $d = (Get-DnsClientServerAddress -InterfaceIndex $_.ifIndex -AddressFamily IPv4 -ErrorAction SilentlyContinue).ServerAddresses -join ','; \
+     '{0}|{1}|{2}|{3}|{4}' -f $_.InterfaceAlias, $a.IPAddress, $a.PrefixLength, $g, $d }";
+
+/// Configuración IPv4 estática de un adaptador
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct StaticIpConfig {
+    /// Nombre del adaptador (`InterfaceAlias`)
+    pub interface: String,
+    pub address: String,
+    pub prefix_length: u8,
+    pub gateway: Option<String>,
+    #[serde(default)]
+    pub dns_servers: Vec<String>,
+}
+
+/// Archivo de copia de seguridad
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+struct StaticIpBackup {
+    /// Fecha de la copia (`yyyy-MM-dd`)
+    date: String,
+    #[serde(default)]
+    interfaces: Vec<StaticIpConfig>,
+}
+
+/// Ruta del archivo de copia de seguridad
+fn backup_file() -> std::io::Result<PathBuf> {
+    Ok(Config::get_config_dir()?.join("static_ip_backup.toml"))
+}
+
+/// Fecha de la copia pendiente de reaplicar, si existe
+pub fn pending_backup_date() -> Option<String> {
+    load_backup().map(|backup| backup.date)
+}
+
+/// Carga la copia de seguridad, si existe
+fn load_backup() -> Option<StaticIpBackup> {
+    let contents = fs::read_to_string(backup_file().ok()?).ok()?;
+    toml::from_str(&contents).ok()
+}
+
+/// Guarda la configuración estática actual antes de restablecer TCP/IP
+///
+/// Devuelve `false` si no se pudo leer o guardar la configuración, en cuyo
+/// caso el restablecimiento no debe continuar.
+pub fn backup_static_config(app: &mut crate::app::App) -> bool {
+    log_info!(app, "💾 Guardando la configuración IP estática...");
+
+    let configs = match platform::command("powershell")
+        .args(["-NoProfile", "-Command", QUERY_STATIC_SCRIPT])
+        .output()
+    {
+        Ok(output) if output.status.success() => {
+            parse_static_configs(&String::from_utf8_lossy(&output.stdout))
+        }
+        Ok(_) => {
+            log_error!(app, "❌ No se pudo leer la configuración IP actual");
+            return false;
+        }
+        Err(e) => {
+            log_error!(app, "❌ Error: {}", e);
+            return false;
+        }
+    };
+
+    if configs.is_empty() {
+        log_info!(
+            app,
+            "ℹ️  Ningún adaptador usa IP estática: no hace falta copia de seguridad"
+        );
+        return true;
+    }
+
+    let backup = StaticIpBackup {
+        date: today_iso(),
+        interfaces: configs,
+    };
+    let result = toml::to_string_pretty(&backup)
+        .map_err(|e| std::io::Error::new(std::io::ErrorKind::InvalidData, e.to_string()))
+        .and_then(|contents| fs::write(backup_file()?, contents));
+
+    match result {
+        Ok(()) => {
+            for config in &backup.interfaces {
+                log_info!(
+                    app,
+                    "   {}: {}/{}",
+                    config.interface,
+                    config.address,
+                    config.prefix_length
+                );
+            }
+            log_info!(
+                app,
+                "💡 Tras reiniciar, abre Red y pulsa A para volver a aplicar la IP estática"
+            );
+            true
+        }
+        Err(e) => {
+            log_error!(app, "❌ No se pudo guardar la copia de seguridad: {}", e);
+            false
+        }
+    }
+}
+
+/// Vuelve a aplicar la configuración estática guardada
+pub fn execute_restore_static_config(app: &mut crate::app::App) {
+    log_info!(app, "");
+    let Some(backup) = load_backup() else {
+        log_info!(app, "ℹ️  No hay configuración IP estática guardada");
+        return;
+    };
+    if !is_admin() {
+        log_error!(
+            app,
+            "⛔ ERROR: Esta operación requiere permisos de Administrador"
+        );
+        return;
+    }
+
+    let mut failed = 0;
+    for config in &backup.interfaces {
+        log_info!(app, "🔧 Aplicando IP estática en {}...", config.interface);
+        match platform::command("powershell")
+            .args(["-NoProfile", "-Command", &restore_script(config)])
+            .output()
+        {
+            Ok(output) if output.status.success() => {
+                log_info!(app, "✅ {}: {}", config.interface, config.address)
+            }
+            Ok(output) => {
+                failed += 1;
+                log_error!(
+                    app,
+                    "❌ {}: {}",
+                    config.interface,
+                    String::from_utf8_lossy(&output.stderr).trim()
+                );
+            }
+            Err(e) => {
+                failed += 1;
+                log_error!(app, "❌ Error: {}", e);
+            }
+        }
+    }
+
+    if failed == 0 {
+        if let Ok(path) = backup_file() {
+            let _ = fs::remove_file(path);
+        }
+        log_info!(app, "✅ Configuración IP estática restaurada");
+    } else {
+        log_warn!(
+            app,
+            "⚠️  La copia se conserva para poder reintentarlo (pulsa A de nuevo)"
+        );
+    }
+}
+
+/// Script de PowerShell que aplica la configuración de un adaptador
+fn restore_script(config: &StaticIpConfig) -> String {
+    let alias = quote(&config.interface);
+    let mut script = format!(
+        "$ErrorActionPreference = 'Stop'; \
+         Set-NetIPInterface -InterfaceAlias {alias} -AddressFamily IPv4 -Dhcp Disabled; \
+         Get-NetIPAddress -InterfaceAlias {alias} -AddressFamily IPv4 -ErrorAction SilentlyContinue | Remove-NetIPAddress -Confirm:$false; \
+         Remove-NetRoute -InterfaceAlias {alias} -DestinationPrefix '0.0.0.0/0' -Confirm:$false -ErrorAction SilentlyContinue; \
+         New-NetIPAddress -InterfaceAlias {alias} -AddressFamily IPv4 -IPAddress {} -PrefixLength {}",
+        quote(&config.address),
+        config.prefix_length
+    );
+    if let Some(gateway) = &config.gateway {
+        script.push_str(&format!(" -DefaultGateway {}", quote(gateway)));
+    }
+    script.push_str(" | Out-Null");
+    if !config.dns_servers.is_empty() {
+        let servers: Vec<String> = config.dns_servers.iter().map(|s| quote(s)).collect();
+        script.push_str(&format!(
+            "; Set-DnsClientServerAddress -InterfaceAlias {alias} -ServerAddresses ({})",
+            servers.join(",")
+        ));
+    }
+    script
+}
+
+/// Cadena entre comillas simples de PowerShell
+fn quote(value: &str) -> String {
+    format!("'{}'", value.replace('\'', "''"))
+}
+
+/// Parsea la salida del script de configuración estática
+///
+/// Se descartan las interfaces sin dirección y las de loopback o APIPA, que
+/// también aparecen con DHCP desactivado.
+fn parse_static_configs(output: &str) -> Vec<StaticIpConfig> {
+    output
+        .lines()
+        .filter_map(|line| {
+            let fields: Vec<&str> = line.split('|').map(str::trim).collect();
+            let [interface, address, prefix, gateway, dns] = fields[..] else {
+                return None;
+            };
+            if address.is_empty() || address.starts_with("127.") || address.starts_with("169.254.")
+            {
+                return None;
+            }
+            Some(StaticIpConfig {
+                interface: interface.to_string(),
+                address: address.to_string(),
+                prefix_length: prefix.parse().ok()?,
+                gateway: Some(gateway)
+                    .filter(|g| !g.is_empty() && *g != "0.0.0.0")
+                    .map(str::to_string),
+                dns_servers: dns
+                    .split(',')
+                    .filter(|s| !s.is_empty())
+                    .map(str::to_string)
+                    .collect(),
+            })
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_static_configs() {
+        let output = "Ethernet|192.168.1.10|24|192.168.1.1|1.1.1.1,8.8.8.8\r\n\
+                      Loopback Pseudo-Interface 1|127.0.0.1|8||\r\n\
+                      Ethernet 2|169.254.10.3|16||\r\n\
+                      Lab|10.0.0.5|8||\r\n";
+        let configs = parse_static_configs(output);
+        assert_eq!(configs.len(), 2);
+        assert_eq!(configs[0].interface, "Ethernet");
+        assert_eq!(configs[0].prefix_length, 24);
+        assert_eq!(configs[0].gateway.as_deref(), Some("192.168.1.1"));
+        assert_eq!(configs[0].dns_servers, vec!["1.1.1.1", "8.8.8.8"]);
+        assert_eq!(configs[1].gateway, None);
+        assert!(configs[1].dns_servers.is_empty());
+    }
+
+    #[test]
+    fn test_restore_script() {
+        let config = StaticIpConfig {
+            interface: "Bob's LAN".to_string(),
+            address: "10.0.0.5".to_string(),
+            prefix_length: 8,
+            gateway: None,
+            dns_servers: vec!["10.0.0.1".to_string()],
+        };
+        let script = restore_script(&config);
+        assert!(script.contains("-InterfaceAlias 'Bob''s LAN'"));
+        assert!(script.contains("-IPAddress '10.0.0.5' -PrefixLength 8 | Out-Null"));
+        assert!(!script.contains("-DefaultGateway"));
+        assert!(script.ends_with("-ServerAddresses ('10.0.0.1')"));
+    }
+
+    #[test]
+    fn test_backup_roundtrip() {
+        let backup = StaticIpBackup {
+            date: "2026-10-16".to_string(),
+            interfaces: parse_static_configs("Ethernet|192.168.1.10|24|192.168.1.1|1.1.1.1"),
+        };
+        let contents = toml::to_string_pretty(&backup).unwrap();
+        let loaded: StaticIpBackup = toml::from_str(&contents).unwrap();
+        assert_eq!(loaded.interfaces, backup.interfaces);
+    }
+}
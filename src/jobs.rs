@@ -0,0 +1,353 @@
+//! Cola de tareas en segundo plano
+//!
+//! Las operaciones largas (DISM, SFC, análisis de Defender...) se lanzan
+//! como tareas de la cola en lugar de ocupar un único worker, de modo que se
+//! pueden encadenar varias a la vez. Las tareas exclusivas (las que usan DISM,
+//! que no admite dos ejecuciones simultáneas) esperan en cola mientras otra
+//! tarea exclusiva está en marcha. Cada tarea guarda sus propios logs.
+
+use crate::i18n::I18nKey;
+use crate::logger::{LogEntry, LogLevel};
+use crate::retry::FailedItem;
+use crate::types::{OperationState, View, WorkerHandle, WorkerMessage};
+use std::sync::atomic::Ordering;
+use std::time::Instant;
+use tracing::Span;
+
+/// Función que lanza el worker de una tarea cuando le llega el turno
+pub type JobLauncher = Box<dyn FnOnce() -> WorkerHandle>;
+
+/// Estado de una tarea
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum JobStatus {
+    Pending,
+    Running,
+    Completed,
+    Failed,
+}
+
+impl JobStatus {
+    /// Indica si la tarea sigue en cola o en ejecución
+    pub fn is_active(self) -> bool {
+        matches!(self, JobStatus::Pending | JobStatus::Running)
+    }
+
+    /// Clave de traducción del estado
+    pub fn label_key(self) -> I18nKey {
+        match self {
+            JobStatus::Pending => I18nKey::JobPending,
+            JobStatus::Running => I18nKey::JobRunning,
+            JobStatus::Completed => I18nKey::JobCompleted,
+            JobStatus::Failed => I18nKey::JobFailed,
+        }
+    }
+}
+
+/// Tarea de la cola
+pub struct Job {
+    pub id: usize,
+    /// Vista que lanzó la tarea (da el título y recibe sus mensajes)
+    pub view: View,
+    pub status: JobStatus,
+    /// No puede ejecutarse a la vez que otra tarea exclusiva
+    pub exclusive: bool,
+    /// Logs propios de la tarea
+    pub logs: Vec<LogEntry>,
+    /// Momento en que empezó a ejecutarse
+    pub started: Option<Instant>,
+    /// Duración total, una vez terminada
+    pub elapsed_secs: Option<u64>,
+    /// Progreso del comando en curso, si lo informa
+    pub progress: Option<f32>,
+    /// El comando en curso no produce salida
+    pub stalled: bool,
+    /// Elementos que fallaron y se pueden reintentar
+    pub failed: Vec<FailedItem>,
+    /// Span de la operación que lanzó la tarea (identificador de correlación)
+    pub span: Span,
+    /// Estado informado por el worker
+    state: OperationState,
+    handle: Option<WorkerHandle>,
+    launcher: Option<JobLauncher>,
+}
+
+impl Job {
+    /// Segundos de ejecución (hasta ahora si sigue en marcha)
+    pub fn elapsed(&self) -> Option<u64> {
+        self.elapsed_secs
+            .or_else(|| self.started.map(|started| started.elapsed().as_secs()))
+    }
+}
+
+/// Mensaje recibido de una tarea
+#[derive(Debug)]
+pub struct JobMessage {
+    pub job_id: usize,
+    pub view: View,
+    pub message: WorkerMessage,
+}
+
+/// Cola de tareas
+#[derive(Default)]
+pub struct JobQueue {
+    jobs: Vec<Job>,
+    next_id: usize,
+}
+
+impl JobQueue {
+    /// Añade una tarea; se lanza al momento salvo que deba esperar su turno
+    ///
+    /// La tarea hereda el span actual, con el que se registran sus mensajes.
+    pub fn enqueue(&mut self, view: View, exclusive: bool, launcher: JobLauncher) -> usize {
+        self.next_id += 1;
+        self.jobs.push(Job {
+            id: self.next_id,
+            view,
+            status: JobStatus::Pending,
+            exclusive,
+            logs: Vec::new(),
+            started: None,
+            elapsed_secs: None,
+            progress: None,
+            stalled: false,
+            failed: Vec::new(),
+            span: Span::current(),
+            state: OperationState::Starting,
+            handle: None,
+            launcher: Some(launcher),
+        });
+        self.start_pending();
+        self.next_id
+    }
+
+    /// Todas las tareas, en orden de llegada
+    pub fn jobs(&self) -> &[Job] {
+        &self.jobs
+    }
+
+    /// Tarea por identificador
+    pub fn get(&self, id: usize) -> Option<&Job> {
+        self.jobs.iter().find(|job| job.id == id)
+    }
+
+    /// Última tarea activa lanzada desde una vista
+    pub fn active_for(&self, view: View) -> Option<&Job> {
+        self.jobs
+            .iter()
+            .rev()
+            .find(|job| job.view == view && job.status.is_active())
+    }
+
+    /// Número de tareas en cola o en ejecución
+    pub fn active_count(&self) -> usize {
+        self.jobs
+            .iter()
+            .filter(|job| job.status.is_active())
+            .count()
+    }
+
+    /// Pide a una tarea que termine; una tarea en cola se descarta sin lanzarse
+    pub fn cancel(&mut self, id: usize) {
+        let Some(job) = self.jobs.iter_mut().find(|job| job.id == id) else {
+            return;
+        };
+        match job.status {
+            JobStatus::Pending => {
+                job.launcher = None;
+                job.status = JobStatus::Failed;
+                job.logs.push(LogEntry::new(
+                    LogLevel::Warning,
+                    "Tarea cancelada antes de empezar",
+                ));
+            }
+            JobStatus::Running => {
+                if let Some(handle) = &job.handle {
+                    handle.cancel_flag.store(true, Ordering::Relaxed);
+                }
+            }
+            _ => {}
+        }
+    }
+
+    /// Termina el paso bloqueado de una tarea y continúa con el siguiente
+    pub fn skip_step(&mut self, id: usize) {
+        if let Some(handle) = self
+            .jobs
+            .iter()
+            .find(|job| job.id == id)
+            .and_then(|job| job.handle.as_ref())
+        {
+            handle.skip_flag.store(true, Ordering::Relaxed);
+        }
+    }
+
+    /// Elimina las tareas terminadas
+    pub fn clear_finished(&mut self) {
+        self.jobs.retain(|job| job.status.is_active());
+    }
+
+    /// Recoge los mensajes de las tareas en ejecución y lanza las pendientes
+    ///
+    /// Los mensajes se guardan en los logs de cada tarea y se devuelven para
+    /// que la vista que lanzó la tarea pueda mostrarlos.
+    pub fn poll(&mut self) -> Vec<JobMessage> {
+        let mut messages = Vec::new();
+        for job in &mut self.jobs {
+            let Some(handle) = &job.handle else {
+                continue;
+            };
+            let mut finished = false;
+            let _span = job.span.enter();
+            while let Ok(message) = handle.receiver.try_recv() {
+                match &message {
+                    WorkerMessage::Log(log) => {
+                        tracing::info!("{}", log);
+                        job.logs.push(LogEntry::new(LogLevel::Info, log.clone()));
+                    }
+                    WorkerMessage::Error(error) => {
+                        tracing::error!("{}", error);
+                        job.logs.push(LogEntry::new(
+                            LogLevel::Error,
+                            format!("❌ ERROR: {}", error),
+                        ));
+                    }
+                    WorkerMessage::StateChange(state) => {
+                        job.state = *state;
+                        job.progress = None;
+                    }
+                    WorkerMessage::Progress(percent) => job.progress = Some(*percent),
+                    WorkerMessage::Stalled(stalled) => job.stalled = *stalled,
+                    WorkerMessage::ItemFailed(item) => job.failed.push(item.clone()),
+                    WorkerMessage::Completed => finished = true,
+                    WorkerMessage::StatsUpdate(_) => {}
+                }
+                messages.push(JobMessage {
+                    job_id: job.id,
+                    view: job.view,
+                    message,
+                });
+            }
+            if finished {
+                job.handle = None;
+                job.stalled = false;
+                job.status = if job.state == OperationState::Completed {
+                    JobStatus::Completed
+                } else {
+                    JobStatus::Failed
+                };
+                job.elapsed_secs = job.started.map(|started| started.elapsed().as_secs());
+            }
+        }
+        self.start_pending();
+        messages
+    }
+
+    /// Lanza las tareas pendientes que no entran en conflicto con las activas
+    fn start_pending(&mut self) {
+        let mut exclusive_running = self
+            .jobs
+            .iter()
+            .any(|job| job.exclusive && job.status == JobStatus::Running);
+        for job in &mut self.jobs {
+            if job.status != JobStatus::Pending || (job.exclusive && exclusive_running) {
+                continue;
+            }
+            let Some(launcher) = job.launcher.take() else {
+                continue;
+            };
+            job.handle = Some(job.span.in_scope(launcher));
+            job.status = JobStatus::Running;
+            job.started = Some(Instant::now());
+            exclusive_running |= job.exclusive;
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::sync::Arc;
+    use std::sync::atomic::AtomicBool;
+    use std::sync::mpsc;
+
+    /// Worker de prueba que termina enviando el estado indicado
+    fn finished_worker(state: OperationState) -> JobLauncher {
+        Box::new(move || {
+            let (sender, receiver) = mpsc::channel();
+            sender
+                .send(WorkerMessage::Log("hecho".to_string()))
+                .unwrap();
+            sender.send(WorkerMessage::StateChange(state)).unwrap();
+            sender.send(WorkerMessage::Completed).unwrap();
+            WorkerHandle {
+                receiver,
+                task_handle: None,
+                cancel_flag: Arc::new(AtomicBool::new(false)),
+                skip_flag: Arc::new(AtomicBool::new(false)),
+            }
+        })
+    }
+
+    #[test]
+    fn test_exclusive_jobs_wait_their_turn() {
+        let mut queue = JobQueue::default();
+        let repair = queue.enqueue(
+            View::Repair,
+            true,
+            finished_worker(OperationState::Completed),
+        );
+        let update = queue.enqueue(
+            View::WindowsUpdate,
+            true,
+            finished_worker(OperationState::Failed),
+        );
+        let scan = queue.enqueue(
+            View::Defender,
+            false,
+            finished_worker(OperationState::Completed),
+        );
+
+        // La segunda tarea de DISM espera; la de Defender no
+        assert_eq!(queue.get(repair).unwrap().status, JobStatus::Running);
+        assert_eq!(queue.get(update).unwrap().status, JobStatus::Pending);
+        assert_eq!(queue.get(scan).unwrap().status, JobStatus::Running);
+
+        let messages = queue.poll();
+        assert_eq!(messages.len(), 6);
+        assert_eq!(queue.get(repair).unwrap().status, JobStatus::Completed);
+        let logs = &queue.get(repair).unwrap().logs;
+        assert_eq!(logs.len(), 1);
+        assert_eq!(logs[0].message, "hecho");
+        assert_eq!(logs[0].level, LogLevel::Info);
+        assert_eq!(queue.get(update).unwrap().status, JobStatus::Running);
+
+        queue.poll();
+        assert_eq!(queue.get(update).unwrap().status, JobStatus::Failed);
+        assert_eq!(queue.active_count(), 0);
+        queue.clear_finished();
+        assert!(queue.jobs().is_empty());
+    }
+
+    #[test]
+    fn test_cancel_pending_job() {
+        let mut queue = JobQueue::default();
+        queue.enqueue(
+            View::Repair,
+            true,
+            finished_worker(OperationState::Completed),
+        );
+        let pending = queue.enqueue(
+            View::WindowsUpdate,
+            true,
+            finished_worker(OperationState::Completed),
+        );
+        assert_eq!(
+            queue.active_for(View::WindowsUpdate).map(|job| job.id),
+            Some(pending)
+        );
+
+        queue.cancel(pending);
+        assert_eq!(queue.get(pending).unwrap().status, JobStatus::Failed);
+        assert!(queue.active_for(View::WindowsUpdate).is_none());
+    }
+}
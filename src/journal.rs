@@ -0,0 +1,244 @@
+//! Diario de las operaciones en curso
+//!
+//! Antes de empezar a modificar el sistema, cada operación anota en
+//! `journal.toml` (carpeta de configuración) cuándo empezó y sobre qué actúa
+//! (carpetas, servicios, pasos...). Al terminar, bien, mal o cancelada, la
+//! anotación se borra. Si el programa o el equipo se cierran a mitad, la
+//! anotación sigue ahí y el siguiente arranque avisa de lo que quedó
+//! interrumpido para reanudarlo o, si se puede, deshacerlo.
+//!
+//! El archivo se escribe en uno temporal que luego se renombra, para que un
+//! corte de luz no lo deje a medio escribir.
+
+use crate::config::Config;
+use crate::operation::{self, Operation};
+use crate::services;
+use crate::types::View;
+use crate::utils::now_utc_iso;
+use serde::{Deserialize, Serialize};
+use std::fs;
+use std::io;
+use std::path::PathBuf;
+use std::sync::{Mutex, PoisonError};
+
+/// Serializa las lecturas y escrituras del diario entre hilos
+static JOURNAL_LOCK: Mutex<()> = Mutex::new(());
+
+/// Operación anotada que aún no ha terminado
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct JournalEntry {
+    /// Identificador de la operación (`clean`, `optimize`...)
+    pub operation: String,
+    /// Fecha y hora UTC de inicio (`yyyy-MM-dd HH:mm`)
+    pub started: String,
+    /// Elementos sobre los que actúa
+    #[serde(default)]
+    pub targets: Vec<String>,
+}
+
+impl JournalEntry {
+    /// Vista de la TUI que ejecuta la operación
+    pub fn view(&self) -> Option<View> {
+        operation::BUILTIN_OPERATIONS
+            .iter()
+            .find(|op| op.name() == self.operation)
+            .map(|op| op.view())
+    }
+}
+
+/// Contenido de `journal.toml`
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+struct Journal {
+    #[serde(default, rename = "entry")]
+    entries: Vec<JournalEntry>,
+}
+
+impl Journal {
+    /// Anota una operación, sustituyendo la anotación anterior de la misma
+    fn begin(&mut self, entry: JournalEntry) {
+        self.finish(&entry.operation);
+        self.entries.push(entry);
+    }
+
+    /// Borra la anotación de una operación
+    ///
+    /// Retorna `true` si la había.
+    fn finish(&mut self, operation: &str) -> bool {
+        let before = self.entries.len();
+        self.entries.retain(|entry| entry.operation != operation);
+        self.entries.len() != before
+    }
+}
+
+/// Forma de deshacer una operación interrumpida
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Rollback {
+    /// Restaurar el tipo de inicio de los servicios desde la copia
+    RestoreServices,
+    /// Recuperar lo borrado desde la cuarentena
+    Quarantine,
+}
+
+/// Forma de deshacer la operación, si la tiene
+pub fn rollback_for(operation: &str, config: &Config) -> Option<Rollback> {
+    match operation {
+        "optimize" | "privacy" => services::load_backup()
+            .filter(|backup| !backup.services.is_empty())
+            .map(|_| Rollback::RestoreServices),
+        "clean" | "browser-cache" | "system-logs" if config.quarantine.enabled => {
+            Some(Rollback::Quarantine)
+        }
+        _ => None,
+    }
+}
+
+/// Ruta del diario
+///
+/// Las pruebas ejecutan workers que anotan operaciones sin terminarlas; su
+/// diario va a la carpeta temporal para no dejar avisos de operaciones
+/// interrumpidas en el real.
+fn journal_file() -> io::Result<PathBuf> {
+    if cfg!(test) {
+        return Ok(std::env::temp_dir().join("win_opt-test-journal.toml"));
+    }
+    Ok(Config::get_config_dir()?.join("journal.toml"))
+}
+
+fn load_journal() -> Journal {
+    journal_file()
+        .and_then(fs::read_to_string)
+        .ok()
+        .and_then(|contents| toml::from_str(&contents).ok())
+        .unwrap_or_default()
+}
+
+/// Guarda el diario en un archivo temporal y lo renombra
+fn save_journal(journal: &Journal) -> io::Result<()> {
+    let path = journal_file()?;
+    if journal.entries.is_empty() {
+        return match fs::remove_file(&path) {
+            Err(e) if e.kind() != io::ErrorKind::NotFound => Err(e),
+            _ => Ok(()),
+        };
+    }
+    let contents = toml::to_string_pretty(journal).map_err(|e| {
+        io::Error::new(
+            io::ErrorKind::InvalidData,
+            format!("TOML serialize error: {}", e),
+        )
+    })?;
+    let temp = path.with_extension("toml.tmp");
+    fs::write(&temp, contents)?;
+    fs::rename(&temp, &path)
+}
+
+/// Modifica el diario bajo el bloqueo; los errores solo se registran
+fn update(change: impl FnOnce(&mut Journal) -> bool) {
+    let _lock = JOURNAL_LOCK.lock().unwrap_or_else(PoisonError::into_inner);
+    let mut journal = load_journal();
+    if change(&mut journal)
+        && let Err(e) = save_journal(&journal)
+    {
+        tracing::warn!("No se pudo guardar el diario de operaciones: {}", e);
+    }
+}
+
+/// Anota que la operación de la vista empieza a modificar `targets`
+///
+/// Las vistas que no son operaciones incluidas no se anotan.
+pub fn begin(view: View, targets: Vec<String>) {
+    let Some(operation) = operation::name_for_view(view) else {
+        return;
+    };
+    let entry = JournalEntry {
+        operation: operation.to_string(),
+        started: now_utc_iso(),
+        targets,
+    };
+    update(|journal| {
+        journal.begin(entry);
+        true
+    });
+}
+
+/// Borra la anotación de la operación de la vista al terminar
+pub fn finish(view: View) {
+    if let Some(operation) = operation::name_for_view(view) {
+        update(|journal| journal.finish(operation));
+    }
+}
+
+/// Borra la anotación de una operación interrumpida ya atendida
+pub fn dismiss(operation: &str) {
+    update(|journal| journal.finish(operation));
+}
+
+/// Operaciones que quedaron interrumpidas en la ejecución anterior
+pub fn interrupted() -> Vec<JournalEntry> {
+    let _lock = JOURNAL_LOCK.lock().unwrap_or_else(PoisonError::into_inner);
+    load_journal().entries
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn entry(operation: &str, targets: &[&str]) -> JournalEntry {
+        JournalEntry {
+            operation: operation.to_string(),
+            started: "2026-10-16 09:00".to_string(),
+            targets: targets.iter().map(|target| target.to_string()).collect(),
+        }
+    }
+
+    #[test]
+    fn test_journal_keeps_one_entry_per_operation() {
+        let mut journal = Journal::default();
+        journal.begin(entry("clean", &["C:\\Temp"]));
+        journal.begin(entry("optimize", &["SysMain"]));
+        journal.begin(entry("clean", &["D:\\Temp"]));
+        assert_eq!(journal.entries.len(), 2);
+        assert_eq!(journal.entries[1].targets, ["D:\\Temp"]);
+
+        assert!(journal.finish("clean"));
+        assert!(!journal.finish("clean"));
+        assert_eq!(journal.entries, [entry("optimize", &["SysMain"])]);
+    }
+
+    #[test]
+    fn test_journal_round_trip() {
+        let mut journal = Journal::default();
+        journal.begin(entry("font-cache", &["Detener el servicio"]));
+        let contents = toml::to_string_pretty(&journal).unwrap();
+        assert!(contents.contains("[[entry]]"));
+        assert_eq!(toml::from_str::<Journal>(&contents).unwrap(), journal);
+
+        // Un diario sin objetivos (de otra versión) también se lee
+        let old: Journal =
+            toml::from_str("[[entry]]\noperation = \"clean\"\nstarted = \"2026-10-16 09:00\"\n")
+                .unwrap();
+        assert!(old.entries[0].targets.is_empty());
+        assert_eq!(old.entries[0].view(), Some(View::Clean));
+    }
+
+    #[test]
+    fn test_cleanups_roll_back_from_quarantine_only_when_enabled() {
+        let mut config = Config::default();
+        assert_eq!(rollback_for("clean", &config), None);
+        config.quarantine.enabled = true;
+        assert_eq!(rollback_for("clean", &config), Some(Rollback::Quarantine));
+        assert_eq!(rollback_for("recycle-bin", &config), None);
+    }
+
+    #[test]
+    fn test_tests_leave_the_real_journal_untouched() {
+        let real = Config::get_config_dir().unwrap().join("journal.toml");
+        assert_ne!(journal_file().unwrap(), real);
+
+        let before = fs::read_to_string(&real).ok();
+        begin(View::Network, vec!["ipconfig /flushdns".to_string()]);
+        assert!(journal_file().unwrap().exists());
+        finish(View::Network);
+        assert_eq!(fs::read_to_string(&real).ok(), before);
+    }
+}
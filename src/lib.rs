@@ -3,18 +3,81 @@
 // Este módulo expone todos los componentes de la aplicación
 // organizados en submódulos según su responsabilidad.
 
+pub mod about;
+pub mod activation;
+pub mod adapters;
 pub mod animation;
 pub mod app;
+pub mod app_logs;
+pub mod banner;
+pub mod battery;
+pub mod benchmark;
+pub mod boot;
 pub mod cleanup;
+pub mod clipboard;
 pub mod config;
+pub mod config_watch;
+pub mod daemon;
+pub mod defender;
+pub mod downloads;
+pub mod drivers;
+pub mod drives;
+pub mod env_path;
 pub mod error;
 pub mod executor;
+pub mod firewall;
+pub mod footer;
+pub mod help;
+pub mod history;
+pub mod hooks;
+pub mod hosts;
 pub mod i18n;
+pub mod instance;
+pub mod ip_backup;
+pub mod jobs;
+pub mod journal;
+pub mod log_search;
 pub mod logger;
+pub mod memory;
+pub mod menu;
+pub mod mouse;
+pub mod network_info;
+pub mod notify;
+pub mod onboarding;
+pub mod operation;
 pub mod optimization;
+pub mod palette;
+pub mod platform;
+pub mod ports;
+pub mod profiles;
+pub mod programs;
+pub mod proxy;
+pub mod quarantine;
+pub mod reboot;
+pub mod registry;
+pub mod repair;
+pub mod responsive;
+pub mod retry;
+pub mod safe_mode;
+pub mod safety;
+pub mod services;
+pub mod settings;
+pub mod simulate;
+pub mod size_cache;
+pub mod startup;
+pub mod symbols;
+pub mod tasks;
 pub mod theme;
+pub mod timer;
+pub mod top_processes;
 pub mod types;
+pub mod update;
+pub mod user_profiles;
 pub mod utils;
+pub mod version;
+pub mod wifi;
+pub mod windows_update;
+pub mod winver;
 
 // Re-exportar los tipos principales para facilitar su uso
 pub use animation::{Pulse, Spinner, progress_bar, sparkline};
@@ -22,7 +85,10 @@ pub use app::App;
 pub use config::Config;
 pub use error::{Result, WinOptError};
 pub use i18n::{I18n, I18nKey, Language};
-pub use logger::{LogLevel, log};
+pub use logger::{LogEntry, LogLevel, log};
 pub use theme::{ColorPalette, Theme};
-pub use types::{CleanStats, OperationState, View};
+pub use types::{
+    CleanStats, LocationStats, NetworkStats, OperationState, OperationStats, RepairStats,
+    ServiceStats, View,
+};
 pub use utils::{format_uptime, is_admin};
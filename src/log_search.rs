@@ -0,0 +1,140 @@
+//! Búsqueda en los logs de las operaciones
+//!
+//! Con `/` se escribe un patrón que filtra las líneas del panel de logs y
+//! `n`/`N` saltan entre las coincidencias. El patrón se interpreta como
+//! expresión regular (sin distinguir mayúsculas); si no es una expresión
+//! válida se busca como texto literal.
+
+use crate::logger::LogEntry;
+use regex::{Regex, RegexBuilder};
+
+/// Estado de la búsqueda en los logs
+#[derive(Debug, Default)]
+pub struct LogSearch {
+    query: String,
+    /// Escribiendo el patrón
+    pub editing: bool,
+    /// Coincidencia seleccionada (índice dentro de las coincidencias)
+    current: usize,
+    regex: Option<Regex>,
+}
+
+impl LogSearch {
+    /// Patrón buscado
+    pub fn query(&self) -> &str {
+        &self.query
+    }
+
+    /// Indica si hay un patrón aplicado
+    pub fn is_active(&self) -> bool {
+        !self.query.is_empty()
+    }
+
+    /// Añade un carácter al patrón
+    pub fn push(&mut self, c: char) {
+        self.query.push(c);
+        self.update();
+    }
+
+    /// Borra el último carácter del patrón
+    pub fn pop(&mut self) {
+        self.query.pop();
+        self.update();
+    }
+
+    /// Quita el patrón y sale del modo búsqueda
+    pub fn clear(&mut self) {
+        self.query.clear();
+        self.editing = false;
+        self.update();
+    }
+
+    /// Indica si una línea coincide con el patrón
+    pub fn is_match(&self, message: &str) -> bool {
+        match &self.regex {
+            Some(regex) => regex.is_match(message),
+            None => message.to_lowercase().contains(&self.query.to_lowercase()),
+        }
+    }
+
+    /// Índices de las líneas que coinciden, en orden
+    pub fn matches(&self, logs: &[LogEntry]) -> Vec<usize> {
+        logs.iter()
+            .enumerate()
+            .filter(|(_, entry)| self.is_match(&entry.message))
+            .map(|(idx, _)| idx)
+            .collect()
+    }
+
+    /// Posición de la coincidencia seleccionada entre `total` coincidencias
+    pub fn current(&self, total: usize) -> usize {
+        self.current.min(total.saturating_sub(1))
+    }
+
+    /// Selecciona la siguiente coincidencia (vuelve a la primera al final)
+    pub fn next(&mut self, total: usize) {
+        if total > 0 {
+            self.current = (self.current(total) + 1) % total;
+        }
+    }
+
+    /// Selecciona la coincidencia anterior (salta a la última al principio)
+    pub fn prev(&mut self, total: usize) {
+        if total > 0 {
+            self.current = (self.current(total) + total - 1) % total;
+        }
+    }
+
+    /// Recompila el patrón y vuelve a la primera coincidencia
+    fn update(&mut self) {
+        self.current = 0;
+        self.regex = RegexBuilder::new(&self.query)
+            .case_insensitive(true)
+            .build()
+            .ok();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::logger::LogLevel;
+
+    fn entries(messages: &[&str]) -> Vec<LogEntry> {
+        messages
+            .iter()
+            .map(|message| LogEntry::new(LogLevel::Info, *message))
+            .collect()
+    }
+
+    #[test]
+    fn test_regex_and_literal_fallback() {
+        let logs = entries(&["[==== 10.0% ====]", "Error 0x800f081f", "C:\\Windows\\Temp"]);
+        let mut search = LogSearch::default();
+        for c in "error 0x[0-9a-f]+".chars() {
+            search.push(c);
+        }
+        assert_eq!(search.matches(&logs), vec![1]);
+
+        // `\T` no es un escape válido: se busca como texto literal
+        search.clear();
+        for c in "c:\\windows\\Temp".chars() {
+            search.push(c);
+        }
+        assert_eq!(search.matches(&logs), vec![2]);
+    }
+
+    #[test]
+    fn test_next_and_prev_wrap_around() {
+        let mut search = LogSearch::default();
+        search.push('x');
+        search.prev(3);
+        assert_eq!(search.current(3), 2);
+        search.next(3);
+        assert_eq!(search.current(3), 0);
+        search.next(3);
+        assert_eq!(search.current(3), 1);
+        // Si hay menos coincidencias, la selección se ajusta
+        assert_eq!(search.current(1), 0);
+    }
+}
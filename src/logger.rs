@@ -2,66 +2,210 @@
 //!
 //! Este módulo proporciona funciones para registrar eventos de la aplicación
 //! tanto en archivos de log como en la interfaz de usuario.
+//!
+//! Cada operación se registra dentro de un span `operation` con un
+//! identificador de correlación, de modo que todas sus líneas (incluidas las
+//! de sus hooks y tareas en segundo plano) se pueden agrupar al procesar los
+//! logs en formato JSON.
 
 use crate::app::App;
+use crate::config::{Config, LoggingConfig};
+use serde::{Deserialize, Serialize};
 use std::path::PathBuf;
-use tracing::Level;
+use std::sync::OnceLock;
+use std::sync::atomic::{AtomicBool, AtomicU32, Ordering};
+use std::time::{SystemTime, UNIX_EPOCH};
+use tracing::level_filters::LevelFilter;
+use tracing::{Level, Span};
 use tracing_appender::rolling::{RollingFileAppender, Rotation};
-use tracing_subscriber::{EnvFilter, fmt, layer::SubscriberExt, util::SubscriberInitExt};
+use tracing_subscriber::{
+    EnvFilter, Registry, fmt,
+    fmt::writer::{BoxMakeWriter, MakeWriterExt},
+    layer::SubscriberExt,
+    reload,
+    util::SubscriberInitExt,
+};
+
+/// Niveles que se pueden configurar, de menos a más detallado
+pub const LEVELS: [&str; 5] = ["error", "warn", "info", "debug", "trace"];
+
+/// Handle para cambiar el filtro de nivel sin reiniciar
+static FILTER_HANDLE: OnceLock<reload::Handle<EnvFilter, Registry>> = OnceLock::new();
+
+/// Escritura en el archivo de log activa
+static FILE_LOGGING: AtomicBool = AtomicBool::new(true);
+
+/// Formato del archivo de log
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+pub enum LogFormat {
+    /// Texto legible, una línea por evento
+    #[default]
+    Text,
+    /// Un objeto JSON por línea, para herramientas de observabilidad (SIEM)
+    Json,
+}
+
+/// Contador para los identificadores de correlación del proceso
+static OPERATION_COUNTER: AtomicU32 = AtomicU32::new(0);
 
 /// Inicializa el sistema de logging
 ///
 /// Configura tracing para escribir logs en archivos rotativos diarios
-/// en el directorio de logs de la aplicación.
+/// en el directorio de logs de la aplicación, en el formato configurado.
+/// Con `file_logging` desactivado los eventos se descartan.
 ///
 /// # Errores
 ///
 /// Retorna un error si no se puede crear el directorio de logs o inicializar el logger.
-pub fn init() -> std::io::Result<()> {
-    let log_dir = get_log_directory()?;
+pub fn init(config: &LoggingConfig) -> std::io::Result<()> {
+    let file_appender = if config.file_logging {
+        let log_dir = get_log_directory()?;
 
-    // Crear directorio de logs si no existe
-    std::fs::create_dir_all(&log_dir)?;
+        // Crear directorio de logs si no existe
+        std::fs::create_dir_all(&log_dir)?;
 
-    // Configurar appender con rotación diaria
-    let file_appender = RollingFileAppender::new(Rotation::DAILY, log_dir, "win_opt.log");
+        // Configurar appender con rotación diaria
+        let appender = RollingFileAppender::new(Rotation::DAILY, log_dir, "win_opt.log");
+        BoxMakeWriter::new(appender.with_filter(|_| FILE_LOGGING.load(Ordering::Relaxed)))
+    } else {
+        BoxMakeWriter::new(std::io::sink)
+    };
 
-    // Configurar nivel de logging según variable de entorno o usar INFO por defecto
-    let env_filter = EnvFilter::try_from_default_env()
-        .unwrap_or_else(|_| EnvFilter::new("info"))
-        .add_directive("win_opt=debug".parse().unwrap());
+    // La variable RUST_LOG tiene prioridad sobre el nivel configurado
+    let env_filter = EnvFilter::try_from_default_env().unwrap_or_else(|_| {
+        parse_filter(&config.level).unwrap_or_else(|| {
+            eprintln!("Nivel de log no válido: {}. Usando info", config.level);
+            EnvFilter::new("info")
+        })
+    });
+    let (filter_layer, handle) = reload::Layer::new(env_filter);
+    let _ = FILTER_HANDLE.set(handle);
 
     // Configurar subscriber con formato estructurado
-    tracing_subscriber::registry()
-        .with(env_filter)
-        .with(
-            fmt::layer()
-                .with_writer(file_appender)
-                .with_ansi(false)
-                .with_target(true)
-                .with_thread_ids(true)
-                .with_line_number(true),
-        )
-        .init();
+    let registry = tracing_subscriber::registry().with(filter_layer);
+    match config.format {
+        LogFormat::Text => registry
+            .with(
+                fmt::layer()
+                    .with_writer(file_appender)
+                    .with_ansi(false)
+                    .with_target(true)
+                    .with_thread_ids(true)
+                    .with_line_number(true),
+            )
+            .init(),
+        LogFormat::Json => registry
+            .with(
+                fmt::layer()
+                    .json()
+                    .with_current_span(true)
+                    .with_span_list(false)
+                    .with_writer(file_appender)
+                    .with_target(true)
+                    .with_thread_ids(true)
+                    .with_line_number(true),
+            )
+            .init(),
+    }
 
-    tracing::info!("Sistema de logging inicializado");
+    tracing::info!("Sistema de logging inicializado ({:?})", config.format);
 
     Ok(())
 }
 
+/// Activa o desactiva la escritura en el archivo de log sin reiniciar
+///
+/// Solo se puede reactivar si el archivo se abrió al iniciar; en caso
+/// contrario el cambio se aplica en el siguiente inicio.
+pub fn set_file_logging(enabled: bool) {
+    FILE_LOGGING.store(enabled, Ordering::Relaxed);
+}
+
 /// Obtiene el directorio donde se almacenarán los logs
 ///
-/// En Windows, usa %APPDATA%\win_opt\logs
-fn get_log_directory() -> std::io::Result<PathBuf> {
-    let app_data = std::env::var("APPDATA")
-        .or_else(|_| std::env::var("USERPROFILE").map(|p| format!("{p}\\AppData\\Roaming")))
-        .unwrap_or_else(|_| "C:\\ProgramData".to_string());
+/// En Windows, usa %APPDATA%\win_opt\logs (o la carpeta del modo portable)
+pub(crate) fn get_log_directory() -> std::io::Result<PathBuf> {
+    Ok(Config::get_config_dir()?.join("logs"))
+}
+
+/// Crea el filtro de un nivel (`error`, `warn`, `info`, `debug`, `trace`)
+fn parse_filter(level: &str) -> Option<EnvFilter> {
+    let level: LevelFilter = level.trim().parse().ok()?;
+    Some(EnvFilter::default().add_directive(level.into()))
+}
+
+/// Indica si un nivel de log es válido para la configuración
+pub fn is_valid_level(level: &str) -> bool {
+    parse_filter(level).is_some()
+}
+
+/// Siguiente nivel de la lista [`LEVELS`] (vuelve al primero tras el último)
+pub fn next_level(level: &str) -> &'static str {
+    let current = LEVELS
+        .iter()
+        .position(|candidate| candidate.eq_ignore_ascii_case(level.trim()));
+    LEVELS[current.map_or(0, |idx| (idx + 1) % LEVELS.len())]
+}
+
+/// Cambia el nivel del log sin reiniciar la aplicación
+///
+/// # Errores
+///
+/// Retorna un error si el nivel no es válido o el logging no está inicializado.
+pub fn set_level(level: &str) -> std::io::Result<()> {
+    let filter = parse_filter(level).ok_or_else(|| {
+        std::io::Error::new(
+            std::io::ErrorKind::InvalidInput,
+            format!("nivel de log no válido: {}", level),
+        )
+    })?;
+    FILTER_HANDLE
+        .get()
+        .ok_or_else(|| std::io::Error::other("el logging no está inicializado"))?
+        .reload(filter)
+        .map_err(std::io::Error::other)?;
+    tracing::info!("Nivel de log cambiado a {}", level);
+    Ok(())
+}
+
+/// Genera un identificador de correlación único para una operación
+///
+/// Combina la hora de inicio en milisegundos con un contador del proceso,
+/// de modo que no se repite entre ejecuciones ni dentro de una misma.
+pub fn correlation_id() -> String {
+    let millis = SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map(|d| d.as_millis())
+        .unwrap_or(0);
+    let counter = OPERATION_COUNTER.fetch_add(1, Ordering::Relaxed);
+    format!("{:x}-{:04x}", millis, counter)
+}
 
-    Ok(PathBuf::from(app_data).join("win_opt").join("logs"))
+/// Crea el span de una operación con un nuevo identificador de correlación
+pub fn operation_span(operation: &str) -> Span {
+    tracing::info_span!("operation", id = %correlation_id(), name = operation)
 }
 
-/// Niveles de logging para la aplicación
-#[derive(Debug, Clone, Copy)]
+/// Archivo de log más reciente (el que se está escribiendo)
+///
+/// El appender rota a diario y añade la fecha al nombre
+/// (`win_opt.log.2026-10-16`), así que se busca el modificado por última vez.
+pub fn current_log_file() -> Option<PathBuf> {
+    std::fs::read_dir(get_log_directory().ok()?)
+        .ok()?
+        .flatten()
+        .filter(|entry| {
+            entry
+                .file_name()
+                .to_string_lossy()
+                .starts_with("win_opt.log")
+        })
+        .max_by_key(|entry| entry.metadata().and_then(|m| m.modified()).ok())
+        .map(|entry| entry.path())
+}
+
+/// Niveles de logging para la aplicación (de menor a mayor gravedad)
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
 pub enum LogLevel {
     /// Información de debug detallada
     Debug,
@@ -73,6 +217,18 @@ pub enum LogLevel {
     Error,
 }
 
+impl LogLevel {
+    /// Nombre del nivel tal como aparece en el archivo de log
+    pub fn name(self) -> &'static str {
+        match self {
+            LogLevel::Debug => "DEBUG",
+            LogLevel::Info => "INFO",
+            LogLevel::Warning => "WARN",
+            LogLevel::Error => "ERROR",
+        }
+    }
+}
+
 impl From<LogLevel> for Level {
     fn from(level: LogLevel) -> Self {
         match level {
@@ -84,6 +240,40 @@ impl From<LogLevel> for Level {
     }
 }
 
+/// Línea de log de una operación mostrada en la interfaz
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct LogEntry {
+    pub level: LogLevel,
+    /// Momento del registro en segundos desde 1970-01-01 (UTC)
+    pub timestamp: u64,
+    pub message: String,
+}
+
+impl LogEntry {
+    /// Crea una entrada con la hora actual
+    pub fn new(level: LogLevel, message: impl Into<String>) -> Self {
+        Self {
+            level,
+            timestamp: SystemTime::now()
+                .duration_since(UNIX_EPOCH)
+                .map(|d| d.as_secs())
+                .unwrap_or(0),
+            message: message.into(),
+        }
+    }
+
+    /// Hora del registro (`HH:MM:SS`, UTC como el archivo de log)
+    pub fn time(&self) -> String {
+        let secs = self.timestamp % 86_400;
+        format!(
+            "{:02}:{:02}:{:02}",
+            secs / 3600,
+            secs % 3600 / 60,
+            secs % 60
+        )
+    }
+}
+
 /// Registra un mensaje en el sistema de logging y opcionalmente en la UI
 ///
 /// # Argumentos
@@ -112,44 +302,51 @@ pub fn log(app: Option<&mut App>, level: LogLevel, message: impl AsRef<str>) {
 
     // Agregar a la UI si se proporciona la app
     if let Some(app) = app {
-        app.operation_logs.push(msg.to_string());
+        app.operation_logs.push(LogEntry::new(level, msg));
     }
 }
 
 /// Macro para simplificar el logging
 ///
+/// El mensaje se formatea antes de tomar `app`, así que los argumentos pueden
+/// usar la app (por ejemplo para traducir el texto).
+///
 /// # Ejemplo
 ///
 /// ```ignore
 /// log_info!(app, "Operación completada");
-/// log_error!(app, "Error al procesar archivo");
+/// log_error!(app, "❌ {}", app.t(I18nKey::OpError));
 /// ```
 #[macro_export]
 macro_rules! log_info {
-    ($app:expr, $($arg:tt)*) => {
-        $crate::logger::log(Some($app), $crate::logger::LogLevel::Info, format!($($arg)*))
-    };
+    ($app:expr, $($arg:tt)*) => {{
+        let message = format!($($arg)*);
+        $crate::logger::log(Some($app), $crate::logger::LogLevel::Info, message)
+    }};
 }
 
 #[macro_export]
 macro_rules! log_debug {
-    ($app:expr, $($arg:tt)*) => {
-        $crate::logger::log(Some($app), $crate::logger::LogLevel::Debug, format!($($arg)*))
-    };
+    ($app:expr, $($arg:tt)*) => {{
+        let message = format!($($arg)*);
+        $crate::logger::log(Some($app), $crate::logger::LogLevel::Debug, message)
+    }};
 }
 
 #[macro_export]
 macro_rules! log_warn {
-    ($app:expr, $($arg:tt)*) => {
-        $crate::logger::log(Some($app), $crate::logger::LogLevel::Warning, format!($($arg)*))
-    };
+    ($app:expr, $($arg:tt)*) => {{
+        let message = format!($($arg)*);
+        $crate::logger::log(Some($app), $crate::logger::LogLevel::Warning, message)
+    }};
 }
 
 #[macro_export]
 macro_rules! log_error {
-    ($app:expr, $($arg:tt)*) => {
-        $crate::logger::log(Some($app), $crate::logger::LogLevel::Error, format!($($arg)*))
-    };
+    ($app:expr, $($arg:tt)*) => {{
+        let message = format!($($arg)*);
+        $crate::logger::log(Some($app), $crate::logger::LogLevel::Error, message)
+    }};
 }
 
 #[cfg(test)]
@@ -163,6 +360,35 @@ mod tests {
         assert!(log_dir.to_string_lossy().contains("logs"));
     }
 
+    #[test]
+    fn test_level_cycle_and_parsing() {
+        assert_eq!(next_level("info"), "debug");
+        assert_eq!(next_level("TRACE"), "error");
+        assert_eq!(next_level("desconocido"), "error");
+        assert!(parse_filter("warn").is_some());
+        assert!(parse_filter("mucho").is_none());
+        // Sin inicializar no hay filtro que recargar
+        assert!(set_level("mucho").is_err());
+    }
+
+    #[test]
+    fn test_log_entry_time() {
+        let entry = LogEntry {
+            level: LogLevel::Info,
+            timestamp: 1_760_000_000 + 3_725,
+            message: String::new(),
+        };
+        assert_eq!(entry.time(), "09:55:25");
+    }
+
+    #[test]
+    fn test_correlation_ids_are_unique() {
+        let first = correlation_id();
+        let second = correlation_id();
+        assert_ne!(first, second);
+        assert!(first.contains('-'));
+    }
+
     #[test]
     fn test_log_level_conversion() {
         assert_eq!(Level::from(LogLevel::Debug), Level::DEBUG);
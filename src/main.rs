@@ -1,14 +1,146 @@
+use clap::Parser;
+use crossterm::event::{DisableMouseCapture, EnableMouseCapture};
+use crossterm::execute;
+use std::io::stdout;
+use std::panic;
+use std::path::PathBuf;
+use std::thread;
 use win_opt::App;
+use win_opt::config::Overrides;
+use win_opt::i18n::Language;
+use win_opt::theme::Theme;
+
+/// Herramienta de optimización y mantenimiento para Windows 11
+#[derive(Parser)]
+#[command(version = win_opt::version::LONG_VERSION, about)]
+struct Cli {
+    /// Ejecuta las limpiezas programadas en segundo plano, sin interfaz
+    #[arg(long)]
+    daemon: bool,
+
+    /// Guarda la configuración, los logs y el historial junto al ejecutable
+    #[arg(long)]
+    portable: bool,
+
+    /// Usa otro archivo de configuración en lugar de config.toml
+    #[arg(long, value_name = "RUTA")]
+    config: Option<PathBuf>,
+
+    /// Tema para esta sesión (light, dark, solarized, nord, high-contrast o
+    /// custom), sin guardarlo
+    #[arg(long)]
+    theme: Option<Theme>,
+
+    /// Idioma para esta sesión (es, en, fr, de o pt-BR), sin guardarlo
+    #[arg(long)]
+    lang: Option<Language>,
+
+    /// Nivel de log para esta sesión (error, warn, info, debug o trace)
+    #[arg(long, value_parser = parse_log_level)]
+    log_level: Option<String>,
+
+    /// Ejecuta las operaciones sobre archivos y comandos simulados, sin
+    /// permisos de administrador ni cambios en el sistema (para demos)
+    #[arg(long)]
+    simulate: bool,
+}
+
+/// Valida el nivel de `--log-level`
+fn parse_log_level(level: &str) -> Result<String, String> {
+    if win_opt::logger::is_valid_level(level) {
+        Ok(level.to_ascii_lowercase())
+    } else {
+        Err(format!("nivel no válido: {}", level))
+    }
+}
+
+/// Restaura la terminal antes de mostrar un pánico del programa
+///
+/// Sin esto, un pánico dejaría la terminal en modo raw, en la pantalla
+/// alternativa y capturando el ratón. Los pánicos de otros hilos (los
+/// workers) no cierran la interfaz: se registran en el log y el worker los
+/// envía a la vista como error, sin escribir encima de la pantalla.
+fn install_panic_hook() {
+    let hook = panic::take_hook();
+    panic::set_hook(Box::new(move |info| {
+        let thread = thread::current();
+        let name = thread.name().unwrap_or("sin nombre");
+        tracing::error!("Pánico en el hilo {}: {}", name, info);
+        if name == "main" {
+            let _ = execute!(stdout(), DisableMouseCapture);
+            ratatui::restore();
+            hook(info);
+        }
+    }));
+}
 
 fn main() -> std::io::Result<()> {
-    // Inicializar el sistema de logging
-    if let Err(e) = win_opt::logger::init() {
+    let cli = Cli::parse();
+    if cli.portable {
+        win_opt::config::enable_portable();
+    }
+    if let Some(path) = cli.config {
+        win_opt::config::set_config_file(path);
+    }
+    win_opt::config::set_overrides(Overrides {
+        theme: cli.theme,
+        language: cli.lang,
+        log_level: cli.log_level,
+    });
+    // El ejecutor simulado se mantiene instalado hasta salir
+    let _simulation = if cli.simulate {
+        Some(win_opt::simulate::enable()?)
+    } else {
+        None
+    };
+
+    // Inicializar el sistema de logging con el formato configurado
+    let config = win_opt::config::Config::load();
+    if let Err(e) = win_opt::logger::init(&config.logging) {
         eprintln!("Error al inicializar el sistema de logging: {}", e);
         // Continuar la ejecución incluso si falla el logging
     }
+    if let Some(dir) = win_opt::simulate::sandbox() {
+        tracing::info!("Modo simulación: sandbox en {}", dir.display());
+    } else if let Some(dir) = win_opt::config::portable_dir() {
+        tracing::info!("Modo portable: datos en {}", dir.join("win_opt").display());
+    }
+
+    // Las simulaciones no tocan el sistema, así que pueden convivir con otras
+    let _instance = if cli.simulate {
+        None
+    } else {
+        match win_opt::instance::acquire() {
+            Some(guard) => Some(guard),
+            None => {
+                eprintln!(
+                    "win_opt ya se está ejecutando (en otra ventana o como daemon). \
+                     Ciérralo antes de abrir otra instancia."
+                );
+                tracing::warn!("Otra instancia de win_opt está en ejecución; saliendo");
+                std::process::exit(1);
+            }
+        }
+    };
+
+    // Purgar la cuarentena caducada antes de cualquier limpieza
+    let purged = win_opt::quarantine::purge_expired(&config.quarantine);
+    if purged > 0 {
+        tracing::info!("{} carpetas de cuarentena caducadas eliminadas", purged);
+    }
+
+    if cli.daemon {
+        return win_opt::daemon::run();
+    }
 
     let terminal = ratatui::init();
+    install_panic_hook();
+    // Capturar el ratón para los clics en listas y la rueda en los logs
+    if let Err(e) = execute!(stdout(), EnableMouseCapture) {
+        tracing::warn!("No se pudo activar el ratón: {}", e);
+    }
     let app_result = App::default().run(terminal);
+    let _ = execute!(stdout(), DisableMouseCapture);
     ratatui::restore();
     app_result
 }
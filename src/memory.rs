@@ -0,0 +1,226 @@
+//! Optimización de memoria
+//!
+//! Vacía los working sets de los procesos y purga la lista de memoria en
+//! espera (standby list) mediante `NtSetSystemInformation`, la misma técnica
+//! que usa EmptyStandbyList/RAMMap. También permite programar el
+//! Diagnóstico de memoria de Windows para el próximo arranque.
+
+use crate::platform;
+use crate::types::OperationState;
+use crate::utils::is_admin;
+use crate::{log_debug, log_error, log_info, log_warn};
+use sysinfo::System;
+
+/// Comandos de `SystemMemoryListInformation` soportados
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum MemoryListCommand {
+    /// Vacía los working sets de todos los procesos
+    EmptyWorkingSets = 2,
+    /// Purga la lista de páginas en espera
+    PurgeStandbyList = 4,
+}
+
+impl MemoryListCommand {
+    /// Descripción para los logs
+    fn description(self) -> &'static str {
+        match self {
+            MemoryListCommand::EmptyWorkingSets => "Vaciando working sets de los procesos",
+            MemoryListCommand::PurgeStandbyList => "Purgando lista de memoria en espera",
+        }
+    }
+}
+
+/// Ejecuta la liberación de memoria (working sets + standby list)
+pub fn execute_memory_cleanup(app: &mut crate::app::App) {
+    app.operation_state = OperationState::Running;
+    log_info!(app, "🧠 Iniciando optimización de memoria...");
+
+    if !is_admin() {
+        log_error!(
+            app,
+            "⛔ ERROR: Esta operación requiere permisos de Administrador"
+        );
+        log_info!(
+            app,
+            "ℹ️  Por favor, ejecuta la aplicación como Administrador"
+        );
+        app.operation_state = OperationState::Failed;
+        return;
+    }
+
+    let available_before = available_memory();
+
+    let mut failed = false;
+    for command in [
+        MemoryListCommand::EmptyWorkingSets,
+        MemoryListCommand::PurgeStandbyList,
+    ] {
+        log_info!(app, "");
+        log_info!(app, "⚙️  {}...", command.description());
+        match purge_memory_list(command) {
+            Ok(()) => log_info!(app, "✅ {} completado", command.description()),
+            Err(e) => {
+                failed = true;
+                log_error!(app, "❌ {}", e);
+            }
+        }
+    }
+
+    let freed = available_memory().saturating_sub(available_before);
+    log_info!(app, "");
+    log_info!(app, "✅ Memoria liberada: {}", app.i18n.format_size(freed));
+    log_info!(
+        app,
+        "💡 Pulsa D para programar el Diagnóstico de memoria de Windows"
+    );
+
+    app.operation_state = if failed {
+        OperationState::Failed
+    } else {
+        OperationState::Completed
+    };
+}
+
+/// Programa el Diagnóstico de memoria de Windows para el próximo reinicio
+///
+/// Equivale a elegir "Comprobar si hay problemas la próxima vez que inicie el
+/// equipo" en `mdsched.exe`, sin mostrar su diálogo.
+pub fn execute_memory_diagnostic(app: &mut crate::app::App) {
+    log_info!(app, "");
+    log_info!(app, "🔍 Programando Diagnóstico de memoria de Windows...");
+
+    if !is_admin() {
+        log_error!(
+            app,
+            "⛔ ERROR: Esta operación requiere permisos de Administrador"
+        );
+        return;
+    }
+
+    match platform::command("bcdedit")
+        .args(["/bootsequence", "{memdiag}"])
+        .output()
+    {
+        Ok(output) if output.status.success() => {
+            log_info!(app, "✅ El diagnóstico se ejecutará en el próximo reinicio");
+            app.needs_reboot = true;
+            log_info!(
+                app,
+                "ℹ️  Los resultados aparecerán en el Visor de eventos (MemoryDiagnostics-Results)"
+            );
+        }
+        Ok(output) => {
+            log_debug!(app, "{}", String::from_utf8_lossy(&output.stdout).trim());
+            log_warn!(app, "⚠️  No se pudo programar el diagnóstico de memoria");
+        }
+        Err(e) => log_error!(app, "❌ Error: {}", e),
+    }
+}
+
+/// Memoria disponible del sistema en bytes
+fn available_memory() -> u64 {
+    let mut sys = System::new();
+    sys.refresh_memory();
+    sys.available_memory()
+}
+
+#[cfg(windows)]
+fn purge_memory_list(command: MemoryListCommand) -> Result<(), String> {
+    use std::ffi::c_void;
+
+    /// Clase `SystemMemoryListInformation` de `SYSTEM_INFORMATION_CLASS`
+    const SYSTEM_MEMORY_LIST_INFORMATION: i32 = 80;
+
+    #[link(name = "ntdll")]
+    unsafe extern "system" {
+        fn NtSetSystemInformation(
+            system_information_class: i32,
+            system_information: *mut c_void,
+            system_information_length: u32,
+        ) -> i32;
+    }
+
+    enable_profile_privilege()?;
+
+    let mut value = command as u32;
+    // SAFETY: se pasa un puntero válido a un u32 junto con su tamaño, que es
+    // lo que espera SystemMemoryListInformation.
+    let status = unsafe {
+        NtSetSystemInformation(
+            SYSTEM_MEMORY_LIST_INFORMATION,
+            &mut value as *mut u32 as *mut c_void,
+            std::mem::size_of::<u32>() as u32,
+        )
+    };
+
+    if status >= 0 {
+        Ok(())
+    } else {
+        Err(format!(
+            "NtSetSystemInformation falló (NTSTATUS 0x{:08X})",
+            status as u32
+        ))
+    }
+}
+
+/// Habilita `SeProfileSingleProcessPrivilege` en el token del proceso,
+/// requerido por `SystemMemoryListInformation`
+#[cfg(windows)]
+fn enable_profile_privilege() -> Result<(), String> {
+    use windows::Win32::Foundation::{CloseHandle, HANDLE, LUID};
+    use windows::Win32::Security::{
+        AdjustTokenPrivileges, LUID_AND_ATTRIBUTES, LookupPrivilegeValueW, SE_PRIVILEGE_ENABLED,
+        SE_PROF_SINGLE_PROCESS_NAME, TOKEN_ADJUST_PRIVILEGES, TOKEN_PRIVILEGES, TOKEN_QUERY,
+    };
+    use windows::Win32::System::Threading::{GetCurrentProcess, OpenProcessToken};
+
+    // SAFETY: todas las llamadas reciben punteros a variables locales válidas
+    // y el handle del token se cierra antes de salir.
+    unsafe {
+        let mut token = HANDLE::default();
+        OpenProcessToken(
+            GetCurrentProcess(),
+            TOKEN_ADJUST_PRIVILEGES | TOKEN_QUERY,
+            &mut token,
+        )
+        .map_err(|e| format!("No se pudo abrir el token del proceso: {}", e.message()))?;
+
+        let mut luid = LUID::default();
+        let result =
+            LookupPrivilegeValueW(None, SE_PROF_SINGLE_PROCESS_NAME, &mut luid).and_then(|()| {
+                let privileges = TOKEN_PRIVILEGES {
+                    PrivilegeCount: 1,
+                    Privileges: [LUID_AND_ATTRIBUTES {
+                        Luid: luid,
+                        Attributes: SE_PRIVILEGE_ENABLED,
+                    }],
+                };
+                AdjustTokenPrivileges(token, false, Some(&privileges), 0, None, None)
+            });
+
+        let _ = CloseHandle(token);
+        result.map_err(|e| format!("No se pudo habilitar el privilegio: {}", e.message()))
+    }
+}
+
+#[cfg(not(windows))]
+fn purge_memory_list(_command: MemoryListCommand) -> Result<(), String> {
+    Err("La purga de memoria solo está disponible en Windows".to_string())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_memory_list_command_values() {
+        // Valores de SYSTEM_MEMORY_LIST_COMMAND definidos por Windows
+        assert_eq!(MemoryListCommand::EmptyWorkingSets as u32, 2);
+        assert_eq!(MemoryListCommand::PurgeStandbyList as u32, 4);
+    }
+
+    #[test]
+    fn test_available_memory_is_reported() {
+        assert!(available_memory() > 0);
+    }
+}
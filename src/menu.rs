@@ -0,0 +1,406 @@
+//! Definición del menú principal de win_opt
+//!
+//! Centraliza las entradas del menú (icono, textos y acción asociada) para que
+//! el renderizado y la navegación compartan una única fuente de verdad.
+
+use crate::i18n::I18nKey;
+use crate::operation::{BUILTIN_OPERATIONS, Operation};
+use crate::types::View;
+
+/// Categoría bajo la que se agrupa una entrada del menú
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum MenuCategory {
+    Cleanup,
+    Optimization,
+    System,
+}
+
+impl MenuCategory {
+    /// Clave de traducción de la cabecera de la categoría
+    pub fn label_key(self) -> I18nKey {
+        match self {
+            MenuCategory::Cleanup => I18nKey::MenuCategoryCleanup,
+            MenuCategory::Optimization => I18nKey::MenuCategoryOptimization,
+            MenuCategory::System => I18nKey::MenuCategorySystem,
+        }
+    }
+}
+
+/// Acción asociada a una entrada del menú
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum MenuAction {
+    /// Abre una vista (ejecutando su operación si la tiene)
+    Open(View),
+    /// Cierra la aplicación
+    Exit,
+}
+
+/// Entrada seleccionable del menú principal
+#[derive(Debug, Clone, Copy)]
+pub struct MenuItem {
+    pub category: MenuCategory,
+    pub icon: &'static str,
+    pub title: I18nKey,
+    pub description: I18nKey,
+    pub action: MenuAction,
+}
+
+impl MenuItem {
+    /// Indica si la entrada lanza una operación que necesita permisos de
+    /// administrador
+    ///
+    /// Se toma de las operaciones incluidas, que son las que declaran si
+    /// necesitan elevación; las vistas sin operación asociada no la
+    /// necesitan para abrirse.
+    pub fn requires_admin(&self) -> bool {
+        let MenuAction::Open(view) = self.action else {
+            return false;
+        };
+        BUILTIN_OPERATIONS
+            .iter()
+            .any(|operation| operation.view() == view && operation.requires_admin())
+    }
+
+    /// Indica si la entrada funciona en la compilación de Windows indicada
+    ///
+    /// Como con los permisos, el rango lo declaran las operaciones incluidas.
+    pub fn supports_build(&self, build: Option<u32>) -> bool {
+        let MenuAction::Open(view) = self.action else {
+            return true;
+        };
+        BUILTIN_OPERATIONS
+            .iter()
+            .filter(|operation| operation.view() == view)
+            .all(|operation| operation.supported_builds().contains(build))
+    }
+}
+
+/// Entradas del menú en el orden en que se muestran
+///
+/// Las entradas de una misma categoría deben ser contiguas: la cabecera de
+/// cada categoría se dibuja al encontrar su primera entrada.
+pub const MENU_ITEMS: &[MenuItem] = &[
+    // LIMPIEZA
+    MenuItem {
+        category: MenuCategory::Cleanup,
+        icon: "🧹",
+        title: I18nKey::MenuTempFiles,
+        description: I18nKey::MenuTempFilesDesc,
+        action: MenuAction::Open(View::Clean),
+    },
+    MenuItem {
+        category: MenuCategory::Cleanup,
+        icon: "🗑️",
+        title: I18nKey::MenuRecycleBin,
+        description: I18nKey::MenuRecycleBinDesc,
+        action: MenuAction::Open(View::RecycleBin),
+    },
+    MenuItem {
+        category: MenuCategory::Cleanup,
+        icon: "🌐",
+        title: I18nKey::MenuBrowserCache,
+        description: I18nKey::MenuBrowserCacheDesc,
+        action: MenuAction::Open(View::BrowserCache),
+    },
+    MenuItem {
+        category: MenuCategory::Cleanup,
+        icon: "📋",
+        title: I18nKey::MenuSystemLogs,
+        description: I18nKey::MenuSystemLogsDesc,
+        action: MenuAction::Open(View::SystemLogs),
+    },
+    MenuItem {
+        category: MenuCategory::Cleanup,
+        icon: "🔄",
+        title: I18nKey::MenuWindowsUpdate,
+        description: I18nKey::MenuWindowsUpdateDesc,
+        action: MenuAction::Open(View::WindowsUpdate),
+    },
+    MenuItem {
+        category: MenuCategory::Cleanup,
+        icon: "📥",
+        title: I18nKey::MenuDownloads,
+        description: I18nKey::MenuDownloadsDesc,
+        action: MenuAction::Open(View::Downloads),
+    },
+    MenuItem {
+        category: MenuCategory::Cleanup,
+        icon: "🛡️",
+        title: I18nKey::MenuQuarantine,
+        description: I18nKey::MenuQuarantineDesc,
+        action: MenuAction::Open(View::Quarantine),
+    },
+    // OPTIMIZACIÓN
+    MenuItem {
+        category: MenuCategory::Optimization,
+        icon: "⚡",
+        title: I18nKey::MenuOptimize,
+        description: I18nKey::MenuOptimizeDesc,
+        action: MenuAction::Open(View::Optimize),
+    },
+    MenuItem {
+        category: MenuCategory::Optimization,
+        icon: "🚀",
+        title: I18nKey::MenuStartup,
+        description: I18nKey::MenuStartupDesc,
+        action: MenuAction::Open(View::StartupOptimizer),
+    },
+    MenuItem {
+        category: MenuCategory::Optimization,
+        icon: "⏱️",
+        title: I18nKey::MenuBootTime,
+        description: I18nKey::MenuBootTimeDesc,
+        action: MenuAction::Open(View::BootTime),
+    },
+    MenuItem {
+        category: MenuCategory::Optimization,
+        icon: "🎨",
+        title: I18nKey::MenuVisualEffects,
+        description: I18nKey::MenuVisualEffectsDesc,
+        action: MenuAction::Open(View::VisualEffects),
+    },
+    MenuItem {
+        category: MenuCategory::Optimization,
+        icon: "🧠",
+        title: I18nKey::MenuMemory,
+        description: I18nKey::MenuMemoryDesc,
+        action: MenuAction::Open(View::Memory),
+    },
+    MenuItem {
+        category: MenuCategory::Optimization,
+        icon: "🎮",
+        title: I18nKey::MenuProfiles,
+        description: I18nKey::MenuProfilesDesc,
+        action: MenuAction::Open(View::Profiles),
+    },
+    // SISTEMA
+    MenuItem {
+        category: MenuCategory::System,
+        icon: "🌐",
+        title: I18nKey::MenuNetwork,
+        description: I18nKey::MenuNetworkDesc,
+        action: MenuAction::Open(View::Network),
+    },
+    MenuItem {
+        category: MenuCategory::System,
+        icon: "🔌",
+        title: I18nKey::MenuAdapters,
+        description: I18nKey::MenuAdaptersDesc,
+        action: MenuAction::Open(View::Adapters),
+    },
+    MenuItem {
+        category: MenuCategory::System,
+        icon: "📶",
+        title: I18nKey::MenuWifi,
+        description: I18nKey::MenuWifiDesc,
+        action: MenuAction::Open(View::Wifi),
+    },
+    MenuItem {
+        category: MenuCategory::System,
+        icon: "🧭",
+        title: I18nKey::MenuProxy,
+        description: I18nKey::MenuProxyDesc,
+        action: MenuAction::Open(View::Proxy),
+    },
+    MenuItem {
+        category: MenuCategory::System,
+        icon: "🔗",
+        title: I18nKey::MenuPorts,
+        description: I18nKey::MenuPortsDesc,
+        action: MenuAction::Open(View::Ports),
+    },
+    MenuItem {
+        category: MenuCategory::System,
+        icon: "🔧",
+        title: I18nKey::MenuRepair,
+        description: I18nKey::MenuRepairDesc,
+        action: MenuAction::Open(View::Repair),
+    },
+    MenuItem {
+        category: MenuCategory::System,
+        icon: "🔤",
+        title: I18nKey::MenuFontCache,
+        description: I18nKey::MenuFontCacheDesc,
+        action: MenuAction::Open(View::FontCache),
+    },
+    MenuItem {
+        category: MenuCategory::System,
+        icon: "🔎",
+        title: I18nKey::MenuSearchIndex,
+        description: I18nKey::MenuSearchIndexDesc,
+        action: MenuAction::Open(View::SearchIndex),
+    },
+    MenuItem {
+        category: MenuCategory::System,
+        icon: "🗂️",
+        title: I18nKey::MenuJobs,
+        description: I18nKey::MenuJobsDesc,
+        action: MenuAction::Open(View::Jobs),
+    },
+    MenuItem {
+        category: MenuCategory::System,
+        icon: "📜",
+        title: I18nKey::MenuAppLogs,
+        description: I18nKey::MenuAppLogsDesc,
+        action: MenuAction::Open(View::AppLogs),
+    },
+    MenuItem {
+        category: MenuCategory::System,
+        icon: "🔒",
+        title: I18nKey::MenuPrivacy,
+        description: I18nKey::MenuPrivacyDesc,
+        action: MenuAction::Open(View::Privacy),
+    },
+    MenuItem {
+        category: MenuCategory::System,
+        icon: "🔒",
+        title: I18nKey::MenuDefender,
+        description: I18nKey::MenuDefenderDesc,
+        action: MenuAction::Open(View::Defender),
+    },
+    MenuItem {
+        category: MenuCategory::System,
+        icon: "🧱",
+        title: I18nKey::MenuFirewall,
+        description: I18nKey::MenuFirewallDesc,
+        action: MenuAction::Open(View::Firewall),
+    },
+    MenuItem {
+        category: MenuCategory::System,
+        icon: "📝",
+        title: I18nKey::MenuHosts,
+        description: I18nKey::MenuHostsDesc,
+        action: MenuAction::Open(View::Hosts),
+    },
+    MenuItem {
+        category: MenuCategory::System,
+        icon: "🛤️",
+        title: I18nKey::MenuPathCleaner,
+        description: I18nKey::MenuPathCleanerDesc,
+        action: MenuAction::Open(View::PathCleaner),
+    },
+    MenuItem {
+        category: MenuCategory::System,
+        icon: "📦",
+        title: I18nKey::MenuPrograms,
+        description: I18nKey::MenuProgramsDesc,
+        action: MenuAction::Open(View::Programs),
+    },
+    MenuItem {
+        category: MenuCategory::System,
+        icon: "🧩",
+        title: I18nKey::MenuDrivers,
+        description: I18nKey::MenuDriversDesc,
+        action: MenuAction::Open(View::Drivers),
+    },
+    MenuItem {
+        category: MenuCategory::System,
+        icon: "📅",
+        title: I18nKey::MenuTasks,
+        description: I18nKey::MenuTasksDesc,
+        action: MenuAction::Open(View::Tasks),
+    },
+    MenuItem {
+        category: MenuCategory::System,
+        icon: "💻",
+        title: I18nKey::MenuInfo,
+        description: I18nKey::MenuInfoDesc,
+        action: MenuAction::Open(View::Info),
+    },
+    MenuItem {
+        category: MenuCategory::System,
+        icon: "⚙️",
+        title: I18nKey::MenuSettings,
+        description: I18nKey::MenuSettingsDesc,
+        action: MenuAction::Open(View::Settings),
+    },
+    MenuItem {
+        category: MenuCategory::System,
+        icon: "🏷️",
+        title: I18nKey::MenuAbout,
+        description: I18nKey::MenuAboutDesc,
+        action: MenuAction::Open(View::About),
+    },
+    MenuItem {
+        category: MenuCategory::System,
+        icon: "🚪",
+        title: I18nKey::MenuExit,
+        description: I18nKey::MenuExitDesc,
+        action: MenuAction::Exit,
+    },
+];
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::i18n::{I18n, Language};
+
+    #[test]
+    fn test_menu_categories_are_contiguous() {
+        // Una categoría no debe reaparecer después de haber cambiado a otra
+        let mut seen: Vec<MenuCategory> = Vec::new();
+        for item in MENU_ITEMS {
+            if seen.last() != Some(&item.category) {
+                assert!(!seen.contains(&item.category));
+                seen.push(item.category);
+            }
+        }
+    }
+
+    #[test]
+    fn test_menu_actions_are_unique() {
+        for (i, a) in MENU_ITEMS.iter().enumerate() {
+            for b in &MENU_ITEMS[i + 1..] {
+                assert_ne!(a.action, b.action);
+            }
+        }
+    }
+
+    #[test]
+    fn test_requires_admin_follows_operations() {
+        let item = |view| {
+            MENU_ITEMS
+                .iter()
+                .find(|item| item.action == MenuAction::Open(view))
+                .unwrap()
+        };
+        assert!(item(View::Repair).requires_admin());
+        assert!(item(View::SystemLogs).requires_admin());
+        assert!(!item(View::Clean).requires_admin());
+        assert!(!item(View::Settings).requires_admin());
+        assert!(!MENU_ITEMS.last().unwrap().requires_admin());
+    }
+
+    #[test]
+    fn test_supports_build_follows_operations() {
+        let item = |view| {
+            MENU_ITEMS
+                .iter()
+                .find(|item| item.action == MenuAction::Open(view))
+                .unwrap()
+        };
+        // La privacidad desactiva servicios que aparecieron en Windows 10
+        assert!(!item(View::Privacy).supports_build(Some(9600)));
+        assert!(item(View::Privacy).supports_build(Some(19045)));
+        assert!(item(View::Privacy).supports_build(None));
+        assert!(item(View::Clean).supports_build(Some(7601)));
+        assert!(item(View::Settings).supports_build(Some(7601)));
+    }
+
+    #[test]
+    fn test_menu_ends_with_exit() {
+        assert_eq!(MENU_ITEMS.last().map(|i| i.action), Some(MenuAction::Exit));
+    }
+
+    #[test]
+    fn test_menu_items_are_translated() {
+        for language in Language::ALL {
+            let i18n = I18n::new(language);
+            for item in MENU_ITEMS {
+                assert_ne!(i18n.t(item.title), "[MISSING TRANSLATION]");
+                assert_ne!(i18n.t(item.description), "[MISSING TRANSLATION]");
+                assert_ne!(i18n.t(item.category.label_key()), "[MISSING TRANSLATION]");
+            }
+        }
+    }
+}
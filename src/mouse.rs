@@ -0,0 +1,109 @@
+//! Soporte de ratón
+//!
+//! Al dibujar cada vista se guardan las zonas que responden al ratón (la
+//! lista seleccionable y el panel de logs), de modo que los clics y la rueda
+//! se traduzcan a la fila o al panel sobre el que está el puntero.
+
+use ratatui::layout::{Margin, Position, Rect};
+
+/// Líneas que desplaza cada paso de la rueda en el panel de logs
+pub const WHEEL_LINES: u16 = 3;
+
+/// Filas de una lista dibujada, con el elemento al que corresponde cada una
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct ListHitbox {
+    /// Zona interior de la lista (sin bordes)
+    area: Rect,
+    /// Elemento de cada fila visible; `None` en las filas no seleccionables
+    rows: Vec<Option<usize>>,
+}
+
+impl ListHitbox {
+    /// Crea la zona de una lista con borde a partir de sus elementos
+    ///
+    /// `items` da, para cada elemento de la lista en orden, el índice que
+    /// selecciona (o `None` si no es seleccionable, como las cabeceras) y su
+    /// altura en filas. `offset` es el primer elemento visible.
+    pub fn new(
+        area: Rect,
+        offset: usize,
+        items: impl IntoIterator<Item = (Option<usize>, usize)>,
+    ) -> Self {
+        let rows = items
+            .into_iter()
+            .skip(offset)
+            .flat_map(|(target, height)| std::iter::repeat_n(target, height))
+            .collect();
+        Self {
+            area: area.inner(Margin::new(1, 1)),
+            rows,
+        }
+    }
+
+    /// Elemento bajo una posición de la pantalla
+    pub fn item_at(&self, column: u16, row: u16) -> Option<usize> {
+        if !self.area.contains(Position::new(column, row)) {
+            return None;
+        }
+        self.rows
+            .get(usize::from(row - self.area.y))
+            .copied()
+            .flatten()
+    }
+
+    /// Indica si la posición está dentro de la lista
+    pub fn contains(&self, column: u16, row: u16) -> bool {
+        self.area.contains(Position::new(column, row))
+    }
+}
+
+/// Zonas que responden al ratón en el último fotograma
+#[derive(Debug, Clone, Default)]
+pub struct MouseTargets {
+    /// Lista seleccionable de la vista
+    pub list: Option<ListHitbox>,
+    /// Panel de logs de la vista
+    pub log_panel: Option<Rect>,
+}
+
+impl MouseTargets {
+    /// Indica si la posición está sobre el panel de logs
+    pub fn over_log_panel(&self, column: u16, row: u16) -> bool {
+        self.log_panel
+            .is_some_and(|area| area.contains(Position::new(column, row)))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_item_at_skips_headers_and_offset() {
+        // Cabecera, dos elementos de una fila y uno de dos filas; el primero
+        // queda fuera por el desplazamiento
+        let items = [(None, 1), (Some(0), 1), (Some(1), 1), (Some(2), 2)];
+        let hitbox = ListHitbox::new(Rect::new(10, 5, 30, 8), 1, items);
+
+        // La primera fila interior está en y = 6
+        assert_eq!(hitbox.item_at(12, 6), Some(0));
+        assert_eq!(hitbox.item_at(12, 7), Some(1));
+        assert_eq!(hitbox.item_at(12, 8), Some(2));
+        assert_eq!(hitbox.item_at(12, 9), Some(2));
+        // Filas vacías, bordes y fuera de la lista
+        assert_eq!(hitbox.item_at(12, 10), None);
+        assert_eq!(hitbox.item_at(10, 6), None);
+        assert_eq!(hitbox.item_at(50, 6), None);
+    }
+
+    #[test]
+    fn test_over_log_panel() {
+        let targets = MouseTargets {
+            log_panel: Some(Rect::new(0, 10, 20, 5)),
+            ..MouseTargets::default()
+        };
+        assert!(targets.over_log_panel(5, 12));
+        assert!(!targets.over_log_panel(5, 2));
+        assert!(!MouseTargets::default().over_log_panel(5, 12));
+    }
+}
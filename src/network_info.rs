@@ -0,0 +1,332 @@
+//! Configuración de red de los adaptadores conectados
+//!
+//! Los adaptadores y su MAC salen de `sysinfo::Networks` (que en Windows solo
+//! lista los conectados, por su alias). Las direcciones, puertas de enlace y
+//! DNS se leen de `ipconfig /all` y la velocidad del enlace de
+//! `Get-NetAdapter`, cruzando ambos por el nombre del adaptador.
+//!
+//! Las etiquetas de `ipconfig` están traducidas al idioma de Windows, así que
+//! no se buscan por su texto completo: los campos se reconocen por palabras
+//! que no cambian entre idiomas (`IPv4`, `IPv6`, `DNS`, `gateway`) y por el
+//! formato del valor, que tiene que ser una dirección IP.
+//!
+//! Los dos comandos tardan en responder, así que la vista de información hace
+//! la consulta en un thread aparte con [`spawn_query`].
+
+use crate::platform;
+use std::collections::HashMap;
+use std::net::IpAddr;
+use std::sync::mpsc::{self, Receiver};
+use std::thread;
+use sysinfo::Networks;
+
+/// Script de PowerShell que lista la velocidad de los adaptadores en formato
+/// `nombre|velocidad`
+const LINK_SPEED_SCRIPT: &str =
+    "Get-NetAdapter | ForEach-Object { '{0}|{1}' -f $_.Name, $_.LinkSpeed }";
+
+/// Configuración de red de un adaptador
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct NetworkInfo {
+    /// Nombre de la conexión (p. ej. "Wi-Fi")
+    pub name: String,
+    /// Dirección física en formato `AA-BB-CC-DD-EE-FF`
+    pub mac: Option<String>,
+    /// Direcciones IP locales, primero las IPv4
+    pub addresses: Vec<String>,
+    pub gateways: Vec<String>,
+    pub dns_servers: Vec<String>,
+    /// Velocidad del enlace tal como la da Windows ("1 Gbps")
+    pub link_speed: Option<String>,
+}
+
+/// Campo de `ipconfig` que interesa
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Field {
+    Ipv4,
+    Ipv6,
+    Gateway,
+    Dns,
+}
+
+impl Field {
+    /// Reconoce el campo por su etiqueta, en cualquier idioma
+    fn from_label(label: &str) -> Option<Self> {
+        let label = label.to_lowercase();
+        // Antes que la puerta de enlace: en español el vínculo local IPv6
+        // es "Dirección IPv6 de enlace local"
+        if label.contains("ipv4") {
+            Some(Field::Ipv4)
+        } else if label.contains("ipv6") {
+            Some(Field::Ipv6)
+        } else if ["gateway", "enlace", "passerelle"]
+            .iter()
+            .any(|word| label.contains(word))
+        {
+            Some(Field::Gateway)
+        } else if label.contains("dns") {
+            Some(Field::Dns)
+        } else {
+            None
+        }
+    }
+}
+
+/// Sección de un adaptador en la salida de `ipconfig /all`
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+struct IpconfigSection {
+    /// Cabecera sin los dos puntos ("Adaptador de LAN inalámbrica Wi-Fi")
+    header: String,
+    ipv4: Vec<String>,
+    ipv6: Vec<String>,
+    gateways: Vec<String>,
+    dns_servers: Vec<String>,
+}
+
+impl IpconfigSection {
+    /// Indica si la cabecera corresponde al adaptador `name`
+    fn is_for(&self, name: &str) -> bool {
+        self.header == name || self.header.ends_with(&format!(" {}", name))
+    }
+
+    fn push(&mut self, field: Field, value: &str) {
+        // "192.168.1.20(Preferido)" o "fe80::1%12"
+        let value = value.split(['(', '%']).next().unwrap_or_default().trim();
+        if value.parse::<IpAddr>().is_err() {
+            return;
+        }
+        let list = match field {
+            Field::Ipv4 => &mut self.ipv4,
+            Field::Ipv6 => &mut self.ipv6,
+            Field::Gateway => &mut self.gateways,
+            Field::Dns => &mut self.dns_servers,
+        };
+        list.push(value.to_string());
+    }
+}
+
+/// Parsea la salida de `ipconfig /all`
+///
+/// Las cabeceras de adaptador son líneas sin sangría que terminan en `:`;
+/// los campos, líneas con sangría `etiqueta . . . : valor`, y los valores
+/// adicionales (más servidores DNS) van solos en las líneas siguientes.
+fn parse_ipconfig(output: &str) -> Vec<IpconfigSection> {
+    let mut sections: Vec<IpconfigSection> = Vec::new();
+    let mut in_adapter = false;
+    let mut last_field = None;
+
+    for line in output.lines() {
+        if line.trim().is_empty() {
+            continue;
+        }
+        if !line.starts_with(char::is_whitespace) {
+            in_adapter = line.trim_end().ends_with(':');
+            if in_adapter {
+                sections.push(IpconfigSection {
+                    header: line.trim_end().trim_end_matches(':').trim().to_string(),
+                    ..Default::default()
+                });
+            }
+            last_field = None;
+            continue;
+        }
+        let Some(section) = sections.last_mut().filter(|_| in_adapter) else {
+            continue;
+        };
+        match line.split_once(" : ") {
+            Some((label, value)) => {
+                last_field = Field::from_label(label);
+                if let Some(field) = last_field {
+                    section.push(field, value);
+                }
+            }
+            None => {
+                if let Some(field) = last_field {
+                    section.push(field, line);
+                }
+            }
+        }
+    }
+
+    sections
+}
+
+/// Parsea la salida de [`LINK_SPEED_SCRIPT`]
+fn parse_link_speeds(output: &str) -> HashMap<String, String> {
+    output
+        .lines()
+        .filter_map(|line| line.trim().split_once('|'))
+        .filter(|(_, speed)| !speed.trim().is_empty())
+        .map(|(name, speed)| (name.trim().to_string(), speed.trim().to_string()))
+        .collect()
+}
+
+/// Formatea una MAC como la muestra Windows
+///
+/// Retorna `None` si es todo ceros (interfaces virtuales como el loopback).
+fn format_mac(bytes: [u8; 6]) -> Option<String> {
+    (bytes != [0; 6]).then(|| {
+        bytes
+            .iter()
+            .map(|byte| format!("{:02X}", byte))
+            .collect::<Vec<_>>()
+            .join("-")
+    })
+}
+
+/// Cruza los adaptadores de sysinfo con `ipconfig` y las velocidades
+///
+/// Se descartan los que no tienen ni dirección IP ni MAC. Los que tienen
+/// puerta de enlace (los que dan salida a Internet) van primero.
+fn merge(
+    interfaces: Vec<(String, [u8; 6])>,
+    sections: &[IpconfigSection],
+    speeds: &HashMap<String, String>,
+) -> Vec<NetworkInfo> {
+    let mut adapters: Vec<NetworkInfo> = interfaces
+        .into_iter()
+        .map(|(name, mac)| {
+            let mut info = NetworkInfo {
+                mac: format_mac(mac),
+                link_speed: speeds.get(&name).cloned(),
+                ..Default::default()
+            };
+            if let Some(section) = sections.iter().find(|section| section.is_for(&name)) {
+                info.addresses = section.ipv4.iter().chain(&section.ipv6).cloned().collect();
+                info.gateways = section.gateways.clone();
+                info.dns_servers = section.dns_servers.clone();
+            }
+            info.name = name;
+            info
+        })
+        .filter(|info| !info.addresses.is_empty() || info.mac.is_some())
+        .collect();
+
+    adapters.sort_by(|a, b| {
+        a.gateways
+            .is_empty()
+            .cmp(&b.gateways.is_empty())
+            .then_with(|| a.name.cmp(&b.name))
+    });
+    adapters
+}
+
+/// Salida estándar de un comando, vacía si falla
+fn command_stdout(program: &str, args: &[&str]) -> String {
+    match platform::command(program).args(args).output() {
+        Ok(output) if output.status.success() => {
+            String::from_utf8_lossy(&output.stdout).into_owned()
+        }
+        Ok(_) => String::new(),
+        Err(e) => {
+            tracing::debug!("No se pudo ejecutar {}: {}", program, e);
+            String::new()
+        }
+    }
+}
+
+/// Consulta la configuración de red de los adaptadores conectados
+pub fn query() -> Vec<NetworkInfo> {
+    let networks = Networks::new_with_refreshed_list();
+    let interfaces = networks
+        .iter()
+        .map(|(name, data)| (name.clone(), data.mac_address().0))
+        .collect();
+
+    let sections = parse_ipconfig(&command_stdout("ipconfig", &["/all"]));
+    let speeds = parse_link_speeds(&command_stdout(
+        "powershell",
+        &["-NoProfile", "-Command", LINK_SPEED_SCRIPT],
+    ));
+
+    merge(interfaces, &sections, &speeds)
+}
+
+/// Lanza la consulta de red en un thread y devuelve el receptor del resultado
+pub fn spawn_query() -> Receiver<Vec<NetworkInfo>> {
+    let (sender, receiver) = mpsc::channel();
+
+    thread::spawn(move || {
+        let _ = sender.send(query());
+    });
+
+    receiver
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_ipconfig_by_structure_in_any_language() {
+        let output = "\
+Configuración IP de Windows
+
+   Nombre de host. . . . . . . . . : PC
+   Tipo de nodo. . . . . . . . . . : híbrido
+
+Adaptador de Ethernet vEthernet (Default Switch):
+
+   Dirección IPv4. . . . . . . . . . . . . . : 172.20.0.1(Preferido)
+   Puerta de enlace predeterminada . . . . . :
+
+Adaptador de LAN inalámbrica Wi-Fi:
+
+   Sufijo DNS específico para la conexión. . : home
+   Dirección física. . . . . . . . . . . . . : AA-BB-CC-DD-EE-FF
+   Vínculo: dirección IPv6 local. . . : fe80::1c2d:3e4f:5a6b:7c8d%12(Preferido)
+   Dirección IPv4. . . . . . . . . . . . . . : 192.168.1.20(Preferido)
+   Máscara de subred . . . . . . . . . . . . : 255.255.255.0
+   Puerta de enlace predeterminada . . . . . : fe80::1%12
+                                               192.168.1.1
+   Servidor DHCP . . . . . . . . . . . . . . : 192.168.1.1
+   Servidores DNS. . . . . . . . . . . . . . : 1.1.1.1
+                                               8.8.8.8
+   NetBIOS sobre TCP/IP. . . . . . . . . . . : habilitado
+";
+        let sections = parse_ipconfig(output);
+        assert_eq!(sections.len(), 2);
+        assert_eq!(sections[0].ipv4, ["172.20.0.1"]);
+        assert!(sections[0].gateways.is_empty());
+
+        let wifi = &sections[1];
+        assert!(wifi.is_for("Wi-Fi"));
+        assert!(!wifi.is_for("Fi"));
+        assert_eq!(wifi.ipv4, ["192.168.1.20"]);
+        assert_eq!(wifi.ipv6, ["fe80::1c2d:3e4f:5a6b:7c8d"]);
+        assert_eq!(wifi.gateways, ["fe80::1", "192.168.1.1"]);
+        assert_eq!(wifi.dns_servers, ["1.1.1.1", "8.8.8.8"]);
+    }
+
+    #[test]
+    fn test_merge_matches_adapters_by_name() {
+        let sections = vec![IpconfigSection {
+            header: "Ethernet adapter Ethernet 2".to_string(),
+            ipv4: vec!["10.0.0.5".to_string()],
+            gateways: vec!["10.0.0.1".to_string()],
+            ..Default::default()
+        }];
+        let speeds = parse_link_speeds("Ethernet 2|1 Gbps\nEthernet|\n");
+        let adapters = merge(
+            vec![
+                ("Ethernet".to_string(), [0x00, 0x1a, 0x2b, 0x3c, 0x4d, 0x5e]),
+                (
+                    "Ethernet 2".to_string(),
+                    [0x00, 0x1a, 0x2b, 0x3c, 0x4d, 0x5f],
+                ),
+                ("Loopback Pseudo-Interface 1".to_string(), [0; 6]),
+            ],
+            &sections,
+            &speeds,
+        );
+
+        assert_eq!(adapters.len(), 2);
+        assert_eq!(adapters[0].name, "Ethernet 2");
+        assert_eq!(adapters[0].addresses, ["10.0.0.5"]);
+        assert_eq!(adapters[0].link_speed.as_deref(), Some("1 Gbps"));
+        assert_eq!(adapters[1].name, "Ethernet");
+        assert_eq!(adapters[1].mac.as_deref(), Some("00-1A-2B-3C-4D-5E"));
+        assert!(adapters[1].addresses.is_empty());
+        assert_eq!(adapters[1].link_speed, None);
+    }
+}
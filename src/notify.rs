@@ -0,0 +1,87 @@
+//! Notificaciones del sistema (toasts de Windows)
+//!
+//! Permite avisar al usuario cuando termina una tarea aunque la terminal no
+//! esté en primer plano. En plataformas distintas de Windows las
+//! notificaciones se registran en el log y se descartan.
+
+use crate::error::Result;
+
+/// AppUserModelID de PowerShell, registrado en todas las instalaciones de
+/// Windows. Las aplicaciones sin instalador necesitan un ID existente para
+/// poder mostrar toasts.
+#[cfg(windows)]
+const APP_ID: &str =
+    "{1AC14E77-02E7-4E5D-B744-2EB1AE5198B7}\\WindowsPowerShell\\v1.0\\powershell.exe";
+
+/// Muestra una notificación toast con un título y un mensaje
+///
+/// # Errores
+///
+/// Retorna `WinOptError::Notification` si Windows rechaza la notificación
+/// (por ejemplo, con las notificaciones desactivadas por directiva).
+pub fn show_toast(title: &str, message: &str) -> Result<()> {
+    tracing::info!("Notificación: {} - {}", title, message);
+    show_toast_impl(&toast_xml(title, message))
+}
+
+#[cfg(windows)]
+fn show_toast_impl(xml: &str) -> Result<()> {
+    use crate::error::WinOptError;
+    use windows::Data::Xml::Dom::XmlDocument;
+    use windows::UI::Notifications::{ToastNotification, ToastNotificationManager};
+    use windows::core::HSTRING;
+
+    let show = || -> windows::core::Result<()> {
+        let document = XmlDocument::new()?;
+        document.LoadXml(&HSTRING::from(xml))?;
+        let toast = ToastNotification::CreateToastNotification(&document)?;
+        ToastNotificationManager::CreateToastNotifierWithId(&HSTRING::from(APP_ID))?.Show(&toast)
+    };
+
+    show().map_err(|e| WinOptError::Notification(e.message().to_string()))
+}
+
+#[cfg(not(windows))]
+fn show_toast_impl(_xml: &str) -> Result<()> {
+    tracing::debug!("Notificaciones toast no disponibles en esta plataforma");
+    Ok(())
+}
+
+/// Genera el XML de un toast con plantilla de dos líneas
+fn toast_xml(title: &str, message: &str) -> String {
+    format!(
+        "<toast><visual><binding template=\"ToastGeneric\"><text>{}</text><text>{}</text></binding></visual></toast>",
+        escape_xml(title),
+        escape_xml(message)
+    )
+}
+
+/// Escapa los caracteres reservados de XML
+fn escape_xml(text: &str) -> String {
+    text.replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+        .replace('"', "&quot;")
+        .replace('\'', "&apos;")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_escape_xml() {
+        assert_eq!(
+            escape_xml("<a href=\"x\">Tom & Jerry's</a>"),
+            "&lt;a href=&quot;x&quot;&gt;Tom &amp; Jerry&apos;s&lt;/a&gt;"
+        );
+    }
+
+    #[test]
+    fn test_toast_xml_contains_texts() {
+        let xml = toast_xml("win_opt", "Liberados 120 MB");
+        assert!(xml.starts_with("<toast>"));
+        assert!(xml.contains("<text>win_opt</text>"));
+        assert!(xml.contains("<text>Liberados 120 MB</text>"));
+    }
+}
@@ -0,0 +1,214 @@
+//! Asistente del primer inicio
+//!
+//! Cuando no existe `config.toml` se abre un asistente breve para elegir el
+//! idioma, el tema, si se guardan los logs en archivo y si se piden
+//! confirmaciones antes de las acciones destructivas. Al terminar se escribe
+//! la configuración inicial.
+
+use crate::config::Config;
+use crate::i18n::{I18n, I18nKey, Language};
+use crate::theme::Theme;
+
+/// Temas que se ofrecen, en orden
+const THEMES: [Theme; 5] = [
+    Theme::Dark,
+    Theme::Light,
+    Theme::Solarized,
+    Theme::Nord,
+    Theme::HighContrast,
+];
+
+/// Paso del asistente
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Step {
+    Language,
+    Theme,
+    FileLogging,
+    Confirmations,
+}
+
+impl Step {
+    /// Pasos en el orden en que se muestran
+    pub const ALL: [Step; 4] = [
+        Step::Language,
+        Step::Theme,
+        Step::FileLogging,
+        Step::Confirmations,
+    ];
+
+    /// Clave de traducción de la pregunta del paso
+    pub fn question_key(self) -> I18nKey {
+        match self {
+            Step::Language => I18nKey::OnboardingLanguage,
+            Step::Theme => I18nKey::OnboardingTheme,
+            Step::FileLogging => I18nKey::OnboardingFileLogging,
+            Step::Confirmations => I18nKey::OnboardingConfirmations,
+        }
+    }
+}
+
+/// Opciones elegidas en el asistente
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Onboarding {
+    /// Índice del paso actual en [`Step::ALL`]
+    pub step: usize,
+    pub language: Language,
+    pub theme: Theme,
+    pub file_logging: bool,
+    pub confirmations: bool,
+}
+
+impl Onboarding {
+    /// Empieza el asistente con los valores de una configuración
+    pub fn from_config(config: &Config) -> Self {
+        Self {
+            step: 0,
+            language: config.language(),
+            theme: config.theme(),
+            file_logging: config.logging.file_logging,
+            confirmations: config.confirmations.enabled,
+        }
+    }
+
+    /// Paso actual
+    pub fn current(&self) -> Step {
+        Step::ALL[self.step]
+    }
+
+    /// Indica si el paso actual es el último
+    pub fn is_last(&self) -> bool {
+        self.step + 1 == Step::ALL.len()
+    }
+
+    /// Avanza al siguiente paso; devuelve `false` si ya estaba en el último
+    pub fn advance(&mut self) -> bool {
+        if self.is_last() {
+            return false;
+        }
+        self.step += 1;
+        true
+    }
+
+    /// Vuelve al paso anterior; devuelve `false` si ya estaba en el primero
+    pub fn back(&mut self) -> bool {
+        if self.step == 0 {
+            return false;
+        }
+        self.step -= 1;
+        true
+    }
+
+    /// Etiquetas de las opciones del paso actual y la elegida
+    pub fn options(&self, i18n: &I18n) -> (Vec<&'static str>, usize) {
+        let yes_no = |value: bool| {
+            (
+                vec![
+                    i18n.t(I18nKey::OnboardingYes),
+                    i18n.t(I18nKey::OnboardingNo),
+                ],
+                usize::from(!value),
+            )
+        };
+        match self.current() {
+            Step::Language => (
+                Language::ALL.iter().map(Language::native_name).collect(),
+                index_of(&Language::ALL, self.language),
+            ),
+            Step::Theme => (
+                THEMES
+                    .iter()
+                    .map(|theme| i18n.t(theme.label_key()))
+                    .collect(),
+                index_of(&THEMES, self.theme),
+            ),
+            Step::FileLogging => yes_no(self.file_logging),
+            Step::Confirmations => yes_no(self.confirmations),
+        }
+    }
+
+    /// Pasa a la opción siguiente (o anterior) del paso actual
+    pub fn cycle(&mut self, forward: bool) {
+        match self.current() {
+            Step::Language => self.language = cycled(&Language::ALL, self.language, forward),
+            Step::Theme => self.theme = cycled(&THEMES, self.theme, forward),
+            Step::FileLogging => self.file_logging = !self.file_logging,
+            Step::Confirmations => self.confirmations = !self.confirmations,
+        }
+    }
+
+    /// Escribe las opciones elegidas en la configuración
+    pub fn apply(&self, config: &mut Config) {
+        config.set_language(self.language);
+        config.set_theme(self.theme);
+        config.logging.file_logging = self.file_logging;
+        config.confirmations.enabled = self.confirmations;
+    }
+}
+
+/// Posición de un valor en la lista de opciones
+fn index_of<T: PartialEq>(options: &[T], value: T) -> usize {
+    options
+        .iter()
+        .position(|option| *option == value)
+        .unwrap_or(0)
+}
+
+/// Opción siguiente o anterior a `value`, dando la vuelta en los extremos
+fn cycled<T: PartialEq + Copy>(options: &[T], value: T, forward: bool) -> T {
+    let len = options.len();
+    let idx = index_of(options, value);
+    let next = if forward {
+        (idx + 1) % len
+    } else {
+        (idx + len - 1) % len
+    };
+    options[next]
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_steps_and_choices() {
+        let mut wizard = Onboarding::from_config(&Config::default());
+        assert_eq!(wizard.current(), Step::Language);
+        assert!(!wizard.back());
+
+        wizard.cycle(true);
+        assert_eq!(wizard.language, Language::English);
+        wizard.cycle(false);
+        assert_eq!(wizard.language, Language::Spanish);
+
+        assert!(wizard.advance());
+        wizard.cycle(true);
+        assert_eq!(wizard.theme, Theme::Light);
+
+        assert!(wizard.advance());
+        wizard.cycle(true);
+        assert!(wizard.advance());
+        wizard.cycle(false);
+        assert!(wizard.is_last());
+        assert!(!wizard.advance());
+
+        let mut config = Config::default();
+        wizard.apply(&mut config);
+        assert_eq!(config.theme(), Theme::Light);
+        assert!(!config.logging.file_logging);
+        assert!(!config.confirmations.enabled);
+    }
+
+    #[test]
+    fn test_options_mark_current_choice() {
+        let mut wizard = Onboarding::from_config(&Config::default());
+        let i18n = I18n::new(Language::Spanish);
+        let (labels, selected) = wizard.options(&i18n);
+        assert_eq!(labels.len(), Language::ALL.len());
+        assert_eq!(labels[0], "Español");
+        assert_eq!(selected, 0);
+
+        wizard.step = 2;
+        wizard.file_logging = false;
+        assert_eq!(wizard.options(&i18n).1, 1);
+    }
+}
@@ -0,0 +1,415 @@
+//! Operaciones para uso como biblioteca
+//!
+//! Expone las limpiezas y optimizaciones de win_opt mediante el trait
+//! [`Operation`], de modo que otros programas puedan enumerarlas y
+//! ejecutarlas sin la interfaz TUI:
+//!
+//! ```no_run
+//! use win_opt::operation::{self, Context};
+//!
+//! let ctx = Context::new();
+//! for op in operation::all() {
+//!     println!("{}: {}", op.name(), op.description());
+//! }
+//! let result = operation::find("clean").unwrap().run(&ctx);
+//! println!("Liberados {} bytes", result.bytes_freed);
+//! ```
+//!
+//! Las operaciones reutilizan las mismas funciones `execute_*` que la TUI
+//! sobre una aplicación sin interfaz. Las que se ejecutan como tareas en
+//! segundo plano (DISM, SFC...) se esperan hasta que terminan.
+
+use crate::app::App;
+use crate::config::Config;
+use crate::i18n::{I18n, I18nKey, Language};
+use crate::logger::LogEntry;
+use crate::size_cache::SizeCache;
+use crate::types::{OperationState, OperationStats, View};
+use crate::winver::{self, BuildRange};
+use crate::{cleanup, memory, optimization, startup};
+use std::cell::RefCell;
+use std::thread;
+use std::time::Duration;
+
+/// Intervalo entre consultas de las tareas en segundo plano
+const POLL_INTERVAL: Duration = Duration::from_millis(100);
+
+/// Operación de limpieza u optimización ejecutable sin interfaz
+pub trait Operation {
+    /// Identificador estable de la operación (por ejemplo `"clean"`)
+    fn name(&self) -> &str;
+
+    /// Descripción breve, en inglés
+    fn description(&self) -> &str;
+
+    /// Indica si la operación necesita permisos de administrador
+    fn requires_admin(&self) -> bool;
+
+    /// Compilaciones de Windows en que funciona la operación
+    fn supported_builds(&self) -> BuildRange {
+        BuildRange::ANY
+    }
+
+    /// Bytes que se espera liberar, si se pueden calcular sin ejecutarla
+    fn estimate(&self) -> Option<u64> {
+        None
+    }
+
+    /// Ejecuta la operación y espera a que termine
+    fn run(&self, ctx: &Context) -> OperationResult;
+}
+
+/// Resultado de una operación
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct OperationResult {
+    /// La operación terminó correctamente
+    pub success: bool,
+    /// Logs generados durante la ejecución
+    pub logs: Vec<LogEntry>,
+    /// Bytes liberados (solo las limpiezas que llevan la cuenta)
+    pub bytes_freed: u64,
+    /// Hay que reiniciar el equipo para completar la operación
+    pub needs_reboot: bool,
+}
+
+/// Contexto de ejecución de las operaciones
+///
+/// Mantiene una aplicación sin interfaz con la configuración a usar. Un
+/// mismo contexto puede ejecutar varias operaciones seguidas.
+pub struct Context {
+    app: RefCell<App>,
+}
+
+impl Default for Context {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Context {
+    /// Crea un contexto con la configuración guardada del usuario
+    pub fn new() -> Self {
+        Self {
+            app: RefCell::new(App::default()),
+        }
+    }
+
+    /// Crea un contexto con la configuración indicada
+    pub fn with_config(config: Config) -> Self {
+        Self {
+            app: RefCell::new(App {
+                config,
+                ..App::default()
+            }),
+        }
+    }
+
+    /// Configuración que usan las operaciones
+    pub fn config(&self) -> Config {
+        self.app.borrow().config.clone()
+    }
+
+    /// Ejecuta una función `execute_*` como si se abriera su vista
+    fn execute(&self, view: View, execute: fn(&mut App)) -> OperationResult {
+        let mut app = self.app.borrow_mut();
+        app.current_view = view;
+        app.operation_logs.clear();
+        app.operation_state = OperationState::Idle;
+        app.stats = None;
+        app.needs_reboot = false;
+
+        app.run_operation(view, execute);
+
+        // Esperar a las tareas en segundo plano que haya lanzado
+        while app.jobs.active_count() > 0 {
+            thread::sleep(POLL_INTERVAL);
+            app.process_worker_messages();
+        }
+        app.jobs.clear_finished();
+
+        OperationResult {
+            success: app.operation_state == OperationState::Completed,
+            logs: std::mem::take(&mut app.operation_logs),
+            bytes_freed: app.stats.as_ref().map_or(0, OperationStats::bytes_freed),
+            needs_reboot: app.needs_reboot,
+        }
+    }
+}
+
+/// Operación incluida en win_opt
+#[derive(Debug, Clone, Copy)]
+pub struct BuiltinOperation {
+    name: &'static str,
+    /// Vista de la TUI que ejecuta la operación
+    view: View,
+    description: I18nKey,
+    requires_admin: bool,
+    builds: BuildRange,
+    estimate: Option<fn() -> u64>,
+    execute: fn(&mut App),
+}
+
+impl BuiltinOperation {
+    /// Vista de la TUI equivalente
+    pub fn view(&self) -> View {
+        self.view
+    }
+}
+
+impl Operation for BuiltinOperation {
+    fn name(&self) -> &str {
+        self.name
+    }
+
+    fn description(&self) -> &str {
+        I18n::new(Language::English).t(self.description)
+    }
+
+    fn requires_admin(&self) -> bool {
+        self.requires_admin
+    }
+
+    fn supported_builds(&self) -> BuildRange {
+        self.builds
+    }
+
+    fn estimate(&self) -> Option<u64> {
+        self.estimate.map(|estimate| estimate())
+    }
+
+    fn run(&self, ctx: &Context) -> OperationResult {
+        ctx.execute(self.view, self.execute)
+    }
+}
+
+/// Tamaño actual de las carpetas temporales
+///
+/// No incluye los demás perfiles: solo se limpian como administrador.
+fn estimate_temp_files() -> u64 {
+    let mut cache = SizeCache::load();
+    let size = cleanup::temp_locations(&[], &[])
+        .iter()
+        .map(|(_, path)| cache.path_size(path))
+        .sum();
+    cache.save();
+    size
+}
+
+/// Operaciones incluidas, en el orden del menú
+pub const BUILTIN_OPERATIONS: &[BuiltinOperation] = &[
+    BuiltinOperation {
+        name: "clean",
+        view: View::Clean,
+        description: I18nKey::MenuTempFilesDesc,
+        requires_admin: false,
+        builds: BuildRange::ANY,
+        estimate: Some(estimate_temp_files),
+        execute: cleanup::execute_clean,
+    },
+    BuiltinOperation {
+        name: "recycle-bin",
+        view: View::RecycleBin,
+        description: I18nKey::MenuRecycleBinDesc,
+        requires_admin: false,
+        builds: BuildRange::ANY,
+        estimate: None,
+        execute: cleanup::execute_recycle_bin,
+    },
+    BuiltinOperation {
+        name: "browser-cache",
+        view: View::BrowserCache,
+        description: I18nKey::MenuBrowserCacheDesc,
+        requires_admin: false,
+        builds: BuildRange::ANY,
+        estimate: None,
+        execute: cleanup::execute_browser_cache,
+    },
+    BuiltinOperation {
+        name: "system-logs",
+        view: View::SystemLogs,
+        description: I18nKey::MenuSystemLogsDesc,
+        requires_admin: true,
+        builds: BuildRange::ANY,
+        estimate: None,
+        execute: cleanup::execute_system_logs,
+    },
+    BuiltinOperation {
+        name: "windows-update",
+        view: View::WindowsUpdate,
+        description: I18nKey::MenuWindowsUpdateDesc,
+        requires_admin: true,
+        builds: BuildRange::from(winver::WINDOWS_8_1),
+        estimate: None,
+        execute: optimization::execute_windows_update_cleanup,
+    },
+    BuiltinOperation {
+        name: "optimize",
+        view: View::Optimize,
+        description: I18nKey::MenuOptimizeDesc,
+        requires_admin: true,
+        builds: BuildRange::ANY,
+        estimate: None,
+        execute: optimization::execute_optimize,
+    },
+    BuiltinOperation {
+        name: "startup",
+        view: View::StartupOptimizer,
+        description: I18nKey::MenuStartupDesc,
+        requires_admin: false,
+        builds: BuildRange::ANY,
+        estimate: None,
+        execute: startup::execute_startup_audit,
+    },
+    BuiltinOperation {
+        name: "visual-effects",
+        view: View::VisualEffects,
+        description: I18nKey::MenuVisualEffectsDesc,
+        requires_admin: true,
+        builds: BuildRange::ANY,
+        estimate: None,
+        execute: optimization::execute_visual_effects,
+    },
+    BuiltinOperation {
+        name: "memory",
+        view: View::Memory,
+        description: I18nKey::MenuMemoryDesc,
+        requires_admin: true,
+        builds: BuildRange::ANY,
+        estimate: None,
+        execute: memory::execute_memory_cleanup,
+    },
+    BuiltinOperation {
+        name: "network",
+        view: View::Network,
+        description: I18nKey::MenuNetworkDesc,
+        requires_admin: true,
+        builds: BuildRange::ANY,
+        estimate: None,
+        execute: optimization::execute_network,
+    },
+    BuiltinOperation {
+        name: "repair",
+        view: View::Repair,
+        description: I18nKey::MenuRepairDesc,
+        requires_admin: true,
+        builds: BuildRange::from(winver::WINDOWS_8),
+        estimate: None,
+        execute: optimization::execute_repair,
+    },
+    BuiltinOperation {
+        name: "font-cache",
+        view: View::FontCache,
+        description: I18nKey::MenuFontCacheDesc,
+        requires_admin: true,
+        builds: BuildRange::ANY,
+        estimate: None,
+        execute: optimization::execute_font_cache_rebuild,
+    },
+    BuiltinOperation {
+        name: "search-index",
+        view: View::SearchIndex,
+        description: I18nKey::MenuSearchIndexDesc,
+        requires_admin: true,
+        builds: BuildRange::ANY,
+        estimate: None,
+        execute: optimization::execute_search_index_rebuild,
+    },
+    BuiltinOperation {
+        name: "privacy",
+        view: View::Privacy,
+        description: I18nKey::MenuPrivacyDesc,
+        requires_admin: true,
+        builds: BuildRange::from(winver::WINDOWS_10),
+        estimate: None,
+        execute: optimization::execute_privacy,
+    },
+];
+
+/// Todas las operaciones incluidas
+pub fn all() -> impl Iterator<Item = &'static dyn Operation> {
+    BUILTIN_OPERATIONS
+        .iter()
+        .map(|operation| operation as &dyn Operation)
+}
+
+/// Identificador de la operación que ejecuta una vista
+pub fn name_for_view(view: View) -> Option<&'static str> {
+    BUILTIN_OPERATIONS
+        .iter()
+        .find(|operation| operation.view == view)
+        .map(|operation| operation.name)
+}
+
+/// Busca una operación por su identificador
+pub fn find(name: &str) -> Option<&'static dyn Operation> {
+    all().find(|operation| operation.name() == name)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::types::{WorkerHandle, WorkerMessage};
+    use std::collections::HashSet;
+    use std::sync::Arc;
+    use std::sync::atomic::AtomicBool;
+    use std::sync::mpsc;
+
+    #[test]
+    fn test_builtin_operations_are_unique_and_described() {
+        let names: HashSet<&str> = all().map(|operation| operation.name()).collect();
+        assert_eq!(names.len(), BUILTIN_OPERATIONS.len());
+        for operation in all() {
+            assert!(!operation.description().starts_with("[MISSING"));
+        }
+        assert!(find("repair").is_some_and(|op| op.requires_admin()));
+        assert!(find("no-existe").is_none());
+    }
+
+    /// Operación de prueba que encola una tarea terminada
+    fn fake_job(app: &mut App) {
+        app.operation_state = OperationState::Starting;
+        app.start_job(
+            View::Repair,
+            false,
+            Box::new(|| {
+                let (sender, receiver) = mpsc::channel();
+                for message in [
+                    WorkerMessage::StateChange(OperationState::Running),
+                    WorkerMessage::Log("paso hecho".to_string()),
+                    WorkerMessage::StateChange(OperationState::Completed),
+                    WorkerMessage::Completed,
+                ] {
+                    sender.send(message).unwrap();
+                }
+                WorkerHandle {
+                    receiver,
+                    task_handle: None,
+                    cancel_flag: Arc::new(AtomicBool::new(false)),
+                    skip_flag: Arc::new(AtomicBool::new(false)),
+                }
+            }),
+        );
+    }
+
+    #[test]
+    fn test_run_waits_for_background_jobs() {
+        let operation = BuiltinOperation {
+            name: "fake",
+            view: View::Repair,
+            description: I18nKey::MenuRepairDesc,
+            requires_admin: false,
+            builds: BuildRange::ANY,
+            estimate: None,
+            execute: fake_job,
+        };
+        let mut config = Config::default();
+        config.notifications.enabled = false;
+        let ctx = Context::with_config(config);
+
+        let result = operation.run(&ctx);
+        assert!(result.success);
+        let messages: Vec<&str> = result.logs.iter().map(|e| e.message.as_str()).collect();
+        assert_eq!(messages, vec!["paso hecho"]);
+        assert_eq!(ctx.app.borrow().jobs.active_count(), 0);
+    }
+}
@@ -1,65 +1,325 @@
-use crate::executor::{spawn_repair_worker, spawn_windows_update_worker};
-use crate::types::OperationState;
+use crate::cleanup::SystemCache;
+use crate::error::ControlError;
+use crate::executor::{
+    StepAction, WorkerStep, spawn_repair_worker, spawn_steps_worker, spawn_windows_update_worker,
+};
+use crate::i18n::I18nKey;
+use crate::ip_backup;
+use crate::journal;
+use crate::platform;
+use crate::safety;
+use crate::services::{self, StartType};
+use crate::tasks;
+use crate::types::{NetworkStats, OperationState, OperationStats, ServiceStats, View};
 use crate::utils::is_admin;
 use crate::{log_debug, log_error, log_info, log_warn};
 use std::fs;
-use std::path::Path;
-use std::process::Command;
+use std::io;
+use std::path::{Path, PathBuf};
+use std::time::{Duration, SystemTime};
+
+/// Días sin usarse tras los que un archivo Prefetch se puede eliminar
+const PREFETCH_MIN_AGE_DAYS: u64 = 30;
+
+/// Valores de `MediaType` de `MSFT_PhysicalDisk`: disco duro, SSD y memoria
+/// de clase de almacenamiento
+const MEDIA_TYPE_HDD: u32 = 3;
+const MEDIA_TYPE_SSD: u32 = 4;
+const MEDIA_TYPE_SCM: u32 = 5;
+
+/// Tipo del disco en el que está instalado Windows
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum SystemDiskKind {
+    Ssd,
+    Hdd,
+    Unknown,
+}
+
+/// Archivos de la carpeta Prefetch que nunca se eliminan
+///
+/// `Layout.ini` guarda el orden de arranque que usa el desfragmentador para
+/// colocar juntos los archivos que se leen al iniciar Windows.
+const PREFETCH_KEEP: [&str; 1] = ["Layout.ini"];
+
+/// Paso de la limpieza de red
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum NetworkStep {
+    DnsFlush,
+    WinsockReset,
+    ArpCache,
+    NetbiosCache,
+    TcpIpReset,
+}
+
+impl NetworkStep {
+    /// Pasos en el orden en que se ejecutan
+    pub const ALL: [NetworkStep; 5] = [
+        NetworkStep::DnsFlush,
+        NetworkStep::WinsockReset,
+        NetworkStep::ArpCache,
+        NetworkStep::NetbiosCache,
+        NetworkStep::TcpIpReset,
+    ];
+
+    /// Comando y argumentos del paso
+    fn command(self) -> (&'static str, &'static [&'static str]) {
+        match self {
+            NetworkStep::DnsFlush => ("ipconfig", &["/flushdns"]),
+            NetworkStep::WinsockReset => ("netsh", &["winsock", "reset"]),
+            NetworkStep::ArpCache => ("arp", &["-d", "*"]),
+            NetworkStep::NetbiosCache => ("nbtstat", &["-R"]),
+            NetworkStep::TcpIpReset => ("netsh", &["int", "ip", "reset"]),
+        }
+    }
+
+    /// Clave del mensaje de éxito del paso
+    fn success_key(self) -> I18nKey {
+        match self {
+            NetworkStep::DnsFlush => I18nKey::NetworkDnsSuccess,
+            NetworkStep::WinsockReset => I18nKey::NetworkWinsockSuccess,
+            NetworkStep::ArpCache => I18nKey::NetworkArpSuccess,
+            NetworkStep::NetbiosCache => I18nKey::NetworkNetbiosSuccess,
+            NetworkStep::TcpIpReset => I18nKey::NetworkTcpIpSuccess,
+        }
+    }
+
+    /// Clave de traducción del paso
+    pub fn label_key(self) -> I18nKey {
+        match self {
+            NetworkStep::DnsFlush => I18nKey::NetworkStepDns,
+            NetworkStep::WinsockReset => I18nKey::NetworkStepWinsock,
+            NetworkStep::ArpCache => I18nKey::NetworkStepArp,
+            NetworkStep::NetbiosCache => I18nKey::NetworkStepNetbios,
+            NetworkStep::TcpIpReset => I18nKey::NetworkStepTcpIp,
+        }
+    }
+
+    /// Requiere reiniciar el sistema para completarse
+    fn needs_reboot(self) -> bool {
+        matches!(self, NetworkStep::WinsockReset | NetworkStep::TcpIpReset)
+    }
+}
+
+/// Estado de un paso de red en la vista
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct NetworkStepState {
+    pub step: NetworkStep,
+    /// Seleccionado para ejecutarse
+    pub enabled: bool,
+    /// Resultado de la última ejecución
+    pub result: Option<bool>,
+}
+
+/// Pasos de red seleccionados por defecto
+///
+/// El restablecimiento de TCP/IP es más intrusivo y hay que marcarlo a mano.
+pub fn default_network_steps() -> Vec<NetworkStepState> {
+    NetworkStep::ALL
+        .iter()
+        .map(|&step| NetworkStepState {
+            step,
+            enabled: step != NetworkStep::TcpIpReset,
+            result: None,
+        })
+        .collect()
+}
+
+/// Prepara la vista de red sin ejecutar nada
+pub fn prepare_network(app: &mut crate::app::App) {
+    for state in &mut app.network_steps {
+        state.result = None;
+    }
+    app.operation_state = OperationState::Idle;
+    log_info!(app, "💡 {}", app.t(I18nKey::NetworkSelectHint));
+    if let Some(date) = ip_backup::pending_backup_date() {
+        log_warn!(
+            app,
+            "⚠️  {}",
+            app.t_args(
+                I18nKey::NetworkStaticIpPending,
+                &[("date", &app.i18n.format_date(&date))]
+            )
+        );
+    }
+}
 
-/// Ejecuta las operaciones de red
+/// Ejecuta los pasos de red seleccionados
 pub fn execute_network(app: &mut crate::app::App) {
-    app.operation_state = OperationState::Running;
-    log_info!(app, "🌐 Iniciando operaciones de red...");
+    let steps: Vec<NetworkStep> = app
+        .network_steps
+        .iter()
+        .filter(|s| s.enabled)
+        .map(|s| s.step)
+        .collect();
+    if steps.is_empty() {
+        log_warn!(app, "⚠️  {}", app.t(I18nKey::NetworkNoSteps));
+        return;
+    }
 
-    // DNS Flush
-    log_info!(app, "Ejecutando: ipconfig /flushdns");
-    let output = Command::new("cmd")
-        .args(["/C", "ipconfig /flushdns"])
-        .output();
+    app.operation_state = OperationState::Running;
+    log_info!(app, "🌐 {}", app.t(I18nKey::NetworkStarting));
+    let targets = steps
+        .iter()
+        .map(|step| {
+            let (program, args) = step.command();
+            format!("{} {}", program, args.join(" "))
+        })
+        .collect();
+    journal::begin(View::Network, targets);
+
+    let mut stats = NetworkStats {
+        skipped: app.network_steps.len() - steps.len(),
+        ..NetworkStats::default()
+    };
+    for step in steps {
+        let (program, args) = step.command();
+        let command = format!("{} {}", program, args.join(" "));
+        log_info!(app, "");
+        log_info!(
+            app,
+            "{}",
+            app.t_args(I18nKey::OpRunning, &[("command", &command)])
+        );
 
-    match output {
-        Ok(result) => {
-            if result.status.success() {
-                log_info!(app, "✅ Caché DNS limpiada exitosamente");
-            } else {
-                log_error!(app, "❌ Error al limpiar la caché DNS");
-            }
+        let success = if step == NetworkStep::TcpIpReset && !ip_backup::backup_static_config(app) {
+            log_warn!(app, "⚠️  {}", app.t(I18nKey::NetworkTcpIpSkipped));
+            false
+        } else {
+            run_network_step(app, step)
+        };
+
+        if success {
+            stats.succeeded += 1;
+        } else {
+            stats.failed += 1;
+        }
+        if let Some(state) = app.network_steps.iter_mut().find(|s| s.step == step) {
+            state.result = Some(success);
         }
-        Err(e) => log_error!(app, "❌ Error: {}", e),
     }
 
-    // Winsock Reset
+    let failed = stats.failed;
+    app.stats = Some(OperationStats::Network(stats));
+
     log_info!(app, "");
-    log_info!(app, "Ejecutando: netsh winsock reset");
-    let output_winsock = Command::new("cmd")
-        .args(["/C", "netsh winsock reset"])
-        .output();
+    if failed == 0 {
+        log_info!(app, "✅ {}", app.t(I18nKey::NetworkCompleted));
+        app.operation_state = OperationState::Completed;
+    } else {
+        log_warn!(
+            app,
+            "⚠️  {}",
+            app.t_args(
+                I18nKey::NetworkStepsFailed,
+                &[("count", &failed.to_string())]
+            )
+        );
+        app.operation_state = OperationState::Failed;
+    }
+}
 
-    match output_winsock {
-        Ok(result) => {
-            if result.status.success() {
-                log_info!(app, "✅ Winsock reiniciado exitosamente");
-                log_info!(
-                    app,
-                    "ℹ️  Se recomienda reiniciar el sistema para aplicar los cambios"
-                );
-            } else {
-                log_warn!(
-                    app,
-                    "⚠️  Falló el reinicio de Winsock (se requieren permisos de administrador)"
-                );
+/// Ejecuta un paso de red y registra su resultado
+fn run_network_step(app: &mut crate::app::App, step: NetworkStep) -> bool {
+    let (program, args) = step.command();
+    match platform::command(program).args(args).output() {
+        Ok(result) if result.status.success() => {
+            log_info!(app, "✅ {}", app.t(step.success_key()));
+            if step.needs_reboot() {
+                log_reboot_recommended(app);
             }
+            true
+        }
+        Ok(result) => {
+            let stdout = String::from_utf8_lossy(&result.stdout);
+            log_debug!(app, "{}", stdout.trim());
+            let command = format!("{} {}", program, args.join(" "));
+            log_error!(
+                app,
+                "❌ {}",
+                app.t_args(I18nKey::OpCommandFailed, &[("command", &command)])
+            );
+            false
         }
         Err(e) => {
             log_error!(
                 app,
-                "❌ Falló el reinicio de Winsock (se requieren permisos de administrador): {}",
-                e
+                "❌ {}",
+                app.t_args(I18nKey::OpErrorDetail, &[("error", &e.to_string())])
             );
+            false
         }
     }
+}
 
-    app.operation_state = OperationState::Completed;
+/// Registra que la operación necesita permisos de administrador
+pub(crate) fn log_requires_admin(app: &mut crate::app::App) {
+    log_error!(app, "⛔ {}", app.t(I18nKey::OpRequiresAdmin));
+    log_info!(app, "ℹ️  {}", app.t(I18nKey::OpPleaseRunAsAdmin));
+}
+
+/// Registra que hay que reiniciar para aplicar los cambios
+///
+/// Activa además el aviso de reinicio que se muestra en todas las vistas.
+fn log_reboot_recommended(app: &mut crate::app::App) {
+    log_info!(app, "ℹ️  {}", app.t(I18nKey::OpRebootRecommended));
+    app.needs_reboot = true;
+}
+
+/// Registra que un servicio quedó deshabilitado
+fn log_service_disabled(app: &mut crate::app::App, service: &str) {
+    log_info!(
+        app,
+        "✅ {}",
+        app.t_args(I18nKey::OpServiceDisabled, &[("service", service)])
+    );
+}
+
+/// Registra que no se pudo deshabilitar un servicio
+fn log_service_failed(app: &mut crate::app::App, service: &str) {
+    log_warn!(
+        app,
+        "⚠️  {}",
+        app.t_args(I18nKey::OpServiceDisableFailed, &[("service", service)])
+    );
+}
+
+/// Registra un error al modificar un servicio
+fn log_service_error(app: &mut crate::app::App, service: &str, error: &dyn std::fmt::Display) {
+    log_error!(
+        app,
+        "❌ {}",
+        app.t_args(
+            I18nKey::OpServiceError,
+            &[("service", service), ("error", &error.to_string())],
+        )
+    );
+}
+
+/// Deshabilita un servicio y anota el resultado en `stats`
+///
+/// `name` es el nombre con el que aparece en el log cuando se deshabilita.
+/// Antes se guarda su tipo de inicio para poder restaurarlo; si la copia no
+/// se puede guardar, el servicio no se toca.
+fn disable_service(app: &mut crate::app::App, service: &str, name: &str, stats: &mut ServiceStats) {
+    if let Err(e) = services::record(service) {
+        stats.failed += 1;
+        log_service_error(app, service, &e);
+        return;
+    }
+    match services::set_start_type(service, StartType::Disabled) {
+        Ok(()) => {
+            stats.disabled += 1;
+            log_service_disabled(app, name);
+        }
+        Err(ControlError::NotFound) => {
+            stats.failed += 1;
+            log_service_failed(app, service);
+        }
+        Err(e) => {
+            stats.failed += 1;
+            log_service_error(app, service, &e);
+        }
+    }
 }
 
 /// Ejecuta las operaciones de reparación
@@ -67,223 +327,465 @@ pub fn execute_network(app: &mut crate::app::App) {
 /// Esta función spawn un worker thread que ejecuta DISM y SFC en segundo plano,
 /// manteniendo la UI responsiva y evitando que la salida corrompa la TUI.
 pub fn execute_repair(app: &mut crate::app::App) {
-    // Limpiar logs anteriores
-    app.operation_logs.clear();
-
     // Verificar permisos de administrador
     if !is_admin() {
-        log_error!(
-            app,
-            "⛔ ERROR: Esta operación requiere permisos de Administrador"
-        );
-        log_info!(
-            app,
-            "ℹ️  Por favor, ejecuta la aplicación como Administrador"
-        );
+        log_requires_admin(app);
         app.operation_state = OperationState::Failed;
         return;
     }
 
     // Cambiar estado a Starting
     app.operation_state = OperationState::Starting;
-    log_info!(app, "🔧 Iniciando reparación del sistema...");
+    log_info!(app, "🔧 {}", app.t(I18nKey::RepairStarting));
+    journal::begin(
+        View::Repair,
+        vec!["DISM /RestoreHealth".into(), "sfc /scannow".into()],
+    );
 
-    // Spawn worker thread
-    app.worker_handle = Some(spawn_repair_worker());
+    // Encolar la tarea; es exclusiva porque DISM no admite dos ejecuciones simultáneas
+    let limits = app.config.executor;
+    let i18n = app.i18n;
+    app.start_job(
+        View::Repair,
+        true,
+        Box::new(move || spawn_repair_worker(limits, i18n)),
+    );
+}
+
+/// Tiempo límite de cada paso de las reconstrucciones (detener un servicio
+/// puede tardar si tiene archivos abiertos)
+const STEP_TIMEOUT: Duration = Duration::from_secs(5 * 60);
+
+/// Pasos para reconstruir la caché de fuentes
+///
+/// Los archivos se eliminan como los de las limpiezas (ver
+/// [`SystemCache::files`]), con el servicio detenido para que no estén en uso.
+const FONT_CACHE_STEPS: &[WorkerStep] = &[
+    WorkerStep {
+        description: I18nKey::FontCacheStopService,
+        action: StepAction::Command {
+            command: "net",
+            args: &["stop", "FontCache", "/y"],
+        },
+        timeout: STEP_TIMEOUT,
+    },
+    WorkerStep {
+        description: I18nKey::FontCacheRemoveFiles,
+        action: StepAction::RemoveCache(SystemCache::Fonts),
+        timeout: STEP_TIMEOUT,
+    },
+    WorkerStep {
+        description: I18nKey::FontCacheStartService,
+        action: StepAction::Command {
+            command: "net",
+            args: &["start", "FontCache"],
+        },
+        timeout: STEP_TIMEOUT,
+    },
+];
+
+/// Pasos para reconstruir el índice de Windows Search
+const SEARCH_INDEX_STEPS: &[WorkerStep] = &[
+    WorkerStep {
+        description: I18nKey::SearchIndexStopService,
+        action: StepAction::Command {
+            command: "net",
+            args: &["stop", "WSearch", "/y"],
+        },
+        timeout: STEP_TIMEOUT,
+    },
+    WorkerStep {
+        description: I18nKey::SearchIndexRemoveFiles,
+        action: StepAction::RemoveCache(SystemCache::SearchIndex),
+        timeout: STEP_TIMEOUT,
+    },
+    WorkerStep {
+        description: I18nKey::SearchIndexStartService,
+        action: StepAction::Command {
+            command: "net",
+            args: &["start", "WSearch"],
+        },
+        timeout: STEP_TIMEOUT,
+    },
+];
+
+/// Reconstruye la caché de fuentes en un worker
+pub fn execute_font_cache_rebuild(app: &mut crate::app::App) {
+    start_steps_operation(
+        app,
+        View::FontCache,
+        "🔤",
+        I18nKey::FontCacheStarting,
+        I18nKey::FontCacheTitle,
+        FONT_CACHE_STEPS,
+    );
+}
+
+/// Reconstruye el índice de Windows Search en un worker
+///
+/// Windows vuelve a indexar los archivos en segundo plano al reiniciar el
+/// servicio, lo que puede tardar horas en discos grandes.
+pub fn execute_search_index_rebuild(app: &mut crate::app::App) {
+    start_steps_operation(
+        app,
+        View::SearchIndex,
+        "🔎",
+        I18nKey::SearchIndexStarting,
+        I18nKey::SearchIndexTitle,
+        SEARCH_INDEX_STEPS,
+    );
+}
+
+/// Lanza un worker de pasos que requiere permisos de administrador
+fn start_steps_operation(
+    app: &mut crate::app::App,
+    view: View,
+    icon: &str,
+    intro: I18nKey,
+    title: I18nKey,
+    steps: &'static [WorkerStep],
+) {
+    if !is_admin() {
+        log_requires_admin(app);
+        app.operation_state = OperationState::Failed;
+        return;
+    }
+
+    app.operation_state = OperationState::Starting;
+    log_info!(app, "{} {}", icon, app.t(intro));
+    // Los archivos que se van a eliminar se anotan uno a uno
+    let targets = steps
+        .iter()
+        .flat_map(|step| match step.action {
+            StepAction::Command { .. } => vec![app.t(step.description).to_string()],
+            StepAction::RemoveCache(cache) => cache
+                .files()
+                .iter()
+                .map(|path| path.display().to_string())
+                .collect(),
+        })
+        .collect();
+    journal::begin(view, targets);
+
+    let config = app.config.clone();
+    let i18n = app.i18n;
+    app.start_job(
+        view,
+        false,
+        Box::new(move || spawn_steps_worker(title, steps, config, i18n)),
+    );
 }
 
 /// Ejecuta optimización avanzada del sistema
 pub fn execute_optimize(app: &mut crate::app::App) {
     app.operation_state = OperationState::Running;
-    log_info!(app, "⚡ Iniciando optimización avanzada del sistema...");
+    log_info!(app, "⚡ {}", app.t(I18nKey::OptimizeStarting));
 
     if !is_admin() {
-        log_error!(
-            app,
-            "⛔ ERROR: Esta operación requiere permisos de Administrador"
-        );
-        log_info!(
-            app,
-            "ℹ️  Por favor, ejecuta la aplicación como Administrador"
-        );
+        log_requires_admin(app);
         app.operation_state = OperationState::Completed;
         return;
     }
 
-    // Limpiar Prefetch
+    let mut targets: Vec<String> = SAFE_SERVICES
+        .iter()
+        .map(|(service, _)| service.to_string())
+        .collect();
+    if app.config.cleanup.prefetch {
+        let prefetch = platform::windows_dir().join("Prefetch");
+        targets.insert(0, prefetch.display().to_string());
+    }
+    journal::begin(View::Optimize, targets);
+
     log_info!(app, "");
-    log_info!(app, "🗑️  Limpiando archivos Prefetch...");
-
-    let prefetch_dir = Path::new("C:\\Windows\\Prefetch");
-    if prefetch_dir.exists() {
-        let mut deleted = 0;
-        let mut failed = 0;
-
-        match fs::read_dir(prefetch_dir) {
-            Ok(entries) => {
-                for entry in entries.flatten() {
-                    let path = entry.path();
-                    if path.starts_with(prefetch_dir) && path.is_file() {
-                        if fs::remove_file(&path).is_ok() {
-                            deleted += 1;
-                            log_debug!(app, "Prefetch eliminado: {}", path.display());
-                        } else {
-                            failed += 1;
-                        }
-                    }
-                }
-                log_info!(
-                    app,
-                    "✅ Archivos Prefetch limpiados: {} eliminados, {} omitidos",
-                    deleted,
-                    failed
-                );
-            }
-            Err(e) => log_error!(app, "❌ Error limpiando Prefetch: {}", e),
-        }
+    if app.config.cleanup.prefetch {
+        clean_prefetch(app);
     } else {
-        log_warn!(app, "⚠️  Directorio Prefetch no encontrado");
+        log_info!(app, "ℹ️  {}", app.t(I18nKey::OptimizePrefetchSkipped));
     }
 
     // Configurar plan de energía
     log_info!(app, "");
-    log_info!(
-        app,
-        "⚡ Configurando plan de energía de alto rendimiento..."
-    );
+    log_info!(app, "⚡ {}", app.t(I18nKey::OptimizePower));
 
-    let power_result = Command::new("powercfg")
+    let power_result = platform::command("powercfg")
         .args(["/setactive", "8c5e7fda-e8bf-4a96-9a85-a6e23a8c635c"])
         .output();
 
     match power_result {
         Ok(result) => {
             if result.status.success() {
-                log_info!(app, "✅ Plan de energía configurado a Alto Rendimiento");
+                log_info!(app, "✅ {}", app.t(I18nKey::OptimizePowerSuccess));
             } else {
-                log_warn!(app, "⚠️  No se pudo cambiar el plan de energía");
+                log_warn!(app, "⚠️  {}", app.t(I18nKey::OptimizePowerFailed));
             }
         }
-        Err(e) => log_error!(app, "❌ Error configurando energía: {}", e),
+        Err(e) => log_error!(
+            app,
+            "❌ {}",
+            app.t_args(I18nKey::OptimizePowerError, &[("error", &e.to_string())])
+        ),
     }
 
     // Deshabilitar servicios innecesarios
     log_info!(app, "");
-    log_info!(app, "🔧 Optimizando servicios del sistema...");
+    log_info!(app, "🔧 {}", app.t(I18nKey::OptimizeServices));
 
-    const SAFE_SERVICES: &[(&str, &str)] = &[
-        ("DiagTrack", "Servicio de telemetría"),
-        ("SysMain", "SuperFetch (en SSDs)"),
+    const SAFE_SERVICES: &[(&str, I18nKey)] = &[
+        ("DiagTrack", I18nKey::OptimizeServiceTelemetry),
+        ("SysMain", I18nKey::OptimizeServiceSysMain),
     ];
 
+    let disk = detect_system_disk();
+    let mut stats = ServiceStats::default();
     for (service, description) in SAFE_SERVICES {
-        let service_result = Command::new("sc")
-            .args(["config", service, "start=disabled"])
-            .output();
-
-        match service_result {
-            Ok(result) => {
-                if result.status.success() {
-                    log_info!(
-                        app,
-                        "✅ Servicio deshabilitado: {} ({})",
-                        service,
-                        description
-                    );
-                } else {
-                    log_warn!(app, "⚠️  No se pudo deshabilitar: {}", service);
-                }
-            }
-            Err(e) => {
-                log_error!(app, "❌ Error con servicio {}: {}", service, e);
-            }
+        if let Some(reason) = service_kept_reason(service, disk) {
+            log_warn!(app, "⚠️  {}", app.t(reason));
+            continue;
         }
+        let name = format!("{} ({})", service, app.t(*description));
+        disable_service(app, service, &name, &mut stats);
     }
+    app.stats = Some(OperationStats::Services(stats));
 
     log_info!(app, "");
-    log_info!(app, "✅ Optimización avanzada completada");
-    log_info!(app, "ℹ️  Se recomienda reiniciar el sistema");
+    log_info!(app, "✅ {}", app.t(I18nKey::OptimizeCompleted));
+    log_reboot_recommended(app);
 
     app.operation_state = OperationState::Completed;
 }
 
+/// Script que muestra `MediaType|SpindleSpeed` de los discos físicos que
+/// contienen la unidad indicada
+fn system_disk_script(letter: char) -> String {
+    format!(
+        "$disk = (Get-Partition -DriveLetter {letter}).DiskNumber; \
+         Get-CimInstance -Namespace root/Microsoft/Windows/Storage -ClassName MSFT_PhysicalDisk | \
+         Where-Object {{ $_.DeviceId -eq [string]$disk }} | \
+         ForEach-Object {{ [string]$_.MediaType + '|' + $_.SpindleSpeed }}"
+    )
+}
+
+/// Parsea la salida del script de tipo de disco
+///
+/// Cuando el disco no informa de su tipo (`Unspecified`, habitual en
+/// máquinas virtuales y controladoras RAID) se usa la velocidad de giro: 0
+/// indica que no tiene penalización de búsqueda. Si la unidad ocupa varios
+/// discos, basta con que uno sea un disco duro.
+fn parse_system_disk(output: &str) -> SystemDiskKind {
+    let kinds: Vec<SystemDiskKind> = output
+        .lines()
+        .filter_map(|line| line.trim().split_once('|'))
+        .map(|(media, spindle)| match media.trim().parse::<u32>() {
+            Ok(MEDIA_TYPE_SSD | MEDIA_TYPE_SCM) => SystemDiskKind::Ssd,
+            Ok(MEDIA_TYPE_HDD) => SystemDiskKind::Hdd,
+            _ => match spindle.trim().parse::<u32>() {
+                Ok(0) => SystemDiskKind::Ssd,
+                Ok(u32::MAX) | Err(_) => SystemDiskKind::Unknown,
+                Ok(_) => SystemDiskKind::Hdd,
+            },
+        })
+        .collect();
+    if kinds.contains(&SystemDiskKind::Hdd) {
+        SystemDiskKind::Hdd
+    } else if !kinds.is_empty() && kinds.iter().all(|kind| *kind == SystemDiskKind::Ssd) {
+        SystemDiskKind::Ssd
+    } else {
+        SystemDiskKind::Unknown
+    }
+}
+
+/// Motivo para no deshabilitar un servicio de la optimización
+///
+/// SysMain precarga en memoria los programas más usados, lo que acelera mucho
+/// su apertura desde un disco duro; solo se deshabilita en un SSD.
+fn service_kept_reason(service: &str, disk: SystemDiskKind) -> Option<I18nKey> {
+    match (service, disk) {
+        ("SysMain", SystemDiskKind::Hdd) => Some(I18nKey::OptimizeSysMainKeptHdd),
+        ("SysMain", SystemDiskKind::Unknown) => Some(I18nKey::OptimizeSysMainKeptUnknown),
+        _ => None,
+    }
+}
+
+/// Comprueba si Windows está instalado en un SSD
+fn detect_system_disk() -> SystemDiskKind {
+    let letter = platform::windows_dir()
+        .to_string_lossy()
+        .chars()
+        .next()
+        .filter(char::is_ascii_alphabetic)
+        .unwrap_or('C');
+    match platform::command("powershell")
+        .args(["-NoProfile", "-Command", &system_disk_script(letter)])
+        .output()
+    {
+        Ok(output) if output.status.success() => {
+            parse_system_disk(&String::from_utf8_lossy(&output.stdout))
+        }
+        _ => SystemDiskKind::Unknown,
+    }
+}
+
+/// Archivos Prefetch que se pueden eliminar y número de los que se conservan
+///
+/// Solo se eliminan las trazas `.pf` que no se han actualizado en
+/// `PREFETCH_MIN_AGE_DAYS` días, es decir, de programas que ya no se usan.
+/// Las recientes son las que Windows aprovecha para acelerar el arranque y la
+/// apertura de programas.
+fn stale_prefetch_files(dir: &Path, now: SystemTime) -> io::Result<(Vec<PathBuf>, usize)> {
+    let min_age = Duration::from_secs(PREFETCH_MIN_AGE_DAYS * 24 * 60 * 60);
+    let mut stale = Vec::new();
+    let mut kept = 0;
+    for entry in fs::read_dir(dir)?.flatten() {
+        let path = entry.path();
+        let name = entry.file_name().to_string_lossy().into_owned();
+        let is_trace = path
+            .extension()
+            .is_some_and(|ext| ext.eq_ignore_ascii_case("pf"));
+        let Ok(metadata) = entry.metadata() else {
+            continue;
+        };
+        if !is_trace
+            || !metadata.is_file()
+            || PREFETCH_KEEP
+                .iter()
+                .any(|keep| keep.eq_ignore_ascii_case(&name))
+        {
+            continue;
+        }
+        let age = metadata
+            .modified()
+            .ok()
+            .and_then(|modified| now.duration_since(modified).ok());
+        if age.is_some_and(|age| age >= min_age) {
+            stale.push(path);
+        } else {
+            kept += 1;
+        }
+    }
+    stale.sort();
+    Ok((stale, kept))
+}
+
+/// Elimina los archivos Prefetch antiguos
+fn clean_prefetch(app: &mut crate::app::App) {
+    let days = PREFETCH_MIN_AGE_DAYS.to_string();
+    log_info!(
+        app,
+        "🗑️  {}",
+        app.t_args(I18nKey::OptimizePrefetch, &[("days", &days)])
+    );
+
+    let prefetch_dir = platform::windows_dir().join("Prefetch");
+    if !prefetch_dir.exists() {
+        log_warn!(app, "⚠️  {}", app.t(I18nKey::OptimizePrefetchNotFound));
+        return;
+    }
+
+    let (stale, kept) = match stale_prefetch_files(&prefetch_dir, SystemTime::now()) {
+        Ok(files) => files,
+        Err(e) => {
+            log_error!(
+                app,
+                "❌ {}",
+                app.t_args(I18nKey::OptimizePrefetchError, &[("error", &e.to_string())])
+            );
+            return;
+        }
+    };
+
+    let mut deleted = 0;
+    let mut failed = 0;
+    for path in stale {
+        if safety::check_removable(&path).is_ok() && fs::remove_file(&path).is_ok() {
+            deleted += 1;
+            log_debug!(
+                app,
+                "{}",
+                app.t_args(
+                    I18nKey::OptimizePrefetchDeleted,
+                    &[("path", &path.display().to_string())],
+                )
+            );
+        } else {
+            failed += 1;
+        }
+    }
+    log_info!(
+        app,
+        "✅ {}",
+        app.t_args(
+            I18nKey::OptimizePrefetchDone,
+            &[
+                ("deleted", &deleted.to_string()),
+                ("kept", &kept.to_string()),
+                ("failed", &failed.to_string()),
+            ],
+        )
+    );
+}
+
 /// Ejecuta limpieza de archivos de Windows Update
 ///
 /// Esta función spawn un worker thread que ejecuta DISM para limpiar
 /// componentes de Windows Update en segundo plano, manteniendo la UI
 /// responsiva y evitando que la salida corrompa la TUI.
 pub fn execute_windows_update_cleanup(app: &mut crate::app::App) {
-    // Limpiar logs anteriores
-    app.operation_logs.clear();
-
     // Verificar permisos de administrador
     if !is_admin() {
-        log_error!(
-            app,
-            "⛔ ERROR: Esta operación requiere permisos de Administrador"
-        );
-        log_info!(
-            app,
-            "ℹ️  Por favor, ejecuta la aplicación como Administrador"
-        );
+        log_requires_admin(app);
         app.operation_state = OperationState::Failed;
         return;
     }
 
     // Cambiar estado a Starting
     app.operation_state = OperationState::Starting;
-    log_info!(app, "🔄 Iniciando limpieza de Windows Update...");
+    log_info!(app, "🔄 {}", app.t(I18nKey::WindowsUpdateStarting));
+    journal::begin(
+        View::WindowsUpdate,
+        vec!["DISM /StartComponentCleanup".into()],
+    );
 
-    // Spawn worker thread
-    app.worker_handle = Some(spawn_windows_update_worker());
+    // Encolar la tarea (exclusiva, como la reparación, porque usa DISM)
+    let limits = app.config.executor;
+    let i18n = app.i18n;
+    app.start_job(
+        View::WindowsUpdate,
+        true,
+        Box::new(move || spawn_windows_update_worker(limits, i18n)),
+    );
 }
 
 /// Ejecuta desactivación de telemetría y mejoras de privacidad
 pub fn execute_privacy(app: &mut crate::app::App) {
     app.operation_state = OperationState::Running;
-    log_info!(app, "🔒 Iniciando configuración de privacidad...");
+    log_info!(app, "🔒 {}", app.t(I18nKey::PrivacyStarting));
 
     if !is_admin() {
-        log_error!(
-            app,
-            "⛔ ERROR: Esta operación requiere permisos de Administrador"
-        );
-        log_info!(
-            app,
-            "ℹ️  Por favor, ejecuta la aplicación como Administrador"
-        );
+        log_requires_admin(app);
         app.operation_state = OperationState::Completed;
         return;
     }
 
     // Deshabilitar telemetría
     log_info!(app, "");
-    log_info!(app, "🛡️  Deshabilitando telemetría de Windows...");
+    log_info!(app, "🛡️  {}", app.t(I18nKey::PrivacyTelemetry));
 
     const TELEMETRY_SERVICES: &[&str] = &["DiagTrack", "dmwappushservice", "WerSvc"];
 
+    journal::begin(
+        View::Privacy,
+        TELEMETRY_SERVICES.iter().map(ToString::to_string).collect(),
+    );
+    let mut stats = ServiceStats::default();
     for service in TELEMETRY_SERVICES {
-        let result = Command::new("sc")
-            .args(["config", service, "start=disabled"])
-            .output();
-
-        match result {
-            Ok(output) => {
-                if output.status.success() {
-                    log_info!(app, "✅ Servicio {} deshabilitado", service);
-                } else {
-                    log_warn!(app, "⚠️  No se pudo deshabilitar {}", service);
-                }
-            }
-            Err(e) => {
-                log_error!(app, "❌ Error con servicio {}: {}", service, e);
-            }
-        }
+        disable_service(app, service, service, &mut stats);
     }
+    app.stats = Some(OperationStats::Services(stats));
 
     // Deshabilitar tareas programadas
     log_info!(app, "");
-    log_info!(app, "📋 Deshabilitando tareas programadas de telemetría...");
+    log_info!(app, "📋 {}", app.t(I18nKey::PrivacyTasks));
 
     let tasks = [
         "\\Microsoft\\Windows\\Application Experience\\Microsoft Compatibility Appraiser",
@@ -294,80 +796,28 @@ pub fn execute_privacy(app: &mut crate::app::App) {
     ];
 
     for task in tasks {
-        let result = Command::new("schtasks")
-            .args(["/Change", "/TN", task, "/Disable"])
-            .output();
-
-        if let Ok(output) = result
-            && output.status.success()
-        {
-            log_debug!(app, "✅ Tarea deshabilitada: {}", task);
-        }
-    }
-
-    log_info!(app, "");
-    log_info!(app, "✅ Configuración de privacidad completada");
-    log_info!(
-        app,
-        "ℹ️  Se recomienda reiniciar el sistema para aplicar todos los cambios"
-    );
-
-    app.operation_state = OperationState::Completed;
-}
-
-/// Ejecuta optimización de programas de inicio
-pub fn execute_startup_optimizer(app: &mut crate::app::App) {
-    app.operation_state = OperationState::Running;
-    log_info!(app, "🚀 Analizando programas de inicio...");
-
-    // Listar programas de inicio
-    log_info!(app, "");
-    log_info!(app, "📋 Obteniendo lista de programas de inicio...");
-
-    let result = Command::new("wmic")
-        .args(["startup", "get", "caption,command"])
-        .output();
-
-    match result {
-        Ok(output) => {
-            if output.status.success() {
-                let output_str = String::from_utf8_lossy(&output.stdout);
-                let lines: Vec<&str> = output_str.lines().collect();
-
-                log_info!(app, "");
-                log_info!(
-                    app,
-                    "✅ Programas de inicio encontrados: {}",
-                    lines.len().saturating_sub(1)
-                );
-
-                for (i, line) in lines.iter().take(10).enumerate() {
-                    if i > 0 && !line.trim().is_empty() {
-                        log_info!(app, "  • {}", line.trim());
-                        log_debug!(app, "Programa de inicio: {}", line);
-                    }
-                }
-            } else {
-                log_warn!(
-                    app,
-                    "⚠️  No se pudo obtener la lista de programas de inicio"
-                );
-            }
-        }
-        Err(e) => {
-            log_error!(app, "❌ Error: {}", e);
+        match tasks::set_enabled(task, false) {
+            Ok(()) => log_debug!(
+                app,
+                "✅ {}",
+                app.t_args(I18nKey::PrivacyTaskDisabled, &[("task", task)])
+            ),
+            // Algunas tareas no existen en todas las versiones de Windows
+            Err(ControlError::NotFound) => {}
+            Err(e) => log_warn!(
+                app,
+                "⚠️  {}",
+                app.t_args(
+                    I18nKey::PrivacyTaskFailed,
+                    &[("task", task), ("error", &e.to_string())],
+                )
+            ),
         }
     }
 
     log_info!(app, "");
-    log_info!(
-        app,
-        "ℹ️  Para deshabilitar programas: Ejecuta 'msconfig' o 'Administrador de tareas'"
-    );
-    log_info!(
-        app,
-        "ℹ️  Recomendación: Deshabilita programas innecesarios para acelerar el inicio"
-    );
+    log_info!(app, "✅ {}", app.t(I18nKey::PrivacyCompleted));
+    log_reboot_recommended(app);
 
     app.operation_state = OperationState::Completed;
 }
@@ -375,51 +825,137 @@ pub fn execute_startup_optimizer(app: &mut crate::app::App) {
 /// Ejecuta deshabilitación de efectos visuales
 pub fn execute_visual_effects(app: &mut crate::app::App) {
     app.operation_state = OperationState::Running;
-    log_info!(app, "🎨 Optimizando efectos visuales...");
+    log_info!(app, "🎨 {}", app.t(I18nKey::VisualEffectsOptimizing));
 
     if !is_admin() {
-        log_error!(
-            app,
-            "⛔ ERROR: Esta operación requiere permisos de Administrador"
-        );
-        log_info!(
-            app,
-            "ℹ️  Por favor, ejecuta la aplicación como Administrador"
-        );
+        log_requires_admin(app);
         app.operation_state = OperationState::Completed;
         return;
     }
 
     // Configuraciones de efectos visuales
     let settings = [
+        (I18nKey::VisualEffectsMinAnimate, "MinAnimate", "0"),
         (
-            "Desactivar animaciones al minimizar/maximizar",
-            "MinAnimate",
+            I18nKey::VisualEffectsTransparency,
+            "EnableTransparency",
             "0",
         ),
-        ("Desactivar transparencias", "EnableTransparency", "0"),
-        ("Deshabilitar sombras bajo el mouse", "MouseShadow", "0"),
-        ("Ajustar para mejor rendimiento", "VisualFXSetting", "2"),
+        (I18nKey::VisualEffectsMouseShadow, "MouseShadow", "0"),
+        (
+            I18nKey::VisualEffectsBestPerformance,
+            "VisualFXSetting",
+            "2",
+        ),
     ];
 
     log_info!(app, "");
-    log_info!(app, "⚙️  Aplicando configuraciones de rendimiento...");
+    log_info!(app, "⚙️  {}", app.t(I18nKey::VisualEffectsApplying));
 
     for (desc, key, value) in settings {
-        log_info!(app, "  • {}", desc);
-        log_debug!(app, "Configurando {} = {}", key, value);
+        log_info!(app, "  • {}", app.t(desc));
+        log_debug!(
+            app,
+            "{}",
+            app.t_args(
+                I18nKey::VisualEffectsSetting,
+                &[("key", key), ("value", value)]
+            )
+        );
     }
 
     log_info!(app, "");
-    log_info!(app, "✅ Efectos visuales optimizados");
-    log_info!(
-        app,
-        "ℹ️  Los cambios se aplicarán después de cerrar sesión o reiniciar"
-    );
-    log_info!(
-        app,
-        "💡 Esto puede mejorar significativamente el rendimiento en equipos antiguos"
-    );
+    log_info!(app, "✅ {}", app.t(I18nKey::VisualEffectsCompleted));
+    log_info!(app, "ℹ️  {}", app.t(I18nKey::VisualEffectsLogoutRequired));
+    log_info!(app, "💡 {}", app.t(I18nKey::VisualEffectsHint));
 
     app.operation_state = OperationState::Completed;
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::platform::FakeRunner;
+
+    #[test]
+    fn test_network_with_fake_runner() {
+        let (runner, _guard) = FakeRunner::default()
+            .respond("ipconfig /flushdns", 0, "")
+            .respond("netsh winsock reset", 1, "Access denied")
+            .install();
+        let mut app = crate::app::App::default();
+        for state in &mut app.network_steps {
+            state.enabled = matches!(
+                state.step,
+                NetworkStep::DnsFlush | NetworkStep::WinsockReset
+            );
+        }
+        execute_network(&mut app);
+
+        assert!(runner.ran("ipconfig /flushdns"));
+        assert!(runner.ran("netsh winsock reset"));
+        assert!(!runner.ran("arp"));
+        let Some(OperationStats::Network(stats)) = &app.stats else {
+            panic!("sin estadísticas de red");
+        };
+        assert_eq!((stats.succeeded, stats.failed, stats.skipped), (1, 1, 3));
+        assert_eq!(app.operation_state, OperationState::Failed);
+        // El paso que pide reiniciar falló, así que no hace falta
+        assert!(!app.needs_reboot);
+    }
+
+    #[test]
+    fn test_parse_system_disk() {
+        assert_eq!(parse_system_disk("4|0\r\n"), SystemDiskKind::Ssd);
+        assert_eq!(parse_system_disk("3|7200\r\n"), SystemDiskKind::Hdd);
+        // Tipo sin especificar: decide la velocidad de giro
+        assert_eq!(parse_system_disk("0|0"), SystemDiskKind::Ssd);
+        assert_eq!(parse_system_disk("0|5400"), SystemDiskKind::Hdd);
+        assert_eq!(parse_system_disk("0|4294967295"), SystemDiskKind::Unknown);
+        // Un espacio de almacenamiento con un SSD y un disco duro
+        assert_eq!(parse_system_disk("4|0\r\n3|7200\r\n"), SystemDiskKind::Hdd);
+        assert_eq!(parse_system_disk(""), SystemDiskKind::Unknown);
+    }
+
+    #[test]
+    fn test_sysmain_is_only_disabled_on_ssd() {
+        assert_eq!(service_kept_reason("SysMain", SystemDiskKind::Ssd), None);
+        assert_eq!(
+            service_kept_reason("SysMain", SystemDiskKind::Hdd),
+            Some(I18nKey::OptimizeSysMainKeptHdd)
+        );
+        assert!(service_kept_reason("SysMain", SystemDiskKind::Unknown).is_some());
+        assert_eq!(service_kept_reason("DiagTrack", SystemDiskKind::Hdd), None);
+    }
+
+    #[test]
+    fn test_only_old_prefetch_traces_are_stale() {
+        let dir = std::env::temp_dir().join("win_opt_test_prefetch");
+        let _ = fs::remove_dir_all(&dir);
+        fs::create_dir_all(dir.join("ReadyBoot")).unwrap();
+        let now = SystemTime::now();
+        let day = Duration::from_secs(24 * 60 * 60);
+        for (name, age_days) in [
+            ("OLDAPP.EXE-1A2B3C4D.pf", 45),
+            ("CHROME.EXE-5A8B9C1D.pf", 2),
+            ("lower.exe-00000000.PF", 31),
+            ("Layout.ini", 90),
+            ("ReadyBoot/Trace1.fx", 90),
+        ] {
+            let file = fs::File::create(dir.join(name)).unwrap();
+            file.set_modified(now - day * age_days).unwrap();
+        }
+
+        let (stale, kept) = stale_prefetch_files(&dir, now).unwrap();
+        assert_eq!(
+            stale,
+            [
+                dir.join("OLDAPP.EXE-1A2B3C4D.pf"),
+                dir.join("lower.exe-00000000.PF"),
+            ]
+        );
+        assert_eq!(kept, 1);
+
+        fs::remove_dir_all(&dir).unwrap();
+    }
+}
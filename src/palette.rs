@@ -0,0 +1,153 @@
+//! Paleta de comandos
+//!
+//! Con Ctrl+P se abre, desde cualquier vista, una ventana para buscar las
+//! opciones del menú escribiendo parte de su nombre. La búsqueda es difusa:
+//! basta con que las letras aparezcan en orden ("lmp tmp" encuentra
+//! "Limpiar temporales"), sin distinguir mayúsculas ni tildes, y Enter
+//! ejecuta directamente la opción elegida.
+
+use crate::i18n::I18n;
+use crate::menu::MENU_ITEMS;
+
+/// Estado de la paleta de comandos abierta
+#[derive(Debug, Default)]
+pub struct CommandPalette {
+    query: String,
+    /// Resultado seleccionado (índice dentro de los resultados)
+    selected: usize,
+}
+
+impl CommandPalette {
+    /// Texto buscado
+    pub fn query(&self) -> &str {
+        &self.query
+    }
+
+    /// Añade un carácter a la búsqueda
+    pub fn push(&mut self, c: char) {
+        self.query.push(c);
+        self.selected = 0;
+    }
+
+    /// Borra el último carácter de la búsqueda
+    pub fn pop(&mut self) {
+        self.query.pop();
+        self.selected = 0;
+    }
+
+    /// Opciones del menú que coinciden, de la más a la menos parecida
+    ///
+    /// Se busca en el título traducido y, con menos peso, en la descripción.
+    /// Sin texto se muestran todas en el orden del menú.
+    pub fn matches(&self, i18n: &I18n) -> Vec<usize> {
+        let mut scored: Vec<(usize, i32)> = MENU_ITEMS
+            .iter()
+            .enumerate()
+            .filter_map(|(idx, item)| {
+                let title = fuzzy_score(&self.query, i18n.t(item.title));
+                let description =
+                    fuzzy_score(&self.query, i18n.t(item.description)).map(|score| score / 2);
+                title.max(description).map(|score| (idx, score))
+            })
+            .collect();
+        // Orden estable: a igual puntuación se respeta el orden del menú
+        scored.sort_by_key(|&(_, score)| std::cmp::Reverse(score));
+        scored.into_iter().map(|(idx, _)| idx).collect()
+    }
+
+    /// Posición del resultado seleccionado entre `total` resultados
+    pub fn selected(&self, total: usize) -> usize {
+        self.selected.min(total.saturating_sub(1))
+    }
+
+    /// Selecciona el siguiente resultado (vuelve al primero al final)
+    pub fn next(&mut self, total: usize) {
+        if total > 0 {
+            self.selected = (self.selected(total) + 1) % total;
+        }
+    }
+
+    /// Selecciona el resultado anterior (salta al último al principio)
+    pub fn prev(&mut self, total: usize) {
+        if total > 0 {
+            self.selected = (self.selected(total) + total - 1) % total;
+        }
+    }
+}
+
+/// Quita tildes y pasa a minúsculas para comparar
+fn fold(c: char) -> char {
+    match c.to_lowercase().next().unwrap_or(c) {
+        'á' | 'à' | 'â' | 'ä' | 'ã' => 'a',
+        'é' | 'è' | 'ê' | 'ë' => 'e',
+        'í' | 'ì' | 'î' | 'ï' => 'i',
+        'ó' | 'ò' | 'ô' | 'ö' | 'õ' => 'o',
+        'ú' | 'ù' | 'û' | 'ü' => 'u',
+        'ñ' => 'n',
+        'ç' => 'c',
+        c => c,
+    }
+}
+
+/// Puntuación de `text` para la búsqueda difusa `query`
+///
+/// Retorna `None` si las letras de la búsqueda (sin espacios) no aparecen en
+/// orden dentro del texto. Las letras seguidas y las que empiezan una
+/// palabra puntúan más, y cada letra saltada resta un punto.
+pub fn fuzzy_score(query: &str, text: &str) -> Option<i32> {
+    let text: Vec<char> = text.chars().map(fold).collect();
+    let mut score = 0;
+    let mut position = 0;
+    let mut previous: Option<usize> = None;
+
+    for wanted in query.chars().filter(|c| !c.is_whitespace()).map(fold) {
+        let found = position + text[position..].iter().position(|&c| c == wanted)?;
+        score += 1;
+        if previous.is_some_and(|previous| previous + 1 == found) {
+            score += 5;
+        }
+        if found == 0 || !text[found - 1].is_alphanumeric() {
+            score += 8;
+        }
+        score -= (found - position) as i32;
+        previous = Some(found);
+        position = found + 1;
+    }
+    Some(score)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::i18n::{I18nKey, Language};
+
+    #[test]
+    fn test_fuzzy_score() {
+        assert!(fuzzy_score("lmp tmp", "Limpiar temporales").is_some());
+        assert!(fuzzy_score("limpieza", "Optimizar").is_none());
+        // Sin distinguir mayúsculas ni tildes
+        assert!(fuzzy_score("INFORMACION", "Información").is_some());
+        // Las coincidencias seguidas y al inicio de palabra puntúan más
+        assert!(fuzzy_score("red", "Red") > fuzzy_score("red", "Reparar dispositivos"));
+        assert_eq!(fuzzy_score("", "Red"), Some(0));
+    }
+
+    #[test]
+    fn test_matches_rank_best_first() {
+        let i18n = I18n::new(Language::English);
+        let mut palette = CommandPalette::default();
+        assert_eq!(palette.matches(&i18n).len(), MENU_ITEMS.len());
+
+        for c in "repair".chars() {
+            palette.push(c);
+        }
+        let matches = palette.matches(&i18n);
+        let best = MENU_ITEMS[matches[0]].title;
+        assert_eq!(i18n.t(best), i18n.t(I18nKey::MenuRepair));
+
+        palette.next(matches.len());
+        assert_eq!(palette.selected(matches.len()), 1.min(matches.len() - 1));
+        palette.pop();
+        assert_eq!(palette.selected(matches.len()), 0);
+    }
+}
@@ -0,0 +1,401 @@
+//! Herramientas de Windows en otras plataformas y en las pruebas
+//!
+//! win_opt solo hace su trabajo en Windows, pero la interfaz, el estado y
+//! las pruebas se desarrollan también en Linux y macOS. Allí las
+//! herramientas de Windows (`reg`, `netsh`, `powercfg`...) no existen, y
+//! algunas con el mismo nombre hacen otra cosa (`shutdown`, `net`, `sc`).
+//! Por eso los comandos del sistema se crean con [`command`] o
+//! [`async_command`]: fuera de Windows las herramientas de Windows se
+//! sustituyen por un programa que termina con error sin hacer nada, y cada
+//! operación sigue su camino habitual de error.
+//!
+//! Las herramientas de Windows se ejecutan además a través de un
+//! [`CommandRunner`]. Las pruebas pueden sustituirlo con
+//! [`replace_runner`] por uno que registre los comandos y devuelva salidas
+//! preparadas, para probar las operaciones de principio a fin sin tocar el
+//! sistema.
+//!
+//! Las carpetas del sistema que limpian las operaciones se obtienen también
+//! aquí ([`temp_dir`], [`user_profile`], [`windows_dir`]...), para que el
+//! modo simulación pueda sustituirlas por las de su sandbox.
+
+use crate::simulate;
+use std::ffi::OsStr;
+use std::io;
+use std::path::{Path, PathBuf};
+use std::process::{Command, Output};
+use std::sync::{Arc, Mutex, MutexGuard, PoisonError, RwLock};
+use tokio::process::Command as AsyncCommand;
+
+/// Herramientas de Windows que lanza win_opt
+const WINDOWS_TOOLS: &[&str] = &[
+    "arp",
+    "bcdedit",
+    "cmd",
+    "dism",
+    "ipconfig",
+    "nbtstat",
+    "net",
+    "netsh",
+    "netstat",
+    "pnputil",
+    "powercfg",
+    "powershell",
+    "reg",
+    "sfc",
+    "shutdown",
+    "taskkill",
+    "usoclient",
+    "wmic",
+];
+
+/// Programa que sustituye a las herramientas de Windows: siempre falla
+const NOOP_PROGRAM: &str = "false";
+
+/// Ejecuta los comandos de las herramientas de Windows
+pub trait CommandRunner: Send + Sync {
+    /// Ejecuta `program` con `args` y espera a que termine
+    fn run(&self, program: &str, args: &[String]) -> io::Result<Output>;
+}
+
+/// Ejecutor que lanza los programas de verdad
+#[derive(Debug, Default, Clone, Copy)]
+pub struct SystemRunner;
+
+impl CommandRunner for SystemRunner {
+    fn run(&self, program: &str, args: &[String]) -> io::Result<Output> {
+        Command::new(resolve(program)).args(args).output()
+    }
+}
+
+/// Ejecutor que sustituye al del sistema, si hay uno instalado
+static RUNNER: RwLock<Option<Arc<dyn CommandRunner>>> = RwLock::new(None);
+
+/// Solo puede haber un ejecutor sustituto a la vez
+static RUNNER_LOCK: Mutex<()> = Mutex::new(());
+
+/// Mantiene instalado un ejecutor sustituto; al soltarse vuelve el del
+/// sistema
+pub struct RunnerGuard {
+    _lock: MutexGuard<'static, ()>,
+}
+
+impl Drop for RunnerGuard {
+    fn drop(&mut self) {
+        *RUNNER.write().unwrap_or_else(PoisonError::into_inner) = None;
+    }
+}
+
+/// Ejecuta las herramientas de Windows con `runner` mientras viva el guard
+///
+/// Si otro ejecutor sustituto está instalado, espera a que se suelte.
+pub fn replace_runner(runner: Arc<dyn CommandRunner>) -> RunnerGuard {
+    let lock = RUNNER_LOCK.lock().unwrap_or_else(PoisonError::into_inner);
+    *RUNNER.write().unwrap_or_else(PoisonError::into_inner) = Some(runner);
+    RunnerGuard { _lock: lock }
+}
+
+/// Ejecutor sustituto que debe ejecutar `program`, si lo hay
+///
+/// Solo se sustituyen las herramientas de Windows; el resto de programas
+/// se lanzan siempre.
+pub fn replaced_runner(program: &str) -> Option<Arc<dyn CommandRunner>> {
+    if !is_windows_tool(program) {
+        return None;
+    }
+    RUNNER
+        .read()
+        .unwrap_or_else(PoisonError::into_inner)
+        .clone()
+}
+
+/// Comando de un programa del sistema
+///
+/// Admite el subconjunto de `std::process::Command` que usan las
+/// operaciones: argumentos y ejecución esperando la salida.
+#[derive(Debug, Clone)]
+pub struct SystemCommand {
+    program: String,
+    args: Vec<String>,
+    /// Por cada argumento, si se pasa sin reescapar
+    raw: Vec<bool>,
+}
+
+impl SystemCommand {
+    /// Añade un argumento
+    pub fn arg(&mut self, arg: impl AsRef<OsStr>) -> &mut Self {
+        self.args.push(arg.as_ref().to_string_lossy().into_owned());
+        self.raw.push(false);
+        self
+    }
+
+    /// Añade un argumento que en Windows se pasa tal cual, sin reescapar
+    /// las comillas; en otras plataformas equivale a [`Self::arg`]
+    pub fn raw_arg(&mut self, arg: impl AsRef<OsStr>) -> &mut Self {
+        self.arg(arg);
+        if let Some(raw) = self.raw.last_mut() {
+            *raw = true;
+        }
+        self
+    }
+
+    /// Añade varios argumentos
+    pub fn args<I, S>(&mut self, args: I) -> &mut Self
+    where
+        I: IntoIterator<Item = S>,
+        S: AsRef<OsStr>,
+    {
+        for arg in args {
+            self.arg(arg);
+        }
+        self
+    }
+
+    /// Ejecuta el comando y espera a que termine
+    pub fn output(&self) -> io::Result<Output> {
+        if let Some(runner) = replaced_runner(&self.program) {
+            return runner.run(&self.program, &self.args);
+        }
+
+        let mut command = Command::new(resolve(&self.program));
+        for (arg, &raw) in self.args.iter().zip(&self.raw) {
+            push_arg(&mut command, arg, raw);
+        }
+        command.output()
+    }
+}
+
+/// Añade un argumento a un proceso, sin reescapar si `raw`
+#[cfg(windows)]
+fn push_arg(command: &mut Command, arg: &str, raw: bool) {
+    use std::os::windows::process::CommandExt;
+
+    if raw {
+        command.raw_arg(arg);
+    } else {
+        command.arg(arg);
+    }
+}
+
+#[cfg(not(windows))]
+fn push_arg(command: &mut Command, arg: &str, _raw: bool) {
+    command.arg(arg);
+}
+
+/// Crea el comando de un programa del sistema
+pub fn command(program: &str) -> SystemCommand {
+    SystemCommand {
+        program: program.to_string(),
+        args: Vec::new(),
+        raw: Vec::new(),
+    }
+}
+
+/// Versión asíncrona de [`command`] para los workers
+///
+/// Los workers leen la salida según se produce, así que deben consultar
+/// antes [`replaced_runner`] para respetar el ejecutor sustituto.
+pub fn async_command(program: &str) -> AsyncCommand {
+    AsyncCommand::new(resolve(program))
+}
+
+/// Indica si el programa es una herramienta de Windows
+fn is_windows_tool(program: &str) -> bool {
+    let name = program.to_ascii_lowercase();
+    let name = name.strip_suffix(".exe").unwrap_or(&name);
+    WINDOWS_TOOLS.contains(&name)
+}
+
+/// Programa que se ejecuta realmente en esta plataforma
+fn resolve(program: &str) -> &str {
+    if cfg!(windows) || !is_windows_tool(program) {
+        return program;
+    }
+    tracing::debug!("{} solo existe en Windows: no se ejecuta", program);
+    NOOP_PROGRAM
+}
+
+/// Carpeta temporal del usuario
+pub fn temp_dir() -> PathBuf {
+    simulate::sandbox().map_or_else(std::env::temp_dir, |root| root.join("Temp"))
+}
+
+/// Carpeta del perfil del usuario (`%USERPROFILE%`)
+pub fn user_profile() -> PathBuf {
+    match simulate::sandbox() {
+        Some(root) => root.join("Users").join("Demo"),
+        None => std::env::var("USERPROFILE")
+            .unwrap_or_else(|_| "C:\\Users\\Default".to_string())
+            .into(),
+    }
+}
+
+/// Carpeta de Windows (`%SystemRoot%`)
+pub fn windows_dir() -> PathBuf {
+    match simulate::sandbox() {
+        Some(root) => root.join("Windows"),
+        None => std::env::var("SystemRoot")
+            .unwrap_or_else(|_| "C:\\Windows".to_string())
+            .into(),
+    }
+}
+
+/// Carpeta de datos de programas de todos los usuarios (`%ProgramData%`)
+pub fn program_data_dir() -> PathBuf {
+    match simulate::sandbox() {
+        Some(root) => root.join("ProgramData"),
+        None => std::env::var("ProgramData")
+            .unwrap_or_else(|_| "C:\\ProgramData".to_string())
+            .into(),
+    }
+}
+
+/// Carpeta Descargas del usuario
+pub fn downloads_dir() -> Option<PathBuf> {
+    if simulate::is_enabled() {
+        return Some(user_profile().join("Downloads"));
+    }
+    directories::UserDirs::new().and_then(|dirs| dirs.download_dir().map(Path::to_path_buf))
+}
+
+/// Carpeta Documentos del usuario
+pub fn documents_dir() -> Option<PathBuf> {
+    if simulate::is_enabled() {
+        return Some(user_profile().join("Documents"));
+    }
+    directories::UserDirs::new().and_then(|dirs| dirs.document_dir().map(Path::to_path_buf))
+}
+
+/// Ejecutor falso para las pruebas
+///
+/// Registra cada comando como una línea (`programa arg1 arg2`) y responde
+/// con la primera salida preparada cuyo comando sea el principio de esa
+/// línea (sin distinguir mayúsculas). Los comandos sin respuesta preparada
+/// fallan con código 1, como fuera de Windows.
+#[cfg(test)]
+#[derive(Default)]
+pub(crate) struct FakeRunner {
+    responses: Vec<(String, i32, String)>,
+    calls: Mutex<Vec<String>>,
+}
+
+#[cfg(test)]
+impl FakeRunner {
+    /// Responde a `command` con el código de salida y la salida estándar
+    pub(crate) fn respond(mut self, command: &str, code: i32, stdout: &str) -> Self {
+        self.responses
+            .push((command.to_lowercase(), code, stdout.to_string()));
+        self
+    }
+
+    /// Instala el ejecutor mientras viva el guard
+    pub(crate) fn install(self) -> (Arc<FakeRunner>, RunnerGuard) {
+        let runner = Arc::new(self);
+        let guard = replace_runner(runner.clone());
+        (runner, guard)
+    }
+
+    /// Comandos ejecutados hasta ahora
+    ///
+    /// Las pruebas que se ejecutan a la vez también pasan por el ejecutor
+    /// instalado, así que conviene buscar los comandos esperados en lugar
+    /// de comparar la lista completa.
+    pub(crate) fn calls(&self) -> Vec<String> {
+        self.calls
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner)
+            .clone()
+    }
+
+    /// Indica si se ejecutó algún comando que empiece por `command`
+    pub(crate) fn ran(&self, command: &str) -> bool {
+        let command = command.to_lowercase();
+        self.calls()
+            .iter()
+            .any(|call| call.to_lowercase().starts_with(&command))
+    }
+}
+
+#[cfg(test)]
+impl CommandRunner for FakeRunner {
+    fn run(&self, program: &str, args: &[String]) -> io::Result<Output> {
+        let line = std::iter::once(program)
+            .chain(args.iter().map(String::as_str))
+            .collect::<Vec<_>>()
+            .join(" ");
+        let lower = line.to_lowercase();
+        self.calls
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner)
+            .push(line);
+
+        let (code, stdout) = self
+            .responses
+            .iter()
+            .find(|(command, _, _)| lower.starts_with(command.as_str()))
+            .map_or((1, ""), |(_, code, stdout)| (*code, stdout.as_str()));
+        Ok(Output {
+            status: exit_status(code),
+            stdout: stdout.as_bytes().to_vec(),
+            stderr: Vec::new(),
+        })
+    }
+}
+
+/// Estado de salida con el código indicado
+#[cfg(unix)]
+pub(crate) fn exit_status(code: i32) -> std::process::ExitStatus {
+    use std::os::unix::process::ExitStatusExt;
+    std::process::ExitStatus::from_raw(code << 8)
+}
+
+/// Estado de salida con el código indicado
+#[cfg(windows)]
+pub(crate) fn exit_status(code: i32) -> std::process::ExitStatus {
+    use std::os::windows::process::ExitStatusExt;
+    std::process::ExitStatus::from_raw(code as u32)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_is_windows_tool() {
+        assert!(is_windows_tool("reg"));
+        assert!(is_windows_tool("UsoClient"));
+        assert!(is_windows_tool("powershell.exe"));
+        assert!(!is_windows_tool("sleep"));
+        assert!(!is_windows_tool("sh"));
+    }
+
+    #[cfg(not(windows))]
+    #[test]
+    fn test_windows_tools_fail_without_running() {
+        // `shutdown` existe en Linux y macOS: no debe llegar a ejecutarse
+        let output = SystemRunner.run("shutdown", &["/r".to_string(), "/t".to_string()]);
+        assert!(output.is_ok_and(|output| !output.status.success()));
+        assert_eq!(resolve("sleep"), "sleep");
+    }
+
+    #[test]
+    fn test_fake_runner_records_and_answers() {
+        let (runner, _guard) = FakeRunner::default()
+            .respond("reg query HKLM\\Prueba", 0, "salida")
+            .install();
+
+        let output = command("reg")
+            .args(["query", "HKLM\\Prueba"])
+            .output()
+            .unwrap();
+        assert!(output.status.success());
+        assert_eq!(output.stdout, b"salida");
+
+        let output = command("netsh").arg("show").output().unwrap();
+        assert_eq!(output.status.code(), Some(1));
+        assert!(runner.ran("reg query HKLM\\Prueba"));
+        assert!(runner.ran("netsh show"));
+        // Los programas que no son de Windows no pasan por el ejecutor
+        assert!(replaced_runner("sleep").is_none());
+    }
+}
@@ -0,0 +1,283 @@
+//! Puertos y conexiones activas
+//!
+//! Lista las conexiones TCP/UDP con `netstat -ano` y las asocia al nombre del
+//! proceso propietario mediante `sysinfo`. La lista se puede filtrar por texto
+//! y permite terminar el proceso dueño de una conexión.
+
+use crate::platform;
+use crate::types::OperationState;
+use crate::{log_error, log_info, log_warn};
+use sysinfo::{Pid, System};
+
+/// PIDs del sistema que nunca se deben terminar (Idle y System)
+const PROTECTED_PIDS: [u32; 2] = [0, 4];
+
+/// Conexión o puerto a la escucha
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Connection {
+    /// `TCP` o `UDP`
+    pub protocol: String,
+    pub local: String,
+    pub remote: String,
+    /// Estado TCP tal como lo muestra `netstat` (vacío en UDP)
+    pub state: String,
+    pub pid: u32,
+    /// Nombre del proceso propietario
+    pub process: String,
+}
+
+impl Connection {
+    /// Indica si la conexión coincide con el filtro (sin distinguir mayúsculas)
+    fn matches(&self, filter: &str) -> bool {
+        let filter = filter.to_lowercase();
+        [
+            &self.protocol,
+            &self.local,
+            &self.remote,
+            &self.state,
+            &self.process,
+        ]
+        .iter()
+        .any(|field| field.to_lowercase().contains(&filter))
+            || self.pid.to_string() == filter
+    }
+}
+
+/// Conexiones que coinciden con el filtro (todas si está vacío)
+pub fn filtered<'a>(connections: &'a [Connection], filter: &str) -> Vec<&'a Connection> {
+    connections
+        .iter()
+        .filter(|c| filter.is_empty() || c.matches(filter))
+        .collect()
+}
+
+/// Lista las conexiones activas
+pub fn execute_ports(app: &mut crate::app::App) {
+    app.operation_state = OperationState::Running;
+    app.connections.clear();
+    app.ports_kill_confirm = None;
+    log_info!(app, "🔌 Leyendo conexiones activas...");
+
+    let output = match platform::command("netstat").args(["-ano"]).output() {
+        Ok(output) if output.status.success() => output,
+        Ok(_) => {
+            log_error!(app, "❌ No se pudo ejecutar netstat");
+            app.operation_state = OperationState::Failed;
+            return;
+        }
+        Err(e) => {
+            log_error!(app, "❌ Error: {}", e);
+            app.operation_state = OperationState::Failed;
+            return;
+        }
+    };
+
+    let mut sys = System::new();
+    sys.refresh_processes();
+    let mut connections = parse_netstat(&String::from_utf8_lossy(&output.stdout));
+    for connection in &mut connections {
+        if let Some(process) = sys.process(Pid::from_u32(connection.pid)) {
+            connection.process = process.name().to_string();
+        }
+    }
+    app.connections = connections;
+
+    let total = app.connections.len();
+    log_info!(app, "✅ Conexiones encontradas: {}", total);
+    log_info!(
+        app,
+        "💡 / filtra por proceso, puerto o estado; X termina el proceso seleccionado"
+    );
+    app.operation_state = OperationState::Completed;
+}
+
+/// Qué hacer al confirmar la terminación del proceso de una conexión
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum KillDecision {
+    /// El PID sigue siendo del proceso listado: se termina
+    Kill,
+    /// El PID se reutilizó para otro programa: solo se actualiza la lista
+    Reused,
+    /// El proceso ya no existe
+    Gone,
+}
+
+/// Decide qué hacer con la conexión `listed` según el nombre del proceso que
+/// tiene ahora su PID
+///
+/// El PID se pudo reutilizar desde que se listaron las conexiones.
+fn kill_decision(listed: &Connection, current_name: Option<&str>) -> KillDecision {
+    match current_name {
+        None => KillDecision::Gone,
+        Some(name) if name != listed.process => KillDecision::Reused,
+        Some(_) => KillDecision::Kill,
+    }
+}
+
+/// Solicita terminar el proceso de la conexión; la segunda pulsación lo confirma
+pub fn request_kill(app: &mut crate::app::App) {
+    let Some(connection) = filtered(&app.connections, &app.ports_filter)
+        .get(app.selected_item)
+        .map(|c| (*c).clone())
+    else {
+        return;
+    };
+
+    if PROTECTED_PIDS.contains(&connection.pid) {
+        log_warn!(
+            app,
+            "⚠️  El PID {} pertenece al sistema y no se puede terminar",
+            connection.pid
+        );
+        return;
+    }
+
+    if app.config.confirmations.enabled && app.ports_kill_confirm != Some(connection.pid) {
+        app.ports_kill_confirm = Some(connection.pid);
+        log_warn!(
+            app,
+            "⚠️  Se va a terminar {} (PID {}). Pulsa X de nuevo para confirmar",
+            connection.process,
+            connection.pid
+        );
+        return;
+    }
+
+    app.ports_kill_confirm = None;
+    let mut sys = System::new();
+    sys.refresh_processes();
+    let process = sys.process(Pid::from_u32(connection.pid));
+    match kill_decision(&connection, process.map(|process| process.name())) {
+        KillDecision::Reused => {
+            log_warn!(
+                app,
+                "⚠️  El PID {} ahora pertenece a otro proceso ({}); se actualiza la lista",
+                connection.pid,
+                process.map(|process| process.name()).unwrap_or_default()
+            );
+            execute_ports(app);
+        }
+        KillDecision::Kill if process.is_some_and(|process| process.kill()) => {
+            log_info!(
+                app,
+                "✅ Proceso terminado: {} (PID {})",
+                connection.process,
+                connection.pid
+            );
+            execute_ports(app);
+        }
+        KillDecision::Kill => log_error!(
+            app,
+            "❌ No se pudo terminar el proceso (¿requiere permisos de Administrador?)"
+        ),
+        KillDecision::Gone => {
+            log_warn!(app, "⚠️  El proceso ya no existe");
+            execute_ports(app);
+        }
+    }
+}
+
+/// Parsea la salida de `netstat -ano`
+///
+/// Las cabeceras están traducidas, así que solo se consideran las líneas
+/// que empiezan por `TCP` o `UDP`. Las de UDP no tienen columna de estado.
+fn parse_netstat(output: &str) -> Vec<Connection> {
+    let mut connections: Vec<Connection> = output
+        .lines()
+        .filter_map(|line| {
+            let fields: Vec<&str> = line.split_whitespace().collect();
+            let (protocol, local, remote, state, pid) = match fields[..] {
+                [protocol @ ("TCP" | "UDP"), local, remote, state, pid] => {
+                    (protocol, local, remote, state, pid)
+                }
+                [protocol @ "UDP", local, remote, pid] => (protocol, local, remote, "", pid),
+                _ => return None,
+            };
+            Some(Connection {
+                protocol: protocol.to_string(),
+                local: local.to_string(),
+                remote: remote.to_string(),
+                state: state.to_string(),
+                pid: pid.parse().ok()?,
+                process: String::new(),
+            })
+        })
+        .collect();
+
+    connections.sort_by(|a, b| {
+        a.protocol
+            .cmp(&b.protocol)
+            .then_with(|| port(&a.local).cmp(&port(&b.local)))
+    });
+    connections
+}
+
+/// Puerto de una dirección `ip:puerto` (también `[::]:puerto`)
+fn port(address: &str) -> u16 {
+    address
+        .rsplit(':')
+        .next()
+        .and_then(|port| port.parse().ok())
+        .unwrap_or_default()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const SAMPLE: &str = "\r\nActive Connections\r\n\r\n\
+        \x20 Proto  Local Address          Foreign Address        State           PID\r\n\
+        \x20 TCP    0.0.0.0:445            0.0.0.0:0              LISTENING       4\r\n\
+        \x20 TCP    0.0.0.0:135            0.0.0.0:0              LISTENING       1040\r\n\
+        \x20 TCP    192.168.1.20:51234     140.82.112.3:443       ESTABLISHED     8812\r\n\
+        \x20 TCP    [::]:135               [::]:0                 LISTENING       1040\r\n\
+        \x20 UDP    0.0.0.0:5353           *:*                                    2200\r\n";
+
+    #[test]
+    fn test_kill_skips_a_reused_pid() {
+        let listed = Connection {
+            protocol: "TCP".to_string(),
+            local: "127.0.0.1:5000".to_string(),
+            remote: "0.0.0.0:0".to_string(),
+            state: "LISTENING".to_string(),
+            pid: 8812,
+            process: "node.exe".to_string(),
+        };
+        assert_eq!(kill_decision(&listed, Some("node.exe")), KillDecision::Kill);
+        assert_eq!(
+            kill_decision(&listed, Some("chrome.exe")),
+            KillDecision::Reused
+        );
+        assert_eq!(kill_decision(&listed, None), KillDecision::Gone);
+    }
+
+    #[test]
+    fn test_parse_netstat() {
+        let connections = parse_netstat(SAMPLE);
+        assert_eq!(connections.len(), 5);
+        assert_eq!(connections[0].local, "0.0.0.0:135");
+        assert_eq!(connections[2].local, "0.0.0.0:445");
+        assert_eq!(connections[2].pid, 4);
+        let udp = connections.last().unwrap();
+        assert_eq!(udp.protocol, "UDP");
+        assert_eq!(udp.state, "");
+        assert_eq!(udp.pid, 2200);
+    }
+
+    #[test]
+    fn test_filtered() {
+        let mut connections = parse_netstat(SAMPLE);
+        connections[3].process = "chrome.exe".to_string();
+        assert_eq!(filtered(&connections, "").len(), 5);
+        assert_eq!(filtered(&connections, "CHROME").len(), 1);
+        assert_eq!(filtered(&connections, "listening").len(), 3);
+        assert_eq!(filtered(&connections, "1040").len(), 2);
+        assert_eq!(filtered(&connections, "udp").len(), 1);
+    }
+
+    #[test]
+    fn test_port() {
+        assert_eq!(port("[::1]:8080"), 8080);
+        assert_eq!(port("*:*"), 0);
+    }
+}
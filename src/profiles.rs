@@ -0,0 +1,414 @@
+//! Perfiles de optimización (modo juego y modo batería)
+//!
+//! Un perfil agrupa varios ajustes del sistema (plan de energía, efectos
+//! visuales, aplicaciones en segundo plano y Game Mode) que se aplican de una
+//! sola vez. Antes de aplicar un perfil se guarda una copia de los valores
+//! actuales para poder revertirlo más tarde.
+
+use crate::config::Config;
+use crate::i18n::I18nKey;
+use crate::platform;
+use crate::registry;
+use crate::types::OperationState;
+use crate::{log_debug, log_error, log_info, log_warn};
+use serde::{Deserialize, Serialize};
+use std::fs;
+use std::path::PathBuf;
+
+/// GUID del plan de energía "Alto rendimiento"
+const POWER_SCHEME_HIGH_PERFORMANCE: &str = "8c5e7fda-e8bf-4a96-9a85-a6e23a8c635c";
+
+/// GUID del plan de energía "Economizador"
+const POWER_SCHEME_POWER_SAVER: &str = "a1841308-3541-4fab-bc81-f71556f20b4a";
+
+const VISUAL_EFFECTS_KEY: &str =
+    "HKCU\\Software\\Microsoft\\Windows\\CurrentVersion\\Explorer\\VisualEffects";
+const BACKGROUND_APPS_KEY: &str =
+    "HKCU\\Software\\Microsoft\\Windows\\CurrentVersion\\BackgroundAccessApplications";
+const GAME_BAR_KEY: &str = "HKCU\\Software\\Microsoft\\GameBar";
+
+/// Valor DWORD del registro que modifica un perfil
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct RegistrySetting {
+    pub key: &'static str,
+    pub name: &'static str,
+    pub value: u32,
+}
+
+const GAMING_SETTINGS: &[RegistrySetting] = &[
+    // Ajustar para obtener el mejor rendimiento
+    RegistrySetting {
+        key: VISUAL_EFFECTS_KEY,
+        name: "VisualFXSetting",
+        value: 2,
+    },
+    // Impedir que las apps se ejecuten en segundo plano
+    RegistrySetting {
+        key: BACKGROUND_APPS_KEY,
+        name: "GlobalUserDisabled",
+        value: 1,
+    },
+    // Activar Game Mode
+    RegistrySetting {
+        key: GAME_BAR_KEY,
+        name: "AllowAutoGameMode",
+        value: 1,
+    },
+    RegistrySetting {
+        key: GAME_BAR_KEY,
+        name: "AutoGameModeEnabled",
+        value: 1,
+    },
+];
+
+const BATTERY_SETTINGS: &[RegistrySetting] = &[
+    RegistrySetting {
+        key: VISUAL_EFFECTS_KEY,
+        name: "VisualFXSetting",
+        value: 2,
+    },
+    RegistrySetting {
+        key: BACKGROUND_APPS_KEY,
+        name: "GlobalUserDisabled",
+        value: 1,
+    },
+    // Game Mode prioriza rendimiento sobre consumo
+    RegistrySetting {
+        key: GAME_BAR_KEY,
+        name: "AutoGameModeEnabled",
+        value: 0,
+    },
+];
+
+/// Perfiles de optimización disponibles
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum Profile {
+    /// Máximo rendimiento para juegos
+    Gaming,
+    /// Máxima autonomía en portátiles
+    Battery,
+}
+
+impl Profile {
+    /// Todos los perfiles en el orden en que se muestran
+    pub const ALL: [Profile; 2] = [Profile::Gaming, Profile::Battery];
+
+    /// Clave de traducción del nombre del perfil
+    pub fn name_key(self) -> I18nKey {
+        match self {
+            Profile::Gaming => I18nKey::ProfileGaming,
+            Profile::Battery => I18nKey::ProfileBattery,
+        }
+    }
+
+    /// Clave de traducción de la descripción del perfil
+    pub fn description_key(self) -> I18nKey {
+        match self {
+            Profile::Gaming => I18nKey::ProfileGamingDesc,
+            Profile::Battery => I18nKey::ProfileBatteryDesc,
+        }
+    }
+
+    /// Icono del perfil
+    pub fn icon(self) -> &'static str {
+        match self {
+            Profile::Gaming => "🎮",
+            Profile::Battery => "🔋",
+        }
+    }
+
+    /// GUID del plan de energía que activa el perfil
+    pub fn power_scheme(self) -> &'static str {
+        match self {
+            Profile::Gaming => POWER_SCHEME_HIGH_PERFORMANCE,
+            Profile::Battery => POWER_SCHEME_POWER_SAVER,
+        }
+    }
+
+    /// Valores de registro que aplica el perfil
+    pub fn registry_settings(self) -> &'static [RegistrySetting] {
+        match self {
+            Profile::Gaming => GAMING_SETTINGS,
+            Profile::Battery => BATTERY_SETTINGS,
+        }
+    }
+}
+
+/// Valor de registro guardado antes de aplicar un perfil
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct BackedUpValue {
+    pub key: String,
+    pub name: String,
+    /// Valor anterior (`None` si el valor no existía)
+    pub previous: Option<u32>,
+}
+
+/// Copia de seguridad del estado previo a aplicar un perfil
+///
+/// Se conserva mientras haya un perfil activo; al cambiar de perfil se
+/// mantienen los valores originales para que revertir devuelva siempre el
+/// sistema al estado anterior al primer perfil aplicado.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ProfileBackup {
+    /// Perfil actualmente aplicado
+    pub profile: Profile,
+    /// Plan de energía activo antes de aplicar el perfil
+    pub power_scheme: Option<String>,
+    /// Valores de registro anteriores
+    pub values: Vec<BackedUpValue>,
+}
+
+impl ProfileBackup {
+    /// Registra el valor actual de los ajustes del perfil que aún no estén guardados
+    fn capture(&mut self, profile: Profile) {
+        for setting in profile.registry_settings() {
+            let already_saved = self
+                .values
+                .iter()
+                .any(|v| v.key == setting.key && v.name == setting.name);
+            if !already_saved {
+                self.values.push(BackedUpValue {
+                    key: setting.key.to_string(),
+                    name: setting.name.to_string(),
+                    previous: registry::query_dword(setting.key, setting.name),
+                });
+            }
+        }
+    }
+}
+
+/// Ruta del archivo de copia de seguridad de perfiles
+fn backup_file() -> std::io::Result<PathBuf> {
+    Ok(Config::get_config_dir()?.join("profile_backup.toml"))
+}
+
+/// Carga la copia de seguridad del perfil activo, si existe
+pub fn load_backup() -> Option<ProfileBackup> {
+    let contents = fs::read_to_string(backup_file().ok()?).ok()?;
+    toml::from_str(&contents).ok()
+}
+
+/// Guarda la copia de seguridad del perfil activo
+fn save_backup(backup: &ProfileBackup) -> std::io::Result<()> {
+    let toml_string = toml::to_string_pretty(backup).map_err(|e| {
+        std::io::Error::new(
+            std::io::ErrorKind::InvalidData,
+            format!("TOML serialize error: {}", e),
+        )
+    })?;
+    fs::write(backup_file()?, toml_string)
+}
+
+/// Obtiene el GUID del plan de energía activo
+fn active_power_scheme() -> Option<String> {
+    let output = platform::command("powercfg")
+        .args(["/getactivescheme"])
+        .output()
+        .ok()?;
+
+    if !output.status.success() {
+        return None;
+    }
+
+    parse_scheme_guid(&String::from_utf8_lossy(&output.stdout))
+}
+
+/// Extrae el GUID de la salida de `powercfg /getactivescheme`
+///
+/// El texto está localizado, por lo que se busca directamente un token con
+/// forma de GUID en lugar de depender de la etiqueta.
+fn parse_scheme_guid(output: &str) -> Option<String> {
+    output
+        .split_whitespace()
+        .find(|token| {
+            token.len() == 36
+                && token.matches('-').count() == 4
+                && token.chars().all(|c| c == '-' || c.is_ascii_hexdigit())
+        })
+        .map(|token| token.to_lowercase())
+}
+
+/// Activa un plan de energía por su GUID
+fn set_power_scheme(app: &mut crate::app::App, guid: &str) {
+    match platform::command("powercfg")
+        .args(["/setactive", guid])
+        .output()
+    {
+        Ok(result) if result.status.success() => {
+            log_info!(app, "✅ Plan de energía activado: {}", guid);
+        }
+        Ok(_) => log_warn!(app, "⚠️  No se pudo cambiar el plan de energía"),
+        Err(e) => log_error!(app, "❌ Error configurando energía: {}", e),
+    }
+}
+
+/// Aplica un perfil de optimización
+///
+/// Guarda primero los valores actuales para poder revertir el perfil; si la
+/// copia de seguridad no puede escribirse, el perfil no se aplica.
+pub fn execute_apply_profile(app: &mut crate::app::App, profile: Profile) {
+    app.operation_state = OperationState::Running;
+    let profile_name = app.t(profile.name_key()).to_string();
+    log_info!(
+        app,
+        "{}  Aplicando perfil: {}",
+        profile.icon(),
+        profile_name
+    );
+
+    let mut backup = load_backup().unwrap_or_else(|| ProfileBackup {
+        profile,
+        power_scheme: active_power_scheme(),
+        values: Vec::new(),
+    });
+    backup.profile = profile;
+    backup.capture(profile);
+
+    if let Err(e) = save_backup(&backup) {
+        log_error!(
+            app,
+            "❌ No se pudo guardar la copia de seguridad del perfil: {}",
+            e
+        );
+        app.operation_state = OperationState::Failed;
+        return;
+    }
+    log_debug!(app, "Copia de seguridad del perfil guardada");
+
+    log_info!(app, "");
+    log_info!(app, "⚡ Configurando plan de energía...");
+    set_power_scheme(app, profile.power_scheme());
+
+    log_info!(app, "");
+    log_info!(app, "⚙️  Aplicando ajustes del registro...");
+    for setting in profile.registry_settings() {
+        match registry::set_dword(setting.key, setting.name, setting.value) {
+            Ok(()) => log_info!(app, "✅ {} = {}", setting.name, setting.value),
+            Err(e) => log_warn!(app, "⚠️  No se pudo aplicar {}: {}", setting.name, e),
+        }
+    }
+
+    app.active_profile = Some(profile);
+
+    log_info!(app, "");
+    log_info!(app, "✅ Perfil aplicado");
+    log_info!(
+        app,
+        "ℹ️  Algunos cambios se aplicarán después de cerrar sesión"
+    );
+
+    app.operation_state = OperationState::Completed;
+}
+
+/// Revierte el perfil activo restaurando los valores guardados
+pub fn execute_revert_profile(app: &mut crate::app::App) {
+    let Some(backup) = load_backup() else {
+        log_warn!(app, "⚠️  No hay ningún perfil activo que revertir");
+        return;
+    };
+
+    app.operation_state = OperationState::Running;
+    let profile_name = app.t(backup.profile.name_key()).to_string();
+    log_info!(app, "↩️  Revirtiendo perfil: {}", profile_name);
+
+    if let Some(ref guid) = backup.power_scheme {
+        log_info!(app, "");
+        log_info!(app, "⚡ Restaurando plan de energía...");
+        set_power_scheme(app, guid);
+    }
+
+    log_info!(app, "");
+    log_info!(app, "⚙️  Restaurando ajustes del registro...");
+    let mut failed = 0;
+    for value in &backup.values {
+        let result = match value.previous {
+            Some(previous) => registry::set_dword(&value.key, &value.name, previous),
+            None => registry::delete_value(&value.key, &value.name),
+        };
+        match result {
+            Ok(()) => log_debug!(app, "Restaurado: {}", value.name),
+            Err(e) => {
+                failed += 1;
+                log_warn!(app, "⚠️  No se pudo restaurar {}: {}", value.name, e);
+            }
+        }
+    }
+
+    // Conservar la copia si algo falló para poder reintentar
+    if failed == 0 {
+        if let Ok(path) = backup_file() {
+            let _ = fs::remove_file(path);
+        }
+        app.active_profile = None;
+        log_info!(app, "");
+        log_info!(app, "✅ Perfil revertido");
+        app.operation_state = OperationState::Completed;
+    } else {
+        log_error!(app, "❌ {} ajustes no pudieron restaurarse", failed);
+        app.operation_state = OperationState::Failed;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_scheme_guid() {
+        let output = "Power Scheme GUID: 381B4222-F694-41F0-9685-FF5BB260DF2E  (Balanced)";
+        assert_eq!(
+            parse_scheme_guid(output),
+            Some("381b4222-f694-41f0-9685-ff5bb260df2e".to_string())
+        );
+    }
+
+    #[test]
+    fn test_parse_scheme_guid_localized() {
+        let output =
+            "GUID de plan de energía: 8c5e7fda-e8bf-4a96-9a85-a6e23a8c635c  (Alto rendimiento)";
+        assert_eq!(
+            parse_scheme_guid(output),
+            Some(POWER_SCHEME_HIGH_PERFORMANCE.to_string())
+        );
+    }
+
+    #[test]
+    fn test_parse_scheme_guid_missing() {
+        assert_eq!(parse_scheme_guid("Error: acceso denegado"), None);
+    }
+
+    #[test]
+    fn test_profiles_have_distinct_power_schemes() {
+        assert_ne!(
+            Profile::Gaming.power_scheme(),
+            Profile::Battery.power_scheme()
+        );
+    }
+
+    #[test]
+    fn test_backup_roundtrip() {
+        let backup = ProfileBackup {
+            profile: Profile::Gaming,
+            power_scheme: Some(POWER_SCHEME_POWER_SAVER.to_string()),
+            values: vec![
+                BackedUpValue {
+                    key: GAME_BAR_KEY.to_string(),
+                    name: "AutoGameModeEnabled".to_string(),
+                    previous: Some(0),
+                },
+                BackedUpValue {
+                    key: BACKGROUND_APPS_KEY.to_string(),
+                    name: "GlobalUserDisabled".to_string(),
+                    previous: None,
+                },
+            ],
+        };
+
+        let toml_string = toml::to_string_pretty(&backup).unwrap();
+        let restored: ProfileBackup = toml::from_str(&toml_string).unwrap();
+
+        assert_eq!(restored.profile, Profile::Gaming);
+        assert_eq!(restored.power_scheme, backup.power_scheme);
+        assert_eq!(restored.values, backup.values);
+    }
+}
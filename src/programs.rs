@@ -0,0 +1,339 @@
+//! Inventario de programas instalados
+//!
+//! Enumera las claves `Uninstall` del registro (máquina de 64 y 32 bits y
+//! usuario actual), con su tamaño estimado y fecha de instalación, y permite
+//! lanzar el desinstalador del programa seleccionado.
+
+use crate::i18n::I18nKey;
+use crate::platform;
+use crate::registry::{self, RegKey};
+use crate::types::OperationState;
+use crate::{log_error, log_info, log_warn};
+use std::cmp::Reverse;
+use std::io;
+
+/// Claves del registro donde Windows registra los programas instalados
+const UNINSTALL_KEYS: &[&str] = &[
+    "HKLM\\SOFTWARE\\Microsoft\\Windows\\CurrentVersion\\Uninstall",
+    "HKLM\\SOFTWARE\\WOW6432Node\\Microsoft\\Windows\\CurrentVersion\\Uninstall",
+    "HKCU\\SOFTWARE\\Microsoft\\Windows\\CurrentVersion\\Uninstall",
+];
+
+/// Programa instalado
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct InstalledProgram {
+    /// Nombre visible del programa
+    pub name: String,
+    /// Editor del programa
+    pub publisher: String,
+    /// Versión instalada
+    pub version: String,
+    /// Fecha de instalación en formato `yyyy-MM-dd`
+    pub install_date: Option<String>,
+    /// Tamaño estimado en KB
+    pub size_kb: Option<u64>,
+    /// Línea de comandos del desinstalador
+    pub uninstall_string: String,
+}
+
+/// Criterio de ordenación de la lista de programas
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum ProgramSort {
+    #[default]
+    Name,
+    Size,
+    InstallDate,
+}
+
+impl ProgramSort {
+    /// Siguiente criterio (ciclo Nombre → Tamaño → Fecha)
+    pub fn next(self) -> Self {
+        match self {
+            ProgramSort::Name => ProgramSort::Size,
+            ProgramSort::Size => ProgramSort::InstallDate,
+            ProgramSort::InstallDate => ProgramSort::Name,
+        }
+    }
+
+    /// Clave de traducción del criterio
+    pub fn label_key(self) -> I18nKey {
+        match self {
+            ProgramSort::Name => I18nKey::ProgramsSortName,
+            ProgramSort::Size => I18nKey::ProgramsSortSize,
+            ProgramSort::InstallDate => I18nKey::ProgramsSortDate,
+        }
+    }
+}
+
+/// Ordena los programas según el criterio indicado
+///
+/// El tamaño y la fecha se ordenan de mayor a menor (más grandes y más
+/// recientes primero); los programas sin dato quedan al final.
+pub fn sort_programs(programs: &mut [InstalledProgram], sort: ProgramSort) {
+    match sort {
+        ProgramSort::Name => programs.sort_by_key(|p| p.name.to_lowercase()),
+        ProgramSort::Size => programs.sort_by_key(|p| Reverse(p.size_kb)),
+        ProgramSort::InstallDate => programs.sort_by(|a, b| b.install_date.cmp(&a.install_date)),
+    }
+}
+
+/// Carga el inventario de programas instalados
+pub fn execute_programs(app: &mut crate::app::App) {
+    app.operation_state = OperationState::Running;
+    log_info!(app, "📦 Leyendo programas instalados...");
+
+    let mut programs: Vec<InstalledProgram> = Vec::new();
+    for key in UNINSTALL_KEYS {
+        for entry in registry::query_key_recursive(key) {
+            if let Some(program) = program_from_key(&entry)
+                && !programs
+                    .iter()
+                    .any(|p| p.name == program.name && p.version == program.version)
+            {
+                programs.push(program);
+            }
+        }
+    }
+
+    sort_programs(&mut programs, app.program_sort);
+
+    let total_kb: u64 = programs.iter().filter_map(|p| p.size_kb).sum();
+    log_info!(
+        app,
+        "✅ Programas encontrados: {} ({} estimados)",
+        programs.len(),
+        app.i18n.format_size(total_kb * 1024)
+    );
+    log_info!(
+        app,
+        "ℹ️  Pulsa U para lanzar el desinstalador del programa seleccionado"
+    );
+
+    app.programs = programs;
+    app.operation_state = OperationState::Completed;
+}
+
+/// Lanza el desinstalador del programa seleccionado
+pub fn execute_uninstall(app: &mut crate::app::App, index: usize) {
+    let Some(program) = app.programs.get(index).cloned() else {
+        return;
+    };
+
+    log_info!(app, "");
+    log_info!(app, "🗑️  Lanzando desinstalador de {}...", program.name);
+
+    match launch_uninstaller(&program.uninstall_string) {
+        Ok(()) => {
+            log_info!(
+                app,
+                "✅ Desinstalador iniciado: {}",
+                program.uninstall_string
+            );
+            log_info!(
+                app,
+                "ℹ️  Sigue las instrucciones del desinstalador y vuelve a abrir esta vista para actualizar la lista"
+            );
+        }
+        Err(e) => log_error!(app, "❌ No se pudo lanzar el desinstalador: {}", e),
+    }
+}
+
+/// Cambia el criterio de ordenación y reordena la lista
+pub fn execute_cycle_sort(app: &mut crate::app::App) {
+    app.program_sort = app.program_sort.next();
+    sort_programs(&mut app.programs, app.program_sort);
+    app.selected_item = 0;
+    if app.programs.is_empty() {
+        log_warn!(app, "⚠️  No hay programas que ordenar");
+    }
+}
+
+/// Construye un programa a partir de una subclave `Uninstall`
+///
+/// Se omiten los componentes del sistema, las actualizaciones (que tienen
+/// `ParentKeyName`) y las entradas sin nombre o sin desinstalador.
+fn program_from_key(key: &RegKey) -> Option<InstalledProgram> {
+    let name = key.string("DisplayName")?;
+    let uninstall_string = key.string("UninstallString")?;
+
+    let is_system_component = key
+        .value("SystemComponent")
+        .and_then(|v| v.as_dword())
+        .is_some_and(|v| v == 1);
+    if is_system_component || key.value("ParentKeyName").is_some() {
+        return None;
+    }
+
+    Some(InstalledProgram {
+        name: name.to_string(),
+        publisher: key.string("Publisher").unwrap_or_default().to_string(),
+        version: key.string("DisplayVersion").unwrap_or_default().to_string(),
+        install_date: key.string("InstallDate").and_then(parse_install_date),
+        size_kb: key
+            .value("EstimatedSize")
+            .and_then(|v| v.as_dword())
+            .map(u64::from),
+        uninstall_string: uninstall_string.to_string(),
+    })
+}
+
+/// Convierte `InstallDate` (`yyyyMMdd`) a `yyyy-MM-dd`
+fn parse_install_date(raw: &str) -> Option<String> {
+    if raw.len() != 8 || !raw.bytes().all(|b| b.is_ascii_digit()) {
+        return None;
+    }
+    Some(format!("{}-{}-{}", &raw[..4], &raw[4..6], &raw[6..]))
+}
+
+/// Ejecuta la línea de comandos del desinstalador sin esperar a que termine
+///
+/// Con un ejecutor sustituto (simulación o pruebas) se le pasa el comando en
+/// lugar de lanzarlo.
+fn launch_uninstaller(command_line: &str) -> io::Result<()> {
+    if let Some(runner) = platform::replaced_runner("cmd") {
+        return runner
+            .run("cmd", &["/C".to_string(), command_line.to_string()])
+            .map(|_| ());
+    }
+    spawn_uninstaller(command_line)
+}
+
+#[cfg(windows)]
+fn spawn_uninstaller(command_line: &str) -> io::Result<()> {
+    use std::os::windows::process::CommandExt;
+    use std::process::{Command, Stdio};
+
+    // La línea se pasa tal cual a cmd: ya viene entrecomillada desde el registro
+    Command::new("cmd")
+        .arg("/C")
+        .raw_arg(command_line)
+        .stdin(Stdio::null())
+        .stdout(Stdio::null())
+        .stderr(Stdio::null())
+        .spawn()
+        .map(|_| ())
+}
+
+#[cfg(not(windows))]
+fn spawn_uninstaller(_command_line: &str) -> io::Result<()> {
+    Err(io::Error::new(
+        io::ErrorKind::Unsupported,
+        "solo disponible en Windows",
+    ))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::registry::RegValue;
+
+    fn reg_key(values: &[(&str, &str, &str)]) -> RegKey {
+        RegKey {
+            path: "HKEY_LOCAL_MACHINE\\Uninstall\\Test".to_string(),
+            values: values
+                .iter()
+                .map(|(name, kind, data)| {
+                    (
+                        name.to_string(),
+                        RegValue {
+                            kind: kind.to_string(),
+                            data: data.to_string(),
+                        },
+                    )
+                })
+                .collect(),
+        }
+    }
+
+    fn program(name: &str, size_kb: Option<u64>, date: Option<&str>) -> InstalledProgram {
+        InstalledProgram {
+            name: name.to_string(),
+            publisher: String::new(),
+            version: String::new(),
+            install_date: date.map(str::to_string),
+            size_kb,
+            uninstall_string: String::new(),
+        }
+    }
+
+    #[test]
+    fn test_program_from_key() {
+        let key = reg_key(&[
+            ("DisplayName", "REG_SZ", "7-Zip 23.01 (x64)"),
+            ("DisplayVersion", "REG_SZ", "23.01"),
+            ("Publisher", "REG_SZ", "Igor Pavlov"),
+            ("InstallDate", "REG_SZ", "20240115"),
+            ("EstimatedSize", "REG_DWORD", "0x1620"),
+            (
+                "UninstallString",
+                "REG_SZ",
+                "\"C:\\Program Files\\7-Zip\\Uninstall.exe\"",
+            ),
+        ]);
+        let program = program_from_key(&key).unwrap();
+        assert_eq!(program.name, "7-Zip 23.01 (x64)");
+        assert_eq!(program.install_date.as_deref(), Some("2024-01-15"));
+        assert_eq!(program.size_kb, Some(5664));
+    }
+
+    #[test]
+    fn test_program_from_key_skips_system_components_and_updates() {
+        let component = reg_key(&[
+            ("DisplayName", "REG_SZ", "Runtime"),
+            ("UninstallString", "REG_SZ", "x.exe"),
+            ("SystemComponent", "REG_DWORD", "0x1"),
+        ]);
+        let update = reg_key(&[
+            ("DisplayName", "REG_SZ", "Update for Office"),
+            ("UninstallString", "REG_SZ", "x.exe"),
+            ("ParentKeyName", "REG_SZ", "Office16"),
+        ]);
+        let no_uninstaller = reg_key(&[("DisplayName", "REG_SZ", "Driver")]);
+        assert!(program_from_key(&component).is_none());
+        assert!(program_from_key(&update).is_none());
+        assert!(program_from_key(&no_uninstaller).is_none());
+    }
+
+    #[test]
+    fn test_parse_install_date_rejects_invalid() {
+        assert_eq!(parse_install_date("2024-01-15"), None);
+        assert_eq!(parse_install_date(""), None);
+    }
+
+    #[test]
+    fn test_sort_programs() {
+        let mut programs = vec![
+            program("zoom", Some(100), Some("2023-05-01")),
+            program("Audacity", None, Some("2024-02-01")),
+            program("chrome", Some(500), None),
+        ];
+
+        sort_programs(&mut programs, ProgramSort::Name);
+        assert_eq!(programs[0].name, "Audacity");
+        assert_eq!(programs[2].name, "zoom");
+
+        sort_programs(&mut programs, ProgramSort::Size);
+        assert_eq!(programs[0].name, "chrome");
+        assert_eq!(programs[2].name, "Audacity");
+
+        sort_programs(&mut programs, ProgramSort::InstallDate);
+        assert_eq!(programs[0].name, "Audacity");
+        assert_eq!(programs[2].name, "chrome");
+    }
+
+    #[test]
+    fn test_program_sort_cycles() {
+        let sort = ProgramSort::default();
+        assert_eq!(sort.next().next().next(), sort);
+    }
+
+    #[test]
+    fn test_uninstaller_goes_through_replaced_runner() {
+        use crate::platform::FakeRunner;
+
+        let (runner, _guard) = FakeRunner::default().install();
+        let line = "\"C:\\Program Files\\7-Zip\\Uninstall.exe\"";
+        assert!(launch_uninstaller(line).is_ok());
+        assert!(runner.ran(&format!("cmd /C {}", line)));
+    }
+}
@@ -0,0 +1,193 @@
+//! Configuración de proxy
+//!
+//! Muestra el proxy de WinINET (el de "Configuración de proxy" de Windows,
+//! usado por navegadores y la mayoría de aplicaciones) y el de WinHTTP (usado
+//! por servicios como Windows Update), y permite restablecer ambos a conexión
+//! directa. Malware y VPN desinstaladas suelen dejar un proxy que rompe la
+//! conectividad.
+
+use crate::config::Config;
+use crate::platform;
+use crate::registry;
+use crate::types::OperationState;
+use crate::utils::{is_admin, today_iso};
+use crate::{log_error, log_info, log_warn};
+
+/// Clave del registro con la configuración de WinINET del usuario
+const INTERNET_SETTINGS_KEY: &str =
+    "HKCU\\Software\\Microsoft\\Windows\\CurrentVersion\\Internet Settings";
+
+/// Configuración de proxy actual
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct ProxySettings {
+    /// `ProxyEnable` de WinINET
+    pub wininet_enabled: bool,
+    /// `ProxyServer` de WinINET
+    pub wininet_server: Option<String>,
+    /// `ProxyOverride` de WinINET (direcciones que no usan proxy)
+    pub wininet_bypass: Option<String>,
+    /// Script de configuración automática (PAC)
+    pub auto_config_url: Option<String>,
+    /// Proxy de WinHTTP (`None` = acceso directo)
+    pub winhttp_server: Option<String>,
+    /// Lista de exclusión de WinHTTP
+    pub winhttp_bypass: Option<String>,
+}
+
+impl ProxySettings {
+    /// Indica si todo el tráfico sale sin proxy
+    pub fn is_direct(&self) -> bool {
+        !self.wininet_enabled && self.auto_config_url.is_none() && self.winhttp_server.is_none()
+    }
+}
+
+/// Lee la configuración de proxy de WinINET y WinHTTP
+pub fn execute_proxy_status(app: &mut crate::app::App) {
+    app.operation_state = OperationState::Running;
+    log_info!(app, "🧭 Consultando la configuración de proxy...");
+
+    let text = |name: &str| {
+        registry::query_value(INTERNET_SETTINGS_KEY, name)
+            .map(|value| value.data)
+            .filter(|data| !data.is_empty())
+    };
+    let mut settings = ProxySettings {
+        wininet_enabled: registry::query_dword(INTERNET_SETTINGS_KEY, "ProxyEnable") == Some(1),
+        wininet_server: text("ProxyServer"),
+        wininet_bypass: text("ProxyOverride"),
+        auto_config_url: text("AutoConfigURL"),
+        ..Default::default()
+    };
+
+    match platform::command("netsh")
+        .args(["winhttp", "show", "proxy"])
+        .output()
+    {
+        Ok(output) if output.status.success() => {
+            let (server, bypass) = parse_winhttp_output(&String::from_utf8_lossy(&output.stdout));
+            settings.winhttp_server = server;
+            settings.winhttp_bypass = bypass;
+        }
+        Ok(_) => log_warn!(app, "⚠️  No se pudo leer el proxy de WinHTTP"),
+        Err(e) => log_error!(app, "❌ Error: {}", e),
+    }
+
+    if settings.is_direct() {
+        log_info!(app, "✅ Sin proxy: las conexiones son directas");
+    } else {
+        log_warn!(
+            app,
+            "⚠️  Hay un proxy configurado; si no lo reconoces, pulsa X para restablecerlo"
+        );
+    }
+
+    app.proxy_settings = Some(settings);
+    app.operation_state = OperationState::Completed;
+}
+
+/// Restablece el proxy de WinINET y WinHTTP a conexión directa
+pub fn execute_proxy_reset(app: &mut crate::app::App) {
+    log_info!(app, "");
+
+    // Copia de la clave antes de tocarla, restaurable con `reg import`
+    match Config::get_config_dir() {
+        Ok(dir) => {
+            let backup = dir.join(format!("proxy_{}.reg", today_iso()));
+            match registry::export_key(INTERNET_SETTINGS_KEY, &backup) {
+                Ok(()) => log_info!(app, "💾 Copia de seguridad: {}", backup.display()),
+                Err(e) => {
+                    log_error!(app, "❌ No se pudo crear la copia de seguridad: {}", e);
+                    return;
+                }
+            }
+        }
+        Err(e) => {
+            log_error!(app, "❌ No se pudo crear la copia de seguridad: {}", e);
+            return;
+        }
+    }
+
+    log_info!(app, "🔧 Restableciendo el proxy de WinINET...");
+    match registry::set_dword(INTERNET_SETTINGS_KEY, "ProxyEnable", 0) {
+        Ok(()) => {
+            // Los valores pueden no existir: no es un error
+            for name in ["ProxyServer", "AutoConfigURL"] {
+                let _ = registry::delete_value(INTERNET_SETTINGS_KEY, name);
+            }
+            log_info!(app, "✅ Proxy de WinINET desactivado");
+        }
+        Err(e) => log_error!(app, "❌ Error: {}", e),
+    }
+
+    if is_admin() {
+        log_info!(app, "Ejecutando: netsh winhttp reset proxy");
+        match platform::command("netsh")
+            .args(["winhttp", "reset", "proxy"])
+            .output()
+        {
+            Ok(output) if output.status.success() => {
+                log_info!(app, "✅ Proxy de WinHTTP restablecido a acceso directo")
+            }
+            Ok(_) => log_error!(app, "❌ No se pudo restablecer el proxy de WinHTTP"),
+            Err(e) => log_error!(app, "❌ Error: {}", e),
+        }
+    } else {
+        log_warn!(
+            app,
+            "⚠️  Restablecer el proxy de WinHTTP requiere permisos de Administrador"
+        );
+    }
+
+    execute_proxy_status(app);
+}
+
+/// Parsea la salida de `netsh winhttp show proxy`
+///
+/// Con acceso directo solo hay una línea de texto sin dos puntos; con proxy
+/// aparecen dos líneas sangradas `etiqueta : valor` (servidor y exclusiones).
+/// Las etiquetas están traducidas, así que se usa su posición.
+fn parse_winhttp_output(output: &str) -> (Option<String>, Option<String>) {
+    let mut values = output
+        .lines()
+        .filter(|line| line.starts_with(char::is_whitespace))
+        .filter_map(|line| line.split_once(':'))
+        .map(|(_, value)| value.trim().to_string());
+
+    let server = values.next().filter(|value| !value.is_empty());
+    let bypass = values.next().filter(|value| !value.is_empty());
+    (server, bypass)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_winhttp_direct() {
+        let output = "\r\nCurrent WinHTTP proxy settings:\r\n\r\n\
+                      \x20   Direct access (no proxy server).\r\n\r\n";
+        assert_eq!(parse_winhttp_output(output), (None, None));
+    }
+
+    #[test]
+    fn test_parse_winhttp_proxy() {
+        let output = "\r\nCurrent WinHTTP proxy settings:\r\n\r\n\
+                      \x20   Proxy Server(s) :  http=10.0.0.1:8080\r\n\
+                      \x20   Bypass List     :  <local>\r\n";
+        assert_eq!(
+            parse_winhttp_output(output),
+            (
+                Some("http=10.0.0.1:8080".to_string()),
+                Some("<local>".to_string())
+            )
+        );
+    }
+
+    #[test]
+    fn test_is_direct() {
+        let mut settings = ProxySettings::default();
+        assert!(settings.is_direct());
+        settings.auto_config_url = Some("http://wpad/wpad.dat".to_string());
+        assert!(!settings.is_direct());
+    }
+}
@@ -0,0 +1,401 @@
+//! Modo cuarentena (borrado reversible)
+//!
+//! Con `[quarantine] enabled = true`, las limpiezas mueven los archivos a
+//! `%APPDATA%\win_opt\quarantine\<yyyy-MM-dd>\` en lugar de eliminarlos. Cada
+//! carpeta diaria contiene un `manifest.tsv` con la ruta original de cada
+//! elemento para poder restaurarlo, y las carpetas más antiguas que
+//! `retention_days` se purgan automáticamente al iniciar la aplicación.
+
+use crate::config::Config;
+use crate::safety;
+use crate::types::OperationState;
+use crate::utils::{days_since_epoch, parse_iso_date, path_size, today_iso};
+use crate::{log_error, log_info, log_warn};
+use serde::{Deserialize, Serialize};
+use std::fs::{self, OpenOptions};
+use std::io::{self, Write};
+use std::path::{Path, PathBuf};
+use std::time::{SystemTime, UNIX_EPOCH};
+
+/// Nombre del manifiesto de cada carpeta diaria
+const MANIFEST_FILE: &str = "manifest.tsv";
+
+/// Configuración del modo cuarentena
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct QuarantineConfig {
+    /// Mover a cuarentena en lugar de eliminar
+    pub enabled: bool,
+
+    /// Días que se conservan los elementos antes de purgarlos
+    pub retention_days: u64,
+}
+
+impl Default for QuarantineConfig {
+    fn default() -> Self {
+        Self {
+            enabled: false,
+            retention_days: 7,
+        }
+    }
+}
+
+/// Elemento guardado en cuarentena
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct QuarantinedItem {
+    /// Día en que se puso en cuarentena (`yyyy-MM-dd`)
+    pub day: String,
+    /// Ruta del elemento dentro de la cuarentena
+    pub stored: PathBuf,
+    /// Ruta original del elemento
+    pub original: PathBuf,
+    /// Tamaño en bytes
+    pub size: u64,
+}
+
+/// Directorio raíz de la cuarentena
+fn quarantine_root() -> io::Result<PathBuf> {
+    Ok(Config::get_config_dir()?.join("quarantine"))
+}
+
+/// Mueve un archivo o carpeta a la carpeta de cuarentena del día
+pub fn quarantine_path(path: &Path) -> io::Result<()> {
+    move_to_quarantine(&quarantine_root()?, &today_iso(), path)
+}
+
+/// Purga las carpetas de cuarentena más antiguas que `retention_days`
+///
+/// Retorna el número de carpetas diarias eliminadas.
+pub fn purge_expired(config: &QuarantineConfig) -> usize {
+    match quarantine_root() {
+        Ok(root) => purge_expired_in(&root, config.retention_days, days_since_epoch()),
+        Err(_) => 0,
+    }
+}
+
+/// Carga la lista de elementos en cuarentena
+pub fn execute_quarantine_list(app: &mut crate::app::App) {
+    app.operation_state = OperationState::Running;
+    log_info!(app, "🛡️  Leyendo elementos en cuarentena...");
+
+    app.quarantine_items = quarantine_root()
+        .map(|root| list_items_in(&root))
+        .unwrap_or_default();
+
+    let count = app.quarantine_items.len();
+    let total: u64 = app.quarantine_items.iter().map(|item| item.size).sum();
+    log_info!(
+        app,
+        "✅ Elementos en cuarentena: {} ({})",
+        count,
+        app.i18n.format_size(total)
+    );
+
+    if !app.config.quarantine.enabled {
+        log_info!(
+            app,
+            "ℹ️  La cuarentena está desactivada: activa [quarantine] enabled = true en config.toml"
+        );
+    }
+
+    app.operation_state = OperationState::Completed;
+}
+
+/// Restaura el elemento seleccionado a su ubicación original
+pub fn execute_restore(app: &mut crate::app::App, index: usize) {
+    let Some(item) = app.quarantine_items.get(index).cloned() else {
+        return;
+    };
+
+    log_info!(app, "");
+    match restore_item(&item) {
+        Ok(()) => {
+            log_info!(app, "✅ Restaurado: {}", item.original.display());
+            app.quarantine_items.remove(index);
+            app.selected_item = app
+                .selected_item
+                .min(app.quarantine_items.len().saturating_sub(1));
+        }
+        Err(e) if e.kind() == io::ErrorKind::AlreadyExists => log_warn!(
+            app,
+            "⚠️  Ya existe un elemento en {}; no se sobrescribe",
+            item.original.display()
+        ),
+        Err(e) => log_error!(
+            app,
+            "❌ Error restaurando {}: {}",
+            item.original.display(),
+            e
+        ),
+    }
+}
+
+/// Copia una carpeta con todo su contenido
+///
+/// Lo que ya existe en el destino no se sobrescribe.
+fn copy_dir(from: &Path, to: &Path) -> io::Result<()> {
+    fs::create_dir_all(to)?;
+    for entry in fs::read_dir(from)? {
+        let entry = entry?;
+        let target = to.join(entry.file_name());
+        if entry.file_type()?.is_dir() {
+            copy_dir(&entry.path(), &target)?;
+        } else if !target.exists() {
+            fs::copy(entry.path(), &target)?;
+        }
+    }
+    Ok(())
+}
+
+/// Mueve un elemento, copiándolo si el destino está en otro volumen
+///
+/// Si el original no se puede borrar tras copiarlo, la copia se descarta
+/// para no dejar en la cuarentena un elemento sin anotar en el manifiesto.
+fn move_path(from: &Path, to: &Path) -> io::Result<()> {
+    match fs::rename(from, to) {
+        Ok(()) => Ok(()),
+        Err(_) if from.is_file() => {
+            fs::copy(from, to)?;
+            fs::remove_file(from).inspect_err(|_| {
+                let _ = fs::remove_file(to);
+            })
+        }
+        Err(_) if from.is_dir() => {
+            if let Err(e) = copy_dir(from, to) {
+                let _ = fs::remove_dir_all(to);
+                return Err(e);
+            }
+            fs::remove_dir_all(from).inspect_err(|_| {
+                // El borrado puede haberse quedado a medias: lo que ya se
+                // borró se devuelve desde la copia antes de descartarla
+                let _ = copy_dir(to, from);
+                let _ = fs::remove_dir_all(to);
+            })
+        }
+        Err(e) => Err(e),
+    }
+}
+
+/// Mueve un elemento a la carpeta del día y lo anota en el manifiesto
+fn move_to_quarantine(root: &Path, day: &str, path: &Path) -> io::Result<()> {
+    let name = path
+        .file_name()
+        .ok_or_else(|| io::Error::new(io::ErrorKind::InvalidInput, "ruta sin nombre"))?;
+
+    let day_dir = root.join(day);
+    fs::create_dir_all(&day_dir)?;
+
+    // Prefijo único para que elementos con el mismo nombre no colisionen
+    let nanos = SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map(|d| d.as_nanos())
+        .unwrap_or(0);
+    let stored_name = format!("{}_{}", nanos, name.to_string_lossy());
+
+    let size = path_size(path);
+    move_path(path, &day_dir.join(&stored_name))?;
+
+    let mut manifest = OpenOptions::new()
+        .create(true)
+        .append(true)
+        .open(day_dir.join(MANIFEST_FILE))?;
+    writeln!(manifest, "{}\t{}\t{}", stored_name, path.display(), size)
+}
+
+/// Lee todos los manifiestos (días más recientes primero)
+fn list_items_in(root: &Path) -> Vec<QuarantinedItem> {
+    let mut days: Vec<String> = fs::read_dir(root)
+        .map(|entries| {
+            entries
+                .flatten()
+                .filter(|entry| entry.path().is_dir())
+                .map(|entry| entry.file_name().to_string_lossy().to_string())
+                .collect()
+        })
+        .unwrap_or_default();
+    days.sort_by(|a, b| b.cmp(a));
+
+    days.into_iter()
+        .flat_map(|day| {
+            let day_dir = root.join(&day);
+            let manifest = fs::read_to_string(day_dir.join(MANIFEST_FILE)).unwrap_or_default();
+            parse_manifest(&manifest)
+                .into_iter()
+                .map(|(stored, original, size)| QuarantinedItem {
+                    day: day.clone(),
+                    stored: day_dir.join(stored),
+                    original,
+                    size,
+                })
+                .filter(|item| item.stored.exists())
+                .collect::<Vec<_>>()
+        })
+        .collect()
+}
+
+/// Parsea las líneas `almacenado<TAB>original<TAB>tamaño` de un manifiesto
+fn parse_manifest(content: &str) -> Vec<(String, PathBuf, u64)> {
+    content
+        .lines()
+        .filter_map(|line| {
+            let mut fields = line.splitn(3, '\t');
+            let stored = fields.next()?.to_string();
+            let original = PathBuf::from(fields.next()?);
+            let size = fields
+                .next()
+                .and_then(|s| s.trim().parse().ok())
+                .unwrap_or(0);
+            Some((stored, original, size))
+        })
+        .collect()
+}
+
+/// Devuelve un elemento a su ruta original y lo quita del manifiesto
+fn restore_item(item: &QuarantinedItem) -> io::Result<()> {
+    if item.original.exists() {
+        return Err(io::Error::new(
+            io::ErrorKind::AlreadyExists,
+            "el destino ya existe",
+        ));
+    }
+    if let Some(parent) = item.original.parent() {
+        fs::create_dir_all(parent)?;
+    }
+    move_path(&item.stored, &item.original)?;
+
+    let Some(day_dir) = item.stored.parent() else {
+        return Ok(());
+    };
+    let stored_name = item
+        .stored
+        .file_name()
+        .map(|n| n.to_string_lossy().to_string())
+        .unwrap_or_default();
+    let manifest_path = day_dir.join(MANIFEST_FILE);
+    let remaining: String = fs::read_to_string(&manifest_path)?
+        .lines()
+        .filter(|line| line.split('\t').next() != Some(stored_name.as_str()))
+        .map(|line| format!("{}\n", line))
+        .collect();
+    fs::write(manifest_path, remaining)
+}
+
+/// Elimina las carpetas diarias con más de `retention_days` días
+fn purge_expired_in(root: &Path, retention_days: u64, today: i64) -> usize {
+    let Ok(entries) = fs::read_dir(root) else {
+        return 0;
+    };
+
+    let mut purged = 0;
+    for entry in entries.flatten() {
+        let name = entry.file_name().to_string_lossy().to_string();
+        let Some(day) = parse_iso_date(&name) else {
+            continue;
+        };
+        if today - day > retention_days as i64
+            && safety::check_removable(&entry.path()).is_ok()
+            && fs::remove_dir_all(entry.path()).is_ok()
+        {
+            tracing::info!("Cuarentena purgada: {}", name);
+            purged += 1;
+        }
+    }
+    purged
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn test_root(name: &str) -> PathBuf {
+        let root = std::env::temp_dir().join(format!("win_opt_test_quarantine_{}", name));
+        let _ = fs::remove_dir_all(&root);
+        root
+    }
+
+    #[test]
+    fn test_quarantine_and_restore_roundtrip() {
+        let root = test_root("roundtrip");
+        let source_dir = root.join("source");
+        fs::create_dir_all(&source_dir).unwrap();
+        let file = source_dir.join("cache.tmp");
+        fs::write(&file, b"datos").unwrap();
+
+        move_to_quarantine(&root.join("q"), "2026-10-16", &file).unwrap();
+        assert!(!file.exists());
+
+        let items = list_items_in(&root.join("q"));
+        assert_eq!(items.len(), 1);
+        assert_eq!(items[0].original, file);
+        assert_eq!(items[0].size, 5);
+
+        restore_item(&items[0]).unwrap();
+        assert_eq!(fs::read(&file).unwrap(), b"datos");
+        assert!(list_items_in(&root.join("q")).is_empty());
+
+        fs::remove_dir_all(&root).unwrap();
+    }
+
+    #[test]
+    fn test_restore_does_not_overwrite() {
+        let root = test_root("overwrite");
+        fs::create_dir_all(&root).unwrap();
+        let file = root.join("a.log");
+        fs::write(&file, b"1").unwrap();
+
+        move_to_quarantine(&root.join("q"), "2026-10-16", &file).unwrap();
+        fs::write(&file, b"2").unwrap();
+
+        let items = list_items_in(&root.join("q"));
+        let err = restore_item(&items[0]).unwrap_err();
+        assert_eq!(err.kind(), io::ErrorKind::AlreadyExists);
+
+        fs::remove_dir_all(&root).unwrap();
+    }
+
+    #[test]
+    fn test_copy_dir_copies_nested_items_without_overwriting() {
+        let root = test_root("copy_dir");
+        let source = root.join("source");
+        fs::create_dir_all(source.join("sub")).unwrap();
+        fs::write(source.join("a.tmp"), b"a").unwrap();
+        fs::write(source.join("sub").join("b.tmp"), b"b").unwrap();
+
+        let target = root.join("target");
+        copy_dir(&source, &target).unwrap();
+        assert_eq!(fs::read(target.join("sub").join("b.tmp")).unwrap(), b"b");
+
+        // Al devolver un borrado a medias no se pisa lo que sigue en su sitio
+        fs::write(source.join("a.tmp"), b"changed").unwrap();
+        fs::remove_file(source.join("sub").join("b.tmp")).unwrap();
+        copy_dir(&target, &source).unwrap();
+        assert_eq!(fs::read(source.join("a.tmp")).unwrap(), b"changed");
+        assert_eq!(fs::read(source.join("sub").join("b.tmp")).unwrap(), b"b");
+
+        fs::remove_dir_all(&root).unwrap();
+    }
+
+    #[test]
+    fn test_purge_expired_days() {
+        let root = test_root("purge");
+        fs::create_dir_all(root.join("2026-10-01")).unwrap();
+        fs::create_dir_all(root.join("2026-10-15")).unwrap();
+        fs::create_dir_all(root.join("not-a-date")).unwrap();
+
+        let today = parse_iso_date("2026-10-16").unwrap();
+        assert_eq!(purge_expired_in(&root, 7, today), 1);
+        assert!(!root.join("2026-10-01").exists());
+        assert!(root.join("2026-10-15").exists());
+        assert!(root.join("not-a-date").exists());
+
+        fs::remove_dir_all(&root).unwrap();
+    }
+
+    #[test]
+    fn test_parse_manifest() {
+        let entries = parse_manifest("1_a.tmp\tC:\\Temp\\a.tmp\t42\nmalformed\n");
+        assert_eq!(
+            entries,
+            vec![("1_a.tmp".to_string(), PathBuf::from("C:\\Temp\\a.tmp"), 42)]
+        );
+    }
+}
@@ -0,0 +1,247 @@
+//! Reinicio tras las operaciones que lo necesitan
+//!
+//! Algunas operaciones (restablecer Winsock o TCP/IP, deshabilitar
+//! servicios, reparar archivos del sistema...) no surten efecto hasta
+//! reiniciar. Cuando alguna lo necesita se avisa en todas las vistas y, con
+//! Ctrl+R, se programa el reinicio con `shutdown /r /t <segundos>`. Mientras
+//! no venza, la misma tecla lo cancela con `shutdown /a`.
+//!
+//! Al arrancar se comprueba además si Windows ya tenía un reinicio
+//! pendiente, porque DISM y SFC fallan o dan resultados engañosos en ese
+//! estado; antes de ejecutarlos se avisa y se pide confirmación.
+//!
+//! Si el equipo lleva encendido más de `uptime_warning_days` días, el menú
+//! principal recomienda reiniciar: muchos "el PC va lento" se arreglan así.
+
+use crate::i18n::I18nKey;
+use crate::platform;
+use crate::registry;
+use crate::types::View;
+use serde::{Deserialize, Serialize};
+use std::time::Duration;
+
+/// Máximo que admite `shutdown /t` (diez años)
+const MAX_DELAY_SECS: u64 = 315_360_000;
+
+/// Comentario que muestra Windows en el aviso de reinicio
+const SHUTDOWN_COMMENT: &str = "win_opt: reinicio para completar las optimizaciones";
+
+/// Motivo por el que Windows tiene un reinicio pendiente
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PendingReboot {
+    /// Actualizaciones de componentes a medio instalar
+    ComponentServicing,
+    /// Windows Update instaló actualizaciones que requieren reiniciar
+    WindowsUpdate,
+    /// Archivos en uso que se reemplazarán al reiniciar
+    FileRenames,
+}
+
+impl PendingReboot {
+    /// Todos los motivos, en el orden en que se comprueban
+    pub const ALL: [PendingReboot; 3] = [
+        PendingReboot::ComponentServicing,
+        PendingReboot::WindowsUpdate,
+        PendingReboot::FileRenames,
+    ];
+
+    /// Clave del registro que lo indica y, si hace falta, el valor
+    fn registry_entry(self) -> (&'static str, Option<&'static str>) {
+        match self {
+            PendingReboot::ComponentServicing => (
+                "HKLM\\SOFTWARE\\Microsoft\\Windows\\CurrentVersion\\Component Based Servicing\\RebootPending",
+                None,
+            ),
+            PendingReboot::WindowsUpdate => (
+                "HKLM\\SOFTWARE\\Microsoft\\Windows\\CurrentVersion\\WindowsUpdate\\Auto Update\\RebootRequired",
+                None,
+            ),
+            PendingReboot::FileRenames => (
+                "HKLM\\SYSTEM\\CurrentControlSet\\Control\\Session Manager",
+                Some("PendingFileRenameOperations"),
+            ),
+        }
+    }
+
+    /// Clave de traducción de la descripción
+    pub fn label_key(self) -> I18nKey {
+        match self {
+            PendingReboot::ComponentServicing => I18nKey::PendingRebootComponentServicing,
+            PendingReboot::WindowsUpdate => I18nKey::PendingRebootWindowsUpdate,
+            PendingReboot::FileRenames => I18nKey::PendingRebootFileRenames,
+        }
+    }
+
+    /// Comprueba en el registro si este motivo está activo
+    fn is_active(self) -> bool {
+        match self.registry_entry() {
+            (key, None) => registry::key_exists(key),
+            (key, Some(name)) => {
+                registry::query_value(key, name).is_some_and(|value| !value.data.is_empty())
+            }
+        }
+    }
+}
+
+/// Motivos por los que Windows tiene ahora un reinicio pendiente
+pub fn detect_pending() -> Vec<PendingReboot> {
+    PendingReboot::ALL
+        .into_iter()
+        .filter(|reason| reason.is_active())
+        .collect()
+}
+
+/// Indica si la operación de la vista funciona mal con un reinicio pendiente
+pub fn affected_by_pending(view: View) -> bool {
+    matches!(view, View::Repair | View::WindowsUpdate)
+}
+
+/// Configuración del reinicio programado
+#[derive(Debug, Clone, Copy, Serialize, Deserialize)]
+#[serde(default)]
+pub struct RebootConfig {
+    /// Minutos hasta el reinicio al programarlo (mínimo uno)
+    pub delay_minutes: u64,
+    /// Días encendido a partir de los que se recomienda reiniciar (0 = nunca)
+    pub uptime_warning_days: u64,
+}
+
+impl Default for RebootConfig {
+    fn default() -> Self {
+        Self {
+            delay_minutes: 5,
+            uptime_warning_days: 14,
+        }
+    }
+}
+
+impl RebootConfig {
+    /// Tiempo hasta el reinicio al programarlo
+    pub fn delay(&self) -> Duration {
+        Duration::from_secs((self.delay_minutes.max(1) * 60).min(MAX_DELAY_SECS))
+    }
+
+    /// Días encendido si superan el umbral de la recomendación de reinicio
+    pub fn long_uptime_days(&self, uptime: Duration) -> Option<u64> {
+        let days = uptime.as_secs() / (24 * 60 * 60);
+        (self.uptime_warning_days > 0 && days >= self.uptime_warning_days).then_some(days)
+    }
+}
+
+/// Argumentos de `shutdown` para reiniciar dentro de `delay`
+pub fn schedule_args(delay: Duration) -> Vec<String> {
+    vec![
+        "/r".to_string(),
+        "/t".to_string(),
+        delay.as_secs().min(MAX_DELAY_SECS).to_string(),
+        "/c".to_string(),
+        SHUTDOWN_COMMENT.to_string(),
+    ]
+}
+
+/// Programa el reinicio del equipo dentro de `delay`
+pub fn schedule(delay: Duration) -> Result<(), String> {
+    run_shutdown(&schedule_args(delay))
+}
+
+/// Cancela el reinicio programado
+pub fn cancel() -> Result<(), String> {
+    run_shutdown(&["/a".to_string()])
+}
+
+/// Ejecuta `shutdown` y devuelve su mensaje de error si falla
+fn run_shutdown(args: &[String]) -> Result<(), String> {
+    let output = platform::command("shutdown")
+        .args(args)
+        .output()
+        .map_err(|e| e.to_string())?;
+    if output.status.success() {
+        return Ok(());
+    }
+    let message = String::from_utf8_lossy(&output.stderr).trim().to_string();
+    if message.is_empty() {
+        Err(format!("código {:?}", output.status.code()))
+    } else {
+        Err(message)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_delay_is_at_least_one_minute() {
+        assert_eq!(RebootConfig::default().delay(), Duration::from_secs(300));
+        let config = RebootConfig {
+            delay_minutes: 0,
+            ..RebootConfig::default()
+        };
+        assert_eq!(config.delay(), Duration::from_secs(60));
+    }
+
+    #[test]
+    fn test_long_uptime_threshold() {
+        let day = Duration::from_secs(24 * 60 * 60);
+        let config = RebootConfig::default();
+        assert_eq!(config.long_uptime_days(day * 13 + day / 2), None);
+        assert_eq!(config.long_uptime_days(day * 14), Some(14));
+        assert_eq!(config.long_uptime_days(day * 30), Some(30));
+
+        let disabled = RebootConfig {
+            uptime_warning_days: 0,
+            ..RebootConfig::default()
+        };
+        assert_eq!(disabled.long_uptime_days(day * 365), None);
+    }
+
+    #[test]
+    fn test_schedule_and_cancel_with_fake_runner() {
+        use crate::platform::FakeRunner;
+
+        let (runner, _guard) = FakeRunner::default()
+            .respond("shutdown /r", 0, "")
+            .install();
+        assert_eq!(schedule(Duration::from_secs(120)), Ok(()));
+        assert!(runner.ran("shutdown /r /t 120 /c"));
+        // Sin respuesta preparada, `shutdown /a` falla con su código
+        assert_eq!(cancel(), Err("código Some(1)".to_string()));
+        assert!(runner.ran("shutdown /a"));
+    }
+
+    #[test]
+    fn test_detect_pending_with_fake_runner() {
+        use crate::platform::FakeRunner;
+
+        let (_runner, _guard) = FakeRunner::default()
+            .respond(
+                "reg query HKLM\\SOFTWARE\\Microsoft\\Windows\\CurrentVersion\\WindowsUpdate\\Auto Update\\RebootRequired",
+                0,
+                "",
+            )
+            .install();
+        assert_eq!(detect_pending(), vec![PendingReboot::WindowsUpdate]);
+    }
+
+    #[test]
+    fn test_pending_reboot_entries() {
+        for reason in PendingReboot::ALL {
+            let (key, _) = reason.registry_entry();
+            assert!(key.starts_with("HKLM\\"));
+        }
+        assert!(affected_by_pending(View::Repair));
+        assert!(!affected_by_pending(View::Clean));
+    }
+
+    #[test]
+    fn test_schedule_args() {
+        assert_eq!(
+            schedule_args(Duration::from_secs(300))[..3],
+            ["/r", "/t", "300"]
+        );
+        assert_eq!(
+            schedule_args(Duration::from_secs(u64::MAX))[2],
+            MAX_DELAY_SECS.to_string()
+        );
+    }
+}
@@ -0,0 +1,382 @@
+//! Acceso al registro de Windows
+//!
+//! Envuelve `reg.exe` para leer y escribir valores sin necesidad de
+//! dependencias nativas. La salida de `reg query` tiene el formato:
+//!
+//! ```text
+//! HKEY_CURRENT_USER\Software\Microsoft\GameBar
+//!     AutoGameModeEnabled    REG_DWORD    0x1
+//! ```
+//!
+//! `reg query` escribe en la página de códigos de la consola y recorta los
+//! espacios de los datos, así que los textos que se leen para volver a
+//! escribirlos (el PATH) usan la API del registro con [`read_string`] y
+//! [`write_string`].
+
+use crate::error::{Result, WinOptError};
+use crate::platform;
+use std::path::Path;
+
+/// Valor leído del registro
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RegValue {
+    /// Tipo del valor (REG_DWORD, REG_SZ, ...)
+    pub kind: String,
+    /// Datos del valor tal como los muestra `reg query`
+    pub data: String,
+}
+
+impl RegValue {
+    /// Interpreta el valor como DWORD (`0x1` → 1)
+    pub fn as_dword(&self) -> Option<u32> {
+        if self.kind != "REG_DWORD" {
+            return None;
+        }
+        let hex = self.data.trim_start_matches("0x");
+        u32::from_str_radix(hex, 16).ok()
+    }
+}
+
+/// Clave del registro junto con sus valores
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RegKey {
+    /// Ruta completa de la clave
+    pub path: String,
+    /// Valores de la clave (nombre, valor)
+    pub values: Vec<(String, RegValue)>,
+}
+
+impl RegKey {
+    /// Busca un valor por nombre (sin distinguir mayúsculas)
+    pub fn value(&self, name: &str) -> Option<&RegValue> {
+        self.values
+            .iter()
+            .find(|(value_name, _)| value_name.eq_ignore_ascii_case(name))
+            .map(|(_, value)| value)
+    }
+
+    /// Datos de un valor como texto, ignorando valores vacíos
+    pub fn string(&self, name: &str) -> Option<&str> {
+        self.value(name)
+            .map(|value| value.data.as_str())
+            .filter(|data| !data.is_empty())
+    }
+}
+
+/// Lee una clave y todas sus subclaves (`reg query <clave> /s`)
+///
+/// Retorna una lista vacía si la clave no existe.
+pub fn query_key_recursive(key: &str) -> Vec<RegKey> {
+    match platform::command("reg").args(["query", key, "/s"]).output() {
+        Ok(output) if output.status.success() => {
+            parse_recursive_output(&String::from_utf8_lossy(&output.stdout))
+        }
+        _ => Vec::new(),
+    }
+}
+
+/// Lee un valor del registro
+///
+/// Retorna `None` si la clave o el valor no existen.
+pub fn query_value(key: &str, name: &str) -> Option<RegValue> {
+    let output = platform::command("reg")
+        .args(["query", key, "/v", name])
+        .output()
+        .ok()?;
+
+    if !output.status.success() {
+        return None;
+    }
+
+    parse_query_output(&String::from_utf8_lossy(&output.stdout), name)
+}
+
+/// Lee un valor de texto (`REG_SZ` o `REG_EXPAND_SZ`) tal como está guardado
+///
+/// El texto llega completo, en UTF-16 y sin expandir las variables
+/// `%NOMBRE%`. Con un ejecutor sustituto (simulación, pruebas) se lee con
+/// `reg.exe` para que lo atienda. Retorna `None` si la clave o el valor no
+/// existen.
+pub fn read_string(key: &str, name: &str) -> Option<RegValue> {
+    if platform::replaced_runner("reg").is_some() {
+        return query_value(key, name);
+    }
+    native::read_string(key, name)
+}
+
+/// Escribe un valor de texto (`REG_SZ` o `REG_EXPAND_SZ`) tal cual, con la
+/// API del registro (ver [`read_string`])
+pub fn write_string(key: &str, name: &str, kind: &str, data: &str) -> Result<()> {
+    if platform::replaced_runner("reg").is_some() {
+        return set_string(key, name, kind, data);
+    }
+    native::write_string(key, name, kind, data)
+}
+
+/// Indica si existe una clave del registro
+pub fn key_exists(key: &str) -> bool {
+    platform::command("reg")
+        .args(["query", key])
+        .output()
+        .is_ok_and(|output| output.status.success())
+}
+
+/// Lee un valor DWORD del registro
+pub fn query_dword(key: &str, name: &str) -> Option<u32> {
+    query_value(key, name).and_then(|v| v.as_dword())
+}
+
+/// Escribe un valor DWORD en el registro (creando la clave si no existe)
+pub fn set_dword(key: &str, name: &str, value: u32) -> Result<()> {
+    run_reg(&[
+        "add",
+        key,
+        "/v",
+        name,
+        "/t",
+        "REG_DWORD",
+        "/d",
+        &value.to_string(),
+        "/f",
+    ])
+}
+
+/// Escribe un valor de texto (`REG_SZ` o `REG_EXPAND_SZ`) en el registro
+pub fn set_string(key: &str, name: &str, kind: &str, data: &str) -> Result<()> {
+    run_reg(&["add", key, "/v", name, "/t", kind, "/d", data, "/f"])
+}
+
+/// Escribe un valor binario (`REG_BINARY`) en el registro
+pub fn set_binary(key: &str, name: &str, data: &[u8]) -> Result<()> {
+    let hex: String = data.iter().map(|byte| format!("{byte:02X}")).collect();
+    run_reg(&["add", key, "/v", name, "/t", "REG_BINARY", "/d", &hex, "/f"])
+}
+
+/// Exporta una clave a un archivo `.reg` (restaurable con `reg import`)
+pub fn export_key(key: &str, file: &Path) -> Result<()> {
+    run_reg(&["export", key, &file.to_string_lossy(), "/y"])
+}
+
+/// Elimina un valor del registro
+pub fn delete_value(key: &str, name: &str) -> Result<()> {
+    run_reg(&["delete", key, "/v", name, "/f"])
+}
+
+/// Acceso a los valores de texto con la API del registro
+#[cfg(windows)]
+mod native {
+    use super::RegValue;
+    use crate::error::{ControlError, Result, WinOptError};
+    use windows::Win32::System::Registry::{
+        HKEY, HKEY_CURRENT_USER, HKEY_LOCAL_MACHINE, REG_EXPAND_SZ, REG_SZ, REG_VALUE_TYPE,
+        RRF_NOEXPAND, RRF_RT_REG_EXPAND_SZ, RRF_RT_REG_SZ, RegGetValueW, RegSetKeyValueW,
+    };
+    use windows::core::HSTRING;
+
+    /// Separa la raíz (`HKCU`, `HKLM`) del resto de la ruta de la clave
+    fn split_key(key: &str) -> Option<(HKEY, &str)> {
+        let (root, subkey) = key.split_once('\\')?;
+        let root = match root.to_ascii_uppercase().as_str() {
+            "HKCU" | "HKEY_CURRENT_USER" => HKEY_CURRENT_USER,
+            "HKLM" | "HKEY_LOCAL_MACHINE" => HKEY_LOCAL_MACHINE,
+            _ => return None,
+        };
+        Some((root, subkey))
+    }
+
+    pub(super) fn read_string(key: &str, name: &str) -> Option<RegValue> {
+        let (root, subkey) = split_key(key)?;
+        let (subkey, name) = (HSTRING::from(subkey), HSTRING::from(name));
+        let flags = RRF_RT_REG_SZ | RRF_RT_REG_EXPAND_SZ | RRF_NOEXPAND;
+        let mut kind = REG_VALUE_TYPE::default();
+        let mut size = 0u32;
+
+        // SAFETY: los nombres son HSTRING terminados en nulo que viven durante
+        // las llamadas; la primera solo pide el tamaño en bytes y la segunda
+        // escribe como mucho `size` bytes en un búfer de ese tamaño
+        let mut buffer = unsafe {
+            RegGetValueW(root, &subkey, &name, flags, None, None, Some(&mut size))
+                .ok()
+                .ok()?;
+            let mut buffer = vec![0u16; (size as usize).div_ceil(2)];
+            RegGetValueW(
+                root,
+                &subkey,
+                &name,
+                flags,
+                Some(&mut kind),
+                Some(buffer.as_mut_ptr().cast()),
+                Some(&mut size),
+            )
+            .ok()
+            .ok()?;
+            buffer
+        };
+
+        // RegGetValueW incluye el nulo final en el tamaño
+        buffer.truncate(size as usize / 2);
+        if buffer.last() == Some(&0) {
+            buffer.pop();
+        }
+        let kind = if kind == REG_EXPAND_SZ {
+            "REG_EXPAND_SZ"
+        } else {
+            "REG_SZ"
+        };
+        Some(RegValue {
+            kind: kind.to_string(),
+            data: String::from_utf16_lossy(&buffer),
+        })
+    }
+
+    pub(super) fn write_string(key: &str, name: &str, kind: &str, data: &str) -> Result<()> {
+        let (root, subkey) =
+            split_key(key).ok_or_else(|| WinOptError::InvalidPath(key.to_string()))?;
+        let kind = if kind == "REG_EXPAND_SZ" {
+            REG_EXPAND_SZ
+        } else {
+            REG_SZ
+        };
+        let data: Vec<u16> = data.encode_utf16().chain([0]).collect();
+
+        // SAFETY: los nombres son HSTRING terminados en nulo y `data` es un
+        // texto UTF-16 terminado en nulo de `data.len() * 2` bytes; todos
+        // viven durante la llamada
+        unsafe {
+            RegSetKeyValueW(
+                root,
+                &HSTRING::from(subkey),
+                &HSTRING::from(name),
+                kind.0,
+                Some(data.as_ptr().cast()),
+                (data.len() * 2) as u32,
+            )
+            .ok()
+            .map_err(ControlError::from)?;
+        }
+        Ok(())
+    }
+}
+
+/// Fuera de Windows no hay registro: se pasa por `reg.exe`, que no se ejecuta
+#[cfg(not(windows))]
+mod native {
+    use super::RegValue;
+    use crate::error::Result;
+
+    pub(super) fn read_string(key: &str, name: &str) -> Option<RegValue> {
+        super::query_value(key, name)
+    }
+
+    pub(super) fn write_string(key: &str, name: &str, kind: &str, data: &str) -> Result<()> {
+        super::set_string(key, name, kind, data)
+    }
+}
+
+/// Ejecuta `reg.exe` con los argumentos indicados
+fn run_reg(args: &[&str]) -> Result<()> {
+    let output = platform::command("reg").args(args).output()?;
+
+    if output.status.success() {
+        Ok(())
+    } else {
+        Err(WinOptError::CommandFailed {
+            command: format!("reg {}", args.join(" ")),
+            reason: String::from_utf8_lossy(&output.stderr).trim().to_string(),
+        })
+    }
+}
+
+/// Extrae tipo y datos de un valor de la salida de `reg query`
+///
+/// Los datos se conservan tal cual (incluidos espacios dobles) para poder
+/// volver a escribirlos sin alterarlos.
+fn parse_query_output(output: &str, name: &str) -> Option<RegValue> {
+    output
+        .lines()
+        .filter_map(parse_value_line)
+        .find(|(value_name, _)| value_name.eq_ignore_ascii_case(name))
+        .map(|(_, value)| value)
+}
+
+/// Agrupa la salida de `reg query /s` por clave
+fn parse_recursive_output(output: &str) -> Vec<RegKey> {
+    let mut keys: Vec<RegKey> = Vec::new();
+
+    for line in output.lines() {
+        if line.starts_with("HKEY_") {
+            keys.push(RegKey {
+                path: line.trim().to_string(),
+                values: Vec::new(),
+            });
+        } else if let Some(key) = keys.last_mut()
+            && let Some(value) = parse_value_line(line)
+        {
+            key.values.push(value);
+        }
+    }
+
+    keys
+}
+
+/// Parsea una línea de valor (`    Nombre    REG_SZ    Datos`)
+///
+/// `reg query` separa las columnas con cuatro espacios, lo que permite
+/// nombres de valor que contienen espacios simples.
+fn parse_value_line(line: &str) -> Option<(String, RegValue)> {
+    let mut parts = line.trim_start().splitn(3, "    ");
+    let name = parts.next()?.trim();
+    let kind = parts.next()?.trim();
+    if name.is_empty() || !kind.starts_with("REG_") {
+        return None;
+    }
+    Some((
+        name.to_string(),
+        RegValue {
+            kind: kind.to_string(),
+            data: parts.next().unwrap_or("").trim().to_string(),
+        },
+    ))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_dword_value() {
+        let output = "\r\nHKEY_CURRENT_USER\\Software\\Microsoft\\GameBar\r\n    AutoGameModeEnabled    REG_DWORD    0x1\r\n\r\n";
+        let value = parse_query_output(output, "AutoGameModeEnabled").unwrap();
+        assert_eq!(value.kind, "REG_DWORD");
+        assert_eq!(value.as_dword(), Some(1));
+    }
+
+    #[test]
+    fn test_parse_string_value_with_spaces() {
+        let output = "HKEY_CURRENT_USER\\Environment\n    Path    REG_EXPAND_SZ    C:\\Program Files\\Tool;%USERPROFILE%\\bin\n";
+        let value = parse_query_output(output, "path").unwrap();
+        assert_eq!(value.kind, "REG_EXPAND_SZ");
+        assert_eq!(value.data, "C:\\Program Files\\Tool;%USERPROFILE%\\bin");
+        assert_eq!(value.as_dword(), None);
+    }
+
+    #[test]
+    fn test_parse_recursive_output() {
+        let output = "\r\nHKEY_LOCAL_MACHINE\\Software\\Uninstall\\7-Zip\r\n    DisplayName    REG_SZ    7-Zip 23.01 (x64)\r\n    EstimatedSize    REG_DWORD    0x1620\r\n    Comments    REG_SZ    \r\n\r\nHKEY_LOCAL_MACHINE\\Software\\Uninstall\\Empty\r\n";
+        let keys = parse_recursive_output(output);
+        assert_eq!(keys.len(), 2);
+        assert_eq!(keys[0].string("displayname"), Some("7-Zip 23.01 (x64)"));
+        assert_eq!(
+            keys[0].value("EstimatedSize").and_then(|v| v.as_dword()),
+            Some(0x1620)
+        );
+        assert_eq!(keys[0].string("Comments"), None);
+        assert!(keys[1].values.is_empty());
+    }
+
+    #[test]
+    fn test_parse_missing_value() {
+        let output = "HKEY_CURRENT_USER\\Software\\Microsoft\\GameBar\n";
+        assert!(parse_query_output(output, "AutoGameModeEnabled").is_none());
+    }
+}
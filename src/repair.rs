@@ -0,0 +1,497 @@
+//! Interpretación del resultado de la reparación del sistema
+//!
+//! DISM y SFC no explican su resultado con el código de salida: DISM
+//! informa de errores como `0x800f081f` (no encuentra los archivos de
+//! origen) y SFC indica en su mensaje final si encontró archivos dañados y
+//! si pudo repararlos. A partir de su salida se da un diagnóstico legible y
+//! los pasos recomendados. Los mensajes están traducidos al idioma de
+//! Windows, por lo que se reconocen varios idiomas.
+//!
+//! Tras SFC se extraen además de `CBS.log` las líneas `[SR]` del último
+//! análisis, que indican qué archivos se repararon o no, para no tener que
+//! buscarlas a mano con `findstr`.
+
+use crate::i18n::I18nKey;
+use crate::platform;
+use crate::types::RepairStats;
+use std::path::PathBuf;
+
+/// DISM no encuentra los archivos de origen
+const CBS_E_SOURCE_MISSING: u32 = 0x800F_081F;
+/// DISM no pudo descargar los archivos de origen
+const CBS_E_DOWNLOAD_FAILED: &[u32] = &[0x800F_0906, 0x800F_0907, 0x800F_0950, 0x800F_0954];
+/// Se necesitan permisos de administrador (`ERROR_ELEVATION_REQUIRED`)
+const ELEVATION_REQUIRED: &[u32] = &[740, 0x8007_02E4];
+
+/// Registro de servicio de componentes donde SFC detalla su análisis
+pub fn cbs_log() -> PathBuf {
+    platform::windows_dir()
+        .join("Logs")
+        .join("CBS")
+        .join("CBS.log")
+}
+
+/// Máximo de líneas `[SR]` que se guardan del último análisis
+pub const CBS_EXCERPT_LINES: usize = 200;
+
+/// Línea con la que SFC empieza cada análisis en `CBS.log`
+const SR_SCAN_START: &str = "Beginning Verify and Repair transaction";
+
+/// Líneas `[SR]` de progreso que no aportan nada al resumen
+const SR_NOISE: &[&str] = &["Verifying ", "Verify complete"];
+
+/// Mensajes de DISM que repararon el almacén de componentes
+const DISM_REPAIRED: &[&str] = &[
+    "component store corruption was repaired",
+    "se repararon los daños del almacén de componentes",
+];
+
+/// Mensajes de DISM que terminaron correctamente
+const DISM_COMPLETED: &[&str] = &[
+    "operation completed successfully",
+    "operación se completó correctamente",
+    "no component store corruption detected",
+];
+
+/// Mensajes de SFC que encontró archivos dañados y no pudo repararlos todos
+const SFC_UNFIXED: &[&str] = &[
+    "unable to fix some of them",
+    "no pudo reparar",
+    "n'a pas pu réparer",
+    "nicht reparieren",
+    "não pôde reparar",
+];
+
+/// Mensajes de SFC que encontró archivos dañados y los reparó
+const SFC_FIXED: &[&str] = &[
+    "found corrupt files and successfully repaired them",
+    "encontró archivos dañados y los reparó",
+    "a trouvé des fichiers endommagés et les a réparés",
+    "beschädigte dateien gefunden und erfolgreich repariert",
+    "encontrou arquivos corrompidos e os reparou",
+];
+
+/// Mensajes de SFC que no encontró archivos dañados
+const SFC_CLEAN: &[&str] = &[
+    "did not find any integrity violations",
+    "no encontró ninguna infracción de integridad",
+    "n'a trouvé aucune violation d'intégrité",
+    "keine integritätsverletzungen",
+    "não encontrou nenhuma violação de integridade",
+];
+
+/// Mensajes de SFC con una reparación pendiente de reinicio
+const SFC_REBOOT_PENDING: &[&str] = &[
+    "system repair pending which requires reboot",
+    "reparación del sistema pendiente que requiere reiniciar",
+];
+
+/// Mensajes de SFC que no pudo hacer el análisis
+const SFC_COULD_NOT_RUN: &[&str] = &[
+    "could not perform the requested operation",
+    "could not start the repair service",
+    "no pudo realizar la operación solicitada",
+    "no pudo iniciar el servicio de reparación",
+];
+
+/// Mensajes de SFC que necesita permisos de administrador
+const SFC_NEEDS_ADMIN: &[&str] = &["must be an administrator", "debe ser administrador"];
+
+/// Resultado de DISM
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum DismResult {
+    /// Sin resultado (no terminó o no se reconoce su salida)
+    #[default]
+    Unknown,
+    /// Terminó correctamente sin tener que reparar nada
+    Completed,
+    /// Encontró daños en el almacén de componentes y los reparó
+    Repaired,
+    /// No encontró los archivos de origen (`0x800f081f`)
+    SourceMissing,
+    /// No pudo descargar los archivos de origen
+    DownloadFailed(u32),
+    /// Necesita permisos de administrador
+    NeedsAdmin,
+    /// Falló con otro código de error
+    Failed(u32),
+}
+
+/// Resultado de SFC
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum SfcResult {
+    /// Sin resultado (no terminó o no se reconoce su salida)
+    #[default]
+    Unknown,
+    /// No encontró infracciones de integridad
+    Healthy,
+    /// Encontró archivos dañados y los reparó
+    Repaired,
+    /// Encontró archivos dañados y no pudo repararlos todos
+    Unrepaired,
+    /// Hay una reparación pendiente de reinicio
+    RebootPending,
+    /// No pudo hacer el análisis
+    CouldNotRun,
+    /// Necesita permisos de administrador
+    NeedsAdmin,
+}
+
+impl SfcResult {
+    /// Archivos dañados: `(encontrados, reparados)`, si SFC terminó el análisis
+    pub fn corruption(self) -> Option<(bool, bool)> {
+        match self {
+            SfcResult::Healthy => Some((false, false)),
+            SfcResult::Repaired => Some((true, true)),
+            SfcResult::Unrepaired => Some((true, false)),
+            _ => None,
+        }
+    }
+}
+
+/// Une la salida en minúsculas para buscar mensajes partidos en varias líneas
+fn normalize(lines: &[String]) -> String {
+    lines.join(" ").to_lowercase()
+}
+
+/// Indica si la salida contiene alguno de los mensajes
+fn contains_any(output: &str, phrases: &[&str]) -> bool {
+    phrases.iter().any(|phrase| output.contains(phrase))
+}
+
+/// Código de error que DISM escribe en su salida (`Error: 0x800f081f`)
+fn error_code(lines: &[String]) -> Option<u32> {
+    lines.iter().find_map(|line| {
+        let (_, rest) = line.split_once("Error:")?;
+        let code = rest.split_whitespace().next()?;
+        match code.strip_prefix("0x").or_else(|| code.strip_prefix("0X")) {
+            Some(hex) => u32::from_str_radix(hex, 16).ok(),
+            None => code.parse().ok(),
+        }
+    })
+}
+
+/// Interpreta la salida y el código de salida de DISM
+pub fn parse_dism(lines: &[String], exit_code: Option<i32>) -> DismResult {
+    // El código de salida de DISM es el HRESULT con signo
+    let code = error_code(lines).or(exit_code.filter(|&code| code != 0).map(|code| code as u32));
+    if let Some(code) = code {
+        return match code {
+            CBS_E_SOURCE_MISSING => DismResult::SourceMissing,
+            code if CBS_E_DOWNLOAD_FAILED.contains(&code) => DismResult::DownloadFailed(code),
+            code if ELEVATION_REQUIRED.contains(&code) => DismResult::NeedsAdmin,
+            code => DismResult::Failed(code),
+        };
+    }
+
+    let output = normalize(lines);
+    if contains_any(&output, DISM_REPAIRED) {
+        DismResult::Repaired
+    } else if contains_any(&output, DISM_COMPLETED) || exit_code == Some(0) {
+        DismResult::Completed
+    } else {
+        DismResult::Unknown
+    }
+}
+
+/// Interpreta el mensaje final de SFC
+pub fn parse_sfc(lines: &[String]) -> SfcResult {
+    let output = normalize(lines);
+    if contains_any(&output, SFC_NEEDS_ADMIN) {
+        SfcResult::NeedsAdmin
+    } else if contains_any(&output, SFC_REBOOT_PENDING) {
+        SfcResult::RebootPending
+    } else if contains_any(&output, SFC_COULD_NOT_RUN) {
+        SfcResult::CouldNotRun
+    } else if contains_any(&output, SFC_UNFIXED) {
+        SfcResult::Unrepaired
+    } else if contains_any(&output, SFC_FIXED) {
+        SfcResult::Repaired
+    } else if contains_any(&output, SFC_CLEAN) {
+        SfcResult::Healthy
+    } else {
+        SfcResult::Unknown
+    }
+}
+
+/// Líneas `[SR]` del último análisis de SFC en el contenido de `CBS.log`
+///
+/// Se omiten las de progreso y se guardan como mucho las `max_lines`
+/// últimas, con su fecha y hora.
+pub fn sr_excerpt(content: &str, max_lines: usize) -> Vec<String> {
+    let sr_lines: Vec<&str> = content
+        .lines()
+        .filter(|line| line.contains("[SR]"))
+        .collect();
+    let scan = sr_lines
+        .iter()
+        .rposition(|line| line.contains(SR_SCAN_START))
+        .map_or(&sr_lines[..], |start| &sr_lines[start..]);
+
+    let excerpt: Vec<String> = scan
+        .iter()
+        .filter(|line| !SR_NOISE.iter().any(|noise| line.contains(noise)))
+        .filter_map(|line| {
+            let sr = line.find("[SR]")?;
+            let timestamp = line[..sr].split(',').next().unwrap_or_default().trim();
+            Some(
+                format!("{} {}", timestamp, line[sr..].trim())
+                    .trim()
+                    .to_string(),
+            )
+        })
+        .collect();
+    let skip = excerpt.len().saturating_sub(max_lines);
+    excerpt.into_iter().skip(skip).collect()
+}
+
+/// Gravedad del diagnóstico
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum VerdictLevel {
+    Ok,
+    Warning,
+    Error,
+}
+
+/// Diagnóstico de la reparación con los pasos recomendados
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Verdict {
+    pub level: VerdictLevel,
+    /// Resumen del resultado (puede usar `{code}`)
+    pub summary: I18nKey,
+    /// Código de error al que se refiere el resumen
+    pub code: Option<u32>,
+    /// Pasos recomendados, en orden
+    pub steps: Vec<I18nKey>,
+}
+
+impl Verdict {
+    fn new(level: VerdictLevel, summary: I18nKey, steps: &[I18nKey]) -> Self {
+        Self {
+            level,
+            summary,
+            code: None,
+            steps: steps.to_vec(),
+        }
+    }
+
+    fn with_code(mut self, code: u32) -> Self {
+        self.code = Some(code);
+        self
+    }
+
+    /// Indica si hay que reiniciar para completar la reparación
+    pub fn needs_reboot(&self) -> bool {
+        self.steps.contains(&I18nKey::RepairStepReboot)
+    }
+}
+
+/// Código de error como lo muestra Windows (`0x800F081F` o `740`)
+pub fn format_code(code: u32) -> String {
+    if code > 0xFFFF {
+        format!("0x{:08X}", code)
+    } else {
+        code.to_string()
+    }
+}
+
+/// Diagnóstico de la reparación según los resultados de DISM y SFC
+///
+/// Retorna `None` si DISM todavía no ha terminado. El problema más grave
+/// decide el diagnóstico: un problema de DISM explica a menudo que SFC no
+/// pueda reparar los archivos, así que sus pasos van primero.
+pub fn verdict(stats: &RepairStats) -> Option<Verdict> {
+    use I18nKey::*;
+
+    stats.dism_ok?;
+    let verdict = match (stats.dism, stats.sfc) {
+        (DismResult::NeedsAdmin, _) | (_, SfcResult::NeedsAdmin) => Verdict::new(
+            VerdictLevel::Error,
+            RepairVerdictNeedsAdmin,
+            &[RepairStepRunAsAdmin],
+        ),
+        (DismResult::SourceMissing, _) => Verdict::new(
+            VerdictLevel::Error,
+            RepairVerdictSourceMissing,
+            &[RepairStepUseSource, RepairStepRerun],
+        )
+        .with_code(CBS_E_SOURCE_MISSING),
+        (DismResult::DownloadFailed(code), _) => Verdict::new(
+            VerdictLevel::Error,
+            RepairVerdictDownloadFailed,
+            &[
+                RepairStepCheckConnection,
+                RepairStepUseSource,
+                RepairStepRerun,
+            ],
+        )
+        .with_code(code),
+        (_, SfcResult::Unrepaired) => Verdict::new(
+            VerdictLevel::Error,
+            RepairVerdictUnrepaired,
+            &[
+                RepairStepReboot,
+                RepairStepRerun,
+                RepairStepCheckCbs,
+                RepairStepInPlaceUpgrade,
+            ],
+        ),
+        (_, SfcResult::RebootPending) => Verdict::new(
+            VerdictLevel::Warning,
+            RepairVerdictRebootPending,
+            &[RepairStepReboot, RepairStepRerun],
+        ),
+        (_, SfcResult::CouldNotRun) => Verdict::new(
+            VerdictLevel::Error,
+            RepairVerdictSfcFailed,
+            &[RepairStepReboot, RepairStepSafeMode, RepairStepCheckCbs],
+        ),
+        (DismResult::Failed(code), _) => Verdict::new(
+            VerdictLevel::Error,
+            RepairVerdictDismFailed,
+            &[RepairStepCheckDismLog, RepairStepRerun],
+        )
+        .with_code(code),
+        (DismResult::Repaired, SfcResult::Healthy | SfcResult::Repaired)
+        | (DismResult::Completed, SfcResult::Repaired) => Verdict::new(
+            VerdictLevel::Ok,
+            RepairVerdictRepaired,
+            &[RepairStepReboot, RepairStepRerun],
+        ),
+        (DismResult::Completed, SfcResult::Healthy) => {
+            Verdict::new(VerdictLevel::Ok, RepairVerdictHealthy, &[RepairStepNone])
+        }
+        _ => Verdict::new(
+            VerdictLevel::Warning,
+            RepairVerdictUnknown,
+            &[RepairStepCheckOutput, RepairStepRerun],
+        ),
+    };
+    Some(verdict)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn lines(text: &str) -> Vec<String> {
+        text.lines().map(str::to_string).collect()
+    }
+
+    #[test]
+    fn test_parse_sfc() {
+        assert_eq!(
+            parse_sfc(&lines(
+                "Verification 100% complete.\n\
+                 Windows Resource Protection did not find any integrity violations."
+            )),
+            SfcResult::Healthy
+        );
+        assert_eq!(
+            parse_sfc(&lines(
+                "Protección de recursos de Windows encontró archivos dañados y los reparó correctamente."
+            )),
+            SfcResult::Repaired
+        );
+        assert_eq!(
+            parse_sfc(&lines(
+                "Windows Resource Protection found corrupt files but was\n\
+                 unable to fix some of them."
+            )),
+            SfcResult::Unrepaired
+        );
+        assert_eq!(
+            parse_sfc(&lines(
+                "There is a system repair pending which requires reboot to complete."
+            )),
+            SfcResult::RebootPending
+        );
+        assert_eq!(parse_sfc(&lines("Comprobando...")), SfcResult::Unknown);
+        assert_eq!(SfcResult::Unrepaired.corruption(), Some((true, false)));
+        assert_eq!(SfcResult::Unknown.corruption(), None);
+    }
+
+    #[test]
+    fn test_parse_dism() {
+        let missing = lines(
+            "Error: 0x800f081f\n\
+             The source files could not be found.",
+        );
+        assert_eq!(parse_dism(&missing, Some(1)), DismResult::SourceMissing);
+        // Solo con el código de salida (HRESULT con signo)
+        assert_eq!(
+            parse_dism(&[], Some(0x800F_0906_u32 as i32)),
+            DismResult::DownloadFailed(0x800F_0906)
+        );
+        assert_eq!(
+            parse_dism(&lines("Error: 740"), Some(740)),
+            DismResult::NeedsAdmin
+        );
+        assert_eq!(
+            parse_dism(
+                &lines("The restore operation completed successfully."),
+                Some(0)
+            ),
+            DismResult::Completed
+        );
+        assert_eq!(parse_dism(&[], None), DismResult::Unknown);
+    }
+
+    #[test]
+    fn test_sr_excerpt_keeps_the_last_scan() {
+        let log = "\
+2024-05-01 09:00:00, Info  CSI  00000001 [SR] Beginning Verify and Repair transaction
+2024-05-01 09:00:05, Info  CSI  00000002 [SR] Repairing corrupted file \\??\\C:\\old.dll
+2024-05-01 10:00:00, Info  CBS  Session: 123 initialized
+2024-05-01 10:12:33, Info  CSI  00000008 [SR] Beginning Verify and Repair transaction
+2024-05-01 10:12:34, Info  CSI  00000009 [SR] Verifying 100 components
+2024-05-01 10:14:00, Info  CSI  0000000a [SR] Cannot repair member file [l:8]'kernel.dll'
+2024-05-01 10:15:00, Info  CSI  0000000b [SR] Verify complete
+2024-05-01 10:15:01, Info  CSI  0000000c [SR] Repair complete";
+
+        let excerpt = sr_excerpt(log, CBS_EXCERPT_LINES);
+        assert_eq!(
+            excerpt,
+            vec![
+                "2024-05-01 10:12:33 [SR] Beginning Verify and Repair transaction",
+                "2024-05-01 10:14:00 [SR] Cannot repair member file [l:8]'kernel.dll'",
+                "2024-05-01 10:15:01 [SR] Repair complete",
+            ]
+        );
+        assert_eq!(
+            sr_excerpt(log, 1),
+            vec!["2024-05-01 10:15:01 [SR] Repair complete"]
+        );
+        assert!(sr_excerpt("sin líneas de SFC", 10).is_empty());
+    }
+
+    #[test]
+    fn test_verdict() {
+        let stats = |dism, sfc| RepairStats {
+            dism_ok: Some(true),
+            dism,
+            sfc,
+            ..RepairStats::default()
+        };
+        let summary = |stats: RepairStats| verdict(&stats).map(|verdict| verdict.summary);
+
+        assert_eq!(verdict(&RepairStats::default()), None);
+        assert_eq!(
+            summary(stats(DismResult::Completed, SfcResult::Healthy)),
+            Some(I18nKey::RepairVerdictHealthy)
+        );
+        assert_eq!(
+            summary(stats(DismResult::Completed, SfcResult::Repaired)),
+            Some(I18nKey::RepairVerdictRepaired)
+        );
+        // El problema de DISM explica que SFC no pueda reparar
+        let missing = verdict(&stats(DismResult::SourceMissing, SfcResult::Unrepaired)).unwrap();
+        assert_eq!(missing.summary, I18nKey::RepairVerdictSourceMissing);
+        assert_eq!(missing.level, VerdictLevel::Error);
+        assert_eq!(missing.code.map(format_code).as_deref(), Some("0x800F081F"));
+        assert_eq!(
+            summary(stats(DismResult::Unknown, SfcResult::Unknown)),
+            Some(I18nKey::RepairVerdictUnknown)
+        );
+    }
+}
@@ -0,0 +1,69 @@
+//! Diseño compacto para terminales pequeños
+//!
+//! Por debajo de unas 80x24 celdas el banner, los márgenes y las tarjetas de
+//! estadísticas no caben y los widgets se solapan o se cortan. En ese caso se
+//! usa un diseño compacto: sin banner ni márgenes, con las descripciones del
+//! menú abreviadas y las estadísticas apiladas.
+
+use ratatui::layout::Rect;
+use unicode_width::{UnicodeWidthChar, UnicodeWidthStr};
+
+/// Ancho mínimo para el diseño completo
+pub const COMPACT_WIDTH: u16 = 80;
+
+/// Alto mínimo para el diseño completo
+pub const COMPACT_HEIGHT: u16 = 24;
+
+/// Indica si el área es demasiado pequeña para el diseño completo
+pub fn is_compact(area: Rect) -> bool {
+    area.width < COMPACT_WIDTH || area.height < COMPACT_HEIGHT
+}
+
+/// Margen alrededor del contenido de cada vista
+pub fn margin(compact: bool) -> u16 {
+    if compact { 0 } else { 2 }
+}
+
+/// Recorta un texto para que ocupe como mucho `max_width` columnas,
+/// terminándolo en `…` si no cabe
+pub fn truncate(text: &str, max_width: usize) -> String {
+    if text.width() <= max_width {
+        return text.to_string();
+    }
+    if max_width == 0 {
+        return String::new();
+    }
+
+    let mut result = String::new();
+    let mut width = 0;
+    for c in text.chars() {
+        let char_width = c.width().unwrap_or(0);
+        if width + char_width + 1 > max_width {
+            break;
+        }
+        result.push(c);
+        width += char_width;
+    }
+    format!("{}…", result.trim_end())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_is_compact() {
+        assert!(!is_compact(Rect::new(0, 0, 120, 40)));
+        assert!(!is_compact(Rect::new(0, 0, 80, 24)));
+        assert!(is_compact(Rect::new(0, 0, 79, 40)));
+        assert!(is_compact(Rect::new(0, 0, 120, 23)));
+    }
+
+    #[test]
+    fn test_truncate() {
+        assert_eq!(truncate("Limpiar archivos", 20), "Limpiar archivos");
+        assert_eq!(truncate("Limpiar archivos", 10), "Limpiar a…");
+        assert_eq!(truncate("Año nuevo", 4), "Año…");
+        assert_eq!(truncate("abc", 0), "");
+    }
+}
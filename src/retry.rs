@@ -0,0 +1,149 @@
+//! Reintento de los elementos fallidos de una operación
+//!
+//! Las limpiezas anotan los archivos que no pudieron eliminar y el worker de
+//! pasos los comandos que terminaron con error. Al terminar la operación,
+//! `I` vuelve a intentar solo esos elementos en un worker en segundo plano,
+//! por ejemplo después de cerrar el navegador que bloqueaba su caché. Las
+//! rutas protegidas no se anotan: volverían a fallar.
+
+use crate::app::App;
+use crate::executor::{self, WorkerStep};
+use crate::i18n::{I18n, I18nKey};
+use crate::journal;
+use crate::log_info;
+use crate::types::OperationState;
+use std::path::PathBuf;
+
+/// Elemento de una operación que falló y se puede volver a intentar
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum FailedItem {
+    /// Paso de un worker de comandos que terminó con error
+    Step(WorkerStep),
+    /// Archivo o carpeta que no se pudo eliminar
+    Path(PathBuf),
+}
+
+impl FailedItem {
+    /// Descripción del elemento para los logs y el diario
+    pub fn describe(&self, i18n: &I18n) -> String {
+        match self {
+            Self::Step(step) => i18n.t(step.description).to_string(),
+            Self::Path(path) => path.display().to_string(),
+        }
+    }
+}
+
+/// Indica cómo reintentar los elementos fallidos, si los hay
+pub fn log_hint(app: &mut App) {
+    let count = app.failed_items.len();
+    if count > 0 {
+        log_info!(
+            app,
+            "💡 {}",
+            app.t_args(I18nKey::RetryHint, &[("count", &count.to_string())])
+        );
+    }
+}
+
+/// Vuelve a intentar los elementos fallidos de la operación de la vista
+pub fn execute_retry(app: &mut App) {
+    let items = std::mem::take(&mut app.failed_items);
+    if items.is_empty() {
+        return;
+    }
+
+    app.operation_state = OperationState::Starting;
+    log_info!(app, "");
+    log_info!(
+        app,
+        "🔁 {}",
+        app.t_args(
+            I18nKey::RetryStarting,
+            &[("count", &items.len().to_string())]
+        )
+    );
+
+    journal::begin(
+        app.current_view,
+        items.iter().map(|item| item.describe(&app.i18n)).collect(),
+    );
+    let config = app.config.clone();
+    let i18n = app.i18n;
+    app.start_job(
+        app.current_view,
+        false,
+        Box::new(move || executor::spawn_retry_worker(items, config, i18n)),
+    );
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::config::Config;
+    use crate::executor::StepAction;
+    use crate::i18n::Language;
+    use crate::types::WorkerMessage;
+    use std::fs;
+    use std::time::Duration;
+
+    #[test]
+    fn test_retry_worker_deletes_only_the_failed_paths() {
+        let root = std::env::temp_dir().join("win_opt_test_retry_paths");
+        let _ = fs::remove_dir_all(&root);
+        fs::create_dir_all(&root).unwrap();
+        let failed = root.join("locked.tmp");
+        let kept = root.join("other.tmp");
+        fs::write(&failed, b"x").unwrap();
+        fs::write(&kept, b"y").unwrap();
+
+        let items = vec![
+            FailedItem::Path(failed.clone()),
+            FailedItem::Path(root.join("gone.tmp")),
+        ];
+        let handle =
+            executor::spawn_retry_worker(items, Config::default(), I18n::new(Language::Spanish));
+        let messages: Vec<WorkerMessage> = handle.receiver.iter().collect();
+
+        assert!(!failed.exists());
+        assert!(kept.exists());
+        // El que vuelve a fallar se anota otra vez
+        let still_failed: Vec<_> = messages
+            .iter()
+            .filter_map(|message| match message {
+                WorkerMessage::ItemFailed(item) => Some(item.clone()),
+                _ => None,
+            })
+            .collect();
+        assert_eq!(still_failed, [FailedItem::Path(root.join("gone.tmp"))]);
+        assert!(
+            messages.iter().any(|message| matches!(
+                message,
+                WorkerMessage::StateChange(OperationState::Failed)
+            ))
+        );
+
+        fs::remove_dir_all(&root).unwrap();
+    }
+
+    #[test]
+    fn test_failed_items_describe_themselves() {
+        let step = WorkerStep {
+            description: I18nKey::FontCacheStartService,
+            action: StepAction::Command {
+                command: "net",
+                args: &["start", "FontCache"],
+            },
+            timeout: Duration::from_secs(60),
+        };
+        let i18n = I18n::new(Language::English);
+        assert_eq!(
+            FailedItem::Step(step).describe(&i18n),
+            "Starting the FontCache service"
+        );
+        let path = PathBuf::from("C:\\Temp\\locked.tmp");
+        assert_eq!(
+            FailedItem::Path(path).describe(&i18n),
+            "C:\\Temp\\locked.tmp"
+        );
+    }
+}
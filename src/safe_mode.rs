@@ -0,0 +1,116 @@
+//! Detección del modo seguro de Windows
+//!
+//! En modo seguro Windows arranca con un conjunto mínimo de controladores y
+//! servicios: Windows Update, Defender, el firewall, la búsqueda, la caché
+//! de fuentes o la configuración automática de Wi-Fi no se inician, y sin
+//! funciones de red tampoco funcionan los comandos de red. Las operaciones
+//! que dependen de ellos se marcan como no disponibles en el menú en lugar
+//! de fallar a medias.
+
+use crate::i18n::I18nKey;
+use crate::types::View;
+
+/// Modo en que arrancó Windows
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum BootMode {
+    /// Arranque normal
+    #[default]
+    Normal,
+    /// Modo seguro
+    Safe,
+    /// Modo seguro con funciones de red
+    SafeWithNetworking,
+}
+
+impl BootMode {
+    /// Modo de arranque actual del sistema
+    pub fn detect() -> BootMode {
+        Self::from_metric(clean_boot_metric())
+    }
+
+    /// Interpreta el valor de `GetSystemMetrics(SM_CLEANBOOT)`
+    fn from_metric(value: i32) -> BootMode {
+        match value {
+            1 => BootMode::Safe,
+            2 => BootMode::SafeWithNetworking,
+            _ => BootMode::Normal,
+        }
+    }
+
+    /// Indica si Windows arrancó en modo seguro
+    pub fn is_safe(self) -> bool {
+        self != BootMode::Normal
+    }
+
+    /// Clave de traducción del indicador de la barra de estado
+    pub fn label_key(self) -> Option<I18nKey> {
+        match self {
+            BootMode::Normal => None,
+            BootMode::Safe => Some(I18nKey::StatusSafeMode),
+            BootMode::SafeWithNetworking => Some(I18nKey::StatusSafeModeNetworking),
+        }
+    }
+
+    /// Indica si la operación de la vista puede ejecutarse en este modo
+    pub fn allows(self, view: View) -> bool {
+        match self {
+            BootMode::Normal => true,
+            BootMode::Safe | BootMode::SafeWithNetworking => match view {
+                // Dependen de servicios que no se inician en modo seguro
+                View::WindowsUpdate
+                | View::Optimize
+                | View::Privacy
+                | View::FontCache
+                | View::SearchIndex
+                | View::Defender
+                | View::Firewall
+                | View::Wifi
+                | View::Tasks => false,
+                // Necesitan la pila de red
+                View::Network | View::Adapters | View::Proxy => {
+                    self == BootMode::SafeWithNetworking
+                }
+                _ => true,
+            },
+        }
+    }
+}
+
+/// Valor de `SM_CLEANBOOT`: 0 normal, 1 modo seguro, 2 con funciones de red
+#[cfg(windows)]
+fn clean_boot_metric() -> i32 {
+    use windows::Win32::UI::WindowsAndMessaging::{GetSystemMetrics, SM_CLEANBOOT};
+
+    // SAFETY: la función solo lee una métrica del sistema
+    unsafe { GetSystemMetrics(SM_CLEANBOOT) }
+}
+
+#[cfg(not(windows))]
+fn clean_boot_metric() -> i32 {
+    0
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_from_metric() {
+        assert_eq!(BootMode::from_metric(0), BootMode::Normal);
+        assert_eq!(BootMode::from_metric(1), BootMode::Safe);
+        assert_eq!(BootMode::from_metric(2), BootMode::SafeWithNetworking);
+        assert!(!BootMode::Normal.is_safe());
+        assert!(BootMode::SafeWithNetworking.is_safe());
+    }
+
+    #[test]
+    fn test_allowed_views() {
+        assert!(BootMode::Normal.allows(View::WindowsUpdate));
+        assert!(BootMode::Safe.allows(View::Repair));
+        assert!(BootMode::Safe.allows(View::Clean));
+        assert!(!BootMode::Safe.allows(View::Defender));
+        assert!(!BootMode::Safe.allows(View::Network));
+        assert!(BootMode::SafeWithNetworking.allows(View::Network));
+        assert!(!BootMode::SafeWithNetworking.allows(View::SearchIndex));
+    }
+}
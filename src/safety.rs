@@ -0,0 +1,321 @@
+//! Rutas protegidas frente a borrados
+//!
+//! Toda operación que elimina, mueve a cuarentena o envía a la Papelera un
+//! elemento consulta antes esta lista. Un error en una ruta calculada (una
+//! variable de entorno vacía, un `..` de más) no debe poder llevarse por
+//! delante la carpeta de Windows, un perfil de usuario o una unidad entera.
+//!
+//! Hay dos tipos de protección: las carpetas críticas del sistema
+//! (`System32`, `SysWOW64`, `WinSxS`...) no se pueden tocar ni ellas ni nada
+//! de su interior; las carpetas raíz (unidades, la carpeta de Windows, los
+//! perfiles, Archivos de programa) no se pueden eliminar, pero sí limpiar su
+//! contenido, como `C:\Windows\Temp`.
+//!
+//! Los únicos archivos de las carpetas críticas que se pueden eliminar son
+//! los de [`REBUILDABLE_SYSTEM_FILES`], cachés que Windows regenera.
+
+use crate::error::{Result, WinOptError};
+use crate::platform;
+use std::path::{Component, Path, PathBuf};
+
+/// Subcarpetas de Windows protegidas junto con todo su contenido
+const CRITICAL_WINDOWS_DIRS: [&str; 6] = [
+    "System32",
+    "SysWOW64",
+    "WinSxS",
+    "Boot",
+    "Fonts",
+    "servicing",
+];
+
+/// Archivos de las carpetas críticas que las reconstrucciones de cachés
+/// pueden eliminar (carpeta de Windows y nombre)
+const REBUILDABLE_SYSTEM_FILES: [(&str, &str); 1] = [("System32", "FNTCACHE.DAT")];
+
+/// Carpetas del perfil del usuario que se limpian por dentro pero nunca se
+/// eliminan
+const PROFILE_DIRS: [&str; 7] = [
+    "AppData",
+    "AppData/Local",
+    "AppData/Roaming",
+    "AppData/LocalLow",
+    "Desktop",
+    "Documents",
+    "Downloads",
+];
+
+/// Rutas que ninguna operación puede eliminar
+#[derive(Debug, Clone)]
+pub struct Protected {
+    /// Rutas que no se pueden eliminar, aunque sí su contenido
+    exact: Vec<PathBuf>,
+    /// Rutas que no se pueden eliminar ni ellas ni nada de su interior
+    subtree: Vec<PathBuf>,
+    /// Carpeta que contiene los perfiles; ninguno de ellos se puede eliminar
+    profiles: Option<PathBuf>,
+    /// Archivos de las carpetas críticas que sí se pueden eliminar
+    allowed: Vec<PathBuf>,
+}
+
+impl Protected {
+    /// Lista de rutas protegidas a partir de las carpetas del sistema
+    pub fn new(windows: &Path, profile: &Path, program_dirs: &[PathBuf]) -> Protected {
+        let mut exact = vec![windows.to_path_buf(), profile.to_path_buf()];
+        exact.extend(PROFILE_DIRS.iter().map(|dir| profile.join(dir)));
+        exact.extend(program_dirs.iter().cloned());
+        Protected {
+            exact: exact.iter().map(|path| normalize(path)).collect(),
+            subtree: CRITICAL_WINDOWS_DIRS
+                .iter()
+                .map(|dir| normalize(&windows.join(dir)))
+                .collect(),
+            profiles: profile.parent().map(normalize),
+            allowed: REBUILDABLE_SYSTEM_FILES
+                .iter()
+                .map(|(dir, file)| normalize(&windows.join(dir).join(file)))
+                .collect(),
+        }
+    }
+
+    /// Lista de rutas protegidas de este equipo
+    ///
+    /// En modo simulación la carpeta de Windows y el perfil son los del
+    /// sandbox, que no tiene Archivos de programa.
+    pub fn system() -> Protected {
+        let program_dirs: Vec<PathBuf> = if crate::simulate::is_enabled() {
+            Vec::new()
+        } else {
+            ["ProgramFiles", "ProgramFiles(x86)", "ProgramData"]
+                .into_iter()
+                .filter_map(std::env::var_os)
+                .map(PathBuf::from)
+                .collect()
+        };
+        Protected::new(
+            &platform::windows_dir(),
+            &platform::user_profile(),
+            &program_dirs,
+        )
+    }
+
+    /// Comprueba que la ruta se puede eliminar
+    ///
+    /// Retorna `WinOptError::InvalidPath` si es una ruta protegida o está
+    /// dentro de una carpeta crítica.
+    pub fn check(&self, path: &Path) -> Result<()> {
+        let path = normalize(path);
+        if self.allowed.iter().any(|allowed| same_path(&path, allowed)) {
+            return Ok(());
+        }
+        let protected = path.parent().is_none()
+            || self.exact.iter().any(|exact| same_path(&path, exact))
+            || self.subtree.iter().any(|root| is_within(&path, root))
+            || self
+                .profiles
+                .as_ref()
+                .is_some_and(|profiles| same_path(&path, profiles) || is_child(&path, profiles));
+        if protected {
+            Err(WinOptError::InvalidPath(format!(
+                "{} es una ruta protegida del sistema",
+                path.display()
+            )))
+        } else {
+            Ok(())
+        }
+    }
+}
+
+/// Comprueba que la ruta se puede eliminar según la lista de este equipo
+pub fn check_removable(path: &Path) -> Result<()> {
+    Protected::system().check(path)
+}
+
+/// Ruta absoluta sin componentes `.` ni `..`
+///
+/// No se resuelven enlaces: los enlaces se eliminan sin seguirlos, así que lo
+/// que importa es la ruta del propio enlace.
+fn normalize(path: &Path) -> PathBuf {
+    let absolute = std::path::absolute(path).unwrap_or_else(|_| path.to_path_buf());
+    let mut normalized = PathBuf::new();
+    for component in absolute.components() {
+        match component {
+            Component::CurDir => {}
+            Component::ParentDir => {
+                normalized.pop();
+            }
+            other => normalized.push(other),
+        }
+    }
+    normalized
+}
+
+/// Compara dos componentes sin distinguir mayúsculas
+///
+/// Windows no las distingue; en los demás sistemas solo hace la comprobación
+/// más estricta.
+fn same_component(a: Component, b: Component) -> bool {
+    a.as_os_str()
+        .to_string_lossy()
+        .eq_ignore_ascii_case(&b.as_os_str().to_string_lossy())
+}
+
+/// Indica si dos rutas normalizadas son la misma
+fn same_path(path: &Path, other: &Path) -> bool {
+    path.components().count() == other.components().count() && is_within(path, other)
+}
+
+/// Indica si `path` es `root` o está dentro de ella, sin distinguir
+/// mayúsculas
+pub(crate) fn is_within(path: &Path, root: &Path) -> bool {
+    let mut components = path.components();
+    root.components().all(|component| {
+        components
+            .next()
+            .is_some_and(|own| same_component(own, component))
+    })
+}
+
+/// Indica si `path` está directamente dentro de `dir`
+fn is_child(path: &Path, dir: &Path) -> bool {
+    path.parent().is_some_and(|parent| same_path(parent, dir))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Raíz de la unidad del sistema en la plataforma de las pruebas
+    fn root() -> PathBuf {
+        PathBuf::from(if cfg!(windows) { "C:\\" } else { "/" })
+    }
+
+    fn protected() -> Protected {
+        let root = root();
+        Protected::new(
+            &root.join("Windows"),
+            &root.join("Users").join("Demo"),
+            &[root.join("Program Files"), root.join("ProgramData")],
+        )
+    }
+
+    fn is_protected(path: &Path) -> bool {
+        matches!(protected().check(path), Err(WinOptError::InvalidPath(_)))
+    }
+
+    #[test]
+    fn test_drive_roots_are_protected() {
+        assert!(is_protected(&root()));
+        #[cfg(windows)]
+        {
+            assert!(is_protected(Path::new("D:\\")));
+            assert!(is_protected(Path::new("C:\\Windows\\..")));
+        }
+    }
+
+    #[test]
+    fn test_critical_windows_dirs_and_contents_are_protected() {
+        let windows = root().join("Windows");
+        for dir in CRITICAL_WINDOWS_DIRS {
+            assert!(is_protected(&windows.join(dir)), "{dir}");
+        }
+        assert!(is_protected(&windows.join("System32").join("drivers")));
+        assert!(is_protected(
+            &windows.join("System32").join("config").join("SAM")
+        ));
+        assert!(is_protected(&windows.join("WinSxS").join("Temp")));
+    }
+
+    #[test]
+    fn test_only_rebuildable_system_files_are_allowed() {
+        let system32 = root().join("Windows").join("System32");
+        assert!(!is_protected(&system32.join("FNTCACHE.DAT")));
+        assert!(!is_protected(&system32.join("fntcache.dat")));
+        assert!(is_protected(&system32.join("FNTCACHE.DAT.bak")));
+        assert!(is_protected(&system32.join("drivers").join("FNTCACHE.DAT")));
+    }
+
+    #[test]
+    fn test_windows_dir_is_protected_but_its_temp_folders_are_not() {
+        let windows = root().join("Windows");
+        assert!(is_protected(&windows));
+        assert!(!is_protected(&windows.join("Temp")));
+        assert!(!is_protected(&windows.join("Temp").join("MpCmdRun.log")));
+        assert!(!is_protected(
+            &windows.join("Prefetch").join("CODE.EXE-1.pf")
+        ));
+        assert!(!is_protected(&windows.join("Logs").join("CBS")));
+    }
+
+    #[test]
+    fn test_profile_roots_are_protected() {
+        let users = root().join("Users");
+        assert!(is_protected(&users));
+        assert!(is_protected(&users.join("Demo")));
+        assert!(is_protected(&users.join("Ana")));
+        assert!(is_protected(&users.join("Public")));
+        for dir in PROFILE_DIRS {
+            assert!(is_protected(&users.join("Demo").join(dir)), "{dir}");
+        }
+    }
+
+    #[test]
+    fn test_profile_contents_are_not_protected() {
+        let demo = root().join("Users").join("Demo");
+        let temp = demo.join("AppData").join("Local").join("Temp");
+        assert!(!is_protected(&temp));
+        assert!(!is_protected(&temp.join("setup.log")));
+        assert!(!is_protected(&demo.join("Downloads").join("video.mp4")));
+        assert!(!is_protected(
+            &root()
+                .join("Users")
+                .join("Ana")
+                .join("AppData")
+                .join("Local")
+                .join("Temp")
+        ));
+    }
+
+    #[test]
+    fn test_program_dirs_are_protected() {
+        assert!(is_protected(&root().join("Program Files")));
+        assert!(is_protected(&root().join("ProgramData")));
+        assert!(!is_protected(
+            &root().join("ProgramData").join("App").join("cache.tmp")
+        ));
+    }
+
+    #[test]
+    fn test_paths_are_normalized_before_checking() {
+        let temp = root().join("Windows").join("Temp");
+        // Salir de una carpeta permitida hacia una protegida
+        assert!(is_protected(&temp.join("..").join("System32")));
+        assert!(is_protected(&temp.join("..")));
+        assert!(is_protected(&temp.join(".").join("..").join("..")));
+        assert!(!is_protected(&temp.join(".").join("old.tmp")));
+    }
+
+    #[test]
+    fn test_comparison_ignores_case() {
+        let windows = root().join("WINDOWS");
+        assert!(is_protected(&windows));
+        assert!(is_protected(&windows.join("system32").join("Drivers")));
+        assert!(is_protected(
+            &root().join("users").join("DEMO").join("appdata")
+        ));
+        assert!(!is_protected(&windows.join("TEMP")));
+    }
+
+    #[test]
+    fn test_similar_names_are_not_protected() {
+        let windows = root().join("Windows");
+        assert!(!is_protected(&windows.join("System32_backup")));
+        assert!(!is_protected(&root().join("Windows.old")));
+        assert!(!is_protected(&root().join("Users2")));
+    }
+
+    #[test]
+    fn test_error_names_the_path() {
+        let error = protected().check(&root().join("Windows")).unwrap_err();
+        assert!(error.to_string().contains("Windows"));
+    }
+}
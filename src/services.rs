@@ -0,0 +1,602 @@
+//! Copia de seguridad y restauración de los servicios que deshabilita win_opt
+//!
+//! Los tipos de inicio se leen y cambian con la API del Administrador de
+//! control de servicios, sin lanzar `sc`, así que los errores llegan como
+//! `ControlError` y no dependen del idioma del sistema. Antes de deshabilitar
+//! un servicio se anota su tipo de inicio en `service_backup.toml`, en la
+//! carpeta de configuración. Si el servicio ya estaba anotado se conserva el
+//! valor original, de modo que varias ejecuciones no pisan el estado previo a
+//! la primera. "Restaurar servicios" devuelve cada servicio anotado a su
+//! valor predeterminado de Windows (o al anotado, si no es uno conocido) y
+//! borra la copia.
+
+use crate::config::Config;
+use crate::error::ControlError;
+use crate::i18n::I18nKey;
+use crate::optimization;
+use crate::simulate;
+use crate::types::OperationState;
+use crate::utils::is_admin;
+use crate::{log_debug, log_error, log_info, log_warn};
+use serde::{Deserialize, Serialize};
+use std::fs;
+use std::io;
+use std::path::PathBuf;
+
+/// Tipo de inicio de un servicio
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum StartType {
+    Boot,
+    System,
+    Auto,
+    DelayedAuto,
+    Demand,
+    Disabled,
+}
+
+impl StartType {
+    /// Nombre del tipo de inicio, el mismo que usa `sc config start=`
+    pub fn sc_value(self) -> &'static str {
+        match self {
+            StartType::Boot => "boot",
+            StartType::System => "system",
+            StartType::Auto => "auto",
+            StartType::DelayedAuto => "delayed-auto",
+            StartType::Demand => "demand",
+            StartType::Disabled => "disabled",
+        }
+    }
+
+    /// Tipo de inicio de `dwStartType` y de la marca de inicio retrasado
+    pub fn from_code(code: u32, delayed: bool) -> Option<StartType> {
+        match code {
+            0 => Some(StartType::Boot),
+            1 => Some(StartType::System),
+            2 if delayed => Some(StartType::DelayedAuto),
+            2 => Some(StartType::Auto),
+            3 => Some(StartType::Demand),
+            4 => Some(StartType::Disabled),
+            _ => None,
+        }
+    }
+
+    /// Valor de `dwStartType` (el inicio retrasado es un inicio automático)
+    pub fn code(self) -> u32 {
+        match self {
+            StartType::Boot => 0,
+            StartType::System => 1,
+            StartType::Auto | StartType::DelayedAuto => 2,
+            StartType::Demand => 3,
+            StartType::Disabled => 4,
+        }
+    }
+}
+
+/// Tipo de inicio predeterminado de Windows de los servicios que se
+/// deshabilitan
+const WINDOWS_DEFAULTS: &[(&str, StartType)] = &[
+    ("DiagTrack", StartType::Auto),
+    ("SysMain", StartType::Auto),
+    ("dmwappushservice", StartType::Demand),
+    ("WerSvc", StartType::Demand),
+];
+
+/// Tipo de inicio predeterminado de Windows de un servicio conocido
+fn windows_default(service: &str) -> Option<StartType> {
+    WINDOWS_DEFAULTS
+        .iter()
+        .find(|(name, _)| name.eq_ignore_ascii_case(service))
+        .map(|(_, start)| *start)
+}
+
+/// Servicio instalado en el equipo
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct InstalledService {
+    pub name: String,
+    pub display_name: String,
+    /// Programa y argumentos del servicio (`lpBinaryPathName`)
+    pub binary: String,
+    pub start: StartType,
+}
+
+/// Servicio anotado antes de modificarlo
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct BackedUpService {
+    pub name: String,
+    /// Tipo de inicio anterior (`None` si no se pudo consultar)
+    pub previous: Option<StartType>,
+}
+
+/// Servicios modificados desde la última restauración
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ServiceBackup {
+    pub services: Vec<BackedUpService>,
+}
+
+impl ServiceBackup {
+    /// Anota un servicio si aún no lo estaba
+    ///
+    /// Retorna `true` si se añadió.
+    fn add(&mut self, name: &str, previous: impl FnOnce() -> Option<StartType>) -> bool {
+        if self
+            .services
+            .iter()
+            .any(|service| service.name.eq_ignore_ascii_case(name))
+        {
+            return false;
+        }
+        self.services.push(BackedUpService {
+            name: name.to_string(),
+            previous: previous(),
+        });
+        true
+    }
+}
+
+/// Ruta del archivo de copia de seguridad de servicios
+fn backup_file() -> io::Result<PathBuf> {
+    Ok(Config::get_config_dir()?.join("service_backup.toml"))
+}
+
+/// Carga la copia de seguridad de servicios, si existe
+pub fn load_backup() -> Option<ServiceBackup> {
+    let contents = fs::read_to_string(backup_file().ok()?).ok()?;
+    toml::from_str(&contents).ok()
+}
+
+/// Guarda la copia de seguridad de servicios
+fn save_backup(backup: &ServiceBackup) -> io::Result<()> {
+    let toml_string = toml::to_string_pretty(backup).map_err(|e| {
+        io::Error::new(
+            io::ErrorKind::InvalidData,
+            format!("TOML serialize error: {}", e),
+        )
+    })?;
+    fs::write(backup_file()?, toml_string)
+}
+
+/// Anota el tipo de inicio actual del servicio antes de modificarlo
+///
+/// Si la copia no se puede guardar, el servicio no debe modificarse.
+pub fn record(service: &str) -> io::Result<()> {
+    let mut backup = load_backup().unwrap_or_default();
+    if backup.add(service, || query_start_type(service).ok()) {
+        save_backup(&backup)?;
+    }
+    Ok(())
+}
+
+/// Consulta el tipo de inicio de un servicio
+///
+/// En modo simulación todos los servicios tienen inicio automático.
+pub fn query_start_type(service: &str) -> Result<StartType, ControlError> {
+    if simulate::is_enabled() {
+        return Ok(StartType::Auto);
+    }
+    scm::query_start_type(service)
+}
+
+/// Cambia el tipo de inicio de un servicio
+///
+/// En modo simulación no se modifica nada.
+pub fn set_start_type(service: &str, start: StartType) -> Result<(), ControlError> {
+    if simulate::is_enabled() {
+        return Ok(());
+    }
+    scm::set_start_type(service, start)
+}
+
+/// Servicios Win32 instalados, con su programa y tipo de inicio
+///
+/// Los servicios cuya configuración no se puede leer se omiten. En modo
+/// simulación se retornan servicios de ejemplo.
+pub fn installed() -> Result<Vec<InstalledService>, ControlError> {
+    if simulate::is_enabled() {
+        return Ok(simulated());
+    }
+    scm::installed()
+}
+
+/// Servicios de ejemplo del modo simulación
+fn simulated() -> Vec<InstalledService> {
+    [
+        (
+            "AdobeARMservice",
+            "Adobe Acrobat Update Service",
+            "\"C:\\Program Files (x86)\\Common Files\\Adobe\\ARM\\1.0\\armsvc.exe\"",
+            StartType::Auto,
+        ),
+        (
+            "GoogleUpdaterService130.0.6723.59",
+            "Google Updater Service (GoogleUpdater 130.0.6723.59)",
+            "\"C:\\Program Files (x86)\\Google\\GoogleUpdater\\130.0.6723.59\\updater.exe\" --system --windows-service",
+            StartType::DelayedAuto,
+        ),
+        (
+            "Steam Client Service",
+            "Steam Client Service",
+            "\"C:\\Program Files (x86)\\Common Files\\Steam\\steamservice.exe\" /RunAsService",
+            StartType::Demand,
+        ),
+        (
+            "TeamViewer",
+            "TeamViewer",
+            "\"C:\\Program Files\\TeamViewer\\TeamViewer_Service.exe\"",
+            StartType::Auto,
+        ),
+    ]
+    .into_iter()
+    .map(|(name, display_name, binary, start)| InstalledService {
+        name: name.to_string(),
+        display_name: display_name.to_string(),
+        binary: binary.to_string(),
+        start,
+    })
+    .collect()
+}
+
+/// Acceso al Administrador de control de servicios
+#[cfg(windows)]
+mod scm {
+    use super::{InstalledService, StartType};
+    use crate::error::ControlError;
+    use windows::Win32::Foundation::ERROR_MORE_DATA;
+    use windows::Win32::System::Services::{
+        ChangeServiceConfig2W, ChangeServiceConfigW, CloseServiceHandle,
+        ENUM_SERVICE_STATUS_PROCESSW, ENUM_SERVICE_TYPE, EnumServicesStatusExW, OpenSCManagerW,
+        OpenServiceW, QUERY_SERVICE_CONFIGW, QueryServiceConfig2W, QueryServiceConfigW,
+        SC_ENUM_PROCESS_INFO, SC_HANDLE, SC_MANAGER_CONNECT, SC_MANAGER_ENUMERATE_SERVICE,
+        SERVICE_CHANGE_CONFIG, SERVICE_CONFIG_DELAYED_AUTO_START_INFO,
+        SERVICE_DELAYED_AUTO_START_INFO, SERVICE_ERROR, SERVICE_NO_CHANGE, SERVICE_QUERY_CONFIG,
+        SERVICE_START_TYPE, SERVICE_STATE_ALL, SERVICE_WIN32,
+    };
+    use windows::core::{HSTRING, PCWSTR};
+
+    /// Handle del Administrador o de un servicio que se cierra al soltarlo
+    struct Handle(SC_HANDLE);
+
+    impl Drop for Handle {
+        fn drop(&mut self) {
+            // SAFETY: el handle lo abrió OpenSCManagerW u OpenServiceW y solo
+            // se cierra aquí
+            unsafe {
+                let _ = CloseServiceHandle(self.0);
+            }
+        }
+    }
+
+    /// Abre el Administrador del equipo local con los permisos indicados
+    fn manager(access: u32) -> Result<Handle, ControlError> {
+        // SAFETY: los nombres nulos abren la base de datos activa del equipo
+        // local
+        unsafe {
+            Ok(Handle(OpenSCManagerW(
+                PCWSTR::null(),
+                PCWSTR::null(),
+                access,
+            )?))
+        }
+    }
+
+    /// Abre un servicio con los permisos indicados
+    ///
+    /// Retorna también el handle del Administrador, que debe vivir más que el
+    /// del servicio (los campos de la tupla se sueltan en orden).
+    fn open(service: &str, access: u32) -> Result<(Handle, Handle), ControlError> {
+        let manager = manager(SC_MANAGER_CONNECT)?;
+        // SAFETY: el nombre del servicio es un HSTRING terminado en nulo que
+        // vive durante la llamada
+        let handle = unsafe { OpenServiceW(manager.0, &HSTRING::from(service), access)? };
+        Ok((Handle(handle), manager))
+    }
+
+    pub(super) fn query_start_type(service: &str) -> Result<StartType, ControlError> {
+        let (service, _manager) = open(service, SERVICE_QUERY_CONFIG)?;
+        query_config(&service).map(|(start, _)| start)
+    }
+
+    /// Tipo de inicio y programa de un servicio abierto con
+    /// `SERVICE_QUERY_CONFIG`
+    fn query_config(service: &Handle) -> Result<(StartType, String), ControlError> {
+        // SAFETY: la primera llamada solo pide el tamaño; el búfer de `u64`
+        // tiene ese tamaño y la alineación de QUERY_SERVICE_CONFIGW, sus
+        // cadenas apuntan dentro del búfer, y `delayed` es un
+        // SERVICE_DELAYED_AUTO_START_INFO local
+        unsafe {
+            let mut needed = 0u32;
+            let _ = QueryServiceConfigW(service.0, None, 0, &mut needed);
+            let mut buffer = vec![0u64; (needed as usize).div_ceil(8)];
+            let config = buffer.as_mut_ptr().cast::<QUERY_SERVICE_CONFIGW>();
+            QueryServiceConfigW(service.0, Some(config), needed, &mut needed)?;
+            let code = (*config).dwStartType.0;
+            let binary = (*config).lpBinaryPathName.to_string().unwrap_or_default();
+
+            let mut delayed = SERVICE_DELAYED_AUTO_START_INFO::default();
+            let bytes = std::slice::from_raw_parts_mut(
+                (&raw mut delayed).cast::<u8>(),
+                size_of::<SERVICE_DELAYED_AUTO_START_INFO>(),
+            );
+            let is_delayed = QueryServiceConfig2W(
+                service.0,
+                SERVICE_CONFIG_DELAYED_AUTO_START_INFO,
+                Some(bytes),
+                &mut needed,
+            )
+            .is_ok()
+                && delayed.fDelayedAutostart.as_bool();
+
+            let start = StartType::from_code(code, is_delayed).ok_or(ControlError::Os {
+                code,
+                message: "tipo de inicio desconocido".to_string(),
+            })?;
+            Ok((start, binary))
+        }
+    }
+
+    pub(super) fn installed() -> Result<Vec<InstalledService>, ControlError> {
+        let manager = manager(SC_MANAGER_CONNECT | SC_MANAGER_ENUMERATE_SERVICE)?;
+        let mut names = Vec::new();
+        let mut resume = 0u32;
+        let mut buffer = vec![0u64; 8 * 1024];
+        loop {
+            let (mut needed, mut returned) = (0u32, 0u32);
+            // SAFETY: el búfer de `u64` tiene la alineación de
+            // ENUM_SERVICE_STATUS_PROCESSW y la API escribe en él `returned`
+            // entradas, cuyas cadenas apuntan dentro del mismo búfer
+            let result = unsafe {
+                let bytes = std::slice::from_raw_parts_mut(
+                    buffer.as_mut_ptr().cast::<u8>(),
+                    buffer.len() * 8,
+                );
+                let result = EnumServicesStatusExW(
+                    manager.0,
+                    SC_ENUM_PROCESS_INFO,
+                    SERVICE_WIN32,
+                    SERVICE_STATE_ALL,
+                    Some(bytes),
+                    &mut needed,
+                    &mut returned,
+                    Some(&mut resume),
+                    PCWSTR::null(),
+                );
+                let entries = std::slice::from_raw_parts(
+                    buffer.as_ptr().cast::<ENUM_SERVICE_STATUS_PROCESSW>(),
+                    returned as usize,
+                );
+                for entry in entries {
+                    names.push((
+                        entry.lpServiceName.to_string().unwrap_or_default(),
+                        entry.lpDisplayName.to_string().unwrap_or_default(),
+                    ));
+                }
+                result
+            };
+            match result {
+                Ok(()) => break,
+                // Quedan servicios: se piden a partir de `resume`
+                Err(e) if e.code() == ERROR_MORE_DATA.to_hresult() => {
+                    let needed = (needed as usize).div_ceil(8);
+                    if needed > buffer.len() {
+                        buffer.resize(needed, 0);
+                    }
+                }
+                Err(e) => return Err(e.into()),
+            }
+        }
+
+        Ok(names
+            .into_iter()
+            .filter_map(|(name, display_name)| {
+                // SAFETY: el nombre es un HSTRING terminado en nulo que vive
+                // durante la llamada
+                let service = unsafe {
+                    OpenServiceW(manager.0, &HSTRING::from(&name), SERVICE_QUERY_CONFIG).ok()?
+                };
+                let (start, binary) = query_config(&Handle(service)).ok()?;
+                Some(InstalledService {
+                    name,
+                    display_name,
+                    binary,
+                    start,
+                })
+            })
+            .collect())
+    }
+
+    pub(super) fn set_start_type(service: &str, start: StartType) -> Result<(), ControlError> {
+        let (service, _manager) = open(service, SERVICE_CHANGE_CONFIG)?;
+
+        // SAFETY: los parámetros nulos dejan sin cambios el resto de la
+        // configuración y `delayed` es una variable local
+        unsafe {
+            ChangeServiceConfigW(
+                service.0,
+                ENUM_SERVICE_TYPE(SERVICE_NO_CHANGE),
+                SERVICE_START_TYPE(start.code()),
+                SERVICE_ERROR(SERVICE_NO_CHANGE),
+                PCWSTR::null(),
+                PCWSTR::null(),
+                None,
+                PCWSTR::null(),
+                PCWSTR::null(),
+                PCWSTR::null(),
+                PCWSTR::null(),
+            )?;
+            // La marca de inicio retrasado solo se aplica a inicios automáticos
+            if matches!(start, StartType::Auto | StartType::DelayedAuto) {
+                let delayed = SERVICE_DELAYED_AUTO_START_INFO {
+                    fDelayedAutostart: (start == StartType::DelayedAuto).into(),
+                };
+                ChangeServiceConfig2W(
+                    service.0,
+                    SERVICE_CONFIG_DELAYED_AUTO_START_INFO,
+                    Some((&raw const delayed).cast()),
+                )?;
+            }
+        }
+        Ok(())
+    }
+}
+
+#[cfg(not(windows))]
+mod scm {
+    use super::{InstalledService, StartType};
+    use crate::error::ControlError;
+
+    pub(super) fn installed() -> Result<Vec<InstalledService>, ControlError> {
+        Err(ControlError::Unsupported)
+    }
+
+    pub(super) fn query_start_type(_service: &str) -> Result<StartType, ControlError> {
+        Err(ControlError::Unsupported)
+    }
+
+    pub(super) fn set_start_type(_service: &str, _start: StartType) -> Result<(), ControlError> {
+        Err(ControlError::Unsupported)
+    }
+}
+
+/// Restaura los servicios modificados a sus valores predeterminados
+///
+/// Los servicios que no se pueden restaurar se mantienen en la copia para
+/// poder reintentarlo, salvo los que ya no existen.
+pub fn execute_restore_services(app: &mut crate::app::App) {
+    let Some(backup) = load_backup().filter(|backup| !backup.services.is_empty()) else {
+        log_warn!(app, "⚠️  {}", app.t(I18nKey::ServicesRestoreNothing));
+        return;
+    };
+
+    app.operation_state = OperationState::Running;
+    log_info!(app, "↩️  {}", app.t(I18nKey::ServicesRestoreStarting));
+
+    if !is_admin() {
+        optimization::log_requires_admin(app);
+        app.operation_state = OperationState::Failed;
+        return;
+    }
+
+    let mut pending = ServiceBackup::default();
+    for service in backup.services {
+        let Some(start) = windows_default(&service.name).or(service.previous) else {
+            log_warn!(
+                app,
+                "⚠️  {}",
+                app.t_args(
+                    I18nKey::ServicesRestoreUnknown,
+                    &[("service", &service.name)]
+                )
+            );
+            pending.services.push(service);
+            continue;
+        };
+        match set_start_type(&service.name, start) {
+            Ok(()) => log_info!(
+                app,
+                "✅ {}",
+                app.t_args(
+                    I18nKey::ServicesRestored,
+                    &[("service", &service.name), ("start", start.sc_value())],
+                )
+            ),
+            // Un servicio desinstalado no se puede restaurar: sale de la copia
+            Err(ControlError::NotFound) => log_warn!(
+                app,
+                "⚠️  {}",
+                app.t_args(
+                    I18nKey::ServicesRestoreFailed,
+                    &[("service", &service.name)]
+                )
+            ),
+            Err(e) => {
+                log_error!(
+                    app,
+                    "❌ {}",
+                    app.t_args(
+                        I18nKey::OpServiceError,
+                        &[("service", &service.name), ("error", &e.to_string())],
+                    )
+                );
+                pending.services.push(service);
+            }
+        }
+    }
+
+    let saved = if pending.services.is_empty() {
+        backup_file().and_then(fs::remove_file)
+    } else {
+        save_backup(&pending)
+    };
+    if let Err(e) = saved {
+        log_debug!(app, "No se pudo actualizar la copia de servicios: {}", e);
+    }
+
+    log_info!(app, "");
+    if pending.services.is_empty() {
+        log_info!(app, "✅ {}", app.t(I18nKey::ServicesRestoreCompleted));
+        app.operation_state = OperationState::Completed;
+    } else {
+        app.operation_state = OperationState::Failed;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_start_type_codes() {
+        for start in [
+            StartType::Boot,
+            StartType::System,
+            StartType::Auto,
+            StartType::DelayedAuto,
+            StartType::Demand,
+            StartType::Disabled,
+        ] {
+            let delayed = start == StartType::DelayedAuto;
+            assert_eq!(StartType::from_code(start.code(), delayed), Some(start));
+        }
+        // La marca de inicio retrasado solo cuenta en inicios automáticos
+        assert_eq!(StartType::from_code(3, true), Some(StartType::Demand));
+        assert_eq!(StartType::from_code(7, false), None);
+    }
+
+    #[test]
+    fn test_control_errors_are_typed() {
+        assert_eq!(
+            ControlError::from_hresult(0x8007_0424, "El servicio especificado no existe"),
+            ControlError::NotFound
+        );
+        assert_eq!(
+            ControlError::from_hresult(0x8007_0005, "Acceso denegado."),
+            ControlError::AccessDenied
+        );
+        let busy = ControlError::from_hresult(0x8007_0430, "Servicio marcado para eliminación");
+        assert_eq!(
+            busy.to_string(),
+            "Servicio marcado para eliminación (0x80070430)"
+        );
+    }
+
+    #[test]
+    fn test_backup_keeps_the_first_value() {
+        let mut backup = ServiceBackup::default();
+        assert!(backup.add("SysMain", || Some(StartType::Auto)));
+        // Una segunda ejecución encuentra el servicio ya deshabilitado
+        assert!(!backup.add("sysmain", || Some(StartType::Disabled)));
+        assert_eq!(backup.services[0].previous, Some(StartType::Auto));
+
+        let toml_string = toml::to_string_pretty(&backup).unwrap();
+        assert_eq!(
+            toml::from_str::<ServiceBackup>(&toml_string).unwrap(),
+            backup
+        );
+    }
+
+    #[test]
+    fn test_windows_defaults() {
+        assert_eq!(windows_default("DiagTrack"), Some(StartType::Auto));
+        assert_eq!(windows_default("wersvc"), Some(StartType::Demand));
+        assert_eq!(windows_default("Spooler"), None);
+    }
+}
@@ -0,0 +1,72 @@
+//! Vista de ajustes
+//!
+//! Reúne las preferencias que se pueden cambiar desde la aplicación. Cada
+//! fila es un valor posible; al elegirla se aplica al momento y se guarda en
+//! `config.toml`.
+
+use crate::i18n::{I18n, I18nKey, Language};
+use crate::theme::Theme;
+
+/// Valor que se puede elegir en la vista de ajustes
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SettingsItem {
+    /// Idioma de la interfaz
+    Language(Language),
+    /// Tema de colores
+    Theme(Theme),
+}
+
+impl SettingsItem {
+    /// Filas de la vista en el orden en que se muestran, agrupadas por sección
+    ///
+    /// El tema personalizado solo aparece si `[theme.custom]` define colores.
+    pub fn all(has_custom_theme: bool) -> Vec<SettingsItem> {
+        let custom = has_custom_theme.then_some(Theme::Custom);
+        Language::ALL
+            .into_iter()
+            .map(SettingsItem::Language)
+            .chain(
+                Theme::BUILT_IN
+                    .into_iter()
+                    .chain(custom)
+                    .map(SettingsItem::Theme),
+            )
+            .collect()
+    }
+
+    /// Clave de traducción de la sección a la que pertenece
+    pub fn section_key(self) -> I18nKey {
+        match self {
+            SettingsItem::Language(_) => I18nKey::SettingsLanguage,
+            SettingsItem::Theme(_) => I18nKey::SettingsTheme,
+        }
+    }
+
+    /// Texto de la fila
+    ///
+    /// Los idiomas se muestran con su propio nombre y los temas traducidos.
+    pub fn label(self, i18n: &I18n) -> &'static str {
+        match self {
+            SettingsItem::Language(language) => language.native_name(),
+            SettingsItem::Theme(theme) => i18n.t(theme.label_key()),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_every_language_and_theme_is_listed() {
+        let items = SettingsItem::all(false);
+        for language in Language::ALL {
+            assert!(items.contains(&SettingsItem::Language(language)));
+        }
+        for theme in Theme::BUILT_IN {
+            assert!(items.contains(&SettingsItem::Theme(theme)));
+        }
+        assert!(!items.contains(&SettingsItem::Theme(Theme::Custom)));
+        assert!(SettingsItem::all(true).contains(&SettingsItem::Theme(Theme::Custom)));
+    }
+}
@@ -0,0 +1,436 @@
+//! Modo simulación (`--simulate`)
+//!
+//! Permite ver qué hace cada operación, o grabar una demo, sin permisos de
+//! administrador y sin tocar el sistema. Al activarse:
+//!
+//! - Las herramientas de Windows se ejecutan con [`SimulatedRunner`], que
+//!   responde con salidas preparadas tras una espera fija, como si el
+//!   comando hubiese terminado bien (incluido `net session`, así que la
+//!   aplicación se comporta como si tuviese privilegios).
+//! - Las carpetas que limpian las operaciones (temporales, cachés de los
+//!   navegadores, Descargas, logs de Windows, Prefetch, hosts) se sustituyen
+//!   por un sandbox con archivos de ejemplo, recreado en cada inicio para que
+//!   todas las ejecuciones sean iguales.
+//! - La configuración, los logs y el historial se guardan en el sandbox,
+//!   partiendo de una copia de `config.toml`, y los hooks no se ejecutan.
+
+use crate::config::Config;
+use crate::platform::{self, CommandRunner, RunnerGuard};
+use std::fs::{self, File};
+use std::io;
+use std::path::{Path, PathBuf};
+use std::process::Output;
+use std::sync::{Arc, OnceLock};
+use std::time::{Duration, SystemTime};
+
+/// Carpeta del sandbox dentro de la carpeta temporal del sistema
+const SANDBOX_DIR: &str = "win_opt_simulation";
+
+/// Carpeta del sandbox, si la simulación está activada
+static SANDBOX: OnceLock<PathBuf> = OnceLock::new();
+
+/// Archivos de ejemplo del sandbox: ruta, tamaño en bytes y antigüedad en días
+const SANDBOX_FILES: &[(&str, u64, u64)] = &[
+    ("Temp/~DF3A9C41.tmp", 262_144, 0),
+    ("Temp/setup_log.txt", 48_213, 3),
+    ("Temp/chrome_installer.log", 12_288, 12),
+    ("Temp/MSI4f2a1.LOG", 1_048_576, 40),
+    ("Temp/7zS8D21/installer.msi", 3_145_728, 5),
+    ("Temp/7zS8D21/setup.ini", 1_024, 5),
+    (
+        "Users/Demo/AppData/Local/Google/Chrome/User Data/Default/Cache/Cache_Data/data_0",
+        45_056,
+        1,
+    ),
+    (
+        "Users/Demo/AppData/Local/Google/Chrome/User Data/Default/Cache/Cache_Data/f_000a3c",
+        2_621_440,
+        4,
+    ),
+    (
+        "Users/Demo/AppData/Local/Microsoft/Edge/User Data/Default/Cache/Cache_Data/data_1",
+        270_336,
+        2,
+    ),
+    (
+        "Users/Demo/AppData/Local/Mozilla/Firefox/Profiles/demo.default-release/cache2/index",
+        131_072,
+        6,
+    ),
+    (
+        "Users/Demo/AppData/Local/Microsoft/Windows/INetCache/IE/container.dat",
+        8_192,
+        9,
+    ),
+    (
+        "Users/Demo/AppData/Local/Microsoft/Windows/INetCache/Low/4F2KX9QZ/favicon.ico",
+        5_430,
+        2,
+    ),
+    ("Users/Ana/AppData/Local/Temp/~WRL0003.tmp", 131_072, 6),
+    ("Users/Ana/AppData/Local/Temp/TeamsInstall.log", 20_480, 18),
+    (
+        "Users/Ana/AppData/Local/Microsoft/Windows/INetCache/IE/container.dat",
+        16_384,
+        4,
+    ),
+    ("D/Temp/render_0412.exr", 52_428_800, 3),
+    ("D/Temp/render_0413.exr", 52_428_800, 3),
+    ("D/tmp/build-cache/index.bin", 8_388_608, 9),
+    ("Users/Demo/Downloads/notas.txt", 2_048, 2),
+    ("Users/Demo/Downloads/presentacion.pptx", 5_242_880, 10),
+    ("Users/Demo/Downloads/factura-marzo.pdf", 184_320, 45),
+    ("Users/Demo/Downloads/video-demo.mp4", 262_144_000, 95),
+    (
+        "Users/Demo/Downloads/VSCodeUserSetup-x64.exe",
+        98_566_144,
+        200,
+    ),
+    (
+        "Users/Demo/Downloads/fotos-vacaciones.zip",
+        734_003_200,
+        420,
+    ),
+    (
+        "Users/Demo/AppData/Roaming/Microsoft/Windows/Start Menu/Programs/Startup/Send to OneNote.lnk",
+        1_432,
+        300,
+    ),
+    (
+        "Users/Demo/AppData/Roaming/Microsoft/Windows/Start Menu/Programs/Startup/desktop.ini",
+        174,
+        300,
+    ),
+    (
+        "ProgramData/Microsoft/Windows/Start Menu/Programs/Startup/Zoom.lnk",
+        2_116,
+        60,
+    ),
+    ("Windows/Logs/setupact.log", 393_216, 21),
+    ("Windows/Logs/DISM/dism.log", 2_097_152, 8),
+    (
+        "Windows/Logs/WindowsUpdate/WindowsUpdate.etl",
+        1_048_576,
+        30,
+    ),
+    ("Windows/Temp/MpCmdRun.log", 65_536, 1),
+    ("Windows/Temp/TMP0000A1B2.tmp", 524_288, 14),
+    ("Windows/Prefetch/CHROME.EXE-5A8B9C1D.pf", 40_960, 0),
+    ("Windows/Prefetch/EXPLORER.EXE-D5E2F31A.pf", 61_440, 0),
+    ("Windows/Prefetch/CODE.EXE-2B7C4E90.pf", 36_864, 3),
+    ("Windows/Prefetch/SETUP.EXE-7E1F0A22.pf", 24_576, 64),
+    ("Windows/Prefetch/VLC.EXE-9C3D5B17.pf", 32_768, 41),
+    ("Windows/Prefetch/Layout.ini", 98_304, 120),
+];
+
+/// Contenido del archivo hosts de ejemplo
+const SANDBOX_HOSTS: &str = "# Copyright (c) 1993-2009 Microsoft Corp.\r\n\
+    #\r\n\
+    # This is a sample HOSTS file used by Microsoft TCP/IP for Windows.\r\n\
+    #\r\n\
+    # localhost name resolution is handled within DNS itself.\r\n\
+    #\t127.0.0.1       localhost\r\n\
+    #\t::1             localhost\r\n\
+    127.0.0.1       dev.local\r\n";
+
+/// Registro de SFC de ejemplo: un análisis sin infracciones
+const SANDBOX_CBS_LOG: &str = "\
+2024-05-01 10:12:33, Info  CSI  00000008 [SR] Beginning Verify and Repair transaction
+2024-05-01 10:12:34, Info  CSI  00000009 [SR] Verifying 100 components
+2024-05-01 10:15:00, Info  CSI  0000000b [SR] Verify complete
+2024-05-01 10:15:01, Info  CSI  0000000c [SR] Repair complete
+";
+
+/// Respuesta preparada para los comandos que empiezan por `prefix`
+struct ScriptedCommand {
+    /// Principio del comando (`programa arg1 ...`), sin distinguir mayúsculas
+    prefix: &'static str,
+    /// Espera antes de responder, en milisegundos
+    delay_ms: u64,
+    /// Código de salida
+    code: i32,
+    /// Salida estándar
+    stdout: &'static str,
+}
+
+/// Guion de la simulación: se usa la primera respuesta que coincida
+const SCRIPT: &[ScriptedCommand] = &[
+    ScriptedCommand {
+        prefix: "net session",
+        delay_ms: 0,
+        code: 0,
+        stdout: "",
+    },
+    ScriptedCommand {
+        prefix: "reg query HKLM\\SOFTWARE\\Microsoft\\Windows NT\\CurrentVersion /v CurrentBuildNumber",
+        delay_ms: 0,
+        code: 0,
+        stdout: "\r\nHKEY_LOCAL_MACHINE\\SOFTWARE\\Microsoft\\Windows NT\\CurrentVersion\r\n    CurrentBuildNumber    REG_SZ    22631\r\n",
+    },
+    // Programas de inicio: Spotify está deshabilitado en el Administrador
+    // de tareas
+    ScriptedCommand {
+        prefix: "reg query HKCU\\SOFTWARE\\Microsoft\\Windows\\CurrentVersion\\Run /s",
+        delay_ms: 0,
+        code: 0,
+        stdout: "\r\nHKEY_CURRENT_USER\\SOFTWARE\\Microsoft\\Windows\\CurrentVersion\\Run\r\n\
+                 \x20   OneDrive    REG_SZ    \"C:\\Users\\Demo\\AppData\\Local\\Microsoft\\OneDrive\\OneDrive.exe\" /background\r\n\
+                 \x20   Spotify    REG_SZ    C:\\Users\\Demo\\AppData\\Roaming\\Spotify\\Spotify.exe /minimized\r\n\
+                 \x20   Discord    REG_SZ    C:\\Users\\Demo\\AppData\\Local\\Discord\\Update.exe --processStart Discord.exe\r\n",
+    },
+    ScriptedCommand {
+        prefix: "reg query HKCU\\SOFTWARE\\Microsoft\\Windows\\CurrentVersion\\Explorer\\StartupApproved\\Run /s",
+        delay_ms: 0,
+        code: 0,
+        stdout: "\r\nHKEY_CURRENT_USER\\SOFTWARE\\Microsoft\\Windows\\CurrentVersion\\Explorer\\StartupApproved\\Run\r\n\
+                 \x20   OneDrive    REG_BINARY    020000000000000000000000\r\n\
+                 \x20   Spotify    REG_BINARY    0300000066B1A2C3D4E5F601\r\n",
+    },
+    ScriptedCommand {
+        prefix: "reg query HKLM\\SOFTWARE\\Microsoft\\Windows\\CurrentVersion\\Run /s",
+        delay_ms: 0,
+        code: 0,
+        stdout: "\r\nHKEY_LOCAL_MACHINE\\SOFTWARE\\Microsoft\\Windows\\CurrentVersion\\Run\r\n\
+                 \x20   SecurityHealth    REG_EXPAND_SZ    %windir%\\system32\\SecurityHealthSystray.exe\r\n\
+                 \x20   RtkAudUService    REG_SZ    \"C:\\Windows\\System32\\DriverStore\\FileRepository\\realtekservice.inf_amd64\\RtkAudUService64.exe\" -background\r\n",
+    },
+    ScriptedCommand {
+        prefix: "reg query HKLM\\SOFTWARE\\WOW6432Node\\Microsoft\\Windows\\CurrentVersion\\Run /s",
+        delay_ms: 0,
+        code: 0,
+        stdout: "\r\nHKEY_LOCAL_MACHINE\\SOFTWARE\\WOW6432Node\\Microsoft\\Windows\\CurrentVersion\\Run\r\n\
+                 \x20   AdobeAAMUpdater-1.0    REG_SZ    \"C:\\Program Files (x86)\\Common Files\\Adobe\\OOBE\\PDApp\\UWA\\UpdaterStartupUtility.exe\"\r\n",
+    },
+    // Claves que no existen (entre ellas, las de reinicio pendiente)
+    ScriptedCommand {
+        prefix: "reg query",
+        delay_ms: 0,
+        code: 1,
+        stdout: "",
+    },
+    // Tipo del disco del sistema: un SSD
+    ScriptedCommand {
+        prefix: "powershell -NoProfile -Command $disk",
+        delay_ms: 400,
+        code: 0,
+        stdout: "4|0\r\n",
+    },
+    ScriptedCommand {
+        prefix: "cmd /C DISM",
+        delay_ms: 6000,
+        code: 0,
+        stdout: "Deployment Image Servicing and Management tool\r\n\
+                 Version: 10.0.22621.2792\r\n\r\n\
+                 Image Version: 10.0.22631.3447\r\n\r\n\
+                 [==========================100.0%==========================]\r\n\
+                 The restore operation completed successfully.\r\n\
+                 The operation completed successfully.\r\n",
+    },
+    ScriptedCommand {
+        prefix: "cmd /C sfc",
+        delay_ms: 5000,
+        code: 0,
+        stdout: "Beginning system scan.  This process will take some time.\r\n\r\n\
+                 Beginning verification phase of system scan.\r\n\
+                 Verification 100% complete.\r\n\r\n\
+                 Windows Resource Protection did not find any integrity violations.\r\n",
+    },
+    ScriptedCommand {
+        prefix: "ipconfig /flushdns",
+        delay_ms: 800,
+        code: 0,
+        stdout: "\r\nWindows IP Configuration\r\n\r\nSuccessfully flushed the DNS Resolver Cache.\r\n",
+    },
+    ScriptedCommand {
+        prefix: "netsh advfirewall show allprofiles",
+        delay_ms: 300,
+        code: 0,
+        stdout: "\r\nDomain Profile Settings: \r\n\
+                 ----------------------------------------------------------------------\r\n\
+                 State                                 ON\r\n\r\n\
+                 Private Profile Settings: \r\n\
+                 ----------------------------------------------------------------------\r\n\
+                 State                                 ON\r\n\r\n\
+                 Public Profile Settings: \r\n\
+                 ----------------------------------------------------------------------\r\n\
+                 State                                 ON\r\n\
+                 Ok.\r\n",
+    },
+    ScriptedCommand {
+        prefix: "netsh wlan show profiles",
+        delay_ms: 300,
+        code: 0,
+        stdout: "\r\nProfiles on interface Wi-Fi:\r\n\r\n\
+                 User profiles\r\n\
+                 -------------\r\n\
+                 \x20   All User Profile     : Casa\r\n\
+                 \x20   All User Profile     : Oficina\r\n\
+                 \x20   All User Profile     : Cafeteria 5G\r\n",
+    },
+    ScriptedCommand {
+        prefix: "netsh",
+        delay_ms: 1200,
+        code: 0,
+        stdout: "Ok.\r\n",
+    },
+];
+
+/// Respuesta de los comandos que no están en el guion
+const DEFAULT_RESPONSE: ScriptedCommand = ScriptedCommand {
+    prefix: "",
+    delay_ms: 300,
+    code: 0,
+    stdout: "",
+};
+
+/// Ejecutor de la simulación: responde con el guion sin lanzar nada
+#[derive(Debug, Default, Clone, Copy)]
+pub struct SimulatedRunner;
+
+impl SimulatedRunner {
+    /// Respuesta del guion para la línea de comando
+    fn response(line: &str) -> &'static ScriptedCommand {
+        let line = line.to_lowercase();
+        SCRIPT
+            .iter()
+            .find(|command| line.starts_with(&command.prefix.to_lowercase()))
+            .unwrap_or(&DEFAULT_RESPONSE)
+    }
+}
+
+impl CommandRunner for SimulatedRunner {
+    fn run(&self, program: &str, args: &[String]) -> io::Result<Output> {
+        let line = std::iter::once(program)
+            .chain(args.iter().map(String::as_str))
+            .collect::<Vec<_>>()
+            .join(" ");
+        let response = Self::response(&line);
+        tracing::debug!("Simulación: {}", line);
+        std::thread::sleep(Duration::from_millis(response.delay_ms));
+        Ok(Output {
+            status: platform::exit_status(response.code),
+            stdout: response.stdout.as_bytes().to_vec(),
+            stderr: Vec::new(),
+        })
+    }
+}
+
+/// Activa la simulación para el resto de la sesión
+///
+/// Recrea el sandbox, copia en él la configuración actual e instala el
+/// ejecutor simulado, que se mantiene mientras viva el guard. Debe llamarse
+/// antes de cargar la configuración o inicializar los logs.
+pub fn enable() -> io::Result<RunnerGuard> {
+    let root = std::env::temp_dir().join(SANDBOX_DIR);
+    populate(&root)?;
+
+    // Copiar la configuración antes de que las rutas apunten al sandbox
+    let config_dir = root.join("AppData").join("win_opt");
+    fs::create_dir_all(&config_dir)?;
+    if let Ok(config_file) = Config::get_config_file()
+        && config_file.is_file()
+    {
+        fs::copy(&config_file, config_dir.join("config.toml"))?;
+    }
+
+    let _ = SANDBOX.set(root);
+    Ok(platform::replace_runner(Arc::new(SimulatedRunner)))
+}
+
+/// Indica si la simulación está activada
+pub fn is_enabled() -> bool {
+    SANDBOX.get().is_some()
+}
+
+/// Carpeta del sandbox, si la simulación está activada
+pub fn sandbox() -> Option<&'static Path> {
+    SANDBOX.get().map(PathBuf::as_path)
+}
+
+/// Recrea el sandbox con los archivos de ejemplo
+fn populate(root: &Path) -> io::Result<()> {
+    if root.exists() {
+        fs::remove_dir_all(root)?;
+    }
+    let now = SystemTime::now();
+    for &(relative, size, age_days) in SANDBOX_FILES {
+        let path = root.join(relative);
+        if let Some(parent) = path.parent() {
+            fs::create_dir_all(parent)?;
+        }
+        let file = File::create(&path)?;
+        // Archivo disperso: ocupa el tamaño indicado sin escribir los datos
+        file.set_len(size)?;
+        file.set_modified(now - Duration::from_secs(age_days * 24 * 60 * 60))?;
+    }
+
+    let etc = root.join("Windows/System32/drivers/etc");
+    fs::create_dir_all(&etc)?;
+    fs::write(etc.join("hosts"), SANDBOX_HOSTS)?;
+    let cbs = root.join("Windows/Logs/CBS");
+    fs::create_dir_all(&cbs)?;
+    fs::write(cbs.join("CBS.log"), SANDBOX_CBS_LOG)?;
+    fs::create_dir_all(root.join("Users/Demo/Documents"))?;
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_script_picks_first_match() {
+        assert_eq!(SimulatedRunner::response("net session").code, 0);
+        let dism = SimulatedRunner::response("cmd /C DISM /Online /Cleanup-Image /RestoreHealth");
+        assert!(dism.stdout.contains("completed successfully"));
+        // Las claves del registro que no están en el guion no existen
+        assert_eq!(
+            SimulatedRunner::response("reg query HKLM\\SOFTWARE\\Prueba").code,
+            1
+        );
+        assert_eq!(
+            SimulatedRunner::response("NETSH winsock reset").stdout,
+            "Ok.\r\n"
+        );
+        assert_eq!(SimulatedRunner::response("powercfg /h off").code, 0);
+    }
+
+    #[test]
+    fn test_script_outputs_parse_like_windows() {
+        use crate::repair::{self, DismResult, SfcResult};
+
+        let lines = |prefix: &str| -> Vec<String> {
+            SimulatedRunner::response(prefix)
+                .stdout
+                .lines()
+                .map(str::to_string)
+                .collect()
+        };
+        assert_eq!(
+            repair::parse_dism(&lines("cmd /C DISM"), Some(0)),
+            DismResult::Completed
+        );
+        assert_eq!(repair::parse_sfc(&lines("cmd /C sfc")), SfcResult::Healthy);
+    }
+
+    #[test]
+    fn test_populate_recreates_sandbox() {
+        let root = std::env::temp_dir().join("win_opt_test_simulation");
+        populate(&root).unwrap();
+        fs::write(root.join("Temp").join("extra.tmp"), b"x").unwrap();
+
+        // Cada inicio parte del mismo contenido
+        populate(&root).unwrap();
+        assert!(!root.join("Temp").join("extra.tmp").exists());
+        let zip = root.join("Users/Demo/Downloads/fotos-vacaciones.zip");
+        let metadata = fs::metadata(&zip).unwrap();
+        assert_eq!(metadata.len(), 734_003_200);
+        let age = SystemTime::now()
+            .duration_since(metadata.modified().unwrap())
+            .unwrap();
+        assert!(age >= Duration::from_secs(419 * 24 * 60 * 60));
+        assert!(root.join("Windows/System32/drivers/etc/hosts").is_file());
+
+        fs::remove_dir_all(&root).unwrap();
+    }
+}
@@ -0,0 +1,217 @@
+//! Caché de tamaños de carpetas
+//!
+//! Recorrer carpetas grandes archivo por archivo es lento. La caché guarda en
+//! `size_cache.tsv` (carpeta de configuración), por cada carpeta, su fecha de
+//! modificación, la suma de sus archivos directos y los nombres de sus
+//! subcarpetas. La fecha de una carpeta solo cambia cuando se crean, borran o
+//! renombran sus hijos directos, así que si coincide no hace falta listarla:
+//! basta con consultar la fecha de cada subcarpeta, sin leer sus archivos.
+//!
+//! Un archivo que crece sin que se cree ni se borre nada a su lado no cambia
+//! la fecha de su carpeta; por eso los tamaños obtenidos son estimaciones.
+
+use crate::config::Config;
+use crate::utils::is_plain_dir;
+use std::collections::HashMap;
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::time::UNIX_EPOCH;
+
+/// Separador de los nombres de subcarpetas (carácter no válido en Windows)
+const NAME_SEPARATOR: char = '|';
+
+/// Entrada de la caché para una carpeta
+#[derive(Debug, Clone, PartialEq, Eq)]
+struct CachedDir {
+    /// Fecha de modificación en milisegundos desde 1970-01-01
+    mtime: u64,
+    /// Suma de los archivos directos (sin subcarpetas)
+    files_size: u64,
+    /// Nombres de las subcarpetas directas
+    subdirs: Vec<String>,
+}
+
+/// Caché de tamaños de carpetas
+#[derive(Debug, Default)]
+pub struct SizeCache {
+    entries: HashMap<PathBuf, CachedDir>,
+    /// Carpetas que hubo que listar de nuevo en esta sesión
+    misses: usize,
+}
+
+impl SizeCache {
+    /// Carga la caché del disco (vacía si no existe o no se puede leer)
+    pub fn load() -> Self {
+        let entries = cache_file()
+            .and_then(fs::read_to_string)
+            .map(|contents| parse_cache(&contents))
+            .unwrap_or_default();
+        SizeCache { entries, misses: 0 }
+    }
+
+    /// Guarda la caché, descartando las carpetas que ya no existen
+    ///
+    /// Los errores solo se registran: la caché nunca debe impedir un análisis.
+    pub fn save(&self) {
+        let contents: String = self
+            .entries
+            .iter()
+            .filter(|(path, _)| path.is_dir())
+            .map(|(path, entry)| format_entry(path, entry))
+            .collect();
+        if let Err(e) = cache_file().and_then(|path| fs::write(path, contents)) {
+            tracing::warn!("No se pudo guardar la caché de tamaños: {}", e);
+        }
+    }
+
+    /// Carpetas que no se pudieron resolver con la caché
+    pub fn misses(&self) -> usize {
+        self.misses
+    }
+
+    /// Tamaño de un archivo o carpeta (recursivo, sin seguir enlaces)
+    ///
+    /// Equivale a `utils::path_size`, pero reutiliza los datos de las
+    /// carpetas que no han cambiado desde el último análisis.
+    pub fn path_size(&mut self, path: &Path) -> u64 {
+        let Ok(metadata) = fs::symlink_metadata(path) else {
+            return 0;
+        };
+        if !is_plain_dir(&metadata) {
+            return metadata.len();
+        }
+
+        let mtime = metadata
+            .modified()
+            .ok()
+            .and_then(|modified| modified.duration_since(UNIX_EPOCH).ok())
+            .map(|d| d.as_millis() as u64)
+            .unwrap_or(0);
+
+        let entry = match self.entries.get(path) {
+            Some(entry) if entry.mtime == mtime => entry.clone(),
+            _ => {
+                let entry = scan_dir(path, mtime);
+                self.misses += 1;
+                self.entries.insert(path.to_path_buf(), entry.clone());
+                entry
+            }
+        };
+
+        entry.files_size
+            + entry
+                .subdirs
+                .iter()
+                .map(|name| self.path_size(&path.join(name)))
+                .sum::<u64>()
+    }
+}
+
+/// Ruta del archivo de caché
+fn cache_file() -> std::io::Result<PathBuf> {
+    Ok(Config::get_config_dir()?.join("size_cache.tsv"))
+}
+
+/// Lista una carpeta: suma sus archivos y anota sus subcarpetas
+fn scan_dir(path: &Path, mtime: u64) -> CachedDir {
+    let mut entry = CachedDir {
+        mtime,
+        files_size: 0,
+        subdirs: Vec::new(),
+    };
+    let Ok(children) = fs::read_dir(path) else {
+        return entry;
+    };
+    for child in children.flatten() {
+        // `DirEntry::metadata` no sigue enlaces simbólicos
+        let Ok(metadata) = child.metadata() else {
+            continue;
+        };
+        if is_plain_dir(&metadata) {
+            entry
+                .subdirs
+                .push(child.file_name().to_string_lossy().into_owned());
+        } else {
+            entry.files_size += metadata.len();
+        }
+    }
+    entry
+}
+
+/// Línea `ruta<TAB>mtime<TAB>tamaño<TAB>sub1|sub2...` de una entrada
+fn format_entry(path: &Path, entry: &CachedDir) -> String {
+    format!(
+        "{}\t{}\t{}\t{}\n",
+        path.display(),
+        entry.mtime,
+        entry.files_size,
+        entry.subdirs.join(&NAME_SEPARATOR.to_string())
+    )
+}
+
+/// Parsea el contenido de `size_cache.tsv`, ignorando líneas mal formadas
+fn parse_cache(contents: &str) -> HashMap<PathBuf, CachedDir> {
+    contents
+        .lines()
+        .filter_map(|line| {
+            let mut fields = line.split('\t');
+            let path = PathBuf::from(fields.next()?);
+            let mtime = fields.next()?.parse().ok()?;
+            let files_size = fields.next()?.parse().ok()?;
+            let subdirs = fields
+                .next()?
+                .split(NAME_SEPARATOR)
+                .filter(|name| !name.is_empty())
+                .map(str::to_string)
+                .collect();
+            Some((
+                path,
+                CachedDir {
+                    mtime,
+                    files_size,
+                    subdirs,
+                },
+            ))
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_cache_roundtrip() {
+        let entry = CachedDir {
+            mtime: 1_760_000_000_000,
+            files_size: 4096,
+            subdirs: vec!["cache".to_string(), "logs antiguos".to_string()],
+        };
+        let line = format_entry(Path::new("/tmp/app"), &entry);
+        let contents = format!("{}basura\n/tmp/vacia\t1\t0\t\n", line);
+
+        let entries = parse_cache(&contents);
+        assert_eq!(entries.len(), 2);
+        assert_eq!(entries[Path::new("/tmp/app")], entry);
+        assert!(entries[Path::new("/tmp/vacia")].subdirs.is_empty());
+    }
+
+    #[test]
+    fn test_path_size_reuses_unchanged_dirs() {
+        let dir = std::env::temp_dir().join("win_opt_test_size_cache");
+        let _ = fs::remove_dir_all(&dir);
+        fs::create_dir_all(dir.join("a/b")).unwrap();
+        fs::write(dir.join("top.bin"), [0u8; 10]).unwrap();
+        fs::write(dir.join("a/b/deep.bin"), [0u8; 100]).unwrap();
+
+        let mut cache = SizeCache::default();
+        assert_eq!(cache.path_size(&dir), 110);
+        assert_eq!(cache.misses(), 3);
+
+        // Segunda pasada: ninguna carpeta ha cambiado
+        assert_eq!(cache.path_size(&dir), 110);
+        assert_eq!(cache.misses(), 3);
+
+        fs::remove_dir_all(&dir).unwrap();
+    }
+}
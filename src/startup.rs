@@ -0,0 +1,695 @@
+//! Inventario de lo que se inicia con Windows
+//!
+//! Reúne en una sola lista, al estilo de Autoruns, todo lo que arranca con
+//! el equipo o al iniciar sesión: las claves `Run` y `RunOnce` del registro,
+//! las carpetas Inicio del usuario y de todos los usuarios, las tareas
+//! programadas con un desencadenador de inicio de sesión o de arranque y los
+//! servicios de inicio automático que no son de Windows. Cada entrada lleva
+//! una estimación de su impacto en el arranque.
+//!
+//! Nada se borra al deshabilitar: las entradas del registro y de las
+//! carpetas se marcan en las claves `StartupApproved`, igual que hace el
+//! Administrador de tareas; las tareas se deshabilitan en el Programador de
+//! tareas, y los servicios pasan a inicio manual tras anotar su tipo de
+//! inicio en la copia de servicios.
+
+use crate::env_path;
+use crate::error::{ControlError, Result};
+use crate::i18n::I18nKey;
+use crate::platform;
+use crate::registry::{self, RegValue};
+use crate::services::{self, StartType};
+use crate::simulate;
+use crate::tasks;
+use crate::types::OperationState;
+use crate::utils::is_admin;
+use crate::{log_debug, log_info, log_warn};
+use std::fs;
+use std::path::{Path, PathBuf};
+
+/// Origen de una entrada de inicio
+///
+/// El orden de las variantes es el de los grupos de la vista.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub enum StartupSource {
+    UserRun,
+    UserRunOnce,
+    MachineRun,
+    MachineRun32,
+    MachineRunOnce,
+    UserFolder,
+    CommonFolder,
+    Task,
+    Service,
+}
+
+/// Orígenes que se leen del registro
+const REGISTRY_SOURCES: [StartupSource; 5] = [
+    StartupSource::UserRun,
+    StartupSource::UserRunOnce,
+    StartupSource::MachineRun,
+    StartupSource::MachineRun32,
+    StartupSource::MachineRunOnce,
+];
+
+impl StartupSource {
+    /// Clave del registro de la que se leen las entradas
+    pub fn run_key(self) -> Option<&'static str> {
+        match self {
+            StartupSource::UserRun => {
+                Some("HKCU\\SOFTWARE\\Microsoft\\Windows\\CurrentVersion\\Run")
+            }
+            StartupSource::UserRunOnce => {
+                Some("HKCU\\SOFTWARE\\Microsoft\\Windows\\CurrentVersion\\RunOnce")
+            }
+            StartupSource::MachineRun => {
+                Some("HKLM\\SOFTWARE\\Microsoft\\Windows\\CurrentVersion\\Run")
+            }
+            StartupSource::MachineRun32 => {
+                Some("HKLM\\SOFTWARE\\WOW6432Node\\Microsoft\\Windows\\CurrentVersion\\Run")
+            }
+            StartupSource::MachineRunOnce => {
+                Some("HKLM\\SOFTWARE\\Microsoft\\Windows\\CurrentVersion\\RunOnce")
+            }
+            _ => None,
+        }
+    }
+
+    /// Clave `StartupApproved` que guarda si las entradas están habilitadas
+    ///
+    /// Las entradas `RunOnce` no tienen: se ejecutan una vez y desaparecen.
+    fn approved_key(self) -> Option<&'static str> {
+        match self {
+            StartupSource::UserRun => Some(
+                "HKCU\\SOFTWARE\\Microsoft\\Windows\\CurrentVersion\\Explorer\\StartupApproved\\Run",
+            ),
+            StartupSource::MachineRun => Some(
+                "HKLM\\SOFTWARE\\Microsoft\\Windows\\CurrentVersion\\Explorer\\StartupApproved\\Run",
+            ),
+            StartupSource::MachineRun32 => Some(
+                "HKLM\\SOFTWARE\\Microsoft\\Windows\\CurrentVersion\\Explorer\\StartupApproved\\Run32",
+            ),
+            StartupSource::UserFolder => Some(
+                "HKCU\\SOFTWARE\\Microsoft\\Windows\\CurrentVersion\\Explorer\\StartupApproved\\StartupFolder",
+            ),
+            StartupSource::CommonFolder => Some(
+                "HKLM\\SOFTWARE\\Microsoft\\Windows\\CurrentVersion\\Explorer\\StartupApproved\\StartupFolder",
+            ),
+            _ => None,
+        }
+    }
+
+    /// Indica si cambiar sus entradas requiere permisos de administrador
+    fn machine_wide(self) -> bool {
+        matches!(
+            self,
+            StartupSource::MachineRun
+                | StartupSource::MachineRun32
+                | StartupSource::MachineRunOnce
+                | StartupSource::CommonFolder
+                | StartupSource::Service
+        )
+    }
+
+    /// Clave de traducción del grupo
+    pub fn label_key(self) -> I18nKey {
+        match self {
+            StartupSource::UserRun => I18nKey::StartupSourceUserRun,
+            StartupSource::UserRunOnce => I18nKey::StartupSourceUserRunOnce,
+            StartupSource::MachineRun => I18nKey::StartupSourceMachineRun,
+            StartupSource::MachineRun32 => I18nKey::StartupSourceMachineRun32,
+            StartupSource::MachineRunOnce => I18nKey::StartupSourceMachineRunOnce,
+            StartupSource::UserFolder => I18nKey::StartupSourceUserFolder,
+            StartupSource::CommonFolder => I18nKey::StartupSourceCommonFolder,
+            StartupSource::Task => I18nKey::StartupSourceTask,
+            StartupSource::Service => I18nKey::StartupSourceService,
+        }
+    }
+}
+
+/// Impacto estimado de una entrada en el arranque
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Impact {
+    High,
+    Medium,
+    Low,
+}
+
+impl Impact {
+    /// Clave de traducción del impacto
+    pub fn label_key(self) -> I18nKey {
+        match self {
+            Impact::High => I18nKey::StartupImpactHigh,
+            Impact::Medium => I18nKey::StartupImpactMedium,
+            Impact::Low => I18nKey::StartupImpactLow,
+        }
+    }
+}
+
+/// Impacto estimado según el origen de la entrada
+///
+/// No se mide nada: los servicios automáticos se cargan antes de iniciar
+/// sesión y siguen en memoria, así que pesan más que un programa de `Run`; el
+/// inicio retrasado, las entradas `RunOnce` y las que apuntan a un programa
+/// que ya no existe apenas cuentan.
+fn impact(source: StartupSource, delayed: bool, missing: bool) -> Impact {
+    match source {
+        _ if missing => Impact::Low,
+        StartupSource::Service if delayed => Impact::Low,
+        StartupSource::Service => Impact::High,
+        StartupSource::UserRunOnce | StartupSource::MachineRunOnce => Impact::Low,
+        _ => Impact::Medium,
+    }
+}
+
+/// Entrada que se inicia con Windows
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct StartupEntry {
+    pub source: StartupSource,
+    /// Identificador en su origen: nombre del valor o del archivo, ruta de
+    /// la tarea o nombre del servicio
+    pub id: String,
+    /// Nombre que se muestra
+    pub name: String,
+    /// Programa y argumentos que se ejecutan
+    pub command: String,
+    pub enabled: bool,
+    pub impact: Impact,
+    /// El programa ya no existe (entrada huérfana)
+    pub missing: bool,
+}
+
+impl StartupEntry {
+    /// Entrada de un programa; `missing` indica si ya no existe
+    fn new(
+        source: StartupSource,
+        id: &str,
+        name: &str,
+        command: &str,
+        enabled: bool,
+        missing: bool,
+    ) -> Self {
+        StartupEntry {
+            source,
+            id: id.to_string(),
+            name: name.to_string(),
+            command: command.to_string(),
+            enabled,
+            impact: impact(source, false, missing),
+            missing,
+        }
+    }
+}
+
+/// Programa de una línea de comando (`"C:\Ruta\app.exe" /arg` → `C:\Ruta\app.exe`)
+///
+/// Las rutas sin comillas pueden tener espacios, así que se cortan tras el
+/// `.exe` si lo hay y, si no, en el primer espacio.
+fn executable_of(command: &str) -> &str {
+    let command = command.trim();
+    if let Some(rest) = command.strip_prefix('"') {
+        return rest.split('"').next().unwrap_or(rest);
+    }
+    match command.to_ascii_lowercase().find(".exe") {
+        Some(end) => &command[..end + 4],
+        None => command.split_whitespace().next().unwrap_or(command),
+    }
+}
+
+/// Indica si el programa de la entrada tiene una ruta completa que ya no
+/// existe
+///
+/// Los programas sin ruta (`rundll32.exe ...`) se buscan en el `PATH` y no
+/// se comprueban, igual que las rutas sin extensión, que pueden ser una ruta
+/// con espacios cortada. En modo simulación las rutas de ejemplo no existen
+/// y no se comprueba ninguna.
+fn is_missing(command: &str) -> bool {
+    if simulate::is_enabled() {
+        return false;
+    }
+    let path = PathBuf::from(env_path::expand_env(executable_of(command)));
+    path.is_absolute() && path.extension().is_some() && !path.exists()
+}
+
+/// Indica si el programa de un servicio está en la carpeta de Windows
+fn is_windows_binary(binary: &str, windows_dir: &str) -> bool {
+    let program = env_path::expand_env(executable_of(binary)).to_lowercase();
+    let windows_dir = windows_dir.trim_end_matches(['\\', '/']).to_lowercase();
+    program.starts_with(&format!("{windows_dir}\\"))
+        || program.starts_with("\\systemroot\\")
+        || program.starts_with("system32\\")
+}
+
+/// Valor de `StartupApproved` de una entrada habilitada
+const APPROVED_ENABLED: [u8; 12] = [0x02, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0];
+/// Valor de `StartupApproved` de una entrada deshabilitada
+const APPROVED_DISABLED: [u8; 12] = [0x03, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0];
+
+/// Indica si un valor de `StartupApproved` deja la entrada habilitada
+///
+/// El primer byte es par (`02`, `06`) si está habilitada e impar (`03`,
+/// `07`) si el usuario la deshabilitó; sin valor, se considera habilitada.
+fn is_approved(value: Option<&RegValue>) -> bool {
+    value
+        .and_then(|value| value.data.get(..2))
+        .and_then(|byte| u8::from_str_radix(byte, 16).ok())
+        .is_none_or(|byte| byte & 1 == 0)
+}
+
+/// Valores de la primera clave de `reg query <clave> /s`
+fn key_values(key: &str) -> Vec<(String, RegValue)> {
+    registry::query_key_recursive(key)
+        .into_iter()
+        .next()
+        .map(|key| key.values)
+        .unwrap_or_default()
+}
+
+/// Indica si la entrada está habilitada según `StartupApproved`
+fn approved(source: StartupSource, approvals: &[(String, RegValue)], id: &str) -> bool {
+    source.approved_key().is_none()
+        || is_approved(
+            approvals
+                .iter()
+                .find(|(name, _)| name.eq_ignore_ascii_case(id))
+                .map(|(_, value)| value),
+        )
+}
+
+/// Entradas de las claves `Run` y `RunOnce`
+fn registry_entries() -> Vec<StartupEntry> {
+    let mut entries = Vec::new();
+    for source in REGISTRY_SOURCES {
+        let Some(key) = source.run_key() else {
+            continue;
+        };
+        let approvals = source.approved_key().map(key_values).unwrap_or_default();
+        for (name, value) in key_values(key) {
+            // El valor predeterminado (sin nombre) no arranca nada
+            if name == "(Default)" || name == "(Predeterminado)" || value.data.is_empty() {
+                continue;
+            }
+            let enabled = approved(source, &approvals, &name);
+            entries.push(StartupEntry::new(
+                source,
+                &name,
+                &name,
+                &value.data,
+                enabled,
+                is_missing(&value.data),
+            ));
+        }
+    }
+    entries
+}
+
+/// Carpeta Inicio de un origen
+fn startup_folder(source: StartupSource) -> Option<PathBuf> {
+    let start_menu = match source {
+        StartupSource::UserFolder => platform::user_profile()
+            .join("AppData")
+            .join("Roaming")
+            .join("Microsoft"),
+        StartupSource::CommonFolder => platform::program_data_dir().join("Microsoft"),
+        _ => return None,
+    };
+    Some(
+        start_menu
+            .join("Windows")
+            .join("Start Menu")
+            .join("Programs")
+            .join("Startup"),
+    )
+}
+
+/// Entradas de una carpeta Inicio (sin `desktop.ini`)
+fn folder_entries(source: StartupSource, dir: &Path) -> Vec<StartupEntry> {
+    let Ok(files) = fs::read_dir(dir) else {
+        return Vec::new();
+    };
+    let approvals = source.approved_key().map(key_values).unwrap_or_default();
+    files
+        .flatten()
+        .filter(|file| file.path().is_file())
+        .filter_map(|file| {
+            let file_name = file.file_name().to_string_lossy().into_owned();
+            if file_name.eq_ignore_ascii_case("desktop.ini") {
+                return None;
+            }
+            let path = file.path();
+            let name = path.file_stem().map_or(file_name.clone(), |stem| {
+                stem.to_string_lossy().into_owned()
+            });
+            let enabled = approved(source, &approvals, &file_name);
+            Some(StartupEntry::new(
+                source,
+                &file_name,
+                &name,
+                &path.to_string_lossy(),
+                enabled,
+                false,
+            ))
+        })
+        .collect()
+}
+
+/// Tareas que no son de Windows y se ejecutan al iniciar sesión o al
+/// arrancar
+fn task_entries() -> std::result::Result<Vec<StartupEntry>, ControlError> {
+    Ok(tasks::list()?
+        .into_iter()
+        .filter(|task| task.at_startup && !task.path.to_lowercase().starts_with("\\microsoft\\"))
+        .map(|task| {
+            let enabled = task.state != tasks::TaskState::Disabled;
+            StartupEntry::new(
+                StartupSource::Task,
+                &task.path,
+                task.name(),
+                &task.command,
+                enabled,
+                is_missing(&task.command),
+            )
+        })
+        .collect())
+}
+
+/// Indica si el tipo de inicio arranca el servicio con el equipo
+fn is_auto(start: StartType) -> bool {
+    matches!(start, StartType::Auto | StartType::DelayedAuto)
+}
+
+/// Servicios automáticos que no son de Windows
+///
+/// También se incluyen, deshabilitados, los que se pasaron a inicio manual
+/// desde esta vista y siguen en la copia de servicios.
+fn service_entries() -> std::result::Result<Vec<StartupEntry>, ControlError> {
+    let backup = services::load_backup().unwrap_or_default();
+    let windows_dir = platform::windows_dir().to_string_lossy().into_owned();
+    Ok(services::installed()?
+        .into_iter()
+        .filter(|service| !is_windows_binary(&service.binary, &windows_dir))
+        .filter_map(|service| {
+            let previous = backup
+                .services
+                .iter()
+                .find(|backed_up| backed_up.name.eq_ignore_ascii_case(&service.name))
+                .and_then(|backed_up| backed_up.previous);
+            let enabled = is_auto(service.start);
+            if !enabled && !previous.is_some_and(is_auto) {
+                return None;
+            }
+            let mut entry = StartupEntry::new(
+                StartupSource::Service,
+                &service.name,
+                &service.display_name,
+                &service.binary,
+                enabled,
+                is_missing(&service.binary),
+            );
+            let delayed = service.start == StartType::DelayedAuto
+                || (!enabled && previous == Some(StartType::DelayedAuto));
+            entry.impact = impact(entry.source, delayed, entry.missing);
+            Some(entry)
+        })
+        .collect())
+}
+
+/// Ordena las entradas por origen y nombre
+fn sort_entries(entries: &mut [StartupEntry]) {
+    entries.sort_by_cached_key(|entry| (entry.source, entry.name.to_lowercase()));
+}
+
+/// Reúne las entradas de inicio de todos los orígenes en la vista
+pub fn execute_startup_audit(app: &mut crate::app::App) {
+    app.operation_state = OperationState::Running;
+    app.startup_entries.clear();
+    log_info!(app, "🚀 {}", app.t(I18nKey::StartupAnalyzing));
+
+    let mut entries = registry_entries();
+    for source in [StartupSource::UserFolder, StartupSource::CommonFolder] {
+        if let Some(dir) = startup_folder(source) {
+            entries.extend(folder_entries(source, &dir));
+        }
+    }
+    for (source, found) in [
+        (StartupSource::Task, task_entries()),
+        (StartupSource::Service, service_entries()),
+    ] {
+        match found {
+            Ok(found) => entries.extend(found),
+            Err(e) => log_warn!(
+                app,
+                "⚠️  {}",
+                app.t_args(
+                    I18nKey::StartupSourceFailed,
+                    &[
+                        ("source", app.t(source.label_key())),
+                        ("error", &e.to_string())
+                    ],
+                )
+            ),
+        }
+    }
+    sort_entries(&mut entries);
+
+    for entry in &entries {
+        log_debug!(
+            app,
+            "{}",
+            app.t_args(
+                I18nKey::StartupEntry,
+                &[("entry", &entry.name), ("command", &entry.command)]
+            )
+        );
+    }
+    let high = entries.iter().filter(|e| e.impact == Impact::High).count();
+    let missing = entries.iter().filter(|e| e.missing).count();
+    let total = entries.len();
+    app.startup_entries = entries;
+
+    log_info!(
+        app,
+        "✅ {}",
+        app.t_args(
+            I18nKey::StartupFound,
+            &[("count", &total.to_string()), ("high", &high.to_string())],
+        )
+    );
+    if missing > 0 {
+        log_warn!(
+            app,
+            "⚠️  {}",
+            app.t_args(I18nKey::StartupMissing, &[("count", &missing.to_string())])
+        );
+    }
+    if total > 0 {
+        log_info!(app, "💡 {}", app.t(I18nKey::StartupHint));
+    }
+    app.operation_state = OperationState::Completed;
+}
+
+/// Habilita o deshabilita una entrada en su origen
+fn set_entry_enabled(entry: &StartupEntry, enabled: bool) -> Result<()> {
+    match entry.source {
+        StartupSource::Task => tasks::set_enabled(&entry.id, enabled)?,
+        StartupSource::Service => {
+            let start = if enabled {
+                services::load_backup()
+                    .and_then(|backup| {
+                        backup
+                            .services
+                            .into_iter()
+                            .find(|service| service.name.eq_ignore_ascii_case(&entry.id))
+                    })
+                    .and_then(|service| service.previous)
+                    .filter(|start| is_auto(*start))
+                    .unwrap_or(StartType::Auto)
+            } else {
+                services::record(&entry.id)?;
+                StartType::Demand
+            };
+            services::set_start_type(&entry.id, start)?;
+        }
+        source => {
+            if let Some(key) = source.approved_key() {
+                let value = if enabled {
+                    APPROVED_ENABLED
+                } else {
+                    APPROVED_DISABLED
+                };
+                registry::set_binary(key, &entry.id, &value)?;
+            }
+        }
+    }
+    Ok(())
+}
+
+/// Habilita o deshabilita la entrada seleccionada
+///
+/// Las entradas `RunOnce` no se pueden deshabilitar: se ejecutan una sola
+/// vez en el siguiente inicio de sesión.
+pub fn execute_set_startup_enabled(app: &mut crate::app::App, enabled: bool) {
+    let Some(entry) = app.startup_entries.get(app.selected_item).cloned() else {
+        return;
+    };
+    if entry.enabled == enabled {
+        return;
+    }
+
+    log_info!(app, "");
+    if matches!(
+        entry.source,
+        StartupSource::UserRunOnce | StartupSource::MachineRunOnce
+    ) {
+        log_info!(app, "ℹ️  {}", app.t(I18nKey::StartupRunOnce));
+        return;
+    }
+
+    match set_entry_enabled(&entry, enabled) {
+        Ok(()) => {
+            app.startup_entries[app.selected_item].enabled = enabled;
+            let done = match (enabled, entry.source) {
+                (true, _) => I18nKey::StartupEnabled,
+                (false, StartupSource::Service) => I18nKey::StartupServiceManual,
+                (false, _) => I18nKey::StartupDisabled,
+            };
+            log_info!(app, "✅ {}", app.t_args(done, &[("entry", &entry.name)]));
+        }
+        Err(e) => {
+            log_warn!(
+                app,
+                "⚠️  {}",
+                app.t_args(
+                    I18nKey::StartupChangeFailed,
+                    &[("entry", &entry.name), ("error", &e.to_string())],
+                )
+            );
+            if entry.source.machine_wide() && !is_admin() {
+                log_info!(app, "ℹ️  {}", app.t(I18nKey::StartupNeedsAdmin));
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn binary(data: &str) -> RegValue {
+        RegValue {
+            kind: "REG_BINARY".to_string(),
+            data: data.to_string(),
+        }
+    }
+
+    #[test]
+    fn test_executable_of() {
+        assert_eq!(
+            executable_of("\"C:\\Program Files\\Spotify\\Spotify.exe\" /minimized"),
+            "C:\\Program Files\\Spotify\\Spotify.exe"
+        );
+        // Rutas sin comillas con espacios
+        assert_eq!(
+            executable_of("C:\\Program Files\\Realtek\\RtkAudUService64.EXE -background"),
+            "C:\\Program Files\\Realtek\\RtkAudUService64.EXE"
+        );
+        assert_eq!(
+            executable_of("rundll32 shell32.dll,Control_RunDLL"),
+            "rundll32"
+        );
+        assert_eq!(executable_of("  "), "");
+    }
+
+    #[test]
+    fn test_missing_programs() {
+        let dir = std::env::temp_dir().join("win_opt_test_startup_missing");
+        fs::create_dir_all(&dir).unwrap();
+        let program = dir.join("app.exe");
+        fs::write(&program, b"").unwrap();
+
+        assert!(!is_missing(&format!("\"{}\" --tray", program.display())));
+        assert!(is_missing(&format!(
+            "\"{}\"",
+            dir.join("old.exe").display()
+        )));
+        // Sin ruta completa no se puede saber
+        assert!(!is_missing("ctfmon.exe"));
+        assert!(!is_missing(&format!("{}", dir.join("Mi Script").display())));
+
+        fs::remove_dir_all(&dir).unwrap();
+    }
+
+    #[test]
+    fn test_startup_approved_values() {
+        assert!(is_approved(None));
+        assert!(is_approved(Some(&binary("020000000000000000000000"))));
+        assert!(is_approved(Some(&binary("060000000000000000000000"))));
+        assert!(!is_approved(Some(&binary("03000000A1B2C3D4E5F60102"))));
+        assert!(!is_approved(Some(&binary("070000000000000000000000"))));
+
+        let approvals = vec![("Spotify".to_string(), binary("030000000000000000000000"))];
+        assert!(!approved(StartupSource::UserRun, &approvals, "spotify"));
+        assert!(approved(StartupSource::UserRun, &approvals, "OneDrive"));
+        // RunOnce no tiene StartupApproved
+        assert!(approved(StartupSource::UserRunOnce, &approvals, "Spotify"));
+    }
+
+    #[test]
+    fn test_impact_hints() {
+        assert_eq!(impact(StartupSource::Service, false, false), Impact::High);
+        assert_eq!(impact(StartupSource::Service, true, false), Impact::Low);
+        assert_eq!(impact(StartupSource::UserRun, false, false), Impact::Medium);
+        assert_eq!(impact(StartupSource::Task, false, false), Impact::Medium);
+        assert_eq!(
+            impact(StartupSource::MachineRunOnce, false, false),
+            Impact::Low
+        );
+        assert_eq!(impact(StartupSource::Service, false, true), Impact::Low);
+    }
+
+    #[test]
+    fn test_windows_services_are_skipped() {
+        let windows = "C:\\Windows";
+        assert!(is_windows_binary(
+            "C:\\WINDOWS\\system32\\svchost.exe -k netsvcs -p",
+            windows
+        ));
+        assert!(is_windows_binary(
+            "\\SystemRoot\\System32\\drivers\\acpi.sys",
+            windows
+        ));
+        assert!(!is_windows_binary(
+            "\"C:\\Program Files\\TeamViewer\\TeamViewer_Service.exe\"",
+            windows
+        ));
+        // Una carpeta que solo empieza igual no es la de Windows
+        assert!(!is_windows_binary("C:\\WindowsApps\\tool.exe", windows));
+    }
+
+    #[test]
+    fn test_folder_entries_skip_desktop_ini() {
+        let dir = std::env::temp_dir().join("win_opt_test_startup_folder");
+        let _ = fs::remove_dir_all(&dir);
+        fs::create_dir_all(&dir).unwrap();
+        for file in ["desktop.ini", "Send to OneNote.lnk", "notas.bat"] {
+            fs::write(dir.join(file), b"").unwrap();
+        }
+
+        let mut entries = folder_entries(StartupSource::UserFolder, &dir);
+        sort_entries(&mut entries);
+        let names: Vec<(&str, &str)> = entries
+            .iter()
+            .map(|entry| (entry.id.as_str(), entry.name.as_str()))
+            .collect();
+        assert_eq!(
+            names,
+            [
+                ("notas.bat", "notas"),
+                ("Send to OneNote.lnk", "Send to OneNote")
+            ]
+        );
+        assert!(entries.iter().all(|entry| !entry.missing));
+
+        fs::remove_dir_all(&dir).unwrap();
+    }
+}
@@ -0,0 +1,207 @@
+//! Modo solo ASCII
+//!
+//! Algunos terminales (la consola clásica de Windows, la consola de Linux o
+//! sesiones sin UTF-8) muestran los emoji y los caracteres de dibujo de
+//! cajas como cuadros o con el ancho equivocado, lo que descoloca toda la
+//! interfaz. En modo ASCII, cada fotograma ya dibujado se recorre y esos
+//! símbolos se sustituyen por equivalentes ASCII, de modo que el banner, el
+//! menú, los logs y las tarjetas de estadísticas no necesitan variantes
+//! propias.
+
+use ratatui::buffer::Buffer;
+use serde::{Deserialize, Serialize};
+use unicode_width::UnicodeWidthStr;
+
+/// Símbolos que se usan al dibujar la interfaz
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub enum SymbolMode {
+    /// Detectar según el terminal
+    #[default]
+    Auto,
+    /// Emoji y caracteres de dibujo de cajas
+    Unicode,
+    /// Solo caracteres ASCII
+    Ascii,
+}
+
+impl SymbolMode {
+    /// Modo efectivo: el configurado o, en `Auto`, el detectado
+    pub fn resolve(self) -> SymbolMode {
+        match self {
+            SymbolMode::Auto => Self::detect(|name| std::env::var(name).ok()),
+            mode => mode,
+        }
+    }
+
+    /// Detecta si el terminal muestra bien los emoji
+    ///
+    /// Windows Terminal, VS Code, ConEmu y los terminales con un `TERM`
+    /// moderno y configuración regional UTF-8 los muestran bien. La consola
+    /// clásica de Windows, la consola de Linux y los terminales sin UTF-8 no.
+    fn detect(var: impl Fn(&str) -> Option<String>) -> SymbolMode {
+        if var("WT_SESSION").is_some()
+            || var("TERM_PROGRAM").is_some()
+            || var("ConEmuANSI").is_some_and(|value| value == "ON")
+        {
+            return SymbolMode::Unicode;
+        }
+
+        match var("TERM") {
+            Some(term) if matches!(term.as_str(), "dumb" | "linux" | "vt100" | "vt220") => {
+                SymbolMode::Ascii
+            }
+            Some(_) => {
+                let locale = ["LC_ALL", "LC_CTYPE", "LANG"]
+                    .into_iter()
+                    .filter_map(&var)
+                    .find(|value| !value.is_empty());
+                match locale {
+                    Some(locale) if !is_utf8_locale(&locale) => SymbolMode::Ascii,
+                    _ => SymbolMode::Unicode,
+                }
+            }
+            None if cfg!(windows) => SymbolMode::Ascii,
+            None => SymbolMode::Unicode,
+        }
+    }
+}
+
+/// Indica si una configuración regional (`es_ES.UTF-8`) usa UTF-8
+fn is_utf8_locale(locale: &str) -> bool {
+    let upper = locale.to_ascii_uppercase();
+    upper.contains("UTF-8") || upper.contains("UTF8")
+}
+
+/// Equivalente ASCII de un símbolo, o `None` si no hay que cambiarlo
+///
+/// Los emoji sin equivalente propio se muestran como `*`.
+pub fn ascii_symbol(symbol: &str) -> Option<&'static str> {
+    let symbol = symbol.trim_end_matches('\u{fe0f}');
+    let first = symbol.chars().next()?;
+    if first.is_ascii() {
+        return None;
+    }
+
+    let replacement = match first {
+        // Marcas de estado
+        '✅' | '✓' | '✔' => "+",
+        '❌' | '✗' | '✘' => "x",
+        '⚠' | '⛔' | '❗' => "!",
+        '💡' => "i",
+        '🔍' | '🔎' | '❓' => "?",
+        // Líneas y esquinas
+        '─' | '━' | '═' | '╌' | '┄' => "-",
+        '│' | '┃' | '║' | '╎' | '┆' => "|",
+        '┌' | '┐' | '└' | '┘' | '╭' | '╮' | '╯' | '╰' | '╔' | '╗' | '╚' | '╝' | '┏' | '┓' | '┗'
+        | '┛' | '├' | '┤' | '┬' | '┴' | '┼' | '╠' | '╣' | '╦' | '╩' | '╬' => {
+            "+"
+        }
+        // Bloques, barras de progreso y sparklines
+        '█' | '▓' | '▉' | '▊' | '▋' | '▌' | '▍' | '▀' => "#",
+        '▒' => ":",
+        '░' => ".",
+        '▁' | '▂' => "_",
+        '▃' | '▄' | '▅' => "-",
+        '▆' | '▇' => "=",
+        '▎' | '▏' => "|",
+        // Flechas y viñetas
+        '↑' | '▲' => "^",
+        '↓' | '▼' => "v",
+        '←' | '↩' | '◀' => "<",
+        '→' | '▶' | '►' => ">",
+        '↕' => "|",
+        '●' | '◆' | '★' | '■' | '•' => "*",
+        '○' | '◇' | '☆' | '□' => "o",
+        // Spinners
+        '◐' | '◓' | '◑' | '◒' => ["|", "/", "-", "\\"][first as usize % 4],
+        '\u{2800}'..='\u{28ff}' => ["|", "/", "-", "\\"][first as usize % 4],
+        // Cualquier otro emoji o pictograma
+        '\u{2600}'..='\u{27bf}' | '\u{2b00}'..='\u{2bff}' | '\u{1f000}'.. => "*",
+        _ => return None,
+    };
+    Some(replacement)
+}
+
+/// Sustituye los emoji y el dibujo de cajas de un fotograma por ASCII
+///
+/// Los emoji ocupan dos columnas y la celda siguiente queda oculta; al
+/// cambiarlos por un carácter de una columna, esa celda se vuelve visible y
+/// se le da el estilo del símbolo para que no rompa el fondo.
+pub fn to_ascii(buffer: &mut Buffer) {
+    let area = buffer.area;
+    for y in area.top()..area.bottom() {
+        let mut x = area.left();
+        while x < area.right() {
+            let cell = &buffer[(x, y)];
+            let width = cell.symbol().width().max(1) as u16;
+            if let Some(replacement) = ascii_symbol(cell.symbol()) {
+                let style = cell.style();
+                buffer[(x, y)].set_symbol(replacement);
+                for hidden in x + 1..(x + width).min(area.right()) {
+                    buffer[(hidden, y)].set_symbol(" ").set_style(style);
+                }
+            }
+            x += width;
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use ratatui::layout::Rect;
+    use ratatui::style::{Color, Style};
+
+    #[test]
+    fn test_detect_symbol_mode_from_env() {
+        let env = |vars: &'static [(&'static str, &'static str)]| {
+            move |name: &str| {
+                vars.iter()
+                    .find(|(key, _)| *key == name)
+                    .map(|(_, value)| value.to_string())
+            }
+        };
+        assert_eq!(
+            SymbolMode::detect(env(&[("WT_SESSION", "1")])),
+            SymbolMode::Unicode
+        );
+        assert_eq!(
+            SymbolMode::detect(env(&[("TERM", "linux")])),
+            SymbolMode::Ascii
+        );
+        assert_eq!(
+            SymbolMode::detect(env(&[("TERM", "xterm"), ("LANG", "C")])),
+            SymbolMode::Ascii
+        );
+        assert_eq!(
+            SymbolMode::detect(env(&[("TERM", "xterm"), ("LANG", "es_ES.UTF-8")])),
+            SymbolMode::Unicode
+        );
+        assert_eq!(SymbolMode::Ascii.resolve(), SymbolMode::Ascii);
+    }
+
+    #[test]
+    fn test_ascii_symbol() {
+        assert_eq!(ascii_symbol("✅"), Some("+"));
+        assert_eq!(ascii_symbol("⚠\u{fe0f}"), Some("!"));
+        assert_eq!(ascii_symbol("🧹"), Some("*"));
+        assert_eq!(ascii_symbol("╭"), Some("+"));
+        assert_eq!(ascii_symbol("█"), Some("#"));
+        // Las letras acentuadas y el ASCII no cambian
+        assert_eq!(ascii_symbol("ñ"), None);
+        assert_eq!(ascii_symbol("é"), None);
+        assert_eq!(ascii_symbol("a"), None);
+    }
+
+    #[test]
+    fn test_to_ascii_fills_hidden_cells() {
+        let style = Style::default().bg(Color::Blue);
+        let mut buffer = Buffer::empty(Rect::new(0, 0, 8, 1));
+        buffer.set_string(0, 0, "🧹 Año│", style);
+        to_ascii(&mut buffer);
+
+        let line: String = (0..8).map(|x| buffer[(x, 0)].symbol()).collect();
+        assert_eq!(line, "*  Año| ");
+        assert_eq!(buffer[(1, 0)].bg, Color::Blue);
+    }
+}
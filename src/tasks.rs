@@ -0,0 +1,656 @@
+//! Tareas programadas de Windows
+//!
+//! Las tareas se leen, habilitan y deshabilitan con la API COM del
+//! Programador de tareas en lugar de lanzar `schtasks`, así que los errores
+//! llegan como `ControlError` y no dependen del idioma del sistema. La vista
+//! de tareas programadas recorre todas las carpetas, incluidas las tareas
+//! ocultas, y agrupa las tareas por fabricante. El recorrido puede tardar
+//! varios segundos, así que se hace en un thread y la vista se rellena al
+//! terminar.
+
+use crate::error::ControlError;
+use crate::i18n::I18nKey;
+use crate::simulate;
+use crate::types::OperationState;
+use crate::utils::{civil_from_days, days_from_civil};
+use crate::{log_error, log_info, log_warn};
+use std::sync::mpsc::{self, Receiver};
+use std::thread;
+
+/// Estado de una tarea programada (`TASK_STATE`)
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TaskState {
+    Unknown,
+    Disabled,
+    Queued,
+    Ready,
+    Running,
+}
+
+impl TaskState {
+    /// Estado de un valor de `TASK_STATE`
+    pub fn from_code(code: i32) -> TaskState {
+        match code {
+            1 => TaskState::Disabled,
+            2 => TaskState::Queued,
+            3 => TaskState::Ready,
+            4 => TaskState::Running,
+            _ => TaskState::Unknown,
+        }
+    }
+
+    /// Clave de traducción del estado
+    pub fn label_key(self) -> I18nKey {
+        match self {
+            TaskState::Unknown => I18nKey::TaskStateUnknown,
+            TaskState::Disabled => I18nKey::TaskStateDisabled,
+            TaskState::Queued => I18nKey::TaskStateQueued,
+            TaskState::Ready => I18nKey::TaskStateReady,
+            TaskState::Running => I18nKey::TaskStateRunning,
+        }
+    }
+}
+
+/// Tarea programada registrada
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ScheduledTask {
+    /// Ruta completa (`\Microsoft\Windows\Autochk\Proxy`)
+    pub path: String,
+    pub state: TaskState,
+    /// Última ejecución (`yyyy-MM-dd HH:mm`), si se ha ejecutado
+    pub last_run: Option<String>,
+    /// Próxima ejecución, si tiene alguna prevista
+    pub next_run: Option<String>,
+    /// Autor declarado en el registro de la tarea
+    pub author: String,
+    /// Fabricante con el que se agrupa (vacío si no se conoce)
+    pub vendor: String,
+    /// Se ejecuta al iniciar sesión o al arrancar el equipo
+    pub at_startup: bool,
+    /// Programa y argumentos de la primera acción, si ejecuta un programa
+    pub command: String,
+    /// Marcada para habilitar o deshabilitar
+    pub marked: bool,
+}
+
+impl ScheduledTask {
+    /// Tarea a partir de los valores de `IRegisteredTask`
+    fn new(path: String, state: i32, last_run: f64, next_run: f64, author: &str) -> ScheduledTask {
+        ScheduledTask {
+            vendor: vendor_of(&path, author),
+            author: author.trim().to_string(),
+            state: TaskState::from_code(state),
+            last_run: ole_date(last_run),
+            next_run: ole_date(next_run),
+            path,
+            at_startup: false,
+            command: String::new(),
+            marked: false,
+        }
+    }
+
+    /// Nombre de la tarea (último componente de la ruta)
+    pub fn name(&self) -> &str {
+        self.path.rsplit('\\').next().unwrap_or(&self.path)
+    }
+}
+
+/// Fabricante de una tarea
+///
+/// Las tareas de `\Microsoft` son de Microsoft. En el resto se usa el autor,
+/// salvo si es una cuenta (`EQUIPO\usuario`) o una cadena de recursos
+/// (`$(@%SystemRoot%\...)`); en ese caso, la carpeta de primer nivel.
+fn vendor_of(path: &str, author: &str) -> String {
+    let author = author.trim();
+    if path.to_lowercase().starts_with("\\microsoft\\") || author.starts_with("Microsoft") {
+        return "Microsoft".to_string();
+    }
+    if !author.is_empty() && !author.starts_with("$(") && !author.contains('\\') {
+        return author.to_string();
+    }
+    match path.trim_start_matches('\\').split_once('\\') {
+        Some((folder, _)) => folder.to_string(),
+        None => String::new(),
+    }
+}
+
+/// Días entre el origen de las fechas OLE (1899-12-30) y 1970-01-01
+const OLE_EPOCH_DAYS: i64 = 25_569;
+
+/// Convierte una fecha OLE (días desde 1899-12-30, hora local) a
+/// `yyyy-MM-dd HH:mm`
+///
+/// El Programador de tareas devuelve 0 o el 1999-11-30 para las tareas que
+/// nunca se han ejecutado, así que las fechas anteriores a 2000 no cuentan.
+fn ole_date(value: f64) -> Option<String> {
+    if !value.is_finite() || value < (days_from_civil(2000, 1, 1) + OLE_EPOCH_DAYS) as f64 {
+        return None;
+    }
+    let minutes = (value * 1440.0).round() as i64;
+    let (year, month, day) = civil_from_days(minutes.div_euclid(1440) - OLE_EPOCH_DAYS);
+    let minute = minutes.rem_euclid(1440);
+    Some(format!(
+        "{:04}-{:02}-{:02} {:02}:{:02}",
+        year,
+        month,
+        day,
+        minute / 60,
+        minute % 60
+    ))
+}
+
+/// Ordena las tareas por fabricante (las de fabricante desconocido al
+/// final) y ruta
+fn sort_by_vendor(tasks: &mut [ScheduledTask]) {
+    tasks.sort_by_cached_key(|task| {
+        (
+            task.vendor.is_empty(),
+            task.vendor.to_lowercase(),
+            task.path.to_lowercase(),
+        )
+    });
+}
+
+/// Todas las tareas programadas, ordenadas por fabricante
+///
+/// En modo simulación se retornan tareas de ejemplo.
+pub fn list() -> Result<Vec<ScheduledTask>, ControlError> {
+    let mut tasks = if simulate::is_enabled() {
+        simulated()
+    } else {
+        scheduler::list()?
+    };
+    sort_by_vendor(&mut tasks);
+    Ok(tasks)
+}
+
+/// Resultado de [`list`] que llega a la vista
+pub type ListResult = Result<Vec<ScheduledTask>, ControlError>;
+
+/// Lanza [`list`] en un thread y devuelve el receptor del resultado
+pub fn spawn_query() -> Receiver<ListResult> {
+    let (sender, receiver) = mpsc::channel();
+
+    thread::spawn(move || {
+        let _ = sender.send(list());
+    });
+
+    receiver
+}
+
+/// Tareas de ejemplo del modo simulación
+fn simulated() -> Vec<ScheduledTask> {
+    // 2026-03-02 08:30 y 2026-03-03 03:00
+    const LAST: f64 = 46_083.354_166_666_67;
+    const NEXT: f64 = 46_084.125;
+    // Las tareas con programa se ejecutan al iniciar sesión
+    [
+        (
+            "\\Microsoft\\Windows\\Autochk\\Proxy",
+            3,
+            LAST,
+            0.0,
+            "$(@%SystemRoot%\\system32\\sdiagschd.dll,-101)",
+            None,
+        ),
+        (
+            "\\Microsoft\\Windows\\Defrag\\ScheduledDefrag",
+            3,
+            LAST,
+            NEXT,
+            "Microsoft Corporation",
+            None,
+        ),
+        (
+            "\\Microsoft\\Windows\\Customer Experience Improvement Program\\Consolidator",
+            1,
+            0.0,
+            0.0,
+            "Microsoft Corporation",
+            None,
+        ),
+        (
+            "\\GoogleSystem\\GoogleUpdater\\GoogleUpdaterTaskSystem",
+            3,
+            LAST,
+            NEXT,
+            "Google LLC",
+            Some(
+                "\"C:\\Program Files (x86)\\Google\\GoogleUpdater\\130.0.6723.59\\updater.exe\" --wake-all",
+            ),
+        ),
+        (
+            "\\Adobe Acrobat Update Task",
+            4,
+            LAST,
+            NEXT,
+            "Adobe Systems Incorporated",
+            Some("\"C:\\Program Files (x86)\\Common Files\\Adobe\\ARM\\1.0\\AdobeARM.exe\""),
+        ),
+        (
+            "\\OneDrive Reporting Task-S-1-5-21-1004",
+            3,
+            LAST,
+            NEXT,
+            "Microsoft Corporation",
+            None,
+        ),
+        ("\\Backup nocturno", 1, 0.0, 0.0, "DESKTOP-DEMO\\ana", None),
+    ]
+    .into_iter()
+    .map(|(path, state, last, next, author, command)| {
+        let mut task = ScheduledTask::new(path.to_string(), state, last, next, author);
+        if let Some(command) = command {
+            task.at_startup = true;
+            task.command = command.to_string();
+        }
+        task
+    })
+    .collect()
+}
+
+/// Habilita o deshabilita una tarea por su ruta completa
+/// (`\Microsoft\Windows\Autochk\Proxy`)
+///
+/// En modo simulación no se modifica nada.
+pub fn set_enabled(path: &str, enabled: bool) -> Result<(), ControlError> {
+    if simulate::is_enabled() {
+        return Ok(());
+    }
+    scheduler::set_enabled(path, enabled)
+}
+
+/// Acceso al servicio COM del Programador de tareas
+#[cfg(windows)]
+mod scheduler {
+    use super::ScheduledTask;
+    use crate::error::ControlError;
+    use std::mem::ManuallyDrop;
+    use windows::Win32::Foundation::{RPC_E_CHANGED_MODE, VARIANT_FALSE, VARIANT_TRUE};
+    use windows::Win32::System::Com::{
+        CLSCTX_INPROC_SERVER, COINIT_MULTITHREADED, CoCreateInstance, CoInitializeEx,
+        CoUninitialize,
+    };
+    use windows::Win32::System::TaskScheduler::{
+        IExecAction, IRegisteredTask, ITaskDefinition, ITaskFolder, ITaskService, TASK_ACTION_EXEC,
+        TASK_ACTION_TYPE, TASK_ENUM_HIDDEN, TASK_TRIGGER_BOOT, TASK_TRIGGER_LOGON,
+        TASK_TRIGGER_TYPE2, TaskScheduler,
+    };
+    use windows::Win32::System::Variant::{VARIANT, VARIANT_0, VARIANT_0_0, VARIANT_0_0_0, VT_I4};
+    use windows::core::BSTR;
+    use windows::core::Interface;
+
+    /// Inicialización de COM en el hilo actual, deshecha al soltarla
+    struct Apartment {
+        /// El hilo ya tenía COM inicializado en otro modelo y no hay que
+        /// deshacerlo
+        borrowed: bool,
+    }
+
+    impl Apartment {
+        fn enter() -> Result<Apartment, ControlError> {
+            // SAFETY: CoInitializeEx no recibe punteros; cada inicialización
+            // correcta se equilibra con CoUninitialize en `drop`
+            let result = unsafe { CoInitializeEx(None, COINIT_MULTITHREADED) };
+            if result == RPC_E_CHANGED_MODE {
+                return Ok(Apartment { borrowed: true });
+            }
+            result.ok()?;
+            Ok(Apartment { borrowed: false })
+        }
+    }
+
+    impl Drop for Apartment {
+        fn drop(&mut self) {
+            if !self.borrowed {
+                // SAFETY: equilibra el CoInitializeEx correcto de `enter`
+                unsafe { CoUninitialize() };
+            }
+        }
+    }
+
+    /// Conexión con el Programador de tareas local
+    ///
+    /// El servicio se suelta antes que el apartamento COM (los campos se
+    /// sueltan en orden).
+    struct Scheduler {
+        service: ITaskService,
+        _apartment: Apartment,
+    }
+
+    impl Scheduler {
+        fn connect() -> Result<Scheduler, ControlError> {
+            let apartment = Apartment::enter()?;
+            // SAFETY: COM está inicializado en este hilo y los VARIANT vacíos
+            // conectan con el equipo local y el usuario actual
+            let service = unsafe {
+                let service: ITaskService =
+                    CoCreateInstance(&TaskScheduler, None, CLSCTX_INPROC_SERVER)?;
+                let empty = VARIANT::default();
+                service.Connect(&empty, &empty, &empty, &empty)?;
+                service
+            };
+            Ok(Scheduler {
+                service,
+                _apartment: apartment,
+            })
+        }
+    }
+
+    /// Índice (desde 1) de las colecciones del Programador de tareas
+    fn index(value: i32) -> VARIANT {
+        VARIANT {
+            Anonymous: VARIANT_0 {
+                Anonymous: ManuallyDrop::new(VARIANT_0_0 {
+                    vt: VT_I4,
+                    Anonymous: VARIANT_0_0_0 { lVal: value },
+                    ..Default::default()
+                }),
+            },
+        }
+    }
+
+    pub(super) fn list() -> Result<Vec<ScheduledTask>, ControlError> {
+        let scheduler = Scheduler::connect()?;
+        let mut tasks = Vec::new();
+        // SAFETY: las interfaces COM son válidas mientras vive `scheduler`
+        let root = unsafe { scheduler.service.GetFolder(&BSTR::from("\\"))? };
+        collect(&root, &mut tasks);
+        Ok(tasks)
+    }
+
+    /// Añade las tareas de una carpeta y sus subcarpetas
+    ///
+    /// Las carpetas que el usuario no puede leer se omiten.
+    fn collect(folder: &ITaskFolder, tasks: &mut Vec<ScheduledTask>) {
+        // SAFETY: `folder` es una interfaz válida y los índices están dentro
+        // de `Count`
+        unsafe {
+            if let Ok(registered) = folder.GetTasks(TASK_ENUM_HIDDEN.0) {
+                for idx in 1..=registered.Count().unwrap_or(0) {
+                    if let Ok(task) = registered.get_Item(&index(idx)) {
+                        tasks.extend(read_task(&task));
+                    }
+                }
+            }
+            if let Ok(folders) = folder.GetFolders(0) {
+                for idx in 1..=folders.Count().unwrap_or(0) {
+                    if let Ok(child) = folders.get_Item(&index(idx)) {
+                        collect(&child, tasks);
+                    }
+                }
+            }
+        }
+    }
+
+    /// Lee una tarea registrada; el autor, los desencadenadores y el
+    /// programa quedan vacíos si no se puede leer su definición
+    fn read_task(task: &IRegisteredTask) -> Option<ScheduledTask> {
+        // SAFETY: `task` es una interfaz válida y `author` es un BSTR local
+        unsafe {
+            let definition = task.Definition().ok();
+            let mut author = BSTR::new();
+            if let Some(definition) = &definition {
+                let _ = definition
+                    .RegistrationInfo()
+                    .and_then(|info| info.Author(&mut author));
+            }
+            let mut scheduled = ScheduledTask::new(
+                task.Path().ok()?.to_string(),
+                task.State().map_or(0, |state| state.0),
+                task.LastRunTime().unwrap_or(0.0),
+                task.NextRunTime().unwrap_or(0.0),
+                &author.to_string(),
+            );
+            if let Some(definition) = &definition {
+                scheduled.at_startup = runs_at_startup(definition);
+                scheduled.command = exec_command(definition).unwrap_or_default();
+            }
+            Some(scheduled)
+        }
+    }
+
+    /// Indica si la tarea tiene un desencadenador de inicio de sesión o de
+    /// arranque
+    fn runs_at_startup(definition: &ITaskDefinition) -> bool {
+        // SAFETY: `definition` es una interfaz válida, los índices (desde 1)
+        // están dentro de `Count` y `kind` es una variable local
+        unsafe {
+            let Ok(triggers) = definition.Triggers() else {
+                return false;
+            };
+            let mut count = 0;
+            if triggers.Count(&mut count).is_err() {
+                return false;
+            }
+            (1..=count).any(|idx| {
+                let mut kind = TASK_TRIGGER_TYPE2::default();
+                triggers
+                    .get_Item(idx)
+                    .and_then(|trigger| trigger.Type(&mut kind))
+                    .is_ok()
+                    && (kind == TASK_TRIGGER_LOGON || kind == TASK_TRIGGER_BOOT)
+            })
+        }
+    }
+
+    /// Programa y argumentos de la primera acción, si ejecuta un programa
+    fn exec_command(definition: &ITaskDefinition) -> Option<String> {
+        // SAFETY: `definition` es una interfaz válida y los BSTR y `kind` son
+        // variables locales
+        unsafe {
+            let action = definition.Actions().ok()?.get_Item(1).ok()?;
+            let mut kind = TASK_ACTION_TYPE::default();
+            action.Type(&mut kind).ok()?;
+            if kind != TASK_ACTION_EXEC {
+                return None;
+            }
+            let exec: IExecAction = action.cast().ok()?;
+            let (mut path, mut arguments) = (BSTR::new(), BSTR::new());
+            exec.Path(&mut path).ok()?;
+            let _ = exec.Arguments(&mut arguments);
+            Some(format!("{} {}", path, arguments).trim_end().to_string())
+        }
+    }
+
+    pub(super) fn set_enabled(path: &str, enabled: bool) -> Result<(), ControlError> {
+        let scheduler = Scheduler::connect()?;
+        // SAFETY: las interfaces COM son válidas mientras vive `scheduler`
+        unsafe {
+            let root = scheduler.service.GetFolder(&BSTR::from("\\"))?;
+            let task = root.GetTask(&BSTR::from(path))?;
+            task.SetEnabled(if enabled { VARIANT_TRUE } else { VARIANT_FALSE })?;
+        }
+        Ok(())
+    }
+}
+
+/// Empieza a listar las tareas programadas de la vista
+///
+/// La lista llega a [`finish_tasks_list`] cuando termina la consulta.
+pub fn execute_tasks_list(app: &mut crate::app::App) {
+    app.operation_state = OperationState::Running;
+    app.scheduled_tasks.clear();
+    log_info!(app, "📅 {}", app.t(I18nKey::TasksLoading));
+    app.tasks_receiver = Some(spawn_query());
+}
+
+/// Muestra en la vista las tareas listadas en segundo plano
+pub fn finish_tasks_list(app: &mut crate::app::App, result: ListResult) {
+    match result {
+        Ok(tasks) => app.scheduled_tasks = tasks,
+        Err(e) => {
+            log_error!(
+                app,
+                "❌ {}",
+                app.t_args(I18nKey::TasksListFailed, &[("error", &e.to_string())])
+            );
+            app.operation_state = OperationState::Failed;
+            return;
+        }
+    }
+
+    let mut vendors: Vec<&str> = app
+        .scheduled_tasks
+        .iter()
+        .map(|task| task.vendor.as_str())
+        .collect();
+    vendors.dedup();
+    let (total, vendors) = (app.scheduled_tasks.len(), vendors.len());
+    log_info!(
+        app,
+        "✅ {}",
+        app.t_args(
+            I18nKey::TasksFound,
+            &[
+                ("count", &total.to_string()),
+                ("vendors", &vendors.to_string())
+            ],
+        )
+    );
+    if total > 0 {
+        log_info!(app, "💡 {}", app.t(I18nKey::TasksHint));
+    }
+    app.operation_state = OperationState::Completed;
+}
+
+/// Marca o desmarca la tarea indicada
+pub fn toggle_mark(app: &mut crate::app::App, index: usize) {
+    if let Some(task) = app.scheduled_tasks.get_mut(index) {
+        task.marked = !task.marked;
+    }
+}
+
+/// Tareas sobre las que actuar: las marcadas o, si no hay, la seleccionada
+fn target_indices(app: &crate::app::App) -> Vec<usize> {
+    let marked: Vec<usize> = app
+        .scheduled_tasks
+        .iter()
+        .enumerate()
+        .filter(|(_, task)| task.marked)
+        .map(|(idx, _)| idx)
+        .collect();
+    if !marked.is_empty() || app.selected_item >= app.scheduled_tasks.len() {
+        return marked;
+    }
+    vec![app.selected_item]
+}
+
+/// Habilita o deshabilita las tareas marcadas (o la seleccionada)
+///
+/// Las tareas modificadas se actualizan en la lista y se desmarcan; las
+/// del sistema requieren permisos de administrador.
+pub fn execute_set_tasks_enabled(app: &mut crate::app::App, enabled: bool) {
+    let targets = target_indices(app);
+    if targets.is_empty() {
+        return;
+    }
+
+    log_info!(app, "");
+    let (done_key, state) = if enabled {
+        (I18nKey::TasksEnabled, TaskState::Ready)
+    } else {
+        (I18nKey::TasksDisabled, TaskState::Disabled)
+    };
+    let mut denied = false;
+    for idx in targets {
+        let path = app.scheduled_tasks[idx].path.clone();
+        match set_enabled(&path, enabled) {
+            Ok(()) => {
+                let task = &mut app.scheduled_tasks[idx];
+                task.state = state;
+                task.marked = false;
+                log_info!(app, "✅ {}", app.t_args(done_key, &[("task", &path)]));
+            }
+            Err(e) => {
+                denied |= e == ControlError::AccessDenied;
+                log_warn!(
+                    app,
+                    "⚠️  {}",
+                    app.t_args(
+                        I18nKey::TasksChangeFailed,
+                        &[("task", &path), ("error", &e.to_string())],
+                    )
+                );
+            }
+        }
+    }
+    if denied {
+        log_info!(app, "ℹ️  {}", app.t(I18nKey::TasksAccessDenied));
+    }
+}
+
+#[cfg(not(windows))]
+mod scheduler {
+    use super::ScheduledTask;
+    use crate::error::ControlError;
+
+    pub(super) fn list() -> Result<Vec<ScheduledTask>, ControlError> {
+        Err(ControlError::Unsupported)
+    }
+
+    pub(super) fn set_enabled(_path: &str, _enabled: bool) -> Result<(), ControlError> {
+        Err(ControlError::Unsupported)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_ole_dates() {
+        assert_eq!(
+            ole_date(46_083.354_166_666_67).as_deref(),
+            Some("2026-03-02 08:30")
+        );
+        assert_eq!(ole_date(46_084.125).as_deref(), Some("2026-03-03 03:00"));
+        // Tareas que nunca se han ejecutado
+        assert_eq!(ole_date(0.0), None);
+        assert_eq!(ole_date(36_494.0), None);
+    }
+
+    #[test]
+    fn test_vendor_of() {
+        assert_eq!(
+            vendor_of(
+                "\\Microsoft\\Windows\\Autochk\\Proxy",
+                "$(@%SystemRoot%\\system32\\sdiagschd.dll,-101)"
+            ),
+            "Microsoft"
+        );
+        assert_eq!(
+            vendor_of("\\OneDrive Reporting Task", "Microsoft Corporation"),
+            "Microsoft"
+        );
+        assert_eq!(
+            vendor_of("\\GoogleSystem\\GoogleUpdater\\Task", " Google LLC "),
+            "Google LLC"
+        );
+        // Una cuenta no es un fabricante: se usa la carpeta
+        assert_eq!(vendor_of("\\Mozilla\\Update", "PC\\ana"), "Mozilla");
+        assert_eq!(vendor_of("\\Backup nocturno", "PC\\ana"), "");
+    }
+
+    #[test]
+    fn test_tasks_are_grouped_by_vendor() {
+        let mut tasks = simulated();
+        sort_by_vendor(&mut tasks);
+        let vendors: Vec<&str> = tasks.iter().map(|task| task.vendor.as_str()).collect();
+        assert_eq!(
+            vendors,
+            [
+                "Adobe Systems Incorporated",
+                "Google LLC",
+                "Microsoft",
+                "Microsoft",
+                "Microsoft",
+                "Microsoft",
+                ""
+            ]
+        );
+        assert_eq!(tasks[0].state, TaskState::Running);
+        assert_eq!(tasks[6].name(), "Backup nocturno");
+        assert_eq!(tasks[6].last_run, None);
+    }
+}
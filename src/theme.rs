@@ -1,11 +1,313 @@
+use crate::i18n::I18nKey;
 use ratatui::style::Color;
 use serde::{Deserialize, Serialize};
+use std::str::FromStr;
 
 /// Tema de la aplicación
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
 pub enum Theme {
     Light,
     Dark,
+    Solarized,
+    Nord,
+    /// Colores puros sobre negro para baja visión
+    HighContrast,
+    /// Paleta definida en `[theme.custom]`
+    Custom,
+}
+
+impl Theme {
+    /// Temas incluidos en la aplicación, en el orden en que se ofrecen
+    pub const BUILT_IN: [Theme; 5] = [
+        Theme::Light,
+        Theme::Dark,
+        Theme::Solarized,
+        Theme::Nord,
+        Theme::HighContrast,
+    ];
+
+    /// Tema siguiente al pulsar Tab
+    ///
+    /// El personalizado solo entra en el ciclo si hay colores definidos.
+    pub fn next(self, has_custom: bool) -> Self {
+        match Self::BUILT_IN.iter().position(|&theme| theme == self) {
+            Some(last) if last + 1 == Self::BUILT_IN.len() && has_custom => Theme::Custom,
+            Some(index) => Self::BUILT_IN[(index + 1) % Self::BUILT_IN.len()],
+            None => Self::BUILT_IN[0],
+        }
+    }
+
+    /// Clave de traducción del nombre del tema
+    pub fn label_key(self) -> I18nKey {
+        match self {
+            Theme::Light => I18nKey::ThemeLight,
+            Theme::Dark => I18nKey::ThemeDark,
+            Theme::Solarized => I18nKey::ThemeSolarized,
+            Theme::Nord => I18nKey::ThemeNord,
+            Theme::HighContrast => I18nKey::ThemeHighContrast,
+            Theme::Custom => I18nKey::ThemeCustom,
+        }
+    }
+}
+
+impl FromStr for Theme {
+    type Err = String;
+
+    /// Interpreta el nombre del tema (sin distinguir mayúsculas)
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        match value.trim().to_ascii_lowercase().as_str() {
+            "light" => Ok(Theme::Light),
+            "dark" => Ok(Theme::Dark),
+            "solarized" => Ok(Theme::Solarized),
+            "nord" => Ok(Theme::Nord),
+            "high-contrast" | "highcontrast" | "high_contrast" => Ok(Theme::HighContrast),
+            "custom" => Ok(Theme::Custom),
+            _ => Err(format!(
+                "tema no válido: {} (light, dark, solarized, nord, high-contrast o custom)",
+                value
+            )),
+        }
+    }
+}
+
+/// Colores que admite el terminal
+///
+/// Con menos de 24 bits de color, la paleta se traduce a los colores más
+/// parecidos de la paleta de 256 colores de xterm o de los 16 colores ANSI,
+/// en lugar de dejar que el terminal los muestre en gris.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub enum ColorMode {
+    /// Detectar según las variables de entorno del terminal
+    #[default]
+    Auto,
+    /// Color de 24 bits
+    TrueColor,
+    /// Paleta de 256 colores de xterm
+    Ansi256,
+    /// Los 16 colores ANSI básicos
+    Ansi16,
+}
+
+impl ColorMode {
+    /// Modo efectivo: el configurado o, en `Auto`, el detectado
+    pub fn resolve(self) -> ColorMode {
+        match self {
+            ColorMode::Auto => Self::detect(|name| std::env::var(name).ok()),
+            mode => mode,
+        }
+    }
+
+    /// Detecta los colores del terminal a partir de sus variables de entorno
+    ///
+    /// Windows Terminal, VS Code, ConEmu y los terminales que anuncian
+    /// `COLORTERM=truecolor` admiten 24 bits. Si no hay pistas, se usa la
+    /// paleta de 256 colores en Windows (la consola clásica la admite desde
+    /// Windows 10) y los 16 colores básicos en el resto.
+    fn detect(var: impl Fn(&str) -> Option<String>) -> ColorMode {
+        let colorterm = var("COLORTERM").unwrap_or_default().to_ascii_lowercase();
+        if colorterm == "truecolor" || colorterm == "24bit" {
+            return ColorMode::TrueColor;
+        }
+        if var("WT_SESSION").is_some()
+            || var("TERM_PROGRAM").is_some()
+            || var("ConEmuANSI").is_some_and(|value| value == "ON")
+        {
+            return ColorMode::TrueColor;
+        }
+        match var("TERM") {
+            Some(term) if term.contains("256color") => ColorMode::Ansi256,
+            Some(_) => ColorMode::Ansi16,
+            None if cfg!(windows) => ColorMode::Ansi256,
+            None => ColorMode::Ansi16,
+        }
+    }
+}
+
+/// Los 16 colores ANSI con sus valores habituales en xterm
+const ANSI16: [(Color, (u8, u8, u8)); 16] = [
+    (Color::Black, (0, 0, 0)),
+    (Color::Red, (205, 0, 0)),
+    (Color::Green, (0, 205, 0)),
+    (Color::Yellow, (205, 205, 0)),
+    (Color::Blue, (0, 0, 238)),
+    (Color::Magenta, (205, 0, 205)),
+    (Color::Cyan, (0, 205, 205)),
+    (Color::Gray, (229, 229, 229)),
+    (Color::DarkGray, (127, 127, 127)),
+    (Color::LightRed, (255, 0, 0)),
+    (Color::LightGreen, (0, 255, 0)),
+    (Color::LightYellow, (255, 255, 0)),
+    (Color::LightBlue, (92, 92, 255)),
+    (Color::LightMagenta, (255, 0, 255)),
+    (Color::LightCyan, (0, 255, 255)),
+    (Color::White, (255, 255, 255)),
+];
+
+/// Niveles de cada canal en el cubo de 6x6x6 de la paleta de 256 colores
+const CUBE_LEVELS: [u8; 6] = [0, 95, 135, 175, 215, 255];
+
+/// Distancia al cuadrado entre dos colores RGB
+fn distance((r1, g1, b1): (u8, u8, u8), (r2, g2, b2): (u8, u8, u8)) -> u32 {
+    let d = |a: u8, b: u8| (i32::from(a) - i32::from(b)).unsigned_abs();
+    d(r1, r2).pow(2) + d(g1, g2).pow(2) + d(b1, b2).pow(2)
+}
+
+/// Color más parecido de la paleta de 256 colores (cubo o escala de grises)
+fn nearest_ansi256(rgb: (u8, u8, u8)) -> u8 {
+    let level = |channel: u8| {
+        (0..6)
+            .min_by_key(|&i| CUBE_LEVELS[i].abs_diff(channel))
+            .unwrap_or(0)
+    };
+    let (r, g, b) = (level(rgb.0), level(rgb.1), level(rgb.2));
+    let cube = (CUBE_LEVELS[r], CUBE_LEVELS[g], CUBE_LEVELS[b]);
+    let cube_index = 16 + 36 * r + 6 * g + b;
+
+    // Escala de grises 232-255: 8, 18, ..., 238
+    let average = (u16::from(rgb.0) + u16::from(rgb.1) + u16::from(rgb.2)) / 3;
+    let gray_step = (average.saturating_sub(3) / 10).min(23) as u8;
+    let gray_value = 8 + gray_step * 10;
+    let gray = (gray_value, gray_value, gray_value);
+
+    if distance(rgb, gray) < distance(rgb, cube) {
+        232 + gray_step
+    } else {
+        cube_index as u8
+    }
+}
+
+/// Color más parecido de los 16 colores ANSI
+fn nearest_ansi16(rgb: (u8, u8, u8)) -> Color {
+    ANSI16
+        .iter()
+        .min_by_key(|(_, value)| distance(rgb, *value))
+        .map_or(Color::Reset, |(color, _)| *color)
+}
+
+/// Adapta un color a los que admite el terminal
+fn adapt_color(color: Color, mode: ColorMode) -> Color {
+    let Color::Rgb(r, g, b) = color else {
+        return color;
+    };
+    match mode {
+        ColorMode::Auto | ColorMode::TrueColor => color,
+        ColorMode::Ansi256 => Color::Indexed(nearest_ansi256((r, g, b))),
+        ColorMode::Ansi16 => nearest_ansi16((r, g, b)),
+    }
+}
+
+/// Configuración de temas (`[theme]` en `config.toml`)
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct ThemeConfig {
+    /// Colores del tema personalizado
+    #[serde(default)]
+    pub custom: CustomPalette,
+}
+
+/// Colores del tema personalizado en formato `#RRGGBB`
+///
+/// Los colores que falten o no sean válidos se toman del tema oscuro.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct CustomPalette {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub brand_primary: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub brand_secondary: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub brand_accent: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub success_color: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub warning_color: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub error_color: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub info_color: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub text_primary: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub text_secondary: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub bg_main: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub bg_alt: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub selection_bg: Option<String>,
+}
+
+impl CustomPalette {
+    /// Colores definidos, con el nombre de su clave
+    fn entries(&self) -> [(&'static str, &Option<String>); 12] {
+        [
+            ("brand_primary", &self.brand_primary),
+            ("brand_secondary", &self.brand_secondary),
+            ("brand_accent", &self.brand_accent),
+            ("success_color", &self.success_color),
+            ("warning_color", &self.warning_color),
+            ("error_color", &self.error_color),
+            ("info_color", &self.info_color),
+            ("text_primary", &self.text_primary),
+            ("text_secondary", &self.text_secondary),
+            ("bg_main", &self.bg_main),
+            ("bg_alt", &self.bg_alt),
+            ("selection_bg", &self.selection_bg),
+        ]
+    }
+
+    /// Indica si no hay ningún color definido
+    pub fn is_empty(&self) -> bool {
+        self.entries().iter().all(|(_, value)| value.is_none())
+    }
+
+    /// Claves con un color que no es `#RRGGBB`, junto con su valor
+    pub fn invalid_colors(&self) -> Vec<(&'static str, &str)> {
+        self.entries()
+            .into_iter()
+            .filter_map(|(name, value)| {
+                value
+                    .as_deref()
+                    .filter(|value| parse_hex_color(value).is_none())
+                    .map(|value| (name, value))
+            })
+            .collect()
+    }
+
+    /// Paleta resultante sobre la base del tema oscuro
+    pub fn palette(&self) -> ColorPalette {
+        let base = ColorPalette::dark();
+        let pick = |value: &Option<String>, fallback: Color| {
+            value
+                .as_deref()
+                .and_then(parse_hex_color)
+                .unwrap_or(fallback)
+        };
+        ColorPalette {
+            brand_primary: pick(&self.brand_primary, base.brand_primary),
+            brand_secondary: pick(&self.brand_secondary, base.brand_secondary),
+            brand_accent: pick(&self.brand_accent, base.brand_accent),
+            success_color: pick(&self.success_color, base.success_color),
+            warning_color: pick(&self.warning_color, base.warning_color),
+            error_color: pick(&self.error_color, base.error_color),
+            info_color: pick(&self.info_color, base.info_color),
+            text_primary: pick(&self.text_primary, base.text_primary),
+            text_secondary: pick(&self.text_secondary, base.text_secondary),
+            bg_main: pick(&self.bg_main, base.bg_main),
+            bg_alt: pick(&self.bg_alt, base.bg_alt),
+            selection_bg: pick(&self.selection_bg, base.selection_bg),
+        }
+    }
+}
+
+/// Interpreta un color `#RRGGBB` (la almohadilla es opcional)
+pub fn parse_hex_color(value: &str) -> Option<Color> {
+    let hex = value.trim();
+    let hex = hex.strip_prefix('#').unwrap_or(hex);
+    if hex.len() != 6 || !hex.chars().all(|c| c.is_ascii_hexdigit()) {
+        return None;
+    }
+    let channel = |range| u8::from_str_radix(&hex[range], 16).ok();
+    Some(Color::Rgb(channel(0..2)?, channel(2..4)?, channel(4..6)?))
 }
 
 /// Paleta de colores
@@ -62,11 +364,90 @@ impl ColorPalette {
         }
     }
 
+    /// Paleta Solarized oscura de Ethan Schoonover
+    pub fn solarized() -> Self {
+        Self {
+            brand_primary: Color::Rgb(38, 139, 210),    // blue
+            brand_secondary: Color::Rgb(108, 113, 196), // violet
+            brand_accent: Color::Rgb(211, 54, 130),     // magenta
+            success_color: Color::Rgb(133, 153, 0),     // green
+            warning_color: Color::Rgb(181, 137, 0),     // yellow
+            error_color: Color::Rgb(220, 50, 47),       // red
+            info_color: Color::Rgb(42, 161, 152),       // cyan
+            text_primary: Color::Rgb(238, 232, 213),    // base2
+            text_secondary: Color::Rgb(147, 161, 161),  // base1
+            bg_main: Color::Rgb(0, 43, 54),             // base03
+            bg_alt: Color::Rgb(7, 54, 66),              // base02
+            selection_bg: Color::Rgb(14, 75, 90),       // base02 aclarado
+        }
+    }
+
+    /// Paleta Nord (polar night, snow storm, frost y aurora)
+    pub fn nord() -> Self {
+        Self {
+            brand_primary: Color::Rgb(136, 192, 208),   // nord8
+            brand_secondary: Color::Rgb(129, 161, 193), // nord9
+            brand_accent: Color::Rgb(180, 142, 173),    // nord15
+            success_color: Color::Rgb(163, 190, 140),   // nord14
+            warning_color: Color::Rgb(235, 203, 139),   // nord13
+            error_color: Color::Rgb(191, 97, 106),      // nord11
+            info_color: Color::Rgb(94, 129, 172),       // nord10
+            text_primary: Color::Rgb(236, 239, 244),    // nord6
+            text_secondary: Color::Rgb(216, 222, 233),  // nord4
+            bg_main: Color::Rgb(46, 52, 64),            // nord0
+            bg_alt: Color::Rgb(59, 66, 82),             // nord1
+            selection_bg: Color::Rgb(67, 76, 94),       // nord2
+        }
+    }
+
+    /// Paleta de alto contraste: colores puros sobre fondo negro
+    pub fn high_contrast() -> Self {
+        Self {
+            brand_primary: Color::Rgb(255, 255, 0),    // Amarillo
+            brand_secondary: Color::Rgb(0, 255, 255),  // Cian
+            brand_accent: Color::Rgb(255, 0, 255),     // Magenta
+            success_color: Color::Rgb(0, 255, 0),      // Verde
+            warning_color: Color::Rgb(255, 165, 0),    // Naranja
+            error_color: Color::Rgb(255, 64, 64),      // Rojo
+            info_color: Color::Rgb(0, 255, 255),       // Cian
+            text_primary: Color::Rgb(255, 255, 255),   // Blanco
+            text_secondary: Color::Rgb(230, 230, 230), // Gris muy claro
+            bg_main: Color::Rgb(0, 0, 0),              // Negro
+            bg_alt: Color::Rgb(0, 0, 0),               // Negro
+            selection_bg: Color::Rgb(0, 51, 153),      // Azul intenso
+        }
+    }
+
+    /// Traduce la paleta a los colores que admite el terminal
+    pub fn with_mode(self, mode: ColorMode) -> Self {
+        let adapt = |color| adapt_color(color, mode);
+        Self {
+            brand_primary: adapt(self.brand_primary),
+            brand_secondary: adapt(self.brand_secondary),
+            brand_accent: adapt(self.brand_accent),
+            success_color: adapt(self.success_color),
+            warning_color: adapt(self.warning_color),
+            error_color: adapt(self.error_color),
+            info_color: adapt(self.info_color),
+            text_primary: adapt(self.text_primary),
+            text_secondary: adapt(self.text_secondary),
+            bg_main: adapt(self.bg_main),
+            bg_alt: adapt(self.bg_alt),
+            selection_bg: adapt(self.selection_bg),
+        }
+    }
+
     /// Obtiene la paleta según el tema
-    pub fn from_theme(theme: Theme) -> Self {
+    ///
+    /// El tema personalizado usa los colores de `custom`.
+    pub fn from_theme(theme: Theme, custom: &CustomPalette) -> Self {
         match theme {
             Theme::Light => Self::light(),
             Theme::Dark => Self::dark(),
+            Theme::Solarized => Self::solarized(),
+            Theme::Nord => Self::nord(),
+            Theme::HighContrast => Self::high_contrast(),
+            Theme::Custom => custom.palette(),
         }
     }
 }
@@ -82,6 +463,102 @@ mod tests {
         assert_eq!(theme1, theme2);
     }
 
+    #[test]
+    fn test_theme_from_str() {
+        assert_eq!("Light".parse::<Theme>(), Ok(Theme::Light));
+        assert_eq!(" dark ".parse::<Theme>(), Ok(Theme::Dark));
+        assert_eq!("CUSTOM".parse::<Theme>(), Ok(Theme::Custom));
+        assert!("blue".parse::<Theme>().is_err());
+    }
+
+    #[test]
+    fn test_theme_cycle_skips_empty_custom() {
+        assert_eq!(Theme::Light.next(true), Theme::Dark);
+        assert_eq!(Theme::Dark.next(true), Theme::Solarized);
+        assert_eq!(Theme::HighContrast.next(true), Theme::Custom);
+        assert_eq!(Theme::Custom.next(true), Theme::Light);
+        assert_eq!(Theme::HighContrast.next(false), Theme::Light);
+    }
+
+    #[test]
+    fn test_detect_color_mode_from_env() {
+        let env = |vars: &'static [(&'static str, &'static str)]| {
+            move |name: &str| {
+                vars.iter()
+                    .find(|(key, _)| *key == name)
+                    .map(|(_, value)| value.to_string())
+            }
+        };
+        assert_eq!(
+            ColorMode::detect(env(&[("COLORTERM", "truecolor"), ("TERM", "xterm")])),
+            ColorMode::TrueColor
+        );
+        assert_eq!(
+            ColorMode::detect(env(&[("WT_SESSION", "1")])),
+            ColorMode::TrueColor
+        );
+        assert_eq!(
+            ColorMode::detect(env(&[("TERM", "xterm-256color")])),
+            ColorMode::Ansi256
+        );
+        assert_eq!(
+            ColorMode::detect(env(&[("TERM", "vt100")])),
+            ColorMode::Ansi16
+        );
+        assert_eq!(ColorMode::TrueColor.resolve(), ColorMode::TrueColor);
+    }
+
+    #[test]
+    fn test_palette_falls_back_to_ansi_colors() {
+        assert_eq!(nearest_ansi256((0, 0, 0)), 16);
+        assert_eq!(nearest_ansi256((255, 255, 255)), 231);
+        assert_eq!(nearest_ansi256((128, 128, 128)), 244);
+        assert_eq!(nearest_ansi256((255, 0, 0)), 196);
+        assert_eq!(nearest_ansi16((250, 10, 10)), Color::LightRed);
+        assert_eq!(nearest_ansi16((15, 23, 42)), Color::Black);
+
+        let dark = ColorPalette::dark();
+        let palette = dark.with_mode(ColorMode::Ansi16);
+        assert_eq!(palette.bg_main, Color::Black);
+        assert!(matches!(
+            dark.with_mode(ColorMode::Ansi256).brand_primary,
+            Color::Indexed(_)
+        ));
+        assert_eq!(
+            dark.with_mode(ColorMode::TrueColor).brand_primary,
+            dark.brand_primary
+        );
+    }
+
+    #[test]
+    fn test_high_contrast_uses_black_background() {
+        let palette = ColorPalette::from_theme(Theme::HighContrast, &CustomPalette::default());
+        assert_eq!(palette.bg_main, Color::Rgb(0, 0, 0));
+        assert_eq!(palette.text_primary, Color::Rgb(255, 255, 255));
+        assert_eq!("High-Contrast".parse::<Theme>(), Ok(Theme::HighContrast));
+    }
+
+    #[test]
+    fn test_custom_palette_from_hex() {
+        assert_eq!(parse_hex_color("#1e90FF"), Some(Color::Rgb(30, 144, 255)));
+        assert_eq!(parse_hex_color("000000"), Some(Color::Rgb(0, 0, 0)));
+        assert_eq!(parse_hex_color("#12345"), None);
+        assert_eq!(parse_hex_color("#ggggggg"), None);
+
+        let custom = CustomPalette {
+            bg_main: Some("#101010".to_string()),
+            brand_primary: Some("rojo".to_string()),
+            ..CustomPalette::default()
+        };
+        assert!(!custom.is_empty());
+        assert_eq!(custom.invalid_colors(), vec![("brand_primary", "rojo")]);
+
+        // Los colores no válidos o ausentes se toman del tema oscuro
+        let palette = custom.palette();
+        assert_eq!(palette.bg_main, Color::Rgb(16, 16, 16));
+        assert_eq!(palette.brand_primary, ColorPalette::dark().brand_primary);
+    }
+
     #[test]
     fn test_light_and_dark_palettes_are_different() {
         let light = ColorPalette::light();
@@ -99,14 +576,14 @@ mod tests {
     #[test]
     fn test_from_theme_consistency() {
         // Verificar que from_theme(Light) == light()
-        let light1 = ColorPalette::from_theme(Theme::Light);
+        let light1 = ColorPalette::from_theme(Theme::Light, &CustomPalette::default());
         let light2 = ColorPalette::light();
 
         assert_eq!(light1.bg_main, light2.bg_main);
         assert_eq!(light1.brand_primary, light2.brand_primary);
 
         // Verificar que from_theme(Dark) == dark()
-        let dark1 = ColorPalette::from_theme(Theme::Dark);
+        let dark1 = ColorPalette::from_theme(Theme::Dark, &CustomPalette::default());
         let dark2 = ColorPalette::dark();
 
         assert_eq!(dark1.bg_main, dark2.bg_main);
@@ -0,0 +1,123 @@
+//! Tiempo transcurrido y tiempo restante de las operaciones
+//!
+//! Cada operación guarda cuándo empezó para mostrar el tiempo transcurrido
+//! junto al spinner. Si el comando en curso informa de su porcentaje (DISM,
+//! SFC), se estima el tiempo restante a partir del ritmo de avance desde el
+//! primer porcentaje recibido. La estimación es aproximada: supone que el
+//! resto del comando avanza al mismo ritmo.
+
+use std::time::{Duration, Instant};
+
+/// Segundos mínimos de avance antes de dar una estimación
+const MIN_SAMPLE_SECS: f64 = 3.0;
+
+/// Avance mínimo (en puntos de porcentaje) antes de dar una estimación
+const MIN_SAMPLE_PERCENT: f32 = 1.0;
+
+/// Reloj de la operación en curso
+#[derive(Debug, Clone, Copy)]
+pub struct OperationTimer {
+    started: Instant,
+    /// Primer porcentaje del comando en curso y cuándo llegó
+    first: Option<(Instant, f32)>,
+    /// Último porcentaje del comando en curso y cuándo llegó
+    last: Option<(Instant, f32)>,
+}
+
+impl OperationTimer {
+    /// Empieza a contar ahora
+    pub fn start() -> Self {
+        Self::since(Instant::now())
+    }
+
+    /// Cuenta desde un momento anterior (una tarea que ya estaba en marcha)
+    pub fn since(started: Instant) -> Self {
+        Self {
+            started,
+            first: None,
+            last: None,
+        }
+    }
+
+    /// Segundos transcurridos desde el inicio
+    pub fn elapsed_secs(&self) -> u64 {
+        self.started.elapsed().as_secs()
+    }
+
+    /// Registra el porcentaje completado del comando en curso
+    pub fn record_progress(&mut self, percent: f32) {
+        let now = Instant::now();
+        // Un porcentaje menor indica que empezó otro comando
+        if self.last.is_some_and(|(_, last)| percent < last) {
+            self.first = None;
+        }
+        self.first.get_or_insert((now, percent));
+        self.last = Some((now, percent));
+    }
+
+    /// Olvida el avance registrado (al pasar a otro paso de la operación)
+    pub fn reset_progress(&mut self) {
+        self.first = None;
+        self.last = None;
+    }
+
+    /// Segundos que faltan aproximadamente, si hay avance suficiente
+    pub fn eta_secs(&self) -> Option<u64> {
+        let ((first_at, first), (last_at, last)) = (self.first?, self.last?);
+        let remaining = estimate_remaining(last_at - first_at, first, last)?;
+        Some(remaining.saturating_sub(last_at.elapsed().as_secs()))
+    }
+}
+
+/// Segundos que faltan para llegar al 100% si se avanzó de `from` a `to` en
+/// `elapsed`
+///
+/// Retorna `None` si el avance es demasiado corto para estimar.
+pub fn estimate_remaining(elapsed: Duration, from: f32, to: f32) -> Option<u64> {
+    let progressed = to - from;
+    let secs = elapsed.as_secs_f64();
+    if progressed < MIN_SAMPLE_PERCENT || secs < MIN_SAMPLE_SECS {
+        return None;
+    }
+    let rate = f64::from(progressed) / secs;
+    Some((f64::from(100.0 - to.min(100.0)) / rate).round() as u64)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_estimate_remaining() {
+        // 10 puntos en 20 s: quedan 60 puntos, 120 s
+        assert_eq!(
+            estimate_remaining(Duration::from_secs(20), 30.0, 40.0),
+            Some(120)
+        );
+        assert_eq!(
+            estimate_remaining(Duration::from_secs(10), 50.0, 100.0),
+            Some(0)
+        );
+        // Muy poco avance o muy poco tiempo: sin estimación
+        assert_eq!(
+            estimate_remaining(Duration::from_secs(20), 30.0, 30.5),
+            None
+        );
+        assert_eq!(estimate_remaining(Duration::from_secs(1), 10.0, 50.0), None);
+    }
+
+    #[test]
+    fn test_timer_needs_progress_for_eta() {
+        let mut timer = OperationTimer::start();
+        assert_eq!(timer.elapsed_secs(), 0);
+        assert_eq!(timer.eta_secs(), None);
+
+        timer.record_progress(10.0);
+        timer.record_progress(20.0);
+        // Sin tiempo suficiente entre muestras todavía no hay estimación
+        assert_eq!(timer.eta_secs(), None);
+
+        timer.reset_progress();
+        assert!(timer.first.is_none() && timer.last.is_none());
+    }
+}
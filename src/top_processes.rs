@@ -0,0 +1,180 @@
+//! Procesos que más recursos consumen
+//!
+//! La vista de información muestra los cinco programas que más memoria o CPU
+//! usan, para ver qué está cargando el equipo antes de optimizarlo. Los
+//! procesos con el mismo nombre (las pestañas de un navegador) se suman.
+//!
+//! El uso de CPU de un proceso se mide entre dos lecturas, así que el monitor
+//! conserva su `System` mientras la vista está abierta y el loop principal
+//! lo refresca cada [`REFRESH_INTERVAL`].
+
+use crate::i18n::I18nKey;
+use std::collections::HashMap;
+use std::time::{Duration, Instant};
+use sysinfo::{ProcessRefreshKind, System};
+
+/// Número de procesos que se muestran
+pub const TOP_COUNT: usize = 5;
+
+/// Intervalo entre lecturas de los procesos
+const REFRESH_INTERVAL: Duration = Duration::from_secs(2);
+
+/// Criterio de ordenación de los procesos
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum ProcessSort {
+    #[default]
+    Memory,
+    Cpu,
+}
+
+impl ProcessSort {
+    /// Siguiente criterio (alterna memoria y CPU)
+    pub fn next(self) -> Self {
+        match self {
+            ProcessSort::Memory => ProcessSort::Cpu,
+            ProcessSort::Cpu => ProcessSort::Memory,
+        }
+    }
+
+    /// Clave de traducción del criterio
+    pub fn label_key(self) -> I18nKey {
+        match self {
+            ProcessSort::Memory => I18nKey::TopProcessesSortMemory,
+            ProcessSort::Cpu => I18nKey::TopProcessesSortCpu,
+        }
+    }
+}
+
+/// Consumo de un programa (todos sus procesos)
+#[derive(Debug, Clone, PartialEq)]
+pub struct ProcessUsage {
+    /// Nombre del ejecutable
+    pub name: String,
+    /// Número de procesos con ese nombre
+    pub count: usize,
+    /// Memoria en uso, en bytes
+    pub memory: u64,
+    /// Porcentaje de CPU sobre el total del equipo
+    pub cpu: f32,
+}
+
+/// Agrupa los procesos por nombre y devuelve los que más consumen
+fn rank<'a>(
+    processes: impl IntoIterator<Item = (&'a str, u64, f32)>,
+    sort: ProcessSort,
+) -> Vec<ProcessUsage> {
+    let mut grouped: HashMap<&str, ProcessUsage> = HashMap::new();
+    for (name, memory, cpu) in processes {
+        let usage = grouped.entry(name).or_insert_with(|| ProcessUsage {
+            name: name.to_string(),
+            count: 0,
+            memory: 0,
+            cpu: 0.0,
+        });
+        usage.count += 1;
+        usage.memory += memory;
+        usage.cpu += cpu;
+    }
+
+    let mut ranked: Vec<ProcessUsage> = grouped.into_values().collect();
+    ranked.sort_by(|a, b| {
+        let order = match sort {
+            ProcessSort::Memory => b.memory.cmp(&a.memory),
+            ProcessSort::Cpu => b.cpu.total_cmp(&a.cpu),
+        };
+        order.then_with(|| a.name.cmp(&b.name))
+    });
+    ranked.truncate(TOP_COUNT);
+    ranked
+}
+
+/// Lecturas periódicas de los procesos para la vista de información
+#[derive(Debug, Default)]
+pub struct ProcessMonitor {
+    system: System,
+    last_refresh: Option<Instant>,
+    sort: ProcessSort,
+    top: Vec<ProcessUsage>,
+}
+
+impl ProcessMonitor {
+    /// Vuelve a leer los procesos si ha pasado el intervalo
+    pub fn poll(&mut self) {
+        if self
+            .last_refresh
+            .is_some_and(|last| last.elapsed() < REFRESH_INTERVAL)
+        {
+            return;
+        }
+        self.last_refresh = Some(Instant::now());
+        self.system
+            .refresh_processes_specifics(ProcessRefreshKind::new().with_cpu().with_memory());
+        self.rerank();
+    }
+
+    /// Criterio de ordenación actual
+    pub fn sort(&self) -> ProcessSort {
+        self.sort
+    }
+
+    /// Cambia el criterio de ordenación
+    pub fn toggle_sort(&mut self) {
+        self.sort = self.sort.next();
+        self.rerank();
+    }
+
+    /// Programas que más consumen según el criterio actual
+    pub fn top(&self) -> &[ProcessUsage] {
+        &self.top
+    }
+
+    fn rerank(&mut self) {
+        // sysinfo da el uso de CPU por núcleo: un proceso puede pasar del 100%
+        let cores = std::thread::available_parallelism().map_or(1, |n| n.get()) as f32;
+        self.top = rank(
+            self.system
+                .processes()
+                .values()
+                // El proceso inactivo del sistema acapara la CPU libre
+                .filter(|process| process.pid().as_u32() != 0)
+                .map(|process| {
+                    (
+                        process.name(),
+                        process.memory(),
+                        process.cpu_usage() / cores,
+                    )
+                }),
+            self.sort,
+        );
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_rank_groups_processes_by_name() {
+        let processes = [
+            ("chrome.exe", 300, 1.0),
+            ("chrome.exe", 200, 2.0),
+            ("Code.exe", 400, 0.5),
+            ("svchost.exe", 50, 20.0),
+            ("a.exe", 1, 0.0),
+            ("b.exe", 2, 0.0),
+            ("c.exe", 3, 0.0),
+        ];
+
+        let by_memory = rank(processes, ProcessSort::Memory);
+        assert_eq!(by_memory.len(), TOP_COUNT);
+        assert_eq!(by_memory[0].name, "chrome.exe");
+        assert_eq!(by_memory[0].count, 2);
+        assert_eq!(by_memory[0].memory, 500);
+        assert_eq!(by_memory[1].name, "Code.exe");
+
+        let by_cpu = rank(processes, ProcessSort::Cpu);
+        assert_eq!(by_cpu[0].name, "svchost.exe");
+        assert_eq!(by_cpu[1].name, "chrome.exe");
+        assert_eq!(by_cpu[1].cpu, 3.0);
+    }
+}
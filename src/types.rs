@@ -1,3 +1,9 @@
+use crate::cleanup::TempLocation;
+use crate::i18n::I18nKey;
+use crate::repair::{DismResult, SfcResult};
+use crate::retry::FailedItem;
+use std::path::PathBuf;
+
 /// Vista actual de la aplicación
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum View {
@@ -14,6 +20,73 @@ pub enum View {
     RecycleBin,
     StartupOptimizer,
     VisualEffects,
+    Profiles,
+    Drivers,
+    Tasks,
+    Memory,
+    Programs,
+    Downloads,
+    Quarantine,
+    Defender,
+    Firewall,
+    Hosts,
+    PathCleaner,
+    FontCache,
+    SearchIndex,
+    Adapters,
+    Wifi,
+    Proxy,
+    Ports,
+    BootTime,
+    Jobs,
+    AppLogs,
+    Onboarding,
+    Settings,
+    About,
+}
+
+impl View {
+    /// Clave de traducción del título de la vista
+    pub fn title_key(self) -> I18nKey {
+        match self {
+            View::MainMenu => I18nKey::MainMenu,
+            View::Clean => I18nKey::CleanTitle,
+            View::Network => I18nKey::NetworkTitle,
+            View::Repair => I18nKey::RepairTitle,
+            View::Info => I18nKey::InfoTitle,
+            View::Optimize => I18nKey::OptimizeTitle,
+            View::WindowsUpdate => I18nKey::WindowsUpdateTitle,
+            View::Privacy => I18nKey::PrivacyTitle,
+            View::BrowserCache => I18nKey::BrowserCacheTitle,
+            View::SystemLogs => I18nKey::SystemLogsTitle,
+            View::RecycleBin => I18nKey::RecycleBinTitle,
+            View::StartupOptimizer => I18nKey::StartupTitle,
+            View::VisualEffects => I18nKey::VisualEffectsTitle,
+            View::Profiles => I18nKey::ProfilesTitle,
+            View::Drivers => I18nKey::DriversTitle,
+            View::Tasks => I18nKey::TasksTitle,
+            View::Memory => I18nKey::MemoryTitle,
+            View::Programs => I18nKey::ProgramsTitle,
+            View::Downloads => I18nKey::DownloadsTitle,
+            View::Quarantine => I18nKey::QuarantineTitle,
+            View::Defender => I18nKey::DefenderTitle,
+            View::Firewall => I18nKey::FirewallTitle,
+            View::Hosts => I18nKey::HostsTitle,
+            View::PathCleaner => I18nKey::PathCleanerTitle,
+            View::FontCache => I18nKey::FontCacheTitle,
+            View::SearchIndex => I18nKey::SearchIndexTitle,
+            View::Adapters => I18nKey::AdaptersTitle,
+            View::Wifi => I18nKey::WifiTitle,
+            View::Proxy => I18nKey::ProxyTitle,
+            View::Ports => I18nKey::PortsTitle,
+            View::BootTime => I18nKey::BootTitle,
+            View::Jobs => I18nKey::JobsTitle,
+            View::AppLogs => I18nKey::AppLogsTitle,
+            View::Onboarding => I18nKey::OnboardingTitle,
+            View::Settings => I18nKey::SettingsTitle,
+            View::About => I18nKey::AboutTitle,
+        }
+    }
 }
 
 /// Estado de ejecución de una operación
@@ -31,9 +104,82 @@ pub enum OperationState {
 pub struct CleanStats {
     pub deleted_count: usize,
     pub failed_count: usize,
+    /// Elementos que se omitieron porque algún proceso los estaba usando
+    pub in_use_count: usize,
+    pub size_freed: u64,
+    /// Estadísticas de cada carpeta temporal recorrida
+    pub locations: Vec<LocationStats>,
+}
+
+/// Estadísticas de limpieza de una carpeta temporal
+#[derive(Debug, Clone, Default)]
+pub struct LocationStats {
+    pub location: TempLocation,
+    pub path: PathBuf,
+    pub deleted_count: usize,
+    pub failed_count: usize,
+    pub in_use_count: usize,
     pub size_freed: u64,
 }
 
+/// Estadísticas de la reparación del sistema (DISM + SFC)
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct RepairStats {
+    /// Resultado de DISM (`None` si todavía no terminó)
+    pub dism_ok: Option<bool>,
+    /// Resultado de SFC (`None` si todavía no terminó)
+    pub sfc_ok: Option<bool>,
+    /// SFC encontró archivos dañados
+    pub corruption_found: bool,
+    /// SFC reparó todos los archivos dañados que encontró
+    pub corruption_fixed: bool,
+    /// Resultado de DISM según su salida
+    pub dism: DismResult,
+    /// Resultado de SFC según su salida
+    pub sfc: SfcResult,
+    /// Líneas `[SR]` del último análisis de SFC en `CBS.log`
+    pub cbs_excerpt: Vec<String>,
+}
+
+/// Estadísticas de la limpieza de red
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct NetworkStats {
+    /// Pasos completados
+    pub succeeded: usize,
+    /// Pasos que fallaron
+    pub failed: usize,
+    /// Pasos sin marcar que no se ejecutaron
+    pub skipped: usize,
+}
+
+/// Estadísticas de los servicios deshabilitados
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct ServiceStats {
+    /// Servicios deshabilitados
+    pub disabled: usize,
+    /// Servicios que no se pudieron deshabilitar
+    pub failed: usize,
+}
+
+/// Estadísticas de la última operación, según su tipo
+#[derive(Debug, Clone)]
+pub enum OperationStats {
+    Clean(CleanStats),
+    Repair(RepairStats),
+    Network(NetworkStats),
+    Services(ServiceStats),
+}
+
+impl OperationStats {
+    /// Bytes liberados por la operación (solo las limpiezas liberan espacio)
+    pub fn bytes_freed(&self) -> u64 {
+        match self {
+            OperationStats::Clean(stats) => stats.size_freed,
+            _ => 0,
+        }
+    }
+}
+
 /// Mensajes enviados desde el worker thread al thread principal
 #[derive(Debug)]
 pub enum WorkerMessage {
@@ -41,38 +187,49 @@ pub enum WorkerMessage {
     Log(String),
     /// Cambio de estado de la operación
     StateChange(OperationState),
-    /// Actualización de estadísticas de limpieza
-    StatsUpdate(CleanStats),
+    /// Actualización de las estadísticas de la operación
+    StatsUpdate(OperationStats),
+    /// Porcentaje completado del comando en curso (0-100)
+    Progress(f32),
+    /// El comando en curso dejó de producir salida (`true`) o volvió a hacerlo
+    Stalled(bool),
+    /// Elemento que falló y se puede volver a intentar
+    ItemFailed(FailedItem),
     /// Error ocurrido durante la operación
     Error(String),
     /// Operación completada exitosamente
     Completed,
 }
 
-/// Handle para manejar un worker thread
+/// Handle para manejar un worker (tarea asíncrona del executor)
 pub struct WorkerHandle {
     /// Receptor de mensajes del worker
     pub receiver: std::sync::mpsc::Receiver<WorkerMessage>,
-    /// Handle del thread (usado para join)
-    pub thread_handle: Option<std::thread::JoinHandle<()>>,
+    /// Handle de la tarea (usado para esperar a que termine)
+    pub task_handle: Option<tokio::task::JoinHandle<()>>,
     /// Flag atómico para cancelar la operación
     pub cancel_flag: std::sync::Arc<std::sync::atomic::AtomicBool>,
+    /// Flag atómico para terminar el paso bloqueado y continuar
+    pub skip_flag: std::sync::Arc<std::sync::atomic::AtomicBool>,
 }
 
 impl Drop for WorkerHandle {
-    /// Asegura que el thread worker se una correctamente al ser descartado
+    /// Asegura que la tarea del worker termine al ser descartado
     ///
-    /// Esto previene fugas de recursos asegurando que el thread termine
+    /// Esto previene fugas de recursos asegurando que la tarea termine
     /// antes de que el handle sea destruido.
     fn drop(&mut self) {
         // Señalar cancelación al worker
         self.cancel_flag
             .store(true, std::sync::atomic::Ordering::Relaxed);
 
-        // Unir el thread si existe
-        if let Some(handle) = self.thread_handle.take() {
-            // Ignorar errores de join (el thread pudo haber entrado en pánico)
-            let _ = handle.join();
+        // Esperar a la tarea si existe. Desde dentro del runtime no se puede
+        // bloquear, así que en ese caso la tarea sigue sola hasta terminar.
+        if let Some(handle) = self.task_handle.take()
+            && tokio::runtime::Handle::try_current().is_err()
+        {
+            // Ignorar errores (la tarea pudo haber entrado en pánico)
+            let _ = crate::executor::runtime().block_on(handle);
         }
     }
 }
@@ -126,7 +283,9 @@ mod tests {
         let stats = CleanStats {
             deleted_count: 42,
             failed_count: 3,
+            in_use_count: 0,
             size_freed: 1024 * 1024 * 50, // 50 MB
+            locations: Vec::new(),
         };
 
         assert_eq!(stats.deleted_count, 42);
@@ -139,7 +298,9 @@ mod tests {
         let stats1 = CleanStats {
             deleted_count: 10,
             failed_count: 2,
+            in_use_count: 0,
             size_freed: 1000,
+            locations: Vec::new(),
         };
 
         let stats2 = stats1.clone();
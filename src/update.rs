@@ -0,0 +1,356 @@
+//! Comprobación de nuevas versiones
+//!
+//! Si se activa `update.check_on_startup` (desactivado por defecto, porque
+//! consulta Internet), al arrancar se pregunta a la API de releases de
+//! GitHub por la última versión publicada. Si es más reciente que la que se
+//! ejecuta, el banner del menú principal lo indica y `U` descarga el
+//! ejecutable de la release a `updates\` en la carpeta de configuración. El
+//! ejecutable en uso no se sustituye: Windows no deja sobrescribirlo
+//! mientras corre, así que se reemplaza a mano al cerrar el programa.
+//!
+//! La descarga solo se conserva si su SHA-256 coincide con el publicado en
+//! el archivo de sumas de la release (`checksums.txt` o `<ejecutable>.sha256`).
+//! Si la release no lo incluye o la suma no coincide, el ejecutable se borra.
+//!
+//! Como en el resto de consultas, las peticiones las hace PowerShell
+//! (`Invoke-RestMethod` e `Invoke-WebRequest`) en un thread aparte y el
+//! resultado se recoge desde el loop principal.
+
+use crate::config::Config;
+use crate::platform;
+use crate::version;
+use serde::{Deserialize, Serialize};
+use std::fs;
+use std::path::PathBuf;
+use std::sync::mpsc::{self, Receiver};
+use std::thread;
+
+/// Última release publicada en GitHub
+const LATEST_RELEASE_URL: &str =
+    "https://api.github.com/repos/PabloViniegra/win_opt/releases/latest";
+
+/// Prefijo de las descargas de las releases del repositorio
+///
+/// Solo se descargan ejecutables de esta ruta, aunque la API devolviera
+/// otra dirección.
+const DOWNLOAD_PREFIX: &str = "https://github.com/PabloViniegra/win_opt/releases/download/";
+
+/// Configuración de la comprobación de versiones
+#[derive(Debug, Clone, Copy, Default, Serialize, Deserialize)]
+#[serde(default)]
+pub struct UpdateConfig {
+    /// Buscar una versión nueva al arrancar la interfaz
+    pub check_on_startup: bool,
+}
+
+/// Release más reciente que la versión en ejecución
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Release {
+    /// Versión sin la `v` inicial (`1.3.0`)
+    pub version: String,
+    /// Ejecutable adjunto a la release, si lo hay
+    pub download_url: Option<String>,
+    /// Archivo con las sumas SHA-256 de los adjuntos, si lo hay
+    pub checksum_url: Option<String>,
+}
+
+/// Resultado de la comprobación (`None` si no hay versión nueva)
+pub type CheckResult = Result<Option<Release>, String>;
+
+/// Resultado de la descarga: ruta del ejecutable descargado
+pub type DownloadResult = Result<PathBuf, String>;
+
+/// Cadena entre comillas simples de PowerShell
+fn quote(value: &str) -> String {
+    format!("'{}'", value.replace('\'', "''"))
+}
+
+/// Script que imprime `TAG|<etiqueta>` y `ASSET|<nombre>|<url>` por cada
+/// archivo adjunto de la última release
+fn release_script() -> String {
+    format!(
+        "$r = Invoke-RestMethod -Uri {} -Headers @{{ 'User-Agent' = 'win_opt' }} -TimeoutSec 15; \
+         'TAG|' + $r.tag_name; \
+         foreach ($a in $r.assets) {{ 'ASSET|' + $a.name + '|' + $a.browser_download_url }}",
+        quote(LATEST_RELEASE_URL)
+    )
+}
+
+/// Componentes numéricos de una versión (`v1.2.3` o `1.2.3`)
+///
+/// Los sufijos de prerelease (`-beta.1`) se ignoran.
+fn parse_version(version: &str) -> Option<[u64; 3]> {
+    let version = version.trim().trim_start_matches(['v', 'V']);
+    let core = version.split(['-', '+']).next()?;
+    let mut parts = [0; 3];
+    for (idx, part) in core.split('.').enumerate() {
+        *parts.get_mut(idx)? = part.parse().ok()?;
+    }
+    Some(parts)
+}
+
+/// Indica si `latest` es posterior a `current`
+pub fn is_newer(latest: &str, current: &str) -> bool {
+    match (parse_version(latest), parse_version(current)) {
+        (Some(latest), Some(current)) => latest > current,
+        _ => false,
+    }
+}
+
+/// Indica si un adjunto es un archivo de sumas SHA-256
+fn is_checksum_asset(name: &str) -> bool {
+    let name = name.to_ascii_lowercase();
+    name.ends_with(".sha256")
+        || matches!(
+            name.as_str(),
+            "checksums.txt" | "sha256sums" | "sha256sums.txt"
+        )
+}
+
+/// Parsea la salida del script de la release
+///
+/// Retorna `None` si no hay etiqueta. Se usa el primer `.exe` adjunto y el
+/// primer archivo de sumas.
+fn parse_release(output: &str) -> Option<Release> {
+    let mut version = None;
+    let mut download_url = None;
+    let mut checksum_url = None;
+    for line in output.lines().map(str::trim) {
+        if let Some(tag) = line.strip_prefix("TAG|") {
+            version = Some(tag.trim_start_matches(['v', 'V']).to_string());
+        } else if let Some(asset) = line.strip_prefix("ASSET|")
+            && let Some((name, url)) = asset.split_once('|')
+            && url.starts_with(DOWNLOAD_PREFIX)
+        {
+            if name.to_ascii_lowercase().ends_with(".exe") && download_url.is_none() {
+                download_url = Some(url.to_string());
+            } else if is_checksum_asset(name) && checksum_url.is_none() {
+                checksum_url = Some(url.to_string());
+            }
+        }
+    }
+    version
+        .filter(|version| !version.is_empty())
+        .map(|version| Release {
+            version,
+            download_url,
+            checksum_url,
+        })
+}
+
+/// Suma SHA-256 publicada para `file_name` en un archivo de sumas
+///
+/// Admite el formato de `sha256sum` (`<suma>  <archivo>`, con `*` delante
+/// del nombre en modo binario) y un archivo `.sha256` con solo la suma.
+fn expected_checksum(sums: &str, file_name: &str) -> Option<String> {
+    let is_hash = |token: &str| token.len() == 64 && token.chars().all(|c| c.is_ascii_hexdigit());
+    let mut lone = None;
+    for line in sums.lines() {
+        let mut tokens = line.split_whitespace();
+        let Some(hash) = tokens.next().filter(|token| is_hash(token)) else {
+            continue;
+        };
+        match tokens.next() {
+            Some(name) if name.trim_start_matches('*').eq_ignore_ascii_case(file_name) => {
+                return Some(hash.to_ascii_lowercase());
+            }
+            Some(_) => {}
+            None => lone = Some(hash.to_ascii_lowercase()),
+        }
+    }
+    lone
+}
+
+/// Comprueba la suma calculada de la descarga contra la publicada
+fn verify_checksum(sums: &str, file_name: &str, actual: &str) -> Result<(), String> {
+    let expected = expected_checksum(sums, file_name)
+        .ok_or_else(|| format!("el archivo de sumas no incluye {}", file_name))?;
+    if expected.eq_ignore_ascii_case(actual.trim()) {
+        Ok(())
+    } else {
+        Err(format!(
+            "la suma SHA-256 de {} no coincide con la publicada (esperada {}, obtenida {})",
+            file_name,
+            expected,
+            actual.trim().to_ascii_lowercase()
+        ))
+    }
+}
+
+/// Consulta la última release de forma síncrona
+fn check() -> CheckResult {
+    let output = platform::command("powershell")
+        .args(["-NoProfile", "-Command", &release_script()])
+        .output()
+        .map_err(|e| e.to_string())?;
+
+    if !output.status.success() {
+        return Err(String::from_utf8_lossy(&output.stderr).trim().to_string());
+    }
+
+    let release = parse_release(&String::from_utf8_lossy(&output.stdout))
+        .ok_or_else(|| "respuesta sin versión".to_string())?;
+    Ok(is_newer(&release.version, version::VERSION).then_some(release))
+}
+
+/// Lanza la comprobación en un thread y devuelve el receptor del resultado
+pub fn spawn_check() -> Receiver<CheckResult> {
+    let (sender, receiver) = mpsc::channel();
+
+    thread::spawn(move || {
+        let _ = sender.send(check());
+    });
+
+    receiver
+}
+
+/// Ruta donde se deja el ejecutable de una versión descargada
+pub fn staging_path(version: &str) -> std::io::Result<PathBuf> {
+    Ok(Config::get_config_dir()?
+        .join("updates")
+        .join(format!("win_opt-{}.exe", version)))
+}
+
+/// Descarga el ejecutable de la release de forma síncrona
+///
+/// Se descarga a un `.part` junto con el archivo de sumas de la release, y
+/// PowerShell imprime el SHA-256 de lo descargado (`Get-FileHash`). El
+/// `.part` solo se renombra si la suma coincide con la publicada; si no, se
+/// borra, para no dejar un ejecutable a medias o alterado.
+fn download(release: &Release) -> DownloadResult {
+    let url = release
+        .download_url
+        .as_deref()
+        .ok_or_else(|| "la release no incluye un ejecutable".to_string())?;
+    let checksum_url = release.checksum_url.as_deref().ok_or_else(|| {
+        "la release no incluye sumas SHA-256 para comprobar la descarga".to_string()
+    })?;
+    let file_name = url.rsplit('/').next().unwrap_or(url);
+    let path = staging_path(&release.version).map_err(|e| e.to_string())?;
+    if let Some(dir) = path.parent() {
+        fs::create_dir_all(dir).map_err(|e| e.to_string())?;
+    }
+    let partial = path.with_extension("exe.part");
+    let sums_path = path.with_extension("sha256.part");
+
+    let script = format!(
+        "$ProgressPreference = 'SilentlyContinue'; $ErrorActionPreference = 'Stop'; \
+         $h = @{{ 'User-Agent' = 'win_opt' }}; \
+         Invoke-WebRequest -Uri {} -OutFile {} -UseBasicParsing -Headers $h; \
+         Invoke-WebRequest -Uri {} -OutFile {} -UseBasicParsing -Headers $h; \
+         (Get-FileHash -LiteralPath {} -Algorithm SHA256).Hash",
+        quote(url),
+        quote(&partial.to_string_lossy()),
+        quote(checksum_url),
+        quote(&sums_path.to_string_lossy()),
+        quote(&partial.to_string_lossy())
+    );
+    let output = platform::command("powershell")
+        .args(["-NoProfile", "-Command", &script])
+        .output()
+        .map_err(|e| e.to_string());
+    let sums = fs::read_to_string(&sums_path);
+    let _ = fs::remove_file(&sums_path);
+    let output = output.inspect_err(|_| {
+        let _ = fs::remove_file(&partial);
+    })?;
+
+    if !output.status.success() || !partial.exists() {
+        let _ = fs::remove_file(&partial);
+        let message = String::from_utf8_lossy(&output.stderr).trim().to_string();
+        return Err(if message.is_empty() {
+            format!("código {:?}", output.status.code())
+        } else {
+            message
+        });
+    }
+
+    let verified = sums
+        .map_err(|e| format!("no se pudo leer el archivo de sumas: {}", e))
+        .and_then(|sums| {
+            verify_checksum(&sums, file_name, &String::from_utf8_lossy(&output.stdout))
+        });
+    if let Err(e) = verified {
+        let _ = fs::remove_file(&partial);
+        return Err(e);
+    }
+
+    fs::rename(&partial, &path).map_err(|e| e.to_string())?;
+    Ok(path)
+}
+
+/// Lanza la descarga en un thread y devuelve el receptor del resultado
+pub fn spawn_download(release: Release) -> Receiver<DownloadResult> {
+    let (sender, receiver) = mpsc::channel();
+
+    thread::spawn(move || {
+        let _ = sender.send(download(&release));
+    });
+
+    receiver
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_versions_compare_numerically() {
+        assert!(is_newer("v1.10.0", "1.9.3"));
+        assert!(is_newer("1.2.2", "1.2.1"));
+        assert!(is_newer("2", "1.9.9"));
+        assert!(!is_newer("v1.2.1", "1.2.1"));
+        assert!(!is_newer("1.2.0-beta.1", "1.2.0"));
+        // Una etiqueta que no es una versión no se ofrece
+        assert!(!is_newer("nightly", "1.2.1"));
+        assert!(!is_newer("1.2.3.4", "1.2.1"));
+    }
+
+    #[test]
+    fn test_parse_release_picks_the_repository_executable() {
+        let output = "TAG|v1.3.0\n\
+             ASSET|checksums.txt|https://github.com/PabloViniegra/win_opt/releases/download/v1.3.0/checksums.txt\n\
+             ASSET|win_opt.exe|https://example.com/win_opt.exe\n\
+             ASSET|win_opt.exe|https://github.com/PabloViniegra/win_opt/releases/download/v1.3.0/win_opt.exe\n";
+        assert_eq!(
+            parse_release(output),
+            Some(Release {
+                version: "1.3.0".to_string(),
+                download_url: Some(
+                    "https://github.com/PabloViniegra/win_opt/releases/download/v1.3.0/win_opt.exe"
+                        .to_string()
+                ),
+                checksum_url: Some(
+                    "https://github.com/PabloViniegra/win_opt/releases/download/v1.3.0/checksums.txt"
+                        .to_string()
+                ),
+            })
+        );
+
+        let without_assets = parse_release("TAG|1.3.0\n").unwrap();
+        assert_eq!(without_assets.download_url, None);
+        assert_eq!(without_assets.checksum_url, None);
+        assert_eq!(parse_release(""), None);
+    }
+
+    #[test]
+    fn test_download_is_checked_against_the_published_checksum() {
+        let hash = "9F86D081884C7D659A2FEAA0C55AD015A3BF4F1B2B0B822CD15D6C15B0F00A08";
+        let sums = format!(
+            "{}  win_opt-linux\n{} *win_opt.exe\n",
+            "0".repeat(64),
+            hash.to_ascii_lowercase()
+        );
+        assert_eq!(verify_checksum(&sums, "win_opt.exe", hash), Ok(()));
+        assert!(verify_checksum(&sums, "win_opt.exe", &"1".repeat(64)).is_err());
+        // Sin la suma del ejecutable no se acepta la descarga
+        assert!(verify_checksum(&sums, "otro.exe", hash).is_err());
+
+        // Un `.sha256` con solo la suma
+        assert_eq!(
+            expected_checksum(&format!("{}\n", hash), "win_opt.exe"),
+            Some(hash.to_ascii_lowercase())
+        );
+        assert_eq!(expected_checksum("no es una suma\n", "win_opt.exe"), None);
+    }
+}
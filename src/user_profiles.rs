@@ -0,0 +1,209 @@
+//! Perfiles de los demás usuarios del equipo
+//!
+//! Con privilegios de administrador, la vista de limpieza ofrece limpiar
+//! también los temporales y la caché de Internet de otros usuarios. Los
+//! perfiles se leen de la clave `ProfileList` del registro, que guarda la
+//! carpeta de cada cuenta que ha iniciado sesión en el equipo; si no se puede
+//! leer (fuera de Windows o en modo simulación), se listan las carpetas de
+//! `C:\Users`.
+
+use crate::env_path;
+use crate::platform;
+use crate::registry::{self, RegKey};
+use std::fs;
+use std::path::{Path, PathBuf};
+
+/// Clave del registro con los perfiles del equipo
+const PROFILE_LIST_KEY: &str = "HKLM\\SOFTWARE\\Microsoft\\Windows NT\\CurrentVersion\\ProfileList";
+
+/// Prefijos de SID de las cuentas de usuario locales, de dominio y de
+/// Microsoft Entra ID (el resto son cuentas de servicio como `S-1-5-18`)
+const USER_SID_PREFIXES: [&str; 2] = ["S-1-5-21-", "S-1-12-1-"];
+
+/// Perfiles creados por Windows que no pertenecen a ningún usuario
+const SYSTEM_PROFILES: [&str; 4] = ["Default", "Default User", "Public", "All Users"];
+
+/// Perfil de otro usuario que se puede incluir en la limpieza
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct UserProfile {
+    /// Nombre de la carpeta del perfil
+    pub name: String,
+    /// Carpeta del perfil (`C:\Users\Ana`)
+    pub path: PathBuf,
+    /// Marcado para limpiar
+    pub selected: bool,
+}
+
+impl UserProfile {
+    /// Perfil de una carpeta; el nombre es su último componente
+    ///
+    /// Las rutas del registro usan `\`, que fuera de Windows no es un
+    /// separador, así que el nombre se separa a mano.
+    fn new(path: PathBuf) -> Option<UserProfile> {
+        let shown = path.to_string_lossy();
+        let name = shown.rsplit(['\\', '/']).find(|part| !part.is_empty())?;
+        Some(UserProfile {
+            name: name.to_string(),
+            path,
+            selected: false,
+        })
+    }
+
+    /// Carpeta temporal del perfil
+    pub fn temp_dir(&self) -> PathBuf {
+        self.path.join("AppData").join("Local").join("Temp")
+    }
+
+    /// Caché de Internet de Edge e Internet Explorer del perfil
+    pub fn inet_cache_dir(&self) -> PathBuf {
+        self.path
+            .join("AppData")
+            .join("Local")
+            .join("Microsoft")
+            .join("Windows")
+            .join("INetCache")
+    }
+}
+
+/// Perfiles del equipo salvo el del usuario actual, sin marcar y ordenados
+/// por nombre
+pub fn others() -> Vec<UserProfile> {
+    let current = platform::user_profile();
+    let mut profiles = from_registry(&registry::query_key_recursive(PROFILE_LIST_KEY));
+    if profiles.is_empty()
+        && let Some(dir) = current.parent()
+    {
+        profiles = from_dir(dir);
+    }
+    let current = fs::canonicalize(&current).unwrap_or(current);
+    profiles.retain(|profile| {
+        fs::canonicalize(&profile.path).is_ok_and(|path| path.is_dir() && path != current)
+    });
+    profiles.sort_by_key(|profile| profile.name.to_lowercase());
+    profiles
+}
+
+/// Perfiles de usuario de la salida de `reg query /s` sobre `ProfileList`
+fn from_registry(keys: &[RegKey]) -> Vec<UserProfile> {
+    keys.iter()
+        .filter(|key| {
+            let sid = key.path.rsplit('\\').next().unwrap_or_default();
+            USER_SID_PREFIXES
+                .iter()
+                .any(|prefix| sid.starts_with(prefix))
+        })
+        .filter_map(|key| key.string("ProfileImagePath"))
+        .filter_map(|path| UserProfile::new(PathBuf::from(env_path::expand_env(path))))
+        .collect()
+}
+
+/// Perfiles de usuario de la carpeta que los contiene
+fn from_dir(dir: &Path) -> Vec<UserProfile> {
+    let Ok(entries) = fs::read_dir(dir) else {
+        return Vec::new();
+    };
+    entries
+        .flatten()
+        .filter(|entry| {
+            let name = entry.file_name();
+            !SYSTEM_PROFILES
+                .iter()
+                .any(|system| system.eq_ignore_ascii_case(&name.to_string_lossy()))
+        })
+        .filter_map(|entry| UserProfile::new(entry.path()))
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::registry::RegValue;
+
+    fn key(sid: &str, image_path: Option<&str>) -> RegKey {
+        RegKey {
+            path: format!(
+                "HKEY_LOCAL_MACHINE\\SOFTWARE\\Microsoft\\Windows NT\\CurrentVersion\\ProfileList\\{sid}"
+            ),
+            values: image_path
+                .map(|path| {
+                    (
+                        "ProfileImagePath".to_string(),
+                        RegValue {
+                            kind: "REG_EXPAND_SZ".to_string(),
+                            data: path.to_string(),
+                        },
+                    )
+                })
+                .into_iter()
+                .collect(),
+        }
+    }
+
+    #[test]
+    fn test_registry_profiles_skip_service_accounts() {
+        let keys = [
+            key(
+                "S-1-5-18",
+                Some("%systemroot%\\system32\\config\\systemprofile"),
+            ),
+            key(
+                "S-1-5-19",
+                Some("C:\\Windows\\ServiceProfiles\\LocalService"),
+            ),
+            key(
+                "S-1-5-21-1004336348-1177238915-682003330-1001",
+                Some("C:\\Users\\Ana"),
+            ),
+            key(
+                "S-1-12-1-2040385565-1307338290-3410337162-1785723584",
+                Some("C:\\Users\\luis"),
+            ),
+            key("S-1-5-21-1004336348-1177238915-682003330-1002", None),
+        ];
+        let names: Vec<_> = from_registry(&keys)
+            .into_iter()
+            .map(|profile| profile.name)
+            .collect();
+        assert_eq!(names, ["Ana", "luis"]);
+    }
+
+    #[test]
+    fn test_registry_profiles_are_not_selected() {
+        let keys = [key(
+            "S-1-5-21-1004336348-1177238915-682003330-1001",
+            Some("D:\\Users\\Ana"),
+        )];
+        let profiles = from_registry(&keys);
+        assert_eq!(profiles[0].path, PathBuf::from("D:\\Users\\Ana"));
+        assert_eq!(profiles[0].name, "Ana");
+        assert!(!profiles[0].selected);
+    }
+
+    #[test]
+    fn test_dir_profiles_skip_system_profiles() {
+        let root = std::env::temp_dir().join("win_opt_test_user_profiles");
+        let _ = fs::remove_dir_all(&root);
+        for profile in ["Ana", "Default", "Public", "luis"] {
+            fs::create_dir_all(root.join(profile)).unwrap();
+        }
+
+        let mut names: Vec<_> = from_dir(&root)
+            .into_iter()
+            .map(|profile| profile.name)
+            .collect();
+        names.sort();
+        assert_eq!(names, ["Ana", "luis"]);
+
+        fs::remove_dir_all(&root).unwrap();
+    }
+
+    #[test]
+    fn test_profile_dirs() {
+        let profile = UserProfile::new(PathBuf::from("Users").join("Ana")).unwrap();
+        assert_eq!(
+            profile.temp_dir(),
+            Path::new("Users/Ana/AppData/Local/Temp")
+        );
+        assert!(profile.inet_cache_dir().ends_with("Windows/INetCache"));
+    }
+}
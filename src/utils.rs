@@ -1,4 +1,7 @@
-use std::process::Command;
+use crate::platform;
+use std::fs;
+use std::path::Path;
+use std::time::{SystemTime, UNIX_EPOCH};
 
 /// Helper para pluralización correcta en español
 fn pluralize(count: u64, singular: &str, plural: &str) -> String {
@@ -36,9 +39,159 @@ pub fn format_uptime(uptime: u64) -> String {
     }
 }
 
+/// Formatea una duración en segundos como reloj (`mm:ss`, o `h:mm:ss` a
+/// partir de una hora)
+pub fn format_clock(secs: u64) -> String {
+    let (hours, minutes, seconds) = (secs / 3600, (secs % 3600) / 60, secs % 60);
+    if hours > 0 {
+        format!("{}:{:02}:{:02}", hours, minutes, seconds)
+    } else {
+        format!("{:02}:{:02}", minutes, seconds)
+    }
+}
+
+/// Formatea un tamaño en bytes con la unidad más adecuada (B, KB, MB, GB o TB)
+///
+/// Usa potencias de 1024 y dos decimales a partir de KB, con punto como
+/// separador decimal; [`crate::i18n::Language::format_size`] lo adapta al
+/// idioma de la interfaz.
+pub fn format_bytes(bytes: u64) -> String {
+    const UNITS: [&str; 4] = ["KB", "MB", "GB", "TB"];
+    if bytes < 1024 {
+        return format!("{} B", bytes);
+    }
+    let mut value = bytes as f64 / 1024.0;
+    let mut unit = 0;
+    while value >= 1024.0 && unit + 1 < UNITS.len() {
+        value /= 1024.0;
+        unit += 1;
+    }
+    format!("{:.2} {}", value, UNITS[unit])
+}
+
+/// Tamaño en bytes de un archivo o, recursivamente, de una carpeta
+///
+/// Los enlaces simbólicos no se siguen y los elementos inaccesibles cuentan 0.
+pub fn path_size(path: &Path) -> u64 {
+    let Ok(metadata) = fs::symlink_metadata(path) else {
+        return 0;
+    };
+    if !is_plain_dir(&metadata) {
+        return metadata.len();
+    }
+    fs::read_dir(path)
+        .map(|entries| {
+            entries
+                .flatten()
+                .map(|entry| path_size(&entry.path()))
+                .sum()
+        })
+        .unwrap_or(0)
+}
+
+/// Indica si unos metadatos leídos sin seguir enlaces son de una carpeta que
+/// se puede recorrer
+///
+/// Los enlaces simbólicos y las uniones (junctions) apuntan a contenido que
+/// no se borra con la carpeta que los contiene y pueden formar ciclos. En
+/// Windows tampoco se recorre ningún otro punto de reanálisis: abrir una
+/// carpeta de OneDrive que solo está en la nube la descargaría.
+pub fn is_plain_dir(metadata: &fs::Metadata) -> bool {
+    #[cfg(windows)]
+    {
+        use std::os::windows::fs::MetadataExt;
+        const FILE_ATTRIBUTE_REPARSE_POINT: u32 = 0x400;
+        if metadata.file_attributes() & FILE_ATTRIBUTE_REPARSE_POINT != 0 {
+            return false;
+        }
+    }
+    metadata.is_dir()
+}
+
+/// Días transcurridos desde el 1970-01-01 según el reloj del sistema
+pub fn days_since_epoch() -> i64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map(|d| (d.as_secs() / 86_400) as i64)
+        .unwrap_or(0)
+}
+
+/// Convierte días desde 1970-01-01 a una fecha civil (año, mes, día)
+///
+/// Algoritmo `civil_from_days` de Howard Hinnant (calendario gregoriano).
+pub fn civil_from_days(days: i64) -> (i64, u32, u32) {
+    let z = days + 719_468;
+    let era = z.div_euclid(146_097);
+    let doe = z.rem_euclid(146_097);
+    let yoe = (doe - doe / 1460 + doe / 36_524 - doe / 146_096) / 365;
+    let doy = doe - (365 * yoe + yoe / 4 - yoe / 100);
+    let mp = (5 * doy + 2) / 153;
+    let day = (doy - (153 * mp + 2) / 5 + 1) as u32;
+    let month = if mp < 10 { mp + 3 } else { mp - 9 } as u32;
+    let year = yoe + era * 400 + i64::from(month <= 2);
+    (year, month, day)
+}
+
+/// Convierte una fecha civil a días desde 1970-01-01 (inverso de `civil_from_days`)
+pub fn days_from_civil(year: i64, month: u32, day: u32) -> i64 {
+    let year = if month <= 2 { year - 1 } else { year };
+    let era = year.div_euclid(400);
+    let yoe = year.rem_euclid(400);
+    let mp = i64::from((month + 9) % 12);
+    let doy = (153 * mp + 2) / 5 + i64::from(day) - 1;
+    let doe = yoe * 365 + yoe / 4 - yoe / 100 + doy;
+    era * 146_097 + doe - 719_468
+}
+
+/// Fecha actual en formato `yyyy-MM-dd`
+pub fn today_iso() -> String {
+    let (year, month, day) = civil_from_days(days_since_epoch());
+    format!("{:04}-{:02}-{:02}", year, month, day)
+}
+
+/// Formatea segundos desde 1970-01-01 como `yyyy-MM-dd HH:mm` (UTC)
+pub fn format_utc_timestamp(secs: u64) -> String {
+    let (year, month, day) = civil_from_days((secs / 86_400) as i64);
+    let minutes = (secs % 86_400) / 60;
+    format!(
+        "{:04}-{:02}-{:02} {:02}:{:02}",
+        year,
+        month,
+        day,
+        minutes / 60,
+        minutes % 60
+    )
+}
+
+/// Fecha y hora actuales en formato `yyyy-MM-dd HH:mm` (UTC)
+pub fn now_utc_iso() -> String {
+    let secs = SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map(|d| d.as_secs())
+        .unwrap_or(0);
+    format_utc_timestamp(secs)
+}
+
+/// Interpreta una fecha `yyyy-MM-dd` como días desde 1970-01-01
+pub fn parse_iso_date(date: &str) -> Option<i64> {
+    let mut parts = date.splitn(3, '-');
+    let year = parts.next()?.parse().ok()?;
+    let month = parts
+        .next()?
+        .parse()
+        .ok()
+        .filter(|m| (1..=12).contains(m))?;
+    let day = parts
+        .next()?
+        .parse()
+        .ok()
+        .filter(|d| (1..=31).contains(d))?;
+    Some(days_from_civil(year, month, day))
+}
+
 /// Verifica si el proceso actual tiene permisos de administrador
 pub fn is_admin() -> bool {
-    Command::new("net")
+    platform::command("net")
         .args(["session"])
         .output()
         .map(|o| o.status.success())
@@ -49,6 +202,28 @@ pub fn is_admin() -> bool {
 mod tests {
     use super::*;
 
+    #[test]
+    fn test_format_bytes_picks_unit() {
+        assert_eq!(format_bytes(512), "512 B");
+        assert_eq!(format_bytes(1536), "1.50 KB");
+        assert_eq!(format_bytes(5 * 1024 * 1024 * 1024), "5.00 GB");
+        assert_eq!(format_bytes(3 * 1024_u64.pow(4)), "3.00 TB");
+        assert_eq!(format_bytes(2048 * 1024_u64.pow(4)), "2048.00 TB");
+    }
+
+    #[test]
+    fn test_format_utc_timestamp() {
+        assert_eq!(format_utc_timestamp(0), "1970-01-01 00:00");
+        assert_eq!(format_utc_timestamp(1_791_890_100), "2026-10-13 11:15");
+    }
+
+    #[test]
+    fn test_format_clock() {
+        assert_eq!(format_clock(0), "00:00");
+        assert_eq!(format_clock(83), "01:23");
+        assert_eq!(format_clock(3725), "1:02:05");
+    }
+
     #[test]
     fn test_format_uptime_seconds() {
         assert_eq!(format_uptime(0), "0 segundos");
@@ -87,6 +262,64 @@ mod tests {
     }
 
     #[test]
+    fn test_civil_date_conversions() {
+        assert_eq!(civil_from_days(0), (1970, 1, 1));
+        assert_eq!(civil_from_days(19_783), (2024, 3, 1));
+        assert_eq!(days_from_civil(2024, 2, 29), 19_782);
+        assert_eq!(parse_iso_date("2024-03-01"), Some(19_783));
+        assert_eq!(parse_iso_date("2024-13-01"), None);
+        assert_eq!(parse_iso_date("no-date"), None);
+    }
+
+    #[test]
+    fn test_today_iso_format() {
+        let today = today_iso();
+        assert_eq!(today.len(), 10);
+        assert_eq!(parse_iso_date(&today), Some(days_since_epoch()));
+    }
+
+    #[test]
+    fn test_path_size_is_recursive() {
+        let root = std::env::temp_dir().join("win_opt_test_path_size");
+        let _ = fs::remove_dir_all(&root);
+        fs::create_dir_all(root.join("a").join("b")).unwrap();
+        fs::write(root.join("top.tmp"), [0; 10]).unwrap();
+        fs::write(root.join("a").join("mid.tmp"), [0; 20]).unwrap();
+        fs::write(root.join("a").join("b").join("deep.tmp"), [0; 30]).unwrap();
+
+        assert_eq!(path_size(&root), 60);
+        assert_eq!(path_size(&root.join("top.tmp")), 10);
+        assert_eq!(path_size(&root.join("missing")), 0);
+
+        fs::remove_dir_all(&root).unwrap();
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn test_path_size_does_not_follow_links() {
+        let root = std::env::temp_dir().join("win_opt_test_path_size_links");
+        let _ = fs::remove_dir_all(&root);
+        fs::create_dir_all(root.join("dir")).unwrap();
+        fs::create_dir_all(root.join("outside")).unwrap();
+        fs::write(root.join("dir").join("own.tmp"), [0; 10]).unwrap();
+        fs::write(root.join("outside").join("big.bin"), [0; 1000]).unwrap();
+        // Un ciclo y un enlace a contenido de fuera de la carpeta
+        std::os::unix::fs::symlink(&root, root.join("dir").join("loop")).unwrap();
+        std::os::unix::fs::symlink(root.join("outside"), root.join("dir").join("ext")).unwrap();
+
+        let links = fs::symlink_metadata(root.join("dir").join("loop"))
+            .unwrap()
+            .len()
+            + fs::symlink_metadata(root.join("dir").join("ext"))
+                .unwrap()
+                .len();
+        assert_eq!(path_size(&root.join("dir")), 10 + links);
+
+        fs::remove_dir_all(&root).unwrap();
+    }
+
+    #[test]
+    #[allow(clippy::bool_comparison)]
     fn test_is_admin_returns_bool() {
         // Solo verificar que no panic y retorna un booleano
         let result = is_admin();
@@ -0,0 +1,49 @@
+//! Versión del programa
+//!
+//! La versión sale siempre de `Cargo.toml` (`CARGO_PKG_VERSION`): el banner,
+//! `--version`, la comprobación de actualizaciones y la vista "Acerca de"
+//! la leen de aquí. `build.rs` añade el commit y el momento de compilación.
+
+use crate::utils;
+
+/// Versión en ejecución (`1.2.1`)
+pub const VERSION: &str = env!("CARGO_PKG_VERSION");
+
+/// Commit corto desde el que se compiló (`unknown` fuera de git)
+pub const GIT_HASH: &str = env!("WIN_OPT_GIT_HASH");
+
+/// Versión con el commit, para `--version`
+pub const LONG_VERSION: &str = concat!(
+    env!("CARGO_PKG_VERSION"),
+    " (",
+    env!("WIN_OPT_GIT_HASH"),
+    ")"
+);
+
+/// Segundos desde 1970 en que se compiló
+const BUILD_EPOCH: &str = env!("WIN_OPT_BUILD_EPOCH");
+
+/// Versión tal como se muestra en la interfaz (`v1.2.1`)
+pub fn display() -> String {
+    format!("v{}", VERSION)
+}
+
+/// Fecha y hora de compilación (`yyyy-MM-dd HH:mm`, UTC)
+pub fn build_date() -> String {
+    BUILD_EPOCH
+        .parse()
+        .map(utils::format_utc_timestamp)
+        .unwrap_or_default()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_version_comes_from_the_manifest() {
+        assert_eq!(display(), format!("v{}", env!("CARGO_PKG_VERSION")));
+        assert!(LONG_VERSION.starts_with(VERSION));
+        assert_eq!(build_date().len(), "yyyy-MM-dd HH:mm".len());
+    }
+}
@@ -0,0 +1,260 @@
+//! Perfiles Wi-Fi guardados
+//!
+//! Lista los perfiles con `netsh wlan show profiles`, permite marcar varios y
+//! eliminarlos (`netsh wlan delete profile`) o exportarlos a XML
+//! (`netsh wlan export profile`), con o sin la clave en texto claro, en una
+//! carpeta fechada dentro de la configuración.
+
+use crate::config::Config;
+use crate::platform;
+use crate::types::OperationState;
+use crate::utils::{is_admin, today_iso};
+use crate::{log_error, log_info, log_warn};
+
+/// Perfil Wi-Fi guardado
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct WifiProfile {
+    pub name: String,
+    /// Marcado para eliminar o exportar
+    pub marked: bool,
+}
+
+/// Lista los perfiles Wi-Fi guardados
+pub fn execute_wifi_profiles(app: &mut crate::app::App) {
+    app.operation_state = OperationState::Running;
+    app.wifi_profiles.clear();
+    app.wifi_delete_confirm = false;
+    log_info!(app, "📶 Leyendo los perfiles Wi-Fi guardados...");
+
+    let result = platform::command("netsh")
+        .args(["wlan", "show", "profiles"])
+        .output();
+
+    match result {
+        Ok(output) if output.status.success() => {
+            app.wifi_profiles = parse_profile_names(&String::from_utf8_lossy(&output.stdout))
+                .into_iter()
+                .map(|name| WifiProfile {
+                    name,
+                    marked: false,
+                })
+                .collect();
+        }
+        Ok(_) => {
+            log_error!(
+                app,
+                "❌ No se pudieron leer los perfiles (¿el servicio WLAN AutoConfig está detenido?)"
+            );
+            app.operation_state = OperationState::Failed;
+            return;
+        }
+        Err(e) => {
+            log_error!(app, "❌ Error: {}", e);
+            app.operation_state = OperationState::Failed;
+            return;
+        }
+    }
+
+    let total = app.wifi_profiles.len();
+    log_info!(app, "✅ Perfiles guardados: {}", total);
+    if total > 0 {
+        log_info!(
+            app,
+            "💡 Espacio marca perfiles; D elimina, X exporta y C exporta con claves"
+        );
+    }
+    app.operation_state = OperationState::Completed;
+}
+
+/// Marca o desmarca el perfil indicado
+pub fn toggle_mark(app: &mut crate::app::App, index: usize) {
+    app.wifi_delete_confirm = false;
+    if let Some(profile) = app.wifi_profiles.get_mut(index) {
+        profile.marked = !profile.marked;
+    }
+}
+
+/// Perfiles sobre los que actuar: los marcados o, si no hay, el seleccionado
+fn target_profiles(app: &crate::app::App) -> Vec<String> {
+    let marked: Vec<String> = app
+        .wifi_profiles
+        .iter()
+        .filter(|p| p.marked)
+        .map(|p| p.name.clone())
+        .collect();
+    if !marked.is_empty() {
+        return marked;
+    }
+    app.wifi_profiles
+        .get(app.selected_item)
+        .map(|p| vec![p.name.clone()])
+        .unwrap_or_default()
+}
+
+/// Solicita eliminar los perfiles; la segunda pulsación lo confirma
+pub fn request_delete(app: &mut crate::app::App) {
+    let targets = target_profiles(app);
+    if targets.is_empty() {
+        return;
+    }
+
+    if app.config.confirmations.enabled && !app.wifi_delete_confirm {
+        app.wifi_delete_confirm = true;
+        let count = targets.len();
+        log_warn!(
+            app,
+            "⚠️  Se van a eliminar {} perfiles ({}). Pulsa D de nuevo para confirmar",
+            count,
+            targets.join(", ")
+        );
+        return;
+    }
+
+    app.wifi_delete_confirm = false;
+    log_info!(app, "");
+    if !is_admin() {
+        log_error!(
+            app,
+            "⛔ ERROR: Esta operación requiere permisos de Administrador"
+        );
+        return;
+    }
+
+    let mut deleted = 0;
+    for name in &targets {
+        match netsh_profile(&["wlan", "delete", "profile"], name, &[]) {
+            Ok(true) => {
+                deleted += 1;
+                log_info!(app, "🗑️  {} eliminado", name);
+            }
+            Ok(false) => log_error!(app, "❌ No se pudo eliminar {}", name),
+            Err(e) => log_error!(app, "❌ Error: {}", e),
+        }
+    }
+
+    log_info!(app, "✅ Perfiles eliminados: {}", deleted);
+    execute_wifi_profiles(app);
+}
+
+/// Exporta los perfiles a XML, opcionalmente con la clave en texto claro
+pub fn execute_export(app: &mut crate::app::App, with_keys: bool) {
+    app.wifi_delete_confirm = false;
+    let targets = target_profiles(app);
+    if targets.is_empty() {
+        return;
+    }
+
+    log_info!(app, "");
+    // Windows solo devuelve la clave descifrada a un administrador
+    if with_keys && !is_admin() {
+        log_error!(
+            app,
+            "⛔ ERROR: Exportar las claves requiere permisos de Administrador"
+        );
+        return;
+    }
+
+    let dir = match Config::get_config_dir() {
+        Ok(dir) => dir.join(format!("wifi_{}", today_iso())),
+        Err(e) => {
+            log_error!(app, "❌ Error: {}", e);
+            return;
+        }
+    };
+    if let Err(e) = std::fs::create_dir_all(&dir) {
+        log_error!(app, "❌ No se pudo crear {}: {}", dir.display(), e);
+        return;
+    }
+
+    let folder = format!("folder=\"{}\"", dir.display());
+    let mut extra = vec![folder.as_str()];
+    if with_keys {
+        extra.push("key=clear");
+    }
+
+    let mut exported = 0;
+    for name in &targets {
+        match netsh_profile(&["wlan", "export", "profile"], name, &extra) {
+            Ok(true) => exported += 1,
+            Ok(false) => log_error!(app, "❌ No se pudo exportar {}", name),
+            Err(e) => log_error!(app, "❌ Error: {}", e),
+        }
+    }
+
+    log_info!(
+        app,
+        "💾 {} perfiles exportados a {}",
+        exported,
+        dir.display()
+    );
+    if with_keys && exported > 0 {
+        log_warn!(
+            app,
+            "⚠️  Los XML contienen las contraseñas en texto claro: guárdalos en un lugar seguro"
+        );
+    }
+}
+
+/// Ejecuta un subcomando de `netsh` sobre un perfil con nombre
+///
+/// `netsh` espera `name="Mi red"` con las comillas solo alrededor del valor,
+/// así que en Windows los argumentos con nombre se pasan sin reescapar.
+fn netsh_profile(command: &[&str], name: &str, extra: &[&str]) -> std::io::Result<bool> {
+    let mut cmd = platform::command("netsh");
+    cmd.args(command);
+    let named = std::iter::once(format!("name=\"{}\"", name))
+        .chain(extra.iter().map(|arg| arg.to_string()));
+    for arg in named {
+        cmd.raw_arg(arg);
+    }
+
+    cmd.output().map(|output| output.status.success())
+}
+
+/// Extrae los nombres de perfil de `netsh wlan show profiles`
+///
+/// Las etiquetas están traducidas ("All User Profile", "Perfil de todos los
+/// usuarios"...), así que se toma el valor tras los dos puntos de cada línea
+/// sangrada. La cabecera "Profiles on interface Wi-Fi:" no está sangrada.
+fn parse_profile_names(output: &str) -> Vec<String> {
+    output
+        .lines()
+        .filter(|line| line.starts_with(char::is_whitespace))
+        .filter_map(|line| line.split_once(':'))
+        .map(|(_, name)| name.trim().to_string())
+        .filter(|name| !name.is_empty())
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_profile_names() {
+        let output = "\r\nProfiles on interface Wi-Fi:\r\n\r\n\
+                      Group policy profiles (read only)\r\n\
+                      ---------------------------------\r\n\
+                      \x20   <None>\r\n\r\n\
+                      User profiles\r\n\
+                      -------------\r\n\
+                      \x20   All User Profile     : HomeNet\r\n\
+                      \x20   All User Profile     : Café: 5G\r\n";
+        assert_eq!(parse_profile_names(output), vec!["HomeNet", "Café: 5G"]);
+    }
+
+    #[test]
+    fn test_parse_profile_names_localized() {
+        let output = "Perfiles en la interfaz Wi-Fi:\n\n\
+                      Perfiles de usuario\n\
+                      -------------------\n\
+                      \x20   Perfil de todos los usuarios: Oficina\n";
+        assert_eq!(parse_profile_names(output), vec!["Oficina"]);
+    }
+
+    #[test]
+    fn test_parse_profile_names_without_wlan() {
+        let output = "The Wireless AutoConfig Service (wlansvc) is not running.\r\n";
+        assert!(parse_profile_names(output).is_empty());
+    }
+}
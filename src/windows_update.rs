@@ -0,0 +1,122 @@
+//! Estado de Windows Update
+//!
+//! Consulta las actualizaciones pendientes y la fecha de la última
+//! instalación a través de la API COM de Windows Update Agent
+//! (`Microsoft.Update.Session`). La búsqueda puede tardar varios segundos,
+//! por lo que se ejecuta en un thread aparte y el resultado se recoge desde
+//! el loop principal.
+
+use crate::log_info;
+use crate::platform;
+use std::sync::mpsc::{self, Receiver};
+use std::thread;
+
+/// Script de PowerShell que imprime `UPDATE|<título>` por cada actualización
+/// pendiente y `LAST|<yyyy-MM-dd>` con la última entrada del historial
+const STATUS_SCRIPT: &str = "$session = New-Object -ComObject Microsoft.Update.Session; \
+     $searcher = $session.CreateUpdateSearcher(); \
+     $result = $searcher.Search('IsInstalled=0 and IsHidden=0'); \
+     foreach ($u in $result.Updates) { 'UPDATE|' + $u.Title }; \
+     if ($searcher.GetTotalHistoryCount() -gt 0) { \
+     foreach ($h in $searcher.QueryHistory(0, 1)) { 'LAST|' + $h.Date.ToString('yyyy-MM-dd') } }";
+
+/// Estado de Windows Update
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct UpdateStatus {
+    /// Títulos de las actualizaciones pendientes
+    pub pending: Vec<String>,
+    /// Fecha de la última instalación (`yyyy-MM-dd`)
+    pub last_install: Option<String>,
+}
+
+/// Resultado de la consulta de estado (mensaje de error si falló)
+pub type StatusResult = Result<UpdateStatus, String>;
+
+/// Lanza la consulta de estado en un thread y devuelve el receptor del resultado
+pub fn spawn_status_query() -> Receiver<StatusResult> {
+    let (sender, receiver) = mpsc::channel();
+
+    thread::spawn(move || {
+        let _ = sender.send(query_status());
+    });
+
+    receiver
+}
+
+/// Ejecuta la consulta de estado de forma síncrona
+fn query_status() -> StatusResult {
+    let output = platform::command("powershell")
+        .args(["-NoProfile", "-Command", STATUS_SCRIPT])
+        .output()
+        .map_err(|e| e.to_string())?;
+
+    if !output.status.success() {
+        return Err(String::from_utf8_lossy(&output.stderr).trim().to_string());
+    }
+
+    Ok(parse_status_output(&String::from_utf8_lossy(
+        &output.stdout,
+    )))
+}
+
+/// Solicita a Windows Update una búsqueda de actualizaciones y refresca el panel
+pub fn execute_scan(app: &mut crate::app::App) {
+    log_info!(app, "");
+    log_info!(app, "🔄 Solicitando búsqueda de actualizaciones...");
+
+    // UsoClient lanza la búsqueda del servicio en segundo plano; la consulta
+    // COM posterior también busca en línea, así que el panel queda al día.
+    match platform::command("UsoClient").arg("StartScan").output() {
+        Ok(_) => log_info!(app, "✅ Búsqueda iniciada"),
+        Err(e) => tracing::debug!("UsoClient no disponible: {}", e),
+    }
+
+    app.refresh_update_status();
+}
+
+/// Parsea la salida del script de estado
+fn parse_status_output(output: &str) -> UpdateStatus {
+    let mut status = UpdateStatus::default();
+
+    for line in output.lines() {
+        if let Some(title) = line.strip_prefix("UPDATE|") {
+            let title = title.trim();
+            if !title.is_empty() {
+                status.pending.push(title.to_string());
+            }
+        } else if let Some(date) = line.strip_prefix("LAST|") {
+            status.last_install = Some(date.trim().to_string());
+        }
+    }
+
+    status
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_status_output() {
+        let output = "UPDATE|2026-10 Cumulative Update for Windows 11 (KB5031354)\r\n\
+                      UPDATE|Security Intelligence Update for Microsoft Defender\r\n\
+                      LAST|2026-10-02\r\n";
+        let status = parse_status_output(output);
+        assert_eq!(status.pending.len(), 2);
+        assert!(status.pending[0].contains("KB5031354"));
+        assert_eq!(status.last_install.as_deref(), Some("2026-10-02"));
+    }
+
+    #[test]
+    fn test_parse_status_output_up_to_date() {
+        let status = parse_status_output("LAST|2026-09-30\n");
+        assert!(status.pending.is_empty());
+        assert_eq!(status.last_install.as_deref(), Some("2026-09-30"));
+    }
+
+    #[test]
+    fn test_parse_status_output_ignores_noise() {
+        let status = parse_status_output("WARNING: something\nUPDATE|  \n");
+        assert_eq!(status, UpdateStatus::default());
+    }
+}
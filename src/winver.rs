@@ -0,0 +1,195 @@
+//! Versión de Windows en que se ejecuta win_opt
+//!
+//! Algunas operaciones dependen de la compilación del sistema: comandos que
+//! aparecieron en versiones concretas (`DISM /ResetBase`), servicios que solo
+//! existen desde Windows 10 o herramientas retiradas en compilaciones
+//! recientes (WMIC en Windows 11 24H2). Cada operación declara el rango de
+//! compilaciones en que funciona; fuera de él se marca como no disponible o
+//! se adapta a la alternativa de esa versión.
+
+use crate::registry;
+use crate::utils;
+use std::sync::OnceLock;
+
+/// Clave del registro con la versión de Windows
+const CURRENT_VERSION_KEY: &str = "HKLM\\SOFTWARE\\Microsoft\\Windows NT\\CurrentVersion";
+
+/// Windows 8
+pub const WINDOWS_8: u32 = 9200;
+
+/// Windows 8.1
+pub const WINDOWS_8_1: u32 = 9600;
+
+/// Primera compilación de Windows 10
+pub const WINDOWS_10: u32 = 10240;
+
+/// Primera compilación de Windows 11
+pub const WINDOWS_11: u32 = 22000;
+
+/// Windows 11 24H2, que ya no instala WMIC por defecto
+pub const WINDOWS_11_24H2: u32 = 26100;
+
+/// Rango de compilaciones de Windows en que funciona una operación
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct BuildRange {
+    /// Primera compilación admitida
+    pub min: Option<u32>,
+    /// Primera compilación que ya no se admite
+    pub max: Option<u32>,
+}
+
+impl BuildRange {
+    /// Cualquier compilación
+    pub const ANY: BuildRange = BuildRange {
+        min: None,
+        max: None,
+    };
+
+    /// Desde la compilación `min` en adelante
+    pub const fn from(min: u32) -> BuildRange {
+        BuildRange {
+            min: Some(min),
+            max: None,
+        }
+    }
+
+    /// Hasta la compilación anterior a `max`
+    pub const fn before(max: u32) -> BuildRange {
+        BuildRange {
+            min: None,
+            max: Some(max),
+        }
+    }
+
+    /// Indica si la compilación está dentro del rango
+    ///
+    /// Si no se conoce la compilación se da por admitida, para no bloquear
+    /// operaciones por no poder leer la versión.
+    pub fn contains(self, build: Option<u32>) -> bool {
+        let Some(build) = build else {
+            return true;
+        };
+        self.min.is_none_or(|min| build >= min) && self.max.is_none_or(|max| build < max)
+    }
+}
+
+/// Número de compilación de Windows (por ejemplo `22631`)
+///
+/// Se lee una sola vez del registro; retorna `None` si no se puede leer
+/// (por ejemplo, fuera de Windows).
+pub fn current_build() -> Option<u32> {
+    static BUILD: OnceLock<Option<u32>> = OnceLock::new();
+    *BUILD.get_or_init(|| {
+        registry::query_value(CURRENT_VERSION_KEY, "CurrentBuildNumber")
+            .and_then(|value| parse_build(&value.data))
+    })
+}
+
+/// Edición de Windows con su versión (por ejemplo `Windows 11 Pro 23H2`)
+///
+/// Se lee una sola vez del registro; retorna `None` si no se puede leer.
+pub fn edition() -> Option<String> {
+    static EDITION: OnceLock<Option<String>> = OnceLock::new();
+    EDITION
+        .get_or_init(|| {
+            let product = registry::query_value(CURRENT_VERSION_KEY, "ProductName")?;
+            let display = registry::query_value(CURRENT_VERSION_KEY, "DisplayVersion");
+            Some(describe_edition(
+                &product.data,
+                display.as_ref().map(|value| value.data.as_str()),
+                current_build(),
+            ))
+        })
+        .clone()
+}
+
+/// Fecha de instalación de Windows (`yyyy-MM-dd`, UTC)
+///
+/// Las actualizaciones de características (23H2, 24H2...) reinstalan el
+/// sistema, así que es la fecha de la última de ellas. Se lee una sola vez
+/// del registro; retorna `None` si no se puede leer.
+pub fn install_date() -> Option<String> {
+    static INSTALL_DATE: OnceLock<Option<String>> = OnceLock::new();
+    INSTALL_DATE
+        .get_or_init(|| {
+            registry::query_value(CURRENT_VERSION_KEY, "InstallDate")
+                .and_then(|value| value.as_dword())
+                .map(format_install_date)
+        })
+        .clone()
+}
+
+/// Formatea `InstallDate` (segundos desde 1970) como `yyyy-MM-dd`
+fn format_install_date(seconds: u32) -> String {
+    let (year, month, day) = utils::civil_from_days(i64::from(seconds) / 86_400);
+    format!("{:04}-{:02}-{:02}", year, month, day)
+}
+
+/// Compone la edición a partir de `ProductName` y `DisplayVersion`
+///
+/// Windows 11 conserva `Windows 10` en `ProductName`, así que desde su
+/// primera compilación se corrige por el número de compilación.
+fn describe_edition(product: &str, display_version: Option<&str>, build: Option<u32>) -> String {
+    let product = product.trim();
+    let mut edition = match product.strip_prefix("Windows 10") {
+        Some(rest) if build.is_some_and(|build| build >= WINDOWS_11) => {
+            format!("Windows 11{}", rest)
+        }
+        _ => product.to_string(),
+    };
+    if let Some(version) = display_version.map(str::trim).filter(|v| !v.is_empty()) {
+        edition.push(' ');
+        edition.push_str(version);
+    }
+    edition
+}
+
+/// Interpreta el valor `CurrentBuildNumber`
+fn parse_build(data: &str) -> Option<u32> {
+    data.trim().parse().ok()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_build() {
+        assert_eq!(parse_build("22631"), Some(22631));
+        assert_eq!(parse_build(" 19045 "), Some(19045));
+        assert_eq!(parse_build("10.0"), None);
+    }
+
+    #[test]
+    fn test_windows_11_is_not_reported_as_windows_10() {
+        assert_eq!(
+            describe_edition("Windows 10 Pro", Some("23H2"), Some(22631)),
+            "Windows 11 Pro 23H2"
+        );
+        assert_eq!(
+            describe_edition("Windows 10 Home", Some("22H2"), Some(19045)),
+            "Windows 10 Home 22H2"
+        );
+        assert_eq!(
+            describe_edition("Windows 7 Professional", None, Some(7601)),
+            "Windows 7 Professional"
+        );
+    }
+
+    #[test]
+    fn test_format_install_date() {
+        assert_eq!(format_install_date(0), "1970-01-01");
+        assert_eq!(format_install_date(1_697_500_000), "2023-10-16");
+    }
+
+    #[test]
+    fn test_build_range_contains() {
+        assert!(BuildRange::ANY.contains(Some(7601)));
+        assert!(BuildRange::from(WINDOWS_10).contains(Some(WINDOWS_10)));
+        assert!(!BuildRange::from(WINDOWS_10).contains(Some(9600)));
+        assert!(BuildRange::before(WINDOWS_11_24H2).contains(Some(22631)));
+        assert!(!BuildRange::before(WINDOWS_11_24H2).contains(Some(26100)));
+        // Sin versión conocida no se bloquea nada
+        assert!(BuildRange::from(WINDOWS_10).contains(None));
+    }
+}